@@ -11,6 +11,7 @@ use serde::Deserialize;
 pub struct Enchantment {
     pub id: u8,
     pub anvil_cost: u32,
+    pub min_cost: EnchantmentCost,
     pub supported_items: String,
     pub description: TextComponent,
     pub exclusive_set: Option<String>,
@@ -18,6 +19,12 @@ pub struct Enchantment {
     pub slots: Vec<AttributeModifierSlot>, // TODO: add more
 }
 
+#[derive(Deserialize)]
+pub struct EnchantmentCost {
+    pub base: i32,
+    pub per_level_above_first: i32,
+}
+
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum AttributeModifierSlot {
@@ -66,6 +73,8 @@ pub fn build() -> TokenStream {
         let raw_name = name.strip_prefix("minecraft:").unwrap();
         let format_name = format_ident!("{}", raw_name.to_shouty_snake_case());
         let anvil_cost = enchantment.anvil_cost;
+        let min_cost_base = enchantment.min_cost.base;
+        let min_cost_per_level = enchantment.min_cost.per_level_above_first;
         let supported_items = format_ident!(
             "{}",
             enchantment
@@ -99,6 +108,10 @@ pub fn build() -> TokenStream {
                     registry_key: #raw_name,
                     description: #translate,
                     anvil_cost: #anvil_cost,
+                    min_cost: EnchantmentCost {
+                        base: #min_cost_base,
+                        per_level_above_first: #min_cost_per_level,
+                    },
                     supported_items: &ItemTag::#supported_items,
                     exclusive_set: Some(&EnchantmentTag::#exclusive_set),
                     max_level: #max_level,
@@ -113,6 +126,10 @@ pub fn build() -> TokenStream {
                     description: #translate,
                     registry_key: #raw_name,
                     anvil_cost: #anvil_cost,
+                    min_cost: EnchantmentCost {
+                        base: #min_cost_base,
+                        per_level_above_first: #min_cost_per_level,
+                    },
                     supported_items: &ItemTag::#supported_items,
                     exclusive_set: None,
                     max_level: #max_level,
@@ -142,6 +159,8 @@ pub fn build() -> TokenStream {
             pub registry_key: &'static str,
             pub description: &'static str, // TODO use TextComponent
             pub anvil_cost: u32,
+            /// The lowest enchanting cost this enchantment can be rolled at.
+            pub min_cost: EnchantmentCost,
             pub supported_items: &'static Tag,
             pub exclusive_set: Option<&'static Tag>,
             pub max_level: i32,
@@ -173,6 +192,17 @@ pub fn build() -> TokenStream {
                 self.id.hash(state);
             }
         }
+        /// A cost that grows linearly with the enchantment level.
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+        pub struct EnchantmentCost {
+            pub base: i32,
+            pub per_level_above_first: i32,
+        }
+        impl EnchantmentCost {
+            pub const fn calculate(&self, level: i32) -> i32 {
+                self.base + self.per_level_above_first * (level - 1)
+            }
+        }
         #[derive(Debug, Clone, Hash, PartialEq)]
         pub enum AttributeModifierSlot {
             Any,
@@ -204,6 +234,9 @@ pub fn build() -> TokenStream {
                 }
             }
 
+            pub const fn get_min_cost(&self, level: i32) -> i32 {
+                self.min_cost.calculate(level)
+            }
             pub fn can_enchant(&self, item: &'static Item) -> bool {
                 self.supported_items.1.contains(&item.id)
             }
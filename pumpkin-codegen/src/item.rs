@@ -23,6 +23,8 @@ pub struct ItemComponents {
     pub max_stack_size: u8,
     #[serde(rename = "minecraft:jukebox_playable")]
     pub jukebox_playable: Option<String>,
+    #[serde(rename = "minecraft:provides_trim_material")]
+    pub provides_trim_material: Option<String>,
     #[serde(rename = "minecraft:damage")]
     pub damage: Option<u16>,
     #[serde(rename = "minecraft:max_damage")]
@@ -59,6 +61,17 @@ impl ToTokens for ItemComponents {
                 }),
             });
         }
+        if let Some(material) = &self.provides_trim_material {
+            let material = LitStr::new(
+                material.strip_prefix("minecraft:").unwrap_or(material),
+                Span::call_site(),
+            );
+            tokens.extend(quote! {
+                (ProvidesTrimMaterial, &ProvidesTrimMaterialImpl {
+                    material: #material,
+                }),
+            });
+        }
 
         let TextContent::Translate {
             translate: text,
@@ -180,7 +180,10 @@ pub enum LootConditionStruct {
     #[serde(rename = "minecraft:random_chance")]
     RandomChance,
     #[serde(rename = "minecraft:random_chance_with_enchanted_bonus")]
-    RandomChanceWithEnchantedBonus,
+    RandomChanceWithEnchantedBonus {
+        unenchanted_chance: f32,
+        enchanted_chance: LootConditionLinearChanceStruct,
+    },
     #[serde(rename = "minecraft:entity_properties")]
     EntityProperties,
     #[serde(rename = "minecraft:killed_by_player")]
@@ -221,8 +224,19 @@ impl ToTokens for LootConditionStruct {
             Self::AnyOf => quote! { LootCondition::AnyOf },
             Self::AllOf => quote! { LootCondition::AllOf },
             Self::RandomChance => quote! { LootCondition::RandomChance },
-            Self::RandomChanceWithEnchantedBonus => {
-                quote! { LootCondition::RandomChanceWithEnchantedBonus }
+            Self::RandomChanceWithEnchantedBonus {
+                unenchanted_chance,
+                enchanted_chance,
+            } => {
+                let base = enchanted_chance.base;
+                let per_level_above_first = enchanted_chance.per_level_above_first;
+                quote! {
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        unenchanted_chance: #unenchanted_chance,
+                        base: #base,
+                        per_level_above_first: #per_level_above_first,
+                    }
+                }
             }
             Self::EntityProperties => quote! { LootCondition::EntityProperties },
             Self::KilledByPlayer => quote! { LootCondition::KilledByPlayer },
@@ -254,6 +268,12 @@ impl ToTokens for LootConditionStruct {
     }
 }
 
+#[derive(Deserialize, Clone, Debug)]
+pub struct LootConditionLinearChanceStruct {
+    base: f32,
+    per_level_above_first: f32,
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct LootFunctionStruct {
     #[serde(flatten)]
@@ -290,7 +310,10 @@ pub enum LootFunctionTypesStruct {
         add: Option<bool>,
     },
     #[serde(rename = "minecraft:enchanted_count_increase")]
-    EnchantedCountIncrease,
+    EnchantedCountIncrease {
+        count: LootFunctionNumberProviderStruct,
+        limit: Option<i32>,
+    },
     #[serde(rename = "minecraft:furnace_smelt")]
     FurnaceSmelt,
     #[serde(rename = "minecraft:set_potion")]
@@ -336,8 +359,14 @@ impl ToTokens for LootFunctionTypesStruct {
             Self::SetPotion => {
                 quote! { LootFunctionTypes::SetPotion }
             }
-            Self::EnchantedCountIncrease => {
-                quote! { LootFunctionTypes::EnchantedCountIncrease }
+            Self::EnchantedCountIncrease { count, limit } => {
+                let count = count.to_token_stream();
+                let limit = if let Some(limit) = limit {
+                    quote! { Some(#limit) }
+                } else {
+                    quote! { None }
+                };
+                quote! { LootFunctionTypes::EnchantedCountIncrease { count: #count, limit: #limit } }
             }
             Self::LimitCount { limit } => {
                 let min = if let Some(min) = limit.min {
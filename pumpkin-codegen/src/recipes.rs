@@ -22,13 +22,13 @@ pub enum RecipeTypes {
     #[serde(rename = "minecraft:smelting")]
     Smelting(CookingRecipeStruct),
     #[serde(rename = "minecraft:smithing_transform")]
-    SmithingTransform,
+    SmithingTransform(SmithingTransformRecipeStruct),
     #[serde(rename = "minecraft:smithing_trim")]
-    SmithingTrim,
+    SmithingTrim(SmithingTrimRecipeStruct),
     #[serde(rename = "minecraft:smoking")]
     Smoking(CookingRecipeStruct),
     #[serde(rename = "minecraft:stonecutting")]
-    Stonecutting,
+    Stonecutting(StonecuttingRecipeStruct),
     #[serde(other)]
     #[serde(rename = "minecraft:crafting_special_*")]
     CraftingSpecial,
@@ -272,6 +272,78 @@ impl ToTokens for CraftingDecoratedPotStruct {
     }
 }
 
+#[derive(Deserialize)]
+pub struct StonecuttingRecipeStruct {
+    ingredient: RecipeIngredientTypes,
+    result: RecipeResultStruct,
+}
+
+impl ToTokens for StonecuttingRecipeStruct {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ingredient = self.ingredient.to_token_stream();
+        let result = self.result.to_token_stream();
+
+        tokens.extend(quote! {
+            StonecuttingRecipe {
+                ingredient: #ingredient,
+                result: #result,
+            }
+        });
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SmithingTransformRecipeStruct {
+    template: RecipeIngredientTypes,
+    base: RecipeIngredientTypes,
+    addition: RecipeIngredientTypes,
+    result: RecipeResultStruct,
+}
+
+impl ToTokens for SmithingTransformRecipeStruct {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let template = self.template.to_token_stream();
+        let base = self.base.to_token_stream();
+        let addition = self.addition.to_token_stream();
+        let result = self.result.to_token_stream();
+
+        tokens.extend(quote! {
+            SmithingRecipeTypes::SmithingTransform {
+                template: #template,
+                base: #base,
+                addition: #addition,
+                result: #result,
+            }
+        });
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SmithingTrimRecipeStruct {
+    template: RecipeIngredientTypes,
+    base: RecipeIngredientTypes,
+    addition: RecipeIngredientTypes,
+    pattern: String,
+}
+
+impl ToTokens for SmithingTrimRecipeStruct {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let template = self.template.to_token_stream();
+        let base = self.base.to_token_stream();
+        let addition = self.addition.to_token_stream();
+        let pattern = self.pattern.to_token_stream();
+
+        tokens.extend(quote! {
+            SmithingRecipeTypes::SmithingTrim {
+                template: #template,
+                base: #base,
+                addition: #addition,
+                pattern: #pattern,
+            }
+        });
+    }
+}
+
 #[derive(Deserialize)]
 pub struct RecipeResultStruct {
     id: String,
@@ -373,6 +445,8 @@ pub fn build() -> TokenStream {
 
     let mut crafting_recipes = Vec::new();
     let mut cooking_recipes = Vec::new();
+    let mut stonecutting_recipes = Vec::new();
+    let mut smithing_recipes = Vec::new();
 
     for recipe in recipes_assets {
         match recipe {
@@ -421,8 +495,12 @@ pub fn build() -> TokenStream {
                 };
                 cooking_recipes.push(smelting_token);
             }
-            RecipeTypes::SmithingTransform => {}
-            RecipeTypes::SmithingTrim => {}
+            RecipeTypes::SmithingTransform(recipe) => {
+                smithing_recipes.push(recipe.to_token_stream());
+            }
+            RecipeTypes::SmithingTrim(recipe) => {
+                smithing_recipes.push(recipe.to_token_stream());
+            }
             RecipeTypes::Smoking(recipe) => {
                 let recipe_id = recipe.generate_recipe_id("smoking");
                 let mut common_cooking_token = TokenStream::new();
@@ -434,7 +512,9 @@ pub fn build() -> TokenStream {
                 };
                 cooking_recipes.push(smoking_token);
             }
-            RecipeTypes::Stonecutting => {}
+            RecipeTypes::Stonecutting(recipe) => {
+                stonecutting_recipes.push(recipe.to_token_stream());
+            }
             RecipeTypes::CraftingSpecial => {}
         }
     }
@@ -534,6 +614,56 @@ pub fn build() -> TokenStream {
         }
 
 
+        #[derive(Clone, Debug)]
+        pub struct StonecuttingRecipe {
+            pub ingredient: RecipeIngredientTypes,
+            pub result: RecipeResultStruct,
+        }
+
+        #[derive(Clone, Debug)]
+        pub enum SmithingRecipeTypes {
+            SmithingTransform {
+                template: RecipeIngredientTypes,
+                base: RecipeIngredientTypes,
+                addition: RecipeIngredientTypes,
+                result: RecipeResultStruct,
+            },
+            SmithingTrim {
+                template: RecipeIngredientTypes,
+                base: RecipeIngredientTypes,
+                addition: RecipeIngredientTypes,
+                pattern: &'static str,
+            },
+        }
+
+        impl SmithingRecipeTypes {
+            pub fn template(&self) -> &RecipeIngredientTypes {
+                match self {
+                    SmithingRecipeTypes::SmithingTransform { template, .. }
+                    | SmithingRecipeTypes::SmithingTrim { template, .. } => template,
+                }
+            }
+
+            pub fn base(&self) -> &RecipeIngredientTypes {
+                match self {
+                    SmithingRecipeTypes::SmithingTransform { base, .. }
+                    | SmithingRecipeTypes::SmithingTrim { base, .. } => base,
+                }
+            }
+
+            pub fn addition(&self) -> &RecipeIngredientTypes {
+                match self {
+                    SmithingRecipeTypes::SmithingTransform { addition, .. }
+                    | SmithingRecipeTypes::SmithingTrim { addition, .. } => addition,
+                }
+            }
+
+            pub fn matches(&self, template: &Item, base: &Item, addition: &Item) -> bool {
+                self.template().match_item(template)
+                    && self.base().match_item(base)
+                    && self.addition().match_item(addition)
+            }
+        }
 
         #[derive(Clone, Debug)]
         pub struct RecipeResultStruct {
@@ -582,6 +712,25 @@ pub fn build() -> TokenStream {
         pub static RECIPES_COOKING: &[CookingRecipeType] = &[
             #(#cooking_recipes ),*
         ];
+        pub static RECIPES_STONECUTTING: &[StonecuttingRecipe] = &[
+            #(#stonecutting_recipes),*
+        ];
+        pub static RECIPES_SMITHING: &[SmithingRecipeTypes] = &[
+            #(#smithing_recipes),*
+        ];
+
+        /// Returns the stonecutting recipes for the given input, in the same order the client lists them.
+        pub fn get_stonecutting_recipes(ingredient: &Item) -> impl Iterator<Item = &'static StonecuttingRecipe> {
+            RECIPES_STONECUTTING
+                .iter()
+                .filter(move |recipe| recipe.ingredient.match_item(ingredient))
+        }
+
+        pub fn get_smithing_recipe(template: &Item, base: &Item, addition: &Item) -> Option<&'static SmithingRecipeTypes> {
+            RECIPES_SMITHING
+                .iter()
+                .find(|recipe| recipe.matches(template, base, addition))
+        }
 
         pub fn get_cooking_recipe_with_ingredient(ingredient: &Item, recipe_type: CookingRecipeKind) -> Option<&'static CookingRecipe> {
             RECIPES_COOKING
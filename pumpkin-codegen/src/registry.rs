@@ -79,15 +79,18 @@ pub fn build() -> TokenStream {
         pub static REGISTRIES_1_21_9: &[StaticRegistry] = #v1_21_9_registries;
         pub static REGISTRIES_1_21_11: &[StaticRegistry] = #v1_21_11_registries;
 
+        /// The registries synced to clients on `version`.
+        pub fn static_registries(version: MinecraftVersion) -> &'static [StaticRegistry] {
+            match version {
+                MinecraftVersion::V_1_21_7 => REGISTRIES_1_21_9,
+                MinecraftVersion::V_1_21_9 => REGISTRIES_1_21_9,
+                _ => REGISTRIES_1_21_11,
+            }
+        }
+
         impl Registry {
             pub fn get_synced(version: MinecraftVersion) -> Vec<Self> {
-                let static_regs = match version {
-                    MinecraftVersion::V_1_21_7 => REGISTRIES_1_21_9,
-                    MinecraftVersion::V_1_21_9 => REGISTRIES_1_21_9,
-                    _ => REGISTRIES_1_21_11,
-                };
-
-                static_regs.iter().map(|static_reg| {
+                static_registries(version).iter().map(|static_reg| {
                     let registry_id = if static_reg.registry_id.contains(':') {
                         static_reg.registry_id.to_string()
                     } else {
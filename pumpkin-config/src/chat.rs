@@ -9,12 +9,19 @@ pub struct ChatConfig {
     /// The custom chat format.
     /// `Note`: it does not apply when secure chat is enabled.
     pub format: String,
+    /// Regular expressions for words that are not allowed in chat.
+    pub blocked_patterns: Vec<String>,
+    /// Whether messages matching a blocked pattern are discarded
+    /// instead of being sent with the matches censored.
+    pub block_filtered_messages: bool,
 }
 
 impl Default for ChatConfig {
     fn default() -> Self {
         Self {
             format: "<{DISPLAYNAME}> {MESSAGE}".to_string(),
+            blocked_patterns: Vec::new(),
+            block_filtered_messages: false,
         }
     }
 }
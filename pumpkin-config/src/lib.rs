@@ -130,6 +130,10 @@ pub struct BasicConfiguration {
     pub white_list: bool,
     /// Whether to enforce the whitelist.
     pub enforce_whitelist: bool,
+    /// Minutes a player may be idle before being kicked. Specifying `0` disables the timeout.
+    pub player_idle_timeout: i32,
+    /// Whether players are never kicked for being idle while they are alone on the server.
+    pub idle_timeout_exempt_solo: bool,
 }
 
 impl Default for BasicConfiguration {
@@ -161,6 +165,8 @@ impl Default for BasicConfiguration {
             allow_chat_reports: false,
             white_list: false,
             enforce_whitelist: false,
+            player_idle_timeout: 0,
+            idle_timeout_exempt_solo: false,
         }
     }
 }
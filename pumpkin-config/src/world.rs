@@ -14,9 +14,44 @@ pub struct LevelConfig {
     /// Number of ticks between autosave checks. If 0, autosave is disabled.
     #[serde(default = "default_autosave_ticks")]
     pub autosave_ticks: u64,
+    /// Hides the world seed from clients.
+    ///
+    /// When enabled, Java clients receive a random per-boot value instead of the hashed seed
+    /// and Bedrock clients receive a zero seed. This only affects cosmetic client-side effects
+    /// such as biome blending; `/seed` still reports the real seed.
+    #[serde(default)]
+    pub hide_seed: bool,
+    /// Caches block states read during a tick to avoid locking the same chunk repeatedly.
+    ///
+    /// Mostly helps with redstone and other block updates that read the same positions often.
+    #[serde(default)]
+    pub block_state_cache: bool,
+    /// Upgrades every chunk saved by an older version when the server starts, instead of only
+    /// the chunks that get loaded.
+    #[serde(default)]
+    pub force_upgrade: bool,
+    /// Lets players break blocks on and above the Nether's bedrock ceiling, where farms are
+    /// commonly built. Breaking them is prevented by default.
+    #[serde(default)]
+    pub allow_nether_ceiling_mining: bool,
+    /// Number of threads generating chunks, split evenly between the dimensions with at least one
+    /// each. If 0, one less than the number of physical cores is used.
+    ///
+    /// Generation runs on these threads instead of the async runtime, so a busy generator never
+    /// delays networking.
+    #[serde(default)]
+    pub generation_threads: usize,
+    /// Radius in chunks around the world spawn that stays loaded and ticked while no player is
+    /// near, like vanilla's spawn chunks. If 0, spawn chunks are unloaded like any other chunk.
+    #[serde(default = "default_spawn_chunk_radius")]
+    pub spawn_chunk_radius: u8,
     // TODO: More options
 }
 
+const fn default_spawn_chunk_radius() -> u8 {
+    2
+}
+
 const fn default_autosave_ticks() -> u64 {
     6000 // Default to 5 minutes at 20 TPS
 }
@@ -53,6 +53,12 @@ pub enum PistonBehavior {
 }
 
 impl BlockState {
+    /// Whether this state fully blocks light passing through it.
+    #[must_use]
+    pub const fn is_opaque(&self) -> bool {
+        self.opacity >= 15
+    }
+
     #[must_use]
     pub const fn is_air(&self) -> bool {
         self.state_flags & IS_AIR != 0
@@ -3,12 +3,16 @@
 use crate::attributes::Attributes;
 use crate::data_component::DataComponent;
 use crate::data_component::DataComponent::{
-    AttributeModifiers, BlocksAttacks, Consumable, CustomData, CustomName, Damage, DeathProtection,
-    Enchantments, Equippable, FireworkExplosion, Fireworks, Food, ItemName, JukeboxPlayable,
-    MaxDamage, MaxStackSize, PotionContents, Tool, Unbreakable,
+    AttributeModifiers, BlocksAttacks, CanBreak, CanPlaceOn, Consumable, CustomData, CustomName,
+    Damage, DeathProtection, Enchantments, Equippable, FireworkExplosion, Fireworks, Food,
+    ItemName, JukeboxPlayable, MapId, MaxDamage, MaxStackSize, PotionContents,
+    ProvidesTrimMaterial, StoredEnchantments, Tool, Trim, Unbreakable, WritableBookContent,
+    WrittenBookContent,
 };
 use crate::entity_type::EntityType;
-use crate::tag::{Tag, Taggable};
+use crate::packet::CURRENT_MC_PROTOCOL;
+use crate::registry::{StaticRegistryEntry, static_registries};
+use crate::tag::{RegistryKey, Tag, Taggable, get_tag_ids};
 use crate::{AttributeModifierSlot, Block, Enchantment};
 use crc_fast::CrcAlgorithm::Crc32Iscsi;
 use crc_fast::Digest;
@@ -16,6 +20,7 @@ use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_nbt::tag::NbtTag;
 use pumpkin_util::registry::RegistryEntryList;
 use pumpkin_util::text::TextComponent;
+use pumpkin_util::version::MinecraftVersion;
 use serde::de::SeqAccess;
 use serde::ser::SerializeSeq;
 use serde::{Deserialize, Serialize, de};
@@ -46,12 +51,20 @@ pub trait DataComponentImpl: Send + Sync {
 pub fn read_data(id: DataComponent, data: &NbtTag) -> Option<Box<dyn DataComponentImpl>> {
     match id {
         MaxStackSize => Some(MaxStackSizeImpl::read_data(data)?.to_dyn()),
+        CustomName => Some(CustomNameImpl::read_data(data)?.to_dyn()),
         Enchantments => Some(EnchantmentsImpl::read_data(data)?.to_dyn()),
+        StoredEnchantments => Some(StoredEnchantmentsImpl::read_data(data)?.to_dyn()),
         Damage => Some(DamageImpl::read_data(data)?.to_dyn()),
         Unbreakable => Some(UnbreakableImpl::read_data(data)?.to_dyn()),
         PotionContents => Some(PotionContentsImpl::read_data(data)?.to_dyn()),
         Fireworks => Some(FireworksImpl::read_data(data)?.to_dyn()),
         FireworkExplosion => Some(FireworkExplosionImpl::read_data(data)?.to_dyn()),
+        WritableBookContent => Some(WritableBookContentImpl::read_data(data)?.to_dyn()),
+        WrittenBookContent => Some(WrittenBookContentImpl::read_data(data)?.to_dyn()),
+        Trim => Some(TrimImpl::read_data(data)?.to_dyn()),
+        MapId => Some(MapIdImpl::read_data(data)?.to_dyn()),
+        CanPlaceOn => Some(CanPlaceOnImpl::read_data(data)?.to_dyn()),
+        CanBreak => Some(CanBreakImpl::read_data(data)?.to_dyn()),
         _ => None,
     }
 }
@@ -175,10 +188,26 @@ impl DataComponentImpl for UnbreakableImpl {
 }
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct CustomNameImpl {
-    // TODO make TextComponent const
-    pub name: &'static str,
+    // TODO: Keep the whole text component instead of only its plain text
+    pub name: String,
+}
+impl CustomNameImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let name = data.extract_string().map(str::to_string).or_else(|| {
+            data.extract_compound()?
+                .get_string("text")
+                .map(str::to_string)
+        })?;
+        Some(Self { name })
+    }
 }
 impl DataComponentImpl for CustomNameImpl {
+    fn write_data(&self) -> NbtTag {
+        NbtTag::String(self.name.clone())
+    }
+    fn get_hash(&self) -> i32 {
+        get_str_hash(&self.name) as i32
+    }
     default_impl!(CustomName);
 }
 #[derive(Clone, Hash, PartialEq, Eq)]
@@ -201,17 +230,40 @@ pub struct EnchantmentsImpl {
 }
 impl EnchantmentsImpl {
     fn read_data(data: &NbtTag) -> Option<Self> {
-        let data = &data.extract_compound()?.child_tags;
-        let mut enc = Vec::with_capacity(data.len());
-        for (name, level) in data {
-            enc.push((Enchantment::from_name(name.as_str())?, level.extract_int()?));
-        }
         Some(Self {
-            enchantment: Cow::from(enc),
+            enchantment: read_enchantment_levels(data)?,
         })
     }
 }
 
+fn read_enchantment_levels(data: &NbtTag) -> Option<Cow<'static, [(&'static Enchantment, i32)]>> {
+    let data = &data.extract_compound()?.child_tags;
+    let mut enc = Vec::with_capacity(data.len());
+    for (name, level) in data {
+        enc.push((Enchantment::from_name(name.as_str())?, level.extract_int()?));
+    }
+    Some(Cow::from(enc))
+}
+
+fn write_enchantment_levels(enchantments: &[(&'static Enchantment, i32)]) -> NbtTag {
+    let mut data = NbtCompound::new();
+    for (enc, level) in enchantments {
+        data.put_int(enc.name, *level);
+    }
+    NbtTag::Compound(data)
+}
+
+fn get_enchantment_levels_hash(enchantments: &[(&'static Enchantment, i32)]) -> i32 {
+    let mut digest = Digest::new(Crc32Iscsi);
+    digest.update(&[2u8]);
+    for (enc, level) in enchantments {
+        digest.update(&get_str_hash(enc.name).to_le_bytes());
+        digest.update(&get_i32_hash(*level).to_le_bytes());
+    }
+    digest.update(&[3u8]);
+    digest.finalize() as i32
+}
+
 fn get_str_hash(val: &str) -> u32 {
     let mut digest = Digest::new(Crc32Iscsi);
     digest.update(&[12u8]);
@@ -244,30 +296,237 @@ fn hash() {
     assert_eq!(MaxStackSizeImpl { size: 99 }.get_hash(), -1632321551i32);
 }
 
+#[test]
+fn adventure_mode_predicate_nbt() {
+    let mut single = NbtCompound::new();
+    single.put_string("blocks", "#logs".to_string());
+    let predicate = AdventureModePredicate::read_data(&NbtTag::Compound(single)).unwrap();
+    assert!(predicate.matches(&Block::OAK_LOG));
+    assert!(!predicate.matches(&Block::STONE));
+
+    let mut stone = NbtCompound::new();
+    stone.put_list(
+        "blocks",
+        vec![
+            NbtTag::String("minecraft:stone".to_string()),
+            NbtTag::String("dirt".to_string()),
+        ],
+    );
+    let list = NbtTag::List(vec![NbtTag::Compound(stone)]);
+    let predicate = AdventureModePredicate::read_data(&list).unwrap();
+    assert!(predicate.matches(&Block::DIRT));
+    assert!(!predicate.matches(&Block::OAK_LOG));
+    assert!(AdventureModePredicate::read_data(&predicate.write_data()).unwrap() == predicate);
+
+    // State and NBT conditions aren't supported, so they must not be dropped silently
+    let mut with_state = NbtCompound::new();
+    with_state.put_string("blocks", "minecraft:stone".to_string());
+    with_state.put_component("state", NbtCompound::new());
+    assert!(AdventureModePredicate::read_data(&NbtTag::Compound(with_state)).is_none());
+}
+
 impl DataComponentImpl for EnchantmentsImpl {
     fn write_data(&self) -> NbtTag {
-        let mut data = NbtCompound::new();
-        for (enc, level) in self.enchantment.iter() {
-            data.put_int(enc.name, *level);
-        }
-        NbtTag::Compound(data)
+        write_enchantment_levels(&self.enchantment)
     }
     fn get_hash(&self) -> i32 {
+        get_enchantment_levels_hash(&self.enchantment)
+    }
+    default_impl!(Enchantments);
+}
+/// The blocks a [`BlockPredicate`] matches.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub enum BlockSet {
+    /// A block tag, e.g. `minecraft:logs`.
+    Tag(String),
+    Blocks(Vec<&'static Block>),
+}
+impl BlockSet {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        if let Some(name) = data.extract_string() {
+            return match name.strip_prefix('#') {
+                Some(tag) => {
+                    let tag = if tag.contains(':') {
+                        tag.to_string()
+                    } else {
+                        format!("minecraft:{tag}")
+                    };
+                    get_tag_ids(RegistryKey::Block, &tag)?;
+                    Some(Self::Tag(tag))
+                }
+                None => Some(Self::Blocks(vec![Block::from_name(name)?])),
+            };
+        }
+        data.extract_list()?
+            .iter()
+            .map(|block| Block::from_name(block.extract_string()?))
+            .collect::<Option<_>>()
+            .map(Self::Blocks)
+    }
+    fn names(&self) -> Vec<String> {
+        match self {
+            Self::Tag(tag) => vec![format!("#{tag}")],
+            Self::Blocks(blocks) => blocks
+                .iter()
+                .map(|block| format!("minecraft:{}", block.name))
+                .collect(),
+        }
+    }
+    fn write_data(&self) -> NbtTag {
+        let mut names = self.names();
+        if names.len() == 1 {
+            NbtTag::String(names.remove(0))
+        } else {
+            NbtTag::List(names.into_iter().map(NbtTag::String).collect())
+        }
+    }
+    fn get_hash(&self) -> u32 {
+        let names = self.names();
+        if let [name] = names.as_slice() {
+            return get_str_hash(name);
+        }
+        let mut digest = Digest::new(Crc32Iscsi);
+        digest.update(&[4u8]);
+        for name in &names {
+            digest.update(&get_str_hash(name).to_le_bytes());
+        }
+        digest.update(&[5u8]);
+        digest.finalize() as u32
+    }
+    #[must_use]
+    pub fn contains(&self, block: &Block) -> bool {
+        match self {
+            Self::Tag(tag) => block.is_tagged_with(tag) == Some(true),
+            Self::Blocks(blocks) => blocks.iter().any(|other| other.id == block.id),
+        }
+    }
+}
+/// A block predicate as used by adventure mode items. Only the `blocks` field is supported, so
+/// predicates that also test block states or NBT are not read.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct BlockPredicate {
+    /// The blocks that match, or every block if `None`.
+    pub blocks: Option<BlockSet>,
+}
+impl BlockPredicate {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let compound = data.extract_compound()?;
+        if compound.child_tags.iter().any(|(key, _)| key != "blocks") {
+            return None;
+        }
+        let blocks = match compound.get("blocks") {
+            Some(blocks) => Some(BlockSet::read_data(blocks)?),
+            None => None,
+        };
+        Some(Self { blocks })
+    }
+    fn write_data(&self) -> NbtTag {
+        let mut compound = NbtCompound::new();
+        if let Some(blocks) = &self.blocks {
+            compound.put("blocks", blocks.write_data());
+        }
+        NbtTag::Compound(compound)
+    }
+    fn get_hash(&self) -> u32 {
         let mut digest = Digest::new(Crc32Iscsi);
         digest.update(&[2u8]);
-        for (enc, level) in self.enchantment.iter() {
-            digest.update(&get_str_hash(enc.name).to_le_bytes());
-            digest.update(&get_i32_hash(*level).to_le_bytes());
+        if let Some(blocks) = &self.blocks {
+            digest.update(&get_str_hash("blocks").to_le_bytes());
+            digest.update(&blocks.get_hash().to_le_bytes());
         }
         digest.update(&[3u8]);
+        digest.finalize() as u32
+    }
+    #[must_use]
+    pub fn matches(&self, block: &Block) -> bool {
+        self.blocks
+            .as_ref()
+            .is_none_or(|blocks| blocks.contains(block))
+    }
+}
+/// The blocks an item can break or be placed on in adventure mode: a single predicate or a list.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct AdventureModePredicate {
+    pub predicates: Vec<BlockPredicate>,
+}
+impl AdventureModePredicate {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let predicates = match data.extract_list() {
+            Some(list) => list
+                .iter()
+                .map(BlockPredicate::read_data)
+                .collect::<Option<_>>()?,
+            None => vec![BlockPredicate::read_data(data)?],
+        };
+        Some(Self { predicates })
+    }
+    fn write_data(&self) -> NbtTag {
+        if let [predicate] = self.predicates.as_slice() {
+            predicate.write_data()
+        } else {
+            NbtTag::List(
+                self.predicates
+                    .iter()
+                    .map(BlockPredicate::write_data)
+                    .collect(),
+            )
+        }
+    }
+    fn get_hash(&self) -> i32 {
+        if let [predicate] = self.predicates.as_slice() {
+            return predicate.get_hash() as i32;
+        }
+        let mut digest = Digest::new(Crc32Iscsi);
+        digest.update(&[4u8]);
+        for predicate in &self.predicates {
+            digest.update(&predicate.get_hash().to_le_bytes());
+        }
+        digest.update(&[5u8]);
         digest.finalize() as i32
     }
-    default_impl!(Enchantments);
+    #[must_use]
+    pub fn matches(&self, block: &Block) -> bool {
+        self.predicates
+            .iter()
+            .any(|predicate| predicate.matches(block))
+    }
+}
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct CanPlaceOnImpl {
+    pub predicate: AdventureModePredicate,
+}
+impl CanPlaceOnImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        AdventureModePredicate::read_data(data).map(|predicate| Self { predicate })
+    }
+}
+impl DataComponentImpl for CanPlaceOnImpl {
+    fn write_data(&self) -> NbtTag {
+        self.predicate.write_data()
+    }
+    fn get_hash(&self) -> i32 {
+        self.predicate.get_hash()
+    }
+    default_impl!(CanPlaceOn);
+}
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct CanBreakImpl {
+    pub predicate: AdventureModePredicate,
+}
+impl CanBreakImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        AdventureModePredicate::read_data(data).map(|predicate| Self { predicate })
+    }
+}
+impl DataComponentImpl for CanBreakImpl {
+    fn write_data(&self) -> NbtTag {
+        self.predicate.write_data()
+    }
+    fn get_hash(&self) -> i32 {
+        self.predicate.get_hash()
+    }
+    default_impl!(CanBreak);
 }
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct CanPlaceOnImpl;
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct CanBreakImpl;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Operation {
@@ -616,14 +875,49 @@ pub struct BlocksAttacksImpl;
 impl DataComponentImpl for BlocksAttacksImpl {
     default_impl!(BlocksAttacks);
 }
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct StoredEnchantmentsImpl;
+/// The enchantments an enchanted book applies, as opposed to enchantments on the book itself.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct StoredEnchantmentsImpl {
+    pub enchantment: Cow<'static, [(&'static Enchantment, i32)]>,
+}
+impl StoredEnchantmentsImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        Some(Self {
+            enchantment: read_enchantment_levels(data)?,
+        })
+    }
+}
+impl DataComponentImpl for StoredEnchantmentsImpl {
+    fn write_data(&self) -> NbtTag {
+        write_enchantment_levels(&self.enchantment)
+    }
+    fn get_hash(&self) -> i32 {
+        get_enchantment_levels_hash(&self.enchantment)
+    }
+    default_impl!(StoredEnchantments);
+}
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct DyedColorImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct MapColorImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct MapIdImpl;
+pub struct MapIdImpl {
+    pub id: i32,
+}
+impl MapIdImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        data.extract_int().map(|id| Self { id })
+    }
+}
+impl DataComponentImpl for MapIdImpl {
+    fn write_data(&self) -> NbtTag {
+        NbtTag::Int(self.id)
+    }
+    fn get_hash(&self) -> i32 {
+        get_i32_hash(self.id) as i32
+    }
+    default_impl!(MapId);
+}
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct MapDecorationsImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -787,12 +1081,215 @@ impl DataComponentImpl for PotionContentsImpl {
 pub struct PotionDurationScaleImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct SuspiciousStewEffectsImpl;
+/// Reads a filterable string, which is either a plain string or a `{raw, filtered}` compound.
+fn read_filterable_string(tag: &NbtTag) -> Option<String> {
+    if let Some(string) = tag.extract_string() {
+        return Some(string.to_string());
+    }
+    let compound = tag.extract_compound()?;
+    let raw = compound.get("raw")?;
+    raw.extract_string().map(str::to_string).or_else(|| {
+        raw.extract_compound()?
+            .get_string("text")
+            .map(str::to_string)
+    })
+}
+
+fn get_pages_hash(pages: &[String]) -> u32 {
+    let mut digest = Digest::new(Crc32Iscsi);
+    digest.update(&[4u8]);
+    for page in pages {
+        digest.update(&get_str_hash(page).to_le_bytes());
+    }
+    digest.update(&[5u8]);
+    digest.finalize() as u32
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct WritableBookContentImpl;
+pub struct WritableBookContentImpl {
+    pub pages: Vec<String>,
+}
+impl WritableBookContentImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let pages = data
+            .extract_compound()?
+            .get_list("pages")
+            .map(|pages| pages.iter().filter_map(read_filterable_string).collect())
+            .unwrap_or_default();
+        Some(Self { pages })
+    }
+}
+impl DataComponentImpl for WritableBookContentImpl {
+    fn write_data(&self) -> NbtTag {
+        let mut compound = NbtCompound::new();
+        if !self.pages.is_empty() {
+            compound.put_list(
+                "pages",
+                self.pages
+                    .iter()
+                    .map(|page| NbtTag::String(page.clone()))
+                    .collect(),
+            );
+        }
+        NbtTag::Compound(compound)
+    }
+    fn get_hash(&self) -> i32 {
+        let mut digest = Digest::new(Crc32Iscsi);
+        digest.update(&[2u8]);
+        if !self.pages.is_empty() {
+            digest.update(&get_str_hash("pages").to_le_bytes());
+            digest.update(&get_pages_hash(&self.pages).to_le_bytes());
+        }
+        digest.update(&[3u8]);
+        digest.finalize() as i32
+    }
+    default_impl!(WritableBookContent);
+}
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct WrittenBookContentImpl;
+pub struct WrittenBookContentImpl {
+    pub title: String,
+    pub author: String,
+    /// 0 for the original, 1 for a copy, 2 for a copy of a copy, 3 for tattered.
+    pub generation: i32,
+    pub pages: Vec<String>,
+    pub resolved: bool,
+}
+impl WrittenBookContentImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let compound = data.extract_compound()?;
+        Some(Self {
+            title: compound.get("title").and_then(read_filterable_string)?,
+            author: compound.get_string("author")?.to_string(),
+            generation: compound.get_int("generation").unwrap_or(0),
+            pages: compound
+                .get_list("pages")
+                .map(|pages| pages.iter().filter_map(read_filterable_string).collect())
+                .unwrap_or_default(),
+            resolved: compound.get_bool("resolved").unwrap_or(false),
+        })
+    }
+}
+impl DataComponentImpl for WrittenBookContentImpl {
+    fn write_data(&self) -> NbtTag {
+        let mut compound = NbtCompound::new();
+        compound.put_string("title", self.title.clone());
+        compound.put_string("author", self.author.clone());
+        if self.generation != 0 {
+            compound.put_int("generation", self.generation);
+        }
+        if !self.pages.is_empty() {
+            compound.put_list(
+                "pages",
+                self.pages
+                    .iter()
+                    .map(|page| NbtTag::String(page.clone()))
+                    .collect(),
+            );
+        }
+        if self.resolved {
+            compound.put_bool("resolved", true);
+        }
+        NbtTag::Compound(compound)
+    }
+    fn get_hash(&self) -> i32 {
+        let mut digest = Digest::new(Crc32Iscsi);
+        digest.update(&[2u8]);
+        digest.update(&get_str_hash(&self.title).to_le_bytes());
+        digest.update(&get_str_hash(&self.author).to_le_bytes());
+        digest.update(&get_i32_hash(self.generation).to_le_bytes());
+        digest.update(&get_pages_hash(&self.pages).to_le_bytes());
+        digest.update(&[u8::from(self.resolved)]);
+        digest.update(&[3u8]);
+        digest.finalize() as i32
+    }
+    default_impl!(WrittenBookContent);
+}
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct TrimImpl;
+pub struct TrimImpl {
+    /// Name of the `trim_material` registry entry, e.g. `iron`
+    pub material: &'static str,
+    /// Name of the `trim_pattern` registry entry, e.g. `bolt`
+    pub pattern: &'static str,
+}
+impl TrimImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        let data = data.extract_compound()?;
+        Some(Self {
+            material: trim_registry_entry("trim_material", data.get_string("material")?)?,
+            pattern: trim_registry_entry("trim_pattern", data.get_string("pattern")?)?,
+        })
+    }
+    /// Creates a trim from registry names, with or without the `minecraft:` namespace.
+    #[must_use]
+    pub fn from_names(material: &str, pattern: &str) -> Option<Self> {
+        Some(Self {
+            material: trim_registry_entry("trim_material", material)?,
+            pattern: trim_registry_entry("trim_pattern", pattern)?,
+        })
+    }
+    /// Creates a trim from the network ids a client on `version` uses for the material and the
+    /// pattern.
+    #[must_use]
+    pub fn from_registry_ids(
+        material: usize,
+        pattern: usize,
+        version: MinecraftVersion,
+    ) -> Option<Self> {
+        Some(Self {
+            material: trim_registry("trim_material", version).get(material)?.name,
+            pattern: trim_registry("trim_pattern", version).get(pattern)?.name,
+        })
+    }
+    /// The network ids of the material and the pattern for a client on `version`, or `None` if
+    /// that version doesn't know one of them.
+    #[must_use]
+    pub fn registry_ids(&self, version: MinecraftVersion) -> Option<(usize, usize)> {
+        let id = |registry, name| {
+            trim_registry(registry, version)
+                .iter()
+                .position(|entry: &StaticRegistryEntry| entry.name == name)
+        };
+        Some((
+            id("trim_material", self.material)?,
+            id("trim_pattern", self.pattern)?,
+        ))
+    }
+}
+fn trim_registry(registry: &str, version: MinecraftVersion) -> &'static [StaticRegistryEntry] {
+    static_registries(version)
+        .iter()
+        .find(|reg| reg.registry_id == registry)
+        .map_or(&[], |reg| reg.entries)
+}
+fn trim_registry_entry(registry: &str, name: &str) -> Option<&'static str> {
+    let name = name.strip_prefix("minecraft:").unwrap_or(name);
+    trim_registry(
+        registry,
+        MinecraftVersion::from_protocol(CURRENT_MC_PROTOCOL),
+    )
+    .iter()
+    .find(|entry| entry.name == name)
+    .map(|entry| entry.name)
+}
+impl DataComponentImpl for TrimImpl {
+    fn write_data(&self) -> NbtTag {
+        let mut compound = NbtCompound::new();
+        compound.put_string("material", format!("minecraft:{}", self.material));
+        compound.put_string("pattern", format!("minecraft:{}", self.pattern));
+        NbtTag::Compound(compound)
+    }
+    fn get_hash(&self) -> i32 {
+        let mut digest = Digest::new(Crc32Iscsi);
+        digest.update(&[2u8]);
+        digest.update(&get_str_hash("material").to_le_bytes());
+        digest.update(&get_str_hash(&format!("minecraft:{}", self.material)).to_le_bytes());
+        digest.update(&get_str_hash("pattern").to_le_bytes());
+        digest.update(&get_str_hash(&format!("minecraft:{}", self.pattern)).to_le_bytes());
+        digest.update(&[3u8]);
+        digest.finalize() as i32
+    }
+    default_impl!(Trim);
+}
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct DebugStickStateImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -804,7 +1301,13 @@ pub struct BlockEntityDataImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct InstrumentImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct ProvidesTrimMaterialImpl;
+pub struct ProvidesTrimMaterialImpl {
+    /// Name of the `trim_material` registry entry, e.g. `iron`
+    pub material: &'static str,
+}
+impl DataComponentImpl for ProvidesTrimMaterialImpl {
+    default_impl!(ProvidesTrimMaterial);
+}
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct OminousBottleAmplifierImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -1079,3 +1582,36 @@ pub struct CatCollarImpl;
 pub struct SheepColorImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ShulkerColorImpl;
+
+#[cfg(test)]
+mod test {
+    use pumpkin_util::version::MinecraftVersion;
+
+    use super::TrimImpl;
+
+    #[test]
+    fn trim_registry_ids_round_trip_for_every_version() {
+        let trim = TrimImpl::from_names("minecraft:iron", "bolt").unwrap();
+        for version in [MinecraftVersion::V_1_21_9, MinecraftVersion::V_1_21_11] {
+            let (material, pattern) = trim.registry_ids(version).unwrap();
+            assert_eq!(
+                TrimImpl::from_registry_ids(material, pattern, version),
+                Some(trim.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_trim_has_no_registry_ids() {
+        assert_eq!(TrimImpl::from_names("unobtainium", "bolt"), None);
+        let trim = TrimImpl {
+            material: "unobtainium",
+            pattern: "bolt",
+        };
+        assert_eq!(trim.registry_ids(MinecraftVersion::V_1_21_11), None);
+        assert_eq!(
+            TrimImpl::from_registry_ids(usize::MAX, 0, MinecraftVersion::V_1_21_11),
+            None
+        );
+    }
+}
@@ -14,6 +14,8 @@ pub struct Enchantment {
     pub registry_key: &'static str,
     pub description: &'static str,
     pub anvil_cost: u32,
+    #[doc = r" The lowest enchanting cost this enchantment can be rolled at."]
+    pub min_cost: EnchantmentCost,
     pub supported_items: &'static Tag,
     pub exclusive_set: Option<&'static Tag>,
     pub max_level: i32,
@@ -44,6 +46,17 @@ impl Hash for Enchantment {
         self.id.hash(state);
     }
 }
+#[doc = r" A cost that grows linearly with the enchantment level."]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct EnchantmentCost {
+    pub base: i32,
+    pub per_level_above_first: i32,
+}
+impl EnchantmentCost {
+    pub const fn calculate(&self, level: i32) -> i32 {
+        self.base + self.per_level_above_first * (level - 1)
+    }
+}
 #[derive(Debug, Clone, Hash, PartialEq)]
 pub enum AttributeModifierSlot {
     Any,
@@ -65,6 +78,10 @@ impl Enchantment {
         description: "enchantment.minecraft.aqua_affinity",
         registry_key: "aqua_affinity",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 1i32,
+            per_level_above_first: 0i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_HEAD_ARMOR,
         exclusive_set: None,
         max_level: 1i32,
@@ -76,6 +93,10 @@ impl Enchantment {
         registry_key: "bane_of_arthropods",
         description: "enchantment.minecraft.bane_of_arthropods",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 5i32,
+            per_level_above_first: 8i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_WEAPON,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_DAMAGE),
         max_level: 5i32,
@@ -87,6 +108,10 @@ impl Enchantment {
         description: "enchantment.minecraft.binding_curse",
         registry_key: "binding_curse",
         anvil_cost: 8u32,
+        min_cost: EnchantmentCost {
+            base: 25i32,
+            per_level_above_first: 0i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_EQUIPPABLE,
         exclusive_set: None,
         max_level: 1i32,
@@ -98,6 +123,10 @@ impl Enchantment {
         registry_key: "blast_protection",
         description: "enchantment.minecraft.blast_protection",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 5i32,
+            per_level_above_first: 8i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_ARMOR,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_ARMOR),
         max_level: 4i32,
@@ -109,6 +138,10 @@ impl Enchantment {
         registry_key: "breach",
         description: "enchantment.minecraft.breach",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 15i32,
+            per_level_above_first: 9i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_MACE,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_DAMAGE),
         max_level: 4i32,
@@ -120,6 +153,10 @@ impl Enchantment {
         description: "enchantment.minecraft.channeling",
         registry_key: "channeling",
         anvil_cost: 8u32,
+        min_cost: EnchantmentCost {
+            base: 25i32,
+            per_level_above_first: 0i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_TRIDENT,
         exclusive_set: None,
         max_level: 1i32,
@@ -131,6 +168,10 @@ impl Enchantment {
         registry_key: "density",
         description: "enchantment.minecraft.density",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 5i32,
+            per_level_above_first: 8i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_MACE,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_DAMAGE),
         max_level: 5i32,
@@ -142,6 +183,10 @@ impl Enchantment {
         registry_key: "depth_strider",
         description: "enchantment.minecraft.depth_strider",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 10i32,
+            per_level_above_first: 10i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_FOOT_ARMOR,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_BOOTS),
         max_level: 3i32,
@@ -153,6 +198,10 @@ impl Enchantment {
         description: "enchantment.minecraft.efficiency",
         registry_key: "efficiency",
         anvil_cost: 1u32,
+        min_cost: EnchantmentCost {
+            base: 1i32,
+            per_level_above_first: 10i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_MINING,
         exclusive_set: None,
         max_level: 5i32,
@@ -164,6 +213,10 @@ impl Enchantment {
         description: "enchantment.minecraft.feather_falling",
         registry_key: "feather_falling",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 5i32,
+            per_level_above_first: 6i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_FOOT_ARMOR,
         exclusive_set: None,
         max_level: 4i32,
@@ -175,6 +228,10 @@ impl Enchantment {
         description: "enchantment.minecraft.fire_aspect",
         registry_key: "fire_aspect",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 10i32,
+            per_level_above_first: 20i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_FIRE_ASPECT,
         exclusive_set: None,
         max_level: 2i32,
@@ -186,6 +243,10 @@ impl Enchantment {
         registry_key: "fire_protection",
         description: "enchantment.minecraft.fire_protection",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 10i32,
+            per_level_above_first: 8i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_ARMOR,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_ARMOR),
         max_level: 4i32,
@@ -197,6 +258,10 @@ impl Enchantment {
         description: "enchantment.minecraft.flame",
         registry_key: "flame",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 20i32,
+            per_level_above_first: 0i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_BOW,
         exclusive_set: None,
         max_level: 1i32,
@@ -208,6 +273,10 @@ impl Enchantment {
         registry_key: "fortune",
         description: "enchantment.minecraft.fortune",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 15i32,
+            per_level_above_first: 9i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_MINING_LOOT,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_MINING),
         max_level: 3i32,
@@ -219,6 +288,10 @@ impl Enchantment {
         registry_key: "frost_walker",
         description: "enchantment.minecraft.frost_walker",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 10i32,
+            per_level_above_first: 10i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_FOOT_ARMOR,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_BOOTS),
         max_level: 2i32,
@@ -230,6 +303,10 @@ impl Enchantment {
         registry_key: "impaling",
         description: "enchantment.minecraft.impaling",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 1i32,
+            per_level_above_first: 8i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_TRIDENT,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_DAMAGE),
         max_level: 5i32,
@@ -241,6 +318,10 @@ impl Enchantment {
         registry_key: "infinity",
         description: "enchantment.minecraft.infinity",
         anvil_cost: 8u32,
+        min_cost: EnchantmentCost {
+            base: 20i32,
+            per_level_above_first: 0i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_BOW,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_BOW),
         max_level: 1i32,
@@ -252,6 +333,10 @@ impl Enchantment {
         description: "enchantment.minecraft.knockback",
         registry_key: "knockback",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 5i32,
+            per_level_above_first: 20i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_MELEE_WEAPON,
         exclusive_set: None,
         max_level: 2i32,
@@ -263,6 +348,10 @@ impl Enchantment {
         description: "enchantment.minecraft.looting",
         registry_key: "looting",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 15i32,
+            per_level_above_first: 9i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_MELEE_WEAPON,
         exclusive_set: None,
         max_level: 3i32,
@@ -274,6 +363,10 @@ impl Enchantment {
         description: "enchantment.minecraft.loyalty",
         registry_key: "loyalty",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 12i32,
+            per_level_above_first: 7i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_TRIDENT,
         exclusive_set: None,
         max_level: 3i32,
@@ -285,6 +378,10 @@ impl Enchantment {
         description: "enchantment.minecraft.luck_of_the_sea",
         registry_key: "luck_of_the_sea",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 15i32,
+            per_level_above_first: 9i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_FISHING,
         exclusive_set: None,
         max_level: 3i32,
@@ -296,6 +393,10 @@ impl Enchantment {
         description: "enchantment.minecraft.lunge",
         registry_key: "lunge",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 5i32,
+            per_level_above_first: 8i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_LUNGE,
         exclusive_set: None,
         max_level: 3i32,
@@ -307,6 +408,10 @@ impl Enchantment {
         description: "enchantment.minecraft.lure",
         registry_key: "lure",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 15i32,
+            per_level_above_first: 9i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_FISHING,
         exclusive_set: None,
         max_level: 3i32,
@@ -318,6 +423,10 @@ impl Enchantment {
         description: "enchantment.minecraft.mending",
         registry_key: "mending",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 25i32,
+            per_level_above_first: 25i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_DURABILITY,
         exclusive_set: None,
         max_level: 1i32,
@@ -329,6 +438,10 @@ impl Enchantment {
         registry_key: "multishot",
         description: "enchantment.minecraft.multishot",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 20i32,
+            per_level_above_first: 0i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_CROSSBOW,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_CROSSBOW),
         max_level: 1i32,
@@ -340,6 +453,10 @@ impl Enchantment {
         registry_key: "piercing",
         description: "enchantment.minecraft.piercing",
         anvil_cost: 1u32,
+        min_cost: EnchantmentCost {
+            base: 1i32,
+            per_level_above_first: 10i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_CROSSBOW,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_CROSSBOW),
         max_level: 4i32,
@@ -351,6 +468,10 @@ impl Enchantment {
         description: "enchantment.minecraft.power",
         registry_key: "power",
         anvil_cost: 1u32,
+        min_cost: EnchantmentCost {
+            base: 1i32,
+            per_level_above_first: 10i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_BOW,
         exclusive_set: None,
         max_level: 5i32,
@@ -362,6 +483,10 @@ impl Enchantment {
         registry_key: "projectile_protection",
         description: "enchantment.minecraft.projectile_protection",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 3i32,
+            per_level_above_first: 6i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_ARMOR,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_ARMOR),
         max_level: 4i32,
@@ -373,6 +498,10 @@ impl Enchantment {
         registry_key: "protection",
         description: "enchantment.minecraft.protection",
         anvil_cost: 1u32,
+        min_cost: EnchantmentCost {
+            base: 1i32,
+            per_level_above_first: 11i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_ARMOR,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_ARMOR),
         max_level: 4i32,
@@ -384,6 +513,10 @@ impl Enchantment {
         description: "enchantment.minecraft.punch",
         registry_key: "punch",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 12i32,
+            per_level_above_first: 20i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_BOW,
         exclusive_set: None,
         max_level: 2i32,
@@ -395,6 +528,10 @@ impl Enchantment {
         description: "enchantment.minecraft.quick_charge",
         registry_key: "quick_charge",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 12i32,
+            per_level_above_first: 20i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_CROSSBOW,
         exclusive_set: None,
         max_level: 3i32,
@@ -409,6 +546,10 @@ impl Enchantment {
         description: "enchantment.minecraft.respiration",
         registry_key: "respiration",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 10i32,
+            per_level_above_first: 10i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_HEAD_ARMOR,
         exclusive_set: None,
         max_level: 3i32,
@@ -420,6 +561,10 @@ impl Enchantment {
         registry_key: "riptide",
         description: "enchantment.minecraft.riptide",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 17i32,
+            per_level_above_first: 7i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_TRIDENT,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_RIPTIDE),
         max_level: 3i32,
@@ -431,6 +576,10 @@ impl Enchantment {
         registry_key: "sharpness",
         description: "enchantment.minecraft.sharpness",
         anvil_cost: 1u32,
+        min_cost: EnchantmentCost {
+            base: 1i32,
+            per_level_above_first: 11i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_SHARP_WEAPON,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_DAMAGE),
         max_level: 5i32,
@@ -442,6 +591,10 @@ impl Enchantment {
         registry_key: "silk_touch",
         description: "enchantment.minecraft.silk_touch",
         anvil_cost: 8u32,
+        min_cost: EnchantmentCost {
+            base: 15i32,
+            per_level_above_first: 0i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_MINING_LOOT,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_MINING),
         max_level: 1i32,
@@ -453,6 +606,10 @@ impl Enchantment {
         registry_key: "smite",
         description: "enchantment.minecraft.smite",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 5i32,
+            per_level_above_first: 8i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_WEAPON,
         exclusive_set: Some(&EnchantmentTag::MINECRAFT_EXCLUSIVE_SET_DAMAGE),
         max_level: 5i32,
@@ -464,6 +621,10 @@ impl Enchantment {
         description: "enchantment.minecraft.soul_speed",
         registry_key: "soul_speed",
         anvil_cost: 8u32,
+        min_cost: EnchantmentCost {
+            base: 10i32,
+            per_level_above_first: 10i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_FOOT_ARMOR,
         exclusive_set: None,
         max_level: 3i32,
@@ -475,6 +636,10 @@ impl Enchantment {
         description: "enchantment.minecraft.sweeping_edge",
         registry_key: "sweeping_edge",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 5i32,
+            per_level_above_first: 9i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_SWEEPING,
         exclusive_set: None,
         max_level: 3i32,
@@ -486,6 +651,10 @@ impl Enchantment {
         description: "enchantment.minecraft.swift_sneak",
         registry_key: "swift_sneak",
         anvil_cost: 8u32,
+        min_cost: EnchantmentCost {
+            base: 25i32,
+            per_level_above_first: 25i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_LEG_ARMOR,
         exclusive_set: None,
         max_level: 3i32,
@@ -497,6 +666,10 @@ impl Enchantment {
         description: "enchantment.minecraft.thorns",
         registry_key: "thorns",
         anvil_cost: 8u32,
+        min_cost: EnchantmentCost {
+            base: 10i32,
+            per_level_above_first: 20i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_ARMOR,
         exclusive_set: None,
         max_level: 3i32,
@@ -508,6 +681,10 @@ impl Enchantment {
         description: "enchantment.minecraft.unbreaking",
         registry_key: "unbreaking",
         anvil_cost: 2u32,
+        min_cost: EnchantmentCost {
+            base: 5i32,
+            per_level_above_first: 8i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_DURABILITY,
         exclusive_set: None,
         max_level: 3i32,
@@ -519,6 +696,10 @@ impl Enchantment {
         description: "enchantment.minecraft.vanishing_curse",
         registry_key: "vanishing_curse",
         anvil_cost: 8u32,
+        min_cost: EnchantmentCost {
+            base: 25i32,
+            per_level_above_first: 0i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_VANISHING,
         exclusive_set: None,
         max_level: 1i32,
@@ -530,6 +711,10 @@ impl Enchantment {
         description: "enchantment.minecraft.wind_burst",
         registry_key: "wind_burst",
         anvil_cost: 4u32,
+        min_cost: EnchantmentCost {
+            base: 15i32,
+            per_level_above_first: 9i32,
+        },
         supported_items: &ItemTag::MINECRAFT_ENCHANTABLE_MACE,
         exclusive_set: None,
         max_level: 3i32,
@@ -631,6 +816,9 @@ impl Enchantment {
             _ => None,
         }
     }
+    pub const fn get_min_cost(&self, level: i32) -> i32 {
+        self.min_cost.calculate(level)
+    }
     pub fn can_enchant(&self, item: &'static Item) -> bool {
         self.supported_items.1.contains(&item.id)
     }
@@ -478,7 +478,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -550,7 +556,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -578,7 +590,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -606,7 +624,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: Some(1i32),
+                                },
                                 conditions: None,
                             },
                             LootFunction {
@@ -662,7 +686,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 1f32,
+                                    max: 2f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -757,7 +787,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -854,7 +890,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -882,7 +924,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -995,7 +1043,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -1017,7 +1071,13 @@ impl EntityType {
                                 conditions: Some(&[LootCondition::AnyOf]),
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -1143,7 +1203,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -1195,7 +1261,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -1227,7 +1299,13 @@ impl EntityType {
                                 conditions: Some(&[LootCondition::AnyOf]),
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -1304,7 +1382,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -1407,7 +1491,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                         LootFunction {
@@ -1462,7 +1552,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -1534,7 +1630,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -1556,7 +1658,11 @@ impl EntityType {
                     bonus_rolls: 0f32,
                     conditions: Some(&[
                         LootCondition::KilledByPlayer,
-                        LootCondition::RandomChanceWithEnchantedBonus,
+                        LootCondition::RandomChanceWithEnchantedBonus {
+                            unenchanted_chance: 0.11f32,
+                            base: 0.13f32,
+                            per_level_above_first: 0.02f32,
+                        },
                     ]),
                     functions: None,
                 },
@@ -1623,7 +1729,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -1642,7 +1754,13 @@ impl EntityType {
                             conditions: None,
                             functions: Some(&[
                                 LootFunction {
-                                    content: LootFunctionTypes::EnchantedCountIncrease,
+                                    content: LootFunctionTypes::EnchantedCountIncrease {
+                                        count: LootFunctionNumberProvider::Uniform {
+                                            min: 0f32,
+                                            max: 1f32,
+                                        },
+                                        limit: None,
+                                    },
                                     conditions: None,
                                 },
                                 LootFunction {
@@ -1657,7 +1775,13 @@ impl EntityType {
                             }),
                             conditions: None,
                             functions: Some(&[LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             }]),
                         },
@@ -1698,7 +1822,11 @@ impl EntityType {
                     bonus_rolls: 0f32,
                     conditions: Some(&[
                         LootCondition::KilledByPlayer,
-                        LootCondition::RandomChanceWithEnchantedBonus,
+                        LootCondition::RandomChanceWithEnchantedBonus {
+                            unenchanted_chance: 0.025f32,
+                            base: 0.035f32,
+                            per_level_above_first: 0.01f32,
+                        },
                     ]),
                     functions: None,
                 },
@@ -1828,7 +1956,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -1917,7 +2051,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -2198,7 +2338,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -2226,7 +2372,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -2344,7 +2496,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -2420,7 +2578,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -2439,7 +2603,13 @@ impl EntityType {
                             conditions: None,
                             functions: Some(&[
                                 LootFunction {
-                                    content: LootFunctionTypes::EnchantedCountIncrease,
+                                    content: LootFunctionTypes::EnchantedCountIncrease {
+                                        count: LootFunctionNumberProvider::Uniform {
+                                            min: 0f32,
+                                            max: 1f32,
+                                        },
+                                        limit: None,
+                                    },
                                     conditions: None,
                                 },
                                 LootFunction {
@@ -2454,7 +2624,13 @@ impl EntityType {
                             }),
                             conditions: None,
                             functions: Some(&[LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             }]),
                         },
@@ -2482,7 +2658,11 @@ impl EntityType {
                     bonus_rolls: 0f32,
                     conditions: Some(&[
                         LootCondition::KilledByPlayer,
-                        LootCondition::RandomChanceWithEnchantedBonus,
+                        LootCondition::RandomChanceWithEnchantedBonus {
+                            unenchanted_chance: 0.025f32,
+                            base: 0.035f32,
+                            per_level_above_first: 0.01f32,
+                        },
                     ]),
                     functions: None,
                 },
@@ -2557,7 +2737,13 @@ impl EntityType {
                                 conditions: Some(&[LootCondition::AnyOf]),
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -2585,7 +2771,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -2657,7 +2849,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -2709,7 +2907,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -2737,7 +2941,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -2778,7 +2988,11 @@ impl EntityType {
                     bonus_rolls: 0f32,
                     conditions: Some(&[
                         LootCondition::KilledByPlayer,
-                        LootCondition::RandomChanceWithEnchantedBonus,
+                        LootCondition::RandomChanceWithEnchantedBonus {
+                            unenchanted_chance: 0.025f32,
+                            base: 0.035f32,
+                            per_level_above_first: 0.01f32,
+                        },
                     ]),
                     functions: None,
                 },
@@ -3097,7 +3311,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -3172,7 +3392,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -3368,7 +3594,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -3400,7 +3632,13 @@ impl EntityType {
                                 conditions: Some(&[LootCondition::AnyOf]),
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -3452,7 +3690,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -3497,7 +3741,11 @@ impl EntityType {
                 bonus_rolls: 0f32,
                 conditions: Some(&[
                     LootCondition::KilledByPlayer,
-                    LootCondition::RandomChanceWithEnchantedBonus,
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        unenchanted_chance: 0.05f32,
+                        base: 0.060000002f32,
+                        per_level_above_first: 0.01f32,
+                    },
                 ]),
                 functions: None,
             }]),
@@ -3729,7 +3977,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -3757,7 +4011,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -3785,7 +4045,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: Some(1i32),
+                                },
                                 conditions: None,
                             },
                             LootFunction {
@@ -3841,7 +4107,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -3892,7 +4164,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -3947,7 +4225,13 @@ impl EntityType {
                             conditions: Some(&[LootCondition::AnyOf]),
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -4123,7 +4407,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -4149,7 +4439,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -4259,7 +4555,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -4288,7 +4590,13 @@ impl EntityType {
                                 conditions: Some(&[LootCondition::AnyOf]),
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -4310,7 +4618,11 @@ impl EntityType {
                     bonus_rolls: 0f32,
                     conditions: Some(&[
                         LootCondition::KilledByPlayer,
-                        LootCondition::RandomChanceWithEnchantedBonus,
+                        LootCondition::RandomChanceWithEnchantedBonus {
+                            unenchanted_chance: 0.1f32,
+                            base: 0.13f32,
+                            per_level_above_first: 0.03f32,
+                        },
                     ]),
                     functions: None,
                 },
@@ -4457,7 +4769,13 @@ impl EntityType {
                                 conditions: Some(&[LootCondition::AnyOf]),
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -4595,7 +4913,11 @@ impl EntityType {
                 }],
                 rolls: LootNumberProviderTypes::Constant(1f32),
                 bonus_rolls: 0f32,
-                conditions: Some(&[LootCondition::RandomChanceWithEnchantedBonus]),
+                conditions: Some(&[LootCondition::RandomChanceWithEnchantedBonus {
+                    unenchanted_chance: 0.5f32,
+                    base: 0.5625f32,
+                    per_level_above_first: 0.0625f32,
+                }]),
                 functions: None,
             }]),
         }),
@@ -4684,7 +5006,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -4712,7 +5040,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -4764,7 +5098,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -4816,7 +5156,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -5031,7 +5377,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -5059,7 +5411,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -5171,7 +5529,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -5223,7 +5587,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -5251,7 +5621,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -5279,7 +5655,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: Some(1i32),
+                                },
                                 conditions: None,
                             },
                             LootFunction {
@@ -5335,7 +5717,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -5470,7 +5858,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -5599,7 +5993,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -5712,7 +6112,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -5845,7 +6251,13 @@ impl EntityType {
                                     conditions: None,
                                 },
                                 LootFunction {
-                                    content: LootFunctionTypes::EnchantedCountIncrease,
+                                    content: LootFunctionTypes::EnchantedCountIncrease {
+                                        count: LootFunctionNumberProvider::Uniform {
+                                            min: 0f32,
+                                            max: 1f32,
+                                        },
+                                        limit: None,
+                                    },
                                     conditions: None,
                                 },
                             ]),
@@ -5867,7 +6279,13 @@ impl EntityType {
                                     conditions: None,
                                 },
                                 LootFunction {
-                                    content: LootFunctionTypes::EnchantedCountIncrease,
+                                    content: LootFunctionTypes::EnchantedCountIncrease {
+                                        count: LootFunctionNumberProvider::Uniform {
+                                            min: 0f32,
+                                            max: 1f32,
+                                        },
+                                        limit: None,
+                                    },
                                     conditions: None,
                                 },
                             ]),
@@ -5889,7 +6307,13 @@ impl EntityType {
                                     conditions: None,
                                 },
                                 LootFunction {
-                                    content: LootFunctionTypes::EnchantedCountIncrease,
+                                    content: LootFunctionTypes::EnchantedCountIncrease {
+                                        count: LootFunctionNumberProvider::Uniform {
+                                            min: 0f32,
+                                            max: 1f32,
+                                        },
+                                        limit: None,
+                                    },
                                     conditions: None,
                                 },
                             ]),
@@ -5911,7 +6335,13 @@ impl EntityType {
                                     conditions: None,
                                 },
                                 LootFunction {
-                                    content: LootFunctionTypes::EnchantedCountIncrease,
+                                    content: LootFunctionTypes::EnchantedCountIncrease {
+                                        count: LootFunctionNumberProvider::Uniform {
+                                            min: 0f32,
+                                            max: 1f32,
+                                        },
+                                        limit: None,
+                                    },
                                     conditions: None,
                                 },
                             ]),
@@ -5933,7 +6363,13 @@ impl EntityType {
                                     conditions: None,
                                 },
                                 LootFunction {
-                                    content: LootFunctionTypes::EnchantedCountIncrease,
+                                    content: LootFunctionTypes::EnchantedCountIncrease {
+                                        count: LootFunctionNumberProvider::Uniform {
+                                            min: 0f32,
+                                            max: 1f32,
+                                        },
+                                        limit: None,
+                                    },
                                     conditions: None,
                                 },
                             ]),
@@ -5955,7 +6391,13 @@ impl EntityType {
                                     conditions: None,
                                 },
                                 LootFunction {
-                                    content: LootFunctionTypes::EnchantedCountIncrease,
+                                    content: LootFunctionTypes::EnchantedCountIncrease {
+                                        count: LootFunctionNumberProvider::Uniform {
+                                            min: 0f32,
+                                            max: 1f32,
+                                        },
+                                        limit: None,
+                                    },
                                     conditions: None,
                                 },
                             ]),
@@ -5989,7 +6431,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -6066,7 +6514,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -6094,7 +6548,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -6116,7 +6576,11 @@ impl EntityType {
                     bonus_rolls: 0f32,
                     conditions: Some(&[
                         LootCondition::KilledByPlayer,
-                        LootCondition::RandomChanceWithEnchantedBonus,
+                        LootCondition::RandomChanceWithEnchantedBonus {
+                            unenchanted_chance: 0.025f32,
+                            base: 0.035f32,
+                            per_level_above_first: 0.01f32,
+                        },
                     ]),
                     functions: None,
                 },
@@ -6206,7 +6670,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -6258,7 +6728,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -6286,7 +6762,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -6327,7 +6809,11 @@ impl EntityType {
                     bonus_rolls: 0f32,
                     conditions: Some(&[
                         LootCondition::KilledByPlayer,
-                        LootCondition::RandomChanceWithEnchantedBonus,
+                        LootCondition::RandomChanceWithEnchantedBonus {
+                            unenchanted_chance: 0.025f32,
+                            base: 0.035f32,
+                            per_level_above_first: 0.01f32,
+                        },
                     ]),
                     functions: None,
                 },
@@ -6389,7 +6875,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -6440,7 +6932,13 @@ impl EntityType {
                             conditions: None,
                         },
                         LootFunction {
-                            content: LootFunctionTypes::EnchantedCountIncrease,
+                            content: LootFunctionTypes::EnchantedCountIncrease {
+                                count: LootFunctionNumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: None,
+                            },
                             conditions: None,
                         },
                     ]),
@@ -6492,7 +6990,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -6533,7 +7037,11 @@ impl EntityType {
                     bonus_rolls: 0f32,
                     conditions: Some(&[
                         LootCondition::KilledByPlayer,
-                        LootCondition::RandomChanceWithEnchantedBonus,
+                        LootCondition::RandomChanceWithEnchantedBonus {
+                            unenchanted_chance: 0.025f32,
+                            base: 0.035f32,
+                            per_level_above_first: 0.01f32,
+                        },
                     ]),
                     functions: None,
                 },
@@ -6580,7 +7088,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -6608,7 +7122,13 @@ impl EntityType {
                                 conditions: None,
                             },
                             LootFunction {
-                                content: LootFunctionTypes::EnchantedCountIncrease,
+                                content: LootFunctionTypes::EnchantedCountIncrease {
+                                    count: LootFunctionNumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: None,
+                                },
                                 conditions: None,
                             },
                         ]),
@@ -6630,7 +7150,11 @@ impl EntityType {
                     bonus_rolls: 0f32,
                     conditions: Some(&[
                         LootCondition::KilledByPlayer,
-                        LootCondition::RandomChanceWithEnchantedBonus,
+                        LootCondition::RandomChanceWithEnchantedBonus {
+                            unenchanted_chance: 0.025f32,
+                            base: 0.035f32,
+                            per_level_above_first: 0.01f32,
+                        },
                     ]),
                     functions: None,
                 },
@@ -489,6 +489,12 @@ impl Item {
         registry_key: "amethyst_shard",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl {
+                    material: "amethyst",
+                },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -6470,6 +6476,10 @@ impl Item {
         registry_key: "copper_ingot",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl { material: "copper" },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -9162,6 +9172,12 @@ impl Item {
         registry_key: "diamond",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl {
+                    material: "diamond",
+                },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -10362,6 +10378,12 @@ impl Item {
         registry_key: "emerald",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl {
+                    material: "emerald",
+                },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -12047,6 +12069,10 @@ impl Item {
         registry_key: "gold_ingot",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl { material: "gold" },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -14674,6 +14700,10 @@ impl Item {
         registry_key: "iron_ingot",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl { material: "iron" },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -15576,6 +15606,10 @@ impl Item {
         registry_key: "lapis_lazuli",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl { material: "lapis" },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -19800,6 +19834,12 @@ impl Item {
         registry_key: "netherite_ingot",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl {
+                    material: "netherite",
+                },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -24115,6 +24155,10 @@ impl Item {
         registry_key: "quartz",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl { material: "quartz" },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -25092,6 +25136,12 @@ impl Item {
         registry_key: "redstone",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl {
+                    material: "redstone",
+                },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -25263,6 +25313,10 @@ impl Item {
         registry_key: "resin_brick",
         components: &[
             (MaxStackSize, &MaxStackSizeImpl { size: 64 }),
+            (
+                ProvidesTrimMaterial,
+                &ProvidesTrimMaterialImpl { material: "resin" },
+            ),
             (
                 ItemName,
                 &ItemNameImpl {
@@ -86,6 +86,51 @@ impl CookingRecipeKind {
     }
 }
 #[derive(Clone, Debug)]
+pub struct StonecuttingRecipe {
+    pub ingredient: RecipeIngredientTypes,
+    pub result: RecipeResultStruct,
+}
+#[derive(Clone, Debug)]
+pub enum SmithingRecipeTypes {
+    SmithingTransform {
+        template: RecipeIngredientTypes,
+        base: RecipeIngredientTypes,
+        addition: RecipeIngredientTypes,
+        result: RecipeResultStruct,
+    },
+    SmithingTrim {
+        template: RecipeIngredientTypes,
+        base: RecipeIngredientTypes,
+        addition: RecipeIngredientTypes,
+        pattern: &'static str,
+    },
+}
+impl SmithingRecipeTypes {
+    pub fn template(&self) -> &RecipeIngredientTypes {
+        match self {
+            SmithingRecipeTypes::SmithingTransform { template, .. }
+            | SmithingRecipeTypes::SmithingTrim { template, .. } => template,
+        }
+    }
+    pub fn base(&self) -> &RecipeIngredientTypes {
+        match self {
+            SmithingRecipeTypes::SmithingTransform { base, .. }
+            | SmithingRecipeTypes::SmithingTrim { base, .. } => base,
+        }
+    }
+    pub fn addition(&self) -> &RecipeIngredientTypes {
+        match self {
+            SmithingRecipeTypes::SmithingTransform { addition, .. }
+            | SmithingRecipeTypes::SmithingTrim { addition, .. } => addition,
+        }
+    }
+    pub fn matches(&self, template: &Item, base: &Item, addition: &Item) -> bool {
+        self.template().match_item(template)
+            && self.base().match_item(base)
+            && self.addition().match_item(addition)
+    }
+}
+#[derive(Clone, Debug)]
 pub struct RecipeResultStruct {
     pub id: &'static str,
     pub count: u8,
@@ -16696,6 +16741,2021 @@ pub static RECIPES_COOKING: &[CookingRecipeType] = &[
         },
     }),
 ];
+pub static RECIPES_STONECUTTING: &[StonecuttingRecipe] = &[
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:andesite"),
+        result: RecipeResultStruct {
+            id: "minecraft:andesite_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:andesite"),
+        result: RecipeResultStruct {
+            id: "minecraft:andesite_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:andesite"),
+        result: RecipeResultStruct {
+            id: "minecraft:andesite_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:blackstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:blackstone_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:blackstone_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:copper_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_copper",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_deepslate",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:nether_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_nether_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_polished_blackstone",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_polished_blackstone",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:quartz_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_quartz_block",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:red_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_red_sandstone",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:resin_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_resin_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_sandstone",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_stone_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_stone_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_tuff_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_tuff_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_tuff_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:chiseled_tuff",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:cobbled_deepslate_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:cobbled_deepslate_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:cobbled_deepslate_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobblestone"),
+        result: RecipeResultStruct {
+            id: "minecraft:cobblestone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobblestone"),
+        result: RecipeResultStruct {
+            id: "minecraft:cobblestone_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobblestone"),
+        result: RecipeResultStruct {
+            id: "minecraft:cobblestone_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:copper_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:copper_grate",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:copper_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:copper_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_copper_slab",
+            count: 8u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_copper_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:copper_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_copper_stairs",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_copper_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:red_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_red_sandstone",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cut_red_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_red_sandstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:red_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_red_sandstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_sandstone",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cut_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_sandstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:cut_sandstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:dark_prismarine"),
+        result: RecipeResultStruct {
+            id: "minecraft:dark_prismarine_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:dark_prismarine"),
+        result: RecipeResultStruct {
+            id: "minecraft:dark_prismarine_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:deepslate_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:deepslate_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:deepslate_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:deepslate_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:deepslate_tiles"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:deepslate_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:deepslate_tiles"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:deepslate_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:deepslate_tiles"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tile_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tiles",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:deepslate_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tiles",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:deepslate_tiles",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:diorite"),
+        result: RecipeResultStruct {
+            id: "minecraft:diorite_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:diorite"),
+        result: RecipeResultStruct {
+            id: "minecraft:diorite_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:diorite"),
+        result: RecipeResultStruct {
+            id: "minecraft:diorite_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:end_stone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:end_stone_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:end_stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:end_stone_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:end_stone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:end_stone_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:end_stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:end_stone_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:end_stone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:end_stone_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:end_stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:end_stone_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:end_stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:end_stone_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:exposed_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:exposed_chiseled_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:exposed_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:exposed_chiseled_copper",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:exposed_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:exposed_copper_grate",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:exposed_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:exposed_cut_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:exposed_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:exposed_cut_copper_slab",
+            count: 8u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:exposed_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:exposed_cut_copper_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:exposed_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:exposed_cut_copper_stairs",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:exposed_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:exposed_cut_copper_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:granite"),
+        result: RecipeResultStruct {
+            id: "minecraft:granite_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:granite"),
+        result: RecipeResultStruct {
+            id: "minecraft:granite_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:granite"),
+        result: RecipeResultStruct {
+            id: "minecraft:granite_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:mossy_cobblestone"),
+        result: RecipeResultStruct {
+            id: "minecraft:mossy_cobblestone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:mossy_cobblestone"),
+        result: RecipeResultStruct {
+            id: "minecraft:mossy_cobblestone_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:mossy_cobblestone"),
+        result: RecipeResultStruct {
+            id: "minecraft:mossy_cobblestone_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:mossy_stone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:mossy_stone_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:mossy_stone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:mossy_stone_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:mossy_stone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:mossy_stone_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:mud_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:mud_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:mud_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:mud_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:mud_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:mud_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:nether_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:nether_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:nether_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:nether_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:nether_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:nether_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:oxidized_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:oxidized_chiseled_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:oxidized_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:oxidized_chiseled_copper",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:oxidized_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:oxidized_copper_grate",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:oxidized_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:oxidized_cut_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:oxidized_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:oxidized_cut_copper_slab",
+            count: 8u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:oxidized_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:oxidized_cut_copper_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:oxidized_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:oxidized_cut_copper_stairs",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:oxidized_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:oxidized_cut_copper_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:andesite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_andesite",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:andesite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_andesite_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_andesite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_andesite_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:andesite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_andesite_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_andesite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_andesite_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:basalt"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_basalt",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_blackstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_blackstone_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_deepslate",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_deepslate_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_deepslate_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_deepslate_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_deepslate_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:cobbled_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_deepslate_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_deepslate"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_deepslate_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:diorite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_diorite",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:diorite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_diorite_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_diorite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_diorite_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:diorite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_diorite_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_diorite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_diorite_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:granite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_granite",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:granite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_granite_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_granite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_granite_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:granite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_granite_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_granite"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_granite_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_tuff",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_tuff_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_tuff_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_tuff_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_tuff_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_tuff_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:polished_tuff_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:prismarine_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:prismarine_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:prismarine_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:prismarine_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:prismarine"),
+        result: RecipeResultStruct {
+            id: "minecraft:prismarine_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:prismarine"),
+        result: RecipeResultStruct {
+            id: "minecraft:prismarine_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:prismarine"),
+        result: RecipeResultStruct {
+            id: "minecraft:prismarine_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:purpur_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:purpur_pillar",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:purpur_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:purpur_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:purpur_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:purpur_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:quartz_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:quartz_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:quartz_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:quartz_pillar",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:quartz_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:quartz_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:quartz_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:quartz_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:red_nether_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:red_nether_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:red_nether_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:red_nether_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:red_nether_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:red_nether_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:red_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:red_sandstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:red_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:red_sandstone_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:red_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:red_sandstone_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:resin_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:resin_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:resin_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:resin_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:resin_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:resin_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:sandstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:sandstone_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:sandstone_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:smooth_quartz"),
+        result: RecipeResultStruct {
+            id: "minecraft:smooth_quartz_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:smooth_quartz"),
+        result: RecipeResultStruct {
+            id: "minecraft:smooth_quartz_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:smooth_red_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:smooth_red_sandstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:smooth_red_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:smooth_red_sandstone_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:smooth_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:smooth_sandstone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:smooth_sandstone"),
+        result: RecipeResultStruct {
+            id: "minecraft:smooth_sandstone_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:smooth_stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:smooth_stone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:stone_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:stone_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:stone_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:stone_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:stone_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:stone_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:stone_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:stone_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:stone"),
+        result: RecipeResultStruct {
+            id: "minecraft:stone_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_brick_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_brick_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff_bricks"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_brick_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:polished_tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_bricks",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:tuff"),
+        result: RecipeResultStruct {
+            id: "minecraft:tuff_wall",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_copper_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_chiseled_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_chiseled_copper",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_copper_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_copper_grate",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_copper_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_cut_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_copper_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_cut_copper_slab",
+            count: 8u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_cut_copper_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_copper_block"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_cut_copper_stairs",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_cut_copper_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_exposed_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_exposed_chiseled_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_exposed_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_exposed_chiseled_copper",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_exposed_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_exposed_copper_grate",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_exposed_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_exposed_cut_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_exposed_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_exposed_cut_copper_slab",
+            count: 8u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_exposed_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_exposed_cut_copper_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_exposed_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_exposed_cut_copper_stairs",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_exposed_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_exposed_cut_copper_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_oxidized_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_oxidized_chiseled_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_oxidized_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_oxidized_chiseled_copper",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_oxidized_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_oxidized_copper_grate",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_oxidized_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_oxidized_cut_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_oxidized_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_oxidized_cut_copper_slab",
+            count: 8u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_oxidized_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_oxidized_cut_copper_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_oxidized_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_oxidized_cut_copper_stairs",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_oxidized_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_oxidized_cut_copper_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_weathered_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_weathered_chiseled_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_weathered_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_weathered_chiseled_copper",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_weathered_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_weathered_copper_grate",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_weathered_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_weathered_cut_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_weathered_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_weathered_cut_copper_slab",
+            count: 8u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_weathered_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_weathered_cut_copper_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_weathered_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_weathered_cut_copper_stairs",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:waxed_weathered_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:waxed_weathered_cut_copper_stairs",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:weathered_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:weathered_chiseled_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:weathered_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:weathered_chiseled_copper",
+            count: 1u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:weathered_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:weathered_copper_grate",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:weathered_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:weathered_cut_copper",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:weathered_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:weathered_cut_copper_slab",
+            count: 8u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:weathered_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:weathered_cut_copper_slab",
+            count: 2u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:weathered_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:weathered_cut_copper_stairs",
+            count: 4u8,
+        },
+    },
+    StonecuttingRecipe {
+        ingredient: RecipeIngredientTypes::Simple("minecraft:weathered_cut_copper"),
+        result: RecipeResultStruct {
+            id: "minecraft:weathered_cut_copper_stairs",
+            count: 1u8,
+        },
+    },
+];
+pub static RECIPES_SMITHING: &[SmithingRecipeTypes] = &[
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:bolt_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:bolt",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:coast_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:coast",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:dune_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:dune",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:eye_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:eye",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:flow_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:flow",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:host_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:host",
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_axe"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_axe",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_boots"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_boots",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_chestplate"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_chestplate",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_helmet"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_helmet",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_hoe"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_hoe",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_horse_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_horse_armor",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_leggings"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_leggings",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_nautilus_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_nautilus_armor",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_pickaxe"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_pickaxe",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_shovel"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_shovel",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_spear"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_spear",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTransform {
+        template: RecipeIngredientTypes::Simple("minecraft:netherite_upgrade_smithing_template"),
+        base: RecipeIngredientTypes::Simple("minecraft:diamond_sword"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:netherite_tool_materials"),
+        result: RecipeResultStruct {
+            id: "minecraft:netherite_sword",
+            count: 1u8,
+        },
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:raiser_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:raiser",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:rib_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:rib",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:sentry_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:sentry",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:shaper_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:shaper",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:silence_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:silence",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:snout_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:snout",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:spire_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:spire",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:tide_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:tide",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:vex_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:vex",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:ward_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:ward",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:wayfinder_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:wayfinder",
+    },
+    SmithingRecipeTypes::SmithingTrim {
+        template: RecipeIngredientTypes::Simple("minecraft:wild_armor_trim_smithing_template"),
+        base: RecipeIngredientTypes::Tagged("#minecraft:trimmable_armor"),
+        addition: RecipeIngredientTypes::Tagged("#minecraft:trim_materials"),
+        pattern: "minecraft:wild",
+    },
+];
+#[doc = r" Returns the stonecutting recipes for the given input, in the same order the client lists them."]
+pub fn get_stonecutting_recipes(
+    ingredient: &Item,
+) -> impl Iterator<Item = &'static StonecuttingRecipe> {
+    RECIPES_STONECUTTING
+        .iter()
+        .filter(move |recipe| recipe.ingredient.match_item(ingredient))
+}
+pub fn get_smithing_recipe(
+    template: &Item,
+    base: &Item,
+    addition: &Item,
+) -> Option<&'static SmithingRecipeTypes> {
+    RECIPES_SMITHING
+        .iter()
+        .find(|recipe| recipe.matches(template, base, addition))
+}
 pub fn get_cooking_recipe_with_ingredient(
     ingredient: &Item,
     recipe_type: CookingRecipeKind,
@@ -19,14 +19,17 @@ pub struct Registry {
 }
 pub static REGISTRIES_1_21_9 : & [StaticRegistry] = & [StaticRegistry { registry_id : "worldgen/biome" , entries : & [StaticRegistryEntry { name : "badlands" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\x04\0\rfoliage_color\0\0\0\0\0\x9E\x81M\x04\0\x0Bgrass_color\0\0\0\0\0\x90\x81M\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0\"minecraft:music.overworld.badlands\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "bamboo_jungle" , data : b"\n\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0'minecraft:music.overworld.bamboo_jungle\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0w\xA8\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xEEffffff\0" } , StaticRegistryEntry { name : "basalt_deltas" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\n\0\x0Fadditions_sound\x08\0\x05sound\0)minecraft:ambient.basalt_deltas.additions\x06\0\x0Btick_chance?\x86\xBB\x98\xC7\xE2\x82A\0\x08\0\rambient_sound\0$minecraft:ambient.basalt_deltas.loop\x04\0\tfog_color\0\0\0\0\0h_p\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0$minecraft:ambient.basalt_deltas.mood\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0$minecraft:music.nether.basalt_deltas\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\n\0\x08particle\n\0\x07options\x08\0\x04type\0\x13minecraft:white_ash\0\x06\0\x0Bprobability?\xBE;]_g\xD5\xB8\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "beach" , data : b"\n\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0x\xA7\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "birch_forest" , data : b"\n\x06\0\x08downfall?\xE3333333\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0 minecraft:music.overworld.forest\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0z\xA5\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE3333333\0" } , StaticRegistryEntry { name : "cherry_grove" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\x04\0\rfoliage_color\0\0\0\0\0\xB6\xDBa\x04\0\x0Bgrass_color\0\0\0\0\0\xB6\xDBa\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0&minecraft:music.overworld.cherry_grove\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0]\xB7\xEF\x04\0\x0Fwater_fog_color\0\0\0\0\0]\xB7\xEF\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "cold_ocean" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0=W\xD6\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "crimson_forest" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\n\0\x0Fadditions_sound\x08\0\x05sound\0*minecraft:ambient.crimson_forest.additions\x06\0\x0Btick_chance?\x86\xBB\x98\xC7\xE2\x82A\0\x08\0\rambient_sound\0%minecraft:ambient.crimson_forest.loop\x04\0\tfog_color\0\0\0\0\x003\x03\x03\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0%minecraft:ambient.crimson_forest.mood\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0%minecraft:music.nether.crimson_forest\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\n\0\x08particle\n\0\x07options\x08\0\x04type\0\x17minecraft:crimson_spore\0\x06\0\x0Bprobability?\x99\x99\x99\x99\x99\x99\x9A\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "dark_forest" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\x11dry_foliage_color\0\0\0\0\0{S4\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\x08\0\x14grass_color_modifier\0\x0Bdark_forest\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0 minecraft:music.overworld.forest\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0y\xA6\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE6ffffff\0" } , StaticRegistryEntry { name : "deep_cold_ocean" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0=W\xD6\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "deep_dark" , data : b"\n\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0#minecraft:music.overworld.deep_dark\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0x\xA7\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "deep_frozen_ocean" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\098\xC9\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\x08\0\x14temperature_modifier\0\x06frozen\0" } , StaticRegistryEntry { name : "deep_lukewarm_ocean" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0E\xAD\xF2\x04\0\x0Fwater_fog_color\0\0\0\0\0\x04\x163\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "deep_ocean" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "desert" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0 minecraft:music.overworld.desert\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "dripstone_caves" , data : b"\n\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0)minecraft:music.overworld.dripstone_caves\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0x\xA7\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "end_barrens" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xA0\x80\xA0\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\0\0\0\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "end_highlands" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xA0\x80\xA0\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\0\0\0\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "end_midlands" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xA0\x80\xA0\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\0\0\0\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "eroded_badlands" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\x04\0\rfoliage_color\0\0\0\0\0\x9E\x81M\x04\0\x0Bgrass_color\0\0\0\0\0\x90\x81M\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0\"minecraft:music.overworld.badlands\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "flower_forest" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0'minecraft:music.overworld.flower_forest\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0y\xA6\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE6ffffff\0" } , StaticRegistryEntry { name : "forest" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0 minecraft:music.overworld.forest\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0y\xA6\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE6ffffff\0" } , StaticRegistryEntry { name : "frozen_ocean" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\x7F\xA1\xFF\x04\0\x0Bwater_color\0\0\0\0\098\xC9\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\0\0\0\0\0\0\0\0\x08\0\x14temperature_modifier\0\x06frozen\0" } , StaticRegistryEntry { name : "frozen_peaks" , data : b"\n\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0&minecraft:music.overworld.frozen_peaks\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\x85\x9D\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\xBF\xE6ffffff\0" } , StaticRegistryEntry { name : "frozen_river" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\x7F\xA1\xFF\x04\0\x0Bwater_color\0\0\0\0\098\xC9\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\0\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "grove" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0\x1Fminecraft:music.overworld.grove\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\x81\xA0\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\xBF\xC9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "ice_spikes" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\x7F\xA1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\0\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "jagged_peaks" , data : b"\n\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0&minecraft:music.overworld.jagged_peaks\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\x85\x9D\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\xBF\xE6ffffff\0" } , StaticRegistryEntry { name : "jungle" , data : b"\n\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0 minecraft:music.overworld.jungle\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0w\xA8\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xEEffffff\0" } , StaticRegistryEntry { name : "lukewarm_ocean" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0E\xAD\xF2\x04\0\x0Fwater_fog_color\0\0\0\0\0\x04\x163\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "lush_caves" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0$minecraft:music.overworld.lush_caves\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "mangrove_swamp" , data : b"\n\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x04\0\x11dry_foliage_color\0\0\0\0\0{S4\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\x04\0\rfoliage_color\0\0\0\0\0\x8D\xB1'\x08\0\x14grass_color_modifier\0\x05swamp\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0\x1Fminecraft:music.overworld.swamp\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0x\xA7\xFF\x04\0\x0Bwater_color\0\0\0\0\0:zj\x04\0\x0Fwater_fog_color\0\0\0\0\0Mz`\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "meadow" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0 minecraft:music.overworld.meadow\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0\x0EN\xCF\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "mushroom_fields" , data : b"\n\x06\0\x08downfall?\xF0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0w\xA8\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\0" } , StaticRegistryEntry { name : "nether_wastes" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\n\0\x0Fadditions_sound\x08\0\x05sound\0)minecraft:ambient.nether_wastes.additions\x06\0\x0Btick_chance?\x86\xBB\x98\xC7\xE2\x82A\0\x08\0\rambient_sound\0$minecraft:ambient.nether_wastes.loop\x04\0\tfog_color\0\0\0\0\x003\x08\x08\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0$minecraft:ambient.nether_wastes.mood\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0$minecraft:music.nether.nether_wastes\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "ocean" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "old_growth_birch_forest" , data : b"\n\x06\0\x08downfall?\xE3333333\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0 minecraft:music.overworld.forest\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0z\xA5\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE3333333\0" } , StaticRegistryEntry { name : "old_growth_pine_taiga" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0*minecraft:music.overworld.old_growth_taiga\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0|\xA3\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xD3333333\0" } , StaticRegistryEntry { name : "old_growth_spruce_taiga" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0*minecraft:music.overworld.old_growth_taiga\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0}\xA3\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xD0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "pale_garden" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\x11dry_foliage_color\0\0\0\0\0\xA0\xA6\x9C\x04\0\tfog_color\0\0\0\0\0\x81wp\x04\0\rfoliage_color\0\0\0\0\0\x87\x8Dv\x04\0\x0Bgrass_color\0\0\0\0\0w\x82r\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\0\0\0\0\0\x06\0\x0Cmusic_volume\0\0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\xB9\xB9\xB9\x04\0\x0Bwater_color\0\0\0\0\0v\x88\x9D\x04\0\x0Fwater_fog_color\0\0\0\0\0Ui\x80\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE6ffffff\0" } , StaticRegistryEntry { name : "plains" , data : b"\n\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0x\xA7\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "river" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "savanna" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "savanna_plateau" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "small_end_islands" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xA0\x80\xA0\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\0\0\0\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "snowy_beach" , data : b"\n\x06\0\x08downfall?\xD3333333\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\x7F\xA1\xFF\x04\0\x0Bwater_color\0\0\0\0\0=W\xD6\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xA9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "snowy_plains" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\x7F\xA1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\0\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "snowy_slopes" , data : b"\n\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0&minecraft:music.overworld.snowy_slopes\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\x82\x9F\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\xBF\xD3333333\0" } , StaticRegistryEntry { name : "snowy_taiga" , data : b"\n\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\x83\x9E\xFF\x04\0\x0Bwater_color\0\0\0\0\0=W\xD6\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\xBF\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "soul_sand_valley" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\n\0\x0Fadditions_sound\x08\0\x05sound\0,minecraft:ambient.soul_sand_valley.additions\x06\0\x0Btick_chance?\x86\xBB\x98\xC7\xE2\x82A\0\x08\0\rambient_sound\0'minecraft:ambient.soul_sand_valley.loop\x04\0\tfog_color\0\0\0\0\0\x1BGE\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0'minecraft:ambient.soul_sand_valley.mood\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0'minecraft:music.nether.soul_sand_valley\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\n\0\x08particle\n\0\x07options\x08\0\x04type\0\rminecraft:ash\0\x06\0\x0Bprobability?y\x99\x99\x99\x99\x99\x9A\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "sparse_jungle" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0'minecraft:music.overworld.sparse_jungle\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0w\xA8\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xEEffffff\0" } , StaticRegistryEntry { name : "stony_peaks" , data : b"\n\x06\0\x08downfall?\xD3333333\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0%minecraft:music.overworld.stony_peaks\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0v\xA8\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xF0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "stony_shore" , data : b"\n\x06\0\x08downfall?\xD3333333\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0}\xA2\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xC9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "sunflower_plains" , data : b"\n\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0x\xA7\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "swamp" , data : b"\n\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x04\0\x11dry_foliage_color\0\0\0\0\0{S4\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\x04\0\rfoliage_color\0\0\0\0\0jp9\x08\0\x14grass_color_modifier\0\x05swamp\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0\x1Fminecraft:music.overworld.swamp\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0x\xA7\xFF\x04\0\x0Bwater_color\0\0\0\0\0a{d\x04\0\x0Fwater_fog_color\0\0\0\0\0##\x17\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "taiga" , data : b"\n\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0}\xA3\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xD0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "the_end" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xA0\x80\xA0\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0\0\0\0\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "the_void" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "warm_ocean" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0{\xA4\xFF\x04\0\x0Bwater_color\0\0\0\0\0C\xD5\xEE\x04\0\x0Fwater_fog_color\0\0\0\0\0\x04\x1F3\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "warped_forest" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\n\0\x0Fadditions_sound\x08\0\x05sound\0)minecraft:ambient.warped_forest.additions\x06\0\x0Btick_chance?\x86\xBB\x98\xC7\xE2\x82A\0\x08\0\rambient_sound\0$minecraft:ambient.warped_forest.loop\x04\0\tfog_color\0\0\0\0\0\x1A\x05\x1A\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0$minecraft:ambient.warped_forest.mood\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0$minecraft:music.nether.warped_forest\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\n\0\x08particle\n\0\x07options\x08\0\x04type\0\x16minecraft:warped_spore\0\x06\0\x0Bprobability?\x8D>\xD5'\xE5!W\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "windswept_forest" , data : b"\n\x06\0\x08downfall?\xD3333333\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0}\xA2\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xC9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "windswept_gravelly_hills" , data : b"\n\x06\0\x08downfall?\xD3333333\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0}\xA2\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xC9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "windswept_hills" , data : b"\n\x06\0\x08downfall?\xD3333333\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0}\xA2\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xC9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "windswept_savanna" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "wooded_badlands" , data : b"\n\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x04\0\tfog_color\0\0\0\0\0\xC0\xD8\xFF\x04\0\rfoliage_color\0\0\0\0\0\x9E\x81M\x04\0\x0Bgrass_color\0\0\0\0\0\x90\x81M\n\0\nmood_sound\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\t\0\x05music\n\0\0\0\x01\n\0\x04data\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x01\0\x15replace_current_music\0\x08\0\x05sound\0\"minecraft:music.overworld.badlands\0\x04\0\x06weight\0\0\0\0\0\0\0\x01\0\x06\0\x0Cmusic_volume?\xF0\0\0\0\0\0\0\x04\0\tsky_color\0\0\0\0\0n\xB1\xFF\x04\0\x0Bwater_color\0\0\0\0\0?v\xE4\x04\0\x0Fwater_fog_color\0\0\0\0\0\x05\x053\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" }] , } , StaticRegistry { registry_id : "chat_type" , entries : & [StaticRegistryEntry { name : "chat" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x0Echat.type.text\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" } , StaticRegistryEntry { name : "emote_command" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x0Fchat.type.emote\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x0Fchat.type.emote\0\0" } , StaticRegistryEntry { name : "msg_command_incoming" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\n\0\x05style\x08\0\x05color\0\x04gray\x01\0\x06italic\x01\0\x08\0\x0Ftranslation_key\0!commands.message.display.incoming\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" } , StaticRegistryEntry { name : "msg_command_outgoing" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x02\0\x06target\0\x07content\n\0\x05style\x08\0\x05color\0\x04gray\x01\0\x06italic\x01\0\x08\0\x0Ftranslation_key\0!commands.message.display.outgoing\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" } , StaticRegistryEntry { name : "say_command" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.announcement\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" } , StaticRegistryEntry { name : "team_msg_command_incoming" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x03\0\x06target\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x13chat.type.team.text\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" } , StaticRegistryEntry { name : "team_msg_command_outgoing" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x03\0\x06target\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x13chat.type.team.sent\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" }] , } , StaticRegistry { registry_id : "trim_pattern" , entries : & [StaticRegistryEntry { name : "bolt" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:bolt\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.bolt\0\0" } , StaticRegistryEntry { name : "coast" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:coast\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ctrim_pattern.minecraft.coast\0\0" } , StaticRegistryEntry { name : "dune" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:dune\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.dune\0\0" } , StaticRegistryEntry { name : "eye" , data : b"\n\x08\0\x08asset_id\0\rminecraft:eye\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Atrim_pattern.minecraft.eye\0\0" } , StaticRegistryEntry { name : "flow" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:flow\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.flow\0\0" } , StaticRegistryEntry { name : "host" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:host\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.host\0\0" } , StaticRegistryEntry { name : "raiser" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:raiser\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Dtrim_pattern.minecraft.raiser\0\0" } , StaticRegistryEntry { name : "rib" , data : b"\n\x08\0\x08asset_id\0\rminecraft:rib\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Atrim_pattern.minecraft.rib\0\0" } , StaticRegistryEntry { name : "sentry" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:sentry\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Dtrim_pattern.minecraft.sentry\0\0" } , StaticRegistryEntry { name : "shaper" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:shaper\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Dtrim_pattern.minecraft.shaper\0\0" } , StaticRegistryEntry { name : "silence" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:silence\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Etrim_pattern.minecraft.silence\0\0" } , StaticRegistryEntry { name : "snout" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:snout\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ctrim_pattern.minecraft.snout\0\0" } , StaticRegistryEntry { name : "spire" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:spire\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ctrim_pattern.minecraft.spire\0\0" } , StaticRegistryEntry { name : "tide" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:tide\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.tide\0\0" } , StaticRegistryEntry { name : "vex" , data : b"\n\x08\0\x08asset_id\0\rminecraft:vex\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Atrim_pattern.minecraft.vex\0\0" } , StaticRegistryEntry { name : "ward" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:ward\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.ward\0\0" } , StaticRegistryEntry { name : "wayfinder" , data : b"\n\x08\0\x08asset_id\0\x13minecraft:wayfinder\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0 trim_pattern.minecraft.wayfinder\0\0" } , StaticRegistryEntry { name : "wild" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:wild\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.wild\0\0" }] , } , StaticRegistry { registry_id : "trim_material" , entries : & [StaticRegistryEntry { name : "amethyst" , data : b"\n\x08\0\nasset_name\0\x08amethyst\n\0\x0Bdescription\x08\0\x05color\0\x07#9A5CC6\x08\0\ttranslate\0 trim_material.minecraft.amethyst\0\0" } , StaticRegistryEntry { name : "copper" , data : b"\n\x08\0\nasset_name\0\x06copper\n\0\x0Bdescription\x08\0\x05color\0\x07#B4684D\x08\0\ttranslate\0\x1Etrim_material.minecraft.copper\0\n\0\x15override_armor_assets\x08\0\x10minecraft:copper\0\rcopper_darker\0\0" } , StaticRegistryEntry { name : "diamond" , data : b"\n\x08\0\nasset_name\0\x07diamond\n\0\x0Bdescription\x08\0\x05color\0\x07#6EECD2\x08\0\ttranslate\0\x1Ftrim_material.minecraft.diamond\0\n\0\x15override_armor_assets\x08\0\x11minecraft:diamond\0\x0Ediamond_darker\0\0" } , StaticRegistryEntry { name : "emerald" , data : b"\n\x08\0\nasset_name\0\x07emerald\n\0\x0Bdescription\x08\0\x05color\0\x07#11A036\x08\0\ttranslate\0\x1Ftrim_material.minecraft.emerald\0\0" } , StaticRegistryEntry { name : "gold" , data : b"\n\x08\0\nasset_name\0\x04gold\n\0\x0Bdescription\x08\0\x05color\0\x07#DEB12D\x08\0\ttranslate\0\x1Ctrim_material.minecraft.gold\0\n\0\x15override_armor_assets\x08\0\x0Eminecraft:gold\0\x0Bgold_darker\0\0" } , StaticRegistryEntry { name : "iron" , data : b"\n\x08\0\nasset_name\0\x04iron\n\0\x0Bdescription\x08\0\x05color\0\x07#ECECEC\x08\0\ttranslate\0\x1Ctrim_material.minecraft.iron\0\n\0\x15override_armor_assets\x08\0\x0Eminecraft:iron\0\x0Biron_darker\0\0" } , StaticRegistryEntry { name : "lapis" , data : b"\n\x08\0\nasset_name\0\x05lapis\n\0\x0Bdescription\x08\0\x05color\0\x07#416E97\x08\0\ttranslate\0\x1Dtrim_material.minecraft.lapis\0\0" } , StaticRegistryEntry { name : "netherite" , data : b"\n\x08\0\nasset_name\0\tnetherite\n\0\x0Bdescription\x08\0\x05color\0\x07#625859\x08\0\ttranslate\0!trim_material.minecraft.netherite\0\n\0\x15override_armor_assets\x08\0\x13minecraft:netherite\0\x10netherite_darker\0\0" } , StaticRegistryEntry { name : "quartz" , data : b"\n\x08\0\nasset_name\0\x06quartz\n\0\x0Bdescription\x08\0\x05color\0\x07#E3D4C4\x08\0\ttranslate\0\x1Etrim_material.minecraft.quartz\0\0" } , StaticRegistryEntry { name : "redstone" , data : b"\n\x08\0\nasset_name\0\x08redstone\n\0\x0Bdescription\x08\0\x05color\0\x07#971607\x08\0\ttranslate\0 trim_material.minecraft.redstone\0\0" } , StaticRegistryEntry { name : "resin" , data : b"\n\x08\0\nasset_name\0\x05resin\n\0\x0Bdescription\x08\0\x05color\0\x07#FC7812\x08\0\ttranslate\0\x1Dtrim_material.minecraft.resin\0\0" }] , } , StaticRegistry { registry_id : "wolf_variant" , entries : & [StaticRegistryEntry { name : "ashen" , data : b"\n\n\0\x06assets\x08\0\x05angry\0&minecraft:entity/wolf/wolf_ashen_angry\x08\0\x04tame\0%minecraft:entity/wolf/wolf_ashen_tame\x08\0\x04wild\0 minecraft:entity/wolf/wolf_ashen\0\0" } , StaticRegistryEntry { name : "black" , data : b"\n\n\0\x06assets\x08\0\x05angry\0&minecraft:entity/wolf/wolf_black_angry\x08\0\x04tame\0%minecraft:entity/wolf/wolf_black_tame\x08\0\x04wild\0 minecraft:entity/wolf/wolf_black\0\0" } , StaticRegistryEntry { name : "chestnut" , data : b"\n\n\0\x06assets\x08\0\x05angry\0)minecraft:entity/wolf/wolf_chestnut_angry\x08\0\x04tame\0(minecraft:entity/wolf/wolf_chestnut_tame\x08\0\x04wild\0#minecraft:entity/wolf/wolf_chestnut\0\0" } , StaticRegistryEntry { name : "pale" , data : b"\n\n\0\x06assets\x08\0\x05angry\0 minecraft:entity/wolf/wolf_angry\x08\0\x04tame\0\x1Fminecraft:entity/wolf/wolf_tame\x08\0\x04wild\0\x1Aminecraft:entity/wolf/wolf\0\0" } , StaticRegistryEntry { name : "rusty" , data : b"\n\n\0\x06assets\x08\0\x05angry\0&minecraft:entity/wolf/wolf_rusty_angry\x08\0\x04tame\0%minecraft:entity/wolf/wolf_rusty_tame\x08\0\x04wild\0 minecraft:entity/wolf/wolf_rusty\0\0" } , StaticRegistryEntry { name : "snowy" , data : b"\n\n\0\x06assets\x08\0\x05angry\0&minecraft:entity/wolf/wolf_snowy_angry\x08\0\x04tame\0%minecraft:entity/wolf/wolf_snowy_tame\x08\0\x04wild\0 minecraft:entity/wolf/wolf_snowy\0\0" } , StaticRegistryEntry { name : "spotted" , data : b"\n\n\0\x06assets\x08\0\x05angry\0(minecraft:entity/wolf/wolf_spotted_angry\x08\0\x04tame\0'minecraft:entity/wolf/wolf_spotted_tame\x08\0\x04wild\0\"minecraft:entity/wolf/wolf_spotted\0\0" } , StaticRegistryEntry { name : "striped" , data : b"\n\n\0\x06assets\x08\0\x05angry\0(minecraft:entity/wolf/wolf_striped_angry\x08\0\x04tame\0'minecraft:entity/wolf/wolf_striped_tame\x08\0\x04wild\0\"minecraft:entity/wolf/wolf_striped\0\0" } , StaticRegistryEntry { name : "woods" , data : b"\n\n\0\x06assets\x08\0\x05angry\0&minecraft:entity/wolf/wolf_woods_angry\x08\0\x04tame\0%minecraft:entity/wolf/wolf_woods_tame\x08\0\x04wild\0 minecraft:entity/wolf/wolf_woods\0\0" }] , } , StaticRegistry { registry_id : "wolf_sound_variant" , entries : & [StaticRegistryEntry { name : "angry" , data : b"\n\x08\0\rambient_sound\0#minecraft:entity.wolf_angry.ambient\x08\0\x0Bdeath_sound\0!minecraft:entity.wolf_angry.death\x08\0\x0Bgrowl_sound\0!minecraft:entity.wolf_angry.growl\x08\0\nhurt_sound\0 minecraft:entity.wolf_angry.hurt\x08\0\npant_sound\0 minecraft:entity.wolf_angry.pant\x08\0\x0Bwhine_sound\0!minecraft:entity.wolf_angry.whine\0" } , StaticRegistryEntry { name : "big" , data : b"\n\x08\0\rambient_sound\0!minecraft:entity.wolf_big.ambient\x08\0\x0Bdeath_sound\0\x1Fminecraft:entity.wolf_big.death\x08\0\x0Bgrowl_sound\0\x1Fminecraft:entity.wolf_big.growl\x08\0\nhurt_sound\0\x1Eminecraft:entity.wolf_big.hurt\x08\0\npant_sound\0\x1Eminecraft:entity.wolf_big.pant\x08\0\x0Bwhine_sound\0\x1Fminecraft:entity.wolf_big.whine\0" } , StaticRegistryEntry { name : "classic" , data : b"\n\x08\0\rambient_sound\0\x1Dminecraft:entity.wolf.ambient\x08\0\x0Bdeath_sound\0\x1Bminecraft:entity.wolf.death\x08\0\x0Bgrowl_sound\0\x1Bminecraft:entity.wolf.growl\x08\0\nhurt_sound\0\x1Aminecraft:entity.wolf.hurt\x08\0\npant_sound\0\x1Aminecraft:entity.wolf.pant\x08\0\x0Bwhine_sound\0\x1Bminecraft:entity.wolf.whine\0" } , StaticRegistryEntry { name : "cute" , data : b"\n\x08\0\rambient_sound\0\"minecraft:entity.wolf_cute.ambient\x08\0\x0Bdeath_sound\0 minecraft:entity.wolf_cute.death\x08\0\x0Bgrowl_sound\0 minecraft:entity.wolf_cute.growl\x08\0\nhurt_sound\0\x1Fminecraft:entity.wolf_cute.hurt\x08\0\npant_sound\0\x1Fminecraft:entity.wolf_cute.pant\x08\0\x0Bwhine_sound\0 minecraft:entity.wolf_cute.whine\0" } , StaticRegistryEntry { name : "grumpy" , data : b"\n\x08\0\rambient_sound\0$minecraft:entity.wolf_grumpy.ambient\x08\0\x0Bdeath_sound\0\"minecraft:entity.wolf_grumpy.death\x08\0\x0Bgrowl_sound\0\"minecraft:entity.wolf_grumpy.growl\x08\0\nhurt_sound\0!minecraft:entity.wolf_grumpy.hurt\x08\0\npant_sound\0!minecraft:entity.wolf_grumpy.pant\x08\0\x0Bwhine_sound\0\"minecraft:entity.wolf_grumpy.whine\0" } , StaticRegistryEntry { name : "puglin" , data : b"\n\x08\0\rambient_sound\0$minecraft:entity.wolf_puglin.ambient\x08\0\x0Bdeath_sound\0\"minecraft:entity.wolf_puglin.death\x08\0\x0Bgrowl_sound\0\"minecraft:entity.wolf_puglin.growl\x08\0\nhurt_sound\0!minecraft:entity.wolf_puglin.hurt\x08\0\npant_sound\0!minecraft:entity.wolf_puglin.pant\x08\0\x0Bwhine_sound\0\"minecraft:entity.wolf_puglin.whine\0" } , StaticRegistryEntry { name : "sad" , data : b"\n\x08\0\rambient_sound\0!minecraft:entity.wolf_sad.ambient\x08\0\x0Bdeath_sound\0\x1Fminecraft:entity.wolf_sad.death\x08\0\x0Bgrowl_sound\0\x1Fminecraft:entity.wolf_sad.growl\x08\0\nhurt_sound\0\x1Eminecraft:entity.wolf_sad.hurt\x08\0\npant_sound\0\x1Eminecraft:entity.wolf_sad.pant\x08\0\x0Bwhine_sound\0\x1Fminecraft:entity.wolf_sad.whine\0" }] , } , StaticRegistry { registry_id : "pig_variant" , entries : & [StaticRegistryEntry { name : "cold" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:entity/pig/cold_pig\x08\0\x05model\0\x04cold\0" } , StaticRegistryEntry { name : "temperate" , data : b"\n\x08\0\x08asset_id\0\"minecraft:entity/pig/temperate_pig\0" } , StaticRegistryEntry { name : "warm" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:entity/pig/warm_pig\0" }] , } , StaticRegistry { registry_id : "frog_variant" , entries : & [StaticRegistryEntry { name : "cold" , data : b"\n\x08\0\x08asset_id\0\x1Fminecraft:entity/frog/cold_frog\0" } , StaticRegistryEntry { name : "temperate" , data : b"\n\x08\0\x08asset_id\0$minecraft:entity/frog/temperate_frog\0" } , StaticRegistryEntry { name : "warm" , data : b"\n\x08\0\x08asset_id\0\x1Fminecraft:entity/frog/warm_frog\0" }] , } , StaticRegistry { registry_id : "cat_variant" , entries : & [StaticRegistryEntry { name : "all_black" , data : b"\n\x08\0\x08asset_id\0\x1Eminecraft:entity/cat/all_black\0" } , StaticRegistryEntry { name : "black" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:entity/cat/black\0" } , StaticRegistryEntry { name : "british_shorthair" , data : b"\n\x08\0\x08asset_id\0&minecraft:entity/cat/british_shorthair\0" } , StaticRegistryEntry { name : "calico" , data : b"\n\x08\0\x08asset_id\0\x1Bminecraft:entity/cat/calico\0" } , StaticRegistryEntry { name : "jellie" , data : b"\n\x08\0\x08asset_id\0\x1Bminecraft:entity/cat/jellie\0" } , StaticRegistryEntry { name : "persian" , data : b"\n\x08\0\x08asset_id\0\x1Cminecraft:entity/cat/persian\0" } , StaticRegistryEntry { name : "ragdoll" , data : b"\n\x08\0\x08asset_id\0\x1Cminecraft:entity/cat/ragdoll\0" } , StaticRegistryEntry { name : "red" , data : b"\n\x08\0\x08asset_id\0\x18minecraft:entity/cat/red\0" } , StaticRegistryEntry { name : "siamese" , data : b"\n\x08\0\x08asset_id\0\x1Cminecraft:entity/cat/siamese\0" } , StaticRegistryEntry { name : "tabby" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:entity/cat/tabby\0" } , StaticRegistryEntry { name : "white" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:entity/cat/white\0" }] , } , StaticRegistry { registry_id : "cow_variant" , entries : & [StaticRegistryEntry { name : "cold" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:entity/cow/cold_cow\x08\0\x05model\0\x04cold\0" } , StaticRegistryEntry { name : "temperate" , data : b"\n\x08\0\x08asset_id\0\"minecraft:entity/cow/temperate_cow\0" } , StaticRegistryEntry { name : "warm" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:entity/cow/warm_cow\x08\0\x05model\0\x04warm\0" }] , } , StaticRegistry { registry_id : "chicken_variant" , entries : & [StaticRegistryEntry { name : "cold" , data : b"\n\x08\0\x08asset_id\0%minecraft:entity/chicken/cold_chicken\x08\0\x05model\0\x04cold\0" } , StaticRegistryEntry { name : "temperate" , data : b"\n\x08\0\x08asset_id\0*minecraft:entity/chicken/temperate_chicken\0" } , StaticRegistryEntry { name : "warm" , data : b"\n\x08\0\x08asset_id\0%minecraft:entity/chicken/warm_chicken\0" }] , } , StaticRegistry { registry_id : "painting_variant" , entries : & [StaticRegistryEntry { name : "alban" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:alban\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.alban.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.alban.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "aztec" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:aztec\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.aztec.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.aztec.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "aztec2" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:aztec2\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0 painting.minecraft.aztec2.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.aztec2.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "backyard" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:backyard\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.backyard.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.backyard.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "baroque" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:baroque\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.baroque.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.baroque.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "bomb" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:bomb\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.bomb.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.bomb.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "bouquet" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:bouquet\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.bouquet.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.bouquet.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "burning_skull" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:burning_skull\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0'painting.minecraft.burning_skull.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0&painting.minecraft.burning_skull.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "bust" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:bust\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.bust.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.bust.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "cavebird" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:cavebird\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.cavebird.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.cavebird.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "changing" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:changing\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.changing.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.changing.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "cotan" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:cotan\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.cotan.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.cotan.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "courbet" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:courbet\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.courbet.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.courbet.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "creebet" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:creebet\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.creebet.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.creebet.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "dennis" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:dennis\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0 painting.minecraft.dennis.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.dennis.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "donkey_kong" , data : b"\n\x08\0\x08asset_id\0\x15minecraft:donkey_kong\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0%painting.minecraft.donkey_kong.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0$painting.minecraft.donkey_kong.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "earth" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:earth\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.earth.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "endboss" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:endboss\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.endboss.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.endboss.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "fern" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:fern\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.fern.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.fern.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "fighters" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:fighters\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.fighters.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.fighters.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "finding" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:finding\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.finding.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.finding.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "fire" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:fire\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.fire.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "graham" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:graham\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0 painting.minecraft.graham.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.graham.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "humble" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:humble\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0 painting.minecraft.humble.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.humble.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "kebab" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:kebab\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.kebab.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.kebab.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "lowmist" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:lowmist\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.lowmist.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.lowmist.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "match" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:match\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.match.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.match.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "meditative" , data : b"\n\x08\0\x08asset_id\0\x14minecraft:meditative\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0$painting.minecraft.meditative.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0#painting.minecraft.meditative.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "orb" , data : b"\n\x08\0\x08asset_id\0\rminecraft:orb\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Dpainting.minecraft.orb.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Cpainting.minecraft.orb.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "owlemons" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:owlemons\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.owlemons.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.owlemons.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "passage" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:passage\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.passage.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.passage.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "pigscene" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:pigscene\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.pigscene.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.pigscene.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "plant" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:plant\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.plant.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.plant.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "pointer" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:pointer\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.pointer.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.pointer.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "pond" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:pond\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.pond.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.pond.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "pool" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:pool\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.pool.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.pool.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "prairie_ride" , data : b"\n\x08\0\x08asset_id\0\x16minecraft:prairie_ride\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0&painting.minecraft.prairie_ride.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0%painting.minecraft.prairie_ride.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "sea" , data : b"\n\x08\0\x08asset_id\0\rminecraft:sea\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Dpainting.minecraft.sea.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Cpainting.minecraft.sea.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "skeleton" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:skeleton\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.skeleton.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.skeleton.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "skull_and_roses" , data : b"\n\x08\0\x08asset_id\0\x19minecraft:skull_and_roses\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0)painting.minecraft.skull_and_roses.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0(painting.minecraft.skull_and_roses.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "stage" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:stage\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.stage.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.stage.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "sunflowers" , data : b"\n\x08\0\x08asset_id\0\x14minecraft:sunflowers\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0$painting.minecraft.sunflowers.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0#painting.minecraft.sunflowers.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "sunset" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:sunset\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0 painting.minecraft.sunset.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.sunset.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "tides" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:tides\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.tides.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.tides.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "unpacked" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:unpacked\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.unpacked.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.unpacked.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "void" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:void\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.void.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.void.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "wanderer" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:wanderer\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.wanderer.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.wanderer.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "wasteland" , data : b"\n\x08\0\x08asset_id\0\x13minecraft:wasteland\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0#painting.minecraft.wasteland.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\"painting.minecraft.wasteland.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "water" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:water\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.water.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "wind" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:wind\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.wind.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "wither" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:wither\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.wither.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" }] , } , StaticRegistry { registry_id : "dimension_type" , entries : & [StaticRegistryEntry { name : "overworld" , data : b"\n\x06\0\rambient_light\0\0\0\0\0\0\0\0\x01\0\tbed_works\x01\x04\0\x0Ccloud_height\0\0\0\0\0\0\0\xC0\x06\0\x10coordinate_scale?\xF0\0\0\0\0\0\0\x08\0\x07effects\0\x13minecraft:overworld\x01\0\x0Bhas_ceiling\0\x01\0\thas_raids\x01\x01\0\x0Chas_skylight\x01\x04\0\x06height\0\0\0\0\0\0\x01\x80\x08\0\ninfiniburn\0\x1F#minecraft:infiniburn_overworld\x04\0\x0Elogical_height\0\0\0\0\0\0\x01\x80\x04\0\x05min_y\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xC0\x04\0\x1Fmonster_spawn_block_light_limit\0\0\0\0\0\0\0\0\n\0\x19monster_spawn_light_level\x04\0\rmax_inclusive\0\0\0\0\0\0\0\x07\x04\0\rmin_inclusive\0\0\0\0\0\0\0\0\x08\0\x04type\0\x11minecraft:uniform\0\x01\0\x07natural\x01\x01\0\x0Bpiglin_safe\0\x01\0\x14respawn_anchor_works\0\x01\0\tultrawarm\0\0" } , StaticRegistryEntry { name : "overworld_caves" , data : b"\n\x06\0\rambient_light\0\0\0\0\0\0\0\0\x01\0\tbed_works\x01\x04\0\x0Ccloud_height\0\0\0\0\0\0\0\xC0\x06\0\x10coordinate_scale?\xF0\0\0\0\0\0\0\x08\0\x07effects\0\x13minecraft:overworld\x01\0\x0Bhas_ceiling\x01\x01\0\thas_raids\x01\x01\0\x0Chas_skylight\x01\x04\0\x06height\0\0\0\0\0\0\x01\x80\x08\0\ninfiniburn\0\x1F#minecraft:infiniburn_overworld\x04\0\x0Elogical_height\0\0\0\0\0\0\x01\x80\x04\0\x05min_y\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xC0\x04\0\x1Fmonster_spawn_block_light_limit\0\0\0\0\0\0\0\0\n\0\x19monster_spawn_light_level\x04\0\rmax_inclusive\0\0\0\0\0\0\0\x07\x04\0\rmin_inclusive\0\0\0\0\0\0\0\0\x08\0\x04type\0\x11minecraft:uniform\0\x01\0\x07natural\x01\x01\0\x0Bpiglin_safe\0\x01\0\x14respawn_anchor_works\0\x01\0\tultrawarm\0\0" } , StaticRegistryEntry { name : "the_end" , data : b"\n\x06\0\rambient_light?\xD0\0\0\0\0\0\0\x01\0\tbed_works\0\x06\0\x10coordinate_scale?\xF0\0\0\0\0\0\0\x08\0\x07effects\0\x11minecraft:the_end\x04\0\nfixed_time\0\0\0\0\0\0\x17p\x01\0\x0Bhas_ceiling\0\x01\0\thas_raids\x01\x01\0\x0Chas_skylight\x01\x04\0\x06height\0\0\0\0\0\0\x01\0\x08\0\ninfiniburn\0\x19#minecraft:infiniburn_end\x04\0\x0Elogical_height\0\0\0\0\0\0\x01\0\x04\0\x05min_y\0\0\0\0\0\0\0\0\x04\0\x1Fmonster_spawn_block_light_limit\0\0\0\0\0\0\0\0\x04\0\x19monster_spawn_light_level\0\0\0\0\0\0\0\x0F\x01\0\x07natural\0\x01\0\x0Bpiglin_safe\0\x01\0\x14respawn_anchor_works\0\x01\0\tultrawarm\0\0" } , StaticRegistryEntry { name : "the_nether" , data : b"\n\x06\0\rambient_light?\xB9\x99\x99\x99\x99\x99\x9A\x01\0\tbed_works\0\x06\0\x10coordinate_scale@ \0\0\0\0\0\0\x08\0\x07effects\0\x14minecraft:the_nether\x04\0\nfixed_time\0\0\0\0\0\0FP\x01\0\x0Bhas_ceiling\x01\x01\0\thas_raids\0\x01\0\x0Chas_skylight\0\x04\0\x06height\0\0\0\0\0\0\x01\0\x08\0\ninfiniburn\0\x1C#minecraft:infiniburn_nether\x04\0\x0Elogical_height\0\0\0\0\0\0\0\x80\x04\0\x05min_y\0\0\0\0\0\0\0\0\x04\0\x1Fmonster_spawn_block_light_limit\0\0\0\0\0\0\0\x0F\x04\0\x19monster_spawn_light_level\0\0\0\0\0\0\0\x07\x01\0\x07natural\0\x01\0\x0Bpiglin_safe\x01\x01\0\x14respawn_anchor_works\x01\x01\0\tultrawarm\x01\0" }] , } , StaticRegistry { registry_id : "damage_type" , entries : & [StaticRegistryEntry { name : "arrow" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x05arrow\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "bad_respawn_point" , data : b"\n\x08\0\x12death_message_type\0\x17intentional_game_design\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x0FbadRespawnPoint\x08\0\x07scaling\0\x06always\0" } , StaticRegistryEntry { name : "cactus" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06cactus\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "campfire" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06inFire\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "cramming" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x08cramming\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "dragon_breath" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x0CdragonBreath\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "drown" , data : b"\n\x08\0\x07effects\0\x08drowning\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x05drown\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "dry_out" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06dryout\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "ender_pearl" , data : b"\n\x08\0\x12death_message_type\0\rfall_variants\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x04fall\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "explosion" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\texplosion\x08\0\x07scaling\0\x06always\0" } , StaticRegistryEntry { name : "fall" , data : b"\n\x08\0\x12death_message_type\0\rfall_variants\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x04fall\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "falling_anvil" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x05anvil\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "falling_block" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x0CfallingBlock\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "falling_stalactite" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x11fallingStalactite\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "fireball" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x08fireball\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "fireworks" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\tfireworks\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "fly_into_wall" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x0BflyIntoWall\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "freeze" , data : b"\n\x08\0\x07effects\0\x08freezing\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x06freeze\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "generic" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x07generic\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "generic_kill" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x0BgenericKill\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "hot_floor" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x08hotFloor\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "in_fire" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06inFire\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "in_wall" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x06inWall\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "indirect_magic" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\rindirectMagic\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "lava" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x04lava\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "lightning_bolt" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\rlightningBolt\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "mace_smash" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\nmace_smash\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "magic" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x05magic\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "mob_attack" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x03mob\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "mob_attack_no_aggro" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x03mob\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "mob_projectile" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x03mob\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "on_fire" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x06onFire\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "out_of_world" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\noutOfWorld\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "outside_border" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\routsideBorder\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "player_attack" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06player\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "player_explosion" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x10explosion.player\x08\0\x07scaling\0\x06always\0" } , StaticRegistryEntry { name : "sonic_boom" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\nsonic_boom\x08\0\x07scaling\0\x06always\0" } , StaticRegistryEntry { name : "spit" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x03mob\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "stalagmite" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\nstalagmite\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "starve" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x06starve\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "sting" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x05sting\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "sweet_berry_bush" , data : b"\n\x08\0\x07effects\0\x06poking\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x0EsweetBerryBush\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "thorns" , data : b"\n\x08\0\x07effects\0\x06thorns\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06thorns\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "thrown" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06thrown\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "trident" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x07trident\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "unattributed_fireball" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06onFire\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "wind_charge" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x03mob\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "wither" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x06wither\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "wither_skull" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x0BwitherSkull\x08\0\x07scaling\0 when_caused_by_living_non_player\0" }] , } , StaticRegistry { registry_id : "banner_pattern" , entries : & [StaticRegistryEntry { name : "base" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:base\x08\0\x0Ftranslation_key\0\x1Bblock.minecraft.banner.base\0" } , StaticRegistryEntry { name : "border" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:border\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.border\0" } , StaticRegistryEntry { name : "bricks" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:bricks\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.bricks\0" } , StaticRegistryEntry { name : "circle" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:circle\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.circle\0" } , StaticRegistryEntry { name : "creeper" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:creeper\x08\0\x0Ftranslation_key\0\x1Eblock.minecraft.banner.creeper\0" } , StaticRegistryEntry { name : "cross" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:cross\x08\0\x0Ftranslation_key\0\x1Cblock.minecraft.banner.cross\0" } , StaticRegistryEntry { name : "curly_border" , data : b"\n\x08\0\x08asset_id\0\x16minecraft:curly_border\x08\0\x0Ftranslation_key\0#block.minecraft.banner.curly_border\0" } , StaticRegistryEntry { name : "diagonal_left" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:diagonal_left\x08\0\x0Ftranslation_key\0$block.minecraft.banner.diagonal_left\0" } , StaticRegistryEntry { name : "diagonal_right" , data : b"\n\x08\0\x08asset_id\0\x18minecraft:diagonal_right\x08\0\x0Ftranslation_key\0%block.minecraft.banner.diagonal_right\0" } , StaticRegistryEntry { name : "diagonal_up_left" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:diagonal_up_left\x08\0\x0Ftranslation_key\0'block.minecraft.banner.diagonal_up_left\0" } , StaticRegistryEntry { name : "diagonal_up_right" , data : b"\n\x08\0\x08asset_id\0\x1Bminecraft:diagonal_up_right\x08\0\x0Ftranslation_key\0(block.minecraft.banner.diagonal_up_right\0" } , StaticRegistryEntry { name : "flow" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:flow\x08\0\x0Ftranslation_key\0\x1Bblock.minecraft.banner.flow\0" } , StaticRegistryEntry { name : "flower" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:flower\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.flower\0" } , StaticRegistryEntry { name : "globe" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:globe\x08\0\x0Ftranslation_key\0\x1Cblock.minecraft.banner.globe\0" } , StaticRegistryEntry { name : "gradient" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:gradient\x08\0\x0Ftranslation_key\0\x1Fblock.minecraft.banner.gradient\0" } , StaticRegistryEntry { name : "gradient_up" , data : b"\n\x08\0\x08asset_id\0\x15minecraft:gradient_up\x08\0\x0Ftranslation_key\0\"block.minecraft.banner.gradient_up\0" } , StaticRegistryEntry { name : "guster" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:guster\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.guster\0" } , StaticRegistryEntry { name : "half_horizontal" , data : b"\n\x08\0\x08asset_id\0\x19minecraft:half_horizontal\x08\0\x0Ftranslation_key\0&block.minecraft.banner.half_horizontal\0" } , StaticRegistryEntry { name : "half_horizontal_bottom" , data : b"\n\x08\0\x08asset_id\0 minecraft:half_horizontal_bottom\x08\0\x0Ftranslation_key\0-block.minecraft.banner.half_horizontal_bottom\0" } , StaticRegistryEntry { name : "half_vertical" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:half_vertical\x08\0\x0Ftranslation_key\0$block.minecraft.banner.half_vertical\0" } , StaticRegistryEntry { name : "half_vertical_right" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:half_vertical_right\x08\0\x0Ftranslation_key\0*block.minecraft.banner.half_vertical_right\0" } , StaticRegistryEntry { name : "mojang" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:mojang\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.mojang\0" } , StaticRegistryEntry { name : "piglin" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:piglin\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.piglin\0" } , StaticRegistryEntry { name : "rhombus" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:rhombus\x08\0\x0Ftranslation_key\0\x1Eblock.minecraft.banner.rhombus\0" } , StaticRegistryEntry { name : "skull" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:skull\x08\0\x0Ftranslation_key\0\x1Cblock.minecraft.banner.skull\0" } , StaticRegistryEntry { name : "small_stripes" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:small_stripes\x08\0\x0Ftranslation_key\0$block.minecraft.banner.small_stripes\0" } , StaticRegistryEntry { name : "square_bottom_left" , data : b"\n\x08\0\x08asset_id\0\x1Cminecraft:square_bottom_left\x08\0\x0Ftranslation_key\0)block.minecraft.banner.square_bottom_left\0" } , StaticRegistryEntry { name : "square_bottom_right" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:square_bottom_right\x08\0\x0Ftranslation_key\0*block.minecraft.banner.square_bottom_right\0" } , StaticRegistryEntry { name : "square_top_left" , data : b"\n\x08\0\x08asset_id\0\x19minecraft:square_top_left\x08\0\x0Ftranslation_key\0&block.minecraft.banner.square_top_left\0" } , StaticRegistryEntry { name : "square_top_right" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:square_top_right\x08\0\x0Ftranslation_key\0'block.minecraft.banner.square_top_right\0" } , StaticRegistryEntry { name : "straight_cross" , data : b"\n\x08\0\x08asset_id\0\x18minecraft:straight_cross\x08\0\x0Ftranslation_key\0%block.minecraft.banner.straight_cross\0" } , StaticRegistryEntry { name : "stripe_bottom" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:stripe_bottom\x08\0\x0Ftranslation_key\0$block.minecraft.banner.stripe_bottom\0" } , StaticRegistryEntry { name : "stripe_center" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:stripe_center\x08\0\x0Ftranslation_key\0$block.minecraft.banner.stripe_center\0" } , StaticRegistryEntry { name : "stripe_downleft" , data : b"\n\x08\0\x08asset_id\0\x19minecraft:stripe_downleft\x08\0\x0Ftranslation_key\0&block.minecraft.banner.stripe_downleft\0" } , StaticRegistryEntry { name : "stripe_downright" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:stripe_downright\x08\0\x0Ftranslation_key\0'block.minecraft.banner.stripe_downright\0" } , StaticRegistryEntry { name : "stripe_left" , data : b"\n\x08\0\x08asset_id\0\x15minecraft:stripe_left\x08\0\x0Ftranslation_key\0\"block.minecraft.banner.stripe_left\0" } , StaticRegistryEntry { name : "stripe_middle" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:stripe_middle\x08\0\x0Ftranslation_key\0$block.minecraft.banner.stripe_middle\0" } , StaticRegistryEntry { name : "stripe_right" , data : b"\n\x08\0\x08asset_id\0\x16minecraft:stripe_right\x08\0\x0Ftranslation_key\0#block.minecraft.banner.stripe_right\0" } , StaticRegistryEntry { name : "stripe_top" , data : b"\n\x08\0\x08asset_id\0\x14minecraft:stripe_top\x08\0\x0Ftranslation_key\0!block.minecraft.banner.stripe_top\0" } , StaticRegistryEntry { name : "triangle_bottom" , data : b"\n\x08\0\x08asset_id\0\x19minecraft:triangle_bottom\x08\0\x0Ftranslation_key\0&block.minecraft.banner.triangle_bottom\0" } , StaticRegistryEntry { name : "triangle_top" , data : b"\n\x08\0\x08asset_id\0\x16minecraft:triangle_top\x08\0\x0Ftranslation_key\0#block.minecraft.banner.triangle_top\0" } , StaticRegistryEntry { name : "triangles_bottom" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:triangles_bottom\x08\0\x0Ftranslation_key\0'block.minecraft.banner.triangles_bottom\0" } , StaticRegistryEntry { name : "triangles_top" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:triangles_top\x08\0\x0Ftranslation_key\0$block.minecraft.banner.triangles_top\0" }] , } , StaticRegistry { registry_id : "enchantment" , entries : & [StaticRegistryEntry { name : "aqua_affinity" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0#enchantment.minecraft.aqua_affinity\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base@\x10\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x10\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0 minecraft:submerged_mining_speed\x08\0\x02id\0#minecraft:enchantment.aqua_affinity\x08\0\toperation\0\x14add_multiplied_total\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0)\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x04head\x08\0\x0Fsupported_items\0!#minecraft:enchantable/head_armor\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "bane_of_arthropods" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0(enchantment.minecraft.bane_of_arthropods\0\n\0\x07effects\t\0\x10minecraft:damage\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\x04\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x04\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\x08\0\x04type\0*#minecraft:sensitive_to_bane_of_arthropods\0\0\0\t\0\x15minecraft:post_attack\n\0\0\0\x01\x08\0\x08affected\0\x06victim\n\0\x06effect\x06\0\rmax_amplifier@\x08\0\0\0\0\0\0\n\0\x0Cmax_duration\x06\0\x04base?\xF8\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xE0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x06\0\rmin_amplifier@\x08\0\0\0\0\0\0\x06\0\x0Cmin_duration?\xF8\0\0\0\0\0\0\x08\0\x08to_apply\0\x12minecraft:slowness\x08\0\x04type\0\x1Aminecraft:apply_mob_effect\0\x08\0\tenchanted\0\x08attacker\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\x08\0\x04type\0*#minecraft:sensitive_to_bane_of_arthropods\0\0\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\x01\0\tis_direct\x01\0\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x08\0\rprimary_items\0\x1C#minecraft:enchantable/sword\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1D#minecraft:enchantable/weapon\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "binding_curse" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0#enchantment.minecraft.binding_curse\0\n\0\x07effects\n\0\x1Eminecraft:prevent_armor_change\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0!#minecraft:enchantable/equippable\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "blast_protection" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0&enchantment.minecraft.blast_protection\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base?\xC3333333\x06\0\x15per_level_above_first?\xC3333333\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0(minecraft:explosion_knockback_resistance\x08\0\x02id\0&minecraft:enchantment.blast_protection\x08\0\toperation\0\tadd_value\0\t\0\x1Bminecraft:damage_protection\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first@\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x02\x01\0\x08expected\x01\x08\0\x02id\0\x16minecraft:is_explosion\0\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/armor\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\r\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/armor\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "breach" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cenchantment.minecraft.breach\0\n\0\x07effects\t\0\x1Dminecraft:armor_effectiveness\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base\xBF\xC3333333\x06\0\x15per_level_above_first\xBF\xC3333333\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1B#minecraft:enchantable/mace\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "channeling" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.channeling\0\n\0\x07effects\t\0\x13minecraft:hit_block\n\0\0\0\x01\n\0\x06effect\t\0\x07effects\n\0\0\0\x02\x08\0\x06entity\0\x18minecraft:lightning_bolt\x08\0\x04type\0\x17minecraft:summon_entity\0\x06\0\x05pitch?\xF0\0\0\0\0\0\0\x08\0\x05sound\0\x1Eminecraft:item.trident.thunder\x08\0\x04type\0\x14minecraft:play_sound\x06\0\x06volume@\x14\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:all_of\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x03\x08\0\tcondition\0\x17minecraft:weather_check\x01\0\nthundering\x01\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\x08\0\x04type\0\x11minecraft:trident\0\0\x08\0\tcondition\0\x18minecraft:location_check\n\0\tpredicate\n\0\x05block\x08\0\x06blocks\0\x19#minecraft:lightning_rods\0\x01\0\x0Bcan_see_sky\x01\0\0\0\0\t\0\x15minecraft:post_attack\n\0\0\0\x01\x08\0\x08affected\0\x06victim\n\0\x06effect\t\0\x07effects\n\0\0\0\x02\x08\0\x06entity\0\x18minecraft:lightning_bolt\x08\0\x04type\0\x17minecraft:summon_entity\0\x06\0\x05pitch?\xF0\0\0\0\0\0\0\x08\0\x05sound\0\x1Eminecraft:item.trident.thunder\x08\0\x04type\0\x14minecraft:play_sound\x06\0\x06volume@\x14\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:all_of\0\x08\0\tenchanted\0\x08attacker\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x03\x08\0\tcondition\0\x17minecraft:weather_check\x01\0\nthundering\x01\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x08location\x01\0\x0Bcan_see_sky\x01\0\0\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x0Fdirect_attacker\n\0\tpredicate\x08\0\x04type\0\x11minecraft:trident\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/trident\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "density" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.density\0\n\0\x07effects\t\0'minecraft:smash_damage_per_fallen_block\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xE0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xE0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1B#minecraft:enchantable/mace\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "depth_strider" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0#enchantment.minecraft.depth_strider\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base?\xD5UU\\}\xDAK\x06\0\x15per_level_above_first?\xD5UU\\}\xDAK\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0#minecraft:water_movement_efficiency\x08\0\x02id\0#minecraft:enchantment.depth_strider\x08\0\toperation\0\tadd_value\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/boots\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x04feet\x08\0\x0Fsupported_items\0!#minecraft:enchantable/foot_armor\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "efficiency" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.efficiency\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x05added?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x18minecraft:levels_squared\0\x08\0\tattribute\0\x1Bminecraft:mining_efficiency\x08\0\x02id\0 minecraft:enchantment.efficiency\x08\0\toperation\0\tadd_value\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x003\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1D#minecraft:enchantable/mining\x04\0\x06weight\0\0\0\0\0\0\0\n\0" } , StaticRegistryEntry { name : "feather_falling" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0%enchantment.minecraft.feather_falling\0\n\0\x07effects\t\0\x1Bminecraft:damage_protection\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\x08\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x08\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x02\x01\0\x08expected\x01\x08\0\x02id\0\x11minecraft:is_fall\0\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x0B\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x06\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x06\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0!#minecraft:enchantable/foot_armor\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "fire_aspect" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0!enchantment.minecraft.fire_aspect\0\n\0\x07effects\t\0\x15minecraft:post_attack\n\0\0\0\x01\x08\0\x08affected\0\x06victim\n\0\x06effect\n\0\x08duration\x06\0\x04base@\x10\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x10\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x10minecraft:ignite\0\x08\0\tenchanted\0\x08attacker\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\x01\0\tis_direct\x01\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0<\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x04\0\tmax_level\0\0\0\0\0\0\0\x02\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x08\0\rprimary_items\0\x1C#minecraft:enchantable/sword\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\"#minecraft:enchantable/fire_aspect\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "fire_protection" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0%enchantment.minecraft.fire_protection\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base\xBF\xC3333333\x06\0\x15per_level_above_first\xBF\xC3333333\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0\x16minecraft:burning_time\x08\0\x02id\0%minecraft:enchantment.fire_protection\x08\0\toperation\0\x13add_multiplied_base\0\t\0\x1Bminecraft:damage_protection\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first@\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x01\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x02\x01\0\x08expected\x01\x08\0\x02id\0\x11minecraft:is_fire\0\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/armor\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x12\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/armor\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "flame" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Benchantment.minecraft.flame\0\n\0\x07effects\t\0\x1Cminecraft:projectile_spawned\n\0\0\0\x01\n\0\x06effect\x06\0\x08duration@Y\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:ignite\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x14\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1A#minecraft:enchantable/bow\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "fortune" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.fortune\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/mining\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\"#minecraft:enchantable/mining_loot\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "frost_walker" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\"enchantment.minecraft.frost_walker\0\n\0\x07effects\t\0\x19minecraft:damage_immunity\n\0\0\0\x01\n\0\x06effect\0\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x02\x01\0\x08expected\x01\x08\0\x02id\0\x1Cminecraft:burn_from_stepping\0\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\t\0\x1Aminecraft:location_changed\n\0\0\0\x01\n\0\x06effect\n\0\x0Bblock_state\n\0\x05state\x08\0\x04Name\0\x15minecraft:frosted_ice\n\0\nProperties\x08\0\x03age\0\x010\0\0\x08\0\x04type\0\x1Fminecraft:simple_state_provider\0\x06\0\x06height?\xF0\0\0\0\0\0\0\t\0\x06offset\x04\0\0\0\x03\0\0\0\0\0\0\0\0\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\0\0\0\0\0\0\0\0\n\0\tpredicate\t\0\npredicates\n\0\0\0\x04\t\0\x06offset\x04\0\0\0\x03\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x08\0\x03tag\0\rminecraft:air\x08\0\x04type\0\x1Cminecraft:matching_block_tag\0\x08\0\x06blocks\0\x0Fminecraft:water\x08\0\x04type\0\x19minecraft:matching_blocks\0\x08\0\x06fluids\0\x0Fminecraft:water\x08\0\x04type\0\x19minecraft:matching_fluids\0\x08\0\x04type\0\x16minecraft:unobstructed\0\x08\0\x04type\0\x10minecraft:all_of\0\n\0\x06radius\x06\0\x03max@0\0\0\0\0\0\0\x06\0\x03min\0\0\0\0\0\0\0\0\x08\0\x04type\0\x11minecraft:clamped\n\0\x05value\x06\0\x04base@\x08\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\x08\0\x12trigger_game_event\0\x15minecraft:block_place\x08\0\x04type\0\x16minecraft:replace_disk\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\x0Cis_on_ground\x01\0\0\0\x08\0\tcondition\0\x12minecraft:inverted\n\0\x04term\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x07vehicle\0\0\0\0\0\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/boots\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x02\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x04feet\x08\0\x0Fsupported_items\0!#minecraft:enchantable/foot_armor\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "impaling" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Eenchantment.minecraft.impaling\0\n\0\x07effects\t\0\x10minecraft:damage\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\x04\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x04\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\x08\0\x04type\0 #minecraft:sensitive_to_impaling\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x15\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/trident\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "infinity" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0\x1Eenchantment.minecraft.infinity\0\n\0\x07effects\t\0\x12minecraft:ammo_use\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:set\x06\0\x05value\0\0\0\0\0\0\0\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x14minecraft:match_tool\n\0\tpredicate\x08\0\x05items\0\x0Fminecraft:arrow\0\0\0\0\x08\0\rexclusive_set\0\x1C#minecraft:exclusive_set/bow\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x14\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1A#minecraft:enchantable/bow\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "knockback" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Fenchantment.minecraft.knockback\0\n\0\x07effects\t\0\x13minecraft:knockback\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x007\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x04\0\tmax_level\0\0\0\0\0\0\0\x02\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/sword\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "looting" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.looting\0\n\0\x07effects\t\0\x19minecraft:equipment_drops\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\x84z\xE1G\xAE\x14{\x06\0\x15per_level_above_first?\x84z\xE1G\xAE\x14{\x08\0\x04type\0\x10minecraft:linear\0\0\x08\0\tenchanted\0\x08attacker\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x08attacker\n\0\tpredicate\x08\0\x04type\0\x10minecraft:player\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/sword\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "loyalty" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.loyalty\0\n\0\x07effects\t\0%minecraft:trident_return_acceleration\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0C\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x07\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/trident\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "luck_of_the_sea" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0%enchantment.minecraft.luck_of_the_sea\0\n\0\x07effects\t\0\x1Cminecraft:fishing_luck_bonus\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/fishing\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "lure" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Aenchantment.minecraft.lure\0\n\0\x07effects\t\0 minecraft:fishing_time_reduction\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\x14\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x14\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/fishing\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "mending" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.mending\0\n\0\x07effects\t\0\x18minecraft:repair_with_xp\n\0\0\0\x01\n\0\x06effect\x06\0\x06factor@\0\0\0\0\0\0\0\x08\0\x04type\0\x12minecraft:multiply\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0K\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x19\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x19\0\t\0\x05slots\x08\0\0\0\x01\0\x03any\x08\0\x0Fsupported_items\0!#minecraft:enchantable/durability\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "multishot" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Fenchantment.minecraft.multishot\0\n\0\x07effects\t\0\x1Aminecraft:projectile_count\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first@\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\t\0\x1Bminecraft:projectile_spread\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@$\0\0\0\0\0\0\x06\0\x15per_level_above_first@$\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\x08\0\rexclusive_set\0!#minecraft:exclusive_set/crossbow\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x14\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1F#minecraft:enchantable/crossbow\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "piercing" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0\x1Eenchantment.minecraft.piercing\0\n\0\x07effects\t\0\x1Dminecraft:projectile_piercing\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\x08\0\rexclusive_set\0!#minecraft:exclusive_set/crossbow\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1F#minecraft:enchantable/crossbow\x04\0\x06weight\0\0\0\0\0\0\0\n\0" } , StaticRegistryEntry { name : "power" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0\x1Benchantment.minecraft.power\0\n\0\x07effects\t\0\x10minecraft:damage\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xE0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x0Fdirect_attacker\n\0\tpredicate\x08\0\x04type\0\x11#minecraft:arrows\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x10\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1A#minecraft:enchantable/bow\x04\0\x06weight\0\0\0\0\0\0\0\n\0" } , StaticRegistryEntry { name : "projectile_protection" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0+enchantment.minecraft.projectile_protection\0\n\0\x07effects\t\0\x1Bminecraft:damage_protection\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first@\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x02\x01\0\x08expected\x01\x08\0\x02id\0\x17minecraft:is_projectile\0\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/armor\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\t\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x06\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x03\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x06\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/armor\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "protection" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.protection\0\n\0\x07effects\t\0\x1Bminecraft:damage_protection\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x01\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/armor\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x0C\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x0B\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x0B\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/armor\x04\0\x06weight\0\0\0\0\0\0\0\n\0" } , StaticRegistryEntry { name : "punch" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Benchantment.minecraft.punch\0\n\0\x07effects\t\0\x13minecraft:knockback\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x0Fdirect_attacker\n\0\tpredicate\x08\0\x04type\0\x11#minecraft:arrows\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0%\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x04\0\tmax_level\0\0\0\0\0\0\0\x02\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0C\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1A#minecraft:enchantable/bow\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "quick_charge" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\"enchantment.minecraft.quick_charge\0\n\0\x07effects\n\0\x1Eminecraft:crossbow_charge_time\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base\xBF\xD0\0\0\0\0\0\0\x06\0\x15per_level_above_first\xBF\xD0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\t\0\"minecraft:crossbow_charging_sounds\n\0\0\0\x03\x08\0\x03end\0#minecraft:item.crossbow.loading_end\x08\0\x05start\0&minecraft:item.crossbow.quick_charge_1\0\x08\0\x03end\0#minecraft:item.crossbow.loading_end\x08\0\x05start\0&minecraft:item.crossbow.quick_charge_2\0\x08\0\x03end\0#minecraft:item.crossbow.loading_end\x08\0\x05start\0&minecraft:item.crossbow.quick_charge_3\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0C\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\t\0\x05slots\x08\0\0\0\x02\0\x08mainhand\0\x07offhand\x08\0\x0Fsupported_items\0\x1F#minecraft:enchantable/crossbow\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "respiration" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0!enchantment.minecraft.respiration\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0\x16minecraft:oxygen_bonus\x08\0\x02id\0!minecraft:enchantment.respiration\x08\0\toperation\0\tadd_value\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0(\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x04head\x08\0\x0Fsupported_items\0!#minecraft:enchantable/head_armor\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "riptide" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.riptide\0\n\0\x07effects\t\0\x17minecraft:trident_sound\x08\0\0\0\x03\0 minecraft:item.trident.riptide_1\0 minecraft:item.trident.riptide_2\0 minecraft:item.trident.riptide_3\n\0&minecraft:trident_spin_attack_strength\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF8\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xE8\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\x08\0\rexclusive_set\0 #minecraft:exclusive_set/riptide\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x11\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x07\0\t\0\x05slots\x08\0\0\0\x01\0\x04hand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/trident\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "sharpness" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0\x1Fenchantment.minecraft.sharpness\0\n\0\x07effects\t\0\x10minecraft:damage\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xE0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x15\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x0B\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x0B\0\x08\0\rprimary_items\0\x1C#minecraft:enchantable/sword\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0##minecraft:enchantable/sharp_weapon\x04\0\x06weight\0\0\0\0\0\0\0\n\0" } , StaticRegistryEntry { name : "silk_touch" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.silk_touch\0\n\0\x07effects\t\0\x1Aminecraft:block_experience\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:set\x06\0\x05value\0\0\0\0\0\0\0\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/mining\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\"#minecraft:enchantable/mining_loot\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "smite" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Benchantment.minecraft.smite\0\n\0\x07effects\t\0\x10minecraft:damage\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\x04\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x04\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\x08\0\x04type\0\x1D#minecraft:sensitive_to_smite\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x08\0\rprimary_items\0\x1C#minecraft:enchantable/sword\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1D#minecraft:enchantable/weapon\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "soul_speed" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.soul_speed\0\n\0\x07effects\t\0\x1Aminecraft:location_changed\n\0\0\0\x02\n\0\x06effect\t\0\x07effects\n\0\0\0\x02\n\0\x06amount\x06\0\x04base?\xA4\xBCj~\xF9\xDB#\x06\0\x15per_level_above_first?\x85\x81\x06$\xDD/\x1B\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0\x18minecraft:movement_speed\x08\0\x02id\0 minecraft:enchantment.soul_speed\x08\0\toperation\0\tadd_value\x08\0\x04type\0\x13minecraft:attribute\0\x06\0\x06amount?\xF0\0\0\0\0\0\0\x08\0\tattribute\0\x1Dminecraft:movement_efficiency\x08\0\x02id\0 minecraft:enchantment.soul_speed\x08\0\toperation\0\tadd_value\x08\0\x04type\0\x13minecraft:attribute\0\x08\0\x04type\0\x10minecraft:all_of\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\x08\0\tcondition\0\x12minecraft:inverted\n\0\x04term\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x07vehicle\0\0\0\0\x08\0\tcondition\0\x10minecraft:any_of\t\0\x05terms\n\0\0\0\x02\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x03\x01\0\x06active\x01\x08\0\tcondition\0\"minecraft:enchantment_active_check\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\tis_flying\0\0\0\0\x08\0\tcondition\0\x10minecraft:any_of\t\0\x05terms\n\0\0\0\x02\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x14movement_affected_by\n\0\x05block\x08\0\x06blocks\0\x1C#minecraft:soul_speed_blocks\0\0\0\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\x0Cis_on_ground\0\0\0\0\0\0\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\x01\0\x06active\0\x08\0\tcondition\0\"minecraft:enchantment_active_check\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\tis_flying\0\0\n\0\x14movement_affected_by\n\0\x05block\x08\0\x06blocks\0\x1C#minecraft:soul_speed_blocks\0\0\0\0\0\0\0\0\n\0\x06effect\x06\0\x06amount?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x1Cminecraft:change_item_damage\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\n\0\x06chance\x06\0\x06amount?\xA4z\xE1G\xAE\x14{\x08\0\x04type\0\x1Bminecraft:enchantment_level\0\x08\0\tcondition\0\x17minecraft:random_chance\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\x0Cis_on_ground\x01\0\n\0\x14movement_affected_by\n\0\x05block\x08\0\x06blocks\0\x1C#minecraft:soul_speed_blocks\0\0\0\0\0\0\t\0\x0Eminecraft:tick\n\0\0\0\x02\n\0\x06effect\n\0\x13horizontal_position\x08\0\x04type\0\x0Fin_bounding_box\0\n\0\x13horizontal_velocity\x06\0\x0Emovement_scale\xBF\xC9\x99\x99\x99\x99\x99\x9A\0\n\0\x08particle\x08\0\x04type\0\x0Eminecraft:soul\0\x06\0\x05speed?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x19minecraft:spawn_particles\n\0\x11vertical_position\x06\0\x06offset?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\x04type\0\x0Fentity_position\0\n\0\x11vertical_velocity\x06\0\x04base?\xB9\x99\x99\x99\x99\x99\x9A\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\tis_flying\0\x01\0\x0Cis_on_ground\x01\0\n\0\x08movement\n\0\x10horizontal_speed\x06\0\x03min>\xE4\xF8\xB5\x80\0\0\0\0\0\n\0\x14movement_affected_by\n\0\x05block\x08\0\x06blocks\0\x1C#minecraft:soul_speed_blocks\0\0\x04\0\rperiodic_tick\0\0\0\0\0\0\0\x05\0\0\0\n\0\x06effect\n\0\x05pitch\x06\0\rmax_exclusive?\xF0\0\0\0\0\0\0\x06\0\rmin_inclusive?\xE3333333\x08\0\x04type\0\x11minecraft:uniform\0\x08\0\x05sound\0\x1Eminecraft:particle.soul_escape\x08\0\x04type\0\x14minecraft:play_sound\x06\0\x06volume?\xE3333333\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\x06\0\x06chance?\xD6ffffff\x08\0\tcondition\0\x17minecraft:random_chance\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\tis_flying\0\x01\0\x0Cis_on_ground\x01\0\n\0\x08movement\n\0\x10horizontal_speed\x06\0\x03min>\xE4\xF8\xB5\x80\0\0\0\0\0\n\0\x14movement_affected_by\n\0\x05block\x08\0\x06blocks\0\x1C#minecraft:soul_speed_blocks\0\0\x04\0\rperiodic_tick\0\0\0\0\0\0\0\x05\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x04feet\x08\0\x0Fsupported_items\0!#minecraft:enchantable/foot_armor\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "sweeping_edge" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0#enchantment.minecraft.sweeping_edge\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\n\0\x0Bdenominator\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\n\0\tnumerator\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x12minecraft:fraction\0\x08\0\tattribute\0\x1Fminecraft:sweeping_damage_ratio\x08\0\x02id\0#minecraft:enchantment.sweeping_edge\x08\0\toperation\0\tadd_value\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x14\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/sword\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "swift_sneak" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0!enchantment.minecraft.swift_sneak\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base?\xC3333333\x06\0\x15per_level_above_first?\xC3333333\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0\x18minecraft:sneaking_speed\x08\0\x02id\0!minecraft:enchantment.swift_sneak\x08\0\toperation\0\tadd_value\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0K\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x19\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x19\0\t\0\x05slots\x08\0\0\0\x01\0\x04legs\x08\0\x0Fsupported_items\0 #minecraft:enchantable/leg_armor\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "thorns" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cenchantment.minecraft.thorns\0\n\0\x07effects\t\0\x15minecraft:post_attack\n\0\0\0\x01\x08\0\x08affected\0\x08attacker\n\0\x06effect\t\0\x07effects\n\0\0\0\x02\x08\0\x0Bdamage_type\0\x10minecraft:thorns\x06\0\nmax_damage@\x14\0\0\0\0\0\0\x06\0\nmin_damage?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x17minecraft:damage_entity\0\x06\0\x06amount@\0\0\0\0\0\0\0\x08\0\x04type\0\x1Cminecraft:change_item_damage\0\x08\0\x04type\0\x10minecraft:all_of\0\x08\0\tenchanted\0\x06victim\n\0\x0Crequirements\n\0\x06chance\n\0\x06amount\x06\0\x04base?\xC3333333\x06\0\x15per_level_above_first?\xC3333333\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x1Bminecraft:enchantment_level\0\x08\0\tcondition\0\x17minecraft:random_chance\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0<\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x08\0\rprimary_items\0\"#minecraft:enchantable/chest_armor\t\0\x05slots\x08\0\0\0\x01\0\x03any\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/armor\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "unbreaking" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.unbreaking\0\n\0\x07effects\t\0\x15minecraft:item_damage\n\0\0\0\x02\n\0\x06effect\n\0\x06chance\n\0\x0Bdenominator\x06\0\x04base@$\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x14\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\n\0\tnumerator\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first@\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x12minecraft:fraction\0\x08\0\x04type\0\x19minecraft:remove_binomial\0\n\0\x0Crequirements\x08\0\tcondition\0\x14minecraft:match_tool\n\0\tpredicate\x08\0\x05items\0\x1C#minecraft:enchantable/armor\0\0\0\n\0\x06effect\n\0\x06chance\n\0\x0Bdenominator\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\n\0\tnumerator\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x12minecraft:fraction\0\x08\0\x04type\0\x19minecraft:remove_binomial\0\n\0\x0Crequirements\x08\0\tcondition\0\x12minecraft:inverted\n\0\x04term\x08\0\tcondition\0\x14minecraft:match_tool\n\0\tpredicate\x08\0\x05items\0\x1C#minecraft:enchantable/armor\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x007\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x03any\x08\0\x0Fsupported_items\0!#minecraft:enchantable/durability\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "vanishing_curse" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0%enchantment.minecraft.vanishing_curse\0\n\0\x07effects\n\0 minecraft:prevent_equipment_drop\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x03any\x08\0\x0Fsupported_items\0 #minecraft:enchantable/vanishing\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "wind_burst" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.wind_burst\0\n\0\x07effects\t\0\x15minecraft:post_attack\n\0\0\0\x01\x08\0\x08affected\0\x08attacker\n\0\x06effect\x08\0\x11block_interaction\0\x07trigger\x08\0\rimmune_blocks\0(#minecraft:blocks_wind_charge_explosions\n\0\x14knockback_multiplier\n\0\x08fallback\x06\0\x04base?\xF8\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xD6ffffff\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x10minecraft:lookup\t\0\x06values\x06\0\0\0\x03?\xF3333333?\xFC\0\0\0\0\0\0@\x01\x99\x99\x99\x99\x99\x9A\0\n\0\x0Elarge_particle\x08\0\x04type\0\x1Cminecraft:gust_emitter_large\0\x06\0\x06radius@\x0C\0\0\0\0\0\0\n\0\x0Esmall_particle\x08\0\x04type\0\x1Cminecraft:gust_emitter_small\0\x08\0\x05sound\0'minecraft:entity.wind_charge.wind_burst\x08\0\x04type\0\x11minecraft:explode\0\x08\0\tenchanted\0\x08attacker\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x0Fdirect_attacker\n\0\tpredicate\n\0\x05flags\x01\0\tis_flying\0\0\n\0\x08movement\n\0\rfall_distance\x06\0\x03min?\xF8\0\0\0\0\0\0\0\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1B#minecraft:enchantable/mace\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" }] , } , StaticRegistry { registry_id : "jukebox_song" , entries : & [StaticRegistryEntry { name : "11" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0B\n\0\x0Bdescription\x08\0\ttranslate\0\x19jukebox_song.minecraft.11\0\x06\0\x11length_in_seconds@Q\xC0\0\0\0\0\0\x08\0\x0Bsound_event\0\x17minecraft:music_disc.11\0" } , StaticRegistryEntry { name : "13" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0\x19jukebox_song.minecraft.13\0\x06\0\x11length_in_seconds@f@\0\0\0\0\0\x08\0\x0Bsound_event\0\x17minecraft:music_disc.13\0" } , StaticRegistryEntry { name : "5" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0F\n\0\x0Bdescription\x08\0\ttranslate\0\x18jukebox_song.minecraft.5\0\x06\0\x11length_in_seconds@f@\0\0\0\0\0\x08\0\x0Bsound_event\0\x16minecraft:music_disc.5\0" } , StaticRegistryEntry { name : "blocks" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x03\n\0\x0Bdescription\x08\0\ttranslate\0\x1Djukebox_song.minecraft.blocks\0\x06\0\x11length_in_seconds@u\x90\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Bminecraft:music_disc.blocks\0" } , StaticRegistryEntry { name : "cat" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ajukebox_song.minecraft.cat\0\x06\0\x11length_in_seconds@g \0\0\0\0\0\x08\0\x0Bsound_event\0\x18minecraft:music_disc.cat\0" } , StaticRegistryEntry { name : "chirp" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cjukebox_song.minecraft.chirp\0\x06\0\x11length_in_seconds@g \0\0\0\0\0\x08\0\x0Bsound_event\0\x1Aminecraft:music_disc.chirp\0" } , StaticRegistryEntry { name : "creator" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0C\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ejukebox_song.minecraft.creator\0\x06\0\x11length_in_seconds@f\0\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Cminecraft:music_disc.creator\0" } , StaticRegistryEntry { name : "creator_music_box" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0B\n\0\x0Bdescription\x08\0\ttranslate\0(jukebox_song.minecraft.creator_music_box\0\x06\0\x11length_in_seconds@R@\0\0\0\0\0\x08\0\x0Bsound_event\0&minecraft:music_disc.creator_music_box\0" } , StaticRegistryEntry { name : "far" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x05\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ajukebox_song.minecraft.far\0\x06\0\x11length_in_seconds@e\xC0\0\0\0\0\0\x08\0\x0Bsound_event\0\x18minecraft:music_disc.far\0" } , StaticRegistryEntry { name : "lava_chicken" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\t\n\0\x0Bdescription\x08\0\ttranslate\0#jukebox_song.minecraft.lava_chicken\0\x06\0\x11length_in_seconds@`\xC0\0\0\0\0\0\x08\0\x0Bsound_event\0!minecraft:music_disc.lava_chicken\0" } , StaticRegistryEntry { name : "mall" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x06\n\0\x0Bdescription\x08\0\ttranslate\0\x1Bjukebox_song.minecraft.mall\0\x06\0\x11length_in_seconds@h\xA0\0\0\0\0\0\x08\0\x0Bsound_event\0\x19minecraft:music_disc.mall\0" } , StaticRegistryEntry { name : "mellohi" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x07\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ejukebox_song.minecraft.mellohi\0\x06\0\x11length_in_seconds@X\0\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Cminecraft:music_disc.mellohi\0" } , StaticRegistryEntry { name : "otherside" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0E\n\0\x0Bdescription\x08\0\ttranslate\0 jukebox_song.minecraft.otherside\0\x06\0\x11length_in_seconds@h`\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Eminecraft:music_disc.otherside\0" } , StaticRegistryEntry { name : "pigstep" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\r\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ejukebox_song.minecraft.pigstep\0\x06\0\x11length_in_seconds@b\xA0\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Cminecraft:music_disc.pigstep\0" } , StaticRegistryEntry { name : "precipice" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\r\n\0\x0Bdescription\x08\0\ttranslate\0 jukebox_song.minecraft.precipice\0\x06\0\x11length_in_seconds@r\xB0\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Eminecraft:music_disc.precipice\0" } , StaticRegistryEntry { name : "relic" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0E\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cjukebox_song.minecraft.relic\0\x06\0\x11length_in_seconds@k@\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Aminecraft:music_disc.relic\0" } , StaticRegistryEntry { name : "stal" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0\x1Bjukebox_song.minecraft.stal\0\x06\0\x11length_in_seconds@b\xC0\0\0\0\0\0\x08\0\x0Bsound_event\0\x19minecraft:music_disc.stal\0" } , StaticRegistryEntry { name : "strad" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\t\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cjukebox_song.minecraft.strad\0\x06\0\x11length_in_seconds@g\x80\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Aminecraft:music_disc.strad\0" } , StaticRegistryEntry { name : "tears" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\n\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cjukebox_song.minecraft.tears\0\x06\0\x11length_in_seconds@e\xE0\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Aminecraft:music_disc.tears\0" } , StaticRegistryEntry { name : "wait" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0C\n\0\x0Bdescription\x08\0\ttranslate\0\x1Bjukebox_song.minecraft.wait\0\x06\0\x11length_in_seconds@m\xC0\0\0\0\0\0\x08\0\x0Bsound_event\0\x19minecraft:music_disc.wait\0" } , StaticRegistryEntry { name : "ward" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\n\n\0\x0Bdescription\x08\0\ttranslate\0\x1Bjukebox_song.minecraft.ward\0\x06\0\x11length_in_seconds@o`\0\0\0\0\0\x08\0\x0Bsound_event\0\x19minecraft:music_disc.ward\0" }] , } , StaticRegistry { registry_id : "instrument" , entries : & [StaticRegistryEntry { name : "admire_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0%instrument.minecraft.admire_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.4\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "call_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0#instrument.minecraft.call_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.5\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "dream_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0$instrument.minecraft.dream_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.7\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "feel_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0#instrument.minecraft.feel_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.3\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "ponder_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0%instrument.minecraft.ponder_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.0\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "seek_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0#instrument.minecraft.seek_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.2\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "sing_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0#instrument.minecraft.sing_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.1\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "yearn_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0$instrument.minecraft.yearn_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.6\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" }] , } , StaticRegistry { registry_id : "test_environment" , entries : & [StaticRegistryEntry { name : "default" , data : b"\n\t\0\x0Bdefinitions\0\0\0\0\0\x08\0\x04type\0\x10minecraft:all_of\0" }] , } , StaticRegistry { registry_id : "test_instance" , entries : & [StaticRegistryEntry { name : "always_pass" , data : b"\n\x08\0\x0Benvironment\0\x11minecraft:default\x08\0\x08function\0\x15minecraft:always_pass\x04\0\tmax_ticks\0\0\0\0\0\0\0\x01\x01\0\x08required\0\x04\0\x0Bsetup_ticks\0\0\0\0\0\0\0\x01\x08\0\tstructure\0\x0Fminecraft:empty\x08\0\x04type\0\x12minecraft:function\0" }] , } , StaticRegistry { registry_id : "dialog" , entries : & [StaticRegistryEntry { name : "custom_options" , data : b"\n\x04\0\x0Cbutton_width\0\0\0\0\0\0\x016\x04\0\x07columns\0\0\0\0\0\0\0\x01\x08\0\x07dialogs\0!#minecraft:pause_screen_additions\n\0\x0Bexit_action\n\0\x05label\x08\0\ttranslate\0\x08gui.back\0\x04\0\x05width\0\0\0\0\0\0\0\xC8\0\n\0\x0Eexternal_title\x08\0\ttranslate\0\x13menu.custom_options\0\n\0\x05title\x08\0\ttranslate\0\x19menu.custom_options.title\0\x08\0\x04type\0\x15minecraft:dialog_list\0" } , StaticRegistryEntry { name : "quick_actions" , data : b"\n\x04\0\x0Cbutton_width\0\0\0\0\0\0\x016\x04\0\x07columns\0\0\0\0\0\0\0\x01\x08\0\x07dialogs\0\x18#minecraft:quick_actions\n\0\x0Bexit_action\n\0\x05label\x08\0\ttranslate\0\x08gui.back\0\x04\0\x05width\0\0\0\0\0\0\0\xC8\0\n\0\x0Eexternal_title\x08\0\ttranslate\0\x12menu.quick_actions\0\n\0\x05title\x08\0\ttranslate\0\x18menu.quick_actions.title\0\x08\0\x04type\0\x15minecraft:dialog_list\0" } , StaticRegistryEntry { name : "server_links" , data : b"\n\x04\0\x0Cbutton_width\0\0\0\0\0\0\x016\x04\0\x07columns\0\0\0\0\0\0\0\x01\n\0\x0Bexit_action\n\0\x05label\x08\0\ttranslate\0\x08gui.back\0\x04\0\x05width\0\0\0\0\0\0\0\xC8\0\n\0\x0Eexternal_title\x08\0\ttranslate\0\x11menu.server_links\0\n\0\x05title\x08\0\ttranslate\0\x17menu.server_links.title\0\x08\0\x04type\0\x16minecraft:server_links\0" }] , }] ;
 pub static REGISTRIES_1_21_11 : & [StaticRegistry] = & [StaticRegistry { registry_id : "worldgen/biome" , entries : & [StaticRegistryEntry { name : "badlands" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\"minecraft:music.overworld.badlands\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#6eb1ff\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\rfoliage_color\0\x07#9e814d\x08\0\x0Bgrass_color\0\x07#90814d\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "bamboo_jungle" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0'minecraft:music.overworld.bamboo_jungle\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#77a8ff\0\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xEEffffff\0" } , StaticRegistryEntry { name : "basalt_deltas" , data : b"\n\n\0\nattributes\n\0\x1Eminecraft:audio/ambient_sounds\n\0\tadditions\x08\0\x05sound\0)minecraft:ambient.basalt_deltas.additions\x06\0\x0Btick_chance?\x86\xBB\x98\xC7\xE2\x82A\0\x08\0\x04loop\0$minecraft:ambient.basalt_deltas.loop\n\0\x04mood\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0$minecraft:ambient.basalt_deltas.mood\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\0\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0$minecraft:music.nether.basalt_deltas\0\0\t\0\"minecraft:visual/ambient_particles\n\0\0\0\x01\n\0\x08particle\x08\0\x04type\0\x13minecraft:white_ash\0\x06\0\x0Bprobability?\xBE;]_g\xD5\xB8\0\x08\0\x1Aminecraft:visual/fog_color\0\x07#685f70\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "beach" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#78a7ff\0\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "birch_forest" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0 minecraft:music.overworld.forest\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7aa5ff\0\x06\0\x08downfall?\xE3333333\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE3333333\0" } , StaticRegistryEntry { name : "cherry_grove" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0&minecraft:music.overworld.cherry_grove\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\x08\0 minecraft:visual/water_fog_color\0\x07#5db7ef\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\rfoliage_color\0\x07#b6db61\x08\0\x0Bgrass_color\0\x07#b6db61\x08\0\x0Bwater_color\0\x07#5db7ef\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "cold_ocean" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\n\0\nunderwater\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Bminecraft:music.under_water\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3d57d6\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "crimson_forest" , data : b"\n\n\0\nattributes\n\0\x1Eminecraft:audio/ambient_sounds\n\0\tadditions\x08\0\x05sound\0*minecraft:ambient.crimson_forest.additions\x06\0\x0Btick_chance?\x86\xBB\x98\xC7\xE2\x82A\0\x08\0\x04loop\0%minecraft:ambient.crimson_forest.loop\n\0\x04mood\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0%minecraft:ambient.crimson_forest.mood\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\0\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0%minecraft:music.nether.crimson_forest\0\0\t\0\"minecraft:visual/ambient_particles\n\0\0\0\x01\n\0\x08particle\x08\0\x04type\0\x17minecraft:crimson_spore\0\x06\0\x0Bprobability?\x99\x99\x99\x99\x99\x99\x9A\0\x08\0\x1Aminecraft:visual/fog_color\0\x07#330303\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "dark_forest" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0 minecraft:music.overworld.forest\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#79a6ff\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x11dry_foliage_color\0\x07#7b5334\x08\0\x14grass_color_modifier\0\x0Bdark_forest\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE6ffffff\0" } , StaticRegistryEntry { name : "deep_cold_ocean" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\n\0\nunderwater\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Bminecraft:music.under_water\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3d57d6\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "deep_dark" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0#minecraft:music.overworld.deep_dark\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#78a7ff\0\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "deep_frozen_ocean" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3938c9\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\x08\0\x14temperature_modifier\0\x06frozen\0" } , StaticRegistryEntry { name : "deep_lukewarm_ocean" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\n\0\nunderwater\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Bminecraft:music.under_water\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\x08\0 minecraft:visual/water_fog_color\0\x07#041633\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#45adf2\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "deep_ocean" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\n\0\nunderwater\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Bminecraft:music.under_water\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "desert" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0 minecraft:music.overworld.desert\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#6eb1ff\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "dripstone_caves" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0)minecraft:music.overworld.dripstone_caves\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#78a7ff\0\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "end_barrens" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "end_highlands" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "end_midlands" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "eroded_badlands" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\"minecraft:music.overworld.badlands\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#6eb1ff\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\rfoliage_color\0\x07#9e814d\x08\0\x0Bgrass_color\0\x07#90814d\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "flower_forest" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0'minecraft:music.overworld.flower_forest\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#79a6ff\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE6ffffff\0" } , StaticRegistryEntry { name : "forest" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0 minecraft:music.overworld.forest\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#79a6ff\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE6ffffff\0" } , StaticRegistryEntry { name : "frozen_ocean" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7fa1ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3938c9\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\0\0\0\0\0\0\0\0\x08\0\x14temperature_modifier\0\x06frozen\0" } , StaticRegistryEntry { name : "frozen_peaks" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0&minecraft:music.overworld.frozen_peaks\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#859dff\0\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\xBF\xE6ffffff\0" } , StaticRegistryEntry { name : "frozen_river" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\n\0\nunderwater\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Bminecraft:music.under_water\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7fa1ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3938c9\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\0\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "grove" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Fminecraft:music.overworld.grove\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#81a0ff\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\xBF\xC9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "ice_spikes" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7fa1ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\0\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "jagged_peaks" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0&minecraft:music.overworld.jagged_peaks\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#859dff\0\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\xBF\xE6ffffff\0" } , StaticRegistryEntry { name : "jungle" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0 minecraft:music.overworld.jungle\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#77a8ff\0\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xEEffffff\0" } , StaticRegistryEntry { name : "lukewarm_ocean" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\n\0\nunderwater\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Bminecraft:music.under_water\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\x08\0 minecraft:visual/water_fog_color\0\x07#041633\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#45adf2\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "lush_caves" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0$minecraft:music.overworld.lush_caves\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "mangrove_swamp" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Fminecraft:music.overworld.swamp\0\0\x08\0\x1Aminecraft:visual/fog_color\0\x07#c0d8ff\x08\0\x1Aminecraft:visual/sky_color\0\x07#78a7ff\x08\0 minecraft:visual/water_fog_color\0\x07#4d7a60\n\0'minecraft:visual/water_fog_end_distance\x06\0\x08argument?\xEB333333\x08\0\x08modifier\0\x08multiply\0\0\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x08\0\x11dry_foliage_color\0\x07#7b5334\x08\0\rfoliage_color\0\x07#8db127\x08\0\x14grass_color_modifier\0\x05swamp\x08\0\x0Bwater_color\0\x07#3a7a6a\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "meadow" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0 minecraft:music.overworld.meadow\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#0e4ecf\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "mushroom_fields" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#77a8ff\0\x06\0\x08downfall?\xF0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\0" } , StaticRegistryEntry { name : "nether_wastes" , data : b"\n\n\0\nattributes\n\0\x1Eminecraft:audio/ambient_sounds\n\0\tadditions\x08\0\x05sound\0)minecraft:ambient.nether_wastes.additions\x06\0\x0Btick_chance?\x86\xBB\x98\xC7\xE2\x82A\0\x08\0\x04loop\0$minecraft:ambient.nether_wastes.loop\n\0\x04mood\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0$minecraft:ambient.nether_wastes.mood\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\0\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0$minecraft:music.nether.nether_wastes\0\0\x08\0\x1Aminecraft:visual/fog_color\0\x07#330808\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "ocean" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\n\0\nunderwater\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Bminecraft:music.under_water\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "old_growth_birch_forest" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0 minecraft:music.overworld.forest\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7aa5ff\0\x06\0\x08downfall?\xE3333333\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE3333333\0" } , StaticRegistryEntry { name : "old_growth_pine_taiga" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0*minecraft:music.overworld.old_growth_taiga\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ca3ff\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xD3333333\0" } , StaticRegistryEntry { name : "old_growth_spruce_taiga" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0*minecraft:music.overworld.old_growth_taiga\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7da3ff\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xD0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "pale_garden" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\0\x06\0\x1Cminecraft:audio/music_volume\0\0\0\0\0\0\0\0\x08\0\x1Aminecraft:visual/fog_color\0\x07#817770\x08\0\x1Aminecraft:visual/sky_color\0\x07#b9b9b9\x08\0 minecraft:visual/water_fog_color\0\x07#556980\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x11dry_foliage_color\0\x07#a0a69c\x08\0\rfoliage_color\0\x07#878d76\x08\0\x0Bgrass_color\0\x07#778272\x08\0\x0Bwater_color\0\x07#76889d\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE6ffffff\0" } , StaticRegistryEntry { name : "plains" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#78a7ff\0\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "river" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\n\0\nunderwater\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Bminecraft:music.under_water\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "savanna" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#6eb1ff\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "savanna_plateau" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#6eb1ff\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "small_end_islands" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "snowy_beach" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7fa1ff\0\x06\0\x08downfall?\xD3333333\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3d57d6\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xA9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "snowy_plains" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7fa1ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\0\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "snowy_slopes" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0&minecraft:music.overworld.snowy_slopes\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#829fff\0\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\xBF\xD3333333\0" } , StaticRegistryEntry { name : "snowy_taiga" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#839eff\0\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3d57d6\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature\xBF\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "soul_sand_valley" , data : b"\n\n\0\nattributes\n\0\x1Eminecraft:audio/ambient_sounds\n\0\tadditions\x08\0\x05sound\0,minecraft:ambient.soul_sand_valley.additions\x06\0\x0Btick_chance?\x86\xBB\x98\xC7\xE2\x82A\0\x08\0\x04loop\0'minecraft:ambient.soul_sand_valley.loop\n\0\x04mood\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0'minecraft:ambient.soul_sand_valley.mood\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\0\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0'minecraft:music.nether.soul_sand_valley\0\0\t\0\"minecraft:visual/ambient_particles\n\0\0\0\x01\n\0\x08particle\x08\0\x04type\0\rminecraft:ash\0\x06\0\x0Bprobability?y\x99\x99\x99\x99\x99\x9A\0\x08\0\x1Aminecraft:visual/fog_color\0\x07#1b4745\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "sparse_jungle" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0'minecraft:music.overworld.sparse_jungle\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#77a8ff\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xEEffffff\0" } , StaticRegistryEntry { name : "stony_peaks" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0%minecraft:music.overworld.stony_peaks\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#76a8ff\0\x06\0\x08downfall?\xD3333333\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xF0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "stony_shore" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7da2ff\0\x06\0\x08downfall?\xD3333333\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xC9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "sunflower_plains" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#78a7ff\0\x06\0\x08downfall?\xD9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "swamp" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Fminecraft:music.overworld.swamp\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#78a7ff\x08\0 minecraft:visual/water_fog_color\0\x07#232317\n\0'minecraft:visual/water_fog_end_distance\x06\0\x08argument?\xEB333333\x08\0\x08modifier\0\x08multiply\0\0\x06\0\x08downfall?\xEC\xCC\xCC\xCC\xCC\xCC\xCD\n\0\x07effects\x08\0\x11dry_foliage_color\0\x07#7b5334\x08\0\rfoliage_color\0\x07#6a7039\x08\0\x14grass_color_modifier\0\x05swamp\x08\0\x0Bwater_color\0\x07#617b64\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "taiga" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7da3ff\0\x06\0\x08downfall?\xE9\x99\x99\x99\x99\x99\x9A\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xD0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "the_end" , data : b"\n\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "the_void" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "warm_ocean" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\n\0\nunderwater\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x1Bminecraft:music.under_water\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#7ba4ff\x08\0 minecraft:visual/water_fog_color\0\x07#041f33\0\x06\0\x08downfall?\xE0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#43d5ee\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xE0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "warped_forest" , data : b"\n\n\0\nattributes\n\0\x1Eminecraft:audio/ambient_sounds\n\0\tadditions\x08\0\x05sound\0)minecraft:ambient.warped_forest.additions\x06\0\x0Btick_chance?\x86\xBB\x98\xC7\xE2\x82A\0\x08\0\x04loop\0$minecraft:ambient.warped_forest.loop\n\0\x04mood\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0$minecraft:ambient.warped_forest.mood\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\0\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0$minecraft:music.nether.warped_forest\0\0\t\0\"minecraft:visual/ambient_particles\n\0\0\0\x01\n\0\x08particle\x08\0\x04type\0\x16minecraft:warped_spore\0\x06\0\x0Bprobability?\x8D>\xD5'\xE5!W\0\x08\0\x1Aminecraft:visual/fog_color\0\x07#1a051a\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "windswept_forest" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7da2ff\0\x06\0\x08downfall?\xD3333333\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xC9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "windswept_gravelly_hills" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7da2ff\0\x06\0\x08downfall?\xD3333333\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xC9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "windswept_hills" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#7da2ff\0\x06\0\x08downfall?\xD3333333\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\x01\x06\0\x0Btemperature?\xC9\x99\x99\x99\x99\x99\x9A\0" } , StaticRegistryEntry { name : "windswept_savanna" , data : b"\n\n\0\nattributes\x08\0\x1Aminecraft:visual/sky_color\0\x07#6eb1ff\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "wooded_badlands" , data : b"\n\n\0\nattributes\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\"minecraft:music.overworld.badlands\0\0\x08\0\x1Aminecraft:visual/sky_color\0\x07#6eb1ff\0\x06\0\x08downfall\0\0\0\0\0\0\0\0\n\0\x07effects\x08\0\rfoliage_color\0\x07#9e814d\x08\0\x0Bgrass_color\0\x07#90814d\x08\0\x0Bwater_color\0\x07#3f76e4\0\x01\0\x11has_precipitation\0\x06\0\x0Btemperature@\0\0\0\0\0\0\0\0" }] , } , StaticRegistry { registry_id : "chat_type" , entries : & [StaticRegistryEntry { name : "chat" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x0Echat.type.text\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" } , StaticRegistryEntry { name : "emote_command" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x0Fchat.type.emote\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x0Fchat.type.emote\0\0" } , StaticRegistryEntry { name : "msg_command_incoming" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\n\0\x05style\x08\0\x05color\0\x04gray\x01\0\x06italic\x01\0\x08\0\x0Ftranslation_key\0!commands.message.display.incoming\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" } , StaticRegistryEntry { name : "msg_command_outgoing" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x02\0\x06target\0\x07content\n\0\x05style\x08\0\x05color\0\x04gray\x01\0\x06italic\x01\0\x08\0\x0Ftranslation_key\0!commands.message.display.outgoing\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" } , StaticRegistryEntry { name : "say_command" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.announcement\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" } , StaticRegistryEntry { name : "team_msg_command_incoming" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x03\0\x06target\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x13chat.type.team.text\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" } , StaticRegistryEntry { name : "team_msg_command_outgoing" , data : b"\n\n\0\x04chat\t\0\nparameters\x08\0\0\0\x03\0\x06target\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x13chat.type.team.sent\0\n\0\tnarration\t\0\nparameters\x08\0\0\0\x02\0\x06sender\0\x07content\x08\0\x0Ftranslation_key\0\x16chat.type.text.narrate\0\0" }] , } , StaticRegistry { registry_id : "trim_pattern" , entries : & [StaticRegistryEntry { name : "bolt" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:bolt\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.bolt\0\0" } , StaticRegistryEntry { name : "coast" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:coast\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ctrim_pattern.minecraft.coast\0\0" } , StaticRegistryEntry { name : "dune" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:dune\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.dune\0\0" } , StaticRegistryEntry { name : "eye" , data : b"\n\x08\0\x08asset_id\0\rminecraft:eye\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Atrim_pattern.minecraft.eye\0\0" } , StaticRegistryEntry { name : "flow" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:flow\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.flow\0\0" } , StaticRegistryEntry { name : "host" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:host\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.host\0\0" } , StaticRegistryEntry { name : "raiser" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:raiser\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Dtrim_pattern.minecraft.raiser\0\0" } , StaticRegistryEntry { name : "rib" , data : b"\n\x08\0\x08asset_id\0\rminecraft:rib\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Atrim_pattern.minecraft.rib\0\0" } , StaticRegistryEntry { name : "sentry" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:sentry\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Dtrim_pattern.minecraft.sentry\0\0" } , StaticRegistryEntry { name : "shaper" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:shaper\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Dtrim_pattern.minecraft.shaper\0\0" } , StaticRegistryEntry { name : "silence" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:silence\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Etrim_pattern.minecraft.silence\0\0" } , StaticRegistryEntry { name : "snout" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:snout\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ctrim_pattern.minecraft.snout\0\0" } , StaticRegistryEntry { name : "spire" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:spire\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ctrim_pattern.minecraft.spire\0\0" } , StaticRegistryEntry { name : "tide" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:tide\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.tide\0\0" } , StaticRegistryEntry { name : "vex" , data : b"\n\x08\0\x08asset_id\0\rminecraft:vex\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Atrim_pattern.minecraft.vex\0\0" } , StaticRegistryEntry { name : "ward" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:ward\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.ward\0\0" } , StaticRegistryEntry { name : "wayfinder" , data : b"\n\x08\0\x08asset_id\0\x13minecraft:wayfinder\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0 trim_pattern.minecraft.wayfinder\0\0" } , StaticRegistryEntry { name : "wild" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:wild\x01\0\x05decal\0\n\0\x0Bdescription\x08\0\ttranslate\0\x1Btrim_pattern.minecraft.wild\0\0" }] , } , StaticRegistry { registry_id : "trim_material" , entries : & [StaticRegistryEntry { name : "amethyst" , data : b"\n\x08\0\nasset_name\0\x08amethyst\n\0\x0Bdescription\x08\0\x05color\0\x07#9A5CC6\x08\0\ttranslate\0 trim_material.minecraft.amethyst\0\0" } , StaticRegistryEntry { name : "copper" , data : b"\n\x08\0\nasset_name\0\x06copper\n\0\x0Bdescription\x08\0\x05color\0\x07#B4684D\x08\0\ttranslate\0\x1Etrim_material.minecraft.copper\0\n\0\x15override_armor_assets\x08\0\x10minecraft:copper\0\rcopper_darker\0\0" } , StaticRegistryEntry { name : "diamond" , data : b"\n\x08\0\nasset_name\0\x07diamond\n\0\x0Bdescription\x08\0\x05color\0\x07#6EECD2\x08\0\ttranslate\0\x1Ftrim_material.minecraft.diamond\0\n\0\x15override_armor_assets\x08\0\x11minecraft:diamond\0\x0Ediamond_darker\0\0" } , StaticRegistryEntry { name : "emerald" , data : b"\n\x08\0\nasset_name\0\x07emerald\n\0\x0Bdescription\x08\0\x05color\0\x07#11A036\x08\0\ttranslate\0\x1Ftrim_material.minecraft.emerald\0\0" } , StaticRegistryEntry { name : "gold" , data : b"\n\x08\0\nasset_name\0\x04gold\n\0\x0Bdescription\x08\0\x05color\0\x07#DEB12D\x08\0\ttranslate\0\x1Ctrim_material.minecraft.gold\0\n\0\x15override_armor_assets\x08\0\x0Eminecraft:gold\0\x0Bgold_darker\0\0" } , StaticRegistryEntry { name : "iron" , data : b"\n\x08\0\nasset_name\0\x04iron\n\0\x0Bdescription\x08\0\x05color\0\x07#ECECEC\x08\0\ttranslate\0\x1Ctrim_material.minecraft.iron\0\n\0\x15override_armor_assets\x08\0\x0Eminecraft:iron\0\x0Biron_darker\0\0" } , StaticRegistryEntry { name : "lapis" , data : b"\n\x08\0\nasset_name\0\x05lapis\n\0\x0Bdescription\x08\0\x05color\0\x07#416E97\x08\0\ttranslate\0\x1Dtrim_material.minecraft.lapis\0\0" } , StaticRegistryEntry { name : "netherite" , data : b"\n\x08\0\nasset_name\0\tnetherite\n\0\x0Bdescription\x08\0\x05color\0\x07#625859\x08\0\ttranslate\0!trim_material.minecraft.netherite\0\n\0\x15override_armor_assets\x08\0\x13minecraft:netherite\0\x10netherite_darker\0\0" } , StaticRegistryEntry { name : "quartz" , data : b"\n\x08\0\nasset_name\0\x06quartz\n\0\x0Bdescription\x08\0\x05color\0\x07#E3D4C4\x08\0\ttranslate\0\x1Etrim_material.minecraft.quartz\0\0" } , StaticRegistryEntry { name : "redstone" , data : b"\n\x08\0\nasset_name\0\x08redstone\n\0\x0Bdescription\x08\0\x05color\0\x07#971607\x08\0\ttranslate\0 trim_material.minecraft.redstone\0\0" } , StaticRegistryEntry { name : "resin" , data : b"\n\x08\0\nasset_name\0\x05resin\n\0\x0Bdescription\x08\0\x05color\0\x07#FC7812\x08\0\ttranslate\0\x1Dtrim_material.minecraft.resin\0\0" }] , } , StaticRegistry { registry_id : "wolf_variant" , entries : & [StaticRegistryEntry { name : "ashen" , data : b"\n\n\0\x06assets\x08\0\x05angry\0&minecraft:entity/wolf/wolf_ashen_angry\x08\0\x04tame\0%minecraft:entity/wolf/wolf_ashen_tame\x08\0\x04wild\0 minecraft:entity/wolf/wolf_ashen\0\0" } , StaticRegistryEntry { name : "black" , data : b"\n\n\0\x06assets\x08\0\x05angry\0&minecraft:entity/wolf/wolf_black_angry\x08\0\x04tame\0%minecraft:entity/wolf/wolf_black_tame\x08\0\x04wild\0 minecraft:entity/wolf/wolf_black\0\0" } , StaticRegistryEntry { name : "chestnut" , data : b"\n\n\0\x06assets\x08\0\x05angry\0)minecraft:entity/wolf/wolf_chestnut_angry\x08\0\x04tame\0(minecraft:entity/wolf/wolf_chestnut_tame\x08\0\x04wild\0#minecraft:entity/wolf/wolf_chestnut\0\0" } , StaticRegistryEntry { name : "pale" , data : b"\n\n\0\x06assets\x08\0\x05angry\0 minecraft:entity/wolf/wolf_angry\x08\0\x04tame\0\x1Fminecraft:entity/wolf/wolf_tame\x08\0\x04wild\0\x1Aminecraft:entity/wolf/wolf\0\0" } , StaticRegistryEntry { name : "rusty" , data : b"\n\n\0\x06assets\x08\0\x05angry\0&minecraft:entity/wolf/wolf_rusty_angry\x08\0\x04tame\0%minecraft:entity/wolf/wolf_rusty_tame\x08\0\x04wild\0 minecraft:entity/wolf/wolf_rusty\0\0" } , StaticRegistryEntry { name : "snowy" , data : b"\n\n\0\x06assets\x08\0\x05angry\0&minecraft:entity/wolf/wolf_snowy_angry\x08\0\x04tame\0%minecraft:entity/wolf/wolf_snowy_tame\x08\0\x04wild\0 minecraft:entity/wolf/wolf_snowy\0\0" } , StaticRegistryEntry { name : "spotted" , data : b"\n\n\0\x06assets\x08\0\x05angry\0(minecraft:entity/wolf/wolf_spotted_angry\x08\0\x04tame\0'minecraft:entity/wolf/wolf_spotted_tame\x08\0\x04wild\0\"minecraft:entity/wolf/wolf_spotted\0\0" } , StaticRegistryEntry { name : "striped" , data : b"\n\n\0\x06assets\x08\0\x05angry\0(minecraft:entity/wolf/wolf_striped_angry\x08\0\x04tame\0'minecraft:entity/wolf/wolf_striped_tame\x08\0\x04wild\0\"minecraft:entity/wolf/wolf_striped\0\0" } , StaticRegistryEntry { name : "woods" , data : b"\n\n\0\x06assets\x08\0\x05angry\0&minecraft:entity/wolf/wolf_woods_angry\x08\0\x04tame\0%minecraft:entity/wolf/wolf_woods_tame\x08\0\x04wild\0 minecraft:entity/wolf/wolf_woods\0\0" }] , } , StaticRegistry { registry_id : "wolf_sound_variant" , entries : & [StaticRegistryEntry { name : "angry" , data : b"\n\x08\0\rambient_sound\0#minecraft:entity.wolf_angry.ambient\x08\0\x0Bdeath_sound\0!minecraft:entity.wolf_angry.death\x08\0\x0Bgrowl_sound\0!minecraft:entity.wolf_angry.growl\x08\0\nhurt_sound\0 minecraft:entity.wolf_angry.hurt\x08\0\npant_sound\0 minecraft:entity.wolf_angry.pant\x08\0\x0Bwhine_sound\0!minecraft:entity.wolf_angry.whine\0" } , StaticRegistryEntry { name : "big" , data : b"\n\x08\0\rambient_sound\0!minecraft:entity.wolf_big.ambient\x08\0\x0Bdeath_sound\0\x1Fminecraft:entity.wolf_big.death\x08\0\x0Bgrowl_sound\0\x1Fminecraft:entity.wolf_big.growl\x08\0\nhurt_sound\0\x1Eminecraft:entity.wolf_big.hurt\x08\0\npant_sound\0\x1Eminecraft:entity.wolf_big.pant\x08\0\x0Bwhine_sound\0\x1Fminecraft:entity.wolf_big.whine\0" } , StaticRegistryEntry { name : "classic" , data : b"\n\x08\0\rambient_sound\0\x1Dminecraft:entity.wolf.ambient\x08\0\x0Bdeath_sound\0\x1Bminecraft:entity.wolf.death\x08\0\x0Bgrowl_sound\0\x1Bminecraft:entity.wolf.growl\x08\0\nhurt_sound\0\x1Aminecraft:entity.wolf.hurt\x08\0\npant_sound\0\x1Aminecraft:entity.wolf.pant\x08\0\x0Bwhine_sound\0\x1Bminecraft:entity.wolf.whine\0" } , StaticRegistryEntry { name : "cute" , data : b"\n\x08\0\rambient_sound\0\"minecraft:entity.wolf_cute.ambient\x08\0\x0Bdeath_sound\0 minecraft:entity.wolf_cute.death\x08\0\x0Bgrowl_sound\0 minecraft:entity.wolf_cute.growl\x08\0\nhurt_sound\0\x1Fminecraft:entity.wolf_cute.hurt\x08\0\npant_sound\0\x1Fminecraft:entity.wolf_cute.pant\x08\0\x0Bwhine_sound\0 minecraft:entity.wolf_cute.whine\0" } , StaticRegistryEntry { name : "grumpy" , data : b"\n\x08\0\rambient_sound\0$minecraft:entity.wolf_grumpy.ambient\x08\0\x0Bdeath_sound\0\"minecraft:entity.wolf_grumpy.death\x08\0\x0Bgrowl_sound\0\"minecraft:entity.wolf_grumpy.growl\x08\0\nhurt_sound\0!minecraft:entity.wolf_grumpy.hurt\x08\0\npant_sound\0!minecraft:entity.wolf_grumpy.pant\x08\0\x0Bwhine_sound\0\"minecraft:entity.wolf_grumpy.whine\0" } , StaticRegistryEntry { name : "puglin" , data : b"\n\x08\0\rambient_sound\0$minecraft:entity.wolf_puglin.ambient\x08\0\x0Bdeath_sound\0\"minecraft:entity.wolf_puglin.death\x08\0\x0Bgrowl_sound\0\"minecraft:entity.wolf_puglin.growl\x08\0\nhurt_sound\0!minecraft:entity.wolf_puglin.hurt\x08\0\npant_sound\0!minecraft:entity.wolf_puglin.pant\x08\0\x0Bwhine_sound\0\"minecraft:entity.wolf_puglin.whine\0" } , StaticRegistryEntry { name : "sad" , data : b"\n\x08\0\rambient_sound\0!minecraft:entity.wolf_sad.ambient\x08\0\x0Bdeath_sound\0\x1Fminecraft:entity.wolf_sad.death\x08\0\x0Bgrowl_sound\0\x1Fminecraft:entity.wolf_sad.growl\x08\0\nhurt_sound\0\x1Eminecraft:entity.wolf_sad.hurt\x08\0\npant_sound\0\x1Eminecraft:entity.wolf_sad.pant\x08\0\x0Bwhine_sound\0\x1Fminecraft:entity.wolf_sad.whine\0" }] , } , StaticRegistry { registry_id : "pig_variant" , entries : & [StaticRegistryEntry { name : "cold" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:entity/pig/cold_pig\x08\0\x05model\0\x04cold\0" } , StaticRegistryEntry { name : "temperate" , data : b"\n\x08\0\x08asset_id\0\"minecraft:entity/pig/temperate_pig\0" } , StaticRegistryEntry { name : "warm" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:entity/pig/warm_pig\0" }] , } , StaticRegistry { registry_id : "frog_variant" , entries : & [StaticRegistryEntry { name : "cold" , data : b"\n\x08\0\x08asset_id\0\x1Fminecraft:entity/frog/cold_frog\0" } , StaticRegistryEntry { name : "temperate" , data : b"\n\x08\0\x08asset_id\0$minecraft:entity/frog/temperate_frog\0" } , StaticRegistryEntry { name : "warm" , data : b"\n\x08\0\x08asset_id\0\x1Fminecraft:entity/frog/warm_frog\0" }] , } , StaticRegistry { registry_id : "cat_variant" , entries : & [StaticRegistryEntry { name : "all_black" , data : b"\n\x08\0\x08asset_id\0\x1Eminecraft:entity/cat/all_black\0" } , StaticRegistryEntry { name : "black" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:entity/cat/black\0" } , StaticRegistryEntry { name : "british_shorthair" , data : b"\n\x08\0\x08asset_id\0&minecraft:entity/cat/british_shorthair\0" } , StaticRegistryEntry { name : "calico" , data : b"\n\x08\0\x08asset_id\0\x1Bminecraft:entity/cat/calico\0" } , StaticRegistryEntry { name : "jellie" , data : b"\n\x08\0\x08asset_id\0\x1Bminecraft:entity/cat/jellie\0" } , StaticRegistryEntry { name : "persian" , data : b"\n\x08\0\x08asset_id\0\x1Cminecraft:entity/cat/persian\0" } , StaticRegistryEntry { name : "ragdoll" , data : b"\n\x08\0\x08asset_id\0\x1Cminecraft:entity/cat/ragdoll\0" } , StaticRegistryEntry { name : "red" , data : b"\n\x08\0\x08asset_id\0\x18minecraft:entity/cat/red\0" } , StaticRegistryEntry { name : "siamese" , data : b"\n\x08\0\x08asset_id\0\x1Cminecraft:entity/cat/siamese\0" } , StaticRegistryEntry { name : "tabby" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:entity/cat/tabby\0" } , StaticRegistryEntry { name : "white" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:entity/cat/white\0" }] , } , StaticRegistry { registry_id : "cow_variant" , entries : & [StaticRegistryEntry { name : "cold" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:entity/cow/cold_cow\x08\0\x05model\0\x04cold\0" } , StaticRegistryEntry { name : "temperate" , data : b"\n\x08\0\x08asset_id\0\"minecraft:entity/cow/temperate_cow\0" } , StaticRegistryEntry { name : "warm" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:entity/cow/warm_cow\x08\0\x05model\0\x04warm\0" }] , } , StaticRegistry { registry_id : "chicken_variant" , entries : & [StaticRegistryEntry { name : "cold" , data : b"\n\x08\0\x08asset_id\0%minecraft:entity/chicken/cold_chicken\x08\0\x05model\0\x04cold\0" } , StaticRegistryEntry { name : "temperate" , data : b"\n\x08\0\x08asset_id\0*minecraft:entity/chicken/temperate_chicken\0" } , StaticRegistryEntry { name : "warm" , data : b"\n\x08\0\x08asset_id\0%minecraft:entity/chicken/warm_chicken\0" }] , } , StaticRegistry { registry_id : "zombie_nautilus_variant" , entries : & [StaticRegistryEntry { name : "temperate" , data : b"\n\x08\0\x08asset_id\0)minecraft:entity/nautilus/zombie_nautilus\0" } , StaticRegistryEntry { name : "warm" , data : b"\n\x08\0\x08asset_id\0/minecraft:entity/nautilus/zombie_nautilus_coral\x08\0\x05model\0\x04warm\0" }] , } , StaticRegistry { registry_id : "painting_variant" , entries : & [StaticRegistryEntry { name : "alban" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:alban\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.alban.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.alban.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "aztec" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:aztec\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.aztec.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.aztec.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "aztec2" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:aztec2\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0 painting.minecraft.aztec2.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.aztec2.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "backyard" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:backyard\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.backyard.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.backyard.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "baroque" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:baroque\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.baroque.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.baroque.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "bomb" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:bomb\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.bomb.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.bomb.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "bouquet" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:bouquet\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.bouquet.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.bouquet.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "burning_skull" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:burning_skull\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0'painting.minecraft.burning_skull.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0&painting.minecraft.burning_skull.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "bust" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:bust\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.bust.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.bust.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "cavebird" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:cavebird\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.cavebird.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.cavebird.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "changing" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:changing\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.changing.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.changing.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "cotan" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:cotan\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.cotan.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.cotan.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "courbet" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:courbet\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.courbet.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.courbet.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "creebet" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:creebet\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.creebet.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.creebet.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "dennis" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:dennis\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0 painting.minecraft.dennis.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.dennis.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "donkey_kong" , data : b"\n\x08\0\x08asset_id\0\x15minecraft:donkey_kong\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0%painting.minecraft.donkey_kong.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0$painting.minecraft.donkey_kong.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "earth" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:earth\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.earth.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "endboss" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:endboss\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.endboss.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.endboss.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "fern" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:fern\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.fern.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.fern.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "fighters" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:fighters\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.fighters.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.fighters.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "finding" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:finding\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.finding.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.finding.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "fire" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:fire\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.fire.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "graham" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:graham\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0 painting.minecraft.graham.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.graham.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "humble" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:humble\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0 painting.minecraft.humble.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.humble.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "kebab" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:kebab\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.kebab.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.kebab.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "lowmist" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:lowmist\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.lowmist.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.lowmist.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "match" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:match\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.match.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.match.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "meditative" , data : b"\n\x08\0\x08asset_id\0\x14minecraft:meditative\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0$painting.minecraft.meditative.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0#painting.minecraft.meditative.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "orb" , data : b"\n\x08\0\x08asset_id\0\rminecraft:orb\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Dpainting.minecraft.orb.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Cpainting.minecraft.orb.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "owlemons" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:owlemons\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.owlemons.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.owlemons.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "passage" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:passage\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.passage.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.passage.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "pigscene" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:pigscene\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.pigscene.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.pigscene.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "plant" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:plant\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.plant.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.plant.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "pointer" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:pointer\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0!painting.minecraft.pointer.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0 painting.minecraft.pointer.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "pond" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:pond\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.pond.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.pond.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "pool" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:pool\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.pool.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.pool.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "prairie_ride" , data : b"\n\x08\0\x08asset_id\0\x16minecraft:prairie_ride\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0&painting.minecraft.prairie_ride.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0%painting.minecraft.prairie_ride.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "sea" , data : b"\n\x08\0\x08asset_id\0\rminecraft:sea\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Dpainting.minecraft.sea.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Cpainting.minecraft.sea.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "skeleton" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:skeleton\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.skeleton.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.skeleton.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "skull_and_roses" , data : b"\n\x08\0\x08asset_id\0\x19minecraft:skull_and_roses\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0)painting.minecraft.skull_and_roses.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0(painting.minecraft.skull_and_roses.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "stage" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:stage\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.stage.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.stage.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "sunflowers" , data : b"\n\x08\0\x08asset_id\0\x14minecraft:sunflowers\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0$painting.minecraft.sunflowers.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0#painting.minecraft.sunflowers.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "sunset" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:sunset\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0 painting.minecraft.sunset.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.sunset.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "tides" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:tides\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Fpainting.minecraft.tides.author\0\x04\0\x06height\0\0\0\0\0\0\0\x03\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.tides.title\0\x04\0\x05width\0\0\0\0\0\0\0\x03\0" } , StaticRegistryEntry { name : "unpacked" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:unpacked\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.unpacked.author\0\x04\0\x06height\0\0\0\0\0\0\0\x04\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.unpacked.title\0\x04\0\x05width\0\0\0\0\0\0\0\x04\0" } , StaticRegistryEntry { name : "void" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:void\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\x1Epainting.minecraft.void.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.void.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "wanderer" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:wanderer\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0\"painting.minecraft.wanderer.author\0\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0!painting.minecraft.wanderer.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "wasteland" , data : b"\n\x08\0\x08asset_id\0\x13minecraft:wasteland\n\0\x06author\x08\0\x05color\0\x04gray\x08\0\ttranslate\0#painting.minecraft.wasteland.author\0\x04\0\x06height\0\0\0\0\0\0\0\x01\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\"painting.minecraft.wasteland.title\0\x04\0\x05width\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "water" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:water\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Epainting.minecraft.water.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "wind" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:wind\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Dpainting.minecraft.wind.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "wither" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:wither\x04\0\x06height\0\0\0\0\0\0\0\x02\n\0\x05title\x08\0\x05color\0\x06yellow\x08\0\ttranslate\0\x1Fpainting.minecraft.wither.title\0\x04\0\x05width\0\0\0\0\0\0\0\x02\0" }] , } , StaticRegistry { registry_id : "dimension_type" , entries : & [StaticRegistryEntry { name : "overworld" , data : b"\n\x06\0\rambient_light\0\0\0\0\0\0\0\0\n\0\nattributes\n\0\x1Eminecraft:audio/ambient_sounds\n\0\x04mood\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\0\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\0\x08\0\x1Cminecraft:visual/cloud_color\0\t#ccffffff\x06\0\x1Dminecraft:visual/cloud_height@h\n\x8F\\(\xF5\xC3\x08\0\x1Aminecraft:visual/fog_color\0\x07#c0d8ff\x08\0\x1Aminecraft:visual/sky_color\0\x07#78a7ff\0\x06\0\x10coordinate_scale?\xF0\0\0\0\0\0\0\x01\0\x0Bhas_ceiling\0\x01\0\x0Chas_skylight\x01\x04\0\x06height\0\0\0\0\0\0\x01\x80\x08\0\ninfiniburn\0\x1F#minecraft:infiniburn_overworld\x04\0\x0Elogical_height\0\0\0\0\0\0\x01\x80\x04\0\x05min_y\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xC0\x04\0\x1Fmonster_spawn_block_light_limit\0\0\0\0\0\0\0\0\n\0\x19monster_spawn_light_level\x04\0\rmax_inclusive\0\0\0\0\0\0\0\x07\x04\0\rmin_inclusive\0\0\0\0\0\0\0\0\x08\0\x04type\0\x11minecraft:uniform\0\x08\0\ttimelines\0\x17#minecraft:in_overworld\0" } , StaticRegistryEntry { name : "overworld_caves" , data : b"\n\x06\0\rambient_light\0\0\0\0\0\0\0\0\n\0\nattributes\n\0\x1Eminecraft:audio/ambient_sounds\n\0\x04mood\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\0\n\0 minecraft:audio/background_music\n\0\x08creative\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x18minecraft:music.creative\0\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0.\xE0\x08\0\x05sound\0\x14minecraft:music.game\0\0\x08\0\x1Cminecraft:visual/cloud_color\0\t#ccffffff\x06\0\x1Dminecraft:visual/cloud_height@h\n\x8F\\(\xF5\xC3\x08\0\x1Aminecraft:visual/fog_color\0\x07#c0d8ff\x08\0\x1Aminecraft:visual/sky_color\0\x07#78a7ff\0\x06\0\x10coordinate_scale?\xF0\0\0\0\0\0\0\x01\0\x0Bhas_ceiling\x01\x01\0\x0Chas_skylight\x01\x04\0\x06height\0\0\0\0\0\0\x01\x80\x08\0\ninfiniburn\0\x1F#minecraft:infiniburn_overworld\x04\0\x0Elogical_height\0\0\0\0\0\0\x01\x80\x04\0\x05min_y\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xC0\x04\0\x1Fmonster_spawn_block_light_limit\0\0\0\0\0\0\0\0\n\0\x19monster_spawn_light_level\x04\0\rmax_inclusive\0\0\0\0\0\0\0\x07\x04\0\rmin_inclusive\0\0\0\0\0\0\0\0\x08\0\x04type\0\x11minecraft:uniform\0\x08\0\ttimelines\0\x17#minecraft:in_overworld\0" } , StaticRegistryEntry { name : "the_end" , data : b"\n\x06\0\rambient_light?\xD0\0\0\0\0\0\0\n\0\nattributes\n\0\x1Eminecraft:audio/ambient_sounds\n\0\x04mood\x04\0\x13block_search_extent\0\0\0\0\0\0\0\x08\x06\0\x06offset@\0\0\0\0\0\0\0\x08\0\x05sound\0\x16minecraft:ambient.cave\x04\0\ntick_delay\0\0\0\0\0\0\x17p\0\0\n\0 minecraft:audio/background_music\n\0\x07default\x04\0\tmax_delay\0\0\0\0\0\0]\xC0\x04\0\tmin_delay\0\0\0\0\0\0\x17p\x01\0\x15replace_current_music\x01\x08\0\x05sound\0\x13minecraft:music.end\0\0\x08\0\x1Aminecraft:visual/fog_color\0\x07#181318\x08\0\x1Aminecraft:visual/sky_color\0\x07#000000\x08\0 minecraft:visual/sky_light_color\0\x07#e580ff\x06\0!minecraft:visual/sky_light_factor\0\0\0\0\0\0\0\0\0\x06\0\x10coordinate_scale?\xF0\0\0\0\0\0\0\x01\0\x0Bhas_ceiling\0\x01\0\x0Ehas_fixed_time\x01\x01\0\x0Chas_skylight\x01\x04\0\x06height\0\0\0\0\0\0\x01\0\x08\0\ninfiniburn\0\x19#minecraft:infiniburn_end\x04\0\x0Elogical_height\0\0\0\0\0\0\x01\0\x04\0\x05min_y\0\0\0\0\0\0\0\0\x04\0\x1Fmonster_spawn_block_light_limit\0\0\0\0\0\0\0\0\x04\0\x19monster_spawn_light_level\0\0\0\0\0\0\0\x0F\x08\0\x06skybox\0\x03end\x08\0\ttimelines\0\x11#minecraft:in_end\0" } , StaticRegistryEntry { name : "the_nether" , data : b"\n\x06\0\rambient_light?\xB9\x99\x99\x99\x99\x99\x9A\n\0\nattributes\x01\0\x1Cminecraft:gameplay/fast_lava\x01\x01\0\"minecraft:gameplay/piglins_zombify\0\x06\0\"minecraft:gameplay/sky_light_level@\x10\0\0\0\0\0\0\x01\0#minecraft:gameplay/water_evaporates\x01\n\0+minecraft:visual/default_dripstone_particle\x08\0\x04type\0!minecraft:dripping_dripstone_lava\0\x06\0!minecraft:visual/fog_end_distance@X\0\0\0\0\0\0\x06\0#minecraft:visual/fog_start_distance@$\0\0\0\0\0\0\x08\0 minecraft:visual/sky_light_color\0\x07#7a7aff\x06\0!minecraft:visual/sky_light_factor\0\0\0\0\0\0\0\0\0\x08\0\x0Ecardinal_light\0\x06nether\x06\0\x10coordinate_scale@ \0\0\0\0\0\0\x01\0\x0Bhas_ceiling\x01\x01\0\x0Ehas_fixed_time\x01\x01\0\x0Chas_skylight\0\x04\0\x06height\0\0\0\0\0\0\x01\0\x08\0\ninfiniburn\0\x1C#minecraft:infiniburn_nether\x04\0\x0Elogical_height\0\0\0\0\0\0\0\x80\x04\0\x05min_y\0\0\0\0\0\0\0\0\x04\0\x1Fmonster_spawn_block_light_limit\0\0\0\0\0\0\0\x0F\x04\0\x19monster_spawn_light_level\0\0\0\0\0\0\0\x07\x08\0\x06skybox\0\x04none\x08\0\ttimelines\0\x14#minecraft:in_nether\0" }] , } , StaticRegistry { registry_id : "damage_type" , entries : & [StaticRegistryEntry { name : "arrow" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x05arrow\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "bad_respawn_point" , data : b"\n\x08\0\x12death_message_type\0\x17intentional_game_design\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x0FbadRespawnPoint\x08\0\x07scaling\0\x06always\0" } , StaticRegistryEntry { name : "cactus" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06cactus\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "campfire" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06inFire\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "cramming" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x08cramming\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "dragon_breath" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x0CdragonBreath\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "drown" , data : b"\n\x08\0\x07effects\0\x08drowning\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x05drown\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "dry_out" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06dryout\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "ender_pearl" , data : b"\n\x08\0\x12death_message_type\0\rfall_variants\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x04fall\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "explosion" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\texplosion\x08\0\x07scaling\0\x06always\0" } , StaticRegistryEntry { name : "fall" , data : b"\n\x08\0\x12death_message_type\0\rfall_variants\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x04fall\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "falling_anvil" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x05anvil\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "falling_block" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x0CfallingBlock\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "falling_stalactite" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x11fallingStalactite\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "fireball" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x08fireball\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "fireworks" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\tfireworks\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "fly_into_wall" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x0BflyIntoWall\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "freeze" , data : b"\n\x08\0\x07effects\0\x08freezing\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x06freeze\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "generic" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x07generic\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "generic_kill" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x0BgenericKill\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "hot_floor" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x08hotFloor\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "in_fire" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06inFire\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "in_wall" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x06inWall\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "indirect_magic" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\rindirectMagic\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "lava" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x04lava\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "lightning_bolt" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\rlightningBolt\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "mace_smash" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\nmace_smash\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "magic" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x05magic\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "mob_attack" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x03mob\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "mob_attack_no_aggro" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x03mob\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "mob_projectile" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x03mob\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "on_fire" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x06onFire\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "out_of_world" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\noutOfWorld\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "outside_border" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\routsideBorder\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "player_attack" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06player\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "player_explosion" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x10explosion.player\x08\0\x07scaling\0\x06always\0" } , StaticRegistryEntry { name : "sonic_boom" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\nsonic_boom\x08\0\x07scaling\0\x06always\0" } , StaticRegistryEntry { name : "spear" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x05spear\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "spit" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x03mob\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "stalagmite" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\nstalagmite\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "starve" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x06starve\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "sting" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x05sting\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "sweet_berry_bush" , data : b"\n\x08\0\x07effects\0\x06poking\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x0EsweetBerryBush\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "thorns" , data : b"\n\x08\0\x07effects\0\x06thorns\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06thorns\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "thrown" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06thrown\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "trident" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x07trident\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "unattributed_fireball" , data : b"\n\x08\0\x07effects\0\x07burning\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x06onFire\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "wind_charge" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x03mob\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "wither" , data : b"\n\x06\0\nexhaustion\0\0\0\0\0\0\0\0\x08\0\nmessage_id\0\x06wither\x08\0\x07scaling\0 when_caused_by_living_non_player\0" } , StaticRegistryEntry { name : "wither_skull" , data : b"\n\x06\0\nexhaustion?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\nmessage_id\0\x0BwitherSkull\x08\0\x07scaling\0 when_caused_by_living_non_player\0" }] , } , StaticRegistry { registry_id : "banner_pattern" , entries : & [StaticRegistryEntry { name : "base" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:base\x08\0\x0Ftranslation_key\0\x1Bblock.minecraft.banner.base\0" } , StaticRegistryEntry { name : "border" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:border\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.border\0" } , StaticRegistryEntry { name : "bricks" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:bricks\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.bricks\0" } , StaticRegistryEntry { name : "circle" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:circle\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.circle\0" } , StaticRegistryEntry { name : "creeper" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:creeper\x08\0\x0Ftranslation_key\0\x1Eblock.minecraft.banner.creeper\0" } , StaticRegistryEntry { name : "cross" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:cross\x08\0\x0Ftranslation_key\0\x1Cblock.minecraft.banner.cross\0" } , StaticRegistryEntry { name : "curly_border" , data : b"\n\x08\0\x08asset_id\0\x16minecraft:curly_border\x08\0\x0Ftranslation_key\0#block.minecraft.banner.curly_border\0" } , StaticRegistryEntry { name : "diagonal_left" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:diagonal_left\x08\0\x0Ftranslation_key\0$block.minecraft.banner.diagonal_left\0" } , StaticRegistryEntry { name : "diagonal_right" , data : b"\n\x08\0\x08asset_id\0\x18minecraft:diagonal_right\x08\0\x0Ftranslation_key\0%block.minecraft.banner.diagonal_right\0" } , StaticRegistryEntry { name : "diagonal_up_left" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:diagonal_up_left\x08\0\x0Ftranslation_key\0'block.minecraft.banner.diagonal_up_left\0" } , StaticRegistryEntry { name : "diagonal_up_right" , data : b"\n\x08\0\x08asset_id\0\x1Bminecraft:diagonal_up_right\x08\0\x0Ftranslation_key\0(block.minecraft.banner.diagonal_up_right\0" } , StaticRegistryEntry { name : "flow" , data : b"\n\x08\0\x08asset_id\0\x0Eminecraft:flow\x08\0\x0Ftranslation_key\0\x1Bblock.minecraft.banner.flow\0" } , StaticRegistryEntry { name : "flower" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:flower\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.flower\0" } , StaticRegistryEntry { name : "globe" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:globe\x08\0\x0Ftranslation_key\0\x1Cblock.minecraft.banner.globe\0" } , StaticRegistryEntry { name : "gradient" , data : b"\n\x08\0\x08asset_id\0\x12minecraft:gradient\x08\0\x0Ftranslation_key\0\x1Fblock.minecraft.banner.gradient\0" } , StaticRegistryEntry { name : "gradient_up" , data : b"\n\x08\0\x08asset_id\0\x15minecraft:gradient_up\x08\0\x0Ftranslation_key\0\"block.minecraft.banner.gradient_up\0" } , StaticRegistryEntry { name : "guster" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:guster\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.guster\0" } , StaticRegistryEntry { name : "half_horizontal" , data : b"\n\x08\0\x08asset_id\0\x19minecraft:half_horizontal\x08\0\x0Ftranslation_key\0&block.minecraft.banner.half_horizontal\0" } , StaticRegistryEntry { name : "half_horizontal_bottom" , data : b"\n\x08\0\x08asset_id\0 minecraft:half_horizontal_bottom\x08\0\x0Ftranslation_key\0-block.minecraft.banner.half_horizontal_bottom\0" } , StaticRegistryEntry { name : "half_vertical" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:half_vertical\x08\0\x0Ftranslation_key\0$block.minecraft.banner.half_vertical\0" } , StaticRegistryEntry { name : "half_vertical_right" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:half_vertical_right\x08\0\x0Ftranslation_key\0*block.minecraft.banner.half_vertical_right\0" } , StaticRegistryEntry { name : "mojang" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:mojang\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.mojang\0" } , StaticRegistryEntry { name : "piglin" , data : b"\n\x08\0\x08asset_id\0\x10minecraft:piglin\x08\0\x0Ftranslation_key\0\x1Dblock.minecraft.banner.piglin\0" } , StaticRegistryEntry { name : "rhombus" , data : b"\n\x08\0\x08asset_id\0\x11minecraft:rhombus\x08\0\x0Ftranslation_key\0\x1Eblock.minecraft.banner.rhombus\0" } , StaticRegistryEntry { name : "skull" , data : b"\n\x08\0\x08asset_id\0\x0Fminecraft:skull\x08\0\x0Ftranslation_key\0\x1Cblock.minecraft.banner.skull\0" } , StaticRegistryEntry { name : "small_stripes" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:small_stripes\x08\0\x0Ftranslation_key\0$block.minecraft.banner.small_stripes\0" } , StaticRegistryEntry { name : "square_bottom_left" , data : b"\n\x08\0\x08asset_id\0\x1Cminecraft:square_bottom_left\x08\0\x0Ftranslation_key\0)block.minecraft.banner.square_bottom_left\0" } , StaticRegistryEntry { name : "square_bottom_right" , data : b"\n\x08\0\x08asset_id\0\x1Dminecraft:square_bottom_right\x08\0\x0Ftranslation_key\0*block.minecraft.banner.square_bottom_right\0" } , StaticRegistryEntry { name : "square_top_left" , data : b"\n\x08\0\x08asset_id\0\x19minecraft:square_top_left\x08\0\x0Ftranslation_key\0&block.minecraft.banner.square_top_left\0" } , StaticRegistryEntry { name : "square_top_right" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:square_top_right\x08\0\x0Ftranslation_key\0'block.minecraft.banner.square_top_right\0" } , StaticRegistryEntry { name : "straight_cross" , data : b"\n\x08\0\x08asset_id\0\x18minecraft:straight_cross\x08\0\x0Ftranslation_key\0%block.minecraft.banner.straight_cross\0" } , StaticRegistryEntry { name : "stripe_bottom" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:stripe_bottom\x08\0\x0Ftranslation_key\0$block.minecraft.banner.stripe_bottom\0" } , StaticRegistryEntry { name : "stripe_center" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:stripe_center\x08\0\x0Ftranslation_key\0$block.minecraft.banner.stripe_center\0" } , StaticRegistryEntry { name : "stripe_downleft" , data : b"\n\x08\0\x08asset_id\0\x19minecraft:stripe_downleft\x08\0\x0Ftranslation_key\0&block.minecraft.banner.stripe_downleft\0" } , StaticRegistryEntry { name : "stripe_downright" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:stripe_downright\x08\0\x0Ftranslation_key\0'block.minecraft.banner.stripe_downright\0" } , StaticRegistryEntry { name : "stripe_left" , data : b"\n\x08\0\x08asset_id\0\x15minecraft:stripe_left\x08\0\x0Ftranslation_key\0\"block.minecraft.banner.stripe_left\0" } , StaticRegistryEntry { name : "stripe_middle" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:stripe_middle\x08\0\x0Ftranslation_key\0$block.minecraft.banner.stripe_middle\0" } , StaticRegistryEntry { name : "stripe_right" , data : b"\n\x08\0\x08asset_id\0\x16minecraft:stripe_right\x08\0\x0Ftranslation_key\0#block.minecraft.banner.stripe_right\0" } , StaticRegistryEntry { name : "stripe_top" , data : b"\n\x08\0\x08asset_id\0\x14minecraft:stripe_top\x08\0\x0Ftranslation_key\0!block.minecraft.banner.stripe_top\0" } , StaticRegistryEntry { name : "triangle_bottom" , data : b"\n\x08\0\x08asset_id\0\x19minecraft:triangle_bottom\x08\0\x0Ftranslation_key\0&block.minecraft.banner.triangle_bottom\0" } , StaticRegistryEntry { name : "triangle_top" , data : b"\n\x08\0\x08asset_id\0\x16minecraft:triangle_top\x08\0\x0Ftranslation_key\0#block.minecraft.banner.triangle_top\0" } , StaticRegistryEntry { name : "triangles_bottom" , data : b"\n\x08\0\x08asset_id\0\x1Aminecraft:triangles_bottom\x08\0\x0Ftranslation_key\0'block.minecraft.banner.triangles_bottom\0" } , StaticRegistryEntry { name : "triangles_top" , data : b"\n\x08\0\x08asset_id\0\x17minecraft:triangles_top\x08\0\x0Ftranslation_key\0$block.minecraft.banner.triangles_top\0" }] , } , StaticRegistry { registry_id : "enchantment" , entries : & [StaticRegistryEntry { name : "aqua_affinity" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0#enchantment.minecraft.aqua_affinity\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base@\x10\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x10\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0 minecraft:submerged_mining_speed\x08\0\x02id\0#minecraft:enchantment.aqua_affinity\x08\0\toperation\0\x14add_multiplied_total\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0)\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x04head\x08\0\x0Fsupported_items\0!#minecraft:enchantable/head_armor\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "bane_of_arthropods" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0(enchantment.minecraft.bane_of_arthropods\0\n\0\x07effects\t\0\x10minecraft:damage\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\x04\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x04\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\x08\0\x04type\0*#minecraft:sensitive_to_bane_of_arthropods\0\0\0\t\0\x15minecraft:post_attack\n\0\0\0\x01\x08\0\x08affected\0\x06victim\n\0\x06effect\x06\0\rmax_amplifier@\x08\0\0\0\0\0\0\n\0\x0Cmax_duration\x06\0\x04base?\xF8\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xE0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x06\0\rmin_amplifier@\x08\0\0\0\0\0\0\x06\0\x0Cmin_duration?\xF8\0\0\0\0\0\0\x08\0\x08to_apply\0\x12minecraft:slowness\x08\0\x04type\0\x1Aminecraft:apply_mob_effect\0\x08\0\tenchanted\0\x08attacker\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\x08\0\x04type\0*#minecraft:sensitive_to_bane_of_arthropods\0\0\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\x01\0\tis_direct\x01\0\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x08\0\rprimary_items\0##minecraft:enchantable/melee_weapon\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1D#minecraft:enchantable/weapon\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "binding_curse" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0#enchantment.minecraft.binding_curse\0\n\0\x07effects\n\0\x1Eminecraft:prevent_armor_change\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0!#minecraft:enchantable/equippable\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "blast_protection" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0&enchantment.minecraft.blast_protection\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base?\xC3333333\x06\0\x15per_level_above_first?\xC3333333\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0(minecraft:explosion_knockback_resistance\x08\0\x02id\0&minecraft:enchantment.blast_protection\x08\0\toperation\0\tadd_value\0\t\0\x1Bminecraft:damage_protection\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first@\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x02\x01\0\x08expected\x01\x08\0\x02id\0\x16minecraft:is_explosion\0\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/armor\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\r\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/armor\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "breach" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cenchantment.minecraft.breach\0\n\0\x07effects\t\0\x1Dminecraft:armor_effectiveness\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base\xBF\xC3333333\x06\0\x15per_level_above_first\xBF\xC3333333\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1B#minecraft:enchantable/mace\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "channeling" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.channeling\0\n\0\x07effects\t\0\x13minecraft:hit_block\n\0\0\0\x01\n\0\x06effect\t\0\x07effects\n\0\0\0\x02\x08\0\x06entity\0\x18minecraft:lightning_bolt\x08\0\x04type\0\x17minecraft:summon_entity\0\x06\0\x05pitch?\xF0\0\0\0\0\0\0\x08\0\x05sound\0\x1Eminecraft:item.trident.thunder\x08\0\x04type\0\x14minecraft:play_sound\x06\0\x06volume@\x14\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:all_of\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x03\x08\0\tcondition\0\x17minecraft:weather_check\x01\0\nthundering\x01\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\x08\0\x04type\0\x11minecraft:trident\0\0\x08\0\tcondition\0\x18minecraft:location_check\n\0\tpredicate\n\0\x05block\x08\0\x06blocks\0\x19#minecraft:lightning_rods\0\x01\0\x0Bcan_see_sky\x01\0\0\0\0\t\0\x15minecraft:post_attack\n\0\0\0\x01\x08\0\x08affected\0\x06victim\n\0\x06effect\t\0\x07effects\n\0\0\0\x02\x08\0\x06entity\0\x18minecraft:lightning_bolt\x08\0\x04type\0\x17minecraft:summon_entity\0\x06\0\x05pitch?\xF0\0\0\0\0\0\0\x08\0\x05sound\0\x1Eminecraft:item.trident.thunder\x08\0\x04type\0\x14minecraft:play_sound\x06\0\x06volume@\x14\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:all_of\0\x08\0\tenchanted\0\x08attacker\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x03\x08\0\tcondition\0\x17minecraft:weather_check\x01\0\nthundering\x01\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x08location\x01\0\x0Bcan_see_sky\x01\0\0\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x0Fdirect_attacker\n\0\tpredicate\x08\0\x04type\0\x11minecraft:trident\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/trident\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "density" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.density\0\n\0\x07effects\t\0'minecraft:smash_damage_per_fallen_block\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xE0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xE0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1B#minecraft:enchantable/mace\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "depth_strider" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0#enchantment.minecraft.depth_strider\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base?\xD5UU\\}\xDAK\x06\0\x15per_level_above_first?\xD5UU\\}\xDAK\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0#minecraft:water_movement_efficiency\x08\0\x02id\0#minecraft:enchantment.depth_strider\x08\0\toperation\0\tadd_value\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/boots\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x04feet\x08\0\x0Fsupported_items\0!#minecraft:enchantable/foot_armor\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "efficiency" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.efficiency\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x05added?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x18minecraft:levels_squared\0\x08\0\tattribute\0\x1Bminecraft:mining_efficiency\x08\0\x02id\0 minecraft:enchantment.efficiency\x08\0\toperation\0\tadd_value\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x003\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1D#minecraft:enchantable/mining\x04\0\x06weight\0\0\0\0\0\0\0\n\0" } , StaticRegistryEntry { name : "feather_falling" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0%enchantment.minecraft.feather_falling\0\n\0\x07effects\t\0\x1Bminecraft:damage_protection\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\x08\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x08\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x02\x01\0\x08expected\x01\x08\0\x02id\0\x11minecraft:is_fall\0\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x0B\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x06\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x06\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0!#minecraft:enchantable/foot_armor\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "fire_aspect" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0!enchantment.minecraft.fire_aspect\0\n\0\x07effects\t\0\x15minecraft:post_attack\n\0\0\0\x01\x08\0\x08affected\0\x06victim\n\0\x06effect\n\0\x08duration\x06\0\x04base@\x10\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x10\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x10minecraft:ignite\0\x08\0\tenchanted\0\x08attacker\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\x01\0\tis_direct\x01\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0<\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x04\0\tmax_level\0\0\0\0\0\0\0\x02\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x08\0\rprimary_items\0##minecraft:enchantable/melee_weapon\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\"#minecraft:enchantable/fire_aspect\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "fire_protection" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0%enchantment.minecraft.fire_protection\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base\xBF\xC3333333\x06\0\x15per_level_above_first\xBF\xC3333333\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0\x16minecraft:burning_time\x08\0\x02id\0%minecraft:enchantment.fire_protection\x08\0\toperation\0\x13add_multiplied_base\0\t\0\x1Bminecraft:damage_protection\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first@\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x01\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x02\x01\0\x08expected\x01\x08\0\x02id\0\x11minecraft:is_fire\0\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/armor\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x12\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/armor\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "flame" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Benchantment.minecraft.flame\0\n\0\x07effects\t\0\x1Cminecraft:projectile_spawned\n\0\0\0\x01\n\0\x06effect\x06\0\x08duration@Y\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:ignite\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x14\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1A#minecraft:enchantable/bow\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "fortune" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.fortune\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/mining\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\"#minecraft:enchantable/mining_loot\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "frost_walker" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\"enchantment.minecraft.frost_walker\0\n\0\x07effects\t\0\x19minecraft:damage_immunity\n\0\0\0\x01\n\0\x06effect\0\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x02\x01\0\x08expected\x01\x08\0\x02id\0\x1Cminecraft:burn_from_stepping\0\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\t\0\x1Aminecraft:location_changed\n\0\0\0\x01\n\0\x06effect\n\0\x0Bblock_state\n\0\x05state\x08\0\x04Name\0\x15minecraft:frosted_ice\n\0\nProperties\x08\0\x03age\0\x010\0\0\x08\0\x04type\0\x1Fminecraft:simple_state_provider\0\x06\0\x06height?\xF0\0\0\0\0\0\0\t\0\x06offset\x04\0\0\0\x03\0\0\0\0\0\0\0\0\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\0\0\0\0\0\0\0\0\n\0\tpredicate\t\0\npredicates\n\0\0\0\x04\t\0\x06offset\x04\0\0\0\x03\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x08\0\x03tag\0\rminecraft:air\x08\0\x04type\0\x1Cminecraft:matching_block_tag\0\x08\0\x06blocks\0\x0Fminecraft:water\x08\0\x04type\0\x19minecraft:matching_blocks\0\x08\0\x06fluids\0\x0Fminecraft:water\x08\0\x04type\0\x19minecraft:matching_fluids\0\x08\0\x04type\0\x16minecraft:unobstructed\0\x08\0\x04type\0\x10minecraft:all_of\0\n\0\x06radius\x06\0\x03max@0\0\0\0\0\0\0\x06\0\x03min\0\0\0\0\0\0\0\0\x08\0\x04type\0\x11minecraft:clamped\n\0\x05value\x06\0\x04base@\x08\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\x08\0\x12trigger_game_event\0\x15minecraft:block_place\x08\0\x04type\0\x16minecraft:replace_disk\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\x0Cis_on_ground\x01\0\0\0\x08\0\tcondition\0\x12minecraft:inverted\n\0\x04term\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x07vehicle\0\0\0\0\0\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/boots\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x02\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x04feet\x08\0\x0Fsupported_items\0!#minecraft:enchantable/foot_armor\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "impaling" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Eenchantment.minecraft.impaling\0\n\0\x07effects\t\0\x10minecraft:damage\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\x04\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x04\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\x08\0\x04type\0 #minecraft:sensitive_to_impaling\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x15\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/trident\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "infinity" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0\x1Eenchantment.minecraft.infinity\0\n\0\x07effects\t\0\x12minecraft:ammo_use\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:set\x06\0\x05value\0\0\0\0\0\0\0\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x14minecraft:match_tool\n\0\tpredicate\x08\0\x05items\0\x0Fminecraft:arrow\0\0\0\0\x08\0\rexclusive_set\0\x1C#minecraft:exclusive_set/bow\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x14\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1A#minecraft:enchantable/bow\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "knockback" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Fenchantment.minecraft.knockback\0\n\0\x07effects\t\0\x13minecraft:knockback\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x007\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x04\0\tmax_level\0\0\0\0\0\0\0\x02\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0##minecraft:enchantable/melee_weapon\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "looting" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.looting\0\n\0\x07effects\t\0\x19minecraft:equipment_drops\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\x84z\xE1G\xAE\x14{\x06\0\x15per_level_above_first?\x84z\xE1G\xAE\x14{\x08\0\x04type\0\x10minecraft:linear\0\0\x08\0\tenchanted\0\x08attacker\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x08attacker\n\0\tpredicate\x08\0\x04type\0\x10minecraft:player\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0##minecraft:enchantable/melee_weapon\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "loyalty" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.loyalty\0\n\0\x07effects\t\0%minecraft:trident_return_acceleration\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0C\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x07\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/trident\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "luck_of_the_sea" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0%enchantment.minecraft.luck_of_the_sea\0\n\0\x07effects\t\0\x1Cminecraft:fishing_luck_bonus\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/fishing\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "lunge" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Benchantment.minecraft.lunge\0\n\0\x07effects\t\0\x1Eminecraft:post_piercing_attack\n\0\0\0\x01\n\0\x06effect\t\0\x07effects\n\0\0\0\x04\x06\0\x06amount?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x1Cminecraft:change_item_damage\0\n\0\x06amount\x06\0\x04base@\x10\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x10\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x1Aminecraft:apply_exhaustion\0\t\0\x10coordinate_scale\x06\0\0\0\x03?\xF0\0\0\0\0\0\0\0\0\0\0\0\0\0\0?\xF0\0\0\0\0\0\0\t\0\tdirection\x06\0\0\0\x03\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0?\xF0\0\0\0\0\0\0\n\0\tmagnitude\x06\0\x04base?\xDDO\xDF;dZ\x1D\x06\0\x15per_level_above_first?\xDDO\xDF;dZ\x1D\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x17minecraft:apply_impulse\0\x06\0\x05pitch?\xF0\0\0\0\0\0\0\t\0\x05sound\x08\0\0\0\x03\0\x1Cminecraft:item.spear.lunge_1\0\x1Cminecraft:item.spear.lunge_2\0\x1Cminecraft:item.spear.lunge_3\x08\0\x04type\0\x14minecraft:play_sound\x06\0\x06volume?\xF0\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:all_of\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x03\x08\0\tcondition\0\x12minecraft:inverted\n\0\x04term\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x07vehicle\0\0\0\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\x0Eis_fall_flying\0\0\0\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\x0Bis_in_water\0\0\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x04hand\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/lunge\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "lure" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Aenchantment.minecraft.lure\0\n\0\x07effects\t\0 minecraft:fishing_time_reduction\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\x14\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x14\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/fishing\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "mending" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.mending\0\n\0\x07effects\t\0\x18minecraft:repair_with_xp\n\0\0\0\x01\n\0\x06effect\x06\0\x06factor@\0\0\0\0\0\0\0\x08\0\x04type\0\x12minecraft:multiply\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0K\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x19\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x19\0\t\0\x05slots\x08\0\0\0\x01\0\x03any\x08\0\x0Fsupported_items\0!#minecraft:enchantable/durability\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "multishot" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Fenchantment.minecraft.multishot\0\n\0\x07effects\t\0\x1Aminecraft:projectile_count\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first@\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\t\0\x1Bminecraft:projectile_spread\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@$\0\0\0\0\0\0\x06\0\x15per_level_above_first@$\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\x08\0\rexclusive_set\0!#minecraft:exclusive_set/crossbow\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x14\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1F#minecraft:enchantable/crossbow\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "piercing" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0\x1Eenchantment.minecraft.piercing\0\n\0\x07effects\t\0\x1Dminecraft:projectile_piercing\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\x08\0\rexclusive_set\0!#minecraft:exclusive_set/crossbow\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1F#minecraft:enchantable/crossbow\x04\0\x06weight\0\0\0\0\0\0\0\n\0" } , StaticRegistryEntry { name : "power" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0\x1Benchantment.minecraft.power\0\n\0\x07effects\t\0\x10minecraft:damage\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xE0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x0Fdirect_attacker\n\0\tpredicate\x08\0\x04type\0\x11#minecraft:arrows\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x10\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1A#minecraft:enchantable/bow\x04\0\x06weight\0\0\0\0\0\0\0\n\0" } , StaticRegistryEntry { name : "projectile_protection" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0+enchantment.minecraft.projectile_protection\0\n\0\x07effects\t\0\x1Bminecraft:damage_protection\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first@\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x02\x01\0\x08expected\x01\x08\0\x02id\0\x17minecraft:is_projectile\0\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/armor\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\t\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x06\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x03\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x06\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/armor\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "protection" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.protection\0\n\0\x07effects\t\0\x1Bminecraft:damage_protection\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\"minecraft:damage_source_properties\n\0\tpredicate\t\0\x04tags\n\0\0\0\x01\x01\0\x08expected\0\x08\0\x02id\0\"minecraft:bypasses_invulnerability\0\0\0\0\0\x08\0\rexclusive_set\0\x1E#minecraft:exclusive_set/armor\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x0C\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x0B\0\x04\0\tmax_level\0\0\0\0\0\0\0\x04\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x0B\0\t\0\x05slots\x08\0\0\0\x01\0\x05armor\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/armor\x04\0\x06weight\0\0\0\0\0\0\0\n\0" } , StaticRegistryEntry { name : "punch" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Benchantment.minecraft.punch\0\n\0\x07effects\t\0\x13minecraft:knockback\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x0Fdirect_attacker\n\0\tpredicate\x08\0\x04type\0\x11#minecraft:arrows\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0%\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x04\0\tmax_level\0\0\0\0\0\0\0\x02\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0C\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1A#minecraft:enchantable/bow\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "quick_charge" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\"enchantment.minecraft.quick_charge\0\n\0\x07effects\n\0\x1Eminecraft:crossbow_charge_time\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base\xBF\xD0\0\0\0\0\0\0\x06\0\x15per_level_above_first\xBF\xD0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\t\0\"minecraft:crossbow_charging_sounds\n\0\0\0\x03\x08\0\x03end\0#minecraft:item.crossbow.loading_end\x08\0\x05start\0&minecraft:item.crossbow.quick_charge_1\0\x08\0\x03end\0#minecraft:item.crossbow.loading_end\x08\0\x05start\0&minecraft:item.crossbow.quick_charge_2\0\x08\0\x03end\0#minecraft:item.crossbow.loading_end\x08\0\x05start\0&minecraft:item.crossbow.quick_charge_3\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0C\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\t\0\x05slots\x08\0\0\0\x02\0\x08mainhand\0\x07offhand\x08\0\x0Fsupported_items\0\x1F#minecraft:enchantable/crossbow\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "respiration" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0!enchantment.minecraft.respiration\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0\x16minecraft:oxygen_bonus\x08\0\x02id\0!minecraft:enchantment.respiration\x08\0\toperation\0\tadd_value\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0(\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x04head\x08\0\x0Fsupported_items\0!#minecraft:enchantable/head_armor\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "riptide" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Denchantment.minecraft.riptide\0\n\0\x07effects\t\0\x17minecraft:trident_sound\x08\0\0\0\x03\0 minecraft:item.trident.riptide_1\0 minecraft:item.trident.riptide_2\0 minecraft:item.trident.riptide_3\n\0&minecraft:trident_spin_attack_strength\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF8\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xE8\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\x08\0\rexclusive_set\0 #minecraft:exclusive_set/riptide\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x11\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x07\0\t\0\x05slots\x08\0\0\0\x01\0\x04hand\x08\0\x0Fsupported_items\0\x1E#minecraft:enchantable/trident\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "sharpness" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0\x1Fenchantment.minecraft.sharpness\0\n\0\x07effects\t\0\x10minecraft:damage\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xE0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x15\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x0B\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x01\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x0B\0\x08\0\rprimary_items\0##minecraft:enchantable/melee_weapon\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0##minecraft:enchantable/sharp_weapon\x04\0\x06weight\0\0\0\0\0\0\0\n\0" } , StaticRegistryEntry { name : "silk_touch" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.silk_touch\0\n\0\x07effects\t\0\x1Aminecraft:block_experience\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:set\x06\0\x05value\0\0\0\0\0\0\0\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/mining\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\"#minecraft:enchantable/mining_loot\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "smite" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Benchantment.minecraft.smite\0\n\0\x07effects\t\0\x10minecraft:damage\n\0\0\0\x01\n\0\x06effect\x08\0\x04type\0\rminecraft:add\n\0\x05value\x06\0\x04base@\x04\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x04\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\x08\0\x04type\0\x1D#minecraft:sensitive_to_smite\0\0\0\0\x08\0\rexclusive_set\0\x1F#minecraft:exclusive_set/damage\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x05\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x08\0\rprimary_items\0##minecraft:enchantable/melee_weapon\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1D#minecraft:enchantable/weapon\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "soul_speed" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.soul_speed\0\n\0\x07effects\t\0\x1Aminecraft:location_changed\n\0\0\0\x02\n\0\x06effect\t\0\x07effects\n\0\0\0\x02\n\0\x06amount\x06\0\x04base?\xA4\xBCj~\xF9\xDB#\x06\0\x15per_level_above_first?\x85\x81\x06$\xDD/\x1B\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0\x18minecraft:movement_speed\x08\0\x02id\0 minecraft:enchantment.soul_speed\x08\0\toperation\0\tadd_value\x08\0\x04type\0\x13minecraft:attribute\0\x06\0\x06amount?\xF0\0\0\0\0\0\0\x08\0\tattribute\0\x1Dminecraft:movement_efficiency\x08\0\x02id\0 minecraft:enchantment.soul_speed\x08\0\toperation\0\tadd_value\x08\0\x04type\0\x13minecraft:attribute\0\x08\0\x04type\0\x10minecraft:all_of\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\x08\0\tcondition\0\x12minecraft:inverted\n\0\x04term\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x07vehicle\0\0\0\0\x08\0\tcondition\0\x10minecraft:any_of\t\0\x05terms\n\0\0\0\x02\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x03\x01\0\x06active\x01\x08\0\tcondition\0\"minecraft:enchantment_active_check\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\tis_flying\0\0\0\0\x08\0\tcondition\0\x10minecraft:any_of\t\0\x05terms\n\0\0\0\x02\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x14movement_affected_by\n\0\x05block\x08\0\x06blocks\0\x1C#minecraft:soul_speed_blocks\0\0\0\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\x0Cis_on_ground\0\0\0\0\0\0\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\x01\0\x06active\0\x08\0\tcondition\0\"minecraft:enchantment_active_check\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\tis_flying\0\0\n\0\x14movement_affected_by\n\0\x05block\x08\0\x06blocks\0\x1C#minecraft:soul_speed_blocks\0\0\0\0\0\0\0\0\n\0\x06effect\x06\0\x06amount?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x1Cminecraft:change_item_damage\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\n\0\x06chance\x06\0\x06amount?\xA4z\xE1G\xAE\x14{\x08\0\x04type\0\x1Bminecraft:enchantment_level\0\x08\0\tcondition\0\x17minecraft:random_chance\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\x0Cis_on_ground\x01\0\n\0\x14movement_affected_by\n\0\x05block\x08\0\x06blocks\0\x1C#minecraft:soul_speed_blocks\0\0\0\0\0\0\t\0\x0Eminecraft:tick\n\0\0\0\x02\n\0\x06effect\n\0\x13horizontal_position\x08\0\x04type\0\x0Fin_bounding_box\0\n\0\x13horizontal_velocity\x06\0\x0Emovement_scale\xBF\xC9\x99\x99\x99\x99\x99\x9A\0\n\0\x08particle\x08\0\x04type\0\x0Eminecraft:soul\0\x06\0\x05speed?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x19minecraft:spawn_particles\n\0\x11vertical_position\x06\0\x06offset?\xB9\x99\x99\x99\x99\x99\x9A\x08\0\x04type\0\x0Fentity_position\0\n\0\x11vertical_velocity\x06\0\x04base?\xB9\x99\x99\x99\x99\x99\x9A\0\0\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\tis_flying\0\x01\0\x0Cis_on_ground\x01\0\n\0\x08movement\n\0\x10horizontal_speed\x06\0\x03min>\xE4\xF8\xB5\x80\0\0\0\0\0\n\0\x14movement_affected_by\n\0\x05block\x08\0\x06blocks\0\x1C#minecraft:soul_speed_blocks\0\0\x04\0\rperiodic_tick\0\0\0\0\0\0\0\x05\0\0\0\n\0\x06effect\n\0\x05pitch\x06\0\rmax_exclusive?\xF0\0\0\0\0\0\0\x06\0\rmin_inclusive?\xE3333333\x08\0\x04type\0\x11minecraft:uniform\0\x08\0\x05sound\0\x1Eminecraft:particle.soul_escape\x08\0\x04type\0\x14minecraft:play_sound\x06\0\x06volume?\xE3333333\0\n\0\x0Crequirements\x08\0\tcondition\0\x10minecraft:all_of\t\0\x05terms\n\0\0\0\x02\x06\0\x06chance?\xD6ffffff\x08\0\tcondition\0\x17minecraft:random_chance\0\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x04this\n\0\tpredicate\n\0\x05flags\x01\0\tis_flying\0\x01\0\x0Cis_on_ground\x01\0\n\0\x08movement\n\0\x10horizontal_speed\x06\0\x03min>\xE4\xF8\xB5\x80\0\0\0\0\0\n\0\x14movement_affected_by\n\0\x05block\x08\0\x06blocks\0\x1C#minecraft:soul_speed_blocks\0\0\x04\0\rperiodic_tick\0\0\0\0\0\0\0\x05\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\n\0\t\0\x05slots\x08\0\0\0\x01\0\x04feet\x08\0\x0Fsupported_items\0!#minecraft:enchantable/foot_armor\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "sweeping_edge" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0#enchantment.minecraft.sweeping_edge\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\n\0\x0Bdenominator\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\n\0\tnumerator\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x12minecraft:fraction\0\x08\0\tattribute\0\x1Fminecraft:sweeping_damage_ratio\x08\0\x02id\0#minecraft:enchantment.sweeping_edge\x08\0\toperation\0\tadd_value\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0\x14\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1F#minecraft:enchantable/sweeping\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" } , StaticRegistryEntry { name : "swift_sneak" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0!enchantment.minecraft.swift_sneak\0\n\0\x07effects\t\0\x14minecraft:attributes\n\0\0\0\x01\n\0\x06amount\x06\0\x04base?\xC3333333\x06\0\x15per_level_above_first?\xC3333333\x08\0\x04type\0\x10minecraft:linear\0\x08\0\tattribute\0\x18minecraft:sneaking_speed\x08\0\x02id\0!minecraft:enchantment.swift_sneak\x08\0\toperation\0\tadd_value\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0K\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x19\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x19\0\t\0\x05slots\x08\0\0\0\x01\0\x04legs\x08\0\x0Fsupported_items\0 #minecraft:enchantable/leg_armor\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "thorns" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cenchantment.minecraft.thorns\0\n\0\x07effects\t\0\x15minecraft:post_attack\n\0\0\0\x01\x08\0\x08affected\0\x08attacker\n\0\x06effect\t\0\x07effects\n\0\0\0\x02\x08\0\x0Bdamage_type\0\x10minecraft:thorns\x06\0\nmax_damage@\x14\0\0\0\0\0\0\x06\0\nmin_damage?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x17minecraft:damage_entity\0\x06\0\x06amount@\0\0\0\0\0\0\0\x08\0\x04type\0\x1Cminecraft:change_item_damage\0\x08\0\x04type\0\x10minecraft:all_of\0\x08\0\tenchanted\0\x06victim\n\0\x0Crequirements\n\0\x06chance\n\0\x06amount\x06\0\x04base?\xC3333333\x06\0\x15per_level_above_first?\xC3333333\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x1Bminecraft:enchantment_level\0\x08\0\tcondition\0\x17minecraft:random_chance\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0<\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\n\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x14\0\x08\0\rprimary_items\0\"#minecraft:enchantable/chest_armor\t\0\x05slots\x08\0\0\0\x01\0\x03any\x08\0\x0Fsupported_items\0\x1C#minecraft:enchantable/armor\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "unbreaking" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.unbreaking\0\n\0\x07effects\t\0\x15minecraft:item_damage\n\0\0\0\x02\n\0\x06effect\n\0\x06chance\n\0\x0Bdenominator\x06\0\x04base@$\0\0\0\0\0\0\x06\0\x15per_level_above_first@\x14\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\n\0\tnumerator\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first@\0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x12minecraft:fraction\0\x08\0\x04type\0\x19minecraft:remove_binomial\0\n\0\x0Crequirements\x08\0\tcondition\0\x14minecraft:match_tool\n\0\tpredicate\x08\0\x05items\0\x1C#minecraft:enchantable/armor\0\0\0\n\0\x06effect\n\0\x06chance\n\0\x0Bdenominator\x06\0\x04base@\0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\n\0\tnumerator\x06\0\x04base?\xF0\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xF0\0\0\0\0\0\0\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x12minecraft:fraction\0\x08\0\x04type\0\x19minecraft:remove_binomial\0\n\0\x0Crequirements\x08\0\tcondition\0\x12minecraft:inverted\n\0\x04term\x08\0\tcondition\0\x14minecraft:match_tool\n\0\tpredicate\x08\0\x05items\0\x1C#minecraft:enchantable/armor\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x007\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x05\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\x08\0\t\0\x05slots\x08\0\0\0\x01\0\x03any\x08\0\x0Fsupported_items\0!#minecraft:enchantable/durability\x04\0\x06weight\0\0\0\0\0\0\0\x05\0" } , StaticRegistryEntry { name : "vanishing_curse" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0%enchantment.minecraft.vanishing_curse\0\n\0\x07effects\n\0 minecraft:prevent_equipment_drop\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\x002\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\x04\0\tmax_level\0\0\0\0\0\0\0\x01\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x19\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\0\0\t\0\x05slots\x08\0\0\0\x01\0\x03any\x08\0\x0Fsupported_items\0 #minecraft:enchantable/vanishing\x04\0\x06weight\0\0\0\0\0\0\0\x01\0" } , StaticRegistryEntry { name : "wind_burst" , data : b"\n\x04\0\nanvil_cost\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0 enchantment.minecraft.wind_burst\0\n\0\x07effects\t\0\x15minecraft:post_attack\n\0\0\0\x01\x08\0\x08affected\0\x08attacker\n\0\x06effect\x08\0\x11block_interaction\0\x07trigger\x08\0\rimmune_blocks\0(#minecraft:blocks_wind_charge_explosions\n\0\x14knockback_multiplier\n\0\x08fallback\x06\0\x04base?\xF8\0\0\0\0\0\0\x06\0\x15per_level_above_first?\xD6ffffff\x08\0\x04type\0\x10minecraft:linear\0\x08\0\x04type\0\x10minecraft:lookup\t\0\x06values\x06\0\0\0\x03?\xF3333333?\xFC\0\0\0\0\0\0@\x01\x99\x99\x99\x99\x99\x9A\0\n\0\x0Elarge_particle\x08\0\x04type\0\x1Cminecraft:gust_emitter_large\0\x06\0\x06radius@\x0C\0\0\0\0\0\0\n\0\x0Esmall_particle\x08\0\x04type\0\x1Cminecraft:gust_emitter_small\0\x08\0\x05sound\0'minecraft:entity.wind_charge.wind_burst\x08\0\x04type\0\x11minecraft:explode\0\x08\0\tenchanted\0\x08attacker\n\0\x0Crequirements\x08\0\tcondition\0\x1Bminecraft:entity_properties\x08\0\x06entity\0\x0Fdirect_attacker\n\0\tpredicate\n\0\x05flags\x01\0\tis_flying\0\0\n\0\x08movement\n\0\rfall_distance\x06\0\x03min?\xF8\0\0\0\0\0\0\0\0\0\0\0\0\n\0\x08max_cost\x04\0\x04base\0\0\0\0\0\0\0A\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\x04\0\tmax_level\0\0\0\0\0\0\0\x03\n\0\x08min_cost\x04\0\x04base\0\0\0\0\0\0\0\x0F\x04\0\x15per_level_above_first\0\0\0\0\0\0\0\t\0\t\0\x05slots\x08\0\0\0\x01\0\x08mainhand\x08\0\x0Fsupported_items\0\x1B#minecraft:enchantable/mace\x04\0\x06weight\0\0\0\0\0\0\0\x02\0" }] , } , StaticRegistry { registry_id : "jukebox_song" , entries : & [StaticRegistryEntry { name : "11" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0B\n\0\x0Bdescription\x08\0\ttranslate\0\x19jukebox_song.minecraft.11\0\x06\0\x11length_in_seconds@Q\xC0\0\0\0\0\0\x08\0\x0Bsound_event\0\x17minecraft:music_disc.11\0" } , StaticRegistryEntry { name : "13" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x01\n\0\x0Bdescription\x08\0\ttranslate\0\x19jukebox_song.minecraft.13\0\x06\0\x11length_in_seconds@f@\0\0\0\0\0\x08\0\x0Bsound_event\0\x17minecraft:music_disc.13\0" } , StaticRegistryEntry { name : "5" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0F\n\0\x0Bdescription\x08\0\ttranslate\0\x18jukebox_song.minecraft.5\0\x06\0\x11length_in_seconds@f@\0\0\0\0\0\x08\0\x0Bsound_event\0\x16minecraft:music_disc.5\0" } , StaticRegistryEntry { name : "blocks" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x03\n\0\x0Bdescription\x08\0\ttranslate\0\x1Djukebox_song.minecraft.blocks\0\x06\0\x11length_in_seconds@u\x90\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Bminecraft:music_disc.blocks\0" } , StaticRegistryEntry { name : "cat" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x02\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ajukebox_song.minecraft.cat\0\x06\0\x11length_in_seconds@g \0\0\0\0\0\x08\0\x0Bsound_event\0\x18minecraft:music_disc.cat\0" } , StaticRegistryEntry { name : "chirp" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x04\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cjukebox_song.minecraft.chirp\0\x06\0\x11length_in_seconds@g \0\0\0\0\0\x08\0\x0Bsound_event\0\x1Aminecraft:music_disc.chirp\0" } , StaticRegistryEntry { name : "creator" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0C\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ejukebox_song.minecraft.creator\0\x06\0\x11length_in_seconds@f\0\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Cminecraft:music_disc.creator\0" } , StaticRegistryEntry { name : "creator_music_box" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0B\n\0\x0Bdescription\x08\0\ttranslate\0(jukebox_song.minecraft.creator_music_box\0\x06\0\x11length_in_seconds@R@\0\0\0\0\0\x08\0\x0Bsound_event\0&minecraft:music_disc.creator_music_box\0" } , StaticRegistryEntry { name : "far" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x05\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ajukebox_song.minecraft.far\0\x06\0\x11length_in_seconds@e\xC0\0\0\0\0\0\x08\0\x0Bsound_event\0\x18minecraft:music_disc.far\0" } , StaticRegistryEntry { name : "lava_chicken" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\t\n\0\x0Bdescription\x08\0\ttranslate\0#jukebox_song.minecraft.lava_chicken\0\x06\0\x11length_in_seconds@`\xC0\0\0\0\0\0\x08\0\x0Bsound_event\0!minecraft:music_disc.lava_chicken\0" } , StaticRegistryEntry { name : "mall" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x06\n\0\x0Bdescription\x08\0\ttranslate\0\x1Bjukebox_song.minecraft.mall\0\x06\0\x11length_in_seconds@h\xA0\0\0\0\0\0\x08\0\x0Bsound_event\0\x19minecraft:music_disc.mall\0" } , StaticRegistryEntry { name : "mellohi" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x07\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ejukebox_song.minecraft.mellohi\0\x06\0\x11length_in_seconds@X\0\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Cminecraft:music_disc.mellohi\0" } , StaticRegistryEntry { name : "otherside" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0E\n\0\x0Bdescription\x08\0\ttranslate\0 jukebox_song.minecraft.otherside\0\x06\0\x11length_in_seconds@h`\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Eminecraft:music_disc.otherside\0" } , StaticRegistryEntry { name : "pigstep" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\r\n\0\x0Bdescription\x08\0\ttranslate\0\x1Ejukebox_song.minecraft.pigstep\0\x06\0\x11length_in_seconds@b\xA0\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Cminecraft:music_disc.pigstep\0" } , StaticRegistryEntry { name : "precipice" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\r\n\0\x0Bdescription\x08\0\ttranslate\0 jukebox_song.minecraft.precipice\0\x06\0\x11length_in_seconds@r\xB0\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Eminecraft:music_disc.precipice\0" } , StaticRegistryEntry { name : "relic" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0E\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cjukebox_song.minecraft.relic\0\x06\0\x11length_in_seconds@k@\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Aminecraft:music_disc.relic\0" } , StaticRegistryEntry { name : "stal" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x08\n\0\x0Bdescription\x08\0\ttranslate\0\x1Bjukebox_song.minecraft.stal\0\x06\0\x11length_in_seconds@b\xC0\0\0\0\0\0\x08\0\x0Bsound_event\0\x19minecraft:music_disc.stal\0" } , StaticRegistryEntry { name : "strad" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\t\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cjukebox_song.minecraft.strad\0\x06\0\x11length_in_seconds@g\x80\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Aminecraft:music_disc.strad\0" } , StaticRegistryEntry { name : "tears" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\n\n\0\x0Bdescription\x08\0\ttranslate\0\x1Cjukebox_song.minecraft.tears\0\x06\0\x11length_in_seconds@e\xE0\0\0\0\0\0\x08\0\x0Bsound_event\0\x1Aminecraft:music_disc.tears\0" } , StaticRegistryEntry { name : "wait" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\x0C\n\0\x0Bdescription\x08\0\ttranslate\0\x1Bjukebox_song.minecraft.wait\0\x06\0\x11length_in_seconds@m\xC0\0\0\0\0\0\x08\0\x0Bsound_event\0\x19minecraft:music_disc.wait\0" } , StaticRegistryEntry { name : "ward" , data : b"\n\x04\0\x11comparator_output\0\0\0\0\0\0\0\n\n\0\x0Bdescription\x08\0\ttranslate\0\x1Bjukebox_song.minecraft.ward\0\x06\0\x11length_in_seconds@o`\0\0\0\0\0\x08\0\x0Bsound_event\0\x19minecraft:music_disc.ward\0" }] , } , StaticRegistry { registry_id : "instrument" , entries : & [StaticRegistryEntry { name : "admire_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0%instrument.minecraft.admire_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.4\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "call_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0#instrument.minecraft.call_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.5\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "dream_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0$instrument.minecraft.dream_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.7\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "feel_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0#instrument.minecraft.feel_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.3\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "ponder_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0%instrument.minecraft.ponder_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.0\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "seek_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0#instrument.minecraft.seek_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.2\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "sing_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0#instrument.minecraft.sing_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.1\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" } , StaticRegistryEntry { name : "yearn_goat_horn" , data : b"\n\n\0\x0Bdescription\x08\0\ttranslate\0$instrument.minecraft.yearn_goat_horn\0\x06\0\x05range@p\0\0\0\0\0\0\x08\0\x0Bsound_event\0 minecraft:item.goat_horn.sound.6\x06\0\x0Cuse_duration@\x1C\0\0\0\0\0\0\0" }] , } , StaticRegistry { registry_id : "test_environment" , entries : & [StaticRegistryEntry { name : "default" , data : b"\n\t\0\x0Bdefinitions\0\0\0\0\0\x08\0\x04type\0\x10minecraft:all_of\0" }] , } , StaticRegistry { registry_id : "test_instance" , entries : & [StaticRegistryEntry { name : "always_pass" , data : b"\n\x08\0\x0Benvironment\0\x11minecraft:default\x08\0\x08function\0\x15minecraft:always_pass\x04\0\tmax_ticks\0\0\0\0\0\0\0\x01\x01\0\x08required\0\x04\0\x0Bsetup_ticks\0\0\0\0\0\0\0\x01\x08\0\tstructure\0\x0Fminecraft:empty\x08\0\x04type\0\x12minecraft:function\0" }] , } , StaticRegistry { registry_id : "dialog" , entries : & [StaticRegistryEntry { name : "custom_options" , data : b"\n\x04\0\x0Cbutton_width\0\0\0\0\0\0\x016\x04\0\x07columns\0\0\0\0\0\0\0\x01\x08\0\x07dialogs\0!#minecraft:pause_screen_additions\n\0\x0Bexit_action\n\0\x05label\x08\0\ttranslate\0\x08gui.back\0\x04\0\x05width\0\0\0\0\0\0\0\xC8\0\n\0\x0Eexternal_title\x08\0\ttranslate\0\x13menu.custom_options\0\n\0\x05title\x08\0\ttranslate\0\x19menu.custom_options.title\0\x08\0\x04type\0\x15minecraft:dialog_list\0" } , StaticRegistryEntry { name : "quick_actions" , data : b"\n\x04\0\x0Cbutton_width\0\0\0\0\0\0\x016\x04\0\x07columns\0\0\0\0\0\0\0\x01\x08\0\x07dialogs\0\x18#minecraft:quick_actions\n\0\x0Bexit_action\n\0\x05label\x08\0\ttranslate\0\x08gui.back\0\x04\0\x05width\0\0\0\0\0\0\0\xC8\0\n\0\x0Eexternal_title\x08\0\ttranslate\0\x12menu.quick_actions\0\n\0\x05title\x08\0\ttranslate\0\x18menu.quick_actions.title\0\x08\0\x04type\0\x15minecraft:dialog_list\0" } , StaticRegistryEntry { name : "server_links" , data : b"\n\x04\0\x0Cbutton_width\0\0\0\0\0\0\x016\x04\0\x07columns\0\0\0\0\0\0\0\x01\n\0\x0Bexit_action\n\0\x05label\x08\0\ttranslate\0\x08gui.back\0\x04\0\x05width\0\0\0\0\0\0\0\xC8\0\n\0\x0Eexternal_title\x08\0\ttranslate\0\x11menu.server_links\0\n\0\x05title\x08\0\ttranslate\0\x17menu.server_links.title\0\x08\0\x04type\0\x16minecraft:server_links\0" }] , } , StaticRegistry { registry_id : "timeline" , entries : & [StaticRegistryEntry { name : "day" , data : b"\n\x04\0\x0Cperiod_ticks\0\0\0\0\0\0]\xC0\n\0\x06tracks\n\0#minecraft:audio/firefly_bush_sounds\t\0\tkeyframes\n\0\0\0\x02\x04\0\x05ticks\0\0\0\0\0\x0018\x01\0\x05value\x01\0\x04\0\x05ticks\0\0\0\0\0\0[i\x01\0\x05value\0\0\x08\0\x08modifier\0\x02or\0\n\0\"minecraft:gameplay/creaking_active\t\0\tkeyframes\n\0\0\0\x02\x04\0\x05ticks\0\0\0\0\0\x0018\x01\0\x05value\x01\0\x04\0\x05ticks\0\0\0\0\0\0[i\x01\0\x05value\0\0\x08\0\x08modifier\0\x02or\0\n\0\"minecraft:gameplay/sky_light_level\t\0\tkeyframes\n\0\0\0\x04\x04\0\x05ticks\0\0\0\0\0\0\0\x85\x06\0\x05value?\xF0\0\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\0.[\x06\0\x05value?\xF0\0\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\x005f\x06\0\x05value?\xD1\x11\x11\x1Fb\x1A\xFD\0\x04\0\x05ticks\0\0\0\0\0\0W:\x06\0\x05value?\xD1\x11\x11\x1Fb\x1A\xFD\0\x08\0\x08modifier\0\x08multiply\0\n\0\x1Cminecraft:visual/cloud_color\t\0\tkeyframes\n\0\0\0\x04\x04\0\x05ticks\0\0\0\0\0\0\0\x85\x04\0\x05value\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\0\x04\0\x05ticks\0\0\0\0\0\0.[\x04\0\x05value\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\0\x04\0\x05ticks\0\0\0\0\0\x005f\x04\0\x05value\xFF\xFF\xFF\xFF\xFF\x19\x19&\0\x04\0\x05ticks\0\0\0\0\0\0W:\x04\0\x05value\xFF\xFF\xFF\xFF\xFF\x19\x19&\0\x08\0\x08modifier\0\x08multiply\0\n\0\x1Aminecraft:visual/fog_color\t\0\tkeyframes\n\0\0\0\x04\x04\0\x05ticks\0\0\0\0\0\0\0\x85\x08\0\x05value\0\x07#ffffff\0\x04\0\x05ticks\0\0\0\0\0\0.[\x08\0\x05value\0\x07#ffffff\0\x04\0\x05ticks\0\0\0\0\0\x005f\x08\0\x05value\0\x07#0f0f16\0\x04\0\x05ticks\0\0\0\0\0\0W:\x08\0\x05value\0\x07#0f0f16\0\x08\0\x08modifier\0\x08multiply\0\n\0\x1Bminecraft:visual/moon_angle\n\0\x04ease\t\0\x0Ccubic_bezier\x06\0\0\0\x04?\xD7+\x02\x0CI\xBA^?\xCE\xD9\x16\x87+\x02\x0C?\xE4j~\xF9\xDB\"\xD1?\xE8I\xBA^5?}\0\t\0\tkeyframes\n\0\0\0\x02\x04\0\x05ticks\0\0\0\0\0\0\x17p\x06\0\x05value@\x80\xE0\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\0\x17p\x06\0\x05value@f\x80\0\0\0\0\0\0\0\n\0\x1Aminecraft:visual/sky_color\t\0\tkeyframes\n\0\0\0\x04\x04\0\x05ticks\0\0\0\0\0\0\0\x85\x08\0\x05value\0\x07#ffffff\0\x04\0\x05ticks\0\0\0\0\0\0.[\x08\0\x05value\0\x07#ffffff\0\x04\0\x05ticks\0\0\0\0\0\x005f\x08\0\x05value\0\x07#000000\0\x04\0\x05ticks\0\0\0\0\0\0W:\x08\0\x05value\0\x07#000000\0\x08\0\x08modifier\0\x08multiply\0\n\0 minecraft:visual/sky_light_color\t\0\tkeyframes\n\0\0\0\x04\x04\0\x05ticks\0\0\0\0\0\0\x02\xDA\x08\0\x05value\0\x07#ffffff\0\x04\0\x05ticks\0\0\0\0\0\0,\x06\x08\0\x05value\0\x07#ffffff\0\x04\0\x05ticks\0\0\0\0\0\x003T\x08\0\x05value\0\x07#7a7aff\0\x04\0\x05ticks\0\0\0\0\0\0YL\x08\0\x05value\0\x07#7a7aff\0\x08\0\x08modifier\0\x08multiply\0\n\0!minecraft:visual/sky_light_factor\t\0\tkeyframes\n\0\0\0\x04\x04\0\x05ticks\0\0\0\0\0\0\x02\xDA\x06\0\x05value?\xF0\0\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\0,\x06\x06\0\x05value?\xF0\0\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\x003T\x06\0\x05value?\xCE\xB8Q\xEB\x85\x1E\xB8\0\x04\0\x05ticks\0\0\0\0\0\0YL\x06\0\x05value?\xCE\xB8Q\xEB\x85\x1E\xB8\0\x08\0\x08modifier\0\x08multiply\0\n\0\x1Bminecraft:visual/star_angle\n\0\x04ease\t\0\x0Ccubic_bezier\x06\0\0\0\x04?\xD7+\x02\x0CI\xBA^?\xCE\xD9\x16\x87+\x02\x0C?\xE4j~\xF9\xDB\"\xD1?\xE8I\xBA^5?}\0\t\0\tkeyframes\n\0\0\0\x02\x04\0\x05ticks\0\0\0\0\0\0\x17p\x06\0\x05value@v\x80\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\0\x17p\x06\0\x05value\0\0\0\0\0\0\0\0\0\0\n\0 minecraft:visual/star_brightness\t\0\tkeyframes\n\0\0\0\x0C\x04\0\x05ticks\0\0\0\0\0\0\0\\\x06\0\x05value?\xA2\xF1\xA9\xFB\xE7l\x8B\0\x04\0\x05ticks\0\0\0\0\0\0\x02s\x06\0\x05value\0\0\0\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\0,m\x06\0\x05value\0\0\0\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\0-\xD4\x06\0\x05value?\x90bM\xD2\xF1\xA9\xFC\0\x04\0\x05ticks\0\0\0\0\0\0.\xB7\x06\0\x05value?\xA6\x87+\x02\x0CI\xBA\0\x04\0\x05ticks\0\0\0\0\0\x000o\x06\0\x05value?\xC2M\xD2\xF1\xA9\xFB\xE7\0\x04\0\x05ticks\0\0\0\0\0\x001\xB9\x06\0\x05value?\xD0\x83\x12n\x97\x8DP\0\x04\0\x05ticks\0\0\0\0\0\x003\xAC\x06\0\x05value?\xE0\0\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\0X\xF4\x06\0\x05value?\xE0\0\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\0Y\xF8\x06\0\x05value?\xD7K\xC6\xA7\xEF\x9D\xB2\0\x04\0\x05ticks\0\0\0\0\0\0[<\x06\0\x05value?\xCC\xCC\xCC\xCC\xCC\xCC\xCD\0\x04\0\x05ticks\0\0\0\0\0\0\\\xCE\x06\0\x05value?\xB9\xDB\"\xD0\xE5`B\0\x08\0\x08modifier\0\x07maximum\0\n\0\x1Aminecraft:visual/sun_angle\n\0\x04ease\t\0\x0Ccubic_bezier\x06\0\0\0\x04?\xD7+\x02\x0CI\xBA^?\xCE\xD9\x16\x87+\x02\x0C?\xE4j~\xF9\xDB\"\xD1?\xE8I\xBA^5?}\0\t\0\tkeyframes\n\0\0\0\x02\x04\0\x05ticks\0\0\0\0\0\0\x17p\x06\0\x05value@v\x80\0\0\0\0\0\0\x04\0\x05ticks\0\0\0\0\0\0\x17p\x06\0\x05value\0\0\0\0\0\0\0\0\0\0\n\0%minecraft:visual/sunrise_sunset_color\t\0\tkeyframes\n\0\0\0 \x04\0\x05ticks\0\0\0\0\0\0\0G\x08\0\x05value\0\t#5fefa333\0\x04\0\x05ticks\0\0\0\0\0\0\x016\x08\0\x05value\0\t#29f5ba33\0\x04\0\x05ticks\0\0\0\0\0\0\x025\x08\0\x05value\0\t#06fbd433\0\x04\0\x05ticks\0\0\0\0\0\0\x02\xDA\x08\0\x05value\0\t#00ffe533\0\x04\0\x05ticks\0\0\0\0\0\0,\x06\x08\0\x05value\0\t#00ffe533\0\x04\0\x05ticks\0\0\0\0\0\0,\x85\x08\0\x05value\0\t#04fcd833\0\x04\0\x05ticks\0\0\0\0\0\0-\x02\x08\0\x05value\0\t#0ff9cb33\0\x04\0\x05ticks\0\0\0\0\0\0-\xAA\x08\0\x05value\0\t#29f5ba33\0\x04\0\x05ticks\0\0\0\0\0\0.\x99\x08\0\x05value\0\t#5fefa333\0\x04\0\x05ticks\0\0\0\0\0\0/\xD3\x08\0\x05value\0\t#b1e78733\0\x04\0\x05ticks\0\0\0\0\0\x000F\x08\0\x05value\0\t#cce47e33\0\x04\0\x05ticks\0\0\0\0\0\x000\xE0\x08\0\x05value\0\t#e9e07233\0\x04\0\x05ticks\0\0\0\0\0\x001E\x08\0\x05value\0\t#f6dd6b33\0\x04\0\x05ticks\0\0\0\0\0\x001\xBC\x08\0\x05value\0\t#feda6333\0\x04\0\x05ticks\0\0\0\0\0\x002)\x08\0\x05value\0\t#fed75c33\0\x04\0\x05ticks\0\0\0\0\0\x002\xEB\x08\0\x05value\0\t#ecd25133\0\x04\0\x05ticks\0\0\0\0\0\x003\xC4\x08\0\x05value\0\t#c1cc4733\0\x04\0\x05ticks\0\0\0\0\0\x005\xCF\x08\0\x05value\0\t#36be3733\0\x04\0\x05ticks\0\0\0\0\0\x006@\x08\0\x05value\0\t#1fbb3533\0\x04\0\x05ticks\0\0\0\0\0\x006\xD7\x08\0\x05value\0\t#09b73333\0\x04\0\x05ticks\0\0\0\0\0\x007p\x08\0\x05value\0\t#00b33333\0\x04\0\x05ticks\0\0\0\0\0\0U/\x08\0\x05value\0\t#00b23333\0\x04\0\x05ticks\0\0\0\0\0\0U\xC9\x08\0\x05value\0\t#09b73333\0\x04\0\x05ticks\0\0\0\0\0\0V`\x08\0\x05value\0\t#1fbb3533\0\x04\0\x05ticks\0\0\0\0\0\0V\xD1\x08\0\x05value\0\t#36be3733\0\x04\0\x05ticks\0\0\0\0\0\0X\xDC\x08\0\x05value\0\t#c1cc4733\0\x04\0\x05ticks\0\0\0\0\0\0Y\xB5\x08\0\x05value\0\t#ecd25133\0\x04\0\x05ticks\0\0\0\0\0\0Zw\x08\0\x05value\0\t#fed75c33\0\x04\0\x05ticks\0\0\0\0\0\0Z\xE8\x08\0\x05value\0\t#feda6333\0\x04\0\x05ticks\0\0\0\0\0\0[\xC0\x08\0\x05value\0\t#e9e07233\0\x04\0\x05ticks\0\0\0\0\0\0\\Z\x08\0\x05value\0\t#cce47e33\0\x04\0\x05ticks\0\0\0\0\0\0\\\xCD\x08\0\x05value\0\t#b1e78733\0\0\0\0" } , StaticRegistryEntry { name : "early_game" , data : b"\n\0" } , StaticRegistryEntry { name : "moon" , data : b"\n\x04\0\x0Cperiod_ticks\0\0\0\0\0\x02\xEE\0\n\0\x06tracks\n\0\x1Bminecraft:visual/moon_phase\t\0\tkeyframes\n\0\0\0\x08\x04\0\x05ticks\0\0\0\0\0\0\0\0\x08\0\x05value\0\tfull_moon\0\x04\0\x05ticks\0\0\0\0\0\0]\xC0\x08\0\x05value\0\x0Ewaning_gibbous\0\x04\0\x05ticks\0\0\0\0\0\0\xBB\x80\x08\0\x05value\0\rthird_quarter\0\x04\0\x05ticks\0\0\0\0\0\x01\x19@\x08\0\x05value\0\x0Fwaning_crescent\0\x04\0\x05ticks\0\0\0\0\0\x01w\0\x08\0\x05value\0\x08new_moon\0\x04\0\x05ticks\0\0\0\0\0\x01\xD4\xC0\x08\0\x05value\0\x0Fwaxing_crescent\0\x04\0\x05ticks\0\0\0\0\0\x022\x80\x08\0\x05value\0\rfirst_quarter\0\x04\0\x05ticks\0\0\0\0\0\x02\x90@\x08\0\x05value\0\x0Ewaxing_gibbous\0\0\0\0" } , StaticRegistryEntry { name : "villager_schedule" , data : b"\n\x04\0\x0Cperiod_ticks\0\0\0\0\0\0]\xC0\0" }] , }] ;
+#[doc = r" The registries synced to clients on `version`."]
+pub fn static_registries(version: MinecraftVersion) -> &'static [StaticRegistry] {
+    match version {
+        MinecraftVersion::V_1_21_7 => REGISTRIES_1_21_9,
+        MinecraftVersion::V_1_21_9 => REGISTRIES_1_21_9,
+        _ => REGISTRIES_1_21_11,
+    }
+}
 impl Registry {
     pub fn get_synced(version: MinecraftVersion) -> Vec<Self> {
-        let static_regs = match version {
-            MinecraftVersion::V_1_21_7 => REGISTRIES_1_21_9,
-            MinecraftVersion::V_1_21_9 => REGISTRIES_1_21_9,
-            _ => REGISTRIES_1_21_11,
-        };
-        static_regs
+        static_registries(version)
             .iter()
             .map(|static_reg| {
                 let registry_id = if static_reg.registry_id.contains(':') {
@@ -0,0 +1,574 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use pumpkin_data::Enchantment;
+use pumpkin_data::data_component::DataComponent;
+use pumpkin_data::data_component_impl::{EnchantmentsImpl, StoredEnchantmentsImpl};
+use pumpkin_data::item::Item;
+use pumpkin_data::screen::WindowType;
+use pumpkin_data::sound::Sound;
+use pumpkin_data::tag::{self, Taggable};
+use pumpkin_world::inventory::Inventory;
+use pumpkin_world::item::ItemStack;
+use tokio::sync::Mutex;
+
+use crate::crafting::crafting_inventory::CraftingInventory;
+use crate::player::player_inventory::PlayerInventory;
+use crate::screen_handler::{
+    InventoryPlayer, ItemStackFuture, ScreenHandler, ScreenHandlerBehaviour, ScreenHandlerFuture,
+    ScreenHandlerListener,
+};
+use crate::slot::{BoxFuture, Slot};
+
+const FIRST_INPUT_SLOT: i32 = 0;
+const SECOND_INPUT_SLOT: i32 = 1;
+const RESULT_SLOT: i32 = 2;
+const INVENTORY_START: i32 = 3;
+const HOTBAR_START: i32 = 30;
+const HOTBAR_END: i32 = 39;
+
+type EnchantmentLevels = Vec<(&'static Enchantment, i32)>;
+
+fn is_curse(enchantment: &Enchantment) -> bool {
+    enchantment.has_tag(&tag::Enchantment::MINECRAFT_CURSE)
+}
+
+fn is_enchanted_book(stack: &ItemStack) -> bool {
+    stack.item == &Item::ENCHANTED_BOOK
+}
+
+/// The enchantments the grindstone works with: the stored ones of enchanted books and the
+/// item's own enchantments for everything else.
+fn enchantments_of(stack: &ItemStack) -> EnchantmentLevels {
+    if is_enchanted_book(stack) {
+        stack
+            .get_data_component::<StoredEnchantmentsImpl>()
+            .map_or_else(Vec::new, |stored| stored.enchantment.to_vec())
+    } else {
+        stack
+            .get_data_component::<EnchantmentsImpl>()
+            .map_or_else(Vec::new, |enchantments| enchantments.enchantment.to_vec())
+    }
+}
+
+fn set_enchantments(stack: &mut ItemStack, enchantments: EnchantmentLevels) {
+    if is_enchanted_book(stack) {
+        stack.set_data_component(StoredEnchantmentsImpl {
+            enchantment: Cow::Owned(enchantments),
+        });
+    } else if enchantments.is_empty() {
+        stack
+            .patch
+            .retain(|(id, _)| *id != DataComponent::Enchantments);
+    } else {
+        stack.set_data_component(EnchantmentsImpl {
+            enchantment: Cow::Owned(enchantments),
+        });
+    }
+}
+
+/// Whether the stack has enchantments or, for enchanted books, stored enchantments.
+fn has_any_enchantments(stack: &ItemStack) -> bool {
+    stack
+        .get_data_component::<EnchantmentsImpl>()
+        .is_some_and(|enchantments| !enchantments.enchantment.is_empty())
+        || stack
+            .get_data_component::<StoredEnchantmentsImpl>()
+            .is_some_and(|stored| !stored.enchantment.is_empty())
+}
+
+/// Whether a stack may be put into an input slot of the grindstone.
+fn accepts(stack: &ItemStack) -> bool {
+    stack.is_damageable() || has_any_enchantments(stack)
+}
+
+/// Removes all enchantments except curses. An enchanted book left without any becomes a plain
+/// book.
+fn remove_non_curses(mut stack: ItemStack) -> ItemStack {
+    let mut enchantments = enchantments_of(&stack);
+    enchantments.retain(|(enchantment, _)| is_curse(enchantment));
+    if is_enchanted_book(&stack) && enchantments.is_empty() {
+        stack
+            .patch
+            .retain(|(id, _)| *id != DataComponent::StoredEnchantments);
+        stack.item = &Item::BOOK;
+        return stack;
+    }
+    // TODO: Reset the repair cost to match the remaining curses once anvils use it
+    set_enchantments(&mut stack, enchantments);
+    stack
+}
+
+/// Combines two items of the same type: durability is summed with a bonus of 5% and
+/// enchantments of `second` are added to `first`. A curse is only taken from `second` if
+/// `first` doesn't already have it.
+fn merge_items(first: &ItemStack, second: &ItemStack) -> ItemStack {
+    if first.item != second.item {
+        return ItemStack::EMPTY.clone();
+    }
+
+    let max_damage = first
+        .get_max_damage()
+        .unwrap_or(0)
+        .max(second.get_max_damage().unwrap_or(0));
+    let durability = |stack: &ItemStack| stack.get_max_damage().unwrap_or(0) - stack.get_damage();
+    let combined_durability = durability(first) + durability(second) + max_damage * 5 / 100;
+
+    let count = if first.is_damageable() {
+        1
+    } else {
+        // Undamageable items, like enchanted books, are only stacked when they are identical
+        if first.get_max_stack_size() < 2 || !first.are_equal(second) {
+            return ItemStack::EMPTY.clone();
+        }
+        2
+    };
+
+    let mut result = first.copy_with_count(count);
+    if result.is_damageable() {
+        result.set_damage(max_damage - combined_durability);
+    }
+
+    let mut enchantments = enchantments_of(&result);
+    for (enchantment, level) in enchantments_of(second) {
+        let existing = enchantments
+            .iter_mut()
+            .find(|(existing, _)| *existing == enchantment);
+        match existing {
+            Some((_, existing_level)) if !is_curse(enchantment) => {
+                *existing_level = (*existing_level).max(level);
+            }
+            Some(_) => {}
+            None => enchantments.push((enchantment, level)),
+        }
+    }
+    set_enchantments(&mut result, enchantments);
+
+    remove_non_curses(result)
+}
+
+/// Computes the output of the grindstone for its two inputs.
+///
+/// A single enchanted item is disenchanted, two items of the same type are repaired into one.
+/// Curses are kept either way.
+#[must_use]
+pub fn compute_result(first: &ItemStack, second: &ItemStack) -> ItemStack {
+    if first.is_empty() && second.is_empty() {
+        return ItemStack::EMPTY.clone();
+    }
+    if first.item_count > 1 || second.item_count > 1 {
+        return ItemStack::EMPTY.clone();
+    }
+
+    if first.is_empty() || second.is_empty() {
+        let stack = if first.is_empty() { second } else { first };
+        if !has_any_enchantments(stack) {
+            return ItemStack::EMPTY.clone();
+        }
+        return remove_non_curses(stack.clone());
+    }
+    merge_items(first, second)
+}
+
+/// The experience the enchantments removed from `stack` are worth, before randomization.
+fn removed_enchantment_experience(stack: &ItemStack) -> i32 {
+    enchantments_of(stack)
+        .into_iter()
+        .filter(|(enchantment, _)| !is_curse(enchantment))
+        .map(|(enchantment, level)| enchantment.get_min_cost(level))
+        .sum()
+}
+
+/// The experience granted for taking the output, half to all of what the removed
+/// enchantments are worth.
+fn experience_amount(first: &ItemStack, second: &ItemStack) -> u32 {
+    let experience = removed_enchantment_experience(first) + removed_enchantment_experience(second);
+    if experience <= 0 {
+        return 0;
+    }
+    let half = experience.unsigned_abs().div_ceil(2);
+    half + rand::random_range(0..half)
+}
+
+/// State shared between the grindstone screen, its result slot and its listener.
+struct GrindstoneState {
+    inputs: Arc<dyn Inventory>,
+    result: Arc<Mutex<ItemStack>>,
+}
+
+impl GrindstoneState {
+    fn new(inputs: Arc<dyn Inventory>) -> Self {
+        Self {
+            inputs,
+            result: Arc::new(Mutex::new(ItemStack::EMPTY.clone())),
+        }
+    }
+
+    async fn input_stacks(&self) -> [ItemStack; 2] {
+        let mut stacks: [ItemStack; 2] = std::array::from_fn(|_| ItemStack::EMPTY.clone());
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            *stack = self.inputs.get_stack(i).await.lock().await.clone();
+        }
+        stacks
+    }
+
+    /// Recomputes the output from the current inputs.
+    async fn update_result(&self) {
+        let [first, second] = self.input_stacks().await;
+        *self.result.lock().await = compute_result(&first, &second);
+    }
+}
+
+impl ScreenHandlerListener for GrindstoneState {
+    fn on_slot_update<'a>(
+        &'a self,
+        _screen_handler: &'a ScreenHandlerBehaviour,
+        slot: u8,
+        _stack: ItemStack,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            if (FIRST_INPUT_SLOT..=SECOND_INPUT_SLOT).contains(&i32::from(slot)) {
+                self.update_result().await;
+            }
+        })
+    }
+}
+
+/// An input slot of the grindstone, only accepting damageable or enchanted items.
+struct GrindstoneInputSlot {
+    inventory: Arc<dyn Inventory>,
+    index: usize,
+    id: AtomicU8,
+}
+
+impl GrindstoneInputSlot {
+    const fn new(inventory: Arc<dyn Inventory>, index: usize) -> Self {
+        Self {
+            inventory,
+            index,
+            id: AtomicU8::new(0),
+        }
+    }
+}
+
+impl Slot for GrindstoneInputSlot {
+    fn get_inventory(&self) -> Arc<dyn Inventory> {
+        self.inventory.clone()
+    }
+
+    fn get_index(&self) -> usize {
+        self.index
+    }
+
+    fn set_id(&self, id: usize) {
+        self.id.store(id as u8, Ordering::Relaxed);
+    }
+
+    fn can_insert<'a>(&'a self, stack: &'a ItemStack) -> BoxFuture<'a, bool> {
+        Box::pin(async move { accepts(stack) })
+    }
+
+    fn mark_dirty(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.inventory.mark_dirty();
+        })
+    }
+}
+
+/// The output slot of the grindstone. Taking from it consumes both inputs and grants the
+/// experience of the removed enchantments.
+struct GrindstoneResultSlot {
+    state: Arc<GrindstoneState>,
+    id: AtomicU8,
+}
+
+impl GrindstoneResultSlot {
+    const fn new(state: Arc<GrindstoneState>) -> Self {
+        Self {
+            state,
+            id: AtomicU8::new(0),
+        }
+    }
+}
+
+impl Slot for GrindstoneResultSlot {
+    fn get_inventory(&self) -> Arc<dyn Inventory> {
+        self.state.inputs.clone()
+    }
+
+    fn get_index(&self) -> usize {
+        999 // this slot does not belong to any inventory
+    }
+
+    fn set_id(&self, id: usize) {
+        self.id.store(id as u8, Ordering::Relaxed);
+    }
+
+    fn on_take_item<'a>(
+        &'a self,
+        player: &'a dyn InventoryPlayer,
+        _stack: &'a ItemStack,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let [first, second] = self.state.input_stacks().await;
+            let experience = experience_amount(&first, &second);
+            if experience > 0 {
+                player.spawn_container_experience(experience).await;
+            }
+
+            for slot in [FIRST_INPUT_SLOT, SECOND_INPUT_SLOT] {
+                self.state.inputs.remove_stack(slot as usize).await;
+            }
+            self.state.update_result().await;
+
+            player.play_container_sound(Sound::BlockGrindstoneUse).await;
+            self.mark_dirty().await;
+        })
+    }
+
+    fn can_insert(&self, _stack: &ItemStack) -> BoxFuture<'_, bool> {
+        Box::pin(async move { false })
+    }
+
+    fn get_stack(&self) -> BoxFuture<'_, Arc<Mutex<ItemStack>>> {
+        Box::pin(async move { self.state.result.clone() })
+    }
+
+    fn get_cloned_stack(&self) -> BoxFuture<'_, ItemStack> {
+        Box::pin(async move { self.state.result.lock().await.clone() })
+    }
+
+    fn has_stack(&self) -> BoxFuture<'_, bool> {
+        Box::pin(async move { !self.state.result.lock().await.is_empty() })
+    }
+
+    fn set_stack_no_callbacks(&self, stack: ItemStack) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            *self.state.result.lock().await = stack;
+            self.mark_dirty().await;
+        })
+    }
+
+    fn mark_dirty(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.state.inputs.mark_dirty();
+        })
+    }
+
+    fn take_stack(&self, _amount: u8) -> BoxFuture<'_, ItemStack> {
+        Box::pin(async move {
+            // Like the crafting result, the output is always taken as a whole
+            let mut result = self.state.result.lock().await;
+            std::mem::replace(&mut *result, ItemStack::EMPTY.clone())
+        })
+    }
+}
+
+// GrindstoneMenu
+pub struct GrindstoneScreenHandler {
+    behaviour: ScreenHandlerBehaviour,
+    state: Arc<GrindstoneState>,
+}
+
+impl GrindstoneScreenHandler {
+    pub async fn new(sync_id: u8, player_inventory: &Arc<PlayerInventory>) -> Self {
+        let inputs: Arc<dyn Inventory> = Arc::new(CraftingInventory::new(2, 1));
+        let state = Arc::new(GrindstoneState::new(inputs.clone()));
+
+        let mut handler = Self {
+            behaviour: ScreenHandlerBehaviour::new(sync_id, Some(WindowType::Grindstone)),
+            state: state.clone(),
+        };
+
+        for index in 0..2 {
+            handler.add_slot(Arc::new(GrindstoneInputSlot::new(inputs.clone(), index)));
+        }
+        handler.add_slot(Arc::new(GrindstoneResultSlot::new(state.clone())));
+
+        let player_inventory: Arc<dyn Inventory> = player_inventory.clone();
+        handler.add_player_slots(&player_inventory);
+
+        handler.add_listener(state).await;
+
+        handler
+    }
+}
+
+impl ScreenHandler for GrindstoneScreenHandler {
+    fn on_closed<'a>(&'a mut self, player: &'a dyn InventoryPlayer) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            self.default_on_closed(player).await;
+            *self.state.result.lock().await = ItemStack::EMPTY.clone();
+            self.drop_inventory(player, self.state.inputs.clone()).await;
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_behaviour(&self) -> &ScreenHandlerBehaviour {
+        &self.behaviour
+    }
+
+    fn get_behaviour_mut(&mut self) -> &mut ScreenHandlerBehaviour {
+        &mut self.behaviour
+    }
+
+    fn quick_move<'a>(
+        &'a mut self,
+        player: &'a dyn InventoryPlayer,
+        slot_index: i32,
+    ) -> ItemStackFuture<'a> {
+        Box::pin(async move {
+            let slot = self.get_behaviour().slots[slot_index as usize].clone();
+
+            if !slot.has_stack().await {
+                return ItemStack::EMPTY.clone();
+            }
+
+            let slot_stack = slot.get_stack().await;
+            let mut slot_stack = slot_stack.lock().await;
+            let stack_prev = slot_stack.clone();
+
+            let has_free_input = !self.get_behaviour().slots[FIRST_INPUT_SLOT as usize]
+                .has_stack()
+                .await
+                || !self.get_behaviour().slots[SECOND_INPUT_SLOT as usize]
+                    .has_stack()
+                    .await;
+
+            let success = if slot_index == RESULT_SLOT {
+                self.insert_item(&mut slot_stack, INVENTORY_START, HOTBAR_END, true)
+                    .await
+            } else if slot_index < RESULT_SLOT {
+                self.insert_item(&mut slot_stack, INVENTORY_START, HOTBAR_END, false)
+                    .await
+            } else if has_free_input && accepts(&slot_stack) {
+                self.insert_item(&mut slot_stack, FIRST_INPUT_SLOT, RESULT_SLOT, false)
+                    .await
+            } else if slot_index < HOTBAR_START {
+                self.insert_item(&mut slot_stack, HOTBAR_START, HOTBAR_END, false)
+                    .await
+            } else {
+                self.insert_item(&mut slot_stack, INVENTORY_START, HOTBAR_START, false)
+                    .await
+            };
+
+            if !success {
+                return ItemStack::EMPTY.clone();
+            }
+
+            let stack = slot_stack.clone();
+            drop(slot_stack); // release the lock before calling other methods
+
+            if stack.is_empty() {
+                slot.set_stack_prev(ItemStack::EMPTY.clone(), stack_prev.clone())
+                    .await;
+            } else {
+                slot.mark_dirty().await;
+            }
+
+            if stack.item_count == stack_prev.item_count {
+                return ItemStack::EMPTY.clone();
+            }
+
+            slot.on_take_item(player, &stack).await;
+
+            stack_prev
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use pumpkin_data::Enchantment;
+    use pumpkin_data::data_component_impl::{EnchantmentsImpl, StoredEnchantmentsImpl};
+    use pumpkin_data::item::Item;
+    use pumpkin_world::item::ItemStack;
+
+    use super::{compute_result, enchantments_of, removed_enchantment_experience};
+
+    fn damaged_sword(damage: i32) -> ItemStack {
+        let mut stack = ItemStack::new(1, &Item::DIAMOND_SWORD);
+        stack.set_damage(damage);
+        stack
+    }
+
+    fn names(stack: &ItemStack) -> Vec<&'static str> {
+        enchantments_of(stack)
+            .into_iter()
+            .map(|(enchantment, _)| enchantment.name)
+            .collect()
+    }
+
+    #[test]
+    fn disenchanting_keeps_curses() {
+        let mut sword = damaged_sword(10);
+        sword.enchant(&Enchantment::SHARPNESS, 3);
+        sword.enchant(&Enchantment::VANISHING_CURSE, 1);
+
+        let result = compute_result(&sword, ItemStack::EMPTY);
+        assert_eq!(result.item.id, Item::DIAMOND_SWORD.id);
+        assert_eq!(result.get_damage(), 10);
+        assert_eq!(names(&result), ["minecraft:vanishing_curse"]);
+        // Sharpness III is worth its minimum enchanting cost, the curse nothing
+        assert_eq!(removed_enchantment_experience(&sword), 23);
+
+        let plain = damaged_sword(10);
+        assert!(compute_result(ItemStack::EMPTY, &plain).is_empty());
+    }
+
+    #[test]
+    fn repairing_sums_durability_with_bonus() {
+        let max_damage = ItemStack::new(1, &Item::DIAMOND_SWORD)
+            .get_max_damage()
+            .unwrap();
+        let mut first = damaged_sword(max_damage - 100);
+        first.enchant(&Enchantment::UNBREAKING, 2);
+        let mut second = damaged_sword(max_damage - 200);
+        second.enchant(&Enchantment::BINDING_CURSE, 1);
+
+        let result = compute_result(&first, &second);
+        assert_eq!(
+            max_damage - result.get_damage(),
+            100 + 200 + max_damage * 5 / 100
+        );
+        assert_eq!(names(&result), ["minecraft:binding_curse"]);
+
+        let other = ItemStack::new(1, &Item::IRON_SWORD);
+        assert!(compute_result(&first, &other).is_empty());
+        assert!(compute_result(&first.copy_with_count(2), ItemStack::EMPTY).is_empty());
+    }
+
+    #[test]
+    fn stripped_book_becomes_plain_book() {
+        let mut book = ItemStack::new(1, &Item::ENCHANTED_BOOK);
+        book.set_data_component(StoredEnchantmentsImpl {
+            enchantment: Cow::Borrowed(&[(&Enchantment::MENDING, 1)]),
+        });
+        let result = compute_result(&book, ItemStack::EMPTY);
+        assert_eq!(result.item.id, Item::BOOK.id);
+        assert!(
+            result
+                .get_data_component::<StoredEnchantmentsImpl>()
+                .is_none()
+        );
+
+        book.set_data_component(StoredEnchantmentsImpl {
+            enchantment: Cow::Borrowed(&[
+                (&Enchantment::MENDING, 1),
+                (&Enchantment::VANISHING_CURSE, 1),
+            ]),
+        });
+        let result = compute_result(&book, ItemStack::EMPTY);
+        assert_eq!(result.item.id, Item::ENCHANTED_BOOK.id);
+        assert_eq!(names(&result), ["minecraft:vanishing_curse"]);
+        assert!(result.get_data_component::<EnchantmentsImpl>().is_none());
+    }
+}
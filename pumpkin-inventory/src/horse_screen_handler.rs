@@ -0,0 +1,231 @@
+use std::{
+    any::Any,
+    sync::{
+        Arc,
+        atomic::{AtomicU8, Ordering},
+    },
+};
+
+use pumpkin_data::{
+    data_component_impl::{EntityTypeOrTag, EquipmentSlot, EquippableImpl},
+    entity::EntityType,
+};
+use pumpkin_world::{inventory::Inventory, item::ItemStack};
+
+use crate::{
+    player::player_inventory::PlayerInventory,
+    screen_handler::{
+        InventoryPlayer, ItemStackFuture, ScreenHandler, ScreenHandlerBehaviour,
+        ScreenHandlerFuture,
+    },
+    slot::{BoxFuture, NormalSlot, Slot},
+};
+
+/// Returns whether `stack` can be equipped in `slot` by an entity of the given type.
+#[must_use]
+pub fn can_equip(stack: &ItemStack, slot: &EquipmentSlot, entity_type: &EntityType) -> bool {
+    stack
+        .get_data_component::<EquippableImpl>()
+        .is_some_and(|equippable| {
+            equippable.slot == slot
+                && equippable.allowed_entities.is_none_or(|allowed| {
+                    allowed.iter().any(|allowed| match allowed {
+                        EntityTypeOrTag::Tag(tag) => tag.1.contains(&entity_type.id),
+                        EntityTypeOrTag::Single(allowed) => allowed.id == entity_type.id,
+                    })
+                })
+        })
+}
+
+// HorseScreenHandler.java
+pub struct HorseScreenHandler {
+    pub inventory: Arc<dyn Inventory>,
+    pub chest_columns: u8,
+    behaviour: ScreenHandlerBehaviour,
+}
+
+impl HorseScreenHandler {
+    pub const SADDLE_SLOT: usize = 0;
+    pub const ARMOR_SLOT: usize = 1;
+    pub const CHEST_ROWS: u8 = 3;
+
+    /// Creates the screen of a mount. The inventory holds the saddle, the body armor and the
+    /// chest contents, in that order.
+    pub async fn new(
+        sync_id: u8,
+        player_inventory: &Arc<PlayerInventory>,
+        inventory: Arc<dyn Inventory>,
+        entity_type: &'static EntityType,
+        chest_columns: u8,
+    ) -> Self {
+        let mut handler = Self {
+            inventory: inventory.clone(),
+            chest_columns,
+            behaviour: ScreenHandlerBehaviour::new(sync_id, None),
+        };
+
+        inventory.on_open().await;
+
+        handler.add_slot(Arc::new(MountEquipmentSlot::new(
+            inventory.clone(),
+            Self::SADDLE_SLOT,
+            EquipmentSlot::SADDLE,
+            entity_type,
+        )));
+        handler.add_slot(Arc::new(MountEquipmentSlot::new(
+            inventory.clone(),
+            Self::ARMOR_SLOT,
+            EquipmentSlot::BODY,
+            entity_type,
+        )));
+        for i in 0..Self::CHEST_ROWS {
+            for j in 0..chest_columns {
+                handler.add_slot(Arc::new(NormalSlot::new(
+                    inventory.clone(),
+                    2 + (j + i * chest_columns) as usize,
+                )));
+            }
+        }
+
+        let player_inventory: Arc<dyn Inventory> = player_inventory.clone();
+        handler.add_player_slots(&player_inventory);
+
+        handler
+    }
+
+    /// The amount of slots belonging to the mount, including the saddle and armor slot.
+    #[must_use]
+    pub fn mount_slot_count(&self) -> i32 {
+        2 + i32::from(self.chest_columns * Self::CHEST_ROWS)
+    }
+}
+
+impl ScreenHandler for HorseScreenHandler {
+    fn on_closed<'a>(&'a mut self, player: &'a dyn InventoryPlayer) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            self.default_on_closed(player).await;
+            self.inventory.on_close().await;
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_behaviour(&self) -> &ScreenHandlerBehaviour {
+        &self.behaviour
+    }
+
+    fn get_behaviour_mut(&mut self) -> &mut ScreenHandlerBehaviour {
+        &mut self.behaviour
+    }
+
+    fn quick_move<'a>(
+        &'a mut self,
+        _player: &'a dyn InventoryPlayer,
+        slot_index: i32,
+    ) -> ItemStackFuture<'a> {
+        Box::pin(async move {
+            let mut stack_left = ItemStack::EMPTY.clone();
+            let slot = self.get_behaviour().slots[slot_index as usize].clone();
+
+            if slot.has_stack().await {
+                let slot_stack_lock = slot.get_stack().await;
+                let mut slot_stack = slot_stack_lock.lock().await;
+                stack_left = slot_stack.clone();
+
+                let mount_slots = self.mount_slot_count();
+                let slot_count = self.get_behaviour().slots.len() as i32;
+                let saddle_slot = self.get_behaviour().slots[Self::SADDLE_SLOT].clone();
+                let armor_slot = self.get_behaviour().slots[Self::ARMOR_SLOT].clone();
+
+                let success = if slot_index < mount_slots {
+                    // From the mount to the player inventory (end)
+                    self.insert_item(&mut slot_stack, mount_slots, slot_count, true)
+                        .await
+                } else if !saddle_slot.has_stack().await
+                    && saddle_slot.can_insert(&slot_stack).await
+                {
+                    self.insert_item(&mut slot_stack, 0, 1, false).await
+                } else if !armor_slot.has_stack().await && armor_slot.can_insert(&slot_stack).await
+                {
+                    self.insert_item(&mut slot_stack, 1, 2, false).await
+                } else if self.chest_columns > 0 {
+                    // From the player inventory to the chest (start)
+                    self.insert_item(&mut slot_stack, 2, mount_slots, false)
+                        .await
+                } else {
+                    false
+                };
+
+                if !success {
+                    return ItemStack::EMPTY.clone();
+                }
+
+                if slot_stack.is_empty() {
+                    drop(slot_stack);
+                    slot.set_stack(ItemStack::EMPTY.clone()).await;
+                } else {
+                    drop(slot_stack);
+                    slot.mark_dirty().await;
+                }
+            }
+
+            stack_left
+        })
+    }
+}
+
+/// The saddle or body armor slot of a mount.
+pub struct MountEquipmentSlot {
+    pub inventory: Arc<dyn Inventory>,
+    pub index: usize,
+    pub id: AtomicU8,
+    pub equipment_slot: EquipmentSlot,
+    pub entity_type: &'static EntityType,
+}
+
+impl MountEquipmentSlot {
+    pub fn new(
+        inventory: Arc<dyn Inventory>,
+        index: usize,
+        equipment_slot: EquipmentSlot,
+        entity_type: &'static EntityType,
+    ) -> Self {
+        Self {
+            inventory,
+            index,
+            id: AtomicU8::new(0),
+            equipment_slot,
+            entity_type,
+        }
+    }
+}
+
+impl Slot for MountEquipmentSlot {
+    fn get_inventory(&self) -> Arc<dyn Inventory> {
+        self.inventory.clone()
+    }
+
+    fn get_index(&self) -> usize {
+        self.index
+    }
+
+    fn set_id(&self, id: usize) {
+        self.id.store(id as u8, Ordering::Relaxed);
+    }
+
+    fn get_max_item_count(&self) -> BoxFuture<'_, u8> {
+        Box::pin(async move { 1 })
+    }
+
+    fn can_insert<'a>(&'a self, stack: &'a ItemStack) -> BoxFuture<'a, bool> {
+        Box::pin(async move { can_equip(stack, &self.equipment_slot, self.entity_type) })
+    }
+
+    fn mark_dirty(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.inventory.mark_dirty();
+        })
+    }
+}
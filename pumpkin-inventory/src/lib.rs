@@ -6,9 +6,13 @@ pub mod entity_equipment;
 mod error;
 pub mod furnace_like;
 pub mod generic_container_screen_handler;
+pub mod grindstone_screen_handler;
+pub mod horse_screen_handler;
 pub mod player;
 pub mod screen_handler;
 pub mod slot;
+pub mod smithing_screen_handler;
+pub mod stonecutter_screen_handler;
 pub mod sync_handler;
 pub mod window_property;
 
@@ -50,10 +50,20 @@ impl PlayerInventory {
             .clone()
     }
 
+    /// The stack held in `hand`.
     pub async fn get_stack_in_hand(&self, hand: Hand) -> Arc<Mutex<ItemStack>> {
         match hand {
-            Hand::Left => self.off_hand_item().await,
-            Hand::Right => self.held_item(),
+            Hand::Main => self.held_item(),
+            Hand::Off => self.off_hand_item().await,
+        }
+    }
+
+    /// The inventory slot holding the stack in `hand`.
+    #[must_use]
+    pub fn hand_slot(&self, hand: Hand) -> usize {
+        match hand {
+            Hand::Main => self.get_selected_slot() as usize,
+            Hand::Off => Self::OFF_HAND_SLOT,
         }
     }
 
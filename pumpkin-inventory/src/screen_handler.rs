@@ -7,6 +7,7 @@ use crate::{
 use pumpkin_data::{
     data_component_impl::{EquipmentSlot, EquipmentType, EquippableImpl},
     screen::WindowType,
+    sound::Sound,
 };
 use pumpkin_protocol::{
     codec::item_stack_seralizer::OptionalItemStackHash,
@@ -97,6 +98,12 @@ pub trait InventoryPlayer: Send + Sync {
 
     /// Awards experience points to the player (used for furnace smelting, etc.)
     fn award_experience(&self, amount: i32) -> PlayerFuture<'_, ()>;
+
+    /// Plays a sound at the block of the open container (used for stonecutters, smithing tables, etc.)
+    fn play_container_sound(&self, sound: Sound) -> PlayerFuture<'_, ()>;
+
+    /// Spawns experience orbs at the block of the open container (used for grindstones)
+    fn spawn_container_experience(&self, amount: u32) -> PlayerFuture<'_, ()>;
 }
 
 pub async fn offer_or_drop_stack(player: &dyn InventoryPlayer, stack: ItemStack) {
@@ -610,6 +617,17 @@ pub trait ScreenHandler: Send + Sync {
         })
     }
 
+    /// Called when the player clicks a button of the screen, e.g. a stonecutter recipe.
+    ///
+    /// Returns whether the click was handled, in which case the changes are sent to the client.
+    fn on_button_click<'a>(
+        &'a mut self,
+        _player: &'a dyn InventoryPlayer,
+        _id: i32,
+    ) -> ScreenHandlerFuture<'a, bool> {
+        Box::pin(async { false })
+    }
+
     fn handle_slot_click<'a>(
         &'a self,
         _player: &'a dyn InventoryPlayer,
@@ -0,0 +1,436 @@
+use std::any::Any;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI32, AtomicU8, Ordering};
+
+use pumpkin_data::data_component_impl::{ProvidesTrimMaterialImpl, TrimImpl};
+use pumpkin_data::item::Item;
+use pumpkin_data::recipes::{
+    RECIPES_SMITHING, RecipeIngredientTypes, SmithingRecipeTypes, get_smithing_recipe,
+};
+use pumpkin_data::screen::WindowType;
+use pumpkin_data::sound::Sound;
+use pumpkin_world::block::entities::PropertyDelegate;
+use pumpkin_world::inventory::Inventory;
+use pumpkin_world::item::ItemStack;
+use tokio::sync::Mutex;
+
+use crate::crafting::crafting_inventory::CraftingInventory;
+use crate::player::player_inventory::PlayerInventory;
+use crate::screen_handler::{
+    InventoryPlayer, ItemStackFuture, ScreenHandler, ScreenHandlerBehaviour, ScreenHandlerFuture,
+    ScreenHandlerListener, ScreenProperty,
+};
+use crate::slot::{BoxFuture, Slot};
+
+const TEMPLATE_SLOT: i32 = 0;
+const ADDITION_SLOT: i32 = 2;
+const RESULT_SLOT: i32 = 3;
+const INVENTORY_START: i32 = 4;
+const HOTBAR_START: i32 = 31;
+const HOTBAR_END: i32 = 40;
+
+#[derive(Clone, Copy)]
+enum SmithingInput {
+    Template = 0,
+    Base = 1,
+    Addition = 2,
+}
+
+impl SmithingInput {
+    const ALL: [Self; 3] = [Self::Template, Self::Base, Self::Addition];
+
+    fn ingredient(self, recipe: &SmithingRecipeTypes) -> &RecipeIngredientTypes {
+        match self {
+            Self::Template => recipe.template(),
+            Self::Base => recipe.base(),
+            Self::Addition => recipe.addition(),
+        }
+    }
+
+    /// Whether any smithing recipe accepts the item in this slot.
+    fn accepts(self, item: &Item) -> bool {
+        RECIPES_SMITHING
+            .iter()
+            .any(|recipe| self.ingredient(recipe).match_item(item))
+    }
+}
+
+/// Creates the output of a smithing recipe. The output is always a new stack, so changing it
+/// never affects the inputs.
+///
+/// Returns an empty stack if the recipe can't be applied, e.g. the armor already has that trim.
+fn assemble(recipe: &SmithingRecipeTypes, base: &ItemStack, addition: &ItemStack) -> ItemStack {
+    match recipe {
+        SmithingRecipeTypes::SmithingTransform { result, .. } => {
+            // Keeps enchantments, damage, trims and other components of the base item
+            let mut stack = ItemStack::from(result);
+            stack.patch.clone_from(&base.patch);
+            stack
+        }
+        SmithingRecipeTypes::SmithingTrim { pattern, .. } => {
+            let Some(material) = addition.get_data_component::<ProvidesTrimMaterialImpl>() else {
+                return ItemStack::EMPTY.clone();
+            };
+            let Some(trim) = TrimImpl::from_names(material.material, pattern) else {
+                return ItemStack::EMPTY.clone();
+            };
+            if base.get_data_component::<TrimImpl>() == Some(&trim) {
+                return ItemStack::EMPTY.clone();
+            }
+
+            let mut stack = base.copy_with_count(1);
+            stack.set_data_component(trim);
+            stack
+        }
+    }
+}
+
+/// State shared between the smithing screen, its result slot and its listener.
+struct SmithingState {
+    inputs: Arc<dyn Inventory>,
+    result: Arc<Mutex<ItemStack>>,
+    /// 1 if all inputs are filled but no recipe matches. Synced to the client as property 0.
+    has_recipe_error: AtomicI32,
+}
+
+impl SmithingState {
+    fn new(inputs: Arc<dyn Inventory>) -> Self {
+        Self {
+            inputs,
+            result: Arc::new(Mutex::new(ItemStack::EMPTY.clone())),
+            has_recipe_error: AtomicI32::new(0),
+        }
+    }
+
+    async fn input_stacks(&self) -> [ItemStack; 3] {
+        let mut stacks: [ItemStack; 3] = std::array::from_fn(|_| ItemStack::EMPTY.clone());
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            *stack = self.inputs.get_stack(i).await.lock().await.clone();
+        }
+        stacks
+    }
+
+    /// Recomputes the output from the current inputs.
+    async fn update_result(&self) {
+        let [template, base, addition] = self.input_stacks().await;
+        let all_filled = !template.is_empty() && !base.is_empty() && !addition.is_empty();
+
+        let result = if all_filled {
+            get_smithing_recipe(template.item, base.item, addition.item).map_or_else(
+                || ItemStack::EMPTY.clone(),
+                |recipe| assemble(recipe, &base, &addition),
+            )
+        } else {
+            ItemStack::EMPTY.clone()
+        };
+
+        self.has_recipe_error.store(
+            i32::from(all_filled && result.is_empty()),
+            Ordering::Relaxed,
+        );
+        *self.result.lock().await = result;
+    }
+}
+
+impl PropertyDelegate for SmithingState {
+    fn get_property(&self, index: i32) -> i32 {
+        match index {
+            0 => self.has_recipe_error.load(Ordering::Relaxed),
+            _ => 0,
+        }
+    }
+
+    fn set_property(&self, index: i32, value: i32) {
+        if index == 0 {
+            self.has_recipe_error.store(value, Ordering::Relaxed);
+        }
+    }
+
+    fn get_properties_size(&self) -> i32 {
+        1
+    }
+}
+
+impl ScreenHandlerListener for SmithingState {
+    fn on_slot_update<'a>(
+        &'a self,
+        _screen_handler: &'a ScreenHandlerBehaviour,
+        slot: u8,
+        _stack: ItemStack,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            if (TEMPLATE_SLOT..=ADDITION_SLOT).contains(&i32::from(slot)) {
+                self.update_result().await;
+            }
+        })
+    }
+
+    fn on_property_update<'a>(
+        &'a self,
+        screen_handler: &'a ScreenHandlerBehaviour,
+        property: u8,
+        value: i32,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            if let Some(sync_handler) = screen_handler.sync_handler.as_ref() {
+                sync_handler
+                    .update_property(screen_handler, i32::from(property), value)
+                    .await;
+            }
+        })
+    }
+}
+
+/// An input slot of the smithing table, only accepting items used by a smithing recipe.
+struct SmithingInputSlot {
+    inventory: Arc<dyn Inventory>,
+    input: SmithingInput,
+    id: AtomicU8,
+}
+
+impl SmithingInputSlot {
+    const fn new(inventory: Arc<dyn Inventory>, input: SmithingInput) -> Self {
+        Self {
+            inventory,
+            input,
+            id: AtomicU8::new(0),
+        }
+    }
+}
+
+impl Slot for SmithingInputSlot {
+    fn get_inventory(&self) -> Arc<dyn Inventory> {
+        self.inventory.clone()
+    }
+
+    fn get_index(&self) -> usize {
+        self.input as usize
+    }
+
+    fn set_id(&self, id: usize) {
+        self.id.store(id as u8, Ordering::Relaxed);
+    }
+
+    fn can_insert<'a>(&'a self, stack: &'a ItemStack) -> BoxFuture<'a, bool> {
+        Box::pin(async move { self.input.accepts(stack.item) })
+    }
+
+    fn mark_dirty(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.inventory.mark_dirty();
+        })
+    }
+}
+
+/// The output slot of the smithing table. Taking from it consumes one of each input.
+struct SmithingResultSlot {
+    state: Arc<SmithingState>,
+    id: AtomicU8,
+}
+
+impl SmithingResultSlot {
+    const fn new(state: Arc<SmithingState>) -> Self {
+        Self {
+            state,
+            id: AtomicU8::new(0),
+        }
+    }
+}
+
+impl Slot for SmithingResultSlot {
+    fn get_inventory(&self) -> Arc<dyn Inventory> {
+        self.state.inputs.clone()
+    }
+
+    fn get_index(&self) -> usize {
+        999 // this slot does not belong to any inventory
+    }
+
+    fn set_id(&self, id: usize) {
+        self.id.store(id as u8, Ordering::Relaxed);
+    }
+
+    fn on_take_item<'a>(
+        &'a self,
+        player: &'a dyn InventoryPlayer,
+        _stack: &'a ItemStack,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            for input in SmithingInput::ALL {
+                self.state
+                    .inputs
+                    .remove_stack_specific(input as usize, 1)
+                    .await;
+            }
+            self.state.update_result().await;
+
+            player
+                .play_container_sound(Sound::BlockSmithingTableUse)
+                .await;
+            self.mark_dirty().await;
+        })
+    }
+
+    fn can_insert(&self, _stack: &ItemStack) -> BoxFuture<'_, bool> {
+        Box::pin(async move { false })
+    }
+
+    fn get_stack(&self) -> BoxFuture<'_, Arc<Mutex<ItemStack>>> {
+        Box::pin(async move { self.state.result.clone() })
+    }
+
+    fn get_cloned_stack(&self) -> BoxFuture<'_, ItemStack> {
+        Box::pin(async move { self.state.result.lock().await.clone() })
+    }
+
+    fn has_stack(&self) -> BoxFuture<'_, bool> {
+        Box::pin(async move { !self.state.result.lock().await.is_empty() })
+    }
+
+    fn set_stack_no_callbacks(&self, stack: ItemStack) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            *self.state.result.lock().await = stack;
+            self.mark_dirty().await;
+        })
+    }
+
+    fn mark_dirty(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.state.inputs.mark_dirty();
+        })
+    }
+
+    fn take_stack(&self, _amount: u8) -> BoxFuture<'_, ItemStack> {
+        Box::pin(async move {
+            // Like the crafting result, the output is always taken as a whole
+            let mut result = self.state.result.lock().await;
+            std::mem::replace(&mut *result, ItemStack::EMPTY.clone())
+        })
+    }
+}
+
+// SmithingMenu
+pub struct SmithingScreenHandler {
+    behaviour: ScreenHandlerBehaviour,
+    state: Arc<SmithingState>,
+}
+
+impl SmithingScreenHandler {
+    pub async fn new(sync_id: u8, player_inventory: &Arc<PlayerInventory>) -> Self {
+        let inputs: Arc<dyn Inventory> = Arc::new(CraftingInventory::new(3, 1));
+        let state = Arc::new(SmithingState::new(inputs.clone()));
+
+        let mut handler = Self {
+            behaviour: ScreenHandlerBehaviour::new(sync_id, Some(WindowType::Smithing)),
+            state: state.clone(),
+        };
+
+        for input in SmithingInput::ALL {
+            handler.add_slot(Arc::new(SmithingInputSlot::new(inputs.clone(), input)));
+        }
+        handler.add_slot(Arc::new(SmithingResultSlot::new(state.clone())));
+
+        let player_inventory: Arc<dyn Inventory> = player_inventory.clone();
+        handler.add_player_slots(&player_inventory);
+
+        // 0: Whether the inputs don't form a recipe, shows the error icon over the arrow
+        handler.add_property(ScreenProperty::new(state.clone(), 0));
+        handler.add_listener(state).await;
+
+        handler
+    }
+}
+
+impl ScreenHandler for SmithingScreenHandler {
+    fn on_closed<'a>(&'a mut self, player: &'a dyn InventoryPlayer) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            self.default_on_closed(player).await;
+            *self.state.result.lock().await = ItemStack::EMPTY.clone();
+            self.drop_inventory(player, self.state.inputs.clone()).await;
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_behaviour(&self) -> &ScreenHandlerBehaviour {
+        &self.behaviour
+    }
+
+    fn get_behaviour_mut(&mut self) -> &mut ScreenHandlerBehaviour {
+        &mut self.behaviour
+    }
+
+    fn quick_move<'a>(
+        &'a mut self,
+        player: &'a dyn InventoryPlayer,
+        slot_index: i32,
+    ) -> ItemStackFuture<'a> {
+        Box::pin(async move {
+            let slot = self.get_behaviour().slots[slot_index as usize].clone();
+
+            if !slot.has_stack().await {
+                return ItemStack::EMPTY.clone();
+            }
+
+            let slot_stack = slot.get_stack().await;
+            let mut slot_stack = slot_stack.lock().await;
+            let stack_prev = slot_stack.clone();
+
+            // The first input slot that is empty and accepts the stack
+            let mut input_slot = None;
+            if slot_index >= INVENTORY_START {
+                for input in SmithingInput::ALL {
+                    let index = input as i32;
+                    if input.accepts(slot_stack.item)
+                        && !self.get_behaviour().slots[index as usize].has_stack().await
+                    {
+                        input_slot = Some(index);
+                        break;
+                    }
+                }
+            }
+
+            let success = if slot_index == RESULT_SLOT {
+                self.insert_item(&mut slot_stack, INVENTORY_START, HOTBAR_END, true)
+                    .await
+            } else if slot_index < RESULT_SLOT {
+                self.insert_item(&mut slot_stack, INVENTORY_START, HOTBAR_END, false)
+                    .await
+            } else if let Some(input_slot) = input_slot {
+                self.insert_item(&mut slot_stack, input_slot, RESULT_SLOT, false)
+                    .await
+            } else if slot_index < HOTBAR_START {
+                self.insert_item(&mut slot_stack, HOTBAR_START, HOTBAR_END, false)
+                    .await
+            } else {
+                self.insert_item(&mut slot_stack, INVENTORY_START, HOTBAR_START, false)
+                    .await
+            };
+
+            if !success {
+                return ItemStack::EMPTY.clone();
+            }
+
+            let stack = slot_stack.clone();
+            drop(slot_stack); // release the lock before calling other methods
+
+            if stack.is_empty() {
+                slot.set_stack_prev(ItemStack::EMPTY.clone(), stack_prev.clone())
+                    .await;
+            } else {
+                slot.mark_dirty().await;
+            }
+
+            if stack.item_count == stack_prev.item_count {
+                return ItemStack::EMPTY.clone();
+            }
+
+            slot.on_take_item(player, &stack).await;
+
+            stack_prev
+        })
+    }
+}
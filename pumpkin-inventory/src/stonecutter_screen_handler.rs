@@ -0,0 +1,375 @@
+use std::any::Any;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU8, AtomicU16, Ordering};
+
+use pumpkin_data::item::Item;
+use pumpkin_data::recipes::{StonecuttingRecipe, get_stonecutting_recipes};
+use pumpkin_data::screen::WindowType;
+use pumpkin_data::sound::Sound;
+use pumpkin_protocol::java::server::play::SlotActionType;
+use pumpkin_world::block::entities::PropertyDelegate;
+use pumpkin_world::inventory::Inventory;
+use pumpkin_world::item::ItemStack;
+use tokio::sync::Mutex;
+
+use crate::crafting::crafting_inventory::CraftingInventory;
+use crate::player::player_inventory::PlayerInventory;
+use crate::screen_handler::{
+    InventoryPlayer, ItemStackFuture, ScreenHandler, ScreenHandlerBehaviour, ScreenHandlerFuture,
+    ScreenHandlerListener, ScreenProperty,
+};
+use crate::slot::{BoxFuture, NormalSlot, Slot};
+
+const INPUT_SLOT: i32 = 0;
+const RESULT_SLOT: i32 = 1;
+const INVENTORY_START: i32 = 2;
+const HOTBAR_START: i32 = 29;
+const HOTBAR_END: i32 = 38;
+
+/// State shared between the stonecutter screen, its result slot and its listener.
+struct StonecutterState {
+    input: Arc<dyn Inventory>,
+    result: Arc<Mutex<ItemStack>>,
+    /// Index into `recipes`, or -1 if no recipe is selected. Synced to the client as property 0.
+    selected_recipe: AtomicI32,
+    /// The stonecutting recipes accepting the current input, in the order the client lists them.
+    recipes: Mutex<Vec<&'static StonecuttingRecipe>>,
+    /// The item id the recipe list was built for.
+    last_input: AtomicU16,
+    /// Vanilla plays the take sound at most once per tick, and a shift-click crafts everything at once.
+    sound_played: AtomicBool,
+}
+
+impl StonecutterState {
+    fn new(input: Arc<dyn Inventory>) -> Self {
+        Self {
+            input,
+            result: Arc::new(Mutex::new(ItemStack::EMPTY.clone())),
+            selected_recipe: AtomicI32::new(-1),
+            recipes: Mutex::new(Vec::new()),
+            last_input: AtomicU16::new(Item::AIR.id),
+            sound_played: AtomicBool::new(false),
+        }
+    }
+
+    async fn input_stack(&self) -> ItemStack {
+        self.input.get_stack(0).await.lock().await.clone()
+    }
+
+    /// Rebuilds the recipe list when the input changed to a different item, deselecting the recipe.
+    async fn on_input_changed(&self) {
+        let input = self.input_stack().await;
+        let input_id = if input.is_empty() {
+            Item::AIR.id
+        } else {
+            input.item.id
+        };
+        if self.last_input.swap(input_id, Ordering::Relaxed) == input_id {
+            return;
+        }
+
+        self.selected_recipe.store(-1, Ordering::Relaxed);
+        let mut recipes = self.recipes.lock().await;
+        recipes.clear();
+        if !input.is_empty() {
+            recipes.extend(get_stonecutting_recipes(input.item));
+        }
+        drop(recipes);
+        *self.result.lock().await = ItemStack::EMPTY.clone();
+    }
+
+    /// Fills the result slot with a fresh output of the selected recipe.
+    async fn setup_result(&self) {
+        let selected = self.selected_recipe.load(Ordering::Relaxed);
+        let input_empty = self.input_stack().await.is_empty();
+        let recipes = self.recipes.lock().await;
+        let result = match usize::try_from(selected).ok().and_then(|i| recipes.get(i)) {
+            Some(recipe) if !input_empty => ItemStack::from(&recipe.result),
+            _ => ItemStack::EMPTY.clone(),
+        };
+        drop(recipes);
+        *self.result.lock().await = result;
+    }
+
+    async fn select_recipe(&self, id: i32) -> bool {
+        let recipe_count = self.recipes.lock().await.len();
+        let valid = usize::try_from(id).is_ok_and(|id| id < recipe_count);
+        if valid {
+            self.selected_recipe.store(id, Ordering::Relaxed);
+            self.setup_result().await;
+        }
+        valid
+    }
+}
+
+impl PropertyDelegate for StonecutterState {
+    fn get_property(&self, index: i32) -> i32 {
+        match index {
+            0 => self.selected_recipe.load(Ordering::Relaxed),
+            _ => 0,
+        }
+    }
+
+    fn set_property(&self, index: i32, value: i32) {
+        if index == 0 {
+            self.selected_recipe.store(value, Ordering::Relaxed);
+        }
+    }
+
+    fn get_properties_size(&self) -> i32 {
+        1
+    }
+}
+
+impl ScreenHandlerListener for StonecutterState {
+    fn on_slot_update<'a>(
+        &'a self,
+        _screen_handler: &'a ScreenHandlerBehaviour,
+        slot: u8,
+        _stack: ItemStack,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            if i32::from(slot) == INPUT_SLOT {
+                self.on_input_changed().await;
+            }
+        })
+    }
+
+    fn on_property_update<'a>(
+        &'a self,
+        screen_handler: &'a ScreenHandlerBehaviour,
+        property: u8,
+        value: i32,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            if let Some(sync_handler) = screen_handler.sync_handler.as_ref() {
+                sync_handler
+                    .update_property(screen_handler, i32::from(property), value)
+                    .await;
+            }
+        })
+    }
+}
+
+/// The output slot of the stonecutter. Taking from it consumes exactly one input item.
+struct StonecutterResultSlot {
+    state: Arc<StonecutterState>,
+    id: AtomicU8,
+}
+
+impl StonecutterResultSlot {
+    const fn new(state: Arc<StonecutterState>) -> Self {
+        Self {
+            state,
+            id: AtomicU8::new(0),
+        }
+    }
+}
+
+impl Slot for StonecutterResultSlot {
+    fn get_inventory(&self) -> Arc<dyn Inventory> {
+        self.state.input.clone()
+    }
+
+    fn get_index(&self) -> usize {
+        999 // this slot does not belong to any inventory
+    }
+
+    fn set_id(&self, id: usize) {
+        self.id.store(id as u8, Ordering::Relaxed);
+    }
+
+    fn on_take_item<'a>(
+        &'a self,
+        player: &'a dyn InventoryPlayer,
+        _stack: &'a ItemStack,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let consumed = self.state.input.remove_stack_specific(0, 1).await;
+            if !consumed.is_empty() {
+                self.state.setup_result().await;
+            }
+
+            if !self.state.sound_played.swap(true, Ordering::Relaxed) {
+                player
+                    .play_container_sound(Sound::UiStonecutterTakeResult)
+                    .await;
+            }
+            self.mark_dirty().await;
+        })
+    }
+
+    fn can_insert(&self, _stack: &ItemStack) -> BoxFuture<'_, bool> {
+        Box::pin(async move { false })
+    }
+
+    fn get_stack(&self) -> BoxFuture<'_, Arc<Mutex<ItemStack>>> {
+        Box::pin(async move { self.state.result.clone() })
+    }
+
+    fn get_cloned_stack(&self) -> BoxFuture<'_, ItemStack> {
+        Box::pin(async move { self.state.result.lock().await.clone() })
+    }
+
+    fn has_stack(&self) -> BoxFuture<'_, bool> {
+        Box::pin(async move { !self.state.result.lock().await.is_empty() })
+    }
+
+    fn set_stack_no_callbacks(&self, stack: ItemStack) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            *self.state.result.lock().await = stack;
+            self.mark_dirty().await;
+        })
+    }
+
+    fn mark_dirty(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            self.state.input.mark_dirty();
+        })
+    }
+
+    fn take_stack(&self, _amount: u8) -> BoxFuture<'_, ItemStack> {
+        Box::pin(async move {
+            // Like the crafting result, the output is always taken as a whole
+            let mut result = self.state.result.lock().await;
+            std::mem::replace(&mut *result, ItemStack::EMPTY.clone())
+        })
+    }
+}
+
+// StonecutterMenu
+pub struct StonecutterScreenHandler {
+    behaviour: ScreenHandlerBehaviour,
+    state: Arc<StonecutterState>,
+}
+
+impl StonecutterScreenHandler {
+    pub async fn new(sync_id: u8, player_inventory: &Arc<PlayerInventory>) -> Self {
+        let input: Arc<dyn Inventory> = Arc::new(CraftingInventory::new(1, 1));
+        let state = Arc::new(StonecutterState::new(input.clone()));
+
+        let mut handler = Self {
+            behaviour: ScreenHandlerBehaviour::new(sync_id, Some(WindowType::Stonecutter)),
+            state: state.clone(),
+        };
+
+        handler.add_slot(Arc::new(NormalSlot::new(input, 0)));
+        handler.add_slot(Arc::new(StonecutterResultSlot::new(state.clone())));
+
+        let player_inventory: Arc<dyn Inventory> = player_inventory.clone();
+        handler.add_player_slots(&player_inventory);
+
+        // 0: Selected recipe index, -1 if no recipe is selected
+        handler.add_property(ScreenProperty::new(state.clone(), 0));
+        handler.add_listener(state).await;
+
+        handler
+    }
+}
+
+impl ScreenHandler for StonecutterScreenHandler {
+    fn on_closed<'a>(&'a mut self, player: &'a dyn InventoryPlayer) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            self.default_on_closed(player).await;
+            *self.state.result.lock().await = ItemStack::EMPTY.clone();
+            self.drop_inventory(player, self.state.input.clone()).await;
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_behaviour(&self) -> &ScreenHandlerBehaviour {
+        &self.behaviour
+    }
+
+    fn get_behaviour_mut(&mut self) -> &mut ScreenHandlerBehaviour {
+        &mut self.behaviour
+    }
+
+    fn on_button_click<'a>(
+        &'a mut self,
+        _player: &'a dyn InventoryPlayer,
+        id: i32,
+    ) -> ScreenHandlerFuture<'a, bool> {
+        Box::pin(async move { self.state.select_recipe(id).await })
+    }
+
+    fn on_slot_click<'a>(
+        &'a mut self,
+        slot_index: i32,
+        button: i32,
+        action_type: SlotActionType,
+        player: &'a dyn InventoryPlayer,
+    ) -> ScreenHandlerFuture<'a, ()> {
+        Box::pin(async move {
+            self.state.sound_played.store(false, Ordering::Relaxed);
+            self.internal_on_slot_click(slot_index, button, action_type, player)
+                .await;
+        })
+    }
+
+    fn quick_move<'a>(
+        &'a mut self,
+        player: &'a dyn InventoryPlayer,
+        slot_index: i32,
+    ) -> ItemStackFuture<'a> {
+        Box::pin(async move {
+            let slot = self.get_behaviour().slots[slot_index as usize].clone();
+
+            if !slot.has_stack().await {
+                return ItemStack::EMPTY.clone();
+            }
+
+            let slot_stack = slot.get_stack().await;
+            let mut slot_stack = slot_stack.lock().await;
+            let stack_prev = slot_stack.clone();
+
+            let success = if slot_index == RESULT_SLOT {
+                self.insert_item(&mut slot_stack, INVENTORY_START, HOTBAR_END, true)
+                    .await
+            } else if slot_index == INPUT_SLOT {
+                self.insert_item(&mut slot_stack, INVENTORY_START, HOTBAR_END, false)
+                    .await
+            } else if get_stonecutting_recipes(slot_stack.item).next().is_some() {
+                self.insert_item(&mut slot_stack, INPUT_SLOT, RESULT_SLOT, false)
+                    .await
+            } else if slot_index < HOTBAR_START {
+                self.insert_item(&mut slot_stack, HOTBAR_START, HOTBAR_END, false)
+                    .await
+            } else {
+                self.insert_item(&mut slot_stack, INVENTORY_START, HOTBAR_START, false)
+                    .await
+            };
+
+            if !success {
+                return ItemStack::EMPTY.clone();
+            }
+
+            let stack = slot_stack.clone();
+            drop(slot_stack); // release the lock before calling other methods
+
+            if stack.is_empty() {
+                slot.set_stack_prev(ItemStack::EMPTY.clone(), stack_prev.clone())
+                    .await;
+            } else {
+                slot.mark_dirty().await;
+            }
+
+            if stack.item_count == stack_prev.item_count {
+                return ItemStack::EMPTY.clone();
+            }
+
+            slot.on_take_item(player, &stack).await;
+
+            if slot_index == RESULT_SLOT && !stack.is_empty() {
+                // The result slot was refilled, drop what did not fit into the inventory
+                player.drop_item(stack, false).await;
+            }
+
+            stack_prev
+        })
+    }
+}
@@ -509,6 +509,57 @@ pub fn derive_deserialize(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
+/// Implements `MinecraftComponent` and `Serialize` for a struct of data components, sending it as
+/// an NBT compound with each field under `minecraft:<field name>`. `Option` fields are left out
+/// while they are `None`.
+#[proc_macro_error]
+#[proc_macro_derive(MinecraftComponent)]
+pub fn derive_minecraft_component(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let syn::Data::Struct(data) = &input.data else {
+        abort_call_site!("MinecraftComponent can only be derived for structs");
+    };
+    let entries = data.fields.iter().map(|field| {
+        let Some(ident) = field.ident.as_ref() else {
+            abort!(field.span(), "MinecraftComponent fields need names");
+        };
+        let key = format!("minecraft:{ident}");
+        if is_option(&field.ty) {
+            quote! {
+                if let Some(value) = &self.#ident {
+                    map.serialize_entry(#key, value)?;
+                }
+            }
+        } else {
+            quote! {
+                map.serialize_entry(#key, &self.#ident)?;
+            }
+        }
+    });
+
+    let expanded = quote! {
+        impl ::pumpkin_protocol::codec::resource_location_map::MinecraftComponent for #name {
+            fn write_components<M: ::serde::ser::SerializeMap>(
+                &self,
+                map: &mut M,
+            ) -> Result<(), M::Error> {
+                #(#entries)*
+                Ok(())
+            }
+        }
+
+        impl ::serde::Serialize for #name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ::pumpkin_protocol::codec::resource_location_map::serialize_components(self, serializer)
+            }
+        }
+    };
+
+    expanded.into()
+}
+
 fn check_serial_attributes(attrs: &[Attribute]) -> (bool, bool) {
     let mut is_big_endian = false;
     let mut no_prefix = false;
@@ -529,6 +580,19 @@ fn check_serial_attributes(attrs: &[Attribute]) -> (bool, bool) {
     (is_big_endian, no_prefix)
 }
 
+fn is_option(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty {
+        type_path
+            .path
+            .segments
+            .iter()
+            .last()
+            .is_some_and(|segment| segment.ident == "Option")
+    } else {
+        false
+    }
+}
+
 fn is_vec(ty: &Type) -> bool {
     if let Type::Path(type_path) = ty {
         type_path
@@ -16,6 +16,7 @@ pub mod compound;
 pub mod deserializer;
 pub mod nbt_compress;
 pub mod serializer;
+pub mod snbt;
 pub mod tag;
 
 pub use deserializer::{from_bytes, from_bytes_unnamed};
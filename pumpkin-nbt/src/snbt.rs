@@ -0,0 +1,343 @@
+//! Parser for stringified NBT (SNBT), the text format used in commands, e.g.
+//! `{display:{Name:'"Sword"'},Damage:5s,Tags:[a,b]}`.
+
+use thiserror::Error;
+
+use crate::{compound::NbtCompound, tag::NbtTag};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message} at position {position}")]
+pub struct SnbtError {
+    /// Byte offset into the input at which parsing failed.
+    pub position: usize,
+    pub message: String,
+}
+
+/// Parses a complete SNBT string, trailing input is an error.
+pub fn from_snbt(input: &str) -> Result<NbtTag, SnbtError> {
+    let mut reader = SnbtReader::new(input);
+    let tag = reader.read_tag()?;
+    reader.skip_whitespace();
+    if reader.peek().is_some() {
+        return Err(reader.error("Expected end of input"));
+    }
+    Ok(tag)
+}
+
+/// Reads SNBT values one after another, allowing callers to parse the surrounding syntax.
+pub struct SnbtReader<'a> {
+    input: &'a str,
+    cursor: usize,
+}
+
+impl<'a> SnbtReader<'a> {
+    #[must_use]
+    pub const fn new(input: &'a str) -> Self {
+        Self { input, cursor: 0 }
+    }
+
+    /// The byte offset of the next character to read.
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    #[must_use]
+    pub fn peek(&self) -> Option<char> {
+        self.input[self.cursor..].chars().next()
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek()
+            && c.is_whitespace()
+        {
+            self.cursor += c.len_utf8();
+        }
+    }
+
+    /// Consumes `expected` after skipping whitespace.
+    pub fn expect(&mut self, expected: char) -> Result<(), SnbtError> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.cursor += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected '{expected}'")))
+        }
+    }
+
+    #[must_use]
+    pub fn error(&self, message: impl Into<String>) -> SnbtError {
+        SnbtError {
+            position: self.cursor,
+            message: message.into(),
+        }
+    }
+
+    pub fn read_tag(&mut self) -> Result<NbtTag, SnbtError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.read_compound().map(NbtTag::Compound),
+            Some('[') => self.read_list_or_array(),
+            Some('"' | '\'') => self.read_quoted_string().map(NbtTag::String),
+            Some(_) => {
+                let token = self.read_unquoted();
+                if token.is_empty() {
+                    return Err(self.error("Expected value"));
+                }
+                // Anything that isn't a number or boolean is a plain string
+                Ok(parse_unquoted(token).unwrap_or_else(|| NbtTag::String(token.to_string())))
+            }
+            None => Err(self.error("Expected value")),
+        }
+    }
+
+    /// Reads a quoted or unquoted string, as used for compound keys.
+    pub fn read_string(&mut self) -> Result<String, SnbtError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('"' | '\'') => self.read_quoted_string(),
+            _ => {
+                let token = self.read_unquoted();
+                if token.is_empty() {
+                    Err(self.error("Expected key"))
+                } else {
+                    Ok(token.to_string())
+                }
+            }
+        }
+    }
+
+    fn read_unquoted(&mut self) -> &'a str {
+        let start = self.cursor;
+        while let Some(c) = self.peek()
+            && is_unquoted_char(c)
+        {
+            self.cursor += c.len_utf8();
+        }
+        &self.input[start..self.cursor]
+    }
+
+    fn read_quoted_string(&mut self) -> Result<String, SnbtError> {
+        let Some(quote) = self.peek() else {
+            return Err(self.error("Expected quote"));
+        };
+        self.cursor += 1;
+        let mut result = String::new();
+        let mut escaping = false;
+        while let Some(c) = self.peek() {
+            self.cursor += c.len_utf8();
+            if escaping {
+                result.push(match c {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    'b' => '\u{8}',
+                    'f' => '\u{c}',
+                    's' => ' ',
+                    '\\' | '"' | '\'' => c,
+                    _ => {
+                        self.cursor -= c.len_utf8();
+                        return Err(self.error(format!("Invalid escape sequence '\\{c}'")));
+                    }
+                });
+                escaping = false;
+            } else if c == '\\' {
+                escaping = true;
+            } else if c == quote {
+                return Ok(result);
+            } else {
+                result.push(c);
+            }
+        }
+        Err(self.error("Unclosed quoted string"))
+    }
+
+    fn read_compound(&mut self) -> Result<NbtCompound, SnbtError> {
+        self.expect('{')?;
+        let mut compound = NbtCompound::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.cursor += 1;
+            return Ok(compound);
+        }
+        loop {
+            let key = self.read_string()?;
+            self.expect(':')?;
+            let value = self.read_tag()?;
+            compound.put(&key, value);
+            if !self.read_separator('}')? {
+                return Ok(compound);
+            }
+        }
+    }
+
+    fn read_list_or_array(&mut self) -> Result<NbtTag, SnbtError> {
+        self.expect('[')?;
+        let rest = &self.input[self.cursor..];
+        let array_type = rest
+            .chars()
+            .next()
+            .filter(|c| matches!(c, 'B' | 'I' | 'L') && rest[1..].starts_with(';'));
+        if let Some(array_type) = array_type {
+            self.cursor += 2;
+            return self.read_array(array_type);
+        }
+
+        let mut list = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.cursor += 1;
+            return Ok(NbtTag::List(list));
+        }
+        loop {
+            let start = self.cursor;
+            let value = self.read_tag()?;
+            if let Some(first) = list.first()
+                && std::mem::discriminant(first) != std::mem::discriminant(&value)
+            {
+                self.cursor = start;
+                return Err(self.error("Can't mix different types in a list"));
+            }
+            list.push(value);
+            if !self.read_separator(']')? {
+                return Ok(NbtTag::List(list));
+            }
+        }
+    }
+
+    fn read_array(&mut self, array_type: char) -> Result<NbtTag, SnbtError> {
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.cursor += 1;
+        } else {
+            loop {
+                let start = self.cursor;
+                let value = match self.read_tag()? {
+                    NbtTag::Byte(value) => i64::from(value),
+                    NbtTag::Short(value) => i64::from(value),
+                    NbtTag::Int(value) => i64::from(value),
+                    NbtTag::Long(value) => value,
+                    _ => {
+                        self.cursor = start;
+                        return Err(self.error("Expected a number in array"));
+                    }
+                };
+                values.push(value);
+                if !self.read_separator(']')? {
+                    break;
+                }
+            }
+        }
+        Ok(match array_type {
+            'B' => NbtTag::ByteArray(values.into_iter().map(|value| value as u8).collect()),
+            'I' => NbtTag::IntArray(values.into_iter().map(|value| value as i32).collect()),
+            _ => NbtTag::LongArray(values),
+        })
+    }
+
+    /// Reads either a `,` (returning `true`) or the closing character (returning `false`).
+    fn read_separator(&mut self, close: char) -> Result<bool, SnbtError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(',') => {
+                self.cursor += 1;
+                Ok(true)
+            }
+            Some(c) if c == close => {
+                self.cursor += 1;
+                Ok(false)
+            }
+            _ => Err(self.error(format!("Expected ',' or '{close}'"))),
+        }
+    }
+}
+
+const fn is_unquoted_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+')
+}
+
+/// Parses numbers with an optional type suffix and booleans.
+fn parse_unquoted(token: &str) -> Option<NbtTag> {
+    match token {
+        "true" => return Some(NbtTag::Byte(1)),
+        "false" => return Some(NbtTag::Byte(0)),
+        _ => {}
+    }
+    let (number, suffix) = token.split_at(token.len() - 1);
+    let tag = match suffix {
+        "b" | "B" => NbtTag::Byte(number.parse().ok()?),
+        "s" | "S" => NbtTag::Short(number.parse().ok()?),
+        "l" | "L" => NbtTag::Long(number.parse().ok()?),
+        "f" | "F" => NbtTag::Float(number.parse().ok()?),
+        "d" | "D" => NbtTag::Double(number.parse().ok()?),
+        _ => {
+            if let Ok(value) = token.parse() {
+                NbtTag::Int(value)
+            } else if token.contains(['.', 'e', 'E']) {
+                NbtTag::Double(token.parse().ok()?)
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(tag)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SnbtReader, from_snbt};
+    use crate::tag::NbtTag;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(from_snbt("5b").unwrap(), NbtTag::Byte(5));
+        assert_eq!(from_snbt("-3s").unwrap(), NbtTag::Short(-3));
+        assert_eq!(from_snbt("42").unwrap(), NbtTag::Int(42));
+        assert_eq!(from_snbt("7L").unwrap(), NbtTag::Long(7));
+        assert_eq!(from_snbt("1.5f").unwrap(), NbtTag::Float(1.5));
+        assert_eq!(from_snbt("2.5").unwrap(), NbtTag::Double(2.5));
+        assert_eq!(from_snbt("true").unwrap(), NbtTag::Byte(1));
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            from_snbt("'it\\'s'").unwrap(),
+            NbtTag::String("it's".to_string())
+        );
+        assert_eq!(
+            from_snbt("hello_world").unwrap(),
+            NbtTag::String("hello_world".to_string())
+        );
+        assert!(from_snbt("minecraft:stone").is_err());
+    }
+
+    #[test]
+    fn compound_and_lists() {
+        let tag = from_snbt("{a: 1, \"b c\": [I; 1, 2], list: [x, y], nested: {}}").unwrap();
+        let NbtTag::Compound(compound) = tag else {
+            panic!("expected compound");
+        };
+        assert_eq!(compound.get_int("a"), Some(1));
+        assert_eq!(compound.get_int_array("b c"), Some(&[1, 2][..]));
+        assert_eq!(compound.child_tags.len(), 4);
+    }
+
+    #[test]
+    fn error_position() {
+        let err = from_snbt("{a:1,b:[1,2s]}").unwrap_err();
+        assert_eq!(err.position, 10);
+
+        let err = from_snbt("{a:1").unwrap_err();
+        assert_eq!(err.position, 4);
+    }
+
+    #[test]
+    fn reader_stops_after_value() {
+        let mut reader = SnbtReader::new("5,damage=3]");
+        assert_eq!(reader.read_tag().unwrap(), NbtTag::Int(5));
+        assert_eq!(reader.cursor(), 1);
+    }
+}
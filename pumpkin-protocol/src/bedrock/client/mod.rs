@@ -14,6 +14,7 @@ pub mod play_status;
 pub mod player_hotbar;
 pub mod raknet;
 pub mod resource_pack_stack;
+pub mod respawn;
 pub mod resource_packs_info;
 pub mod set_actor_data;
 pub mod set_actor_motion;
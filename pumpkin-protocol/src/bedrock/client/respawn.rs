@@ -0,0 +1,20 @@
+use pumpkin_macros::packet;
+use pumpkin_util::math::vector3::Vector3;
+
+use crate::{codec::var_ulong::VarULong, serial::PacketWrite};
+
+#[derive(PacketWrite)]
+#[packet(45)]
+pub struct CRespawn {
+    // https://mojang.github.io/bedrock-protocol-docs/html/RespawnPacket.html
+    pub position: Vector3<f32>,
+    pub state: u8,
+    pub runtime_id: VarULong,
+}
+
+impl CRespawn {
+    /// Sent on death, the client shows the death screen.
+    pub const STATE_SEARCHING_FOR_SPAWN: u8 = 0;
+    /// Sent once the player was respawned at `position`.
+    pub const STATE_READY_TO_SPAWN: u8 = 1;
+}
@@ -113,6 +113,7 @@ pub struct LevelSettings {
     pub game_version: String,
     // TODO: LE
     pub limited_world_width: i32,
+    /// The height of the dimension the player starts in
     pub limited_world_height: i32,
     pub new_nether: bool,
     pub edu_shared_uri_button_name: String,
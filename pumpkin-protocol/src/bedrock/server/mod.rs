@@ -9,4 +9,5 @@ pub mod raknet;
 pub mod request_chunk_radius;
 pub mod request_network_settings;
 pub mod resource_pack_response;
+pub mod respawn;
 pub mod text;
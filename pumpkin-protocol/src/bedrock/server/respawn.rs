@@ -0,0 +1,23 @@
+use pumpkin_macros::packet;
+use pumpkin_util::math::vector3::Vector3;
+
+use crate::{codec::var_ulong::VarULong, serial::PacketRead};
+
+#[derive(PacketRead, Debug)]
+#[packet(45)]
+pub struct SRespawn {
+    // https://mojang.github.io/bedrock-protocol-docs/html/RespawnPacket.html
+    pub position: Vector3<f32>,
+    pub state: u8,
+    pub runtime_id: VarULong,
+}
+
+impl SRespawn {
+    /// Sent when the player presses the respawn button.
+    pub const STATE_CLIENT_READY_TO_SPAWN: u8 = 2;
+
+    #[must_use]
+    pub const fn is_ready_to_spawn(&self) -> bool {
+        self.state == Self::STATE_CLIENT_READY_TO_SPAWN
+    }
+}
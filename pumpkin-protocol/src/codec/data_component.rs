@@ -1,16 +1,25 @@
 use crate::codec::var_int::VarInt;
-use pumpkin_data::Enchantment;
 use pumpkin_data::data_component::DataComponent;
 use pumpkin_data::data_component_impl::{
+    AdventureModePredicate, BlockPredicate, BlockSet, CanBreakImpl, CanPlaceOnImpl, CustomNameImpl,
     DamageImpl, DataComponentImpl, EnchantmentsImpl, FireworkExplosionImpl, FireworkExplosionShape,
-    FireworksImpl, MaxStackSizeImpl, PotionContentsImpl, StatusEffectInstance, UnbreakableImpl,
-    get,
+    FireworksImpl, MapIdImpl, MaxStackSizeImpl, PotionContentsImpl, StatusEffectInstance,
+    StoredEnchantmentsImpl, TrimImpl, UnbreakableImpl, WritableBookContentImpl,
+    WrittenBookContentImpl, get,
 };
-use serde::de;
+use pumpkin_data::packet::CURRENT_MC_PROTOCOL;
+use pumpkin_data::{Block, Enchantment};
+use pumpkin_util::text::TextComponent;
+use pumpkin_util::version::MinecraftVersion;
 use serde::de::SeqAccess;
 use serde::ser::SerializeStruct;
+use serde::{de, ser};
 use std::borrow::Cow;
 
+/// The version whose registry ids are read from and written to the network when the client's
+/// version isn't known.
+pub const LATEST_VERSION: MinecraftVersion = MinecraftVersion::from_protocol(CURRENT_MC_PROTOCOL);
+
 trait DataComponentCodec<Impl: DataComponentImpl> {
     fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error>;
     fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Impl, A::Error>;
@@ -44,39 +53,76 @@ impl DataComponentCodec<Self> for DamageImpl {
     }
 }
 
-impl DataComponentCodec<Self> for EnchantmentsImpl {
+impl DataComponentCodec<Self> for MapIdImpl {
     fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
-        seq.serialize_field::<VarInt>("", &VarInt::from(self.enchantment.len() as i32))?;
-        for (enc, level) in self.enchantment.iter() {
-            seq.serialize_field::<VarInt>("", &VarInt::from(enc.id))?;
-            seq.serialize_field::<VarInt>("", &VarInt::from(*level))?;
-        }
-        Ok(())
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.id))
     }
     fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
-        let len = seq
+        let id = seq
             .next_element::<VarInt>()?
-            .ok_or(de::Error::custom("No EnchantmentsImpl len VarInt!"))?
-            .0 as usize;
-        let mut enc = Vec::with_capacity(len);
-        for _ in 0..len {
-            let id = seq
-                .next_element::<VarInt>()?
-                .ok_or(de::Error::custom("No EnchantmentsImpl id VarInt!"))?
-                .0 as u8;
-            let level = seq
-                .next_element::<VarInt>()?
-                .ok_or(de::Error::custom("No EnchantmentsImpl level VarInt!"))?
-                .0;
-            enc.push((
-                Enchantment::from_id(id).ok_or(de::Error::custom(
-                    "EnchantmentsImpl Enchantment VarInt Incorrect!",
-                ))?,
-                level,
-            ));
-        }
+            .ok_or(de::Error::custom("No map id VarInt!"))?
+            .0;
+        Ok(Self { id })
+    }
+}
+
+fn serialize_enchantment_levels<T: SerializeStruct>(
+    enchantments: &[(&'static Enchantment, i32)],
+    seq: &mut T,
+) -> Result<(), T::Error> {
+    seq.serialize_field::<VarInt>("", &VarInt::from(enchantments.len() as i32))?;
+    for (enc, level) in enchantments {
+        seq.serialize_field::<VarInt>("", &VarInt::from(enc.id))?;
+        seq.serialize_field::<VarInt>("", &VarInt::from(*level))?;
+    }
+    Ok(())
+}
+
+fn deserialize_enchantment_levels<'a, A: SeqAccess<'a>>(
+    seq: &mut A,
+) -> Result<Cow<'static, [(&'static Enchantment, i32)]>, A::Error> {
+    let len = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No EnchantmentsImpl len VarInt!"))?
+        .0 as usize;
+    let mut enc = Vec::with_capacity(len);
+    for _ in 0..len {
+        let id = seq
+            .next_element::<VarInt>()?
+            .ok_or(de::Error::custom("No EnchantmentsImpl id VarInt!"))?
+            .0 as u8;
+        let level = seq
+            .next_element::<VarInt>()?
+            .ok_or(de::Error::custom("No EnchantmentsImpl level VarInt!"))?
+            .0;
+        enc.push((
+            Enchantment::from_id(id).ok_or(de::Error::custom(
+                "EnchantmentsImpl Enchantment VarInt Incorrect!",
+            ))?,
+            level,
+        ));
+    }
+    Ok(Cow::from(enc))
+}
+
+impl DataComponentCodec<Self> for EnchantmentsImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        serialize_enchantment_levels(&self.enchantment, seq)
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        Ok(Self {
+            enchantment: deserialize_enchantment_levels(seq)?,
+        })
+    }
+}
+
+impl DataComponentCodec<Self> for StoredEnchantmentsImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        serialize_enchantment_levels(&self.enchantment, seq)
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
         Ok(Self {
-            enchantment: Cow::from(enc),
+            enchantment: deserialize_enchantment_levels(seq)?,
         })
     }
 }
@@ -411,34 +457,319 @@ impl DataComponentCodec<Self> for FireworksImpl {
     }
 }
 
+impl DataComponentCodec<Self> for WritableBookContentImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.pages.len() as i32))?;
+        for page in &self.pages {
+            seq.serialize_field::<&str>("", &page.as_str())?;
+            // No filtered text
+            seq.serialize_field::<bool>("", &false)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        const MAX_PAGES: usize = 100;
+
+        let pages_len = seq
+            .next_element::<VarInt>()?
+            .ok_or(de::Error::custom(
+                "No WritableBookContent pages_len VarInt!",
+            ))?
+            .0 as usize;
+        if pages_len > MAX_PAGES {
+            return Err(de::Error::custom(format!(
+                "WritableBookContent pages_len {pages_len} exceeds maximum of {MAX_PAGES}"
+            )));
+        }
+        let mut pages = Vec::with_capacity(pages_len);
+        for _ in 0..pages_len {
+            let page = seq
+                .next_element::<String>()?
+                .ok_or(de::Error::custom("No WritableBookContent page String!"))?;
+            let has_filtered = seq.next_element::<bool>()?.ok_or(de::Error::custom(
+                "No WritableBookContent has_filtered bool!",
+            ))?;
+            if has_filtered {
+                seq.next_element::<String>()?.ok_or(de::Error::custom(
+                    "No WritableBookContent filtered page String!",
+                ))?;
+            }
+            pages.push(page);
+        }
+        Ok(Self { pages })
+    }
+}
+
+impl DataComponentCodec<Self> for WrittenBookContentImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        seq.serialize_field::<&str>("", &self.title.as_str())?;
+        // No filtered title
+        seq.serialize_field::<bool>("", &false)?;
+        seq.serialize_field::<&str>("", &self.author.as_str())?;
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.generation))?;
+        seq.serialize_field::<VarInt>("", &VarInt::from(self.pages.len() as i32))?;
+        for page in &self.pages {
+            seq.serialize_field::<TextComponent>("", &TextComponent::text(page.clone()))?;
+            // No filtered text
+            seq.serialize_field::<bool>("", &false)?;
+        }
+        seq.serialize_field::<bool>("", &self.resolved)
+    }
+
+    fn deserialize<'a, A: SeqAccess<'a>>(_seq: &mut A) -> Result<Self, A::Error> {
+        // Pages are NBT text components, which we can't read from the network yet
+        Err(de::Error::custom(
+            "WrittenBookContent can't be read from the network",
+        ))
+    }
+}
+
+impl DataComponentCodec<Self> for CustomNameImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        seq.serialize_field::<TextComponent>("", &TextComponent::text(self.name.clone()))
+    }
+
+    fn deserialize<'a, A: SeqAccess<'a>>(_seq: &mut A) -> Result<Self, A::Error> {
+        // The name is an NBT text component, which we can't read from the network yet
+        Err(de::Error::custom(
+            "CustomName can't be read from the network",
+        ))
+    }
+}
+
+/// Writes the block predicates of `can_place_on` and `can_break`. They only test blocks, so the
+/// state, NBT and component matchers are always empty.
+fn serialize_adventure_mode_predicate<T: SerializeStruct>(
+    predicate: &AdventureModePredicate,
+    seq: &mut T,
+) -> Result<(), T::Error> {
+    seq.serialize_field::<VarInt>("", &VarInt::from(predicate.predicates.len() as i32))?;
+    for predicate in &predicate.predicates {
+        seq.serialize_field::<bool>("", &predicate.blocks.is_some())?;
+        match &predicate.blocks {
+            // Holder sets are sent as a tag name after a 0, or as the number of ids + 1
+            Some(BlockSet::Tag(tag)) => {
+                seq.serialize_field::<VarInt>("", &VarInt(0))?;
+                seq.serialize_field::<&str>("", &tag.as_str())?;
+            }
+            Some(BlockSet::Blocks(blocks)) => {
+                seq.serialize_field::<VarInt>("", &VarInt::from(blocks.len() as i32 + 1))?;
+                for block in blocks {
+                    seq.serialize_field::<VarInt>("", &VarInt::from(i32::from(block.id)))?;
+                }
+            }
+            None => {}
+        }
+        // No state properties, no NBT
+        seq.serialize_field::<bool>("", &false)?;
+        seq.serialize_field::<bool>("", &false)?;
+        // No exact or partial component matchers
+        seq.serialize_field::<VarInt>("", &VarInt(0))?;
+        seq.serialize_field::<VarInt>("", &VarInt(0))?;
+    }
+    Ok(())
+}
+
+fn deserialize_adventure_mode_predicate<'a, A: SeqAccess<'a>>(
+    seq: &mut A,
+) -> Result<AdventureModePredicate, A::Error> {
+    // Needs a length cap during deserialization to prevent OOM from malicious packets
+    const MAX_PREDICATES: usize = 256;
+    const MAX_BLOCKS: usize = 4096;
+
+    let predicates_len = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No block predicates length VarInt!"))?
+        .0 as usize;
+    if predicates_len > MAX_PREDICATES {
+        return Err(de::Error::custom(format!(
+            "Block predicates length {predicates_len} exceeds maximum of {MAX_PREDICATES}"
+        )));
+    }
+    let mut predicates = Vec::with_capacity(predicates_len);
+    for _ in 0..predicates_len {
+        let has_blocks = seq
+            .next_element::<bool>()?
+            .ok_or(de::Error::custom("No block predicate has_blocks bool!"))?;
+        let blocks = if has_blocks {
+            let len = seq
+                .next_element::<VarInt>()?
+                .ok_or(de::Error::custom(
+                    "No block predicate blocks length VarInt!",
+                ))?
+                .0;
+            if len == 0 {
+                let tag = seq
+                    .next_element::<String>()?
+                    .ok_or(de::Error::custom("No block predicate tag String!"))?;
+                Some(BlockSet::Tag(tag))
+            } else {
+                let len = len as usize - 1;
+                if len > MAX_BLOCKS {
+                    return Err(de::Error::custom(format!(
+                        "Block predicate blocks length {len} exceeds maximum of {MAX_BLOCKS}"
+                    )));
+                }
+                let mut blocks = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let id = seq
+                        .next_element::<VarInt>()?
+                        .ok_or(de::Error::custom("No block predicate block id VarInt!"))?
+                        .0;
+                    let id = u16::try_from(id).map_err(|_| {
+                        de::Error::custom("Block predicate block id out of bounds!")
+                    })?;
+                    blocks.push(Block::from_id(id));
+                }
+                Some(BlockSet::Blocks(blocks))
+            }
+        } else {
+            None
+        };
+        for field in ["state", "nbt"] {
+            let present = seq
+                .next_element::<bool>()?
+                .ok_or(de::Error::custom(format!(
+                    "No block predicate has_{field} bool!"
+                )))?;
+            if present {
+                return Err(de::Error::custom(format!(
+                    "Block predicates with {field} are not supported"
+                )));
+            }
+        }
+        for matchers in ["exact", "partial"] {
+            let len = seq
+                .next_element::<VarInt>()?
+                .ok_or(de::Error::custom(format!(
+                    "No block predicate {matchers} matchers length VarInt!"
+                )))?
+                .0;
+            if len != 0 {
+                return Err(de::Error::custom(format!(
+                    "Block predicates with {matchers} component matchers are not supported"
+                )));
+            }
+        }
+        predicates.push(BlockPredicate { blocks });
+    }
+    Ok(AdventureModePredicate { predicates })
+}
+
+impl DataComponentCodec<Self> for CanPlaceOnImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        serialize_adventure_mode_predicate(&self.predicate, seq)
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        Ok(Self {
+            predicate: deserialize_adventure_mode_predicate(seq)?,
+        })
+    }
+}
+
+impl DataComponentCodec<Self> for CanBreakImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        serialize_adventure_mode_predicate(&self.predicate, seq)
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Self, A::Error> {
+        Ok(Self {
+            predicate: deserialize_adventure_mode_predicate(seq)?,
+        })
+    }
+}
+
+/// Trims are sent as registry ids, which depend on the client's version.
+fn serialize_trim<T: SerializeStruct>(
+    trim: &TrimImpl,
+    version: MinecraftVersion,
+    seq: &mut T,
+) -> Result<(), T::Error> {
+    let (material, pattern) = trim.registry_ids(version).ok_or_else(|| {
+        <T::Error as ser::Error>::custom(format!(
+            "Trim {} {} is not known to {version:?} clients",
+            trim.material, trim.pattern
+        ))
+    })?;
+    // Registry holders are sent as id + 1, 0 would mean an inline definition
+    seq.serialize_field::<VarInt>("", &VarInt::from(material as i32 + 1))?;
+    seq.serialize_field::<VarInt>("", &VarInt::from(pattern as i32 + 1))
+}
+
+/// Reads a trim as the latest version's registry ids, see [`remap_trim_from_version`] for older
+/// clients.
+fn deserialize_trim<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<TrimImpl, A::Error> {
+    let material = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No Trim material VarInt!"))?
+        .0;
+    let pattern = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No Trim pattern VarInt!"))?
+        .0;
+    if material <= 0 || pattern <= 0 {
+        return Err(de::Error::custom(
+            "Inline trim materials and patterns are not supported",
+        ));
+    }
+    TrimImpl::from_registry_ids(material as usize - 1, pattern as usize - 1, LATEST_VERSION)
+        .ok_or(de::Error::custom("Trim registry id out of bounds!"))
+}
+
+/// Reinterprets a trim read by [`deserialize_trim`] with the registry ids of `version`.
+#[must_use]
+pub fn remap_trim_from_version(trim: &TrimImpl, version: MinecraftVersion) -> Option<TrimImpl> {
+    let (material, pattern) = trim.registry_ids(LATEST_VERSION)?;
+    TrimImpl::from_registry_ids(material, pattern, version)
+}
+
 pub fn deserialize<'a, A: SeqAccess<'a>>(
     id: DataComponent,
     seq: &mut A,
 ) -> Result<Box<dyn DataComponentImpl>, A::Error> {
     match id {
         DataComponent::MaxStackSize => Ok(MaxStackSizeImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::CustomName => Ok(CustomNameImpl::deserialize(seq)?.to_dyn()),
         DataComponent::Enchantments => Ok(EnchantmentsImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::StoredEnchantments => Ok(StoredEnchantmentsImpl::deserialize(seq)?.to_dyn()),
         DataComponent::Damage => Ok(DamageImpl::deserialize(seq)?.to_dyn()),
         DataComponent::Unbreakable => Ok(UnbreakableImpl::deserialize(seq)?.to_dyn()),
         DataComponent::PotionContents => Ok(PotionContentsImpl::deserialize(seq)?.to_dyn()),
         DataComponent::FireworkExplosion => Ok(FireworkExplosionImpl::deserialize(seq)?.to_dyn()),
         DataComponent::Fireworks => Ok(FireworksImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::WritableBookContent => {
+            Ok(WritableBookContentImpl::deserialize(seq)?.to_dyn())
+        }
+        DataComponent::WrittenBookContent => Ok(WrittenBookContentImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::Trim => Ok(deserialize_trim(seq)?.to_dyn()),
+        DataComponent::MapId => Ok(MapIdImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::CanPlaceOn => Ok(CanPlaceOnImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::CanBreak => Ok(CanBreakImpl::deserialize(seq)?.to_dyn()),
         _ => todo!("{} not yet implemented", id.to_name()),
     }
 }
 pub fn serialize<T: SerializeStruct>(
     id: DataComponent,
     value: &dyn DataComponentImpl,
+    version: MinecraftVersion,
     seq: &mut T,
 ) -> Result<(), T::Error> {
     match id {
         DataComponent::MaxStackSize => get::<MaxStackSizeImpl>(value).serialize(seq),
+        DataComponent::CustomName => get::<CustomNameImpl>(value).serialize(seq),
         DataComponent::Enchantments => get::<EnchantmentsImpl>(value).serialize(seq),
+        DataComponent::StoredEnchantments => get::<StoredEnchantmentsImpl>(value).serialize(seq),
         DataComponent::Damage => get::<DamageImpl>(value).serialize(seq),
         DataComponent::Unbreakable => get::<UnbreakableImpl>(value).serialize(seq),
         DataComponent::PotionContents => get::<PotionContentsImpl>(value).serialize(seq),
         DataComponent::FireworkExplosion => get::<FireworkExplosionImpl>(value).serialize(seq),
         DataComponent::Fireworks => get::<FireworksImpl>(value).serialize(seq),
+        DataComponent::WritableBookContent => get::<WritableBookContentImpl>(value).serialize(seq),
+        DataComponent::WrittenBookContent => get::<WrittenBookContentImpl>(value).serialize(seq),
+        DataComponent::Trim => serialize_trim(get::<TrimImpl>(value), version, seq),
+        DataComponent::MapId => get::<MapIdImpl>(value).serialize(seq),
+        DataComponent::CanPlaceOn => get::<CanPlaceOnImpl>(value).serialize(seq),
+        DataComponent::CanBreak => get::<CanBreakImpl>(value).serialize(seq),
         _ => todo!("{} not yet implemented", id.to_name()),
     }
 }
@@ -1,7 +1,10 @@
 use crate::VarInt;
-use crate::codec::data_component::{deserialize, serialize};
+use crate::codec::data_component::{
+    LATEST_VERSION, deserialize, remap_trim_from_version, serialize,
+};
 use crate::ser::{WritingError, serializer};
 use pumpkin_data::data_component::DataComponent;
+use pumpkin_data::data_component_impl::{DataComponentImpl, TrimImpl, get};
 use pumpkin_data::item::Item;
 use pumpkin_data::item_id_remap::{remap_item_id_for_version, remap_item_id_from_version};
 use pumpkin_util::version::MinecraftVersion;
@@ -33,6 +36,7 @@ fn item_component_counts(stack: &ItemStack) -> (u8, u8) {
 fn serialize_item_stack_with_id<S: Serializer>(
     stack: &ItemStack,
     item_id: u16,
+    version: MinecraftVersion,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
     if stack.is_empty() {
@@ -48,7 +52,7 @@ fn serialize_item_stack_with_id<S: Serializer>(
         for (id, data) in &stack.patch {
             if let Some(data) = data {
                 seq.serialize_field::<VarInt>("", &VarInt::from(id.to_id()))?;
-                serialize(*id, data.as_ref(), &mut seq)?;
+                serialize(*id, data.as_ref(), version, &mut seq)?;
             }
         }
 
@@ -143,7 +147,7 @@ impl<'de> Deserialize<'de> for ItemStackSerializer<'static> {
 
 impl Serialize for ItemStackSerializer<'_> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serialize_item_stack_with_id(self.0.as_ref(), self.0.item.id, serializer)
+        serialize_item_stack_with_id(self.0.as_ref(), self.0.item.id, LATEST_VERSION, serializer)
     }
 }
 
@@ -155,7 +159,12 @@ impl ItemStackSerializer<'_> {
     ) -> Result<(), WritingError> {
         let remapped_item_id = remap_item_id_for_version(self.0.item.id, *version);
         let mut network_serializer = serializer::Serializer::new(write);
-        serialize_item_stack_with_id(self.0.as_ref(), remapped_item_id, &mut network_serializer)
+        serialize_item_stack_with_id(
+            self.0.as_ref(),
+            remapped_item_id,
+            *version,
+            &mut network_serializer,
+        )
     }
 
     #[must_use]
@@ -172,6 +181,16 @@ impl ItemStackSerializer<'_> {
 
         let remapped_item_id = remap_item_id_from_version(stack.item.id, *version);
         stack.item = Item::from_id(remapped_item_id).unwrap_or(&Item::AIR);
+        // Trims were read with the latest registry ids
+        for (id, data) in &mut stack.patch {
+            if *id == DataComponent::Trim
+                && let Some(trim) = data
+                && let Some(remapped) =
+                    remap_trim_from_version(get::<TrimImpl>(trim.as_ref()), *version)
+            {
+                *data = Some(remapped.to_dyn());
+            }
+        }
         stack
     }
 }
@@ -344,3 +363,46 @@ impl<'de> Deserialize<'de> for ItemComponentHash {
         deserializer.deserialize_seq(Visitor)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::data_component::DataComponent;
+    use pumpkin_data::data_component_impl::{DataComponentImpl, TrimImpl};
+    use pumpkin_data::item::Item;
+    use pumpkin_util::version::MinecraftVersion;
+    use pumpkin_world::item::ItemStack;
+
+    use super::ItemStackSerializer;
+
+    fn trimmed_chestplate(trim: TrimImpl) -> ItemStack {
+        ItemStack::new_with_component(
+            1,
+            &Item::IRON_CHESTPLATE,
+            vec![(DataComponent::Trim, Some(trim.to_dyn()))],
+        )
+    }
+
+    #[test]
+    fn known_trim_is_written() {
+        let stack = trimmed_chestplate(TrimImpl::from_names("iron", "bolt").unwrap());
+        let mut buf = Vec::new();
+        ItemStackSerializer::from(stack)
+            .write_with_version(&mut buf, &MinecraftVersion::V_1_21_9)
+            .unwrap();
+        assert!(!buf.is_empty());
+    }
+
+    #[test]
+    fn unknown_trim_is_an_error() {
+        let stack = trimmed_chestplate(TrimImpl {
+            material: "unobtainium",
+            pattern: "bolt",
+        });
+        let mut buf = Vec::new();
+        assert!(
+            ItemStackSerializer::from(stack)
+                .write_with_version(&mut buf, &MinecraftVersion::V_1_21_11)
+                .is_err()
+        );
+    }
+}
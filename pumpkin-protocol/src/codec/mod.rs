@@ -5,6 +5,7 @@ pub mod data_component;
 pub mod item_stack_seralizer;
 pub mod little_endian;
 pub mod optional_int;
+pub mod resource_location_map;
 mod u24_type;
 pub mod uuid;
 pub mod var_int;
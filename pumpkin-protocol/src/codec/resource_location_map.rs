@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+
+use pumpkin_util::resource_location::ResourceLocation;
+use serde::de::{self, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ser::NBT_MARKER;
+
+/// A map from resource locations to values, sent as an NBT compound with the resource locations
+/// as keys. Keys without a namespace are read as `minecraft:` ones.
+///
+/// This only works with the network serializer, which knows to switch to NBT for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceLocationMap<V>(pub HashMap<ResourceLocation, V>);
+
+impl<V> Default for ResourceLocationMap<V> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<V> From<HashMap<ResourceLocation, V>> for ResourceLocationMap<V> {
+    fn from(map: HashMap<ResourceLocation, V>) -> Self {
+        Self(map)
+    }
+}
+
+impl<V: Serialize> Serialize for ResourceLocationMap<V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(NBT_MARKER, &self.0)
+    }
+}
+
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for ResourceLocationMap<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MapVisitor<V>(PhantomData<V>);
+
+        impl<'de, V: Deserialize<'de>> Visitor<'de> for MapVisitor<V> {
+            type Value = ResourceLocationMap<V>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an NBT compound keyed by resource locations")
+            }
+
+            fn visit_newtype_struct<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                HashMap::<String, V>::deserialize(deserializer)?
+                    .into_iter()
+                    .map(|(key, value)| {
+                        normalize_resource_location(&key)
+                            .map(|key| (key, value))
+                            .ok_or_else(|| {
+                                de::Error::custom(format!("Invalid resource location {key}"))
+                            })
+                    })
+                    .collect::<Result<_, _>>()
+                    .map(ResourceLocationMap)
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(NBT_MARKER, MapVisitor(PhantomData))
+    }
+}
+
+/// Adds the `minecraft` namespace if `key` has none. Returns `None` if `key` isn't a valid
+/// `namespace:path` resource location.
+fn normalize_resource_location(key: &str) -> Option<ResourceLocation> {
+    let (namespace, path) = key.split_once(':').unwrap_or(("minecraft", key));
+    let valid_namespace = namespace
+        .chars()
+        .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.'));
+    let valid_path = path
+        .chars()
+        .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | '/'));
+    (!namespace.is_empty() && !path.is_empty() && valid_namespace && valid_path)
+        .then(|| format!("{namespace}:{path}"))
+}
+
+/// A struct of data components that is sent as an NBT compound keyed by the components'
+/// resource locations. Usually implemented with `#[derive(MinecraftComponent)]`, which names
+/// each component `minecraft:<field name>` and skips `None` fields.
+pub trait MinecraftComponent {
+    /// Writes each component that is set as an entry of `map`.
+    fn write_components<M: SerializeMap>(&self, map: &mut M) -> Result<(), M::Error>;
+}
+
+/// Serializes `components` the same way as a [`ResourceLocationMap`].
+pub fn serialize_components<C: MinecraftComponent, S: Serializer>(
+    components: &C,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    struct Components<'a, C>(&'a C);
+
+    impl<C: MinecraftComponent> Serialize for Components<'_, C> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = serializer.serialize_map(None)?;
+            self.0.write_components(&mut map)?;
+            map.end()
+        }
+    }
+
+    serializer.serialize_newtype_struct(NBT_MARKER, &Components(components))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::io::Cursor;
+
+    use pumpkin_macros::MinecraftComponent;
+    use serde::{Deserialize, Serialize};
+
+    use super::ResourceLocationMap;
+    use crate::ser::{deserializer, serializer};
+
+    fn reserialize<T: Serialize>(value: &T) -> Result<ResourceLocationMap<i32>, String> {
+        let mut bytes = Vec::new();
+        value
+            .serialize(&mut serializer::Serializer::new(&mut bytes))
+            .unwrap();
+        ResourceLocationMap::deserialize(&mut deserializer::Deserializer::new(Cursor::new(bytes)))
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn map_is_sent_as_nbt_compound() {
+        let map = ResourceLocationMap(HashMap::from([
+            ("minecraft:max_stack_size".to_string(), 16),
+            ("pumpkin:custom".to_string(), -3),
+        ]));
+        assert_eq!(reserialize(&map), Ok(map));
+
+        let short_keys = ResourceLocationMap(HashMap::from([("damage".to_string(), 4)]));
+        assert_eq!(
+            reserialize(&short_keys),
+            Ok(ResourceLocationMap(HashMap::from([(
+                "minecraft:damage".to_string(),
+                4
+            )])))
+        );
+
+        let invalid = ResourceLocationMap(HashMap::from([("Not Valid".to_string(), 1)]));
+        assert!(reserialize(&invalid).is_err());
+    }
+
+    #[test]
+    fn derived_components_skip_unset_fields() {
+        #[derive(MinecraftComponent)]
+        struct Components {
+            max_stack_size: Option<i32>,
+            damage: Option<i32>,
+            repair_cost: i32,
+        }
+
+        let components = Components {
+            max_stack_size: Some(1),
+            damage: None,
+            repair_cost: 5,
+        };
+        assert_eq!(
+            reserialize(&components),
+            Ok(ResourceLocationMap(HashMap::from([
+                ("minecraft:max_stack_size".to_string(), 1),
+                ("minecraft:repair_cost".to_string(), 5),
+            ])))
+        );
+    }
+}
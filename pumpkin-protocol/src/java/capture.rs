@@ -0,0 +1,349 @@
+//! A simple file format for recording clientbound packets, used to debug desyncs between the
+//! server and a client.
+//!
+//! A capture starts with [`CAPTURE_MAGIC`] and a format version byte, followed by records of
+//! `timestamp (u64) | tick (i32) | protocol (VarInt) | flags (u8) | packet id (VarInt) |
+//! payload length (VarInt) | payload`. Payloads are stored as serialized for the client's
+//! version, before compression and encryption.
+
+use std::fmt::{self, Display, Formatter};
+use std::io::{Read, Write};
+
+use bytes::Bytes;
+use pumpkin_data::packet::clientbound::{PLAY_PLAYER_CHAT, PLAY_PLAYER_INFO_UPDATE};
+use pumpkin_util::version::MinecraftVersion;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    MultiVersionJavaPacket, ServerPacket,
+    codec::var_int::VarInt,
+    java::client::play::{
+        CAcknowledgeBlockChange, CEntityStatus, CGameEvent, CHeadRot, CHurtAnimation, CKeepAlive,
+        CPingResponse, PlayerInfoFlags,
+    },
+    ser::{NetworkReadExt, NetworkWriteExt, ReadingError, WritingError},
+};
+
+pub const CAPTURE_MAGIC: [u8; 8] = *b"PUMPCAP\0";
+pub const CAPTURE_FORMAT_VERSION: u8 = 1;
+
+/// Length of a chat message signature.
+const SIGNATURE_LENGTH: usize = 256;
+
+/// A single recorded clientbound packet.
+#[derive(Debug, Clone)]
+pub struct CaptureRecord {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The server tick during which the packet was sent.
+    pub tick: i32,
+    pub version: MinecraftVersion,
+    pub packet_id: i32,
+    /// Whether the payload was removed because it contained chat session data.
+    pub redacted: bool,
+    pub payload: Bytes,
+}
+
+impl CaptureRecord {
+    const REDACTED_FLAG: u8 = 1;
+
+    /// Creates a record from a serialized packet, which starts with the packet id.
+    pub fn from_packet(
+        timestamp: u64,
+        tick: i32,
+        version: MinecraftVersion,
+        mut packet: Bytes,
+    ) -> Result<Self, ReadingError> {
+        let mut reader = &packet[..];
+        let packet_id = reader.get_var_int()?.0;
+        let payload = packet.split_off(packet.len() - reader.len());
+        Ok(Self {
+            timestamp,
+            tick,
+            version,
+            packet_id,
+            redacted: false,
+            payload,
+        })
+    }
+
+    /// Removes chat session signatures and keys so the capture can be shared safely.
+    ///
+    /// Message signatures in chat messages are zeroed, player info updates which initialize a
+    /// chat session are dropped entirely.
+    pub fn redact_chat_signatures(&mut self) {
+        if self.packet_id == PLAY_PLAYER_CHAT.to_id(self.version) {
+            let mut payload = self.payload.to_vec();
+            match chat_signature_ranges(&payload) {
+                Ok(ranges) => {
+                    for (start, end) in ranges {
+                        payload[start..end].fill(0);
+                    }
+                    self.payload = payload.into();
+                }
+                Err(_) => self.drop_payload(),
+            }
+        } else if self.packet_id == PLAY_PLAYER_INFO_UPDATE.to_id(self.version)
+            && self.payload.first().is_some_and(|actions| {
+                PlayerInfoFlags::from_bits_retain(*actions)
+                    .contains(PlayerInfoFlags::INITIALIZE_CHAT)
+            })
+        {
+            self.drop_payload();
+        }
+    }
+
+    fn drop_payload(&mut self) {
+        self.payload = Bytes::new();
+        self.redacted = true;
+    }
+
+    pub fn write(&self, write: &mut impl Write) -> Result<(), WritingError> {
+        write.write_u64_be(self.timestamp)?;
+        write.write_i32_be(self.tick)?;
+        write.write_var_int(&VarInt(self.version.protocol_version()))?;
+        write.write_u8(if self.redacted {
+            Self::REDACTED_FLAG
+        } else {
+            0
+        })?;
+        write.write_var_int(&VarInt(self.packet_id))?;
+        write.write_var_int(&self.payload.len().try_into().map_err(|_| {
+            WritingError::Message(format!(
+                "{} isn't representable as a VarInt",
+                self.payload.len()
+            ))
+        })?)?;
+        write.write_slice(&self.payload)
+    }
+
+    /// Reads the next record, returning `None` at the end of the capture.
+    pub fn read(read: &mut impl Read) -> Result<Option<Self>, ReadingError> {
+        let mut first = [0u8; 1];
+        if read
+            .read(&mut first)
+            .map_err(|err| ReadingError::Incomplete(err.to_string()))?
+            == 0
+        {
+            return Ok(None);
+        }
+        let mut read = (&first[..]).chain(read);
+
+        let timestamp = read.get_u64_be()?;
+        let tick = read.get_i32_be()?;
+        let protocol = read.get_var_int()?.0;
+        let version = MinecraftVersion::from_protocol(protocol.cast_unsigned());
+        let redacted = read.get_u8()? & Self::REDACTED_FLAG != 0;
+        let packet_id = read.get_var_int()?.0;
+        let length = read.get_var_int()?.0;
+        let length = usize::try_from(length)
+            .map_err(|_| ReadingError::Message(format!("invalid payload length {length}")))?;
+        let payload = read.read_boxed_slice(length)?;
+
+        Ok(Some(Self {
+            timestamp,
+            tick,
+            version,
+            packet_id,
+            redacted,
+            payload: Bytes::from(payload),
+        }))
+    }
+
+    /// Decodes the payload of packets which implement `Deserialize`.
+    /// Returns `None` for other packets.
+    #[must_use]
+    pub fn describe_payload(&self) -> Option<String> {
+        fn describe<P: MultiVersionJavaPacket + DeserializeOwned + fmt::Debug>(
+            record: &CaptureRecord,
+        ) -> Option<String> {
+            if record.packet_id != P::PACKET_ID.to_id(record.version) {
+                return None;
+            }
+            Some(match P::read(&record.payload[..]) {
+                Ok(packet) => format!("{packet:?}"),
+                Err(err) => format!("<failed to decode: {err}>"),
+            })
+        }
+
+        if self.redacted {
+            return None;
+        }
+        describe::<CKeepAlive>(self)
+            .or_else(|| describe::<CPingResponse>(self))
+            .or_else(|| describe::<CGameEvent>(self))
+            .or_else(|| describe::<CEntityStatus>(self))
+            .or_else(|| describe::<CHeadRot>(self))
+            .or_else(|| describe::<CHurtAnimation>(self))
+            .or_else(|| describe::<CAcknowledgeBlockChange>(self))
+    }
+}
+
+impl Display for CaptureRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{} tick {}] {:?} packet 0x{:02X}",
+            self.timestamp, self.tick, self.version, self.packet_id
+        )?;
+        if self.redacted {
+            return write!(f, " <redacted>");
+        }
+        match self.describe_payload() {
+            Some(description) => write!(f, " {description}"),
+            None => write!(f, " ({} bytes)", self.payload.len()),
+        }
+    }
+}
+
+/// Returns the byte ranges of all signatures in a serialized chat message.
+fn chat_signature_ranges(payload: &[u8]) -> Result<Vec<(usize, usize)>, ReadingError> {
+    fn skip_signature<'a>(
+        payload: &[u8],
+        reader: &'a [u8],
+        ranges: &mut Vec<(usize, usize)>,
+    ) -> Result<&'a [u8], ReadingError> {
+        if reader.len() < SIGNATURE_LENGTH {
+            return Err(ReadingError::Incomplete("message signature".to_string()));
+        }
+        let start = payload.len() - reader.len();
+        ranges.push((start, start + SIGNATURE_LENGTH));
+        Ok(&reader[SIGNATURE_LENGTH..])
+    }
+
+    let mut ranges = Vec::new();
+    let mut reader = payload;
+
+    reader.get_var_int()?; // global index
+    reader.get_uuid()?; // sender
+    reader.get_var_int()?; // index
+    if reader.get_bool()? {
+        reader = skip_signature(payload, reader, &mut ranges)?;
+    }
+    reader.get_string()?; // message
+    reader.get_i64_be()?; // timestamp
+    reader.get_i64_be()?; // salt
+    let previous_messages = reader.get_var_int()?.0;
+    for _ in 0..previous_messages {
+        // An id of 0 means the full signature follows
+        if reader.get_var_int()?.0 == 0 {
+            reader = skip_signature(payload, reader, &mut ranges)?;
+        }
+    }
+    Ok(ranges)
+}
+
+/// Iterates over the records of a capture.
+pub struct CaptureReader<R: Read> {
+    read: R,
+}
+
+impl<R: Read> CaptureReader<R> {
+    /// Checks the capture header and returns a reader positioned at the first record.
+    pub fn new(mut read: R) -> Result<Self, ReadingError> {
+        let mut magic = [0u8; CAPTURE_MAGIC.len()];
+        read.read_exact(&mut magic)
+            .map_err(|err| ReadingError::Incomplete(err.to_string()))?;
+        if magic != CAPTURE_MAGIC {
+            return Err(ReadingError::Message(
+                "not a packet capture file".to_string(),
+            ));
+        }
+        let version = read.get_u8()?;
+        if version != CAPTURE_FORMAT_VERSION {
+            return Err(ReadingError::Message(format!(
+                "unsupported capture format version {version}"
+            )));
+        }
+        Ok(Self { read })
+    }
+}
+
+impl<R: Read> Iterator for CaptureReader<R> {
+    type Item = Result<CaptureRecord, ReadingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        CaptureRecord::read(&mut self.read).transpose()
+    }
+}
+
+/// Writes the header every capture starts with.
+pub fn write_capture_header(write: &mut impl Write) -> Result<(), WritingError> {
+    write.write_slice(&CAPTURE_MAGIC)?;
+    write.write_u8(CAPTURE_FORMAT_VERSION)
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_util::version::MinecraftVersion;
+
+    use super::{CaptureReader, CaptureRecord, write_capture_header};
+    use crate::java::client::play::{CKeepAlive, CPlayerChatMessage, FilterType, PreviousMessage};
+    use crate::{ClientPacket, VarInt, ser::NetworkWriteExt};
+
+    fn record<P: ClientPacket>(packet: &P, tick: i32) -> CaptureRecord {
+        let version = MinecraftVersion::V_1_21_11;
+        let mut data = Vec::new();
+        data.write_var_int(&VarInt(P::PACKET_ID.to_id(version)))
+            .unwrap();
+        packet.write_packet_data(&mut data, &version).unwrap();
+        CaptureRecord::from_packet(0, tick, version, data.into()).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let records = [
+            record(&CKeepAlive::new(42), 1),
+            record(&CKeepAlive::new(7), 2),
+        ];
+        let mut file = Vec::new();
+        write_capture_header(&mut file).unwrap();
+        for record in &records {
+            record.write(&mut file).unwrap();
+        }
+
+        let read: Vec<_> = CaptureReader::new(&file[..])
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[1].tick, 2);
+        assert_eq!(read[1].payload, records[1].payload);
+        assert_eq!(
+            read[0].describe_payload().as_deref(),
+            Some("CKeepAlive { keep_alive_id: 42 }")
+        );
+    }
+
+    #[test]
+    fn redacts_message_signature() {
+        let signature = vec![0xAB; 256].into_boxed_slice();
+        let previous = Box::new([PreviousMessage {
+            id: VarInt(0),
+            signature: Some(vec![0xCD; 256].into_boxed_slice()),
+        }]);
+        let message = CPlayerChatMessage::new(
+            VarInt(0),
+            uuid::Uuid::nil(),
+            VarInt(0),
+            Some(signature),
+            "hello".to_string(),
+            0,
+            0,
+            previous,
+            None,
+            FilterType::PassThrough,
+            VarInt(1),
+            pumpkin_util::text::TextComponent::text("Steve"),
+            None,
+        );
+
+        let mut record = record(&message, 0);
+        assert!(record.payload.windows(4).any(|w| w == [0xAB; 4]));
+        record.redact_chat_signatures();
+        assert!(!record.redacted);
+        assert!(!record.payload.windows(4).any(|w| w == [0xAB; 4]));
+        assert!(!record.payload.windows(4).any(|w| w == [0xCD; 4]));
+        assert!(record.payload.windows(5).any(|w| w == b"hello"));
+    }
+}
@@ -1,16 +1,20 @@
 use pumpkin_data::packet::clientbound::CONFIG_DISCONNECT;
 use pumpkin_macros::java_packet;
-use serde::Deserialize;
+use pumpkin_util::text::TextComponent;
+use serde::Serialize;
 
-#[derive(serde::Serialize, Deserialize)]
+/// Disconnects the client during the "Configuration" state.
+///
+/// Like in the "Play" state the reason is sent as an NBT text component.
+#[derive(Serialize)]
 #[java_packet(CONFIG_DISCONNECT)]
 pub struct CConfigDisconnect<'a> {
-    pub reason: &'a str,
+    pub reason: &'a TextComponent,
 }
 
 impl<'a> CConfigDisconnect<'a> {
     #[must_use]
-    pub const fn new(reason: &'a str) -> Self {
+    pub const fn new(reason: &'a TextComponent) -> Self {
         Self { reason }
     }
 }
@@ -8,7 +8,7 @@ use crate::VarInt;
 ///
 /// This packet is critical for preventing "ghost blocks" and synchronization issues.
 /// It tells the client that the server has processed all actions up to a specific point.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[java_packet(PLAY_BLOCK_CHANGED_ACK)]
 pub struct CAcknowledgeBlockChange {
     /// The ID of the last sequence processed by the server.
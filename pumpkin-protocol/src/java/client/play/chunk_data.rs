@@ -11,6 +11,14 @@ use pumpkin_world::chunk::format::LightContainer;
 use pumpkin_world::chunk::{ChunkData, palette::NetworkPalette};
 use std::io::Write;
 
+const fn set_mask_bit(mask: &mut [i64], bit: usize) {
+    mask[bit / 64] |= 1 << (bit % 64);
+}
+
+fn count_mask_bits(mask: &[i64]) -> i32 {
+    mask.iter().map(|long| long.count_ones() as i32).sum()
+}
+
 /// Sent by the server to provide the client with the full data for a chunk.
 ///
 /// This includes heightmaps, the actual block and biome data (organized into sections),
@@ -171,54 +179,54 @@ impl ClientPacket for CChunkData<'_> {
             // This means we need to account for 2 extra sections in the bitset
             let light_engine = self.0.light_engine.lock().unwrap();
             let num_sections = light_engine.sky_light.len();
+            // Taller dimensions need more than one long per mask
+            let mask_len = (num_sections + 2).div_ceil(64);
 
-            let mut sky_light_empty_mask = 0u64;
-            let mut block_light_empty_mask = 0u64;
-            let mut sky_light_mask = 0u64;
-            let mut block_light_mask = 0u64;
+            let mut sky_light_empty_mask = vec![0i64; mask_len];
+            let mut block_light_empty_mask = vec![0i64; mask_len];
+            let mut sky_light_mask = vec![0i64; mask_len];
+            let mut block_light_mask = vec![0i64; mask_len];
 
             // Bit 0 represents the section below the world (always empty)
-            sky_light_empty_mask |= 1 << 0;
-            block_light_empty_mask |= 1 << 0;
+            set_mask_bit(&mut sky_light_empty_mask, 0);
+            set_mask_bit(&mut block_light_empty_mask, 0);
 
             // Bits 1..=num_sections represent the actual world sections
             for section_index in 0..num_sections {
                 let bit_index = section_index + 1; // Offset by 1 for the below-world section
 
                 if let LightContainer::Full(_) = &light_engine.sky_light[section_index] {
-                    sky_light_mask |= 1 << bit_index;
+                    set_mask_bit(&mut sky_light_mask, bit_index);
                 } else {
-                    sky_light_empty_mask |= 1 << bit_index;
+                    set_mask_bit(&mut sky_light_empty_mask, bit_index);
                 }
 
                 if let LightContainer::Full(_) = &light_engine.block_light[section_index] {
-                    block_light_mask |= 1 << bit_index;
+                    set_mask_bit(&mut block_light_mask, bit_index);
                 } else {
-                    block_light_empty_mask |= 1 << bit_index;
+                    set_mask_bit(&mut block_light_empty_mask, bit_index);
                 }
             }
-
             // Bit num_sections+1 represents the section above the world (always empty)
-            sky_light_empty_mask |= 1 << (num_sections + 1);
-            block_light_empty_mask |= 1 << (num_sections + 1);
+            set_mask_bit(&mut sky_light_empty_mask, num_sections + 1);
+            set_mask_bit(&mut block_light_empty_mask, num_sections + 1);
+
+            let sky_light_count = count_mask_bits(&sky_light_mask);
+            let block_light_count = count_mask_bits(&block_light_mask);
 
             // Write Sky Light Mask
-            write.write_bitset(&BitSet(Box::new([sky_light_mask.try_into().unwrap()])))?;
+            write.write_bitset(&BitSet(sky_light_mask.into_boxed_slice()))?;
             // Write Block Light Mask
-            write.write_bitset(&BitSet(Box::new([block_light_mask.try_into().unwrap()])))?;
+            write.write_bitset(&BitSet(block_light_mask.into_boxed_slice()))?;
             // Write Empty Sky Light Mask
-            write.write_bitset(&BitSet(Box::new([sky_light_empty_mask
-                .try_into()
-                .unwrap()])))?;
+            write.write_bitset(&BitSet(sky_light_empty_mask.into_boxed_slice()))?;
             // Write Empty Block Light Mask
-            write.write_bitset(&BitSet(Box::new([block_light_empty_mask
-                .try_into()
-                .unwrap()])))?;
+            write.write_bitset(&BitSet(block_light_empty_mask.into_boxed_slice()))?;
 
             let light_data_size: VarInt = LightContainer::ARRAY_SIZE.try_into().unwrap();
 
             // Write Sky Light arrays
-            write.write_var_int(&VarInt(sky_light_mask.count_ones() as i32))?;
+            write.write_var_int(&VarInt(sky_light_count))?;
             for section_index in 0..num_sections {
                 if let LightContainer::Full(data) = &light_engine.sky_light[section_index] {
                     write.write_var_int(&light_data_size)?;
@@ -227,7 +235,7 @@ impl ClientPacket for CChunkData<'_> {
             }
 
             // Write Block Light arrays
-            write.write_var_int(&VarInt(block_light_mask.count_ones() as i32))?;
+            write.write_var_int(&VarInt(block_light_count))?;
             for section_index in 0..num_sections {
                 if let LightContainer::Full(data) = &light_engine.block_light[section_index] {
                     write.write_var_int(&light_data_size)?;
@@ -238,3 +246,59 @@ impl ClientPacket for CChunkData<'_> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use pumpkin_data::Block;
+    use pumpkin_data::chunk::ChunkStatus;
+    use pumpkin_util::version::MinecraftVersion;
+    use pumpkin_world::chunk::format::LightContainer;
+    use pumpkin_world::chunk::{
+        ChunkData, ChunkHeightmaps, ChunkLight, ChunkSections, SendGeneration,
+    };
+
+    use super::CChunkData;
+    use crate::ClientPacket;
+
+    #[test]
+    fn serialize_tall_dimension_chunk() {
+        let min_y = -128;
+        let height = 512;
+        let section_count = (height / 16) as usize;
+
+        let chunk = ChunkData {
+            section: ChunkSections::new(section_count, min_y),
+            heightmap: std::sync::Mutex::new(ChunkHeightmaps::new(height)),
+            x: 0,
+            z: 0,
+            block_ticks: Default::default(),
+            fluid_ticks: Default::default(),
+            block_entities: Default::default(),
+            light_engine: std::sync::Mutex::new(ChunkLight {
+                sky_light: vec![LightContainer::new_empty(15); section_count].into_boxed_slice(),
+                block_light: vec![LightContainer::new_empty(0); section_count].into_boxed_slice(),
+            }),
+            light_populated: AtomicBool::new(false),
+            status: ChunkStatus::Full,
+            dirty: AtomicBool::new(false),
+            send_generation: SendGeneration::new(),
+        };
+        let stone = Block::STONE.default_state.id;
+        chunk.section.set_block_absolute_y(0, min_y, 0, stone);
+        chunk
+            .section
+            .set_block_absolute_y(15, min_y + height - 1, 15, stone);
+        *chunk.heightmap.lock().unwrap() = chunk.calculate_heightmap();
+
+        let mut buf = Vec::new();
+        CChunkData(&chunk)
+            .write_packet_data(&mut buf, &MinecraftVersion::V_1_21_11)
+            .unwrap();
+
+        // Chunk X and Z, then the heightmap count, the world surface index and its length.
+        // 512 blocks need 10 bits per entry, so 6 entries fit into a long.
+        assert_eq!(&buf[8..11], &[3, 1, 43]);
+    }
+}
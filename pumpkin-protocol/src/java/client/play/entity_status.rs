@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 /// warrant a complex packet of their own. It primarily handles visual
 /// and logical state triggers, such as tool breaking, totem usage,
 /// or sheep shearing.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[java_packet(PLAY_ENTITY_EVENT)]
 pub struct CEntityStatus {
     /// The Entity ID of the entity affected by the status change.
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 /// This packet is the primary way the server communicates global or
 /// context-specific transitions, such as changing the weather,
 /// altering the player's gamemode, or displaying the credits.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[java_packet(PLAY_GAME_EVENT)]
 pub struct CGameEvent {
     /// The ID of the event type.
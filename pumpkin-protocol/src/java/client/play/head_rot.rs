@@ -10,7 +10,7 @@ use crate::VarInt;
 /// While standard movement packets update the body, this packet is
 /// required to make an entity (like a player or a mob) look in a
 /// specific direction without necessarily turning its entire body.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[java_packet(PLAY_ROTATE_HEAD)]
 pub struct CHeadRot {
     /// The Entity ID of the entity whose head is rotating.
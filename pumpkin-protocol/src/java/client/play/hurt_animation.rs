@@ -9,7 +9,7 @@ use crate::VarInt;
 /// This packet causes the entity to turn red and perform a directional
 /// camera shake or model tilt. It is typically sent immediately after
 /// an entity's health is reduced.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[java_packet(PLAY_HURT_ANIMATION)]
 pub struct CHurtAnimation {
     /// The Entity ID of the entity that was hurt.
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 /// The client must respond with the exact same ID. If the server does not receive
 /// a response within a timeout period (usually 30 seconds), it will disconnect
 /// the player with a "Timed Out" message.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[java_packet(PLAY_KEEP_ALIVE)]
 pub struct CKeepAlive {
     /// A unique random identifier for this specific keep-alive request.
@@ -0,0 +1,99 @@
+use std::io::Write;
+
+use crate::{
+    ClientPacket, VarInt,
+    ser::{NetworkWriteExt, WritingError},
+};
+use pumpkin_data::packet::clientbound::PLAY_MAP_ITEM_DATA;
+use pumpkin_macros::java_packet;
+use pumpkin_util::version::MinecraftVersion;
+
+/// Updates the contents of a map item, i.e. its icons and (a part of) its pixels.
+#[java_packet(PLAY_MAP_ITEM_DATA)]
+pub struct CMapData {
+    /// The ID stored in the map item's `minecraft:map_id` component.
+    pub map_id: VarInt,
+    /// From 0 for a fully zoomed-in map (1 block per pixel) to 4 for a fully zoomed-out map.
+    pub scale: i8,
+    pub locked: bool,
+    /// The icons shown on the map. If None, the client keeps the icons it has.
+    pub decorations: Option<Vec<MapDecoration>>,
+    /// The changed pixels. If None, the client keeps the pixels it has.
+    pub patch: Option<MapPatch>,
+}
+
+/// An icon on a map, e.g. a player marker.
+pub struct MapDecoration {
+    /// The ID in the `minecraft:map_decoration_type` registry.
+    pub decoration_type: VarInt,
+    /// The position on the map, from -128 for the left/top edge to 127 for the right/bottom edge.
+    pub x: i8,
+    pub z: i8,
+    /// The rotation in 16ths of a full turn, 0 is facing south.
+    pub rotation: i8,
+}
+
+/// A rectangle of map pixels, given as map color IDs row by row.
+pub struct MapPatch {
+    pub start_x: u8,
+    pub start_z: u8,
+    pub width: u8,
+    pub height: u8,
+    pub colors: Vec<u8>,
+}
+
+impl CMapData {
+    #[must_use]
+    pub const fn new(
+        map_id: VarInt,
+        scale: i8,
+        locked: bool,
+        decorations: Option<Vec<MapDecoration>>,
+        patch: Option<MapPatch>,
+    ) -> Self {
+        Self {
+            map_id,
+            scale,
+            locked,
+            decorations,
+            patch,
+        }
+    }
+}
+
+impl ClientPacket for CMapData {
+    fn write_packet_data(
+        &self,
+        write: impl Write,
+        _version: &MinecraftVersion,
+    ) -> Result<(), WritingError> {
+        let mut write = write;
+        write.write_var_int(&self.map_id)?;
+        write.write_i8(self.scale)?;
+        write.write_bool(self.locked)?;
+        write.write_option(&self.decorations, |write, decorations| {
+            write.write_list(decorations, |write, decoration| {
+                write.write_var_int(&decoration.decoration_type)?;
+                write.write_i8(decoration.x)?;
+                write.write_i8(decoration.z)?;
+                write.write_i8(decoration.rotation)?;
+                // No display name
+                write.write_bool(false)
+            })
+        })?;
+
+        // A width of 0 means there is no patch
+        match &self.patch {
+            Some(patch) => {
+                write.write_u8(patch.width)?;
+                write.write_u8(patch.height)?;
+                write.write_u8(patch.start_x)?;
+                write.write_u8(patch.start_z)?;
+                write.write_list(&patch.colors, |write, color| write.write_u8(*color))?;
+            }
+            None => write.write_u8(0)?,
+        }
+
+        Ok(())
+    }
+}
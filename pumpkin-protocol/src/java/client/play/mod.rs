@@ -37,7 +37,10 @@ mod keep_alive;
 mod level_event;
 mod light_update;
 mod login;
+mod map_item_data;
+mod mount_screen_open;
 mod multi_block_update;
+mod open_book;
 mod open_screen;
 mod open_sign_editor;
 mod particle;
@@ -63,6 +66,7 @@ mod set_container_content;
 mod set_container_property;
 mod set_container_slot;
 mod set_cursor_slot;
+mod set_entity_link;
 mod set_equipment;
 mod set_experience;
 mod set_health;
@@ -88,6 +92,7 @@ mod update_entity_pos_rot;
 mod update_entity_rot;
 mod update_mob_effect;
 mod update_objectives;
+mod update_recipes;
 mod update_score;
 mod worldevent;
 
@@ -130,7 +135,10 @@ pub use keep_alive::*;
 pub use level_event::*;
 pub use light_update::*;
 pub use login::*;
+pub use map_item_data::*;
+pub use mount_screen_open::*;
 pub use multi_block_update::*;
+pub use open_book::*;
 pub use open_screen::*;
 pub use open_sign_editor::*;
 pub use particle::*;
@@ -156,6 +164,7 @@ pub use set_container_content::*;
 pub use set_container_property::*;
 pub use set_container_slot::*;
 pub use set_cursor_slot::*;
+pub use set_entity_link::*;
 pub use set_equipment::*;
 pub use set_experience::*;
 pub use set_health::*;
@@ -181,5 +190,6 @@ pub use update_entity_pos_rot::*;
 pub use update_entity_rot::*;
 pub use update_mob_effect::*;
 pub use update_objectives::*;
+pub use update_recipes::*;
 pub use update_score::*;
 pub use worldevent::*;
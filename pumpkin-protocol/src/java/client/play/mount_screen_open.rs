@@ -0,0 +1,32 @@
+use pumpkin_data::packet::clientbound::PLAY_MOUNT_SCREEN_OPEN;
+use pumpkin_macros::java_packet;
+use serde::Serialize;
+
+use crate::VarInt;
+
+/// Opens the inventory screen of a mount, such as a horse, donkey or mule.
+///
+/// Unlike `COpenScreen`, the layout is not given by a window type but by the
+/// mount itself: the client always shows the saddle and body armor slots and
+/// adds a chest area with the given amount of columns.
+#[derive(Serialize)]
+#[java_packet(PLAY_MOUNT_SCREEN_OPEN)]
+pub struct CMountScreenOpen {
+    /// The sync ID of the screen handler, used by subsequent container packets.
+    pub sync_id: VarInt,
+    /// The amount of chest columns, each holding 3 slots. 0 if the mount has no chest.
+    pub inventory_columns: VarInt,
+    /// The entity ID of the mount.
+    pub entity_id: i32,
+}
+
+impl CMountScreenOpen {
+    #[must_use]
+    pub const fn new(sync_id: VarInt, inventory_columns: VarInt, entity_id: i32) -> Self {
+        Self {
+            sync_id,
+            inventory_columns,
+            entity_id,
+        }
+    }
+}
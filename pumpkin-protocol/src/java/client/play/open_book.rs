@@ -0,0 +1,22 @@
+use pumpkin_data::packet::clientbound::PLAY_OPEN_BOOK;
+use pumpkin_macros::java_packet;
+use serde::Serialize;
+
+use crate::VarInt;
+
+/// Opens the book screen for the book held in the given hand.
+///
+/// For a book and quill this shows the editor, for a written book the reader.
+#[derive(Serialize)]
+#[java_packet(PLAY_OPEN_BOOK)]
+pub struct COpenBook {
+    /// 0 for the main hand, 1 for the off hand.
+    pub hand: VarInt,
+}
+
+impl COpenBook {
+    #[must_use]
+    pub const fn new(hand: VarInt) -> Self {
+        Self { hand }
+    }
+}
@@ -3,7 +3,7 @@ use pumpkin_macros::java_packet;
 use serde::{Deserialize, Serialize};
 
 /// Responds to a client-initiated ping request to synchronize game state.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[java_packet(PLAY_PONG_RESPONSE)]
 pub struct CPingResponse {
     /// The unique identifier sent by the client in the initial Ping packet.
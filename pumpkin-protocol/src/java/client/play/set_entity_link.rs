@@ -0,0 +1,23 @@
+use pumpkin_data::packet::clientbound::PLAY_SET_ENTITY_LINK;
+use pumpkin_macros::java_packet;
+use serde::Serialize;
+
+/// Attaches a leash from one entity to another, or detaches it.
+#[derive(Serialize)]
+#[java_packet(PLAY_SET_ENTITY_LINK)]
+pub struct CSetEntityLink {
+    /// The entity id of the leashed entity.
+    pub attached_entity_id: i32,
+    /// The entity id of the entity holding the leash, or `-1` to detach.
+    pub holding_entity_id: i32,
+}
+
+impl CSetEntityLink {
+    #[must_use]
+    pub const fn new(attached_entity_id: i32, holding_entity_id: i32) -> Self {
+        Self {
+            attached_entity_id,
+            holding_entity_id,
+        }
+    }
+}
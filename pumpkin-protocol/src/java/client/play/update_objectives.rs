@@ -74,6 +74,7 @@ pub enum Mode {
     Update,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RenderType {
     Integer,
     Hearts,
@@ -0,0 +1,179 @@
+use std::io::Write;
+
+use crate::codec::item_stack_seralizer::ItemStackSerializer;
+use crate::codec::var_int::VarInt;
+use crate::{ClientPacket, WritingError, ser::NetworkWriteExt};
+
+use pumpkin_data::item::Item;
+use pumpkin_data::item_id_remap::remap_item_id_for_version;
+use pumpkin_data::packet::clientbound::PLAY_UPDATE_RECIPES;
+use pumpkin_data::recipes::{
+    CookingRecipeType, RECIPES_COOKING, RECIPES_SMITHING, RECIPES_STONECUTTING,
+    RecipeIngredientTypes,
+};
+use pumpkin_data::tag::{RegistryKey, get_tag_ids};
+use pumpkin_macros::java_packet;
+use pumpkin_util::version::MinecraftVersion;
+use pumpkin_world::item::ItemStack;
+
+/// The id of `minecraft:item_stack` in the `slot_display` registry.
+const ITEM_STACK_SLOT_DISPLAY: i32 = 3;
+
+/// Sends the recipe data the client runs its own logic on: the item sets deciding what fits in
+/// furnace and smithing table slots, and the stonecutter recipes it lists for an input.
+#[java_packet(PLAY_UPDATE_RECIPES)]
+pub struct CUpdateRecipes;
+
+/// The items matching a recipe ingredient, as latest item ids.
+fn ingredient_items(ingredient: &RecipeIngredientTypes) -> Vec<u16> {
+    let item_id = |name: &str| {
+        Item::from_registry_key(name.strip_prefix("minecraft:").unwrap_or(name)).map(|item| item.id)
+    };
+    match ingredient {
+        RecipeIngredientTypes::Simple(name) => item_id(name).into_iter().collect(),
+        RecipeIngredientTypes::Tagged(tag) => {
+            get_tag_ids(RegistryKey::Item, tag.strip_prefix('#').unwrap_or(tag))
+                .map(<[u16]>::to_vec)
+                .unwrap_or_default()
+        }
+        RecipeIngredientTypes::OneOf(names) => names.iter().copied().filter_map(item_id).collect(),
+    }
+}
+
+/// The item sets vanilla sends, keyed by their `recipe_property_set` name.
+fn property_sets() -> Vec<(&'static str, Vec<u16>)> {
+    let collect = |ingredients: &mut dyn Iterator<Item = &RecipeIngredientTypes>| {
+        let mut items: Vec<u16> = ingredients.flat_map(ingredient_items).collect();
+        items.sort_unstable();
+        items.dedup();
+        items
+    };
+    let cooking = |matches: fn(&CookingRecipeType) -> bool| {
+        collect(
+            &mut RECIPES_COOKING.iter().filter(|recipe| matches(recipe)).map(
+                |recipe| match recipe {
+                    CookingRecipeType::Blasting(recipe)
+                    | CookingRecipeType::Smelting(recipe)
+                    | CookingRecipeType::Smoking(recipe)
+                    | CookingRecipeType::CampfireCooking(recipe) => &recipe.ingredient,
+                },
+            ),
+        )
+    };
+    vec![
+        (
+            "minecraft:smithing_template",
+            collect(&mut RECIPES_SMITHING.iter().map(|recipe| recipe.template())),
+        ),
+        (
+            "minecraft:smithing_base",
+            collect(&mut RECIPES_SMITHING.iter().map(|recipe| recipe.base())),
+        ),
+        (
+            "minecraft:smithing_addition",
+            collect(&mut RECIPES_SMITHING.iter().map(|recipe| recipe.addition())),
+        ),
+        (
+            "minecraft:furnace_input",
+            cooking(|recipe| matches!(recipe, CookingRecipeType::Smelting(_))),
+        ),
+        (
+            "minecraft:blast_furnace_input",
+            cooking(|recipe| matches!(recipe, CookingRecipeType::Blasting(_))),
+        ),
+        (
+            "minecraft:smoker_input",
+            cooking(|recipe| matches!(recipe, CookingRecipeType::Smoking(_))),
+        ),
+        (
+            "minecraft:campfire_input",
+            cooking(|recipe| matches!(recipe, CookingRecipeType::CampfireCooking(_))),
+        ),
+    ]
+}
+
+fn write_item_ids(
+    write: &mut impl Write,
+    items: &[u16],
+    version: MinecraftVersion,
+) -> Result<(), WritingError> {
+    write.write_list(items, |p, id| {
+        p.write_var_int(&VarInt::from(remap_item_id_for_version(*id, version)))
+    })
+}
+
+impl ClientPacket for CUpdateRecipes {
+    fn write_packet_data(
+        &self,
+        write: impl Write,
+        version: &MinecraftVersion,
+    ) -> Result<(), WritingError> {
+        let mut write = write;
+        write.write_list(&property_sets(), |p, (name, items)| {
+            p.write_string(name)?;
+            write_item_ids(p, items, *version)
+        })?;
+
+        write.write_list(RECIPES_STONECUTTING, |p, recipe| {
+            // The ingredient is a holder set: a tag, or the item count + 1 followed by the items
+            if let RecipeIngredientTypes::Tagged(tag) = recipe.ingredient {
+                p.write_var_int(&VarInt(0))?;
+                p.write_string(tag.strip_prefix('#').unwrap_or(tag))?;
+            } else {
+                let items = ingredient_items(&recipe.ingredient);
+                p.write_var_int(&VarInt(items.len() as i32 + 1))?;
+                for id in items {
+                    p.write_var_int(&VarInt::from(remap_item_id_for_version(id, *version)))?;
+                }
+            }
+
+            p.write_var_int(&VarInt(ITEM_STACK_SLOT_DISPLAY))?;
+            ItemStackSerializer::from(ItemStack::from(&recipe.result))
+                .write_with_version(p, version)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::recipes::RECIPES_STONECUTTING;
+    use pumpkin_util::version::MinecraftVersion;
+
+    use super::{CUpdateRecipes, property_sets};
+    use crate::ClientPacket;
+    use crate::ser::NetworkReadExt;
+
+    #[test]
+    fn furnace_accepts_raw_iron() {
+        let sets = property_sets();
+        let (_, furnace) = sets
+            .iter()
+            .find(|(name, _)| *name == "minecraft:furnace_input")
+            .unwrap();
+        assert!(furnace.contains(&pumpkin_data::item::Item::RAW_IRON.id));
+    }
+
+    #[test]
+    fn writes_property_sets_then_stonecutter_recipes() {
+        let mut buf = Vec::new();
+        CUpdateRecipes
+            .write_packet_data(&mut buf, &MinecraftVersion::V_1_21_11)
+            .unwrap();
+
+        let mut read = buf.as_slice();
+        assert_eq!(read.get_var_int().unwrap().0, 7);
+        assert_eq!(read.get_string().unwrap(), "minecraft:smithing_template");
+        for set in 0..7 {
+            if set > 0 {
+                read.get_string().unwrap();
+            }
+            for _ in 0..read.get_var_int().unwrap().0 {
+                read.get_var_int().unwrap();
+            }
+        }
+        assert_eq!(
+            read.get_var_int().unwrap().0 as usize,
+            RECIPES_STONECUTTING.len()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod capture;
 pub mod client;
 pub mod packet_decoder;
 pub mod packet_encoder;
@@ -133,8 +133,8 @@ impl<R: AsyncRead + Unpin> TCPNetworkDecoder<R> {
                 expected_uncompressed_packet_data_len = Some(decompressed_length);
                 DecompressionReader::Decompress(ZlibDecoder::new(BufReader::new(bounded_reader)))
             } else {
-                // Validate that we are not less than the compression threshold
-                if raw_packet_length > threshold as u64 {
+                // Packets at or above the threshold must be compressed, matching the encoder
+                if raw_packet_length >= threshold as u64 {
                     Err(PacketDecodeError::NotCompressed)?;
                 }
 
@@ -433,4 +433,68 @@ mod tests {
         assert_eq!(raw_packet.id, packet_id);
         assert_eq!(raw_packet.payload.as_ref(), payload);
     }
+
+    /// Helper function to build a packet that is sent uncompressed while compression is enabled
+    fn build_uncompressed_packet(packet_data: &[u8]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        buffer.write_var_int(&VarInt(0)).unwrap();
+        buffer.write_slice(packet_data).unwrap();
+
+        let mut packet = Vec::new();
+        packet.write_var_int(&VarInt(buffer.len() as i32)).unwrap();
+        packet.write_slice(&buffer).unwrap();
+        packet
+    }
+
+    /// Packet data (a one byte packet ID followed by the payload) of exactly `len` bytes
+    fn packet_data_of_len(len: usize) -> Vec<u8> {
+        let mut data = vec![0xAB; len];
+        data[0] = 0x01;
+        data
+    }
+
+    /// Test that uncompressed packets are only accepted below the compression threshold
+    #[tokio::test]
+    async fn decode_uncompressed_at_threshold_boundary() {
+        const THRESHOLD: usize = 256;
+
+        for len in [THRESHOLD - 1, THRESHOLD, THRESHOLD + 1] {
+            let packet = build_uncompressed_packet(&packet_data_of_len(len));
+            let mut decoder = TCPNetworkDecoder::new(packet.as_slice());
+            decoder.set_compression(THRESHOLD);
+
+            let result = decoder.get_raw_packet().await;
+            if len < THRESHOLD {
+                let raw_packet = result.unwrap();
+                assert_eq!(raw_packet.id, 0x01);
+                assert_eq!(raw_packet.payload.len(), len - 1);
+            } else {
+                assert!(
+                    matches!(result, Err(PacketDecodeError::NotCompressed)),
+                    "uncompressed packet of {len} bytes should be rejected"
+                );
+            }
+        }
+    }
+
+    /// Test that everything the encoder writes around the threshold is accepted by the decoder
+    #[tokio::test]
+    async fn encoder_and_decoder_agree_at_threshold_boundary() {
+        const THRESHOLD: usize = 256;
+
+        for len in [THRESHOLD - 1, THRESHOLD, THRESHOLD + 1] {
+            let data = packet_data_of_len(len);
+
+            let mut buf = Vec::new();
+            let mut encoder = crate::java::packet_encoder::TCPNetworkEncoder::new(&mut buf);
+            encoder.set_compression((THRESHOLD, 6));
+            encoder.write_packet(data.clone().into()).await.unwrap();
+
+            let mut decoder = TCPNetworkDecoder::new(buf.as_slice());
+            decoder.set_compression(THRESHOLD);
+            let raw_packet = decoder.get_raw_packet().await.unwrap();
+            assert_eq!(raw_packet.id, 0x01);
+            assert_eq!(raw_packet.payload.as_ref(), &data[1..]);
+        }
+    }
 }
@@ -166,7 +166,7 @@ impl<W: AsyncWrite + Unpin> TCPNetworkEncoder<W> {
 
     /// Appends a Clientbound `ClientPacket` to the internal buffer and applies compression when needed.
     ///
-    /// If compression is enabled and the packet size exceeds the threshold, the packet is compressed.
+    /// If compression is enabled and the packet size is at least the threshold, the packet is compressed.
     /// The packet is prefixed with its length and, if compressed, the uncompressed data length.
     /// The packet format is as follows:
     ///
@@ -713,4 +713,40 @@ mod tests {
 
         assert_eq!(buffer, expected_payload);
     }
+
+    /// Test that packets are compressed exactly when their data length reaches the threshold
+    #[tokio::test]
+    async fn encode_at_threshold_boundary() {
+        const THRESHOLD: usize = 256;
+
+        for len in [THRESHOLD - 1, THRESHOLD, THRESHOLD + 1] {
+            let mut packet_data = vec![0xAB; len];
+            packet_data[0] = 0x01;
+
+            let mut buf = Vec::new();
+            let mut encoder = TCPNetworkEncoder::new(&mut buf);
+            encoder.set_compression((THRESHOLD, 6));
+            encoder
+                .write_packet(packet_data.clone().into())
+                .await
+                .unwrap();
+
+            let mut buffer = &buf[..];
+            let packet_length = decode_varint(&mut buffer).expect("Failed to decode packet length");
+            assert_eq!(packet_length as usize, buffer.len());
+
+            let data_length = decode_varint(&mut buffer).expect("Failed to decode data length");
+            if len < THRESHOLD {
+                assert_eq!(data_length, 0, "{len} bytes should not be compressed");
+                assert_eq!(buffer, packet_data);
+            } else {
+                assert_eq!(
+                    data_length as usize, len,
+                    "{len} bytes should be compressed"
+                );
+                let decompressed = decompress_zlib(buffer, len).expect("Failed to decompress");
+                assert_eq!(decompressed, packet_data);
+            }
+        }
+    }
 }
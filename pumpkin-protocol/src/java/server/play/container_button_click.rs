@@ -0,0 +1,14 @@
+use pumpkin_data::packet::serverbound::PLAY_CONTAINER_BUTTON_CLICK;
+use pumpkin_macros::java_packet;
+use serde::{Deserialize, Serialize};
+
+use crate::VarInt;
+
+/// Sent when the player clicks a button inside a container, e.g. a stonecutter recipe.
+#[derive(Deserialize, Serialize)]
+#[java_packet(PLAY_CONTAINER_BUTTON_CLICK)]
+pub struct SContainerButtonClick {
+    pub window_id: VarInt,
+    /// The meaning depends on the container, for a stonecutter it is the recipe index.
+    pub button_id: VarInt,
+}
@@ -0,0 +1,46 @@
+use std::io::Read;
+
+use pumpkin_data::packet::serverbound::PLAY_EDIT_BOOK;
+use pumpkin_macros::java_packet;
+
+use crate::{
+    ServerPacket, VarInt,
+    ser::{NetworkReadExt, ReadingError},
+};
+
+/// Sent when the player edits or signs a book and quill.
+#[java_packet(PLAY_EDIT_BOOK)]
+pub struct SEditBook {
+    /// The hotbar slot (or 40 for the off hand) the book is in.
+    pub slot: VarInt,
+    /// The text of each page.
+    pub pages: Vec<String>,
+    /// The title of the book, present only when the book is being signed.
+    pub title: Option<String>,
+}
+
+/// The maximum number of pages a book can have.
+pub const MAX_BOOK_PAGES: usize = 100;
+/// The maximum length of a page the client may send.
+const MAX_PAGE_LENGTH: usize = 1024;
+/// The maximum length of a book title.
+pub const MAX_BOOK_TITLE_LENGTH: usize = 32;
+
+impl ServerPacket for SEditBook {
+    fn read(read: impl Read) -> Result<Self, ReadingError> {
+        let mut read = read;
+
+        let slot = read.get_var_int()?;
+        let page_count = read.get_var_int()?.0;
+        if !(0..=MAX_BOOK_PAGES as i32).contains(&page_count) {
+            return Err(ReadingError::TooLarge("book pages".to_string()));
+        }
+        let mut pages = Vec::with_capacity(page_count as usize);
+        for _ in 0..page_count {
+            pages.push(read.get_string_bounded(MAX_PAGE_LENGTH)?);
+        }
+        let title = read.get_option(|read| read.get_string_bounded(MAX_BOOK_TITLE_LENGTH))?;
+
+        Ok(Self { slot, pages, title })
+    }
+}
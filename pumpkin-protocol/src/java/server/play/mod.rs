@@ -7,10 +7,12 @@ mod client_command;
 mod client_information;
 mod client_tick_end;
 mod close_container;
+mod container_button_click;
 mod command_suggestion;
 mod confirm_teleport;
 mod cookie_response;
 mod custom_payload;
+mod edit_book;
 mod interact;
 mod keep_alive;
 mod pick_item;
@@ -42,10 +44,12 @@ pub use client_command::*;
 pub use client_information::*;
 pub use client_tick_end::*;
 pub use close_container::*;
+pub use container_button_click::*;
 pub use command_suggestion::*;
 pub use confirm_teleport::*;
 pub use cookie_response::*;
 pub use custom_payload::*;
+pub use edit_book::*;
 pub use interact::*;
 pub use keep_alive::*;
 pub use pick_item::*;
@@ -23,6 +23,9 @@ use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
 
 use crate::packet::{MultiVersionJavaPacket, Packet};
 
+// Lets `#[derive(MinecraftComponent)]` refer to this crate by name from inside it as well
+extern crate self as pumpkin_protocol;
+
 pub mod bedrock;
 pub mod codec;
 pub mod java;
@@ -354,7 +357,7 @@ pub enum PacketDecodeError {
     MalformedLength(String),
     #[error("failed to decompress packet: {0}")]
     FailedDecompression(String), // Updated to include error details
-    #[error("packet is uncompressed but greater than the threshold")]
+    #[error("packet is uncompressed but at or above the compression threshold")]
     NotCompressed,
     #[error("the connection has closed")]
     ConnectionClosed,
@@ -402,8 +405,16 @@ pub struct Players {
 pub struct Sample {
     /// The player's name.
     pub name: String,
-    /// The player's UUID.
-    pub id: String,
+    /// The player's UUID, sent in the dashed form.
+    #[serde(serialize_with = "serialize_hyphenated_uuid")]
+    pub id: uuid::Uuid,
+}
+
+fn serialize_hyphenated_uuid<S: serde::Serializer>(
+    id: &uuid::Uuid,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&id.hyphenated())
 }
 
 // basically game profile
@@ -423,7 +434,7 @@ pub struct KnownPack<'a> {
     pub version: &'a str,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 pub enum NumberFormat {
     /// Show nothing.
     Blank,
@@ -529,7 +540,7 @@ mod test {
     use serde::{Deserialize, Serialize};
 
     use crate::{
-        IdOr, SoundEvent,
+        IdOr, Sample, SoundEvent,
         ser::{deserializer::Deserializer, serializer::Serializer},
     };
 
@@ -562,4 +573,16 @@ mod test {
 
         assert!(id == deser_id);
     }
+
+    #[test]
+    fn sample_id_is_hyphenated() {
+        let sample = Sample {
+            name: "Steve".to_string(),
+            id: uuid::Uuid::from_u128(0x8667_ba71_b85a_4004_af54_457a_9734_eed7),
+        };
+
+        let json = serde_json::to_value(&sample).unwrap();
+        assert_eq!(json["id"], "8667ba71-b85a-4004-af54-457a9734eed7");
+        assert_eq!(json["name"], "Steve");
+    }
 }
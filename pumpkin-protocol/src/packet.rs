@@ -17,6 +17,12 @@ pub trait Packet {
 
 pub trait MultiVersionJavaPacket {
     const PACKET_ID: PacketId;
+
+    /// Returns whether this packet exists in the given protocol version.
+    #[must_use]
+    fn supports_version(version: MinecraftVersion) -> bool {
+        Self::PACKET_ID.to_id(version) >= 0
+    }
 }
 
 impl<P: MultiVersionJavaPacket + Serialize> ClientPacket for P {
@@ -48,3 +54,26 @@ impl<P: Packet + PacketRead> BServerPacket for P {
         P::read(&mut read)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_data::packet::serverbound::CONFIG_ACCEPT_CODE_OF_CONDUCT;
+    use pumpkin_macros::java_packet;
+    use pumpkin_util::version::MinecraftVersion;
+
+    use super::MultiVersionJavaPacket;
+
+    #[allow(dead_code)]
+    #[java_packet(CONFIG_ACCEPT_CODE_OF_CONDUCT)]
+    struct AcceptCodeOfConduct;
+
+    #[test]
+    fn supports_version() {
+        assert!(AcceptCodeOfConduct::supports_version(
+            MinecraftVersion::V_1_21_11
+        ));
+        assert!(!AcceptCodeOfConduct::supports_version(
+            MinecraftVersion::V_1_21_7
+        ));
+    }
+}
@@ -1,9 +1,10 @@
 use std::fmt::Display;
+use std::io::{Seek, SeekFrom};
 
 use crate::ser::NetworkReadExt;
 use serde::de::{EnumAccess, IntoDeserializer, VariantAccess, Visitor};
 
-use super::{Read, ReadingError};
+use super::{NBT_MARKER, Read, ReadingError};
 use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess};
 
 pub struct Deserializer<R: Read> {
@@ -22,6 +23,42 @@ impl<R: Read> Deserializer<R> {
     }
 }
 
+/// The NBT reader seeks to skip data it doesn't need, which a network stream can only do by
+/// reading it.
+struct ForwardSeek<R: Read> {
+    inner: R,
+    position: u64,
+}
+
+impl<R: Read> std::io::Read for ForwardSeek<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl<R: Read> Seek for ForwardSeek<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let offset = match pos {
+            SeekFrom::Current(offset) => u64::try_from(offset).ok(),
+            SeekFrom::Start(_) | SeekFrom::End(_) => None,
+        };
+        let Some(offset) = offset else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "network data can only be skipped forward",
+            ));
+        };
+        let skipped = std::io::copy(&mut (&mut self.inner).take(offset), &mut std::io::sink())?;
+        self.position += skipped;
+        if skipped < offset {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(self.position)
+    }
+}
+
 impl<'de, R: Read> de::Deserializer<'de> for &mut Deserializer<R> {
     type Error = ReadingError;
 
@@ -133,10 +170,24 @@ impl<'de, R: Read> de::Deserializer<'de> for &mut Deserializer<R> {
 
     fn deserialize_newtype_struct<V: Visitor<'de>>(
         self,
-        _name: &'static str,
-        _visitor: V,
+        name: &'static str,
+        visitor: V,
     ) -> Result<V::Value, Self::Error> {
-        unimplemented!()
+        if name != NBT_MARKER {
+            return Err(ReadingError::Message(format!(
+                "Newtype struct {name} can't be read from network data"
+            )));
+        }
+        let mut nbt_deserializer = pumpkin_nbt::deserializer::Deserializer::new(
+            ForwardSeek {
+                inner: &mut self.inner,
+                position: 0,
+            },
+            false,
+        );
+        visitor
+            .visit_newtype_struct(&mut nbt_deserializer)
+            .map_err(|err| ReadingError::Message(format!("Failed to deserialize NBT: {err}")))
     }
 
     fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
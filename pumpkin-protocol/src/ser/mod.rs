@@ -24,6 +24,9 @@ pub fn network_serialize_no_prefix<T: serde::Serialize, S: serde::Serializer>(
     serializer.serialize_newtype_struct(NO_PREFIX_MARKER, &input)
 }
 
+/// Values wrapped in a newtype struct with this name are written and read as network NBT.
+pub(crate) const NBT_MARKER: &str = "__network_nbt";
+
 #[derive(Debug, Error)]
 pub enum ReadingError {
     #[error("EOF, Tried to read {0} but No bytes left to consume")]
@@ -702,4 +705,14 @@ mod test {
                 .unwrap();
         assert_eq!(empty_map_original, empty_map_deserialized);
     }
+
+    #[test]
+    fn newtype_struct_is_an_error() {
+        #[derive(Deserialize, Debug)]
+        struct Wrapper(i32);
+
+        let cursor = Cursor::new(vec![0, 0, 0, 1]);
+        let result = Wrapper::deserialize(&mut deserializer::Deserializer::new(cursor));
+        assert!(result.is_err());
+    }
 }
@@ -5,7 +5,7 @@ use serde::{
     ser::{self, Impossible},
 };
 
-use super::{NO_PREFIX_MARKER, NetworkWriteExt, Write, WritingError};
+use super::{NBT_MARKER, NO_PREFIX_MARKER, NetworkWriteExt, Write, WritingError};
 
 pub struct Serializer<W: Write> {
     pub write: W,
@@ -265,11 +265,11 @@ impl<W: Write> ser::Serializer for &mut Serializer<W> {
     ) -> Result<Self::Ok, Self::Error> {
         // TODO: This is super sketchy... is there a way to do it better? Can we choose what
         // serializer to use on a struct somehow from within the struct?
-        if name == "TextComponent" {
+        if name == "TextComponent" || name == NBT_MARKER {
             let mut nbt_serializer =
                 pumpkin_nbt::serializer::Serializer::new(&mut self.write, None);
             value.serialize(&mut nbt_serializer).map_err(|err| {
-                WritingError::Serde(format!("Failed to serialize TextComponent NBT: {err}"))
+                WritingError::Serde(format!("Failed to serialize {name} NBT: {err}"))
             })
         } else if name == NO_PREFIX_MARKER {
             value.serialize(NonPrefixedSeqSerializer { wrapped: self })
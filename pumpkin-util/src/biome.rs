@@ -51,7 +51,6 @@ impl TemperatureModifier {
 
 #[derive(Clone, Debug)]
 pub struct Weather {
-    #[expect(dead_code)]
     has_precipitation: bool,
     temperature: f32,
     temperature_modifier: TemperatureModifier,
@@ -75,6 +74,11 @@ impl Weather {
         }
     }
 
+    #[must_use]
+    pub const fn has_precipitation(&self) -> bool {
+        self.has_precipitation
+    }
+
     /// This is an expensive function and should be cached
     pub fn compute_temperature(&self, x: f64, y: i32, z: f64, sea_level: i32) -> f32 {
         let modified_temperature =
@@ -22,6 +22,12 @@ impl GameMode {
             Self::Spectator => "Spectator",
         }
     }
+
+    /// Whether players in this game mode are allowed to fly.
+    #[must_use]
+    pub const fn allows_flying(&self) -> bool {
+        matches!(self, Self::Creative | Self::Spectator)
+    }
 }
 
 impl TryFrom<i8> for GameMode {
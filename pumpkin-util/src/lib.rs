@@ -181,19 +181,20 @@ macro_rules! assert_eq_delta {
     };
 }
 
-/// Represents the player's dominant hand.
+/// The hand a player interacts with.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Hand {
-    /// Usually the player's off-hand.
-    Left,
-    /// Usually the player's primary hand.
-    Right,
+    /// The hand holding the selected hotbar slot.
+    Main,
+    /// The hand holding the off hand slot.
+    Off,
 }
 
 impl Hand {
+    /// Both hands, in the order interactions try them.
     #[must_use]
     pub const fn all() -> [Self; 2] {
-        [Self::Right, Self::Left]
+        [Self::Main, Self::Off]
     }
 }
 
@@ -202,11 +203,32 @@ pub struct InvalidHand;
 impl TryFrom<i32> for Hand {
     type Error = InvalidHand;
 
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Main),
+            1 => Ok(Self::Off),
+            _ => Err(InvalidHand),
+        }
+    }
+}
+
+/// The arm a player holds their main hand in, as chosen in the client's settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arm {
+    Left,
+    Right,
+}
+
+pub struct InvalidArm;
+
+impl TryFrom<i32> for Arm {
+    type Error = InvalidArm;
+
     fn try_from(value: i32) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(Self::Left),
             1 => Ok(Self::Right),
-            _ => Err(InvalidHand),
+            _ => Err(InvalidArm),
         }
     }
 }
@@ -42,13 +42,19 @@ pub enum LootPoolEntryTypes {
     Group,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum LootCondition {
     Inverted,
     AnyOf,
     AllOf,
     RandomChance,
-    RandomChanceWithEnchantedBonus,
+    /// Passes with `unenchanted_chance` without Looting, and with
+    /// `base + per_level_above_first * (level - 1)` otherwise.
+    RandomChanceWithEnchantedBonus {
+        unenchanted_chance: f32,
+        base: f32,
+        per_level_above_first: f32,
+    },
     EntityProperties,
     KilledByPlayer,
     EntityScores,
@@ -80,7 +86,11 @@ pub enum LootFunctionTypes {
         count: LootFunctionNumberProvider,
         add: bool,
     },
-    EnchantedCountIncrease,
+    /// Adds `count` per level of Looting, capped at `limit` if there is one.
+    EnchantedCountIncrease {
+        count: LootFunctionNumberProvider,
+        limit: Option<i32>,
+    },
     FurnaceSmelt,
     SetPotion,
     SetOminousBottleAmplifier,
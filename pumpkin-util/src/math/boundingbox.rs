@@ -2,6 +2,9 @@ use crate::math::{vector2::Vector2, vector3::Axis};
 
 use super::{position::BlockPos, vector3::Vector3};
 
+/// How far boxes may overlap and still collide instead of passing through each other.
+const COLLISION_EPSILON: f64 = 1.0E-7;
+
 #[derive(Clone, Copy, Debug)]
 pub struct BoundingBox {
     pub min: Vector3<f64>,
@@ -155,9 +158,16 @@ impl BoundingBox {
         let move_positive = movement_on_axis.is_sign_positive();
         let self_plane_const = self.get_side(move_positive).get_axis(axis);
         let other_plane_const = other.get_side(!move_positive).get_axis(axis);
-        let collision_time = (other_plane_const - self_plane_const) / movement_on_axis;
+        let distance = other_plane_const - self_plane_const;
 
-        if collision_time < 0.0 || collision_time >= max_time {
+        // A box that sank into the other one by a rounding error still collides and is pushed
+        // back out, like vanilla. Otherwise it would fall through, e.g. into the top of a slab.
+        let overlap = if move_positive { -distance } else { distance };
+        if overlap > COLLISION_EPSILON {
+            return None;
+        }
+        let collision_time = distance / movement_on_axis;
+        if collision_time >= max_time {
             return None;
         }
 
@@ -280,3 +290,55 @@ impl EntityDimensions {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{BoundingBox, EntityDimensions};
+    use crate::math::vector3::{Axis, Vector3};
+
+    fn slab_at(y: f64) -> BoundingBox {
+        BoundingBox::new(Vector3::new(0.0, y, 0.0), Vector3::new(1.0, y + 0.5, 1.0))
+    }
+
+    #[test]
+    fn falling_box_stops_on_top_of_slab() {
+        let player = EntityDimensions::new(0.6, 1.8, 1.62);
+        let falling = Vector3::new(0.0, -0.5, 0.0);
+
+        let above = BoundingBox::new_from_pos(0.5, 64.7, 0.5, &player);
+        let time = above.calculate_collision_time(&slab_at(64.0), falling, Axis::Y, 1.0);
+        assert!((time.unwrap() - 0.4).abs() < 1e-9);
+
+        // Sunk into the slab by a rounding error: pushed back out instead of falling through
+        let sunk = BoundingBox::new_from_pos(0.5, 64.499_999_999_99, 0.5, &player);
+        let time = sunk
+            .calculate_collision_time(&slab_at(64.0), falling, Axis::Y, 1.0)
+            .unwrap();
+        assert!(time <= 0.0);
+        assert!((sunk.min.y + falling.y * time - 64.5).abs() < 1e-12);
+
+        // Boxes that are really inside each other don't collide
+        let inside = BoundingBox::new_from_pos(0.5, 64.25, 0.5, &player);
+        assert!(
+            inside
+                .calculate_collision_time(&slab_at(64.0), falling, Axis::Y, 1.0)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn box_beside_slab_does_not_collide() {
+        let player = EntityDimensions::new(0.6, 1.8, 1.62);
+        let beside = BoundingBox::new_from_pos(1.5, 64.7, 0.5, &player);
+        assert!(
+            beside
+                .calculate_collision_time(
+                    &slab_at(64.0),
+                    Vector3::new(0.0, -0.5, 0.0),
+                    Axis::Y,
+                    1.0
+                )
+                .is_none()
+        );
+    }
+}
@@ -41,6 +41,31 @@ impl Xoroshiro {
         (l, m)
     }
 
+    /// Seeds a generator the way vanilla seeds a random sequence: like [`Self::from_seed`], but
+    /// mixed with the hash of `key` (e.g. the sequence's id) if there is one.
+    #[must_use]
+    pub fn from_sequence_seed(seed: u64, key: Option<&str>) -> Self {
+        let (mut lo, mut hi) = Self::mix_u64(seed);
+        if let Some(key) = key {
+            let (key_lo, key_hi) = seed_from_hash_of(key);
+            lo ^= key_lo;
+            hi ^= key_hi;
+        }
+        Self::new(mix_stafford_13(lo), mix_stafford_13(hi))
+    }
+
+    /// Continues a generator from a state returned by [`Self::state`].
+    #[must_use]
+    pub const fn from_state(lo: u64, hi: u64) -> Self {
+        Self::new(lo, hi)
+    }
+
+    /// The current state, e.g. to save a random sequence and continue it later.
+    #[must_use]
+    pub const fn state(&self) -> (u64, u64) {
+        (self.lo, self.hi)
+    }
+
     #[must_use]
     pub const fn from_seed_unmixed(seed: u64) -> Self {
         let (lo, hi) = Self::mix_u64(seed);
@@ -72,6 +97,13 @@ impl GaussianGenerator for Xoroshiro {
     }
 }
 
+fn seed_from_hash_of(seed: &str) -> (u64, u64) {
+    let bytes = md5::compute(seed.as_bytes());
+    let lo = u64::from_be_bytes(bytes[0..8].try_into().expect("incorrect length"));
+    let hi = u64::from_be_bytes(bytes[8..16].try_into().expect("incorrect length"));
+    (lo, hi)
+}
+
 const fn mix_stafford_13(z: u64) -> u64 {
     let z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
     let z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
@@ -151,9 +183,7 @@ impl RandomDeriverImpl for XoroshiroSplitter {
     }
 
     fn split_string(&self, seed: &str) -> RandomGenerator {
-        let bytes = md5::compute(seed.as_bytes());
-        let l = u64::from_be_bytes(bytes[0..8].try_into().expect("incorrect length"));
-        let m = u64::from_be_bytes(bytes[8..16].try_into().expect("incorrect length"));
+        let (l, m) = seed_from_hash_of(seed);
 
         RandomGenerator::Xoroshiro(Xoroshiro::new(l ^ self.lo, m ^ self.hi))
     }
@@ -412,4 +442,28 @@ mod tests {
         let mut xoroshiro = Xoroshiro::new(0, 0);
         assert_eq!(xoroshiro.next_i64(), 6807859099481836695);
     }
+
+    #[test]
+    fn sequence_seed_without_key_matches_seed() {
+        let mut sequence = Xoroshiro::from_sequence_seed(1234, None);
+        let mut seeded = Xoroshiro::from_seed(1234);
+        for _ in 0..10 {
+            assert_eq!(sequence.next_i64(), seeded.next_i64());
+        }
+        assert_ne!(
+            Xoroshiro::from_sequence_seed(1234, Some("minecraft:test")).state(),
+            Xoroshiro::from_seed(1234).state()
+        );
+    }
+
+    #[test]
+    fn continue_from_state() {
+        let mut xoroshiro = Xoroshiro::from_seed(42);
+        xoroshiro.next_i64();
+        let (lo, hi) = xoroshiro.state();
+        let mut continued = Xoroshiro::from_state(lo, hi);
+        for _ in 0..10 {
+            assert_eq!(xoroshiro.next_i32(), continued.next_i32());
+        }
+    }
 }
@@ -1,3 +1,36 @@
+use pumpkin_nbt::compound::NbtCompound;
+
+/// The loot table a container gets filled from the first time it is opened, stored as the
+/// `LootTable` and `LootTableSeed` tags like vanilla does.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContainerLootTable {
+    pub key: String,
+    pub seed: i64,
+}
+
+impl ContainerLootTable {
+    #[must_use]
+    pub fn new(key: impl Into<String>, seed: i64) -> Self {
+        Self {
+            key: key.into(),
+            seed,
+        }
+    }
+
+    #[must_use]
+    pub fn read(nbt: &NbtCompound) -> Option<Self> {
+        let key = nbt.get_string("LootTable")?;
+        Some(Self::new(key, nbt.get_long("LootTableSeed").unwrap_or(0)))
+    }
+
+    pub fn write(&self, nbt: &mut NbtCompound) {
+        nbt.put_string("LootTable", self.key.clone());
+        if self.seed != 0 {
+            nbt.put_long("LootTableSeed", self.seed);
+        }
+    }
+}
+
 /// Implements the BlockEntity trait for chest-like block entities.
 /// Parameters:
 /// - $struct_name: The type of the chest struct (e.g., ChestBlockEntity)
@@ -25,6 +58,11 @@ macro_rules! impl_block_entity_for_chest {
                     items: std::array::from_fn(|_| Arc::new(Mutex::new(ItemStack::EMPTY.clone()))),
                     dirty: std::sync::atomic::AtomicBool::new(false),
                     viewers: $crate::block::viewer::ViewerCountTracker::new(),
+                    loot_table: std::sync::Mutex::new(
+                        $crate::block::entities::chest_like_block_entity::ContainerLootTable::read(
+                            nbt,
+                        ),
+                    ),
                 };
 
                 chest.read_data(nbt, &chest.items);
@@ -38,8 +76,14 @@ macro_rules! impl_block_entity_for_chest {
             ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + 'a>> {
                 use $crate::inventory::Inventory;
 
-                // Write inventory data to NBT
-                self.write_inventory_nbt(nbt, true)
+                Box::pin(async move {
+                    let loot_table = self.loot_table.lock().unwrap().clone();
+                    if let Some(loot_table) = loot_table {
+                        loot_table.write(nbt);
+                    }
+                    // Write inventory data to NBT
+                    self.write_inventory_nbt(nbt, true).await;
+                })
             }
 
             fn tick<'a>(
@@ -264,9 +308,19 @@ macro_rules! impl_chest_helper_methods {
                     items: from_fn(|_| Arc::new(Mutex::new(ItemStack::EMPTY.clone()))),
                     dirty: AtomicBool::new(false),
                     viewers: $crate::block::viewer::ViewerCountTracker::new(),
+                    loot_table: std::sync::Mutex::new(None),
                 }
             }
 
+            /// Sets the loot table the chest gets filled from, as world generation does.
+            pub fn set_loot_table(
+                &self,
+                loot_table: $crate::block::entities::chest_like_block_entity::ContainerLootTable,
+            ) {
+                *self.loot_table.lock().unwrap() = Some(loot_table);
+                self.dirty.store(true, std::sync::atomic::Ordering::Relaxed);
+            }
+
             async fn play_sound(
                 &self,
                 world: &Arc<dyn $crate::world::SimpleWorld>,
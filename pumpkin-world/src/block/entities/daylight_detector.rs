@@ -0,0 +1,73 @@
+use std::{pin::Pin, sync::Arc};
+
+use pumpkin_data::Block;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::math::position::BlockPos;
+
+use super::BlockEntity;
+use crate::{tick::TickPriority, world::SimpleWorld};
+
+/// Drives a daylight detector, which measures the sky light once per second.
+///
+/// The measurement itself needs the world's lighting and time, so the block entity only
+/// schedules a tick for the block, which does the rest.
+pub struct DaylightDetectorBlockEntity {
+    pub position: BlockPos,
+}
+
+impl DaylightDetectorBlockEntity {
+    pub const ID: &'static str = "minecraft:daylight_detector";
+    /// How many ticks pass between two measurements.
+    pub const UPDATE_INTERVAL: i64 = 20;
+
+    #[must_use]
+    pub const fn new(position: BlockPos) -> Self {
+        Self { position }
+    }
+}
+
+impl BlockEntity for DaylightDetectorBlockEntity {
+    fn resource_location(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn get_position(&self) -> BlockPos {
+        self.position
+    }
+
+    fn tick<'a>(
+        &'a self,
+        world: &'a Arc<dyn SimpleWorld>,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            if world.get_world_age().await % Self::UPDATE_INTERVAL == 0 {
+                world
+                    .schedule_block_tick(
+                        &Block::DAYLIGHT_DETECTOR,
+                        self.position,
+                        1,
+                        TickPriority::Normal,
+                    )
+                    .await;
+            }
+        })
+    }
+
+    fn from_nbt(_nbt: &NbtCompound, position: BlockPos) -> Self
+    where
+        Self: Sized,
+    {
+        Self { position }
+    }
+
+    fn write_nbt<'a>(
+        &'a self,
+        _nbt: &'a mut NbtCompound,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async {})
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
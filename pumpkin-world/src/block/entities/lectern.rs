@@ -0,0 +1,189 @@
+use std::any::Any;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::math::position::BlockPos;
+use tokio::sync::Mutex;
+
+use crate::inventory::{Clearable, Inventory, InventoryFuture};
+use crate::{block::entities::BlockEntity, item::ItemStack};
+
+/// Matches vanilla's `LecternBlockEntity`
+pub struct LecternBlockEntity {
+    position: BlockPos,
+    /// The book on the lectern (`Book` in NBT)
+    book: Arc<Mutex<ItemStack>>,
+    /// The page the book is open at (`Page` in NBT)
+    page: AtomicI32,
+    dirty: AtomicBool,
+}
+
+const BOOK_NBT_KEY: &str = "Book";
+const PAGE_NBT_KEY: &str = "Page";
+
+impl BlockEntity for LecternBlockEntity {
+    fn resource_location(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn get_position(&self) -> BlockPos {
+        self.position
+    }
+
+    fn from_nbt(nbt: &NbtCompound, position: BlockPos) -> Self
+    where
+        Self: Sized,
+    {
+        let book = nbt
+            .get_compound(BOOK_NBT_KEY)
+            .and_then(ItemStack::read_item_stack)
+            .unwrap_or_else(|| ItemStack::EMPTY.clone());
+
+        Self {
+            position,
+            book: Arc::new(Mutex::new(book)),
+            page: AtomicI32::new(nbt.get_int(PAGE_NBT_KEY).unwrap_or(0)),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    fn write_nbt<'a>(
+        &'a self,
+        nbt: &'a mut NbtCompound,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let book = self.book.lock().await;
+            if !book.is_empty() {
+                let mut book_nbt = NbtCompound::new();
+                book.write_item_stack(&mut book_nbt);
+                nbt.put(BOOK_NBT_KEY, book_nbt);
+                nbt.put_int(PAGE_NBT_KEY, self.page.load(Ordering::Relaxed));
+            }
+        })
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty.load(Ordering::Relaxed)
+    }
+
+    fn clear_dirty(&self) {
+        self.dirty.store(false, Ordering::Relaxed);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_inventory(self: Arc<Self>) -> Option<Arc<dyn Inventory>> {
+        Some(self)
+    }
+}
+
+impl LecternBlockEntity {
+    pub const ID: &'static str = "minecraft:lectern";
+
+    #[must_use]
+    pub fn new(position: BlockPos) -> Self {
+        Self {
+            position,
+            book: Arc::new(Mutex::new(ItemStack::EMPTY.clone())),
+            page: AtomicI32::new(0),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    pub async fn has_book(&self) -> bool {
+        !self.book.lock().await.is_empty()
+    }
+
+    pub async fn get_book(&self) -> ItemStack {
+        self.book.lock().await.clone()
+    }
+
+    /// The stack on the lectern, for changing the book in place, e.g. when it is edited.
+    #[must_use]
+    pub fn book_stack(&self) -> Arc<Mutex<ItemStack>> {
+        self.book.clone()
+    }
+
+    /// Puts a single book on the lectern, opened at the first page.
+    /// Returns `false` if there already is a book.
+    pub async fn place_book(&self, book: ItemStack) -> bool {
+        let mut current = self.book.lock().await;
+        if !current.is_empty() {
+            return false;
+        }
+        *current = book.copy_with_count(1);
+        self.page.store(0, Ordering::Relaxed);
+        self.mark_dirty();
+        true
+    }
+
+    #[must_use]
+    pub fn get_page(&self) -> i32 {
+        self.page.load(Ordering::Relaxed)
+    }
+
+    pub fn set_page(&self, page: i32) {
+        self.page.store(page.max(0), Ordering::Relaxed);
+        self.mark_dirty();
+    }
+}
+
+/// Implements a single-slot inventory for the book
+impl Inventory for LecternBlockEntity {
+    fn size(&self) -> usize {
+        1
+    }
+
+    fn is_empty(&self) -> InventoryFuture<'_, bool> {
+        Box::pin(async move { self.book.lock().await.is_empty() })
+    }
+
+    fn get_stack(&self, _slot: usize) -> InventoryFuture<'_, Arc<Mutex<ItemStack>>> {
+        Box::pin(async move { self.book.clone() })
+    }
+
+    fn remove_stack(&self, _slot: usize) -> InventoryFuture<'_, ItemStack> {
+        Box::pin(async move {
+            let mut book = self.book.lock().await;
+            let taken = book.clone();
+            *book = ItemStack::EMPTY.clone();
+            self.page.store(0, Ordering::Relaxed);
+            self.mark_dirty();
+            taken
+        })
+    }
+
+    fn remove_stack_specific(&self, _slot: usize, _amount: u8) -> InventoryFuture<'_, ItemStack> {
+        // The lectern only holds one book
+        self.remove_stack(0)
+    }
+
+    fn set_stack(&self, _slot: usize, stack: ItemStack) -> InventoryFuture<'_, ()> {
+        Box::pin(async move {
+            *self.book.lock().await = stack;
+            self.mark_dirty();
+        })
+    }
+
+    fn mark_dirty(&self) {
+        self.dirty.store(true, Ordering::Relaxed);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl Clearable for LecternBlockEntity {
+    fn clear(&self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            *self.book.lock().await = ItemStack::EMPTY.clone();
+            self.mark_dirty();
+        })
+    }
+}
@@ -2,7 +2,7 @@ use std::{
     pin::Pin,
     sync::{
         Arc,
-        atomic::{AtomicI32, Ordering},
+        atomic::{AtomicU16, Ordering},
     },
 };
 
@@ -19,57 +19,98 @@ use crate::{block::entities::BlockEntity, world::SimpleWorld};
 
 pub struct MobSpawnerBlockEntity {
     pub position: BlockPos,
-    pub delay: AtomicI32,
-    pub max_delay: i32,
-    pub min_delay: i32,
-    pub spawn_count: i32,
-    pub spawn_range: i32,
-    pub entity_type: AtomicCell<Option<&'static EntityType>>,
+    /// The entity this spawner spawns. Nothing is spawned until it is set.
+    pub spawn_entry: AtomicCell<Option<&'static EntityType>>,
+    /// How many entities are attempted to be spawned at once.
+    pub spawn_count: u8,
+    /// Spawning is skipped while this many entities of the spawned type are nearby.
+    pub max_nearby_entities: u8,
+    /// The spawner only ticks while a player is within this many blocks.
+    pub required_player_range: u8,
+    /// How far from the spawner, in blocks, entities are spawned.
+    pub spawn_range: u8,
+    /// Ticks until the next spawn attempt.
+    pub delay: AtomicU16,
+    pub min_spawn_delay: u16,
+    pub max_spawn_delay: u16,
 }
 
 impl MobSpawnerBlockEntity {
     pub const ID: &'static str = "minecraft:mob_spawner";
-    pub const DEFAULT_DELAY: i32 = 20;
-    pub const DEFAULT_MAX_SPAWN_DELAY: i32 = 800;
-    pub const DEFAULT_MIN_SPAWN_DELAY: i32 = 200;
-    pub const DEFAULT_SPAWN_COUNT: i32 = 4;
-    pub const DEFAULT_SPAWN_RANGE: i32 = 4;
+    pub const DEFAULT_DELAY: u16 = 20;
+    pub const DEFAULT_MAX_SPAWN_DELAY: u16 = 800;
+    pub const DEFAULT_MIN_SPAWN_DELAY: u16 = 200;
+    pub const DEFAULT_SPAWN_COUNT: u8 = 4;
+    pub const DEFAULT_MAX_NEARBY_ENTITIES: u8 = 6;
+    pub const DEFAULT_REQUIRED_PLAYER_RANGE: u8 = 16;
+    pub const DEFAULT_SPAWN_RANGE: u8 = 4;
 
     #[must_use]
     pub const fn new(position: BlockPos) -> Self {
         Self {
             position,
-            delay: AtomicI32::new(Self::DEFAULT_DELAY),
-            max_delay: Self::DEFAULT_MAX_SPAWN_DELAY,
-            min_delay: Self::DEFAULT_MIN_SPAWN_DELAY,
+            spawn_entry: AtomicCell::new(None),
             spawn_count: Self::DEFAULT_SPAWN_COUNT,
+            max_nearby_entities: Self::DEFAULT_MAX_NEARBY_ENTITIES,
+            required_player_range: Self::DEFAULT_REQUIRED_PLAYER_RANGE,
             spawn_range: Self::DEFAULT_SPAWN_RANGE,
-            entity_type: AtomicCell::new(None),
+            delay: AtomicU16::new(Self::DEFAULT_DELAY),
+            min_spawn_delay: Self::DEFAULT_MIN_SPAWN_DELAY,
+            max_spawn_delay: Self::DEFAULT_MAX_SPAWN_DELAY,
         }
     }
 }
 
 impl MobSpawnerBlockEntity {
-    async fn update_spawns(&self, world: &Arc<dyn SimpleWorld>) {
-        let min_delay = self.min_delay;
-        let max_delay = self.max_delay;
-
-        self.delay.store(
-            if max_delay <= min_delay {
-                min_delay
-            } else {
-                min_delay + rand::random_range(0..max_delay - min_delay)
-            },
-            Ordering::Relaxed,
-        );
+    /// Picks a new random delay and tells the clients, which reset the spinning mob and the
+    /// particles they show.
+    async fn reset_delay(&self, world: &Arc<dyn SimpleWorld>) {
+        let delay = if self.max_spawn_delay <= self.min_spawn_delay {
+            self.min_spawn_delay
+        } else {
+            rand::random_range(self.min_spawn_delay..self.max_spawn_delay)
+        };
+        self.delay.store(delay, Ordering::Relaxed);
         world.add_synced_block_event(self.position, 1, 0).await;
     }
 
+    fn random_spawn_pos(&self) -> Vector3<f64> {
+        let pos = self.position.0;
+        let range = f64::from(self.spawn_range);
+        Vector3::new(
+            f64::from(pos.x) + (rand::random::<f64>() - rand::random::<f64>()) * range + 0.5,
+            f64::from(pos.y + rand::random_range(0..3) - 1),
+            f64::from(pos.z) + (rand::random::<f64>() - rand::random::<f64>()) * range + 0.5,
+        )
+    }
+
     pub fn set_entity_type(&self, entity_type: &'static EntityType) {
-        self.entity_type.store(Some(entity_type));
+        self.spawn_entry.store(Some(entity_type));
+    }
+
+    fn write_spawner_data(&self, nbt: &mut NbtCompound) {
+        nbt.put_short("Delay", to_nbt_short(self.delay.load(Ordering::Relaxed)));
+        nbt.put_short("MinSpawnDelay", to_nbt_short(self.min_spawn_delay));
+        nbt.put_short("MaxSpawnDelay", to_nbt_short(self.max_spawn_delay));
+        nbt.put_short("SpawnCount", self.spawn_count.into());
+        nbt.put_short("MaxNearbyEntities", self.max_nearby_entities.into());
+        nbt.put_short("RequiredPlayerRange", self.required_player_range.into());
+        nbt.put_short("SpawnRange", self.spawn_range.into());
+        if let Some(entity_type) = self.spawn_entry.load() {
+            let mut entity_nbt = NbtCompound::new();
+            entity_nbt.put_string("id", format!("minecraft:{}", entity_type.resource_name));
+
+            let mut spawn_data = NbtCompound::new();
+            spawn_data.put_component("entity", entity_nbt);
+            nbt.put_component("SpawnData", spawn_data);
+        }
     }
 }
 
+fn to_nbt_short(value: u16) -> i16 {
+    i16::try_from(value).unwrap_or(i16::MAX)
+}
+
 impl BlockEntity for MobSpawnerBlockEntity {
     fn resource_location(&self) -> &'static str {
         Self::ID
@@ -84,52 +125,60 @@ impl BlockEntity for MobSpawnerBlockEntity {
         world: &'a Arc<dyn SimpleWorld>,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
-            if let Some(entity_type) = &self.entity_type.load() {
-                if self.delay.load(Ordering::Relaxed) == -1 {
-                    self.update_spawns(world).await;
-                } else {
-                    self.delay.fetch_sub(1, Ordering::Relaxed);
+            let Some(entity_type) = self.spawn_entry.load() else {
+                return;
+            };
+            if !world
+                .is_player_in_range(
+                    self.position.to_centered_f64(),
+                    f64::from(self.required_player_range),
+                )
+                .await
+            {
+                return;
+            }
+            let delay = self.delay.load(Ordering::Relaxed);
+            if delay > 0 {
+                self.delay.store(delay - 1, Ordering::Relaxed);
+                return;
+            }
+
+            let nearby_box =
+                BoundingBox::from_block(&self.position).expand_all(f64::from(self.spawn_range));
+            let mut spawned = false;
+            for _ in 0..self.spawn_count {
+                if world.count_entities_of_type(entity_type, nearby_box).await
+                    >= usize::from(self.max_nearby_entities)
+                {
+                    self.reset_delay(world).await;
                     return;
                 }
-                let spawn_range = self.spawn_range;
-                let mut update_spawns = false;
-                for _ in 0..self.spawn_count {
-                    let pos = self.position.0;
-
-                    let spawn_pos = Vector3::new(
-                        pos.x as f64
-                            + (rand::random::<f64>() + rand::random::<f64>()) * spawn_range as f64
-                            + 0.5,
-                        (pos.y + rand::random_range(0..3) - 1) as f64,
-                        pos.z as f64
-                            + (rand::random::<f64>() + rand::random::<f64>()) * spawn_range as f64
-                            + 0.5,
-                    );
-                    // TODO: we should use getSpawnBox, but this is only modified for slimes and magma slimes
-                    if !world
-                        .is_space_empty(BoundingBox::new_from_pos(
-                            spawn_pos.x,
-                            spawn_pos.y,
-                            spawn_pos.z,
-                            &EntityDimensions {
-                                width: entity_type.dimension[0],
-                                height: entity_type.dimension[1],
-                                eye_height: entity_type.eye_height,
-                            },
-                        ))
-                        .await
-                    {
-                        continue;
-                    }
-                    world.clone().spawn_from_type(entity_type, spawn_pos).await;
-                    world
-                        .sync_world_event(WorldEvent::SpawnerSpawnsMob, self.position, 0)
-                        .await;
-                    update_spawns = true;
-                }
-                if update_spawns {
-                    self.update_spawns(world).await;
+
+                let spawn_pos = self.random_spawn_pos();
+                // TODO: we should use getSpawnBox, but this is only modified for slimes and magma slimes
+                if !world
+                    .is_space_empty(BoundingBox::new_from_pos(
+                        spawn_pos.x,
+                        spawn_pos.y,
+                        spawn_pos.z,
+                        &EntityDimensions {
+                            width: entity_type.dimension[0],
+                            height: entity_type.dimension[1],
+                            eye_height: entity_type.eye_height,
+                        },
+                    ))
+                    .await
+                {
+                    continue;
                 }
+                world.clone().spawn_from_type(entity_type, spawn_pos).await;
+                world
+                    .sync_world_event(WorldEvent::SpawnerSpawnsMob, self.position, 0)
+                    .await;
+                spawned = true;
+            }
+            if spawned {
+                self.reset_delay(world).await;
             }
         })
     }
@@ -138,29 +187,35 @@ impl BlockEntity for MobSpawnerBlockEntity {
     where
         Self: Sized,
     {
-        let delay = nbt.get_short("Delay").unwrap_or(Self::DEFAULT_DELAY as i16) as i32;
-        let min_delay = nbt
-            .get_int("MinSpawnDelay")
-            .unwrap_or(Self::DEFAULT_MIN_SPAWN_DELAY);
-        let max_delay = nbt
-            .get_int("MaxSpawnDelay")
-            .unwrap_or(Self::DEFAULT_MAX_SPAWN_DELAY);
-        let spawn_count = nbt
-            .get_int("SpawnCount")
-            .unwrap_or(Self::DEFAULT_SPAWN_COUNT);
-        let spawn_range = nbt
-            .get_int("SpawnRange")
-            .unwrap_or(Self::DEFAULT_SPAWN_RANGE);
-        let _spawn_entry = nbt.get_compound("SpawnData");
+        let get_u16 = |key: &str, default: u16| {
+            nbt.get_short(key)
+                .and_then(|value| u16::try_from(value).ok())
+                .unwrap_or(default)
+        };
+        let get_u8 = |key: &str, default: u8| {
+            nbt.get_short(key)
+                .and_then(|value| u8::try_from(value).ok())
+                .unwrap_or(default)
+        };
+        let spawn_entry = nbt
+            .get_compound("SpawnData")
+            .and_then(|spawn_data| spawn_data.get_compound("entity"))
+            .and_then(|entity| entity.get_string("id"))
+            .and_then(EntityType::from_name);
 
         Self {
             position,
-            delay: AtomicI32::new(delay),
-            max_delay,
-            min_delay,
-            spawn_count,
-            spawn_range,
-            entity_type: AtomicCell::new(None), // TODO
+            spawn_entry: AtomicCell::new(spawn_entry),
+            spawn_count: get_u8("SpawnCount", Self::DEFAULT_SPAWN_COUNT),
+            max_nearby_entities: get_u8("MaxNearbyEntities", Self::DEFAULT_MAX_NEARBY_ENTITIES),
+            required_player_range: get_u8(
+                "RequiredPlayerRange",
+                Self::DEFAULT_REQUIRED_PLAYER_RANGE,
+            ),
+            spawn_range: get_u8("SpawnRange", Self::DEFAULT_SPAWN_RANGE),
+            delay: AtomicU16::new(get_u16("Delay", Self::DEFAULT_DELAY)),
+            min_spawn_delay: get_u16("MinSpawnDelay", Self::DEFAULT_MIN_SPAWN_DELAY),
+            max_spawn_delay: get_u16("MaxSpawnDelay", Self::DEFAULT_MAX_SPAWN_DELAY),
         }
     }
 
@@ -169,27 +224,15 @@ impl BlockEntity for MobSpawnerBlockEntity {
         nbt: &'a mut NbtCompound,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
-            if let Some(entity_type) = self.entity_type.load() {
-                let mut entity_nbt = NbtCompound::new();
-                entity_nbt.put_string("id", format!("minecraft:{}", entity_type.resource_name));
-
-                nbt.put_component("entity", entity_nbt);
-            }
+            self.write_spawner_data(nbt);
         })
     }
 
     fn chunk_data_nbt(&self) -> Option<NbtCompound> {
+        // The client needs the spawn data to show the spinning mob, and the range to know when
+        // to show the particles
         let mut final_nbt = NbtCompound::new();
-        if let Some(entity_type) = self.entity_type.load() {
-            let mut spawn_entry = NbtCompound::new();
-
-            let mut entity_nbt = NbtCompound::new();
-            entity_nbt.put_string("id", format!("minecraft:{}", entity_type.resource_name));
-
-            spawn_entry.put_component("entity", entity_nbt);
-
-            final_nbt.put_component("SpawnData", spawn_entry);
-        }
+        self.write_spawner_data(&mut final_nbt);
         Some(final_nbt)
     }
 
@@ -197,3 +240,67 @@ impl BlockEntity for MobSpawnerBlockEntity {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::Ordering;
+
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_nbt::compound::NbtCompound;
+    use pumpkin_util::math::position::BlockPos;
+
+    use super::MobSpawnerBlockEntity;
+    use crate::block::entities::BlockEntity;
+
+    /// A skeleton spawner as vanilla saves it.
+    fn vanilla_nbt() -> NbtCompound {
+        let mut entity = NbtCompound::new();
+        entity.put_string("id", "minecraft:skeleton".to_string());
+        let mut spawn_data = NbtCompound::new();
+        spawn_data.put_component("entity", entity);
+
+        let mut nbt = NbtCompound::new();
+        nbt.put_short("Delay", 120);
+        nbt.put_short("MinSpawnDelay", 100);
+        nbt.put_short("MaxSpawnDelay", 400);
+        nbt.put_short("SpawnCount", 2);
+        nbt.put_short("MaxNearbyEntities", 3);
+        nbt.put_short("RequiredPlayerRange", 8);
+        nbt.put_short("SpawnRange", 5);
+        nbt.put_component("SpawnData", spawn_data);
+        nbt
+    }
+
+    #[tokio::test]
+    async fn vanilla_nbt_round_trips() {
+        let spawner = MobSpawnerBlockEntity::from_nbt(&vanilla_nbt(), BlockPos::new(1, 2, 3));
+        assert_eq!(
+            spawner.spawn_entry.load().map(|entity_type| entity_type.id),
+            Some(EntityType::SKELETON.id)
+        );
+        assert_eq!(spawner.delay.load(Ordering::Relaxed), 120);
+        assert_eq!(spawner.min_spawn_delay, 100);
+        assert_eq!(spawner.max_spawn_delay, 400);
+        assert_eq!(spawner.spawn_count, 2);
+        assert_eq!(spawner.max_nearby_entities, 3);
+        assert_eq!(spawner.required_player_range, 8);
+        assert_eq!(spawner.spawn_range, 5);
+
+        let mut written = NbtCompound::new();
+        spawner.write_nbt(&mut written).await;
+        assert_eq!(written, vanilla_nbt());
+    }
+
+    #[test]
+    fn missing_keys_use_the_vanilla_defaults() {
+        let spawner = MobSpawnerBlockEntity::from_nbt(&NbtCompound::new(), BlockPos::new(0, 0, 0));
+        assert!(spawner.spawn_entry.load().is_none());
+        assert_eq!(
+            spawner.delay.load(Ordering::Relaxed),
+            MobSpawnerBlockEntity::DEFAULT_DELAY
+        );
+        assert_eq!(spawner.spawn_count, 4);
+        assert_eq!(spawner.max_nearby_entities, 6);
+        assert_eq!(spawner.required_player_range, 16);
+    }
+}
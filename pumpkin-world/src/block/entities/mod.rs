@@ -5,6 +5,7 @@ use barrel::BarrelBlockEntity;
 use bed::BedBlockEntity;
 use chest::ChestBlockEntity;
 use comparator::ComparatorBlockEntity;
+use daylight_detector::DaylightDetectorBlockEntity;
 use end_portal::EndPortalBlockEntity;
 use furnace::FurnaceBlockEntity;
 use furnace_like_block_entity::ExperienceContainer;
@@ -20,6 +21,7 @@ use crate::block::entities::command_block::CommandBlockEntity;
 use crate::block::entities::ender_chest::EnderChestBlockEntity;
 use crate::block::entities::hopper::HopperBlockEntity;
 use crate::block::entities::jukebox::JukeboxBlockEntity;
+use crate::block::entities::lectern::LecternBlockEntity;
 use crate::block::entities::mob_spawner::MobSpawnerBlockEntity;
 use crate::block::entities::shulker_box::ShulkerBoxBlockEntity;
 use crate::block::entities::smoker::SmokerBlockEntity;
@@ -36,6 +38,7 @@ pub mod chest_like_block_entity;
 pub mod chiseled_bookshelf;
 pub mod command_block;
 pub mod comparator;
+pub mod daylight_detector;
 pub mod dropper;
 pub mod end_portal;
 pub mod ender_chest;
@@ -43,6 +46,7 @@ pub mod furnace;
 pub mod furnace_like_block_entity;
 pub mod hopper;
 pub mod jukebox;
+pub mod lectern;
 pub mod mob_spawner;
 pub mod piston;
 pub mod shulker_box;
@@ -51,7 +55,7 @@ pub mod smoker;
 pub mod trapped_chest;
 
 //TODO: We need a mark_dirty for chests
-pub trait BlockEntity: Send + Sync {
+pub trait BlockEntity: Any + Send + Sync {
     fn write_nbt<'a>(
         &'a self,
         nbt: &'a mut NbtCompound,
@@ -131,6 +135,14 @@ pub trait BlockEntity: Send + Sync {
     }
 }
 
+impl dyn BlockEntity {
+    /// Returns this block entity as a `T`, or `None` if it is a different block entity.
+    pub fn downcast_arc<T: BlockEntity>(self: Arc<Self>) -> Option<Arc<T>> {
+        let block_entity: Arc<dyn Any + Send + Sync> = self;
+        block_entity.downcast().ok()
+    }
+}
+
 #[must_use]
 pub fn block_entity_from_generic<T: BlockEntity>(nbt: &NbtCompound) -> T {
     let x = nbt.get_int("x").unwrap();
@@ -150,6 +162,7 @@ pub fn block_entity_from_nbt(nbt: &NbtCompound) -> Option<Arc<dyn BlockEntity>>
             Arc::new(block_entity_from_generic::<EnderChestBlockEntity>(nbt))
         }
         JukeboxBlockEntity::ID => Arc::new(block_entity_from_generic::<JukeboxBlockEntity>(nbt)),
+        LecternBlockEntity::ID => Arc::new(block_entity_from_generic::<LecternBlockEntity>(nbt)),
         SignBlockEntity::ID => Arc::new(block_entity_from_generic::<SignBlockEntity>(nbt)),
         BedBlockEntity::ID => Arc::new(block_entity_from_generic::<BedBlockEntity>(nbt)),
         ComparatorBlockEntity::ID => {
@@ -177,6 +190,9 @@ pub fn block_entity_from_nbt(nbt: &NbtCompound) -> Option<Arc<dyn BlockEntity>>
             Arc::new(block_entity_from_generic::<BlastingFurnaceBlockEntity>(nbt))
         }
         SmokerBlockEntity::ID => Arc::new(block_entity_from_generic::<SmokerBlockEntity>(nbt)),
+        DaylightDetectorBlockEntity::ID => Arc::new(block_entity_from_generic::<
+            DaylightDetectorBlockEntity,
+        >(nbt)),
         _ => return None,
     })
 }
@@ -6,8 +6,9 @@ use pumpkin_util::math::position::BlockPos;
 use tokio::sync::Mutex;
 
 use crate::{
-    block::viewer::ViewerCountTracker, impl_block_entity_for_chest, impl_chest_helper_methods,
-    impl_clearable_for_chest, impl_inventory_for_chest, impl_viewer_count_listener_for_chest,
+    block::{entities::chest_like_block_entity::ContainerLootTable, viewer::ViewerCountTracker},
+    impl_block_entity_for_chest, impl_chest_helper_methods, impl_clearable_for_chest,
+    impl_inventory_for_chest, impl_viewer_count_listener_for_chest,
     item::ItemStack,
 };
 
@@ -18,6 +19,8 @@ pub struct TrappedChestBlockEntity {
 
     // Viewer
     viewers: ViewerCountTracker,
+    /// The loot table the chest still has to be filled from.
+    pub loot_table: std::sync::Mutex<Option<ContainerLootTable>>,
 }
 
 impl TrappedChestBlockEntity {
@@ -11,7 +11,9 @@ use std::{
 use bytes::Bytes;
 use futures::future::join_all;
 use pumpkin_data::{Block, chunk::ChunkStatus, fluid::Fluid};
-use pumpkin_nbt::{compound::NbtCompound, from_bytes, nbt_long_array};
+use pumpkin_nbt::{
+    Nbt, compound::NbtCompound, deserializer::NbtReadHelper, from_bytes, nbt_long_array,
+};
 use rustc_hash::FxHashMap;
 use tokio::sync::Mutex;
 use tracing::debug;
@@ -24,6 +26,7 @@ use crate::{
         format::anvil::{SingleChunkDataSerializer, WORLD_DATA_VERSION},
         io::{Dirtiable, file_manager::PathFromLevelFolder},
     },
+    data::migration::{self, DataKind, PUMPKIN_DATA_VERSION},
     generation::section_coords,
     level::LevelFolder,
     tick::{ScheduledTick, scheduler::ChunkTickScheduler},
@@ -32,7 +35,7 @@ use pumpkin_util::math::vector2::Vector2;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    ChunkData, ChunkHeightmaps, ChunkLight, ChunkParsingError, ChunkSections,
+    ChunkData, ChunkHeightmaps, ChunkLight, ChunkParsingError, ChunkSections, SendGeneration,
     palette::{BiomePalette, BlockPalette},
 };
 use crate::block::BlockStateCodec;
@@ -105,11 +108,26 @@ impl Dirtiable for ChunkData {
 
 impl ChunkData {
     pub fn internal_from_bytes(
-        chunk_data: &[u8],
+        raw: &[u8],
         position: Vector2<i32>,
     ) -> Result<Self, ChunkParsingError> {
-        let chunk_data = from_bytes::<ChunkNbt>(Cursor::new(chunk_data))
+        let mut chunk_data = from_bytes::<ChunkNbt>(Cursor::new(raw))
             .map_err(|e| ChunkParsingError::ErrorDeserializingChunk(e.to_string()))?;
+        migration::check_versions(chunk_data.data_version, chunk_data.pumpkin_data_version)?;
+
+        // Migrations work on the raw NBT, so the chunk is only parsed twice if it's outdated
+        let migrated = migration::needs_migration(DataKind::Chunk, chunk_data.pumpkin_data_version);
+        if migrated {
+            let mut nbt = Nbt::read(&mut NbtReadHelper::new(Cursor::new(raw)))
+                .map_err(|e| ChunkParsingError::ErrorDeserializingChunk(e.to_string()))?;
+            migration::migrate(
+                DataKind::Chunk,
+                chunk_data.pumpkin_data_version,
+                &mut nbt.root_tag,
+            );
+            chunk_data = from_bytes::<ChunkNbt>(Cursor::new(nbt.write()))
+                .map_err(|e| ChunkParsingError::ErrorDeserializingChunk(e.to_string()))?;
+        }
 
         if chunk_data.x_pos != position.x || chunk_data.z_pos != position.y {
             return Err(ChunkParsingError::ErrorDeserializingChunk(format!(
@@ -171,8 +189,8 @@ impl ChunkData {
             heightmap: std::sync::Mutex::new(chunk_data.heightmaps),
             x: position.x,
             z: position.y,
-            // This chunk is read from disk, so it has not been modified
-            dirty: AtomicBool::new(false),
+            // This chunk is read from disk, so it only has to be saved again if it was migrated
+            dirty: AtomicBool::new(migrated),
             block_ticks: ChunkTickScheduler::from_iter(chunk_data.block_ticks),
             fluid_ticks: ChunkTickScheduler::from_iter(chunk_data.fluid_ticks),
             block_entities: {
@@ -188,6 +206,7 @@ impl ChunkData {
             light_engine: std::sync::Mutex::new(light_engine),
             light_populated: AtomicBool::new(chunk_data.light_correct),
             status: chunk_data.status,
+            send_generation: SendGeneration::new(),
         })
     }
 
@@ -251,6 +270,7 @@ impl ChunkData {
         // Build the final NBT
         let nbt = ChunkNbt {
             data_version: WORLD_DATA_VERSION,
+            pumpkin_data_version: PUMPKIN_DATA_VERSION,
             x_pos: self.x,
             z_pos: self.z,
             min_y_section: section_coords::block_to_section(self.section.min_y),
@@ -315,6 +335,9 @@ impl ChunkEntityData {
     ) -> Result<Self, ChunkParsingError> {
         let chunk_entity_data = pumpkin_nbt::from_bytes::<EntityNbt>(Cursor::new(chunk_data))
             .map_err(|e| ChunkParsingError::ErrorDeserializingChunk(e.to_string()))?;
+        let pumpkin_data_version = chunk_entity_data.pumpkin_data_version;
+        migration::check_versions(chunk_entity_data.data_version, pumpkin_data_version)?;
+        let mut migrated = false;
 
         if chunk_entity_data.position[0] != position.x
             || chunk_entity_data.position[1] != position.y
@@ -328,7 +351,8 @@ impl ChunkEntityData {
             )));
         }
         let mut map = FxHashMap::default();
-        for entity_nbt in chunk_entity_data.entities {
+        for mut entity_nbt in chunk_entity_data.entities {
+            migrated |= migration::migrate(DataKind::Entity, pumpkin_data_version, &mut entity_nbt);
             let uuid = if let Some(uuid) = entity_nbt.get_int_array("UUID") {
                 Uuid::from_u128(
                     (uuid[0] as u128) << 96
@@ -351,13 +375,14 @@ impl ChunkEntityData {
             x: position.x,
             z: position.y,
             data: Mutex::new(map),
-            dirty: AtomicBool::new(false),
+            dirty: AtomicBool::new(migrated),
         })
     }
 
     async fn internal_to_bytes(&self) -> Result<Bytes, ChunkSerializingError> {
         let nbt = EntityNbt {
             data_version: WORLD_DATA_VERSION,
+            pumpkin_data_version: PUMPKIN_DATA_VERSION,
             position: [self.x, self.z],
             entities: self.data.lock().await.values().cloned().collect(),
         };
@@ -496,6 +521,8 @@ impl Default for LightContainer {
 #[serde(rename_all = "PascalCase")]
 struct ChunkNbt {
     data_version: i32,
+    #[serde(default)]
+    pumpkin_data_version: i32,
     #[serde(rename = "xPos")]
     x_pos: i32,
     #[serde(rename = "zPos")]
@@ -520,6 +547,8 @@ struct ChunkNbt {
 #[serde(rename_all = "PascalCase")]
 struct EntityNbt {
     data_version: i32,
+    #[serde(default)]
+    pumpkin_data_version: i32,
     position: [i32; 2],
     entities: Vec<NbtCompound>,
 }
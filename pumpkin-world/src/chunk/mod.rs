@@ -1,6 +1,7 @@
 use crate::BlockStateId;
 use crate::block::entities::BlockEntity;
 use crate::chunk::format::LightContainer;
+use crate::data::migration::MigrationError;
 use crate::tick::scheduler::ChunkTickScheduler;
 use palette::{BiomePalette, BlockPalette};
 use pumpkin_data::block_properties::{blocks_movement, is_air};
@@ -15,7 +16,7 @@ use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use std::fmt::Write;
 use std::ops::{BitAnd, BitOr};
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 use thiserror::Error;
 use tokio::sync::Mutex;
@@ -47,6 +48,17 @@ pub enum ChunkReadingError {
     ParsingError(ChunkParsingError),
 }
 
+impl ChunkReadingError {
+    /// Whether the chunk was saved by a newer version. Such chunks must never be overwritten.
+    #[must_use]
+    pub const fn is_unsupported_version(&self) -> bool {
+        matches!(
+            self,
+            Self::ParsingError(ChunkParsingError::UnsupportedVersion(_))
+        )
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum ChunkWritingError {
     #[error("Io error: {0}")]
@@ -85,6 +97,45 @@ pub struct ChunkData {
     pub light_populated: AtomicBool,
     pub status: ChunkStatus,
     pub dirty: AtomicBool,
+    /// Which version of this chunk players need to have received in full
+    pub send_generation: SendGeneration,
+}
+
+static NEXT_SEND_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Identifies the contents of a chunk as they were last sent in full to players. Generations are
+/// unique across all chunks, so a reloaded chunk never matches the one it replaced.
+///
+/// Single block changes reach players through block updates and keep the generation.
+pub struct SendGeneration(AtomicU64);
+
+impl SendGeneration {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(AtomicU64::new(
+            NEXT_SEND_GENERATION.fetch_add(1, Ordering::Relaxed),
+        ))
+    }
+
+    #[must_use]
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Marks the chunk as changed in a way block updates can't describe, e.g. relighting many
+    /// sections or changing biomes, so it has to be sent in full again.
+    pub fn bump(&self) {
+        self.0.store(
+            NEXT_SEND_GENERATION.fetch_add(1, Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+    }
+}
+
+impl Default for SendGeneration {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 pub struct ChunkEntityData {
@@ -99,7 +150,7 @@ pub struct ChunkEntityData {
 
 /// Represents pure block data for a chunk.
 /// Subchunks are vertical portions of a chunk. They are 16 blocks tall.
-/// There are currently 24 subchunks per chunk.
+/// The amount of subchunks depends on the height of the dimension (24 in the vanilla overworld).
 ///
 /// A chunk can be:
 /// - Subchunks: 24 separate subchunks are stored.
@@ -175,84 +226,91 @@ pub struct ChunkHeightmaps {
 }
 
 impl ChunkHeightmaps {
-    pub fn set(&mut self, heightmap: ChunkHeightmapType, pos: BlockPos, min_y: i32) {
-        let data = match heightmap {
-            ChunkHeightmapType::WorldSurface => &mut self.world_surface,
-            ChunkHeightmapType::MotionBlocking => &mut self.motion_blocking,
-            ChunkHeightmapType::MotionBlockingNoLeaves => &mut self.motion_blocking_no_leaves,
-        };
-
-        let local_x = (pos.0.x & 15) as usize;
-        let local_z = (pos.0.z & 15) as usize;
+    /// Creates empty heightmaps for a world with the given height.
+    #[must_use]
+    pub fn new(world_height: i32) -> Self {
+        let bits = Self::bits_per_entry(world_height);
+        let values_per_long = 64 / bits as usize;
+        let len = (16 * 16usize).div_ceil(values_per_long);
+        Self {
+            motion_blocking: vec![0; len].into_boxed_slice(),
+            motion_blocking_no_leaves: vec![0; len].into_boxed_slice(),
+            world_surface: vec![0; len].into_boxed_slice(),
+        }
+    }
 
-        let adjust_height = (pos.0.y + min_y.abs()) as usize;
+    /// The amount of bits needed to store every height from `0` to `world_height` (inclusive).
+    #[must_use]
+    pub const fn bits_per_entry(world_height: i32) -> u32 {
+        u32::BITS - (world_height as u32).leading_zeros()
+    }
 
-        assert!(adjust_height <= 2 << 9);
+    /// Returns the index of the packed long and the bit shift of the given column.
+    const fn entry_position(x: i32, z: i32, bits: u32) -> (usize, u32) {
+        let local_x = (x & 15) as usize;
+        let local_z = (z & 15) as usize;
 
         //chunk column index in 16*16 chunk.
         let column_idx = local_z * 16 + local_x;
 
-        // Each height value uses 9 bits, calculate starting bit position
-        let bit_start_idx = column_idx * 9;
+        // Entries never span two longs, so the last bits of a long may stay unused.
+        let values_per_long = 64 / bits as usize;
+        let packed_array_bit_start_idx = (column_idx % values_per_long) as u32 * bits;
 
-        // Find where these 9 bits start within a 64-bit packed array element
-        // We use bit_start_index % 63, which means the last bit of i64 won't be used,
-        // but this avoids the hassle of bit concatenation.
-        let packed_array_bit_start_idx = bit_start_idx as u32 % 63;
+        (
+            column_idx / values_per_long,
+            64 - bits - packed_array_bit_start_idx,
+        )
+    }
 
-        let mask = {
-            if packed_array_bit_start_idx == 0 {
-                //0b0000_0000_0111_1111_...
-                !(0x1FF << (64 - 9))
-            } else {
-                !(0x1FF << (64 - packed_array_bit_start_idx - 9))
-            }
+    pub fn set(
+        &mut self,
+        heightmap: ChunkHeightmapType,
+        pos: BlockPos,
+        min_y: i32,
+        world_height: i32,
+    ) {
+        let data = match heightmap {
+            ChunkHeightmapType::WorldSurface => &mut self.world_surface,
+            ChunkHeightmapType::MotionBlocking => &mut self.motion_blocking,
+            ChunkHeightmapType::MotionBlockingNoLeaves => &mut self.motion_blocking_no_leaves,
         };
 
-        let height_bit_bytes = adjust_height
-            .wrapping_shl(64 - 9 - packed_array_bit_start_idx)
-            .to_ne_bytes();
-        let height = i64::from_ne_bytes(height_bit_bytes);
+        let bits = Self::bits_per_entry(world_height);
+        let adjust_height = (pos.0.y - min_y) as u64;
+        debug_assert!(adjust_height <= world_height as u64);
 
-        let packed_array_idx = column_idx / 7;
+        let (packed_array_idx, shift) = Self::entry_position(pos.0.x, pos.0.z, bits);
+        let entry_mask = (1u64 << bits) - 1;
+        let mask = !(entry_mask << shift) as i64;
+        let height = ((adjust_height & entry_mask) << shift) as i64;
 
         data[packed_array_idx] = data[packed_array_idx].bitand(mask).bitor(height);
     }
 
     #[must_use]
-    pub fn get(&self, heightmap: ChunkHeightmapType, x: i32, z: i32, min_y: i32) -> i32 {
-        let local_x = (x & 15) as usize;
-        let local_z = (z & 15) as usize;
-
-        let column_idx = local_z * 16 + local_x;
-        let bit_start_idx = column_idx * 9;
-
-        let packed_array_bit_start_idx = bit_start_idx as u32 % 63;
-
-        let mask = {
-            if packed_array_bit_start_idx == 0 {
-                //0b1111_1111_1000_0000_...
-                0x1ff << (64 - 9)
-            } else {
-                0x1ff << (64 - packed_array_bit_start_idx - 9)
-            }
-        };
-
-        let packed_array_idx = column_idx / 7;
-
+    pub fn get(
+        &self,
+        heightmap: ChunkHeightmapType,
+        x: i32,
+        z: i32,
+        min_y: i32,
+        world_height: i32,
+    ) -> i32 {
         let data = match heightmap {
             ChunkHeightmapType::WorldSurface => &self.world_surface,
             ChunkHeightmapType::MotionBlocking => &self.motion_blocking,
             ChunkHeightmapType::MotionBlockingNoLeaves => &self.motion_blocking_no_leaves,
         };
-        let height_bit_bytes_i64 = data[packed_array_idx].bitand(mask).to_ne_bytes();
 
-        (u64::from_ne_bytes(height_bit_bytes_i64)
-            .wrapping_shr(64 - (packed_array_bit_start_idx + 9)) as i32)
-            - min_y.abs()
+        let bits = Self::bits_per_entry(world_height);
+        let (packed_array_idx, shift) = Self::entry_position(x, z, bits);
+        let entry_mask = (1u64 << bits) - 1;
+
+        ((data[packed_array_idx] as u64 >> shift) & entry_mask) as i32 + min_y
     }
 
-    pub fn log_heightmap(&self, _type: ChunkHeightmapType, min_y: i32) {
+    pub fn log_heightmap(&self, _type: ChunkHeightmapType, min_y: i32, world_height: i32) {
         let mut header = "Z/X".to_string();
         for x in 0..16 {
             let _ = write!(header, "{x:4}");
@@ -263,7 +321,7 @@ impl ChunkHeightmaps {
                 let mut row = format!("{z:3}");
                 row.push_str(
                     &(0..16)
-                        .map(|x| format!("{:4}", self.get(_type, x, z, min_y)))
+                        .map(|x| format!("{:4}", self.get(_type, x, z, min_y, world_height)))
                         .collect::<String>(),
                 );
                 row
@@ -275,16 +333,12 @@ impl ChunkHeightmaps {
     }
 }
 
-/// The Heightmap for a completely empty chunk
+/// The Heightmap for a completely empty chunk of vanilla overworld height
 impl Default for ChunkHeightmaps {
     fn default() -> Self {
-        Self {
-            // 9 bits per entry
-            // 0 packed into an i64 7 times.
-            motion_blocking: vec![0; 37].into_boxed_slice(),
-            motion_blocking_no_leaves: vec![0; 37].into_boxed_slice(),
-            world_surface: vec![0; 37].into_boxed_slice(),
-        }
+        // 9 bits per entry
+        // 0 packed into an i64 7 times.
+        Self::new(384)
     }
 }
 
@@ -302,6 +356,12 @@ impl ChunkSections {
         }
     }
 
+    /// The height of the chunk in blocks
+    #[must_use]
+    pub const fn height(&self) -> i32 {
+        (self.count * BlockPalette::SIZE) as i32
+    }
+
     #[must_use]
     pub fn get_block_absolute_y(
         &self,
@@ -508,10 +568,34 @@ impl ChunkData {
             .set_relative_block(relative_x, relative_y, relative_z, block_state_id);
     }
 
+    /// Gets the height of the given heightmap at the given column, using the chunk's own height range.
+    #[must_use]
+    pub fn get_height(&self, heightmap: ChunkHeightmapType, x: i32, z: i32) -> i32 {
+        self.heightmap.lock().unwrap().get(
+            heightmap,
+            x,
+            z,
+            self.section.min_y,
+            self.section.height(),
+        )
+    }
+
+    /// Copies every column of the given heightmap, indexed by `z * 16 + x`.
+    /// The heightmap lock is only held while copying.
+    #[must_use]
+    pub fn heightmap_snapshot(&self, heightmap: ChunkHeightmapType) -> Vec<i16> {
+        let min_y = self.section.min_y;
+        let height = self.section.height();
+        let heightmaps = self.heightmap.lock().unwrap();
+        (0..16 * 16)
+            .map(|column| heightmaps.get(heightmap, column & 15, column >> 4, min_y, height) as i16)
+            .collect()
+    }
+
     //TODO: Tracking heightmaps update.
     pub fn calculate_heightmap(&self) -> ChunkHeightmaps {
         let highest_non_empty_subchunk = self.get_highest_non_empty_subchunk();
-        let mut heightmaps = ChunkHeightmaps::default();
+        let mut heightmaps = ChunkHeightmaps::new(self.section.height());
 
         for x in 0..16 {
             for z in 0..16 {
@@ -520,9 +604,9 @@ impl ChunkData {
         }
 
         // log::info!("WorldSurface:");
-        // heightmaps.log_heightmap(ChunkHeightmapType::WorldSurface, self.section.min_y);
+        // heightmaps.log_heightmap(ChunkHeightmapType::WorldSurface, self.section.min_y, self.section.height());
         // log::info!("MotionBlocking:");
-        // heightmaps.log_heightmap(ChunkHeightmapType::MotionBlocking, self.section.min_y);
+        // heightmaps.log_heightmap(ChunkHeightmapType::MotionBlocking, self.section.min_y, self.section.height());
         // log::info!("min_y: {}", self.section.min_y);
         heightmaps
     }
@@ -535,7 +619,9 @@ impl ChunkData {
         x: usize,
         z: usize,
     ) {
-        let start_height = (start_sub_chunk as i32) * 16 - self.section.min_y.abs() + 15;
+        let start_height = self.section.min_y + (start_sub_chunk as i32) * 16 + 15;
+        let min_y = self.section.min_y;
+        let world_height = self.section.height();
         let mut has_found = [false, false, false];
 
         for y in (self.section.min_y..=start_height).rev() {
@@ -545,7 +631,7 @@ impl ChunkData {
             let block = Block::get_raw_id_from_state_id(state_id);
 
             if !block_state.is_air() && !has_found[ChunkHeightmapType::WorldSurface as usize] {
-                heightmaps.set(ChunkHeightmapType::WorldSurface, pos, self.section.min_y);
+                heightmaps.set(ChunkHeightmapType::WorldSurface, pos, min_y, world_height);
                 has_found[ChunkHeightmapType::WorldSurface as usize] = true;
             }
 
@@ -553,7 +639,7 @@ impl ChunkData {
                 || Fluid::from_id(block).is_some_and(|fluid| !fluid.states.is_empty());
 
             if !has_found[ChunkHeightmapType::MotionBlocking as usize] && is_motion_blocking {
-                heightmaps.set(ChunkHeightmapType::MotionBlocking, pos, self.section.min_y);
+                heightmaps.set(ChunkHeightmapType::MotionBlocking, pos, min_y, world_height);
                 has_found[ChunkHeightmapType::MotionBlocking as usize] = true;
             }
 
@@ -564,7 +650,8 @@ impl ChunkData {
                 heightmaps.set(
                     ChunkHeightmapType::MotionBlockingNoLeaves,
                     pos,
-                    self.section.min_y,
+                    min_y,
+                    world_height,
                 );
                 has_found[ChunkHeightmapType::MotionBlockingNoLeaves as usize] = true;
             }
@@ -577,7 +664,7 @@ impl ChunkData {
         let pos = BlockPos::new(x as i32, self.section.min_y, z as i32);
         for (idx, is_set) in has_found.iter().enumerate() {
             if !(*is_set) {
-                heightmaps.set(idx.try_into().unwrap(), pos, self.section.min_y);
+                heightmaps.set(idx.try_into().unwrap(), pos, min_y, world_height);
             }
         }
     }
@@ -604,6 +691,8 @@ pub enum ChunkParsingError {
     ChunkNotGenerated,
     #[error("Error deserializing chunk: {0}")]
     ErrorDeserializingChunk(String),
+    #[error("{0}")]
+    UnsupportedVersion(#[from] MigrationError),
 }
 
 #[derive(Error, Debug)]
@@ -611,3 +700,235 @@ pub enum ChunkSerializingError {
     #[error("Error serializing chunk: {0}")]
     ErrorSerializingChunk(pumpkin_nbt::Error),
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use pumpkin_data::Block;
+    use pumpkin_data::chunk::ChunkStatus;
+
+    use std::io::Cursor;
+
+    use bytes::Bytes;
+    use pumpkin_nbt::{Nbt, compound::NbtCompound, deserializer::NbtReadHelper, tag::NbtTag};
+    use pumpkin_util::math::vector2::Vector2;
+    use rustc_hash::FxHashMap;
+    use tokio::sync::Mutex;
+    use uuid::Uuid;
+
+    use super::{
+        ChunkData, ChunkEntityData, ChunkHeightmapType, ChunkHeightmaps, ChunkLight, ChunkSections,
+        SendGeneration,
+    };
+    use crate::chunk::format::LightContainer;
+    use crate::chunk::format::anvil::SingleChunkDataSerializer;
+    use crate::chunk::io::Dirtiable;
+    use crate::data::migration::PUMPKIN_DATA_VERSION_KEY;
+    use crate::world_info::{
+        MAXIMUM_SUPPORTED_WORLD_DATA_VERSION, MINIMUM_SUPPORTED_WORLD_DATA_VERSION,
+    };
+
+    const MIN_Y: i32 = -128;
+    const HEIGHT: i32 = 512;
+    const TOP_Y: i32 = MIN_Y + HEIGHT - 1;
+
+    fn tall_chunk() -> ChunkData {
+        let section_count = (HEIGHT / 16) as usize;
+        ChunkData {
+            section: ChunkSections::new(section_count, MIN_Y),
+            heightmap: std::sync::Mutex::new(ChunkHeightmaps::new(HEIGHT)),
+            x: 0,
+            z: 0,
+            block_ticks: Default::default(),
+            fluid_ticks: Default::default(),
+            block_entities: Default::default(),
+            light_engine: std::sync::Mutex::new(ChunkLight {
+                sky_light: vec![LightContainer::new_empty(15); section_count].into_boxed_slice(),
+                block_light: vec![LightContainer::new_empty(0); section_count].into_boxed_slice(),
+            }),
+            light_populated: AtomicBool::new(false),
+            status: ChunkStatus::Full,
+            dirty: AtomicBool::new(false),
+            send_generation: SendGeneration::new(),
+        }
+    }
+
+    #[test]
+    fn tall_dimension_block_access() {
+        let chunk = tall_chunk();
+        let stone = Block::STONE.default_state.id;
+
+        assert_eq!(chunk.section.height(), HEIGHT);
+
+        chunk.section.set_block_absolute_y(0, MIN_Y, 0, stone);
+        chunk.section.set_block_absolute_y(15, TOP_Y, 15, stone);
+
+        assert_eq!(chunk.section.get_block_absolute_y(0, MIN_Y, 0), Some(stone));
+        assert_eq!(
+            chunk.section.get_block_absolute_y(15, TOP_Y, 15),
+            Some(stone)
+        );
+        assert_eq!(chunk.section.get_block_absolute_y(0, MIN_Y - 1, 0), None);
+        assert_eq!(chunk.section.get_block_absolute_y(0, TOP_Y + 1, 0), None);
+    }
+
+    #[test]
+    fn tall_dimension_heightmap() {
+        let chunk = tall_chunk();
+        let stone = Block::STONE.default_state.id;
+
+        chunk.section.set_block_absolute_y(3, TOP_Y, 7, stone);
+        chunk.section.set_block_absolute_y(8, 200, 8, stone);
+        *chunk.heightmap.lock().unwrap() = chunk.calculate_heightmap();
+
+        assert_eq!(
+            chunk.get_height(ChunkHeightmapType::WorldSurface, 3, 7),
+            TOP_Y
+        );
+        assert_eq!(
+            chunk.get_height(ChunkHeightmapType::MotionBlocking, 8, 8),
+            200
+        );
+        assert_eq!(
+            chunk.get_height(ChunkHeightmapType::WorldSurface, 15, 15),
+            MIN_Y
+        );
+    }
+
+    #[test]
+    fn heightmap_snapshot_columns() {
+        let chunk = tall_chunk();
+        let stone = Block::STONE.default_state.id;
+
+        chunk.section.set_block_absolute_y(3, 100, 7, stone);
+        *chunk.heightmap.lock().unwrap() = chunk.calculate_heightmap();
+
+        let snapshot = chunk.heightmap_snapshot(ChunkHeightmapType::WorldSurface);
+        assert_eq!(snapshot.len(), 256);
+        assert_eq!(
+            i32::from(snapshot[7 * 16 + 3]),
+            chunk.get_height(ChunkHeightmapType::WorldSurface, 3, 7)
+        );
+        assert_eq!(i32::from(snapshot[0]), MIN_Y);
+    }
+
+    #[test]
+    fn heightmap_bits_follow_world_height() {
+        assert_eq!(ChunkHeightmaps::bits_per_entry(384), 9);
+        assert_eq!(ChunkHeightmaps::bits_per_entry(512), 10);
+        // 9 bits per entry, 7 entries per long
+        assert_eq!(ChunkHeightmaps::default().world_surface.len(), 37);
+        // 10 bits per entry, 6 entries per long
+        assert_eq!(ChunkHeightmaps::new(512).world_surface.len(), 43);
+    }
+
+    #[test]
+    fn send_generation_changes_on_bump() {
+        let chunk = tall_chunk();
+        let other = SendGeneration::new();
+        let generation = chunk.send_generation.get();
+        assert_ne!(generation, other.get());
+
+        chunk.send_generation.bump();
+        assert_ne!(chunk.send_generation.get(), generation);
+        assert_ne!(chunk.send_generation.get(), other.get());
+    }
+
+    /// Edits the NBT of saved chunk data, to make fixtures of data saved by other versions.
+    fn patch_nbt(bytes: &[u8], patch: impl FnOnce(&mut NbtCompound)) -> Bytes {
+        let mut nbt = Nbt::read(&mut NbtReadHelper::new(Cursor::new(bytes))).unwrap();
+        patch(&mut nbt.root_tag);
+        nbt.write()
+    }
+
+    fn set_int(nbt: &mut NbtCompound, key: &str, value: i32) {
+        nbt.child_tags.retain(|(name, _)| name != key);
+        nbt.put_int(key, value);
+    }
+
+    fn leashed_entity_chunk() -> ChunkEntityData {
+        let uuid = Uuid::from_u128(0x0001_0002_0003_0004);
+        let mut leash = NbtCompound::new();
+        leash.put("UUID", NbtTag::IntArray(vec![5, 6, 7, 8]));
+        let mut entity = NbtCompound::new();
+        entity.put_string("id", "minecraft:cow".to_string());
+        entity.put("UUID", NbtTag::IntArray(vec![0, 1, 2, 3]));
+        entity.put_component("leash", leash);
+
+        let mut entities = FxHashMap::default();
+        entities.insert(uuid, entity);
+        ChunkEntityData {
+            x: 0,
+            z: 0,
+            data: Mutex::new(entities),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    #[tokio::test]
+    async fn outdated_entity_chunk_is_migrated() {
+        let saved = leashed_entity_chunk().to_bytes().await.unwrap();
+        // Before versioning, Pumpkin didn't write a data version of its own and used `Leash`
+        let fixture = patch_nbt(&saved, |root| {
+            root.child_tags
+                .retain(|(name, _)| name != PUMPKIN_DATA_VERSION_KEY);
+            for (name, tag) in &mut root.child_tags {
+                if let ("Entities", NbtTag::List(entities)) = (name.as_str(), tag)
+                    && let Some(NbtTag::Compound(entity)) = entities.first_mut()
+                    && let Some((key, _)) =
+                        entity.child_tags.iter_mut().find(|(key, _)| key == "leash")
+                {
+                    *key = "Leash".to_string();
+                }
+            }
+        });
+
+        let loaded = ChunkEntityData::from_bytes(&fixture, Vector2::new(0, 0)).unwrap();
+        // The upgraded chunk is written back with the next save
+        assert!(loaded.is_dirty());
+        let entities = loaded.data.lock().await;
+        let entity = entities.values().next().unwrap();
+        assert!(entity.get("Leash").is_none());
+        assert!(entity.get_compound("leash").is_some());
+        drop(entities);
+
+        let resaved = loaded.to_bytes().await.unwrap();
+        let reloaded = ChunkEntityData::from_bytes(&resaved, Vector2::new(0, 0)).unwrap();
+        assert!(!reloaded.is_dirty());
+    }
+
+    #[tokio::test]
+    async fn newer_chunks_are_refused() {
+        let saved = tall_chunk().to_bytes().await.unwrap();
+        let loaded = ChunkData::from_bytes(&saved, Vector2::new(0, 0)).unwrap();
+        assert!(!loaded.is_dirty());
+
+        let newer_vanilla = patch_nbt(&saved, |root| {
+            set_int(
+                root,
+                "DataVersion",
+                MAXIMUM_SUPPORTED_WORLD_DATA_VERSION + 1,
+            );
+        });
+        let newer_pumpkin = patch_nbt(&saved, |root| {
+            set_int(root, PUMPKIN_DATA_VERSION_KEY, i32::MAX);
+        });
+        for fixture in [newer_vanilla, newer_pumpkin] {
+            let Err(error) = ChunkData::from_bytes(&fixture, Vector2::new(0, 0)) else {
+                panic!("Newer chunks must not be loaded");
+            };
+            assert!(error.is_unsupported_version());
+        }
+
+        // Chunks of older Minecraft versions are still loaded
+        let older_vanilla = patch_nbt(&saved, |root| {
+            set_int(
+                root,
+                "DataVersion",
+                MINIMUM_SUPPORTED_WORLD_DATA_VERSION - 1,
+            );
+        });
+        assert!(ChunkData::from_bytes(&older_vanilla, Vector2::new(0, 0)).is_ok());
+    }
+}
@@ -1,12 +1,21 @@
 use super::ChunkPos;
 use crate::level::SyncChunk;
 use crossbeam::channel::{Receiver, Sender};
+use std::collections::HashSet;
 use std::sync::Mutex;
 use tokio::sync::oneshot;
 
 pub struct ChunkListener {
     single: Mutex<Vec<(ChunkPos, oneshot::Sender<SyncChunk>)>>,
     global: Mutex<Vec<Sender<(ChunkPos, SyncChunk)>>>,
+    /// Only gets the position, so listeners never keep an unloading chunk alive.
+    unloaded: Mutex<Vec<Sender<ChunkPos>>>,
+    /// If set, newly loaded chunks go here instead of to the global listeners.
+    load_gate: Mutex<Option<Sender<(ChunkPos, SyncChunk)>>>,
+    /// Loaded chunks held back at the load gate and not announced (yet).
+    held: Mutex<HashSet<ChunkPos>>,
+    /// Held chunks that will not be announced before they unload.
+    withheld: Mutex<HashSet<ChunkPos>>,
 }
 impl Default for ChunkListener {
     fn default() -> Self {
@@ -19,6 +28,10 @@ impl ChunkListener {
         Self {
             single: Mutex::new(Vec::new()),
             global: Mutex::new(Vec::new()),
+            unloaded: Mutex::new(Vec::new()),
+            load_gate: Mutex::new(None),
+            held: Mutex::new(HashSet::new()),
+            withheld: Mutex::new(HashSet::new()),
         }
     }
     pub fn add_single_chunk_listener(&self, pos: ChunkPos) -> oneshot::Receiver<SyncChunk> {
@@ -31,44 +44,105 @@ impl ChunkListener {
         self.global.lock().unwrap().push(tx);
         rx
     }
+    pub fn add_unload_listener(&self) -> Receiver<ChunkPos> {
+        let (tx, rx) = crossbeam::channel::unbounded();
+        self.unloaded.lock().unwrap().push(tx);
+        rx
+    }
+    /// Holds newly loaded chunks back from the global listeners. They are handed to the returned
+    /// receiver instead, and stay held until its owner announces them with
+    /// [`Self::announce_chunk`], or withholds them with [`Self::withhold_chunk`] until they
+    /// unload. Single listeners are not affected.
+    pub fn set_load_gate(&self) -> Receiver<(ChunkPos, SyncChunk)> {
+        let (tx, rx) = crossbeam::channel::unbounded();
+        *self.load_gate.lock().unwrap() = Some(tx);
+        rx
+    }
+    /// Whether a loaded chunk is held back at the load gate, so it must not be used as if loaded.
+    #[must_use]
+    pub fn is_chunk_held(&self, pos: &ChunkPos) -> bool {
+        self.held.lock().unwrap().contains(pos)
+    }
+    /// Keeps a held chunk from being announced until it unloads.
+    pub fn withhold_chunk(&self, pos: ChunkPos) {
+        self.withheld.lock().unwrap().insert(pos);
+    }
+    /// Whether a loaded chunk was withheld, rather than still waiting at the load gate.
+    #[must_use]
+    pub fn is_chunk_withheld(&self, pos: &ChunkPos) -> bool {
+        self.withheld.lock().unwrap().contains(pos)
+    }
+    pub fn process_unloaded_chunk(&self, pos: ChunkPos) {
+        self.held.lock().unwrap().remove(&pos);
+        self.withheld.lock().unwrap().remove(&pos);
+        self.unloaded
+            .lock()
+            .unwrap()
+            .retain(|sender| sender.send(pos).is_ok());
+    }
+    /// Notifies the listeners about a chunk that just became available. With a load gate set,
+    /// the global listeners only get it once it is announced.
     pub fn process_new_chunk(&self, pos: ChunkPos, chunk: &SyncChunk) {
+        self.notify_single(pos, chunk);
         {
-            let mut single = self.single.lock().unwrap();
-            let mut i = 0;
-            let mut len = single.len();
-            while i < len {
-                if single[i].0 == pos {
-                    let (_, send) = single.remove(i);
-                    let _ = send.send(chunk.clone());
-                    // log::debug!("single listener {i} send {pos:?}");
-                    len -= 1;
-                    continue;
-                }
-                if single[i].1.is_closed() {
-                    // let listener_pos = single[i].0;
-                    single.remove(i);
-                    // log::debug!("single listener dropped {listener_pos:?}");
-                    len -= 1;
-                    continue;
+            let mut gate = self.load_gate.lock().unwrap();
+            if let Some(sender) = gate.as_ref() {
+                self.held.lock().unwrap().insert(pos);
+                if sender.send((pos, chunk.clone())).is_ok() {
+                    return;
                 }
-                i += 1;
+                // The gate's owner is gone
+                *gate = None;
             }
         }
-        {
-            let mut global = self.global.lock().unwrap();
-            let mut i = 0;
-            let mut len = global.len();
-            while i < len {
-                if matches!(global[i].send((pos, chunk.clone())), Ok(())) {
-                    // log::debug!("global listener {i} send {pos:?}");
-                } else {
-                    // log::debug!("one global listener dropped");
-                    global.remove(i);
-                    len -= 1;
-                    continue;
-                }
-                i += 1;
+        self.announce_chunk(pos, chunk);
+    }
+    /// Notifies the listeners about an already loaded chunk that changed, unless it is held.
+    pub fn process_changed_chunk(&self, pos: ChunkPos, chunk: &SyncChunk) {
+        self.notify_single(pos, chunk);
+        if !self.is_chunk_held(&pos) {
+            self.announce_chunk(pos, chunk);
+        }
+    }
+    /// Hands a loaded chunk to the global listeners.
+    pub fn announce_chunk(&self, pos: ChunkPos, chunk: &SyncChunk) {
+        self.held.lock().unwrap().remove(&pos);
+        self.withheld.lock().unwrap().remove(&pos);
+        let mut global = self.global.lock().unwrap();
+        let mut i = 0;
+        let mut len = global.len();
+        while i < len {
+            if matches!(global[i].send((pos, chunk.clone())), Ok(())) {
+                // log::debug!("global listener {i} send {pos:?}");
+            } else {
+                // log::debug!("one global listener dropped");
+                global.remove(i);
+                len -= 1;
+                continue;
+            }
+            i += 1;
+        }
+    }
+    fn notify_single(&self, pos: ChunkPos, chunk: &SyncChunk) {
+        let mut single = self.single.lock().unwrap();
+        let mut i = 0;
+        let mut len = single.len();
+        while i < len {
+            if single[i].0 == pos {
+                let (_, send) = single.remove(i);
+                let _ = send.send(chunk.clone());
+                // log::debug!("single listener {i} send {pos:?}");
+                len -= 1;
+                continue;
+            }
+            if single[i].1.is_closed() {
+                // let listener_pos = single[i].0;
+                single.remove(i);
+                // log::debug!("single listener dropped {listener_pos:?}");
+                len -= 1;
+                continue;
             }
+            i += 1;
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::chunk::{ChunkData, ChunkLight, ChunkSections};
+use crate::chunk::{ChunkData, ChunkLight, ChunkSections, SendGeneration};
 use crate::generation::biome_coords;
 use pumpkin_config::lighting::LightingEngineConfig;
 use pumpkin_data::dimension::Dimension;
@@ -195,6 +195,7 @@ impl Chunk {
                 light_populated: AtomicBool::new(false),
                 status: ChunkStatus::Empty,
                 dirty: AtomicBool::new(false),
+                send_generation: SendGeneration::new(),
             })),
         ) {
             Chunk::Proto(proto) => proto,
@@ -271,8 +272,9 @@ impl Chunk {
             dirty: AtomicBool::new(true),
             block_ticks: Default::default(),
             fluid_ticks: Default::default(),
-            block_entities: Default::default(),
+            block_entities: Mutex::new(proto_chunk.block_entities),
             status: proto_chunk.stage.into(),
+            send_generation: SendGeneration::new(),
         };
 
         chunk.heightmap = Mutex::new(chunk.calculate_heightmap());
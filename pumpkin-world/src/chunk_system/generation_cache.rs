@@ -1,5 +1,6 @@
 use super::chunk_state::{Chunk, StagedChunkEnum};
 use crate::block::RawBlockState;
+use crate::block::entities::BlockEntity;
 use crate::chunk::ChunkHeightmapType;
 use crate::generation::height_limit::HeightLimitView;
 use crate::generation::proto_chunk::{GenerationCache, TerrainCache};
@@ -17,6 +18,7 @@ use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use tracing::debug;
 
 pub struct Cache {
@@ -198,6 +200,29 @@ impl GenerationCache for Cache {
             }
         }
     }
+    fn add_block_entity(&mut self, block_entity: Arc<dyn BlockEntity>) {
+        let pos = block_entity.get_position();
+        let dx = (pos.0.x >> 4) - self.x;
+        let dz = (pos.0.z >> 4) - self.z;
+        if !(dx < self.size && dz < self.size && dx >= 0 && dz >= 0) {
+            debug!(
+                "illegal add_block_entity {pos:?} cache pos ({}, {}) size {}",
+                self.x, self.z, self.size
+            );
+            return;
+        }
+        match &mut self.chunks[(dx * self.size + dz) as usize] {
+            Chunk::Level(data) => {
+                data.block_entities
+                    .lock()
+                    .unwrap()
+                    .insert(pos, block_entity);
+            }
+            Chunk::Proto(data) => {
+                data.block_entities.insert(pos, block_entity);
+            }
+        }
+    }
 
     fn get_top_y(&self, heightmap: &HeightMap, x: i32, z: i32) -> i32 {
         match heightmap {
@@ -218,12 +243,7 @@ impl GenerationCache for Cache {
         debug_assert!(dx < self.size && dy < self.size);
         debug_assert!(dx >= 0 && dy >= 0);
         match &self.chunks[(dx * self.size + dy) as usize] {
-            Chunk::Level(data) => {
-                let heightmap = data.heightmap.lock().unwrap();
-                let min_y = data.section.min_y;
-
-                heightmap.get(ChunkHeightmapType::MotionBlocking, x, z, min_y)
-            }
+            Chunk::Level(data) => data.get_height(ChunkHeightmapType::MotionBlocking, x, z),
             Chunk::Proto(data) => data.top_motion_blocking_block_height_exclusive(x, z),
         }
     }
@@ -234,11 +254,7 @@ impl GenerationCache for Cache {
         debug_assert!(dx < self.size && dy < self.size);
         debug_assert!(dx >= 0 && dy >= 0);
         match &self.chunks[(dx * self.size + dy) as usize] {
-            Chunk::Level(data) => {
-                let heightmap = data.heightmap.lock().unwrap();
-                let min_y = data.section.min_y;
-                heightmap.get(ChunkHeightmapType::MotionBlockingNoLeaves, x, z, min_y)
-            }
+            Chunk::Level(data) => data.get_height(ChunkHeightmapType::MotionBlockingNoLeaves, x, z),
             Chunk::Proto(data) => data.top_motion_blocking_block_no_leaves_height_exclusive(x, z),
         }
     }
@@ -250,9 +266,7 @@ impl GenerationCache for Cache {
         debug_assert!(dx >= 0 && dy >= 0);
         match &self.chunks[(dx * self.size + dy) as usize] {
             Chunk::Level(data) => {
-                let heightmap = data.heightmap.lock().unwrap();
-                let min_y = data.section.min_y;
-                heightmap.get(ChunkHeightmapType::WorldSurface, x, z, min_y) // can we return this?
+                data.get_height(ChunkHeightmapType::WorldSurface, x, z) // can we return this?
             }
             Chunk::Proto(data) => data.top_block_height_exclusive(x, z),
         }
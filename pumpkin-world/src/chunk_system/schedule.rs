@@ -17,16 +17,35 @@ use slotmap::Key;
 use std::cmp::{Ordering, max};
 use std::collections::{BinaryHeap, HashMap};
 use std::mem::swap;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::Relaxed;
 use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use std::time::Duration;
 use tracing::{debug, error, info, trace, warn};
 
-struct TaskHeapNode(i8, NodeKey);
+/// How many tasks have to be queued after a task for it to gain one priority level. Without
+/// aging, a steady stream of close chunks could keep far ones waiting forever.
+const TASK_AGING_INTERVAL: i64 = 256;
+
+/// A queued task with its priority, lower runs first, and the order it was queued in.
+struct TaskHeapNode(i8, NodeKey, i64);
+impl TaskHeapNode {
+    fn new(priority: i8, node: NodeKey, next_seq: &mut i64) -> Self {
+        let seq = *next_seq;
+        *next_seq += 1;
+        Self(priority, node, seq)
+    }
+
+    /// The priority lowered by one level for every [`TASK_AGING_INTERVAL`] tasks queued after
+    /// this one.
+    fn aged_priority(&self) -> i64 {
+        i64::from(self.0) * TASK_AGING_INTERVAL + self.2
+    }
+}
 impl PartialEq for TaskHeapNode {
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+        self.aged_priority() == other.aged_priority()
     }
 }
 impl Eq for TaskHeapNode {}
@@ -37,12 +56,34 @@ impl PartialOrd for TaskHeapNode {
 }
 impl Ord for TaskHeapNode {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.0.cmp(&other.0).reverse()
+        self.aged_priority().cmp(&other.aged_priority()).reverse()
+    }
+}
+
+/// The state of a level's generation queue, for metrics and debugging.
+#[derive(Default)]
+pub struct GenerationStats {
+    queued: AtomicUsize,
+    in_flight: AtomicUsize,
+}
+
+impl GenerationStats {
+    /// The number of tasks waiting for a generation or IO thread.
+    #[must_use]
+    pub fn queue_depth(&self) -> usize {
+        self.queued.load(Relaxed)
+    }
+
+    /// The number of tasks currently being worked on by a generation or IO thread.
+    #[must_use]
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Relaxed)
     }
 }
 
 pub struct GenerationSchedule {
     queue: BinaryHeap<TaskHeapNode>,
+    next_task_seq: i64,
     graph: DAG,
 
     last_level: ChunkLevel,
@@ -55,6 +96,7 @@ pub struct GenerationSchedule {
 
     io_lock: IOLock,
     running_task_count: u16,
+    stats: Arc<GenerationStats>,
     recv_chunk: crossfire::compat::MRx<(ChunkPos, RecvChunk)>,
     io_read: crossfire::compat::MTx<ChunkPos>,
     io_write: crossfire::compat::Tx<Vec<(ChunkPos, Chunk)>>,
@@ -124,6 +166,7 @@ impl GenerationSchedule {
             .spawn(move || {
                 let scheduler = Self {
                     queue: BinaryHeap::new(),
+                    next_task_seq: 0,
                     graph: DAG::default(),
                     last_level: ChunkLevel::default(),
                     last_high_priority: Vec::new(),
@@ -132,6 +175,7 @@ impl GenerationSchedule {
                     unload_chunks: HashSetType::default(),
                     io_lock,
                     running_task_count: 0,
+                    stats: level_sched.generation_stats.clone(),
                     recv_chunk,
                     io_read: send_read_io,
                     io_write: send_write_io,
@@ -198,6 +242,13 @@ impl GenerationSchedule {
         *last_level.get(&pos).unwrap_or(&ChunkLoading::MAX_LEVEL) + (stage as i8)
     }
 
+    fn publish_stats(&self) {
+        self.stats.queued.store(self.queue.len(), Relaxed);
+        self.stats
+            .in_flight
+            .store(usize::from(self.running_task_count), Relaxed);
+    }
+
     fn sort_queue(&mut self) {
         let mut new_queue = BinaryHeap::with_capacity(self.queue.len());
         for i in &self.queue {
@@ -210,6 +261,7 @@ impl GenerationSchedule {
                         node.stage,
                     ),
                     i.1,
+                    i.2,
                 ));
             }
         }
@@ -303,7 +355,7 @@ impl GenerationSchedule {
                                         // Ensure the implicitly created task is queued
                                         let node = self.graph.nodes.get_mut(*ano_task).unwrap();
                                         node.in_queue = true;
-                                        self.queue.push(TaskHeapNode(
+                                        self.queue.push(TaskHeapNode::new(
                                             Self::calc_priority(
                                                 &self.last_level,
                                                 &self.last_high_priority,
@@ -311,6 +363,7 @@ impl GenerationSchedule {
                                                 req_stage,
                                             ),
                                             *ano_task,
+                                            &mut self.next_task_seq,
                                         ));
                                     }
                                     self.graph.add_edge(*ano_task, task); // task depend on ano_task
@@ -338,7 +391,7 @@ impl GenerationSchedule {
                                     // Ensure the implicitly created task is queued
                                     let node = self.graph.nodes.get_mut(*ano_task).unwrap();
                                     node.in_queue = true;
-                                    self.queue.push(TaskHeapNode(
+                                    self.queue.push(TaskHeapNode::new(
                                         Self::calc_priority(
                                             &self.last_level,
                                             &self.last_high_priority,
@@ -346,6 +399,7 @@ impl GenerationSchedule {
                                             req_stage,
                                         ),
                                         *ano_task,
+                                        &mut self.next_task_seq,
                                     ));
                                 }
                                 self.graph.add_edge(*ano_task, task); // task depend on ano_task
@@ -355,7 +409,8 @@ impl GenerationSchedule {
                     let node = self.graph.nodes.get_mut(task).unwrap();
                     if node.in_degree == 0 {
                         node.in_queue = true;
-                        self.queue.push(TaskHeapNode(0, task));
+                        self.queue
+                            .push(TaskHeapNode::new(0, task, &mut self.next_task_seq));
                     }
                 }
             }
@@ -384,6 +439,7 @@ impl GenerationSchedule {
                         if holder.public {
                             self.public_chunk_map.remove(&pos);
                             holder.public = false;
+                            self.listener.process_unloaded_chunk(pos);
                         }
                         let sc = Arc::strong_count(&chunk);
                         if sc == 1 {
@@ -477,7 +533,7 @@ impl GenerationSchedule {
                 debug_assert!(node.in_degree >= 1);
                 node.in_degree -= 1;
                 if node.in_degree == 0 && !node.in_queue {
-                    self.queue.push(TaskHeapNode(
+                    self.queue.push(TaskHeapNode::new(
                         Self::calc_priority(
                             &self.last_level,
                             &self.last_high_priority,
@@ -485,6 +541,7 @@ impl GenerationSchedule {
                             node.stage,
                         ),
                         cur.to,
+                        &mut self.next_task_seq,
                     ));
                     node.in_queue = true;
                 }
@@ -543,6 +600,7 @@ impl GenerationSchedule {
                             );
                             self.public_chunk_map.remove(&pos);
                             holder.public = false;
+                            self.listener.process_unloaded_chunk(pos);
                         }
                     }
                 }
@@ -616,7 +674,7 @@ impl GenerationSchedule {
                                         "Notifying players: regenerated chunk at {:?} (was already public)",
                                         new_pos
                                     );
-                                    self.listener.process_new_chunk(new_pos, &chunk);
+                                    self.listener.process_changed_chunk(new_pos, &chunk);
                                 }
                             } else {
                                 // Non-center chunk... just restore it to holder without cloning
@@ -729,7 +787,7 @@ impl GenerationSchedule {
                         if let Some(node) = self.graph.nodes.get_mut(first_task) {
                             node.in_queue = true;
                         }
-                        self.queue.push(TaskHeapNode(
+                        self.queue.push(TaskHeapNode::new(
                             Self::calc_priority(
                                 &self.last_level,
                                 &self.last_high_priority,
@@ -737,6 +795,7 @@ impl GenerationSchedule {
                                 StagedChunkEnum::from(1),
                             ) - 50, // Priority boost for retry
                             first_task,
+                            &mut self.next_task_seq,
                         ));
                     }
 
@@ -776,6 +835,7 @@ impl GenerationSchedule {
             }
 
             'out2: while let Some(task) = self.queue.pop() {
+                self.publish_stats();
                 // Check shutdown flag again before processing tasks to avoid IO errors
                 if level.shut_down_chunk_system.load(Relaxed) {
                     // Don't process any more tasks, just break to save chunks
@@ -906,6 +966,7 @@ impl GenerationSchedule {
                 }
             }
 
+            self.publish_stats();
             if self.queue.is_empty() {
                 // debug!("the queue is empty. thread sleep");
                 while self.running_task_count > 0 && self.queue.is_empty() {
@@ -913,6 +974,7 @@ impl GenerationSchedule {
                         Ok((pos, data)) => {
                             self.receive_chunk(pos, data);
                             self.resort_work(self.send_level.get());
+                            self.publish_stats();
                         }
                         Err(_) => {
                             if level.shut_down_chunk_system.load(Relaxed) {
@@ -1029,3 +1091,28 @@ impl GenerationSchedule {
         true
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::BinaryHeap;
+
+    use slotmap::Key;
+
+    use super::{NodeKey, TASK_AGING_INTERVAL, TaskHeapNode};
+
+    #[test]
+    fn closer_tasks_run_first_until_far_ones_aged() {
+        let mut seq = 0;
+        let mut queue = BinaryHeap::new();
+        queue.push(TaskHeapNode::new(1, NodeKey::null(), &mut seq));
+        queue.push(TaskHeapNode::new(0, NodeKey::null(), &mut seq));
+        assert_eq!(queue.pop().unwrap().0, 0);
+        assert_eq!(queue.pop().unwrap().0, 1);
+
+        // A far task queued before a long stream of close ones eventually runs first
+        queue.push(TaskHeapNode::new(1, NodeKey::null(), &mut seq));
+        seq += TASK_AGING_INTERVAL;
+        queue.push(TaskHeapNode::new(0, NodeKey::null(), &mut seq));
+        assert_eq!(queue.pop().unwrap().0, 1);
+    }
+}
@@ -147,8 +147,9 @@ pub async fn io_read_work(
                 continue;
             }
             LoadedData::Missing(_) => {}
-            LoadedData::Error(_) => {
+            LoadedData::Error((_, error)) => {
                 warn!("chunk data read error pos: {pos:?}. regenerating");
+                level.mark_read_only_chunk(pos, &error);
             }
         }
 
@@ -193,6 +194,7 @@ pub async fn io_write_work(recv: AsyncRx<Vec<(ChunkPos, Chunk)>>, level: Arc<Lev
             }
         }
         let pos = vec.iter().map(|(pos, _)| *pos).collect_vec();
+        vec.retain(|(pos, _)| !level.read_only_chunks.contains(pos));
         if let Err(e) = level
             .chunk_saver
             .save_chunks(&level.level_folder, vec)
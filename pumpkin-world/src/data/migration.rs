@@ -0,0 +1,212 @@
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+use thiserror::Error;
+
+use crate::world_info::MAXIMUM_SUPPORTED_WORLD_DATA_VERSION;
+
+/// The version of the data written by Pumpkin, on top of vanilla's `DataVersion`. It has to be
+/// bumped whenever a migration is added, so the data written from then on isn't migrated again.
+/// Data without a Pumpkin data version is treated as version 0.
+pub const PUMPKIN_DATA_VERSION: i32 = 1;
+
+/// The key the Pumpkin data version is saved under, next to vanilla's `DataVersion`.
+pub const PUMPKIN_DATA_VERSION_KEY: &str = "PumpkinDataVersion";
+const DATA_VERSION_KEY: &str = "DataVersion";
+
+/// The kinds of data that are versioned and migrated separately.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataKind {
+    /// The root compound of a chunk in the region files.
+    Chunk,
+    /// A single entity from the entity region files. The version is stored once per chunk.
+    Entity,
+    /// The root compound of a player data file.
+    Player,
+}
+
+/// Upgrades one kind of data written by an older Pumpkin version in place.
+pub struct Migration {
+    pub kind: DataKind,
+    /// The Pumpkin data version this migration upgrades the data to.
+    pub version: i32,
+    pub migrate: fn(&mut NbtCompound),
+}
+
+/// All migrations, ordered by version.
+pub static MIGRATIONS: &[Migration] = &[Migration {
+    kind: DataKind::Entity,
+    version: 1,
+    migrate: rename_leash_key,
+}];
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationError {
+    #[error(
+        "The data was written by a newer Minecraft version (data version {0}, this version supports up to {MAXIMUM_SUPPORTED_WORLD_DATA_VERSION})"
+    )]
+    UnsupportedDataVersion(i32),
+    #[error(
+        "The data was written by a newer Pumpkin version (data version {0}, this version supports up to {PUMPKIN_DATA_VERSION})"
+    )]
+    UnsupportedPumpkinDataVersion(i32),
+}
+
+/// Checks that data with these versions can be loaded without misreading it. Only data written
+/// by newer versions is refused, older chunks are read as well as possible. Callers must not
+/// overwrite refused data, or it is lost.
+pub fn check_versions(data_version: i32, pumpkin_data_version: i32) -> Result<(), MigrationError> {
+    if data_version > MAXIMUM_SUPPORTED_WORLD_DATA_VERSION {
+        return Err(MigrationError::UnsupportedDataVersion(data_version));
+    }
+    if pumpkin_data_version > PUMPKIN_DATA_VERSION {
+        return Err(MigrationError::UnsupportedPumpkinDataVersion(
+            pumpkin_data_version,
+        ));
+    }
+    Ok(())
+}
+
+/// Whether any migration applies to data of `kind` saved with `pumpkin_data_version`.
+#[must_use]
+pub fn needs_migration(kind: DataKind, pumpkin_data_version: i32) -> bool {
+    MIGRATIONS
+        .iter()
+        .any(|migration| migration.kind == kind && migration.version > pumpkin_data_version)
+}
+
+/// Runs the migrations for `kind` that are newer than `pumpkin_data_version` on `nbt`, oldest
+/// first. Returns whether any migration ran.
+pub fn migrate(kind: DataKind, pumpkin_data_version: i32, nbt: &mut NbtCompound) -> bool {
+    let mut migrated = false;
+    for migration in MIGRATIONS
+        .iter()
+        .filter(|migration| migration.kind == kind && migration.version > pumpkin_data_version)
+    {
+        (migration.migrate)(nbt);
+        migrated = true;
+    }
+    migrated
+}
+
+/// Checks the versions stored in a root compound and migrates it. The versions themselves are
+/// left alone, they are updated once the data is written again. Returns whether any migration
+/// ran, in which case the data should be saved again.
+pub fn upgrade(kind: DataKind, nbt: &mut NbtCompound) -> Result<bool, MigrationError> {
+    let pumpkin_data_version = nbt.get_int(PUMPKIN_DATA_VERSION_KEY).unwrap_or(0);
+    if let Some(data_version) = nbt.get_int(DATA_VERSION_KEY) {
+        check_versions(data_version, pumpkin_data_version)?;
+    } else if pumpkin_data_version > PUMPKIN_DATA_VERSION {
+        return Err(MigrationError::UnsupportedPumpkinDataVersion(
+            pumpkin_data_version,
+        ));
+    }
+    Ok(migrate(kind, pumpkin_data_version, nbt))
+}
+
+/// Pumpkin used to save leashes under `Leash`, vanilla reads them from `leash`.
+fn rename_leash_key(entity: &mut NbtCompound) {
+    if entity.get("leash").is_none()
+        && let Some((key, _)) = entity.child_tags.iter_mut().find(|(key, _)| key == "Leash")
+    {
+        *key = "leash".to_string();
+    }
+    for (key, tag) in &mut entity.child_tags {
+        if key == "Passengers"
+            && let NbtTag::List(passengers) = tag
+        {
+            for passenger in passengers {
+                if let NbtTag::Compound(passenger) = passenger {
+                    rename_leash_key(passenger);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+
+    use super::{
+        DataKind, MigrationError, PUMPKIN_DATA_VERSION, PUMPKIN_DATA_VERSION_KEY, check_versions,
+        migrate, needs_migration, upgrade,
+    };
+    use crate::world_info::{
+        MAXIMUM_SUPPORTED_WORLD_DATA_VERSION, MINIMUM_SUPPORTED_WORLD_DATA_VERSION,
+    };
+
+    fn leashed_entity() -> NbtCompound {
+        let mut leash = NbtCompound::new();
+        leash.put("UUID", NbtTag::IntArray(vec![1, 2, 3, 4]));
+        let mut entity = NbtCompound::new();
+        entity.put_string("id", "minecraft:cow".to_string());
+        entity.put_component("Leash", leash);
+        entity
+    }
+
+    #[test]
+    fn leash_is_renamed_for_old_entities() {
+        let mut passenger = leashed_entity();
+        let mut entity = leashed_entity();
+        assert!(migrate(DataKind::Entity, 0, &mut passenger));
+        entity.put_list(
+            "Passengers",
+            vec![
+                NbtTag::Compound(leashed_entity()),
+                NbtTag::Compound(passenger),
+            ],
+        );
+
+        assert!(migrate(DataKind::Entity, 0, &mut entity));
+        assert!(entity.get("Leash").is_none());
+        assert_eq!(
+            entity.get_compound("leash").unwrap().get_int_array("UUID"),
+            Some([1, 2, 3, 4].as_slice())
+        );
+        for passenger in entity.get_list("Passengers").unwrap() {
+            let NbtTag::Compound(passenger) = passenger else {
+                panic!("Passengers are compounds");
+            };
+            assert!(passenger.get("Leash").is_none());
+            assert!(passenger.get("leash").is_some());
+        }
+    }
+
+    #[test]
+    fn current_data_is_not_migrated() {
+        let mut entity = leashed_entity();
+        assert!(!needs_migration(DataKind::Entity, PUMPKIN_DATA_VERSION));
+        assert!(!migrate(
+            DataKind::Entity,
+            PUMPKIN_DATA_VERSION,
+            &mut entity
+        ));
+        assert!(entity.get("Leash").is_some());
+
+        // The leash migration only applies to entities
+        assert!(!needs_migration(DataKind::Chunk, 0));
+        assert!(!needs_migration(DataKind::Player, 0));
+    }
+
+    #[test]
+    fn newer_data_is_refused() {
+        assert_eq!(
+            check_versions(MAXIMUM_SUPPORTED_WORLD_DATA_VERSION + 1, 0),
+            Err(MigrationError::UnsupportedDataVersion(
+                MAXIMUM_SUPPORTED_WORLD_DATA_VERSION + 1
+            ))
+        );
+        // Older worlds are still loaded
+        assert!(check_versions(MINIMUM_SUPPORTED_WORLD_DATA_VERSION - 1, 0).is_ok());
+        assert!(check_versions(MAXIMUM_SUPPORTED_WORLD_DATA_VERSION, 0).is_ok());
+
+        let mut player = NbtCompound::new();
+        player.put_int("DataVersion", MAXIMUM_SUPPORTED_WORLD_DATA_VERSION);
+        player.put_int(PUMPKIN_DATA_VERSION_KEY, PUMPKIN_DATA_VERSION + 1);
+        assert_eq!(
+            upgrade(DataKind::Player, &mut player),
+            Err(MigrationError::UnsupportedPumpkinDataVersion(
+                PUMPKIN_DATA_VERSION + 1
+            ))
+        );
+    }
+}
@@ -1 +1,2 @@
+pub mod migration;
 pub mod player_data;
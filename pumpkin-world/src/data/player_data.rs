@@ -1,3 +1,4 @@
+use crate::data::migration::{self, DataKind, MigrationError};
 use pumpkin_nbt::compound::NbtCompound;
 use std::fs::{File, create_dir_all};
 use std::io;
@@ -22,6 +23,8 @@ pub enum PlayerDataError {
     Io(#[from] io::Error),
     #[error("NBT error: {0}")]
     Nbt(String),
+    #[error("{0}")]
+    UnsupportedVersion(#[from] MigrationError),
 }
 
 impl PlayerDataStorage {
@@ -97,7 +100,11 @@ impl PlayerDataStorage {
         };
 
         match pumpkin_nbt::nbt_compress::read_gzip_compound_tag(file) {
-            Ok(nbt) => {
+            Ok(mut nbt) => {
+                // Upgraded data is written back the next time the player is saved
+                if migration::upgrade(DataKind::Player, &mut nbt)? {
+                    debug!("Upgraded player data for {uuid}");
+                }
                 debug!("Loaded player data for {uuid} from disk");
                 Ok((true, nbt))
             }
@@ -155,3 +162,48 @@ impl PlayerDataStorage {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_nbt::compound::NbtCompound;
+    use temp_dir::TempDir;
+    use uuid::Uuid;
+
+    use super::{PlayerDataError, PlayerDataStorage};
+    use crate::data::migration::{PUMPKIN_DATA_VERSION, PUMPKIN_DATA_VERSION_KEY};
+    use crate::world_info::MINIMUM_SUPPORTED_WORLD_DATA_VERSION;
+
+    fn player_data(data_version: i32, pumpkin_data_version: i32) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+        nbt.put_int("DataVersion", data_version);
+        nbt.put_int(PUMPKIN_DATA_VERSION_KEY, pumpkin_data_version);
+        nbt
+    }
+
+    #[test]
+    fn newer_player_data_is_refused() {
+        let dir = TempDir::new().unwrap();
+        let storage = PlayerDataStorage::new(dir.path(), true);
+        let uuid = Uuid::from_u128(1);
+
+        storage
+            .save_player_data(&uuid, player_data(0, PUMPKIN_DATA_VERSION + 1))
+            .unwrap();
+        assert!(matches!(
+            storage.load_player_data(&uuid),
+            Err(PlayerDataError::UnsupportedVersion(_))
+        ));
+
+        // Data of older Minecraft versions is still loaded
+        storage
+            .save_player_data(
+                &uuid,
+                player_data(
+                    MINIMUM_SUPPORTED_WORLD_DATA_VERSION - 1,
+                    PUMPKIN_DATA_VERSION,
+                ),
+            )
+            .unwrap();
+        assert!(matches!(storage.load_player_data(&uuid), Ok((true, _))));
+    }
+}
@@ -285,6 +285,7 @@ impl ConfiguredFeature {
                 random,
                 pos,
             ),
+            Self::MonsterRoom(feature) => feature.generate(chunk, random, pos),
             Self::DesertWell(feature) => {
                 feature.generate(chunk, min_y, height, feature_name, random, pos)
             }
@@ -1,6 +1,252 @@
+use std::sync::Arc;
+
+use pumpkin_data::{
+    Block, BlockDirection, BlockState,
+    block_properties::{BlockProperties, ChestLikeProperties},
+    entity::EntityType,
+    tag,
+};
+use pumpkin_util::{
+    math::{position::BlockPos, vector3::Vector3},
+    random::{RandomGenerator, RandomImpl},
+};
 use serde::Deserialize;
 
+use crate::{
+    block::entities::{
+        chest::ChestBlockEntity, chest_like_block_entity::ContainerLootTable,
+        mob_spawner::MobSpawnerBlockEntity,
+    },
+    generation::proto_chunk::GenerationCache,
+};
+
+/// The loot table dungeon chests are filled from.
+pub const DUNGEON_LOOT_TABLE: &str = "minecraft:chests/simple_dungeon";
+
+/// The mobs a dungeon spawner can spawn, zombies being twice as likely as the others.
+const SPAWNER_MOBS: [&EntityType; 4] = [
+    &EntityType::SKELETON,
+    &EntityType::ZOMBIE,
+    &EntityType::ZOMBIE,
+    &EntityType::SPIDER,
+];
+
+/// A mossy cobblestone room with a spawner in the middle and up to two chests along the walls.
 #[derive(Deserialize)]
-pub struct DungeonFeature {
-    // TODO
+pub struct DungeonFeature {}
+
+impl DungeonFeature {
+    pub fn generate<T: GenerationCache>(
+        &self,
+        chunk: &mut T,
+        random: &mut RandomGenerator,
+        pos: BlockPos,
+    ) -> bool {
+        let radius_x = random.next_bounded_i32(2) + 2;
+        let min_x = -radius_x - 1;
+        let max_x = radius_x + 1;
+        let radius_z = random.next_bounded_i32(2) + 2;
+        let min_z = -radius_z - 1;
+        let max_z = radius_z + 1;
+
+        // The floor and ceiling have to be solid, and the walls need between one and five openings
+        let mut openings = 0;
+        for x in min_x..=max_x {
+            for y in -1..=4 {
+                for z in min_z..=max_z {
+                    let block_pos = pos.0.add(&Vector3::new(x, y, z));
+                    let solid = GenerationCache::get_block_state(chunk, &block_pos)
+                        .to_state()
+                        .is_solid();
+                    if (y == -1 || y == 4) && !solid {
+                        return false;
+                    }
+                    if (x == min_x || x == max_x || z == min_z || z == max_z)
+                        && y == 0
+                        && chunk.is_air(&block_pos)
+                        && chunk.is_air(&block_pos.add(&Vector3::new(0, 1, 0)))
+                    {
+                        openings += 1;
+                    }
+                }
+            }
+        }
+        if !(1..=5).contains(&openings) {
+            return false;
+        }
+
+        for x in min_x..=max_x {
+            for y in (-1..=3).rev() {
+                for z in min_z..=max_z {
+                    let block_pos = pos.0.add(&Vector3::new(x, y, z));
+                    let state = GenerationCache::get_block_state(chunk, &block_pos);
+                    let block = state.to_block_id();
+                    if x == min_x || y == -1 || z == min_z || x == max_x || z == max_z {
+                        let below = block_pos.add(&Vector3::new(0, -1, 0));
+                        if block_pos.y >= chunk.bottom_y() as i32
+                            && !GenerationCache::get_block_state(chunk, &below)
+                                .to_state()
+                                .is_solid()
+                        {
+                            chunk.set_block_state(&block_pos, Block::AIR.default_state);
+                        } else if state.to_state().is_solid() && block != Block::CHEST.id {
+                            let wall = if y == -1 && random.next_bounded_i32(4) != 0 {
+                                Block::MOSSY_COBBLESTONE.default_state
+                            } else {
+                                Block::COBBLESTONE.default_state
+                            };
+                            Self::set_if_replaceable(chunk, &block_pos, wall);
+                        }
+                    } else if block != Block::CHEST.id && block != Block::SPAWNER.id {
+                        Self::set_if_replaceable(chunk, &block_pos, Block::CAVE_AIR.default_state);
+                    }
+                }
+            }
+        }
+
+        // Up to two chests, each placed against exactly one wall
+        for _ in 0..2 {
+            for _ in 0..3 {
+                let chest_pos = Vector3::new(
+                    pos.0.x + random.next_bounded_i32(radius_x * 2 + 1) - radius_x,
+                    pos.0.y,
+                    pos.0.z + random.next_bounded_i32(radius_z * 2 + 1) - radius_z,
+                );
+                if !chunk.is_air(&chest_pos) {
+                    continue;
+                }
+
+                let mut walls = BlockDirection::horizontal()
+                    .into_iter()
+                    .filter(|direction| {
+                        GenerationCache::get_block_state(
+                            chunk,
+                            &chest_pos.add(&direction.to_offset()),
+                        )
+                        .to_state()
+                        .is_solid()
+                    });
+                let (Some(wall), None) = (walls.next(), walls.next()) else {
+                    continue;
+                };
+
+                let mut props = ChestLikeProperties::default(&Block::CHEST);
+                if let Some(facing) = wall.opposite().to_horizontal_facing() {
+                    props.facing = facing;
+                }
+                let chest = BlockState::from_id(props.to_state_id(&Block::CHEST));
+                let placed = Self::set_if_replaceable(chunk, &chest_pos, chest);
+                let seed = random.next_i64();
+                if placed {
+                    let chest = ChestBlockEntity::new(BlockPos(chest_pos));
+                    chest.set_loot_table(ContainerLootTable::new(DUNGEON_LOOT_TABLE, seed));
+                    chunk.add_block_entity(Arc::new(chest));
+                }
+                break;
+            }
+        }
+
+        if Self::set_if_replaceable(chunk, &pos.0, Block::SPAWNER.default_state) {
+            let spawner = MobSpawnerBlockEntity::new(pos);
+            spawner.set_entity_type(SPAWNER_MOBS[random.next_bounded_i32(4) as usize]);
+            chunk.add_block_entity(Arc::new(spawner));
+        }
+        true
+    }
+
+    /// Sets the block unless it is one features can't replace, returning whether it was set.
+    fn set_if_replaceable<T: GenerationCache>(
+        chunk: &mut T,
+        pos: &Vector3<i32>,
+        state: &BlockState,
+    ) -> bool {
+        let block = GenerationCache::get_block_state(chunk, pos).to_block_id();
+        if tag::Block::MINECRAFT_FEATURES_CANNOT_REPLACE
+            .1
+            .contains(&block)
+        {
+            return false;
+        }
+        chunk.set_block_state(pos, state);
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_config::lighting::LightingEngineConfig;
+    use pumpkin_data::{Block, dimension::Dimension};
+    use pumpkin_util::{
+        math::{position::BlockPos, vector3::Vector3},
+        random::{RandomGenerator, xoroshiro128::Xoroshiro},
+    };
+
+    use super::{DUNGEON_LOOT_TABLE, DungeonFeature, SPAWNER_MOBS};
+    use crate::{
+        ProtoChunk,
+        block::entities::{chest::ChestBlockEntity, mob_spawner::MobSpawnerBlockEntity},
+        chunk_system::{chunk_state::Chunk, generation_cache::Cache},
+        generation::proto_chunk::GenerationCache,
+    };
+
+    /// A chunk of stone with a tunnel at `y` 64 running from the middle to the east edge, so a
+    /// dungeon centered on (8, 64, 8) has exactly one opening.
+    fn tunnel_cache() -> Cache {
+        let mut cache = Cache::new(0, 0, 1);
+        cache.chunks.push(Chunk::Proto(Box::new(ProtoChunk::new(
+            0,
+            0,
+            &Dimension::OVERWORLD,
+            Block::STONE.default_state,
+            0,
+        ))));
+        for x in 0..16 {
+            for z in 0..16 {
+                for y in 58..72 {
+                    let tunnel = z == 8 && x >= 8 && (64..=65).contains(&y);
+                    let state = if tunnel {
+                        Block::AIR.default_state
+                    } else {
+                        Block::STONE.default_state
+                    };
+                    cache.set_block_state(&Vector3::new(x, y, z), state);
+                }
+            }
+        }
+        cache
+    }
+
+    #[test]
+    fn dungeons_get_a_spawner_and_loot() {
+        let center = BlockPos::new(8, 64, 8);
+        let mut chests = 0;
+        for seed in 0..16 {
+            let mut cache = tunnel_cache();
+            let mut random = RandomGenerator::Xoroshiro(Xoroshiro::from_seed(seed));
+            assert!(DungeonFeature {}.generate(&mut cache, &mut random, center));
+
+            let mut chunk = cache.chunks.pop().unwrap();
+            chunk.upgrade_to_level_chunk(&Dimension::OVERWORLD, &LightingEngineConfig::Default);
+            let Chunk::Level(chunk) = chunk else {
+                unreachable!();
+            };
+            let block_entities = chunk.block_entities.lock().unwrap();
+
+            let spawner = block_entities[&center]
+                .clone()
+                .downcast_arc::<MobSpawnerBlockEntity>()
+                .unwrap();
+            let mob = spawner.spawn_entry.load().unwrap();
+            assert!(SPAWNER_MOBS.iter().any(|candidate| candidate.id == mob.id));
+
+            for block_entity in block_entities.values() {
+                if let Some(chest) = block_entity.clone().downcast_arc::<ChestBlockEntity>() {
+                    let loot_table = chest.loot_table.lock().unwrap().clone().unwrap();
+                    assert_eq!(loot_table.key, DUNGEON_LOOT_TABLE);
+                    chests += 1;
+                }
+            }
+        }
+        assert!(chests > 0);
+    }
 }
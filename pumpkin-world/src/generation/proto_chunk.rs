@@ -31,6 +31,7 @@ use super::{
     surface::{MaterialRuleContext, estimate_surface_height, terrain::SurfaceTerrainBuilder},
 };
 use crate::biome::{BiomeSupplier, MultiNoiseBiomeSupplier, end::TheEndBiomeSupplier};
+use crate::block::entities::BlockEntity;
 use crate::chunk::format::LightContainer;
 use crate::chunk::{ChunkData, ChunkHeightmapType, ChunkLight};
 use crate::chunk_system::StagedChunkEnum;
@@ -65,6 +66,7 @@ pub trait GenerationCache: HeightLimitView + BlockAccessor {
     fn get_block_state(&self, pos: &Vector3<i32>) -> RawBlockState;
     fn get_fluid_and_fluid_state(&self, position: &Vector3<i32>) -> (Fluid, FluidState);
     fn set_block_state(&mut self, pos: &Vector3<i32>, block_state: &BlockState);
+    fn add_block_entity(&mut self, block_entity: Arc<dyn BlockEntity>);
     fn top_motion_blocking_block_height_exclusive(&self, x: i32, z: i32) -> i32;
     fn top_motion_blocking_block_no_leaves_height_exclusive(&self, x: i32, z: i32) -> i32;
     fn get_top_y(&self, heightmap: &HeightMap, x: i32, z: i32) -> i32;
@@ -149,6 +151,8 @@ pub struct ProtoChunk {
     pub flat_motion_blocking_height_map: Box<[i16]>,
     pub flat_motion_blocking_no_leaves_height_map: Box<[i16]>,
     structure_starts: FxHashMap<StructureKeys, StructureInstance>,
+    /// Block entities placed by features, moved into the level chunk once it is upgraded
+    pub block_entities: FxHashMap<BlockPos, Arc<dyn BlockEntity>>,
 
     // Height of the chunk for indexing
     height: u16,
@@ -210,6 +214,7 @@ impl ProtoChunk {
             flat_motion_blocking_height_map: default_heightmap.clone(),
             flat_motion_blocking_no_leaves_height_map: default_heightmap,
             structure_starts: FxHashMap::default(),
+            block_entities: FxHashMap::default(),
             height,
             bottom_y: dimension.min_y as i8,
             stage: StagedChunkEnum::Empty,
@@ -249,6 +254,9 @@ impl ProtoChunk {
             biome_mixer_seed,
         );
         proto_chunk.light = chunk_data.light_engine.lock().unwrap().clone();
+        proto_chunk
+            .block_entities
+            .clone_from(&chunk_data.block_entities.lock().unwrap());
 
         let section_data = &chunk_data.section;
         let heightmap_data = chunk_data.heightmap.lock().unwrap();
@@ -307,6 +315,7 @@ impl ProtoChunk {
                     x,
                     z,
                     section_data.min_y,
+                    section_data.height(),
                 ) as i16;
 
                 proto_chunk.flat_motion_blocking_no_leaves_height_map[index] = heightmap_data.get(
@@ -314,12 +323,17 @@ impl ProtoChunk {
                     x,
                     z,
                     section_data.min_y,
+                    section_data.height(),
                 )
                     as i16;
 
-                proto_chunk.flat_surface_height_map[index] =
-                    heightmap_data.get(ChunkHeightmapType::WorldSurface, x, z, section_data.min_y)
-                        as i16;
+                proto_chunk.flat_surface_height_map[index] = heightmap_data.get(
+                    ChunkHeightmapType::WorldSurface,
+                    x,
+                    z,
+                    section_data.min_y,
+                    section_data.height(),
+                ) as i16;
             }
         }
 
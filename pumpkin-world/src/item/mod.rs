@@ -89,6 +89,17 @@ impl ItemStack {
         None
     }
 
+    /// Sets a data component on this stack, replacing any previous value.
+    pub fn set_data_component<T: DataComponentImpl + 'static>(&mut self, component: T) {
+        let to_set_id = T::get_enum();
+        let component = Some(component.to_dyn());
+        if let Some((_, old)) = self.patch.iter_mut().find(|(id, _)| *id == to_set_id) {
+            *old = component;
+        } else {
+            self.patch.push((to_set_id, component));
+        }
+    }
+
     pub const EMPTY: &'static Self = &Self {
         item_count: 0,
         item: &Item::AIR,
@@ -154,6 +165,12 @@ impl ItemStack {
         self.get_max_damage().unwrap_or(0) > 0
     }
 
+    /// Whether one more point of damage breaks the item.
+    #[must_use]
+    pub fn will_break_next_use(&self) -> bool {
+        self.is_damageable() && self.get_damage() >= self.get_max_damage().unwrap_or(0) - 1
+    }
+
     pub fn repair_item(&mut self, amount: i32) -> i32 {
         if amount <= 0 {
             return 0;
@@ -1,3 +1,4 @@
+use crate::chunk_system::schedule::GenerationStats;
 use crate::chunk_system::{ChunkListener, ChunkLoading, GenerationSchedule, LevelChannel};
 use crate::generation::generator::VanillaGenerator;
 use crate::lighting::DynamicLightEngine;
@@ -6,7 +7,10 @@ use crate::{
     block::{RawBlockState, entities::BlockEntity},
     chunk::{
         ChunkData, ChunkEntityData, ChunkReadingError,
-        format::{anvil::AnvilChunkFile, linear::LinearFile},
+        format::{
+            anvil::{AnvilChunkFile, REGION_SIZE, SingleChunkDataSerializer},
+            linear::LinearFile,
+        },
         io::{Dirtiable, FileIO, LoadedData, file_manager::ChunkFileManager},
     },
     generation::get_world_gen,
@@ -14,7 +18,7 @@ use crate::{
     world::BlockRegistryExt,
 };
 use crossbeam::channel::Sender;
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use pumpkin_config::{chunk::ChunkConfig, lighting::LightingEngineConfig, world::LevelConfig};
 use pumpkin_data::biome::Biome;
 use pumpkin_data::dimension::Dimension;
@@ -25,7 +29,7 @@ use rustc_hash::FxHashMap;
 use std::sync::Mutex;
 use std::time::Duration;
 use std::{
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::{
         Arc,
         atomic::{AtomicBool, AtomicU64, Ordering},
@@ -77,6 +81,10 @@ pub struct Level {
 
     pub chunk_saver: Arc<dyn FileIO<Data = SyncChunk>>,
     entity_saver: Arc<dyn FileIO<Data = SyncEntityChunk>>,
+    /// Chunks that were saved by a newer version and could not be read. Whatever is generated in
+    /// their place is never saved, so the original data stays on disk.
+    pub read_only_chunks: DashSet<Vector2<i32>>,
+    read_only_entity_chunks: DashSet<Vector2<i32>>,
 
     pub world_gen: Arc<VanillaGenerator>,
 
@@ -101,6 +109,8 @@ pub struct Level {
     pub level_channel: Arc<LevelChannel>,
     pub thread_tracker: Mutex<Vec<thread::JoinHandle<()>>>,
     pub chunk_listener: Arc<ChunkListener>,
+    /// The size of the chunk generation queue, updated by the schedule thread.
+    pub generation_stats: Arc<GenerationStats>,
 }
 
 pub struct TickData {
@@ -110,6 +120,9 @@ pub struct TickData {
     pub block_entities: Vec<Arc<dyn BlockEntity>>,
 }
 
+/// The number of dimensions a server loads a level for.
+const LOADED_DIMENSIONS: usize = 3;
+
 #[derive(Clone)]
 pub struct LevelFolder {
     pub root_folder: PathBuf,
@@ -190,6 +203,8 @@ impl Level {
             loaded_entity_chunks: Arc::new(DashMap::new()),
             chunk_loading: Mutex::new(ChunkLoading::new(level_channel.clone())),
             chunk_watchers: Arc::new(DashMap::new()),
+            read_only_chunks: DashSet::new(),
+            read_only_entity_chunks: DashSet::new(),
             tasks: TaskTracker::new(),
             chunk_system_tasks: TaskTracker::new(),
             cancel_token: CancellationToken::new(),
@@ -202,16 +217,20 @@ impl Level {
             level_channel: level_channel.clone(),
             thread_tracker,
             chunk_listener: listener.clone(),
+            generation_stats: Arc::new(GenerationStats::default()),
         });
 
-        // TODO
-        let total_cores = num_cpus::get().saturating_sub(2).max(1);
-        let threads_per_dimension = (total_cores / 2).max(1);
-        let entity_threads = (threads_per_dimension / 2).max(1);
+        // Every dimension has its own level, so they split the threads between them
+        let generation_threads = (match level_config.generation_threads {
+            0 => num_cpus::get_physical().saturating_sub(1),
+            threads => threads,
+        } / LOADED_DIMENSIONS)
+            .max(1);
+        let entity_threads = (generation_threads / 2).max(1);
 
         GenerationSchedule::create(
             2,
-            threads_per_dimension,
+            generation_threads,
             level_ref.clone(),
             level_channel,
             listener,
@@ -276,6 +295,45 @@ impl Level {
         self.tasks.spawn(task)
     }
 
+    /// Loads every chunk and entity chunk saved in the region files and writes back the ones
+    /// that were migrated, like vanilla's `--forceUpgrade`. Otherwise outdated chunks are only
+    /// upgraded once they are loaded and saved again.
+    pub async fn force_upgrade(&self) {
+        let world_id = self.level_folder.root_folder.display();
+        let regions = region_files(&self.level_folder.region_folder);
+        let entity_regions = region_files(&self.level_folder.entities_folder);
+        let total = regions.len() + entity_regions.len();
+        info!("Upgrading {total} region files of {world_id}...");
+
+        let mut upgraded = 0;
+        let mut failed = 0;
+        for (index, region) in regions.iter().enumerate() {
+            let (region_upgraded, region_failed) =
+                upgrade_region(self.chunk_saver.as_ref(), &self.level_folder, *region).await;
+            upgraded += region_upgraded;
+            failed += region_failed;
+            info!("Upgraded region {}/{total} of {world_id}", index + 1);
+        }
+        for (index, region) in entity_regions.iter().enumerate() {
+            let (region_upgraded, region_failed) =
+                upgrade_region(self.entity_saver.as_ref(), &self.level_folder, *region).await;
+            upgraded += region_upgraded;
+            failed += region_failed;
+            info!(
+                "Upgraded region {}/{total} of {world_id}",
+                regions.len() + index + 1
+            );
+        }
+        self.chunk_saver.block_and_await_ongoing_tasks().await;
+        self.entity_saver.block_and_await_ongoing_tasks().await;
+
+        if failed > 0 {
+            warn!("Upgraded {upgraded} chunks of {world_id}, {failed} chunks could not be read");
+        } else {
+            info!("Upgraded {upgraded} chunks of {world_id}");
+        }
+    }
+
     pub async fn shutdown(&self) {
         let world_id = self.level_folder.root_folder.display();
         info!("Saving level ({})...", world_id);
@@ -571,7 +629,10 @@ impl Level {
 
         match rx.recv().await {
             Some(LoadedData::Loaded(chunk)) => Ok((chunk, false)),
-            Some(LoadedData::Error((_, err))) => Err(err),
+            Some(LoadedData::Error((pos, err))) => {
+                self.mark_read_only_entity_chunk(pos, &err);
+                Err(err)
+            }
             _ => Err(ChunkReadingError::ChunkNotExist),
         }
     }
@@ -616,6 +677,9 @@ impl Level {
                                 let _ = sender.send((chunk, false));
                             }
                             LoadedData::Missing(pos) | LoadedData::Error((pos, _)) => {
+                                if let LoadedData::Error((_, error)) = &data {
+                                    level.mark_read_only_entity_chunk(pos, error);
+                                }
                                 let sender_clone = sender.clone();
                                 let level_clone = level.clone();
 
@@ -720,7 +784,27 @@ impl Level {
         replaced_block_state_id
     }
 
-    pub async fn write_chunks(&self, chunks_to_write: Vec<(Vector2<i32>, SyncChunk)>) {
+    /// Keeps the chunk at `pos` from being saved if `error` means a newer version wrote it.
+    pub fn mark_read_only_chunk(&self, pos: Vector2<i32>, error: &ChunkReadingError) {
+        if error.is_unsupported_version() && self.read_only_chunks.insert(pos) {
+            error!(
+                "Chunk {pos:?} of {} was saved by a newer version and is generated in its place, but will not be saved: {error}",
+                self.level_folder.root_folder.display()
+            );
+        }
+    }
+
+    fn mark_read_only_entity_chunk(&self, pos: Vector2<i32>, error: &ChunkReadingError) {
+        if error.is_unsupported_version() && self.read_only_entity_chunks.insert(pos) {
+            error!(
+                "The entities of chunk {pos:?} of {} were saved by a newer version, they will not be loaded or saved: {error}",
+                self.level_folder.root_folder.display()
+            );
+        }
+    }
+
+    pub async fn write_chunks(&self, mut chunks_to_write: Vec<(Vector2<i32>, SyncChunk)>) {
+        chunks_to_write.retain(|(pos, _)| !self.read_only_chunks.contains(pos));
         if chunks_to_write.is_empty() {
             return;
         }
@@ -737,7 +821,11 @@ impl Level {
         }
     }
 
-    pub async fn write_entity_chunks(&self, chunks_to_write: Vec<(Vector2<i32>, SyncEntityChunk)>) {
+    pub async fn write_entity_chunks(
+        &self,
+        mut chunks_to_write: Vec<(Vector2<i32>, SyncEntityChunk)>,
+    ) {
+        chunks_to_write.retain(|(pos, _)| !self.read_only_entity_chunks.contains(pos));
         if chunks_to_write.is_empty() {
             return;
         }
@@ -760,6 +848,19 @@ impl Level {
             .map(|x| x.value().clone())
     }
 
+    /// Sends a loaded chunk to all watching players again, for changes block updates can't
+    /// describe, like relighting many sections or changing biomes or heightmaps.
+    /// Returns `false` if the chunk is not loaded.
+    pub fn resend_chunk(&self, coordinates: Vector2<i32>) -> bool {
+        let Some(chunk) = self.try_get_chunk(&coordinates) else {
+            return false;
+        };
+        chunk.send_generation.bump();
+        self.chunk_listener
+            .process_changed_chunk(coordinates, &chunk);
+        true
+    }
+
     pub fn try_get_entity_chunk(
         &self,
         coordinates: Vector2<i32>,
@@ -825,3 +926,171 @@ impl Level {
         chunk.fluid_ticks.is_scheduled(*block_pos, fluid)
     }
 }
+
+/// The coordinates of the region files (`r.<x>.<z>.<extension>`) in `folder`.
+fn region_files(folder: &Path) -> Vec<(i32, i32)> {
+    let Ok(entries) = std::fs::read_dir(folder) else {
+        return Vec::new();
+    };
+    let mut regions: Vec<_> = entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            let mut parts = name.to_str()?.split('.');
+            if parts.next()? != "r" {
+                return None;
+            }
+            let x = parts.next()?.parse().ok()?;
+            let z = parts.next()?.parse().ok()?;
+            Some((x, z))
+        })
+        .collect();
+    regions.sort_unstable();
+    regions.dedup();
+    regions
+}
+
+/// Loads all chunks of a region through `io` and saves the ones that were migrated while
+/// loading. Returns how many chunks were upgraded and how many could not be read.
+async fn upgrade_region<D: SingleChunkDataSerializer>(
+    io: &dyn FileIO<Data = Arc<D>>,
+    folder: &LevelFolder,
+    (region_x, region_z): (i32, i32),
+) -> (usize, usize) {
+    let chunks: Vec<_> = (0..REGION_SIZE as i32)
+        .flat_map(|x| {
+            (0..REGION_SIZE as i32).map(move |z| {
+                Vector2::new(
+                    region_x * REGION_SIZE as i32 + x,
+                    region_z * REGION_SIZE as i32 + z,
+                )
+            })
+        })
+        .collect();
+
+    // The channel can hold the whole region, so fetching never waits for the receiver
+    let (tx, mut rx) = mpsc::channel(chunks.len());
+    io.fetch_chunks(folder, &chunks, tx).await;
+
+    let mut to_save = Vec::new();
+    let mut failed = 0;
+    while let Some(data) = rx.recv().await {
+        match data {
+            LoadedData::Loaded(chunk) if chunk.is_dirty() => {
+                let (x, z) = chunk.position();
+                to_save.push((Vector2::new(x, z), chunk));
+            }
+            LoadedData::Error((position, error)) => {
+                warn!("Failed to upgrade chunk {position:?}: {error}");
+                failed += 1;
+            }
+            _ => {}
+        }
+    }
+
+    let upgraded = to_save.len();
+    if let Err(error) = io.save_chunks(folder, to_save).await {
+        error!("Failed writing upgraded chunks to disk: {error}");
+    }
+    (upgraded, failed)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use pumpkin_config::world::LevelConfig;
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_util::math::vector2::Vector2;
+    use temp_dir::TempDir;
+
+    use super::Level;
+    use crate::chunk_system::ChunkLoading;
+    use crate::world::test::BlockRegistry;
+
+    const VIEW_DISTANCE: i32 = 2;
+
+    fn view_area(center: Vector2<i32>) -> impl Iterator<Item = Vector2<i32>> {
+        (-VIEW_DISTANCE..=VIEW_DISTANCE).flat_map(move |x| {
+            (-VIEW_DISTANCE..=VIEW_DISTANCE).map(move |z| Vector2::new(center.x + x, center.y + z))
+        })
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore] // Generates hundreds of chunks, which takes minutes; run it with --ignored
+    async fn players_flying_apart_get_close_chunks_first_in_bounded_memory() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = LevelConfig {
+            generation_threads: 6,
+            ..Default::default()
+        };
+        let level = Level::from_root_folder(
+            &config,
+            temp_dir.path().to_path_buf(),
+            Arc::new(BlockRegistry),
+            0,
+            Dimension::OVERWORLD,
+        );
+        let loaded = level.chunk_listener.add_global_chunk_listener();
+        let ticket_level = ChunkLoading::get_level_from_view_distance(VIEW_DISTANCE as u8);
+
+        let mut centers: Option<[Vector2<i32>; 2]> = None;
+        let mut first_loaded_count = None;
+        for step in 1..=4 {
+            // One player flies east, the other west, each far enough to need all new chunks
+            let new_centers = [Vector2::new(step * 8, 0), Vector2::new(-step * 8, 0)];
+            {
+                let mut loading = level.chunk_loading.lock().unwrap();
+                for center in new_centers {
+                    loading.add_ticket(center, ticket_level);
+                }
+                for center in centers.into_iter().flatten() {
+                    loading.remove_ticket(center, ticket_level);
+                }
+                loading.send_change();
+            }
+            centers = Some(new_centers);
+
+            let mut wanted: HashSet<_> = new_centers.into_iter().flat_map(view_area).collect();
+            let mut distances = Vec::new();
+            while !wanted.is_empty() {
+                let (pos, _) = loaded
+                    .recv_timeout(Duration::from_secs(120))
+                    .expect("chunk generation stalled");
+                if wanted.remove(&pos) {
+                    let distance = new_centers
+                        .iter()
+                        .map(|center| (pos.x - center.x).abs().max((pos.y - center.y).abs()))
+                        .min()
+                        .unwrap();
+                    distances.push(distance);
+                }
+            }
+
+            // The chunks around the players arrive before the edges of their view
+            let (first, last) = distances.split_at(distances.len() / 2);
+            let average = |half: &[i32]| half.iter().sum::<i32>() as f64 / half.len() as f64;
+            assert!(
+                average(first) <= average(last),
+                "step {step} loaded chunks in the order {distances:?}"
+            );
+
+            first_loaded_count.get_or_insert(level.loaded_chunk_count());
+        }
+
+        // The areas the players left are unloaded, so memory doesn't grow with the distance
+        let bound = 2 * first_loaded_count.unwrap();
+        let deadline = Instant::now() + Duration::from_secs(30);
+        while level.loaded_chunk_count() > bound {
+            assert!(
+                Instant::now() < deadline,
+                "{} chunks are still loaded, expected at most {bound}",
+                level.loaded_chunk_count()
+            );
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        level.shutdown().await;
+    }
+}
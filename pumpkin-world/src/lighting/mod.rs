@@ -5,3 +5,12 @@ pub use engine::LightEngine;
 
 pub mod runtime;
 pub use runtime::DynamicLightEngine;
+
+/// The two kinds of light the light engine keeps track of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LightLayer {
+    /// Light coming down from the sky, before it is dimmed by the time of day.
+    Sky,
+    /// Light emitted by blocks like torches.
+    Block,
+}
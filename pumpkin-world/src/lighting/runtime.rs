@@ -4,14 +4,23 @@ use crate::level::Level;
 use crossbeam::queue::SegQueue;
 use pumpkin_config::lighting::LightingEngineConfig;
 use pumpkin_data::BlockDirection;
-use pumpkin_util::math::position::BlockPos;
-use std::sync::Arc;
+use pumpkin_util::math::position::{BlockPos, chunk_section_from_pos};
+use pumpkin_util::math::vector2::Vector2;
+use pumpkin_util::math::vector3::Vector3;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+/// How many sections of a chunk have to be relit at once before the chunk is sent in full again.
+pub const FULL_RESEND_SECTIONS: usize = 4;
 
 pub struct DynamicLightEngine {
     decrease_block_light_queue: SegQueue<(BlockPos, u8)>,
     increase_block_light_queue: SegQueue<(BlockPos, u8)>,
     decrease_sky_light_queue: SegQueue<(BlockPos, u8)>,
     increase_sky_light_queue: SegQueue<(BlockPos, u8)>,
+    /// Block changes whose lighting hasn't been updated yet, grouped by chunk section.
+    pending_updates: Mutex<FxHashMap<Vector3<i32>, FxHashSet<BlockPos>>>,
 }
 
 impl DynamicLightEngine {
@@ -21,6 +30,7 @@ impl DynamicLightEngine {
             increase_block_light_queue: SegQueue::new(),
             decrease_sky_light_queue: SegQueue::new(),
             increase_sky_light_queue: SegQueue::new(),
+            pending_updates: Mutex::new(FxHashMap::default()),
         }
     }
 }
@@ -60,6 +70,53 @@ impl DynamicLightEngine {
         self.perform_sky_light_updates(level).await;
     }
 
+    /// Queues the lighting update for a block change, to be applied by the next
+    /// [`Self::flush_lighting_updates`]. Changing the same block again before that only updates
+    /// it once.
+    pub fn queue_lighting_update(&self, pos: BlockPos) {
+        self.pending_updates
+            .lock()
+            .unwrap()
+            .entry(chunk_section_from_pos(&pos))
+            .or_default()
+            .insert(pos);
+    }
+
+    /// Applies all queued lighting updates and returns how many blocks were updated. The changes
+    /// in a chunk section are checked together and then propagated in a single pass.
+    ///
+    /// Chunks relit in [`FULL_RESEND_SECTIONS`] or more sections are sent to players again.
+    pub async fn flush_lighting_updates(&self, level: &Arc<Level>) -> usize {
+        let pending = mem::take(&mut *self.pending_updates.lock().unwrap());
+        let mut updated = 0;
+        let mut relit_sections: FxHashMap<Vector2<i32>, usize> = FxHashMap::default();
+
+        for (section, positions) in pending {
+            updated += positions.len();
+            *relit_sections
+                .entry(Vector2::new(section.x, section.z))
+                .or_default() += 1;
+
+            for pos in &positions {
+                self.check_block_light_updates(level, *pos).await;
+            }
+            self.perform_block_light_updates(level).await;
+
+            for pos in &positions {
+                self.check_sky_light_updates(level, *pos).await;
+            }
+            self.perform_sky_light_updates(level).await;
+        }
+
+        for (chunk, sections) in relit_sections {
+            if sections >= FULL_RESEND_SECTIONS {
+                level.resend_chunk(chunk);
+            }
+        }
+
+        updated
+    }
+
     pub fn queue_block_light_decrease(&self, pos: BlockPos, level: u8) {
         self.decrease_block_light_queue.push((pos, level));
     }
@@ -546,3 +603,154 @@ impl DynamicLightEngine {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+
+    use pumpkin_config::world::LevelConfig;
+    use pumpkin_data::Block;
+    use pumpkin_data::chunk::ChunkStatus;
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_util::math::position::BlockPos;
+    use pumpkin_util::math::vector2::Vector2;
+    use temp_dir::TempDir;
+
+    use super::FULL_RESEND_SECTIONS;
+    use crate::chunk::format::LightContainer;
+    use crate::chunk::{ChunkData, ChunkHeightmaps, ChunkLight, ChunkSections, SendGeneration};
+    use crate::level::Level;
+    use crate::world::test::BlockRegistry;
+
+    /// An overworld chunk filled with air, in full daylight and without block light.
+    fn empty_chunk(x: i32, z: i32) -> ChunkData {
+        let dimension = Dimension::OVERWORLD;
+        let section_count = (dimension.height / 16) as usize;
+        ChunkData {
+            section: ChunkSections::new(section_count, dimension.min_y),
+            heightmap: std::sync::Mutex::new(ChunkHeightmaps::new(dimension.height)),
+            x,
+            z,
+            block_ticks: Default::default(),
+            fluid_ticks: Default::default(),
+            block_entities: Default::default(),
+            light_engine: std::sync::Mutex::new(ChunkLight {
+                sky_light: vec![LightContainer::new_empty(15); section_count].into_boxed_slice(),
+                block_light: vec![LightContainer::new_empty(0); section_count].into_boxed_slice(),
+            }),
+            light_populated: AtomicBool::new(true),
+            status: ChunkStatus::Full,
+            dirty: AtomicBool::new(false),
+            send_generation: SendGeneration::new(),
+        }
+    }
+
+    fn empty_level(temp_dir: &TempDir) -> Arc<Level> {
+        let level = Level::from_root_folder(
+            &LevelConfig::default(),
+            temp_dir.path().to_path_buf(),
+            Arc::new(BlockRegistry),
+            0,
+            Dimension::OVERWORLD,
+        );
+        for x in -1..=1 {
+            for z in -1..=1 {
+                level
+                    .loaded_chunks
+                    .insert(Vector2::new(x, z), Arc::new(empty_chunk(x, z)));
+            }
+        }
+        level
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn batched_updates_match_synchronous_updates() {
+        let sync_dir = TempDir::new().unwrap();
+        let batched_dir = TempDir::new().unwrap();
+        let sync_level = empty_level(&sync_dir);
+        let batched_level = empty_level(&batched_dir);
+
+        let torch = BlockPos::new(3, 70, 5);
+        let changes = [
+            (torch, Block::TORCH.default_state.id),
+            (BlockPos::new(10, 72, 12), Block::LANTERN.default_state.id),
+            (BlockPos::new(6, 71, 9), Block::LANTERN.default_state.id),
+            (torch, Block::TORCH.default_state.id),
+        ];
+        for (pos, state_id) in changes {
+            sync_level.set_block_state(&pos, state_id).await;
+            sync_level
+                .light_engine
+                .update_lighting_at(&sync_level, pos)
+                .await;
+
+            batched_level.set_block_state(&pos, state_id).await;
+            batched_level.light_engine.queue_lighting_update(pos);
+        }
+
+        // All changes are in the same chunk section, and repeated changes are only applied once
+        let updated = batched_level
+            .light_engine
+            .flush_lighting_updates(&batched_level)
+            .await;
+        assert_eq!(updated, 3);
+
+        let engine = &batched_level.light_engine;
+        assert_eq!(
+            engine.get_block_light_level(&batched_level, &torch).await,
+            Some(14)
+        );
+
+        for x in -8..24 {
+            for y in 54..88 {
+                for z in -8..24 {
+                    let pos = BlockPos::new(x, y, z);
+                    assert_eq!(
+                        engine.get_block_light_level(&batched_level, &pos).await,
+                        sync_level
+                            .light_engine
+                            .get_block_light_level(&sync_level, &pos)
+                            .await,
+                        "block light at {pos}"
+                    );
+                    assert_eq!(
+                        engine.get_sky_light_level(&batched_level, &pos).await,
+                        sync_level
+                            .light_engine
+                            .get_sky_light_level(&sync_level, &pos)
+                            .await,
+                        "sky light at {pos}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn relighting_many_sections_resends_the_chunk() {
+        let temp_dir = TempDir::new().unwrap();
+        let level = empty_level(&temp_dir);
+        let chunk = level.try_get_chunk(&Vector2::new(0, 0)).unwrap();
+
+        // A single section only needs block updates
+        let generation = chunk.send_generation.get();
+        let torch = BlockPos::new(3, 70, 5);
+        level
+            .set_block_state(&torch, Block::TORCH.default_state.id)
+            .await;
+        level.light_engine.queue_lighting_update(torch);
+        level.light_engine.flush_lighting_updates(&level).await;
+        assert_eq!(chunk.send_generation.get(), generation);
+
+        for section in 0..FULL_RESEND_SECTIONS as i32 {
+            let pos = BlockPos::new(3, 16 * section + 2, 5);
+            level
+                .set_block_state(&pos, Block::GLOWSTONE.default_state.id)
+                .await;
+            level.light_engine.queue_lighting_update(pos);
+        }
+        level.light_engine.flush_lighting_updates(&level).await;
+        assert_ne!(chunk.send_generation.get(), generation);
+    }
+}
@@ -2,6 +2,7 @@ use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::block::entities::BlockEntity;
+use crate::tick::TickPriority;
 use crate::{BlockStateId, inventory::Inventory};
 use bitflags::bitflags;
 use pumpkin_data::entity::EntityType;
@@ -45,6 +46,8 @@ bitflags! {
         /// Prevents the `on_added` logic from firing for the new block state
         /// Use this to avoid recursive placement loops or unnecessary initialization
         const SKIP_BLOCK_ADDED_CALLBACK             = 0b000_1000_0000;
+        /// Breaks the block even where players normally can't, like on the Nether's ceiling
+        const FORCE_BREAK                           = 0b001_0000_0000;
     }
 }
 
@@ -90,6 +93,16 @@ pub trait SimpleWorld: BlockAccessor + Send + Sync {
         position: Vector3<f64>,
     ) -> WorldFuture<'static, ()>;
 
+    /// Whether a living player that isn't a spectator is within `range` of `position`.
+    fn is_player_in_range(&self, position: Vector3<f64>, range: f64) -> WorldFuture<'_, bool>;
+
+    /// Counts the entities of `entity_type` intersecting `bounding_box`.
+    fn count_entities_of_type(
+        &self,
+        entity_type: &'static EntityType,
+        bounding_box: BoundingBox,
+    ) -> WorldFuture<'_, usize>;
+
     fn add_synced_block_event(&self, pos: BlockPos, r#type: u8, data: u8) -> WorldFuture<'_, ()>;
 
     fn sync_world_event(
@@ -108,6 +121,15 @@ pub trait SimpleWorld: BlockAccessor + Send + Sync {
 
     fn get_world_age(&self) -> WorldFuture<'_, i64>;
 
+    /// Schedules a tick for `block` at `position` in `delay` ticks.
+    fn schedule_block_tick<'a>(
+        &'a self,
+        block: &'a Block,
+        position: BlockPos,
+        delay: u8,
+        priority: TickPriority,
+    ) -> WorldFuture<'a, ()>;
+
     fn play_sound<'a>(
         &'a self,
         sound: Sound,
@@ -178,3 +200,26 @@ pub trait BlockAccessor: Send + Sync {
         position: &'a BlockPos,
     ) -> Pin<Box<dyn Future<Output = (&'static Block, &'static BlockState)> + Send + 'a>>;
 }
+
+#[cfg(test)]
+pub(crate) mod test {
+    use pumpkin_data::{Block, BlockState};
+    use pumpkin_util::math::position::BlockPos;
+
+    use super::{BlockAccessor, BlockRegistryExt};
+
+    /// Lets every block be placed anywhere, for levels created without the server's registry.
+    pub(crate) struct BlockRegistry;
+
+    impl BlockRegistryExt for BlockRegistry {
+        fn can_place_at(
+            &self,
+            _block: &Block,
+            _state: &BlockState,
+            _block_accessor: &dyn BlockAccessor,
+            _block_pos: &BlockPos,
+        ) -> bool {
+            true
+        }
+    }
+}
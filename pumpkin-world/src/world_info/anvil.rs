@@ -9,6 +9,7 @@ use tracing::error;
 use flate2::{Compression, read::GzDecoder, write::GzEncoder};
 use serde::{Deserialize, Serialize};
 
+use crate::data::migration::PUMPKIN_DATA_VERSION;
 use crate::world_info::{
     MAXIMUM_SUPPORTED_LEVEL_VERSION, MAXIMUM_SUPPORTED_WORLD_DATA_VERSION,
     MINIMUM_SUPPORTED_LEVEL_VERSION, MINIMUM_SUPPORTED_WORLD_DATA_VERSION,
@@ -92,7 +93,11 @@ impl WorldInfoReader for AnvilLevelInfo {
         let info = pumpkin_nbt::from_bytes::<LevelDat>(Cursor::new(buf))
             .map_err(|e| WorldInfoError::DeserializationError(e.to_string()))?;
 
-        // TODO: check version
+        if info.data.pumpkin_data_version > PUMPKIN_DATA_VERSION {
+            return Err(WorldInfoError::UnsupportedPumpkinDataVersion(
+                info.data.pumpkin_data_version,
+            ));
+        }
 
         Ok(info.data)
     }
@@ -110,6 +115,7 @@ impl WorldInfoWriter for AnvilLevelInfo {
             .expect("Time went backwards");
         let mut level_data = info.clone();
         level_data.last_played = since_the_epoch.as_millis() as i64;
+        level_data.pumpkin_data_version = PUMPKIN_DATA_VERSION;
         let level = LevelDat { data: level_data };
 
         // open file
@@ -136,6 +142,7 @@ pub struct LevelDat {
 mod test {
 
     use std::{
+        collections::HashMap,
         fs,
         io::{Cursor, Read},
         sync::LazyLock,
@@ -234,6 +241,10 @@ mod test {
             spawn_z: 160,
             spawn_yaw: 0.0,
             spawn_pitch: 0.0,
+            time: 1727,
+            scheduled_events: Vec::new(),
+            sleep_percentages: HashMap::new(),
+            pumpkin_data_version: 0,
             level_version: 19133,
             world_version: WorldVersion {
                 name: "1.21.4".to_string(),
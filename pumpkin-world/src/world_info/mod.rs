@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use crate::CURRENT_MC_VERSION;
+use crate::data::migration::PUMPKIN_DATA_VERSION;
 use pumpkin_data::game_rules::GameRuleRegistry;
 use pumpkin_util::{Difficulty, serde_enum_as_integer, world_seed::Seed};
 use serde::{Deserialize, Serialize};
@@ -76,6 +77,20 @@ pub struct LevelData {
     pub spawn_yaw: f32,
     #[serde(default)]
     pub spawn_pitch: f32,
+    /// The game time in ticks, which keeps counting even if the time of day doesn't advance.
+    #[serde(default)]
+    pub time: i64,
+    /// Callbacks scheduled with `/schedule` (or by plugins) that haven't run yet.
+    #[serde(default)]
+    pub scheduled_events: Vec<ScheduledEvent>,
+    /// Per-world overrides of the `playersSleepingPercentage` game rule, keyed by dimension
+    /// name. The game rules are shared by all worlds.
+    #[serde(default)]
+    pub sleep_percentages: HashMap<String, i32>,
+    /// The version of Pumpkin's own data format the world was last saved with. Worlds saved by
+    /// a newer Pumpkin version are refused instead of being misread.
+    #[serde(default)]
+    pub pumpkin_data_version: i32,
     #[serde(rename = "Version", default)]
     pub world_version: WorldVersion,
     #[serde(rename = "version", default = "default_level_version")]
@@ -125,6 +140,25 @@ fn default_level_version() -> i32 {
     MAXIMUM_SUPPORTED_LEVEL_VERSION
 }
 
+/// A callback waiting for the game time to reach `trigger_time`.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScheduledEvent {
+    pub name: String,
+    pub trigger_time: i64,
+    pub callback: ScheduledEventCallback,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScheduledEventCallback {
+    /// Always `minecraft:function`; vanilla also knows `minecraft:function_tag`.
+    #[serde(rename = "Type")]
+    pub callback_type: String,
+    /// The ID the callback was scheduled under.
+    pub name: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct WorldGenSettings {
     // the numerical seed of the world
@@ -323,6 +357,10 @@ impl LevelData {
             spawn_z: 0,
             spawn_yaw: 0.0,
             spawn_pitch: 0.0,
+            time: 0,
+            scheduled_events: Vec::new(),
+            sleep_percentages: HashMap::new(),
+            pumpkin_data_version: PUMPKIN_DATA_VERSION,
             world_version: WorldVersion::default(),
             level_version: MAXIMUM_SUPPORTED_LEVEL_VERSION,
         }
@@ -346,6 +384,10 @@ pub enum WorldInfoError {
     UnsupportedDataVersion(i32),
     #[error("Unsupported world level version: {0}")]
     UnsupportedLevelVersion(i32),
+    #[error(
+        "The world was saved by a newer Pumpkin version (data version {0}, this version supports up to {PUMPKIN_DATA_VERSION})"
+    )]
+    UnsupportedPumpkinDataVersion(i32),
 }
 
 impl From<std::io::Error> for WorldInfoError {
@@ -0,0 +1,153 @@
+use std::hint::black_box;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use pumpkin::world::block_state_cache::BlockStateCache;
+use pumpkin_config::world::LevelConfig;
+use pumpkin_data::Block;
+use pumpkin_data::chunk::ChunkStatus;
+use pumpkin_data::dimension::Dimension;
+use pumpkin_util::math::{position::BlockPos, vector2::Vector2};
+use pumpkin_world::chunk::format::LightContainer;
+use pumpkin_world::chunk::{ChunkData, ChunkHeightmaps, ChunkLight, ChunkSections, SendGeneration};
+use pumpkin_world::level::Level;
+use pumpkin_world::tick::scheduler::ChunkTickScheduler;
+use tokio::runtime::Runtime;
+
+/// The length of the redstone line that is updated. It starts at x = 1, so every read stays in
+/// the loaded chunk.
+const WIRE_LENGTH: i32 = 14;
+const WIRE_Y: i32 = 64;
+
+/// Adds an empty chunk with a line of redstone wire to the level.
+fn load_wire_chunk(level: &Level) {
+    let dimension = Dimension::OVERWORLD;
+    let section_count = (dimension.height / 16) as usize;
+    let chunk = Arc::new(ChunkData {
+        section: ChunkSections::new(section_count, dimension.min_y),
+        heightmap: std::sync::Mutex::new(ChunkHeightmaps::new(dimension.height)),
+        x: 0,
+        z: 0,
+        block_ticks: ChunkTickScheduler::default(),
+        fluid_ticks: ChunkTickScheduler::default(),
+        block_entities: std::sync::Mutex::default(),
+        light_engine: std::sync::Mutex::new(ChunkLight {
+            sky_light: vec![LightContainer::new_empty(15); section_count].into_boxed_slice(),
+            block_light: vec![LightContainer::new_empty(0); section_count].into_boxed_slice(),
+        }),
+        light_populated: AtomicBool::new(true),
+        status: ChunkStatus::Full,
+        dirty: AtomicBool::new(false),
+        send_generation: SendGeneration::new(),
+    });
+    for x in 1..=WIRE_LENGTH {
+        chunk.section.set_block_absolute_y(
+            x as usize,
+            WIRE_Y,
+            8,
+            Block::REDSTONE_WIRE.default_state.id,
+        );
+    }
+    level.loaded_chunks.insert(Vector2::new(0, 0), chunk);
+}
+
+/// The positions read while a signal runs down the wire: every wire reads itself and its
+/// neighbors, and the blocks above and below its horizontal neighbors to find connections.
+fn wire_update_reads() -> Vec<BlockPos> {
+    let mut reads = Vec::new();
+    for x in 1..=WIRE_LENGTH {
+        let wire = BlockPos::new(x, WIRE_Y, 8);
+        reads.push(wire);
+        for (dx, dy, dz) in [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ] {
+            reads.push(BlockPos::new(x + dx, WIRE_Y + dy, 8 + dz));
+        }
+        for (dx, dz) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            reads.push(BlockPos::new(x + dx, WIRE_Y + 1, 8 + dz));
+            reads.push(BlockPos::new(x + dx, WIRE_Y - 1, 8 + dz));
+        }
+    }
+    reads
+}
+
+/// Does the reads of one tick through a cleared cache and returns how many reached the chunk.
+async fn cached_tick(level: &Arc<Level>, cache: &BlockStateCache, reads: &[BlockPos]) -> usize {
+    cache.clear();
+    let chunk_reads = AtomicUsize::new(0);
+    for position in reads {
+        black_box(
+            cache
+                .get_or_read(position, async {
+                    chunk_reads.fetch_add(1, Ordering::Relaxed);
+                    level.get_block_state(position).await.0
+                })
+                .await,
+        );
+    }
+    chunk_reads.into_inner()
+}
+
+fn bench_wire_update(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let level = runtime.block_on(async {
+        Level::from_root_folder(
+            &LevelConfig::default(),
+            dir.path().join("overworld"),
+            pumpkin::block::registry::default_registry(),
+            0,
+            Dimension::OVERWORLD,
+        )
+    });
+    load_wire_chunk(&level);
+    let reads = wire_update_reads();
+    let cache = BlockStateCache::default();
+
+    let chunk_reads = runtime.block_on(cached_tick(&level, &cache, &reads));
+    println!(
+        "{} reads per wire update, {chunk_reads} of them reach the chunk with the cache ({:.1}% \
+         hit rate)",
+        reads.len(),
+        100.0 * (1.0 - chunk_reads as f64 / reads.len() as f64),
+    );
+
+    let mut group = c.benchmark_group("redstone wire update reads");
+    group.bench_function("chunk", |b| {
+        b.iter_custom(|iters| {
+            runtime.block_on(async {
+                let start = Instant::now();
+                for _ in 0..iters {
+                    for position in &reads {
+                        black_box(level.get_block_state(position).await);
+                    }
+                }
+                start.elapsed()
+            })
+        });
+    });
+    group.bench_function("cached", |b| {
+        b.iter_custom(|iters| {
+            runtime.block_on(async {
+                let mut total = Duration::ZERO;
+                for _ in 0..iters {
+                    let start = Instant::now();
+                    black_box(cached_tick(&level, &cache, &reads).await);
+                    total += start.elapsed();
+                }
+                total
+            })
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_wire_update);
+criterion_main!(benches);
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use pumpkin::net::java::JavaClient;
+use pumpkin_protocol::codec::var_int::VarInt;
+use pumpkin_protocol::java::client::play::CUpdateEntityPos;
+use pumpkin_util::math::vector3::Vector3;
+use tokio::io::AsyncReadExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::runtime::Runtime;
+
+const ENTITIES: i32 = 200;
+/// Ticks averaged for the tick-time report.
+const REPORT_TICKS: u32 = 200;
+/// The time budget of one tick at 20 TPS.
+const TICK_BUDGET: Duration = Duration::from_millis(50);
+
+/// A connected client whose outgoing packet task is running, and a counter of the packets the
+/// other end of the connection received.
+struct Connection {
+    client: JavaClient,
+    received: Arc<AtomicUsize>,
+}
+
+async fn connect(listener: &TcpListener, id: u64) -> Connection {
+    let (remote, accepted) = tokio::join!(
+        TcpStream::connect(listener.local_addr().unwrap()),
+        listener.accept()
+    );
+    let (stream, address) = accepted.unwrap();
+    let mut client = JavaClient::new(stream, address, id);
+    client.start_outgoing_packet_task();
+
+    let received = Arc::new(AtomicUsize::new(0));
+    tokio::spawn(count_packets(remote.unwrap(), received.clone()));
+    Connection { client, received }
+}
+
+/// Reads uncompressed, unencrypted frames until the connection closes.
+async fn count_packets(mut stream: TcpStream, received: Arc<AtomicUsize>) {
+    let mut buf = Vec::new();
+    let mut chunk = [0; 64 * 1024];
+    loop {
+        let Ok(read) = stream.read(&mut chunk).await else {
+            return;
+        };
+        if read == 0 {
+            return;
+        }
+        buf.extend_from_slice(&chunk[..read]);
+
+        let mut start = 0;
+        while let Some((length, header)) = read_frame_length(&buf[start..]) {
+            if buf.len() - start < header + length {
+                break;
+            }
+            start += header + length;
+            received.fetch_add(1, Ordering::Relaxed);
+        }
+        buf.drain(..start);
+    }
+}
+
+/// Returns the frame length and the size of the `VarInt` it is encoded in.
+fn read_frame_length(buf: &[u8]) -> Option<(usize, usize)> {
+    let mut value = 0usize;
+    for (i, byte) in buf.iter().take(5).enumerate() {
+        value |= usize::from(byte & 0x7F) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+/// Queues one movement packet per entity to every client the way the world tick does, and
+/// returns how long the queuing took. Waits, untimed, until every packet has arrived, so no
+/// queue fills up between iterations.
+async fn broadcast_tick(connections: &[Connection], sent: &mut usize, shared: bool) -> Duration {
+    let start = Instant::now();
+    for entity_id in 0..ENTITIES {
+        let packet = CUpdateEntityPos::new(VarInt(entity_id), Vector3::new(1, 0, -1), true);
+        if shared {
+            let packet = Arc::new(packet);
+            for connection in connections {
+                connection
+                    .client
+                    .enqueue_packet_shared(packet.clone())
+                    .await;
+            }
+        } else {
+            let version = connections[0].client.version.load();
+            let data = JavaClient::serialize_packet_for_version(&packet, version).unwrap();
+            for connection in connections {
+                connection.client.enqueue_packet_data(data.clone()).await;
+            }
+        }
+    }
+    let elapsed = start.elapsed();
+
+    *sent += ENTITIES as usize;
+    for connection in connections {
+        while connection.received.load(Ordering::Relaxed) < *sent {
+            assert!(!connection.client.is_closed(), "client was disconnected");
+            tokio::task::yield_now().await;
+        }
+    }
+    elapsed
+}
+
+/// Prints the average time the tick spends on the broadcasts, for both ways of queuing, and how
+/// much of the tick budget sharing the packets saves.
+async fn report_tick_times(connections: &[Connection], sent: &mut usize) {
+    let mut average = [Duration::ZERO; 2];
+    for (shared, average) in [false, true].into_iter().zip(&mut average) {
+        let mut total = Duration::ZERO;
+        for _ in 0..REPORT_TICKS {
+            total += broadcast_tick(connections, sent, shared).await;
+        }
+        *average = total / REPORT_TICKS;
+    }
+    let [serialized, shared] = average;
+    println!(
+        "{ENTITIES} entities, {} players: {serialized:?} per tick serialized on tick ({:.2}% of the \
+         budget), {shared:?} shared ({:.2}%), {:.1}% less tick time",
+        connections.len(),
+        100.0 * serialized.as_secs_f64() / TICK_BUDGET.as_secs_f64(),
+        100.0 * shared.as_secs_f64() / TICK_BUDGET.as_secs_f64(),
+        100.0 * (1.0 - shared.as_secs_f64() / serialized.as_secs_f64()),
+    );
+}
+
+fn bench_entity_movement(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("200 entity movement broadcast");
+
+    for players in [1, 10, 50] {
+        let (connections, mut sent) = runtime.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let mut connections = Vec::new();
+            for id in 0..players {
+                connections.push(connect(&listener, id).await);
+            }
+            (connections, 0)
+        });
+
+        for (name, shared) in [("serialized on tick", false), ("shared", true)] {
+            group.bench_function(BenchmarkId::new(name, players), |b| {
+                b.iter_custom(|iters| {
+                    runtime.block_on(async {
+                        let mut total = Duration::ZERO;
+                        for _ in 0..iters {
+                            total += broadcast_tick(&connections, &mut sent, shared).await;
+                        }
+                        total
+                    })
+                });
+            });
+        }
+
+        runtime.block_on(report_tick_times(&connections, &mut sent));
+
+        for connection in &connections {
+            connection.client.close();
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_entity_movement);
+criterion_main!(benches);
@@ -0,0 +1,84 @@
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use pumpkin::world::entity_sections::{EntitySectionIndex, SectionIndexed};
+use pumpkin_util::math::{
+    boundingbox::{BoundingBox, EntityDimensions},
+    vector3::Vector3,
+};
+use pumpkin_util::random::{RandomImpl, xoroshiro128::Xoroshiro};
+
+#[derive(Clone, Copy)]
+struct Mob {
+    id: i32,
+    position: Vector3<f64>,
+}
+
+impl SectionIndexed for Mob {
+    fn index_id(&self) -> i32 {
+        self.id
+    }
+
+    fn index_position(&self) -> Vector3<f64> {
+        self.position
+    }
+
+    fn index_bounding_box(&self) -> BoundingBox {
+        BoundingBox::new_from_pos(
+            self.position.x,
+            self.position.y,
+            self.position.z,
+            &EntityDimensions::new(0.6, 1.8, 1.62),
+        )
+    }
+}
+
+/// Spreads `count` mobs at a constant density, so each query box finds about as many mobs
+/// however many there are in total.
+fn spread_mobs(count: i32) -> Vec<Mob> {
+    let mut rng = Xoroshiro::from_seed(0);
+    let side = f64::from(count).sqrt() * 4.0;
+    (0..count)
+        .map(|id| Mob {
+            id,
+            position: Vector3::new(
+                rng.next_f64() * side,
+                64.0 + rng.next_f64() * 16.0,
+                rng.next_f64() * side,
+            ),
+        })
+        .collect()
+}
+
+fn bench_box_queries(c: &mut Criterion) {
+    let mut group = c.benchmark_group("entities in box");
+    let aabb = BoundingBox::new(Vector3::new(8.0, 64.0, 8.0), Vector3::new(16.0, 72.0, 16.0));
+
+    for count in [100, 1_000, 10_000] {
+        let mobs = spread_mobs(count);
+        let index = EntitySectionIndex::default();
+        for mob in &mobs {
+            index.insert(*mob);
+        }
+
+        group.bench_with_input(
+            BenchmarkId::new("section index", count),
+            &aabb,
+            |b, aabb| {
+                b.iter(|| index.get_in_box(black_box(aabb)));
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("every entity", count), &aabb, |b, aabb| {
+            b.iter(|| {
+                mobs.iter()
+                    .filter(|mob| mob.index_bounding_box().intersects(black_box(aabb)))
+                    .copied()
+                    .collect::<Vec<_>>()
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_box_queries);
+criterion_main!(benches);
@@ -0,0 +1,35 @@
+use pumpkin_data::block_properties::{BlockProperties, BubbleColumnLikeProperties};
+use pumpkin_macros::pumpkin_block;
+
+use crate::block::{BlockBehaviour, BlockFuture, OnEntityCollisionArgs};
+
+#[pumpkin_block("minecraft:bubble_column")]
+pub struct BubbleColumnBlock;
+
+impl BlockBehaviour for BubbleColumnBlock {
+    fn on_entity_collision<'a>(&'a self, args: OnEntityCollisionArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            // Columns above magma blocks drag entities down, the ones above soul sand push them up
+            let drag = BubbleColumnLikeProperties::from_state_id(args.state.id, args.block).drag;
+            let at_surface = args
+                .world
+                .get_block_state(&args.position.up())
+                .await
+                .is_air();
+
+            let entity = args.entity.get_entity();
+            let mut velocity = entity.velocity.load();
+            velocity.y = match (at_surface, drag) {
+                (true, true) => (velocity.y - 0.03).max(-0.9),
+                (true, false) => (velocity.y + 0.1).min(1.8),
+                (false, true) => (velocity.y - 0.03).max(-0.3),
+                (false, false) => (velocity.y + 0.06).min(0.7),
+            };
+            entity.velocity.store(velocity);
+
+            if !at_surface && let Some(living) = args.entity.get_living_entity() {
+                living.fall_distance.store(0.0);
+            }
+        })
+    }
+}
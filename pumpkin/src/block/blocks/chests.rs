@@ -271,8 +271,10 @@ impl BlockBehaviour for CopperChestBlock {
             }
 
             // Only oxidize if no players are viewing the chest
-            if let Some(block_entity) = args.world.get_block_entity(args.position).await
-                && let Some(chest_entity) = block_entity.as_any().downcast_ref::<ChestBlockEntity>()
+            if let Some(chest_entity) = args
+                .world
+                .get_block_entity_data::<ChestBlockEntity>(args.position)
+                .await
                 && chest_entity.get_viewer_count() > 0
             {
                 return;
@@ -464,11 +466,10 @@ impl BlockBehaviour for TrappedChestBlock {
             use pumpkin_world::block::entities::trapped_chest::TrappedChestBlockEntity;
 
             // Get viewer count from this chest
-            let viewer_count = if let Some(block_entity) =
-                args.world.get_block_entity(args.position).await
-                && let Some(trapped_chest) = block_entity
-                    .as_any()
-                    .downcast_ref::<TrappedChestBlockEntity>()
+            let viewer_count = if let Some(trapped_chest) = args
+                .world
+                .get_block_entity_data::<TrappedChestBlockEntity>(args.position)
+                .await
             {
                 trapped_chest.get_viewer_count()
             } else {
@@ -47,16 +47,16 @@ impl BlockBehaviour for ChiseledBookshelfBlock {
 
             if let Some(slot) = Self::get_slot_for_hit(args.hit, properties.facing) {
                 if Self::is_slot_used(properties, slot) {
-                    if let Some(block_entity) = args.world.get_block_entity(args.position).await
-                        && let Some(block_entity) = block_entity
-                            .as_any()
-                            .downcast_ref::<ChiseledBookshelfBlockEntity>()
+                    if let Some(block_entity) = args
+                        .world
+                        .get_block_entity_data::<ChiseledBookshelfBlockEntity>(args.position)
+                        .await
                     {
                         Self::try_remove_book(
                             args.world,
                             args.player,
                             args.position,
-                            block_entity,
+                            &block_entity,
                             properties,
                             slot,
                         )
@@ -91,16 +91,16 @@ impl BlockBehaviour for ChiseledBookshelfBlock {
             if let Some(slot) = Self::get_slot_for_hit(args.hit, properties.facing) {
                 if Self::is_slot_used(properties, slot) {
                     return BlockActionResult::PassToDefaultBlockAction;
-                } else if let Some(block_entity) = args.world.get_block_entity(args.position).await
-                    && let Some(block_entity) = block_entity
-                        .as_any()
-                        .downcast_ref::<ChiseledBookshelfBlockEntity>()
+                } else if let Some(block_entity) = args
+                    .world
+                    .get_block_entity_data::<ChiseledBookshelfBlockEntity>(args.position)
+                    .await
                 {
                     Self::try_add_book(
                         args.world,
                         args.player,
                         args.position,
-                        block_entity,
+                        &block_entity,
                         properties,
                         slot,
                         args.item_stack,
@@ -126,10 +126,10 @@ impl BlockBehaviour for ChiseledBookshelfBlock {
         args: GetComparatorOutputArgs<'a>,
     ) -> BlockFuture<'a, Option<u8>> {
         Box::pin(async move {
-            if let Some(block_entity) = args.world.get_block_entity(args.position).await
-                && let Some(block_entity) = block_entity
-                    .as_any()
-                    .downcast_ref::<ChiseledBookshelfBlockEntity>()
+            if let Some(block_entity) = args
+                .world
+                .get_block_entity_data::<ChiseledBookshelfBlockEntity>(args.position)
+                .await
             {
                 return Some((block_entity.last_interacted_slot.load(Ordering::Relaxed) + 1) as u8);
             }
@@ -1,5 +1,6 @@
 use crate::block::{BlockBehaviour, BlockFuture, OnEntityCollisionArgs};
-use crate::entity::EntityBase;
+use crate::entity::Entity;
+use pumpkin_data::BlockState;
 use pumpkin_data::effect::StatusEffect;
 use pumpkin_macros::pumpkin_block;
 use pumpkin_util::math::vector3::Vector3;
@@ -7,18 +8,76 @@ use pumpkin_util::math::vector3::Vector3;
 #[pumpkin_block("minecraft:cobweb")]
 pub struct CobwebBlock;
 
+impl CobwebBlock {
+    async fn slow_down(entity: &Entity, state: &BlockState) {
+        let vec = if let Some(living) = entity.get_living_entity()
+            && living.has_effect(&StatusEffect::WEAVING).await
+        {
+            Vector3::new(0.5, 0.25, 0.5)
+        } else {
+            Vector3::new(0.25, 0.05, 0.25)
+        };
+        entity.slow_movement(state, vec).await;
+    }
+}
+
 impl BlockBehaviour for CobwebBlock {
     fn on_entity_collision<'a>(&'a self, args: OnEntityCollisionArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
-            let entity = args.entity.get_entity();
-            let vec = if let Some(living) = entity.get_living_entity()
-                && living.has_effect(&StatusEffect::WEAVING).await
-            {
-                Vector3::new(0.5, 0.25, 0.5)
-            } else {
-                Vector3::new(0.25, 0.05, 0.25)
-            };
-            entity.slow_movement(args.state, vec).await;
+            Self::slow_down(args.entity.get_entity(), args.state).await;
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arc_swap::ArcSwap;
+    use pumpkin_data::Block;
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::vector3::Vector3;
+    use pumpkin_util::world_seed::Seed;
+    use pumpkin_world::world_info::LevelData;
+    use tempfile::tempdir;
+
+    use super::CobwebBlock;
+    use crate::entity::EntityBase;
+    use crate::entity::r#type::from_type;
+    use crate::world::test::test_world;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn mobs_but_not_spiders_are_slowed_by_cobwebs() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        let cobweb = Block::COBWEB.default_state;
+
+        let zombie = from_type(
+            &EntityType::ZOMBIE,
+            Vector3::new(0.5, 64.0, 0.5),
+            &world,
+            uuid::Uuid::new_v4(),
+        )
+        .await;
+        CobwebBlock::slow_down(zombie.get_entity(), cobweb).await;
+        assert_eq!(
+            zombie.get_entity().movement_multiplier.load(),
+            Vector3::new(0.25, 0.05, 0.25)
+        );
+
+        let spider = from_type(
+            &EntityType::SPIDER,
+            Vector3::new(0.5, 64.0, 0.5),
+            &world,
+            uuid::Uuid::new_v4(),
+        )
+        .await;
+        CobwebBlock::slow_down(spider.get_entity(), cobweb).await;
+        assert_eq!(
+            spider.get_entity().movement_multiplier.load(),
+            Vector3::default()
+        );
+    }
+}
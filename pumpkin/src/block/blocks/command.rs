@@ -6,9 +6,7 @@ use pumpkin_data::{
 };
 use pumpkin_util::{GameMode, PermissionLvl, math::position::BlockPos};
 use pumpkin_world::{
-    BlockStateId,
-    block::entities::{BlockEntity, command_block::CommandBlockEntity},
-    tick::TickPriority,
+    BlockStateId, block::entities::command_block::CommandBlockEntity, tick::TickPriority,
 };
 use tracing::warn;
 
@@ -62,11 +60,13 @@ impl CommandBlock {
         let Some(before) = Self::get_relative_facing(world, pos, facing.opposite()).await else {
             return false;
         };
-        let Some(before_entity) = world.get_block_entity(&before.0).await else {
+        let Some(command_entity) = world
+            .get_block_entity_data::<CommandBlockEntity>(&before.0)
+            .await
+        else {
             warn!("Command block has no matching entity");
             return false;
         };
-        let command_entity: &CommandBlockEntity = before_entity.as_any().downcast_ref().unwrap();
 
         command_entity.success_count.load(Ordering::Relaxed) > 0
     }
@@ -102,17 +102,16 @@ impl CommandBlock {
         else {
             return;
         };
-        let Some(behind_entity) = world.get_block_entity(&behind.0).await else {
+        let Some(behind_entity) = world
+            .get_block_entity_data::<CommandBlockEntity>(&behind.0)
+            .await
+        else {
             warn!(
                 "Command Block exists at {} with no matching block entity!",
                 behind.0
             );
             return;
         };
-        let behind_entity: &CommandBlockEntity = behind_entity
-            .as_any()
-            .downcast_ref()
-            .expect("behind should always be a command block");
 
         if behind_entity.success_count.load(Ordering::Relaxed) > 0 {
             world
@@ -124,14 +123,13 @@ impl CommandBlock {
     async fn execute(
         server: &Arc<Server>,
         world: Arc<World>,
-        block_entity: Arc<dyn BlockEntity>,
+        command_entity: Arc<CommandBlockEntity>,
         command: &str,
     ) {
         let command_blocks_work = { world.level_info.load().game_rules.command_blocks_work };
         if !command_blocks_work {
             return;
         }
-        let command_entity: &CommandBlockEntity = block_entity.as_any().downcast_ref().unwrap();
         if command.is_empty() {
             command_entity.success_count.store(0, Ordering::Release);
         } else {
@@ -140,7 +138,7 @@ impl CommandBlock {
                 .read()
                 .await
                 .handle_command(
-                    &crate::command::CommandSender::CommandBlock(block_entity, world),
+                    &crate::command::CommandSender::CommandBlock(command_entity, world),
                     server,
                     command,
                 )
@@ -167,12 +165,14 @@ impl CommandBlock {
             if block.id != Block::CHAIN_COMMAND_BLOCK.id {
                 break;
             }
-            let Some(block_entity) = world.get_block_entity(&pos).await else {
+            let Some(command_entity) = world
+                .get_block_entity_data::<CommandBlockEntity>(&pos)
+                .await
+            else {
                 warn!("Missing command block entity");
                 break;
             };
 
-            let command_entity: &CommandBlockEntity = block_entity.as_any().downcast_ref().unwrap();
             let powered = command_entity.powered.load(Ordering::Relaxed);
             let auto = command_entity.auto.load(Ordering::Relaxed);
             let state_id = world.get_block_state_id(&pos).await;
@@ -182,8 +182,7 @@ impl CommandBlock {
                 let conditions_met = Self::conditions_met(&world, &pos, direction).await;
                 if conditions_met {
                     let command = command_entity.command.lock().await;
-                    let entity = world.get_block_entity(&pos).await.unwrap();
-                    Self::execute(server, world.clone(), entity, &command).await;
+                    Self::execute(server, world.clone(), command_entity.clone(), &command).await;
                 } else if props.conditional {
                     command_entity.success_count.store(0, Ordering::Release);
                 }
@@ -242,19 +241,15 @@ impl BlockBehaviour for CommandBlock {
             if !command_blocks_work {
                 return;
             }
-            if let Some(block_entity) = args.world.get_block_entity(args.position).await {
-                if block_entity.resource_location() != CommandBlockEntity::ID {
-                    return;
-                }
-                let command_entity = block_entity
-                    .as_any()
-                    .downcast_ref::<CommandBlockEntity>()
-                    .unwrap();
-
+            if let Some(command_entity) = args
+                .world
+                .get_block_entity_data::<CommandBlockEntity>(args.position)
+                .await
+            {
                 Self::update(
                     args.world,
                     args.block,
-                    command_entity,
+                    &command_entity,
                     args.position,
                     block_receives_redstone_power(args.world, args.position).await,
                 )
@@ -270,14 +265,13 @@ impl BlockBehaviour for CommandBlock {
             if !command_blocks_work {
                 return;
             }
-            let Some(block_entity) = args.world.get_block_entity(args.position).await else {
+            let Some(command_entity) = args
+                .world
+                .get_block_entity_data::<CommandBlockEntity>(args.position)
+                .await
+            else {
                 return;
             };
-            if block_entity.resource_location() != CommandBlockEntity::ID {
-                return;
-            }
-
-            let command_entity: &CommandBlockEntity = block_entity.as_any().downcast_ref().unwrap();
             let Some(server) = args.world.server.upgrade() else {
                 return;
             };
@@ -289,7 +283,7 @@ impl BlockBehaviour for CommandBlock {
             Self::execute(
                 &server,
                 args.world.clone(),
-                block_entity.clone(),
+                command_entity.clone(),
                 &command_entity.command.lock().await,
             )
             .await;
@@ -347,20 +341,17 @@ impl BlockBehaviour for CommandBlock {
         args: crate::block::GetComparatorOutputArgs<'a>,
     ) -> BlockFuture<'a, Option<u8>> {
         Box::pin(async {
-            let entity = args.world.get_block_entity(args.position).await;
+            let entity = args
+                .world
+                .get_block_entity_data::<CommandBlockEntity>(args.position)
+                .await;
 
             entity.map_or_else(
                 || {
                     warn!("Command block is missing its corresponding block entity");
                     None
                 },
-                |entity| {
-                    let command_block_entity: &CommandBlockEntity =
-                        entity.as_any().downcast_ref().expect(
-                            "Block entity command block's position should be a matching entity",
-                        );
-                    Some(command_block_entity.success_count.load(Ordering::Acquire) as u8)
-                },
+                |entity| Some(entity.success_count.load(Ordering::Acquire) as u8),
             )
         })
     }
@@ -75,10 +75,10 @@ impl BlockBehaviour for EnderChestBlock {
                 return BlockActionResult::Success;
             }
 
-            if let Some(block_entity) = args.world.get_block_entity(args.position).await
-                && let Some(block_entity) = block_entity
-                    .as_any()
-                    .downcast_ref::<EnderChestBlockEntity>()
+            if let Some(block_entity) = args
+                .world
+                .get_block_entity_data::<EnderChestBlockEntity>(args.position)
+                .await
             {
                 let inventory = args.player.ender_chest_inventory();
                 inventory.set_tracker(block_entity.get_tracker()).await;
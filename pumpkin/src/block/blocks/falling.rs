@@ -1,9 +1,6 @@
-use crate::{
-    block::{
-        BlockBehaviour, BlockFuture, BlockMetadata, GetStateForNeighborUpdateArgs,
-        OnScheduledTickArgs, PlacedArgs,
-    },
-    entity::falling::FallingEntity,
+use crate::block::{
+    BlockBehaviour, BlockFuture, BlockMetadata, GetStateForNeighborUpdateArgs, OnScheduledTickArgs,
+    PlacedArgs,
 };
 use pumpkin_data::{
     Block, BlockState,
@@ -24,7 +21,29 @@ impl FallingBlock {
 
 impl BlockMetadata for FallingBlock {
     fn ids() -> Box<[u16]> {
-        [Block::GRAVEL.id, Block::SAND.id, Block::RED_SAND.id].into()
+        // TODO: concrete powder should harden when it touches water
+        [
+            Block::GRAVEL.id,
+            Block::SAND.id,
+            Block::RED_SAND.id,
+            Block::WHITE_CONCRETE_POWDER.id,
+            Block::ORANGE_CONCRETE_POWDER.id,
+            Block::MAGENTA_CONCRETE_POWDER.id,
+            Block::LIGHT_BLUE_CONCRETE_POWDER.id,
+            Block::YELLOW_CONCRETE_POWDER.id,
+            Block::LIME_CONCRETE_POWDER.id,
+            Block::PINK_CONCRETE_POWDER.id,
+            Block::GRAY_CONCRETE_POWDER.id,
+            Block::LIGHT_GRAY_CONCRETE_POWDER.id,
+            Block::CYAN_CONCRETE_POWDER.id,
+            Block::PURPLE_CONCRETE_POWDER.id,
+            Block::BLUE_CONCRETE_POWDER.id,
+            Block::BROWN_CONCRETE_POWDER.id,
+            Block::GREEN_CONCRETE_POWDER.id,
+            Block::RED_CONCRETE_POWDER.id,
+            Block::BLACK_CONCRETE_POWDER.id,
+        ]
+        .into()
     }
 }
 
@@ -52,12 +71,7 @@ impl BlockBehaviour for FallingBlock {
 
     fn on_scheduled_tick<'a>(&'a self, args: OnScheduledTickArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
-            let (block, state) = args.world.get_block_and_state(&args.position.down()).await;
-            if !Self::can_fall_through(state, block) || args.position.0.y < args.world.min_y {
-                return;
-            }
-            FallingEntity::replace_spawn(args.world, *args.position, args.block.default_state.id)
-                .await;
+            args.world.apply_gravity_to_block(args.position).await;
         })
     }
 }
@@ -138,7 +138,7 @@ impl FireBlock {
             }
 
             if block == &Block::TNT {
-                TNTBlock::prime(world, pos).await;
+                TNTBlock::prime(world, pos, None).await;
             }
         }
     }
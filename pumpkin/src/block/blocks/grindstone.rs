@@ -1,13 +1,24 @@
+use std::sync::Arc;
+
 use pumpkin_data::{
     Block, BlockDirection, HorizontalFacingExt,
     block_properties::{BlockFace, BlockProperties, GrindstoneLikeProperties},
+    translation,
+};
+use pumpkin_inventory::grindstone_screen_handler::GrindstoneScreenHandler;
+use pumpkin_inventory::player::player_inventory::PlayerInventory;
+use pumpkin_inventory::screen_handler::{
+    BoxFuture, InventoryPlayer, ScreenHandlerFactory, SharedScreenHandler,
 };
 use pumpkin_macros::pumpkin_block;
 use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
 use pumpkin_world::{BlockStateId, world::BlockAccessor};
+use tokio::sync::Mutex;
 
-use crate::block::CanPlaceAtArgs;
+use crate::block::registry::BlockActionResult;
 use crate::block::{BlockBehaviour, BlockFuture};
+use crate::block::{CanPlaceAtArgs, NormalUseArgs};
 use crate::block::{GetStateForNeighborUpdateArgs, OnPlaceArgs};
 
 use super::abstract_wall_mounting::WallMountedBlock;
@@ -16,6 +27,16 @@ use super::abstract_wall_mounting::WallMountedBlock;
 pub struct GrindstoneBlock;
 
 impl BlockBehaviour for GrindstoneBlock {
+    fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
+        Box::pin(async move {
+            args.player
+                .open_handled_screen(&GrindstoneScreenFactory, Some(*args.position))
+                .await;
+
+            BlockActionResult::Success
+        })
+    }
+
     fn on_place<'a>(&'a self, args: OnPlaceArgs<'a>) -> BlockFuture<'a, BlockStateId> {
         Box::pin(async move {
             let mut props =
@@ -66,3 +87,25 @@ impl WallMountedBlock for GrindstoneBlock {
         }
     }
 }
+
+struct GrindstoneScreenFactory;
+
+impl ScreenHandlerFactory for GrindstoneScreenFactory {
+    fn create_screen_handler<'a>(
+        &'a self,
+        sync_id: u8,
+        player_inventory: &'a Arc<PlayerInventory>,
+        _player: &'a dyn InventoryPlayer,
+    ) -> BoxFuture<'a, Option<SharedScreenHandler>> {
+        Box::pin(async move {
+            let handler = GrindstoneScreenHandler::new(sync_id, player_inventory).await;
+            let concrete_arc = Arc::new(Mutex::new(handler));
+
+            Some(concrete_arc as SharedScreenHandler)
+        })
+    }
+
+    fn get_display_name(&self) -> TextComponent {
+        TextComponent::translate(translation::CONTAINER_GRINDSTONE_TITLE, &[])
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::atomic::Ordering;
+
+use pumpkin_data::sound::Sound;
+use pumpkin_macros::pumpkin_block;
+use pumpkin_util::math::position::BlockPos;
+
+use crate::block::{BlockBehaviour, BlockFuture, OnEntityCollisionArgs, OnLandedUponArgs};
+use crate::entity::Entity;
+
+/// The vertical speed of an entity sliding down the side of a honey block
+const SLIDE_VELOCITY: f64 = -0.05;
+
+#[pumpkin_block("minecraft:honey_block")]
+pub struct HoneyBlock;
+
+impl HoneyBlock {
+    /// Whether the entity falls down along a side of the block while touching it.
+    fn is_sliding(position: &BlockPos, entity: &Entity) -> bool {
+        if entity.on_ground.load(Ordering::Relaxed) {
+            return false;
+        }
+        let pos = entity.pos.load();
+        if pos.y > f64::from(position.0.y) + 0.9375 - 1.0e-7 || entity.velocity.load().y >= -0.08 {
+            return false;
+        }
+        let dx = (f64::from(position.0.x) + 0.5 - pos.x).abs();
+        let dz = (f64::from(position.0.z) + 0.5 - pos.z).abs();
+        let edge = 0.4375 + f64::from(entity.width()) / 2.0;
+        dx + 1.0e-7 > edge || dz + 1.0e-7 > edge
+    }
+}
+
+impl BlockBehaviour for HoneyBlock {
+    fn on_entity_collision<'a>(&'a self, args: OnEntityCollisionArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let entity = args.entity.get_entity();
+            if !Self::is_sliding(args.position, entity) {
+                return;
+            }
+
+            let mut velocity = entity.velocity.load();
+            if velocity.y < -0.13 {
+                let factor = SLIDE_VELOCITY / velocity.y;
+                velocity.x *= factor;
+                velocity.z *= factor;
+            }
+            velocity.y = SLIDE_VELOCITY;
+            entity.velocity.store(velocity);
+
+            if let Some(living) = args.entity.get_living_entity() {
+                living.fall_distance.store(0.0);
+            }
+        })
+    }
+
+    fn on_landed_upon<'a>(&'a self, args: OnLandedUponArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            if let Some(living) = args.entity.get_living_entity() {
+                living.entity.play_sound(Sound::BlockHoneyBlockSlide).await;
+                living
+                    .handle_fall_damage(args.entity, args.fall_distance, 0.2)
+                    .await;
+            }
+        })
+    }
+}
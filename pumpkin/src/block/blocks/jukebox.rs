@@ -52,8 +52,9 @@ impl JukeboxBlock {
     /// Drops the record from the jukebox - matches vanilla's `JukeboxBlockEntity.dropRecord()`
     /// Spawns item at (pos + 0.5, pos + 1.01, pos + 0.5) with horizontal random offset
     async fn drop_record(position: &BlockPos, world: &Arc<World>) {
-        if let Some(block_entity) = world.get_block_entity(position).await
-            && let Some(jukebox_entity) = block_entity.as_any().downcast_ref::<JukeboxBlockEntity>()
+        if let Some(jukebox_entity) = world
+            .get_block_entity_data::<JukeboxBlockEntity>(position)
+            .await
         {
             let record = jukebox_entity.clear_record().await;
             if !record.is_empty() {
@@ -157,9 +158,9 @@ impl BlockBehaviour for JukeboxBlock {
             let record = item_stack.split_unless_creative(args.player.gamemode.load(), 1);
 
             // Vanilla: lv4.setStack(lv3)
-            if let Some(block_entity) = world.get_block_entity(args.position).await
-                && let Some(jukebox_entity) =
-                    block_entity.as_any().downcast_ref::<JukeboxBlockEntity>()
+            if let Some(jukebox_entity) = world
+                .get_block_entity_data::<JukeboxBlockEntity>(args.position)
+                .await
             {
                 jukebox_entity.set_record(record).await;
                 // Start tracking playback with song duration
@@ -214,9 +215,10 @@ impl BlockBehaviour for JukeboxBlock {
     ) -> BlockFuture<'a, u8> {
         Box::pin(async move {
             // Vanilla: return world.getBlockEntity(pos) instanceof JukeboxBlockEntity lv && lv.getManager().isPlaying() ? 15 : 0
-            if let Some(block_entity) = args.world.get_block_entity(args.position).await
-                && let Some(jukebox_entity) =
-                    block_entity.as_any().downcast_ref::<JukeboxBlockEntity>()
+            if let Some(jukebox_entity) = args
+                .world
+                .get_block_entity_data::<JukeboxBlockEntity>(args.position)
+                .await
                 && jukebox_entity.is_playing()
             {
                 15
@@ -233,9 +235,10 @@ impl BlockBehaviour for JukeboxBlock {
     ) -> BlockFuture<'a, Option<u8>> {
         Box::pin(async move {
             // Vanilla: return world.getBlockEntity(pos) instanceof JukeboxBlockEntity lv ? lv.getComparatorOutput() : 0
-            if let Some(block_entity) = args.world.get_block_entity(args.position).await
-                && let Some(jukebox_entity) =
-                    block_entity.as_any().downcast_ref::<JukeboxBlockEntity>()
+            if let Some(jukebox_entity) = args
+                .world
+                .get_block_entity_data::<JukeboxBlockEntity>(args.position)
+                .await
             {
                 let record = jukebox_entity.get_record().await;
                 // Get the song from the record's jukebox_playable component
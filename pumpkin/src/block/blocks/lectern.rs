@@ -1,7 +1,22 @@
-use crate::block::{BlockBehaviour, BlockFuture, OnPlaceArgs};
+use std::sync::Arc;
+
+use crate::block::registry::BlockActionResult;
+use crate::block::{
+    BlockBehaviour, BlockFuture, NormalUseArgs, OnPlaceArgs, PlacedArgs, UseWithItemArgs,
+};
+use crate::world::World;
+use pumpkin_data::Block;
 use pumpkin_data::block_properties::{BlockProperties, LecternLikeProperties};
+use pumpkin_data::sound::{Sound, SoundCategory};
+use pumpkin_data::tag::{self, Taggable};
 use pumpkin_macros::pumpkin_block;
+use pumpkin_util::GameMode;
+use pumpkin_util::math::position::BlockPos;
 use pumpkin_world::BlockStateId;
+use pumpkin_world::block::entities::lectern::LecternBlockEntity;
+use pumpkin_world::item::ItemStack;
+use pumpkin_world::world::BlockFlags;
+use tokio::sync::Mutex;
 
 #[pumpkin_block("minecraft:lectern")]
 pub struct LecternBlock;
@@ -19,4 +34,215 @@ impl BlockBehaviour for LecternBlock {
             props.to_state_id(args.block)
         })
     }
+
+    fn placed<'a>(&'a self, args: PlacedArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let block_entity = LecternBlockEntity::new(*args.position);
+            args.world.add_block_entity(Arc::new(block_entity)).await;
+        })
+    }
+
+    /// Vanilla: `LecternBlock.onUseWithItem()`, puts a held book on an empty lectern
+    fn use_with_item<'a>(
+        &'a self,
+        args: UseWithItemArgs<'a>,
+    ) -> BlockFuture<'a, BlockActionResult> {
+        Box::pin(async move {
+            if Self::try_place_book(
+                args.world,
+                args.block,
+                args.position,
+                args.item_stack,
+                args.player.gamemode.load(),
+            )
+            .await
+            {
+                BlockActionResult::Success
+            } else {
+                BlockActionResult::PassToDefaultBlockAction
+            }
+        })
+    }
+
+    /// Vanilla: `LecternBlock.onUse()`, opens the book on the lectern
+    fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
+        Box::pin(async move {
+            let Some(book) = Self::book_to_open(args.world, args.position).await else {
+                return BlockActionResult::Pass;
+            };
+
+            args.player.open_lectern_book(&book, *args.position).await;
+            BlockActionResult::Consume
+        })
+    }
+}
+
+impl LecternBlock {
+    /// Puts one book from `item_stack` on the lectern if it is empty and the stack is a book.
+    /// Returns whether the book was placed.
+    async fn try_place_book(
+        world: &Arc<World>,
+        block: &Block,
+        position: &BlockPos,
+        item_stack: &Mutex<ItemStack>,
+        gamemode: GameMode,
+    ) -> bool {
+        let state_id = world.get_block_state_id(position).await;
+        let mut props = LecternLikeProperties::from_state_id(state_id, block);
+        if props.has_book {
+            return false;
+        }
+
+        let mut item_stack = item_stack.lock().await;
+        if !item_stack.item.has_tag(&tag::Item::MINECRAFT_LECTERN_BOOKS) {
+            return false;
+        }
+        let Some(lectern) = world
+            .get_block_entity_data::<LecternBlockEntity>(position)
+            .await
+        else {
+            return false;
+        };
+
+        let book = item_stack.split_unless_creative(gamemode, 1);
+        drop(item_stack);
+        if !lectern.place_book(book).await {
+            return false;
+        }
+
+        props.has_book = true;
+        world
+            .set_block_state(position, props.to_state_id(block), BlockFlags::NOTIFY_ALL)
+            .await;
+        world
+            .play_block_sound(Sound::ItemBookPut, SoundCategory::Blocks, *position)
+            .await;
+        true
+    }
+
+    /// The book a player opens by using the lectern, if it holds one. A written book opens the
+    /// reading screen and a book and quill opens the editor.
+    async fn book_to_open(world: &Arc<World>, position: &BlockPos) -> Option<ItemStack> {
+        let lectern = world
+            .get_block_entity_data::<LecternBlockEntity>(position)
+            .await?;
+        let book = lectern.get_book().await;
+        (!book.is_empty()).then_some(book)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arc_swap::ArcSwap;
+    use pumpkin_data::{
+        Block,
+        block_properties::{BlockProperties, LecternLikeProperties},
+        dimension::Dimension,
+        item::Item,
+    };
+    use pumpkin_util::{
+        GameMode,
+        math::{position::BlockPos, vector2::Vector2},
+        world_seed::Seed,
+    };
+    use pumpkin_world::{
+        block::entities::lectern::LecternBlockEntity, chunk::ChunkData, item::ItemStack,
+        world_info::LevelData,
+    };
+    use tempfile::tempdir;
+    use tokio::sync::Mutex;
+
+    use super::LecternBlock;
+    use crate::world::World;
+    use crate::world::test::{load_empty_chunk, test_world};
+
+    /// Sets up an empty lectern like placing one does.
+    async fn lectern(world: &World, chunk: &ChunkData, position: BlockPos) {
+        chunk.section.set_block_absolute_y(
+            position.0.x as usize,
+            position.0.y,
+            position.0.z as usize,
+            Block::LECTERN.default_state.id,
+        );
+        world
+            .add_block_entity(Arc::new(LecternBlockEntity::new(position)))
+            .await;
+    }
+
+    async fn has_book(world: &World, position: &BlockPos) -> bool {
+        let state_id = world.get_block_state_id(position).await;
+        LecternLikeProperties::from_state_id(state_id, &Block::LECTERN).has_book
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn books_are_placed_read_and_edited() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        let chunk = load_empty_chunk(&world, Vector2::new(0, 0));
+        let reading = BlockPos::new(4, 64, 4);
+        let editing = BlockPos::new(8, 64, 8);
+        lectern(&world, &chunk, reading).await;
+        lectern(&world, &chunk, editing).await;
+
+        // An empty lectern has nothing to open, and only takes books
+        assert!(LecternBlock::book_to_open(&world, &reading).await.is_none());
+        let stone = Mutex::new(ItemStack::new(1, &Item::STONE));
+        let placed = LecternBlock::try_place_book(
+            &world,
+            &Block::LECTERN,
+            &reading,
+            &stone,
+            GameMode::Survival,
+        )
+        .await;
+        assert!(!placed);
+        assert_eq!(stone.lock().await.item_count, 1);
+
+        // Holding a written book places a single one
+        let written = Mutex::new(ItemStack::new(2, &Item::WRITTEN_BOOK));
+        let placed = LecternBlock::try_place_book(
+            &world,
+            &Block::LECTERN,
+            &reading,
+            &written,
+            GameMode::Survival,
+        )
+        .await;
+        assert!(placed);
+        assert_eq!(written.lock().await.item_count, 1);
+        assert!(has_book(&world, &reading).await);
+
+        // Once there is a book, using the lectern with another one reads it instead
+        let placed = LecternBlock::try_place_book(
+            &world,
+            &Block::LECTERN,
+            &reading,
+            &written,
+            GameMode::Survival,
+        )
+        .await;
+        assert!(!placed);
+        assert_eq!(written.lock().await.item_count, 1);
+        let book = LecternBlock::book_to_open(&world, &reading).await.unwrap();
+        assert!(book.item == &Item::WRITTEN_BOOK);
+        assert_eq!(book.item_count, 1);
+
+        // A book and quill opens the editor, and creative players keep theirs
+        let writable = Mutex::new(ItemStack::new(1, &Item::WRITABLE_BOOK));
+        let placed = LecternBlock::try_place_book(
+            &world,
+            &Block::LECTERN,
+            &editing,
+            &writable,
+            GameMode::Creative,
+        )
+        .await;
+        assert!(placed);
+        assert_eq!(writable.lock().await.item_count, 1);
+        let book = LecternBlock::book_to_open(&world, &editing).await.unwrap();
+        assert!(book.item == &Item::WRITABLE_BOOK);
+    }
 }
@@ -2,6 +2,7 @@ pub mod anvil;
 pub mod barrel;
 pub mod barrier;
 pub mod bed;
+pub mod bubble_column;
 pub mod cake;
 pub mod campfire;
 pub mod candle_cakes;
@@ -31,6 +32,7 @@ pub mod glass_panes;
 pub mod glazed_terracotta;
 pub mod grindstone;
 pub mod hay;
+pub mod honey;
 pub mod infested;
 pub mod iron_bars;
 pub mod jukebox;
@@ -50,10 +52,12 @@ pub mod signs;
 pub mod skull_block;
 pub mod slabs;
 pub mod slime;
+pub mod smithing_table;
 pub mod snow;
 pub mod spawner;
 pub mod sponge;
 pub mod stairs;
+pub mod stonecutter;
 pub mod tnt;
 pub mod torches;
 pub mod trapdoor;
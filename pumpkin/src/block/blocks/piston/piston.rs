@@ -182,11 +182,10 @@ impl BlockBehaviour for PistonBlock {
 
             let extended_pos = pos.offset(dir.to_offset());
 
-            if let Some(block_entity) = world.get_block_entity(&extended_pos).await {
-                let piston = block_entity
-                    .as_any()
-                    .downcast_ref::<PistonBlockEntity>()
-                    .unwrap();
+            if let Some(piston) = world
+                .get_block_entity_data::<PistonBlockEntity>(&extended_pos)
+                .await
+            {
                 piston.finish(world.clone()).await;
             }
 
@@ -229,9 +228,10 @@ impl BlockBehaviour for PistonBlock {
                 let (block, state) = world.get_block_and_state(&pull_pos).await;
                 let mut bl2 = false;
                 if block == &Block::MOVING_PISTON
-                    && let Some(entity) = world.get_block_entity(&pull_pos).await
+                    && let Some(piston) = world
+                        .get_block_entity_data::<PistonBlockEntity>(&pull_pos)
+                        .await
                 {
-                    let piston = entity.as_any().downcast_ref::<PistonBlockEntity>().unwrap();
                     if piston.facing == dir && piston.extending {
                         piston.finish(world.clone()).await;
                         bl2 = true;
@@ -336,9 +336,10 @@ async fn try_move(world: &Arc<World>, block: &Block, block_pos: &BlockPos) {
         if new_block == &Block::MOVING_PISTON {
             let new_props = MovingPistonLikeProperties::from_state_id(new_state, new_block);
             if new_props.facing == props.facing
-                && let Some(entity) = world.get_block_entity(&new_pos).await
+                && let Some(piston) = world
+                    .get_block_entity_data::<PistonBlockEntity>(&new_pos)
+                    .await
             {
-                let piston = entity.as_any().downcast_ref::<PistonBlockEntity>().unwrap();
                 if piston.extending && piston.current_progress.load() < 0.5
                 // TODO: more stuff...
                 {
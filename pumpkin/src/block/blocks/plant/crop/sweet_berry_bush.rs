@@ -19,7 +19,6 @@ use pumpkin_data::{
 };
 use pumpkin_macros::pumpkin_block;
 use pumpkin_util::math::position::BlockPos;
-use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::{
     BlockStateId,
     item::ItemStack,
@@ -112,10 +111,8 @@ impl BlockBehaviour for SweetBerryBushBlock {
                 return;
             }
 
+            // Entities are slowed down while inside by `Entity::tick_block_collisions`
             let living_entity = living_entity_opt.expect("Living entity should exist");
-            entity
-                .slow_movement(args.state, Vector3::new(0.8, 0.75, 0.8))
-                .await;
             let mov = if living_entity.is_player() {
                 living_entity.get_movement()
             } else {
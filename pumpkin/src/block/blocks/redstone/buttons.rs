@@ -2,9 +2,15 @@ use std::sync::Arc;
 
 use pumpkin_data::Block;
 use pumpkin_data::BlockDirection;
+use pumpkin_data::BlockState;
 use pumpkin_data::HorizontalFacingExt;
 use pumpkin_data::block_properties::BlockFace;
 use pumpkin_data::block_properties::BlockProperties;
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::sound::Sound;
+use pumpkin_data::sound::SoundCategory;
+use pumpkin_data::tag;
+use pumpkin_data::tag::Taggable;
 use pumpkin_macros::pumpkin_block_from_tag;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_world::BlockStateId;
@@ -18,6 +24,7 @@ use crate::block::CanPlaceAtArgs;
 use crate::block::EmitsRedstonePowerArgs;
 use crate::block::GetRedstonePowerArgs;
 use crate::block::GetStateForNeighborUpdateArgs;
+use crate::block::OnEntityCollisionArgs;
 use crate::block::OnPlaceArgs;
 use crate::block::OnScheduledTickArgs;
 use crate::block::OnStateReplacedArgs;
@@ -25,9 +32,81 @@ use crate::block::blocks::abstract_wall_mounting::WallMountedBlock;
 use crate::block::blocks::redstone::lever::LeverLikePropertiesExt;
 use crate::block::registry::BlockActionResult;
 use crate::block::{BlockBehaviour, NormalUseArgs};
+use crate::entity::player::Player;
 use crate::world::World;
 
-async fn click_button(world: &Arc<World>, block_pos: &BlockPos) {
+/// Stone buttons stay pressed for 20 ticks, wooden ones for 30
+fn press_ticks(block: &Block) -> u8 {
+    if block.has_tag(&tag::Block::MINECRAFT_STONE_BUTTONS) {
+        20
+    } else {
+        30
+    }
+}
+
+/// Only wooden buttons can be held down by arrows and tridents stuck in them
+fn can_press_by_arrows(block: &Block) -> bool {
+    block.has_tag(&tag::Block::MINECRAFT_WOODEN_BUTTONS)
+}
+
+fn is_persistent_projectile(entity_type: &EntityType) -> bool {
+    entity_type == &EntityType::ARROW
+        || entity_type == &EntityType::SPECTRAL_ARROW
+        || entity_type == &EntityType::TRIDENT
+}
+
+// Todo: The sounds should be from BlockSetType
+fn get_click_sound(block: &Block, powered: bool) -> Sound {
+    let (on, off) = if block.has_tag(&tag::Block::MINECRAFT_STONE_BUTTONS) {
+        (
+            Sound::BlockStoneButtonClickOn,
+            Sound::BlockStoneButtonClickOff,
+        )
+    } else if block == &Block::CHERRY_BUTTON {
+        (
+            Sound::BlockCherryWoodButtonClickOn,
+            Sound::BlockCherryWoodButtonClickOff,
+        )
+    } else if block == &Block::BAMBOO_BUTTON {
+        (
+            Sound::BlockBambooWoodButtonClickOn,
+            Sound::BlockBambooWoodButtonClickOff,
+        )
+    } else if block == &Block::CRIMSON_BUTTON || block == &Block::WARPED_BUTTON {
+        (
+            Sound::BlockNetherWoodButtonClickOn,
+            Sound::BlockNetherWoodButtonClickOff,
+        )
+    } else {
+        (
+            Sound::BlockWoodenButtonClickOn,
+            Sound::BlockWoodenButtonClickOff,
+        )
+    };
+    if powered { on } else { off }
+}
+
+async fn play_click_sound(
+    world: &World,
+    player: Option<&Player>,
+    block: &Block,
+    block_pos: &BlockPos,
+    powered: bool,
+) {
+    let sound = get_click_sound(block, powered);
+    // The pressing player already hears the click on their own client
+    if let Some(player) = player {
+        world
+            .play_block_sound_expect(player, sound, SoundCategory::Blocks, *block_pos)
+            .await;
+    } else {
+        world
+            .play_block_sound(sound, SoundCategory::Blocks, *block_pos)
+            .await;
+    }
+}
+
+async fn click_button(world: &Arc<World>, block_pos: &BlockPos, player: &Player) {
     let (block, state) = world.get_block_and_state_id(block_pos).await;
 
     let mut button_props = ButtonLikeProperties::from_state_id(state, block);
@@ -40,15 +119,11 @@ async fn click_button(world: &Arc<World>, block_pos: &BlockPos) {
                 BlockFlags::NOTIFY_ALL,
             )
             .await;
-        let delay = if block == &Block::STONE_BUTTON {
-            20
-        } else {
-            30
-        };
         world
-            .schedule_block_tick(block, *block_pos, delay, TickPriority::Normal)
+            .schedule_block_tick(block, *block_pos, press_ticks(block), TickPriority::Normal)
             .await;
         ButtonBlock::update_neighbors(world, block_pos, &button_props).await;
+        play_click_sound(world, Some(player), block, block_pos, true).await;
     }
 }
 
@@ -58,7 +133,7 @@ pub struct ButtonBlock;
 impl BlockBehaviour for ButtonBlock {
     fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
         Box::pin(async move {
-            click_button(args.world, args.position).await;
+            click_button(args.world, args.position, args.player).await;
 
             BlockActionResult::Success
         })
@@ -67,16 +142,26 @@ impl BlockBehaviour for ButtonBlock {
     fn on_scheduled_tick<'a>(&'a self, args: OnScheduledTickArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
             let state = args.world.get_block_state(args.position).await;
-            let mut props = ButtonLikeProperties::from_state_id(state.id, args.block);
-            props.powered = false;
-            args.world
-                .set_block_state(
-                    args.position,
-                    props.to_state_id(args.block),
-                    BlockFlags::NOTIFY_ALL,
-                )
-                .await;
-            Self::update_neighbors(args.world, args.position, &props).await;
+            let props = ButtonLikeProperties::from_state_id(state.id, args.block);
+            if props.powered {
+                Self::try_power_with_projectiles(args.world, args.position, args.block, state)
+                    .await;
+            }
+        })
+    }
+
+    fn on_entity_collision<'a>(&'a self, args: OnEntityCollisionArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            if !can_press_by_arrows(args.block)
+                || !is_persistent_projectile(args.entity.get_entity().entity_type)
+            {
+                return;
+            }
+            let props = ButtonLikeProperties::from_state_id(args.state.id, args.block);
+            if !props.powered {
+                Self::try_power_with_projectiles(args.world, args.position, args.block, args.state)
+                    .await;
+            }
         })
     }
 
@@ -166,6 +251,42 @@ impl WallMountedBlock for ButtonBlock {
 }
 
 impl ButtonBlock {
+    /// Keeps the button pressed as long as a projectile is stuck inside of it, otherwise
+    /// releases it.
+    async fn try_power_with_projectiles(
+        world: &Arc<World>,
+        block_pos: &BlockPos,
+        block: &Block,
+        state: &BlockState,
+    ) {
+        let has_projectile = can_press_by_arrows(block)
+            && state.get_block_outline_shapes().any(|shape| {
+                world
+                    .get_all_at_box(&shape.at_pos(*block_pos))
+                    .iter()
+                    .any(|entity| {
+                        !entity.is_spectator()
+                            && is_persistent_projectile(entity.get_entity().entity_type)
+                    })
+            });
+
+        let mut props = ButtonLikeProperties::from_state_id(state.id, block);
+        if has_projectile != props.powered {
+            props.powered = has_projectile;
+            world
+                .set_block_state(block_pos, props.to_state_id(block), BlockFlags::NOTIFY_ALL)
+                .await;
+            Self::update_neighbors(world, block_pos, &props).await;
+            play_click_sound(world, None, block, block_pos, has_projectile).await;
+        }
+
+        if has_projectile {
+            world
+                .schedule_block_tick(block, *block_pos, press_ticks(block), TickPriority::Normal)
+                .await;
+        }
+    }
+
     async fn update_neighbors(
         world: &Arc<World>,
         block_pos: &BlockPos,
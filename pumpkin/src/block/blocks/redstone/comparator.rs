@@ -158,13 +158,10 @@ impl RedstoneGateBlockProperties for ComparatorLikeProperties {
 impl RedstoneGateBlock<ComparatorLikeProperties> for ComparatorBlock {
     fn get_output_level<'a>(&'a self, world: &'a World, pos: BlockPos) -> BlockFuture<'a, u8> {
         Box::pin(async move {
-            if let Some(blockentity) = world.get_block_entity(&pos).await
-                && blockentity.resource_location() == ComparatorBlockEntity::ID
+            if let Some(comparator) = world
+                .get_block_entity_data::<ComparatorBlockEntity>(&pos)
+                .await
             {
-                let comparator = blockentity
-                    .as_any()
-                    .downcast_ref::<ComparatorBlockEntity>()
-                    .unwrap();
                 return comparator.output_signal.load(Ordering::Relaxed);
             }
             0
@@ -367,13 +364,10 @@ impl ComparatorBlock {
     async fn update(&self, world: &Arc<World>, pos: BlockPos, state: &BlockState, block: &Block) {
         let future_level = i32::from(self.calculate_output_signal(world, pos, state, block).await);
         let mut now_level = 0;
-        if let Some(blockentity) = world.get_block_entity(&pos).await
-            && blockentity.resource_location() == ComparatorBlockEntity::ID
+        if let Some(comparator) = world
+            .get_block_entity_data::<ComparatorBlockEntity>(&pos)
+            .await
         {
-            let comparator = blockentity
-                .as_any()
-                .downcast_ref::<ComparatorBlockEntity>()
-                .unwrap();
             now_level = i32::from(comparator.output_signal.load(Ordering::Relaxed));
             comparator
                 .output_signal
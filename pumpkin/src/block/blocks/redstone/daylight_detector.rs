@@ -0,0 +1,195 @@
+use std::f32::consts::{PI, TAU};
+use std::sync::Arc;
+
+use pumpkin_data::{
+    Block,
+    block_properties::{
+        BlockProperties, DaylightDetectorLikeProperties, EnumVariants, Integer0To15,
+    },
+};
+use pumpkin_macros::pumpkin_block;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_world::{
+    block::entities::daylight_detector::DaylightDetectorBlockEntity, lighting::LightLayer,
+    world::BlockFlags,
+};
+
+use crate::{
+    block::{
+        BlockBehaviour, BlockFuture, EmitsRedstonePowerArgs, GetRedstonePowerArgs, NormalUseArgs,
+        OnScheduledTickArgs, PlacedArgs, registry::BlockActionResult,
+    },
+    world::World,
+};
+
+#[pumpkin_block("minecraft:daylight_detector")]
+pub struct DaylightDetectorBlock;
+
+impl DaylightDetectorBlock {
+    /// Recomputes the output from the sky light and the position of the sun.
+    async fn update_power(world: &Arc<World>, block: &Block, position: &BlockPos) {
+        let state_id = world.get_block_state_id(position).await;
+        let mut props = DaylightDetectorLikeProperties::from_state_id(state_id, block);
+
+        // Blocks changed this tick only affect the light once flushed
+        world.flush_light_updates().await;
+        let sky_light = world.get_light_level(position, LightLayer::Sky).await;
+        let mut power = i32::from(sky_light) - i32::from(world.get_ambient_darkness().await);
+        if props.inverted {
+            power = 15 - power;
+        } else if power > 0 {
+            // Weaken the signal while the sun is low, pulling the angle towards noon a little
+            let mut angle = world.level_time.lock().await.sky_angle() * TAU;
+            let noon = if angle < PI { 0.0 } else { TAU };
+            angle += (noon - angle) * 0.2;
+            power = (power as f32 * angle.cos()).round() as i32;
+        }
+        let power = power.clamp(0, 15) as u16;
+
+        if props.power.to_index() != power {
+            props.power = Integer0To15::from_index(power);
+            world
+                .set_block_state(position, props.to_state_id(block), BlockFlags::NOTIFY_ALL)
+                .await;
+        }
+    }
+}
+
+impl BlockBehaviour for DaylightDetectorBlock {
+    fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
+        Box::pin(async move {
+            if args.player.is_block_modification_restricted().await {
+                return BlockActionResult::Pass;
+            }
+
+            let state_id = args.world.get_block_state_id(args.position).await;
+            let mut props = DaylightDetectorLikeProperties::from_state_id(state_id, args.block);
+            props.inverted = !props.inverted;
+            args.world
+                .set_block_state(
+                    args.position,
+                    props.to_state_id(args.block),
+                    BlockFlags::NOTIFY_LISTENERS,
+                )
+                .await;
+            Self::update_power(args.world, args.block, args.position).await;
+
+            BlockActionResult::SuccessServer
+        })
+    }
+
+    fn placed<'a>(&'a self, args: PlacedArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            // The block entity is saved with the chunk, so the detector keeps ticking once the
+            // chunk is loaded again
+            args.world
+                .add_block_entity(Arc::new(DaylightDetectorBlockEntity::new(*args.position)))
+                .await;
+        })
+    }
+
+    fn on_scheduled_tick<'a>(&'a self, args: OnScheduledTickArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            Self::update_power(args.world, args.block, args.position).await;
+        })
+    }
+
+    fn emits_redstone_power<'a>(
+        &'a self,
+        _args: EmitsRedstonePowerArgs<'a>,
+    ) -> BlockFuture<'a, bool> {
+        Box::pin(async move { true })
+    }
+
+    fn get_weak_redstone_power<'a>(
+        &'a self,
+        args: GetRedstonePowerArgs<'a>,
+    ) -> BlockFuture<'a, u8> {
+        Box::pin(async move {
+            let props = DaylightDetectorLikeProperties::from_state_id(args.state.id, args.block);
+            props.power.to_index() as u8
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arc_swap::ArcSwap;
+    use pumpkin_data::{
+        Block,
+        block_properties::{BlockProperties, DaylightDetectorLikeProperties},
+        dimension::Dimension,
+    };
+    use pumpkin_nbt::compound::NbtCompound;
+    use pumpkin_util::{
+        math::{position::BlockPos, vector2::Vector2},
+        world_seed::Seed,
+    };
+    use pumpkin_world::{
+        block::entities::block_entity_from_nbt, world::SimpleWorld, world_info::LevelData,
+    };
+    use tempfile::tempdir;
+
+    use super::DaylightDetectorBlock;
+    use crate::block::{BlockBehaviour, OnScheduledTickArgs};
+    use crate::world::test::{load_empty_chunk, test_world};
+
+    /// A detector loaded from disk only has its block entity, nothing is scheduled for it yet.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn loaded_detector_measures_daylight() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        let chunk = load_empty_chunk(&world, Vector2::new(0, 0));
+        let block = &Block::DAYLIGHT_DETECTOR;
+        let pos = BlockPos::new(8, 64, 8);
+        chunk
+            .section
+            .set_block_absolute_y(8, 64, 8, block.default_state.id);
+
+        let mut nbt = NbtCompound::new();
+        nbt.put_string("id", "minecraft:daylight_detector".to_string());
+        nbt.put_int("x", pos.0.x);
+        nbt.put_int("y", pos.0.y);
+        nbt.put_int("z", pos.0.z);
+        let block_entity = block_entity_from_nbt(&nbt).unwrap();
+        chunk
+            .block_entities
+            .lock()
+            .unwrap()
+            .insert(pos, block_entity.clone());
+
+        {
+            let mut level_time = world.level_time.lock().await;
+            level_time.world_age = 40;
+            level_time.time_of_day = 6000;
+        }
+        let simple_world: Arc<dyn SimpleWorld> = world.clone();
+        block_entity.tick(&simple_world).await;
+        assert!(world.is_block_tick_scheduled(&pos, block).await);
+
+        let power = async |inverted: bool| {
+            let mut props = DaylightDetectorLikeProperties::default(block);
+            props.inverted = inverted;
+            chunk
+                .section
+                .set_block_absolute_y(8, 64, 8, props.to_state_id(block));
+            DaylightDetectorBlock
+                .on_scheduled_tick(OnScheduledTickArgs {
+                    world: &world,
+                    block,
+                    position: &pos,
+                })
+                .await;
+            let state_id = world.get_block_state_id(&pos).await;
+            DaylightDetectorLikeProperties::from_state_id(state_id, block)
+                .power
+                .to_index()
+        };
+        // Full sky light at noon
+        assert_eq!(power(false).await, 15);
+        assert_eq!(power(true).await, 0);
+    }
+}
@@ -148,11 +148,11 @@ impl BlockBehaviour for DropperBlock {
 
     fn on_scheduled_tick<'a>(&'a self, args: OnScheduledTickArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
-            if let Some(block_entity) = args.world.get_block_entity(args.position).await {
-                let dropper = block_entity
-                    .as_any()
-                    .downcast_ref::<DropperBlockEntity>()
-                    .unwrap();
+            if let Some(dropper) = args
+                .world
+                .get_block_entity_data::<DropperBlockEntity>(args.position)
+                .await
+            {
                 if let Some(mut item) = dropper.get_random_slot().await {
                     let props = DispenserLikeProperties::from_state_id(
                         args.world.get_block_state(args.position).await.id,
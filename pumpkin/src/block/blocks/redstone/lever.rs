@@ -8,9 +8,12 @@ use crate::block::{
 use pumpkin_data::{
     Block, BlockDirection, HorizontalFacingExt,
     block_properties::{BlockFace, BlockProperties, LeverLikeProperties},
+    particle::Particle,
+    sound::{Sound, SoundCategory},
 };
 use pumpkin_macros::pumpkin_block;
-use pumpkin_util::math::position::BlockPos;
+use pumpkin_protocol::{VarInt, java::client::play::CParticle};
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
 use pumpkin_world::{BlockStateId, world::BlockFlags};
 
 use crate::{
@@ -18,10 +21,14 @@ use crate::{
         registry::BlockActionResult,
         {BlockBehaviour, NormalUseArgs},
     },
+    entity::player::Player,
     world::World,
 };
 
-async fn toggle_lever(world: &Arc<World>, block_pos: &BlockPos) {
+/// The color of the redstone dust particle, `0xFF0000`
+const DUST_COLOR: i32 = 0x00FF_0000;
+
+async fn toggle_lever(world: &Arc<World>, block_pos: &BlockPos, player: &Player) {
     let (block, state) = world.get_block_and_state_id(block_pos).await;
 
     let mut lever_props = LeverLikeProperties::from_state_id(state, block);
@@ -35,6 +42,20 @@ async fn toggle_lever(world: &Arc<World>, block_pos: &BlockPos) {
         .await;
 
     LeverBlock::update_neighbors(world, block_pos, &lever_props).await;
+
+    let pitch = if lever_props.powered { 0.6 } else { 0.5 };
+    world
+        .play_sound_raw(
+            Sound::BlockLeverClick as u16,
+            SoundCategory::Blocks,
+            &block_pos.to_centered_f64(),
+            0.3,
+            pitch,
+        )
+        .await;
+    if lever_props.powered {
+        LeverBlock::spawn_particles(world, block_pos, &lever_props, player).await;
+    }
 }
 
 #[pumpkin_block("minecraft:lever")]
@@ -43,7 +64,7 @@ pub struct LeverBlock;
 impl BlockBehaviour for LeverBlock {
     fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
         Box::pin(async move {
-            toggle_lever(args.world, args.position).await;
+            toggle_lever(args.world, args.position, args.player).await;
 
             BlockActionResult::Success
         })
@@ -134,6 +155,54 @@ impl WallMountedBlock for LeverBlock {
 }
 
 impl LeverBlock {
+    /// Spawns the red dust puff at the tip of the lever. The toggling player's client already
+    /// spawns it on its own.
+    async fn spawn_particles(
+        world: &World,
+        block_pos: &BlockPos,
+        lever_props: &LeverLikeProperties,
+        player: &Player,
+    ) {
+        let facing = lever_props
+            .facing
+            .to_block_direction()
+            .opposite()
+            .to_offset();
+        let direction = lever_props.get_direction().opposite().to_offset();
+        let position = Vector3::new(
+            f64::from(block_pos.0.x)
+                + 0.5
+                + 0.1 * f64::from(facing.x)
+                + 0.2 * f64::from(direction.x),
+            f64::from(block_pos.0.y)
+                + 0.5
+                + 0.1 * f64::from(facing.y)
+                + 0.2 * f64::from(direction.y),
+            f64::from(block_pos.0.z)
+                + 0.5
+                + 0.1 * f64::from(facing.z)
+                + 0.2 * f64::from(direction.z),
+        );
+
+        let mut data = Vec::with_capacity(8);
+        data.extend_from_slice(&DUST_COLOR.to_be_bytes());
+        data.extend_from_slice(&1.0f32.to_be_bytes());
+
+        let packet = CParticle::new(
+            false,
+            false,
+            position,
+            Vector3::new(0.0, 0.0, 0.0),
+            0.0,
+            1,
+            VarInt(Particle::Dust as i32),
+            &data,
+        );
+        world
+            .broadcast_packet_except(&[player.gameprofile.id], &packet)
+            .await;
+    }
+
     async fn update_neighbors(
         world: &Arc<World>,
         block_pos: &BlockPos,
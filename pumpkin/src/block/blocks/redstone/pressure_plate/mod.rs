@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
-use pumpkin_data::{Block, BlockDirection, BlockState};
+use pumpkin_data::{
+    Block, BlockDirection, BlockState,
+    sound::{Sound, SoundCategory},
+};
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_world::{BlockStateId, tick::TickPriority, world::BlockFlags};
 
 use crate::{
-    block::{OnEntityCollisionArgs, OnScheduledTickArgs, OnStateReplacedArgs},
+    block::{OnScheduledTickArgs, OnStateReplacedArgs, OnWalkedOverArgs},
     world::World,
 };
 
@@ -13,7 +16,7 @@ pub mod plate;
 pub mod weighted;
 
 pub(crate) trait PressurePlate {
-    async fn on_entity_collision_pp(&self, args: OnEntityCollisionArgs<'_>) {
+    async fn on_walked_over_pp(&self, args: OnWalkedOverArgs<'_>) {
         let output = self.get_redstone_output(args.block, args.state.id);
         if output == 0 {
             self.update_plate_state(args.world, args.position, args.block, args.state, output)
@@ -50,6 +53,7 @@ pub(crate) trait PressurePlate {
         let calc_output = self.calculate_redstone_output(world, block, pos).await;
         let has_output = calc_output > 0;
         if calc_output != output {
+            // Neighbor updates also let comparators reading the plate pick up the new power
             let state = self.set_redstone_output(block, state, calc_output);
             world
                 .set_block_state(pos, state, BlockFlags::NOTIFY_LISTENERS)
@@ -57,6 +61,15 @@ pub(crate) trait PressurePlate {
             world.update_neighbors(pos, None).await;
             world.update_neighbors(&pos.down(), None).await;
         }
+        if has_output != (output > 0) {
+            world
+                .play_block_sound(
+                    self.get_click_sound(block, has_output),
+                    SoundCategory::Blocks,
+                    *pos,
+                )
+                .await;
+        }
         if has_output {
             world
                 .schedule_block_tick(block, *pos, self.tick_rate(), TickPriority::Normal)
@@ -75,6 +88,8 @@ pub(crate) trait PressurePlate {
 
     async fn calculate_redstone_output(&self, world: &World, block: &Block, pos: &BlockPos) -> u8;
 
+    fn get_click_sound(&self, block: &Block, pressed: bool) -> Sound;
+
     fn tick_rate(&self) -> u8 {
         20
     }
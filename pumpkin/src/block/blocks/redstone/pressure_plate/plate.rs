@@ -1,16 +1,17 @@
 use pumpkin_data::{
     Block, BlockDirection, BlockState,
     block_properties::BlockProperties,
+    sound::Sound,
     tag::{self},
 };
-use pumpkin_util::math::{boundingbox::BoundingBox, position::BlockPos};
+use pumpkin_util::math::position::BlockPos;
 use pumpkin_world::{BlockStateId, world::BlockFlags};
 
 use crate::{
     block::{
         BlockBehaviour, BlockFuture, BlockMetadata, CanPlaceAtArgs, EmitsRedstonePowerArgs,
-        GetRedstonePowerArgs, OnEntityCollisionArgs, OnNeighborUpdateArgs, OnScheduledTickArgs,
-        OnStateReplacedArgs,
+        GetRedstonePowerArgs, OnNeighborUpdateArgs, OnScheduledTickArgs, OnStateReplacedArgs,
+        OnWalkedOverArgs,
     },
     world::World,
 };
@@ -32,9 +33,9 @@ impl BlockMetadata for PressurePlateBlock {
 }
 
 impl BlockBehaviour for PressurePlateBlock {
-    fn on_entity_collision<'a>(&'a self, args: OnEntityCollisionArgs<'a>) -> BlockFuture<'a, ()> {
+    fn on_walked_over<'a>(&'a self, args: OnWalkedOverArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
-            self.on_entity_collision_pp(args).await;
+            self.on_walked_over_pp(args).await;
         })
     }
 
@@ -99,15 +100,55 @@ impl PressurePlate for PressurePlateBlock {
         if props.powered { 15 } else { 0 }
     }
 
-    async fn calculate_redstone_output(&self, world: &World, _block: &Block, pos: &BlockPos) -> u8 {
-        // TODO: this is bad use real box
-        let aabb = BoundingBox::from_block(pos);
-        if !world.get_entities_at_box(&aabb).is_empty()
-            || !world.get_players_at_box(&aabb).is_empty()
+    async fn calculate_redstone_output(&self, world: &World, block: &Block, pos: &BlockPos) -> u8 {
+        let entities = world.find_entities_on_block(pos);
+        // Stone plates are only pressed by living entities, wooden ones by everything
+        let pressed = if tag::Block::MINECRAFT_STONE_PRESSURE_PLATES
+            .1
+            .contains(&block.id)
         {
-            return 15;
-        }
-        0
+            entities
+                .iter()
+                .any(|entity| entity.get_living_entity().is_some())
+        } else {
+            !entities.is_empty()
+        };
+        if pressed { 15 } else { 0 }
+    }
+
+    // Todo: The sounds should be from BlockSetType
+    fn get_click_sound(&self, block: &Block, pressed: bool) -> Sound {
+        let (on, off) = if tag::Block::MINECRAFT_STONE_PRESSURE_PLATES
+            .1
+            .contains(&block.id)
+        {
+            (
+                Sound::BlockStonePressurePlateClickOn,
+                Sound::BlockStonePressurePlateClickOff,
+            )
+        } else if block == &Block::CHERRY_PRESSURE_PLATE {
+            (
+                Sound::BlockCherryWoodPressurePlateClickOn,
+                Sound::BlockCherryWoodPressurePlateClickOff,
+            )
+        } else if block == &Block::BAMBOO_PRESSURE_PLATE {
+            (
+                Sound::BlockBambooWoodPressurePlateClickOn,
+                Sound::BlockBambooWoodPressurePlateClickOff,
+            )
+        } else if block == &Block::CRIMSON_PRESSURE_PLATE || block == &Block::WARPED_PRESSURE_PLATE
+        {
+            (
+                Sound::BlockNetherWoodPressurePlateClickOn,
+                Sound::BlockNetherWoodPressurePlateClickOff,
+            )
+        } else {
+            (
+                Sound::BlockWoodenPressurePlateClickOn,
+                Sound::BlockWoodenPressurePlateClickOff,
+            )
+        };
+        if pressed { on } else { off }
     }
 
     fn set_redstone_output(
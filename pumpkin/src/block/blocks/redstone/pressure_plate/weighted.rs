@@ -1,15 +1,16 @@
 use pumpkin_data::{
     Block, BlockDirection, BlockState,
     block_properties::{BlockProperties, EnumVariants, Integer0To15},
+    sound::Sound,
 };
-use pumpkin_util::math::{boundingbox::BoundingBox, position::BlockPos};
+use pumpkin_util::math::position::BlockPos;
 use pumpkin_world::{BlockStateId, world::BlockFlags};
 
 use crate::{
     block::{
         BlockBehaviour, BlockFuture, BlockMetadata, CanPlaceAtArgs, EmitsRedstonePowerArgs,
-        GetRedstonePowerArgs, OnEntityCollisionArgs, OnNeighborUpdateArgs, OnScheduledTickArgs,
-        OnStateReplacedArgs,
+        GetRedstonePowerArgs, OnNeighborUpdateArgs, OnScheduledTickArgs, OnStateReplacedArgs,
+        OnWalkedOverArgs,
     },
     world::World,
 };
@@ -34,9 +35,9 @@ impl BlockMetadata for WeightedPressurePlateBlock {
 }
 
 impl BlockBehaviour for WeightedPressurePlateBlock {
-    fn on_entity_collision<'a>(&'a self, args: OnEntityCollisionArgs<'a>) -> BlockFuture<'a, ()> {
+    fn on_walked_over<'a>(&'a self, args: OnWalkedOverArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
-            self.on_entity_collision_pp(args).await;
+            self.on_walked_over_pp(args).await;
         })
     }
 
@@ -111,17 +112,22 @@ impl PressurePlate for WeightedPressurePlateBlock {
             // Iron
             150
         };
-        // TODO: this is bad use real box
-        let aabb = BoundingBox::from_block(pos);
-        let len = world.get_entities_at_box(&aabb).len() + world.get_players_at_box(&aabb).len();
-        let len = len.min(weight);
-        if len > 0 {
-            let f = (weight.min(len) / weight) as f32;
+        let count = world.find_entities_on_block(pos).len().min(weight);
+        if count > 0 {
+            let f = count as f32 / weight as f32;
             return (f * 15.0).ceil() as u8;
         }
         0
     }
 
+    fn get_click_sound(&self, _block: &Block, pressed: bool) -> Sound {
+        if pressed {
+            Sound::BlockMetalPressurePlateClickOn
+        } else {
+            Sound::BlockMetalPressurePlateClickOff
+        }
+    }
+
     fn set_redstone_output(
         &self,
         block: &Block,
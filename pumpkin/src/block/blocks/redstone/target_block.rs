@@ -1,15 +1,124 @@
+use pumpkin_data::{
+    BlockDirection,
+    block_properties::{Axis, BlockProperties, EnumVariants, Integer0To15},
+};
 use pumpkin_macros::pumpkin_block;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_world::{tick::TickPriority, world::BlockFlags};
 
-use crate::block::{BlockBehaviour, BlockFuture, EmitsRedstonePowerArgs};
+use crate::block::{
+    BlockBehaviour, BlockFuture, EmitsRedstonePowerArgs, GetRedstonePowerArgs, OnProjectileHitArgs,
+    OnScheduledTickArgs,
+};
+
+type TargetProperties = pumpkin_data::block_properties::LightWeightedPressurePlateLikeProperties;
+
+/// How long the target stays powered after being hit by a thrown projectile
+// TODO: Arrows and tridents keep it powered for 20 ticks once they exist
+const PROJECTILE_DELAY: u8 = 8;
 
 #[pumpkin_block("minecraft:target")]
 pub struct TargetBlock;
 
+impl TargetBlock {
+    /// The closer the hit is to the center of the face, the stronger the signal.
+    fn calculate_power(face: BlockDirection, hit_pos: Vector3<f64>) -> u8 {
+        let dx = (hit_pos.x - hit_pos.x.floor() - 0.5).abs();
+        let dy = (hit_pos.y - hit_pos.y.floor() - 0.5).abs();
+        let dz = (hit_pos.z - hit_pos.z.floor() - 0.5).abs();
+        let distance = match face.to_axis() {
+            Axis::Y => dx.max(dz),
+            Axis::Z => dx.max(dy),
+            Axis::X => dy.max(dz),
+        };
+        let power = (15.0 * ((0.5 - distance) / 0.5).clamp(0.0, 1.0)).ceil() as u8;
+        power.max(1)
+    }
+}
+
 impl BlockBehaviour for TargetBlock {
+    fn on_projectile_hit<'a>(&'a self, args: OnProjectileHitArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            // A target keeps the power of the first hit until it resets
+            if args
+                .world
+                .is_block_tick_scheduled(args.position, args.block)
+                .await
+            {
+                return;
+            }
+
+            let mut props = TargetProperties::from_state_id(args.state.id, args.block);
+            props.power =
+                Integer0To15::from_index(u16::from(Self::calculate_power(args.face, args.hit_pos)));
+            args.world
+                .set_block_state(
+                    args.position,
+                    props.to_state_id(args.block),
+                    BlockFlags::NOTIFY_ALL,
+                )
+                .await;
+            args.world
+                .schedule_block_tick(
+                    args.block,
+                    *args.position,
+                    PROJECTILE_DELAY,
+                    TickPriority::Normal,
+                )
+                .await;
+        })
+    }
+
+    fn on_scheduled_tick<'a>(&'a self, args: OnScheduledTickArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let state = args.world.get_block_state(args.position).await;
+            let mut props = TargetProperties::from_state_id(state.id, args.block);
+            if props.power.to_index() != 0 {
+                props.power = Integer0To15::L0;
+                args.world
+                    .set_block_state(
+                        args.position,
+                        props.to_state_id(args.block),
+                        BlockFlags::NOTIFY_ALL,
+                    )
+                    .await;
+            }
+        })
+    }
+
     fn emits_redstone_power<'a>(
         &'a self,
         _args: EmitsRedstonePowerArgs<'a>,
     ) -> BlockFuture<'a, bool> {
         Box::pin(async move { true })
     }
+
+    fn get_weak_redstone_power<'a>(
+        &'a self,
+        args: GetRedstonePowerArgs<'a>,
+    ) -> BlockFuture<'a, u8> {
+        Box::pin(async move {
+            let props = TargetProperties::from_state_id(args.state.id, args.block);
+            props.power.to_index() as u8
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::BlockDirection;
+    use pumpkin_util::math::vector3::Vector3;
+
+    use super::TargetBlock;
+
+    #[test]
+    fn hits_closer_to_the_center_are_stronger() {
+        let power =
+            |x, z| TargetBlock::calculate_power(BlockDirection::Up, Vector3::new(x, 1.0, z));
+        assert_eq!(power(0.5, 0.5), 15);
+        assert_eq!(power(0.75, 0.5), 8);
+        assert_eq!(power(0.0, 0.5), 1);
+        // The farther axis decides
+        assert_eq!(power(0.5, 0.99), 1);
+    }
 }
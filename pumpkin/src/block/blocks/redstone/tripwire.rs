@@ -6,14 +6,14 @@ use pumpkin_data::{
     block_properties::{BlockProperties, HorizontalFacing},
 };
 use pumpkin_macros::pumpkin_block;
-use pumpkin_util::math::{boundingbox::BoundingBox, position::BlockPos};
+use pumpkin_util::math::position::BlockPos;
 use pumpkin_world::{BlockStateId, tick::TickPriority, world::BlockFlags};
 
 use crate::block::BlockFuture;
 use crate::{
     block::{
-        BlockBehaviour, BrokenArgs, GetStateForNeighborUpdateArgs, OnEntityCollisionArgs,
-        OnPlaceArgs, OnScheduledTickArgs, OnStateReplacedArgs, PlacedArgs,
+        BlockBehaviour, BrokenArgs, GetStateForNeighborUpdateArgs, OnPlaceArgs,
+        OnScheduledTickArgs, OnStateReplacedArgs, OnWalkedOverArgs, PlacedArgs,
     },
     world::World,
 };
@@ -27,7 +27,7 @@ type TripwireHookProperties = pumpkin_data::block_properties::TripwireHookLikePr
 pub struct TripwireBlock;
 
 impl BlockBehaviour for TripwireBlock {
-    fn on_entity_collision<'a>(&'a self, args: OnEntityCollisionArgs<'a>) -> BlockFuture<'a, ()> {
+    fn on_walked_over<'a>(&'a self, args: OnWalkedOverArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
             let mut props = TripwireProperties::from_state_id(args.state.id, args.block);
             if props.powered {
@@ -138,11 +138,8 @@ impl BlockBehaviour for TripwireBlock {
                 return;
             }
 
-            let aabb = BoundingBox::from_block(args.position);
             // TODO entity.canAvoidTraps()
-            if args.world.get_entities_at_box(&aabb).is_empty()
-                && args.world.get_players_at_box(&aabb).is_empty()
-            {
+            if args.world.find_entities_on_block(args.position).is_empty() {
                 props.powered = false;
                 let state_id = props.to_state_id(args.block);
                 args.world
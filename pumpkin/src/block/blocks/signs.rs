@@ -11,6 +11,7 @@ use pumpkin_macros::pumpkin_block_from_tag;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::BlockStateId;
+use pumpkin_world::block::entities::BlockEntity;
 use pumpkin_world::block::entities::sign::SignBlockEntity;
 use uuid::Uuid;
 
@@ -429,10 +430,11 @@ impl BlockBehaviour for SignBlock {
     /// Handles normal use (right-click) on the sign block.
     fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
         Box::pin(async move {
-            let Some(block_entity) = args.world.get_block_entity(args.position).await else {
-                return BlockActionResult::Pass;
-            };
-            let Some(sign_entity) = block_entity.as_any().downcast_ref::<SignBlockEntity>() else {
+            let Some(sign_entity) = args
+                .world
+                .get_block_entity_data::<SignBlockEntity>(args.position)
+                .await
+            else {
                 return BlockActionResult::Pass;
             };
 
@@ -477,12 +479,14 @@ impl BlockBehaviour for SignBlock {
         args: UseWithItemArgs<'a>,
     ) -> BlockFuture<'a, BlockActionResult> {
         Box::pin(async move {
-            let Some(block_entity) = args.world.get_block_entity(args.position).await else {
-                return BlockActionResult::Pass;
-            };
-            let Some(sign_entity) = block_entity.as_any().downcast_ref::<SignBlockEntity>() else {
+            let Some(sign_entity) = args
+                .world
+                .get_block_entity_data::<SignBlockEntity>(args.position)
+                .await
+            else {
                 return BlockActionResult::Pass;
             };
+            let block_entity: Arc<dyn BlockEntity> = sign_entity.clone();
 
             if sign_entity.is_waxed.load(Ordering::Relaxed) {
                 return BlockActionResult::PassToDefaultBlockAction;
@@ -517,7 +521,7 @@ impl BlockBehaviour for SignBlock {
                 pumpkin_item.as_any().downcast_ref::<HoneyCombItem>()
             {
                 honeycomb_item
-                    .apply_to_sign(&args, &block_entity, sign_entity)
+                    .apply_to_sign(&args, &block_entity, &sign_entity)
                     .await
             } else if let Some(g_ink_sac_item) =
                 pumpkin_item.as_any().downcast_ref::<GlowingInkSacItem>()
@@ -1,8 +1,10 @@
+use std::sync::atomic::Ordering;
+
 use pumpkin_macros::pumpkin_block;
 
 use crate::block::{
-    BlockBehaviour, BlockFuture, OnLandedUponArgs, UpdateEntityMovementAfterFallOnArgs,
-    bounce_entity_after_fall,
+    BlockBehaviour, BlockFuture, OnLandedUponArgs, OnSteppedOnArgs,
+    UpdateEntityMovementAfterFallOnArgs, bounce_entity_after_fall,
 };
 
 #[pumpkin_block("minecraft:slime_block")]
@@ -12,8 +14,14 @@ impl BlockBehaviour for SlimeBlock {
     fn on_landed_upon<'a>(&'a self, args: OnLandedUponArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
             if let Some(living) = args.entity.get_living_entity() {
+                // Sneaking entities don't bounce, so they take the full fall damage
+                let damage_per_distance = if living.entity.sneaking.load(Ordering::Relaxed) {
+                    1.0
+                } else {
+                    0.0
+                };
                 living
-                    .handle_fall_damage(args.entity, args.fall_distance, 0.0)
+                    .handle_fall_damage(args.entity, args.fall_distance, damage_per_distance)
                     .await;
             }
         })
@@ -25,4 +33,18 @@ impl BlockBehaviour for SlimeBlock {
     ) -> BlockFuture<'a, ()> {
         Box::pin(async move { bounce_entity_after_fall(args.entity, 1.0) })
     }
+
+    fn on_stepped_on<'a>(&'a self, args: OnSteppedOnArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async move {
+            let entity = args.entity.get_entity();
+            let mut velocity = entity.velocity.load();
+            let vertical_speed = velocity.y.abs();
+            if vertical_speed < 0.1 && !entity.sneaking.load(Ordering::Relaxed) {
+                let factor = vertical_speed.mul_add(0.2, 0.4);
+                velocity.x *= factor;
+                velocity.z *= factor;
+                entity.velocity.store(velocity);
+            }
+        })
+    }
 }
@@ -0,0 +1,50 @@
+use crate::block::registry::BlockActionResult;
+use crate::block::{BlockBehaviour, BlockFuture, NormalUseArgs};
+
+use pumpkin_data::translation;
+use pumpkin_inventory::player::player_inventory::PlayerInventory;
+use pumpkin_inventory::screen_handler::{
+    BoxFuture, InventoryPlayer, ScreenHandlerFactory, SharedScreenHandler,
+};
+use pumpkin_inventory::smithing_screen_handler::SmithingScreenHandler;
+use pumpkin_macros::pumpkin_block;
+use pumpkin_util::text::TextComponent;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[pumpkin_block("minecraft:smithing_table")]
+pub struct SmithingTableBlock;
+
+impl BlockBehaviour for SmithingTableBlock {
+    fn normal_use<'a>(&'a self, args: NormalUseArgs<'a>) -> BlockFuture<'a, BlockActionResult> {
+        Box::pin(async move {
+            args.player
+                .open_handled_screen(&SmithingTableScreenFactory, Some(*args.position))
+                .await;
+
+            BlockActionResult::Success
+        })
+    }
+}
+
+struct SmithingTableScreenFactory;
+
+impl ScreenHandlerFactory for SmithingTableScreenFactory {
+    fn create_screen_handler<'a>(
+        &'a self,
+        sync_id: u8,
+        player_inventory: &'a Arc<PlayerInventory>,
+        _player: &'a dyn InventoryPlayer,
+    ) -> BoxFuture<'a, Option<SharedScreenHandler>> {
+        Box::pin(async move {
+            let handler = SmithingScreenHandler::new(sync_id, player_inventory).await;
+            let concrete_arc = Arc::new(Mutex::new(handler));
+
+            Some(concrete_arc as SharedScreenHandler)
+        })
+    }
+
+    fn get_display_name(&self) -> TextComponent {
+        TextComponent::translate(translation::CONTAINER_UPGRADE, &[])
+    }
+}
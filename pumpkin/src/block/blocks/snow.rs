@@ -113,7 +113,7 @@ impl BlockBehaviour for LayeredSnowBlock {
     }
 }
 
-async fn can_place_at(block_accessor: &dyn BlockAccessor, position: &BlockPos) -> bool {
+pub async fn can_place_at(block_accessor: &dyn BlockAccessor, position: &BlockPos) -> bool {
     let state = block_accessor.get_block_state(&position.down()).await;
     state.is_side_solid(BlockDirection::Up)
 }
@@ -5,8 +5,9 @@ use crate::block::registry::BlockActionResult;
 use crate::block::{
     BlockBehaviour, BlockFuture, ExplodeArgs, OnNeighborUpdateArgs, PlacedArgs, UseWithItemArgs,
 };
-use crate::entity::Entity;
+use crate::entity::player::Player;
 use crate::entity::tnt::TNTEntity;
+use crate::entity::{Entity, EntityBase};
 use crate::world::World;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::sound::SoundCategory;
@@ -22,10 +23,10 @@ use super::redstone::block_receives_redstone_power;
 pub struct TNTBlock;
 
 impl TNTBlock {
-    pub async fn prime(world: &Arc<World>, location: &BlockPos) {
-        let entity = Entity::new(world.clone(), location.to_f64(), &EntityType::TNT);
-        let pos = entity.pos.load();
-        let tnt = Arc::new(TNTEntity::new(entity, DEFAULT_POWER, DEFAULT_FUSE));
+    /// Replaces the TNT at `location` with primed TNT, whose explosion is attributed to `igniter`.
+    pub async fn prime(world: &Arc<World>, location: &BlockPos, igniter: Option<Arc<Player>>) {
+        let tnt = Self::primed_tnt(world, location, igniter);
+        let pos = tnt.get_entity().pos.load();
         world.spawn_entity(tnt).await;
         world
             .play_sound(
@@ -38,6 +39,15 @@ impl TNTBlock {
             .set_block_state(location, 0, BlockFlags::NOTIFY_ALL)
             .await;
     }
+
+    fn primed_tnt(
+        world: &Arc<World>,
+        location: &BlockPos,
+        igniter: Option<Arc<Player>>,
+    ) -> Arc<TNTEntity> {
+        let entity = Entity::new(world.clone(), location.to_f64(), &EntityType::TNT);
+        Arc::new(TNTEntity::new(entity, DEFAULT_POWER, DEFAULT_FUSE, igniter))
+    }
 }
 
 const DEFAULT_FUSE: u32 = 80;
@@ -54,7 +64,8 @@ impl BlockBehaviour for TNTBlock {
                 return BlockActionResult::Pass;
             }
             let world = args.player.world();
-            Self::prime(&world, args.position).await;
+            let igniter = world.get_player_by_uuid(args.player.gameprofile.id);
+            Self::prime(&world, args.position, igniter).await;
 
             BlockActionResult::Consume
         })
@@ -63,7 +74,7 @@ impl BlockBehaviour for TNTBlock {
     fn placed<'a>(&'a self, args: PlacedArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
             if block_receives_redstone_power(args.world, args.position).await {
-                Self::prime(args.world, args.position).await;
+                Self::prime(args.world, args.position, None).await;
             }
         })
     }
@@ -71,7 +82,7 @@ impl BlockBehaviour for TNTBlock {
     fn on_neighbor_update<'a>(&'a self, args: OnNeighborUpdateArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async move {
             if block_receives_redstone_power(args.world, args.position).await {
-                Self::prime(args.world, args.position).await;
+                Self::prime(args.world, args.position, None).await;
             }
         })
     }
@@ -84,7 +95,7 @@ impl BlockBehaviour for TNTBlock {
                 .set_velocity(Vector3::new(-angle.sin() * 0.02, 0.2, -angle.cos() * 0.02))
                 .await;
             let fuse = rand::rng().random_range(0..DEFAULT_FUSE / 4) + DEFAULT_FUSE / 8;
-            let tnt = Arc::new(TNTEntity::new(entity, DEFAULT_POWER, fuse));
+            let tnt = Arc::new(TNTEntity::new(entity, DEFAULT_POWER, fuse, None));
             args.world.spawn_entity(tnt).await;
         })
     }
@@ -93,3 +104,45 @@ impl BlockBehaviour for TNTBlock {
         false
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arc_swap::ArcSwap;
+    use pumpkin_data::Block;
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::position::BlockPos;
+    use pumpkin_util::math::vector2::Vector2;
+    use pumpkin_util::world_seed::Seed;
+    use pumpkin_world::world::BlockFlags;
+    use pumpkin_world::world_info::LevelData;
+    use tempfile::tempdir;
+
+    use super::TNTBlock;
+    use crate::entity::EntityBase;
+    use crate::world::test::{load_empty_chunk, test_world};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn redstone_primed_tnt_has_no_igniter() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        load_empty_chunk(&world, Vector2::new(0, 0));
+        let pos = BlockPos::new(8, 64, 8);
+        world
+            .set_block_state(&pos, Block::TNT.default_state.id, BlockFlags::NOTIFY_ALL)
+            .await;
+
+        assert!(TNTBlock::primed_tnt(&world, &pos, None).igniter().is_none());
+
+        TNTBlock::prime(&world, &pos, None).await;
+        assert!(world.get_block_state(&pos).await.is_air());
+        let entities = world.entities.load();
+        assert_eq!(entities.len(), 1);
+        let tnt = entities[0].get_entity();
+        assert!(tnt.entity_type == &EntityType::TNT);
+        assert_eq!(tnt.block_pos.load(), pos);
+    }
+}
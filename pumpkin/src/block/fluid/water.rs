@@ -69,7 +69,7 @@ impl FluidBehaviour for FlowingWater {
 
     fn on_entity_collision<'a>(&'a self, entity: &'a dyn EntityBase) -> BlockFuture<'a, ()> {
         Box::pin(async {
-            entity.get_entity().extinguish();
+            entity.get_entity().extinguish().await;
         })
     }
 }
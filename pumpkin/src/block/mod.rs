@@ -1,5 +1,5 @@
 use pumpkin_data::block_properties::Integer0To15;
-use pumpkin_data::{Block, BlockState};
+use pumpkin_data::{Block, BlockState, Enchantment};
 
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::random::{RandomGenerator, get_seed, xoroshiro128::Xoroshiro};
@@ -138,6 +138,21 @@ pub trait BlockBehaviour: Send + Sync {
         })
     }
 
+    /// Called every tick for an entity standing on top of this block.
+    fn on_stepped_on<'a>(&'a self, _args: OnSteppedOnArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async {})
+    }
+
+    /// Called every tick while an entity's bounding box overlaps this block.
+    fn on_walked_over<'a>(&'a self, _args: OnWalkedOverArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async {})
+    }
+
+    /// Called when a projectile hits this block.
+    fn on_projectile_hit<'a>(&'a self, _args: OnProjectileHitArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async {})
+    }
+
     fn broken<'a>(&'a self, _args: BrokenArgs<'a>) -> BlockFuture<'a, ()> {
         Box::pin(async {})
     }
@@ -166,6 +181,11 @@ pub trait BlockBehaviour: Send + Sync {
         Box::pin(async {})
     }
 
+    /// Called after this block formed in the world on its own, e.g. ice from freezing water or snow from snowfall.
+    fn on_block_formed<'a>(&'a self, _args: OnBlockFormedArgs<'a>) -> BlockFuture<'a, ()> {
+        Box::pin(async {})
+    }
+
     // --- Redstone/Comparator Methods ---
 
     /// Sides where redstone connects to
@@ -321,6 +341,34 @@ pub struct UpdateEntityMovementAfterFallOnArgs<'a> {
     pub entity: &'a dyn EntityBase,
 }
 
+pub struct OnSteppedOnArgs<'a> {
+    pub world: &'a Arc<World>,
+    pub block: &'a Block,
+    pub state: &'a BlockState,
+    pub position: &'a BlockPos,
+    pub entity: &'a dyn EntityBase,
+}
+
+pub struct OnWalkedOverArgs<'a> {
+    pub world: &'a Arc<World>,
+    pub block: &'a Block,
+    pub state: &'a BlockState,
+    pub position: &'a BlockPos,
+    pub entity: &'a dyn EntityBase,
+}
+
+pub struct OnProjectileHitArgs<'a> {
+    pub world: &'a Arc<World>,
+    pub block: &'a Block,
+    pub state: &'a BlockState,
+    pub position: &'a BlockPos,
+    /// The face of the block which was hit
+    pub face: BlockDirection,
+    /// The exact point of impact
+    pub hit_pos: Vector3<f64>,
+    pub projectile: &'a dyn EntityBase,
+}
+
 pub struct BrokenArgs<'a> {
     pub block: &'a Block,
     pub player: &'a Arc<Player>,
@@ -370,6 +418,22 @@ pub struct OnStateReplacedArgs<'a> {
     pub moved: bool,
 }
 
+/// Why a block formed in the world.
+#[derive(Clone, Copy)]
+pub struct FormCause {
+    /// Water froze into ice.
+    pub by_freezing: bool,
+    /// Snow fell during precipitation.
+    pub by_precipitation: bool,
+}
+
+pub struct OnBlockFormedArgs<'a> {
+    pub world: &'a Arc<World>,
+    pub block: &'a Block,
+    pub position: &'a BlockPos,
+    pub cause: FormCause,
+}
+
 pub struct EmitsRedstonePowerArgs<'a> {
     pub block: &'a Block,
     pub state: &'a BlockState,
@@ -432,6 +496,21 @@ pub async fn calc_block_breaking(
     player: &Player,
     state: &BlockState,
     block: &'static Block,
+) -> f32 {
+    let progress = {
+        let held = player.inventory.held_item().lock().await;
+        calc_tool_breaking(state, block, Some(&held))
+    };
+    progress * player.get_mining_speed_multiplier().await
+}
+
+/// Returns the breaking progress per tick on `state` with `tool`, or with an empty hand if there
+/// is none, before the miner's status effects and position are taken into account.
+#[must_use]
+pub fn calc_tool_breaking(
+    state: &BlockState,
+    block: &'static Block,
+    tool: Option<&ItemStack>,
 ) -> f32 {
     let hardness = state.hardness;
     #[expect(clippy::float_cmp)]
@@ -439,13 +518,19 @@ pub async fn calc_block_breaking(
         // unbreakable
         return 0.0;
     }
-    let i = if player.can_harvest(state, block).await {
-        30.0
-    } else {
-        100.0
-    };
-
-    player.get_mining_speed(block).await / hardness / i
+    let can_harvest =
+        !state.tool_required() || tool.is_some_and(|tool| tool.is_correct_for_drops(block));
+    let i = if can_harvest { 30.0 } else { 100.0 };
+
+    let mut speed = tool.map_or(1.0, |tool| tool.get_speed(block));
+    let efficiency = tool.map_or(0, |tool| {
+        tool.get_enchantment_level(&Enchantment::EFFICIENCY)
+    });
+    // Efficiency only helps tools that are already faster than a hand
+    if speed > 1.0 && efficiency > 0 {
+        speed += (efficiency * efficiency + 1) as f32;
+    }
+    speed / hardness / i
 }
 
 #[derive(PartialEq, Eq, Debug)]
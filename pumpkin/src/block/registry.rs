@@ -4,6 +4,7 @@ use crate::block::blocks::banners::BannerBlock;
 use crate::block::blocks::barrel::BarrelBlock;
 use crate::block::blocks::barrier::BarrierBlock;
 use crate::block::blocks::bed::BedBlock;
+use crate::block::blocks::bubble_column::BubbleColumnBlock;
 use crate::block::blocks::cake::CakeBlock;
 use crate::block::blocks::campfire::CampfireBlock;
 use crate::block::blocks::candle_cakes::CandleCakeBlock;
@@ -30,6 +31,7 @@ use crate::block::blocks::glass_panes::GlassPaneBlock;
 use crate::block::blocks::glazed_terracotta::GlazedTerracottaBlock;
 use crate::block::blocks::grindstone::GrindstoneBlock;
 use crate::block::blocks::hay::HayBlock;
+use crate::block::blocks::honey::HoneyBlock;
 use crate::block::blocks::infested::InfestedBlock;
 use crate::block::blocks::iron_bars::IronBarsBlock;
 use crate::block::blocks::logs::LogBlock;
@@ -68,6 +70,7 @@ use crate::block::blocks::pumpkin::PumpkinBlock;
 use crate::block::blocks::redstone::buttons::ButtonBlock;
 use crate::block::blocks::redstone::comparator::ComparatorBlock;
 use crate::block::blocks::redstone::copper_bulb::CopperBulbBlock;
+use crate::block::blocks::redstone::daylight_detector::DaylightDetectorBlock;
 use crate::block::blocks::redstone::dispenser::DispenserBlock;
 use crate::block::blocks::redstone::dropper::DropperBlock;
 use crate::block::blocks::redstone::lever::LeverBlock;
@@ -90,10 +93,12 @@ use crate::block::blocks::shelf::ShelfBlock;
 use crate::block::blocks::signs::SignBlock;
 use crate::block::blocks::slabs::SlabBlock;
 use crate::block::blocks::slime::SlimeBlock;
+use crate::block::blocks::smithing_table::SmithingTableBlock;
 use crate::block::blocks::snow::LayeredSnowBlock;
 use crate::block::blocks::spawner::SpawnerBlock;
 use crate::block::blocks::sponge::{SpongeBlock, WetSpongeBlock};
 use crate::block::blocks::stairs::StairBlock;
+use crate::block::blocks::stonecutter::StonecutterBlock;
 use crate::block::blocks::tnt::TNTBlock;
 use crate::block::blocks::torches::TorchBlock;
 use crate::block::blocks::trapdoor::TrapDoorBlock;
@@ -103,8 +108,9 @@ use crate::block::blocks::wither_skull::WitherSkeletonSkullBlock;
 use crate::block::fluid::lava::FlowingLava;
 use crate::block::fluid::water::FlowingWater;
 use crate::block::{
-    BlockBehaviour, BlockHitResult, BlockMetadata, GetInsideCollisionShapeArgs,
-    OnEntityCollisionArgs, OnLandedUponArgs, UpdateEntityMovementAfterFallOnArgs,
+    BlockBehaviour, BlockHitResult, BlockMetadata, FormCause, GetInsideCollisionShapeArgs,
+    OnBlockFormedArgs, OnEntityCollisionArgs, OnLandedUponArgs, OnProjectileHitArgs,
+    OnSteppedOnArgs, OnWalkedOverArgs, UpdateEntityMovementAfterFallOnArgs,
     stop_vertical_movement_after_fall,
 };
 use crate::entity::EntityBase;
@@ -117,11 +123,12 @@ use pumpkin_data::{Block, BlockDirection, BlockState};
 use pumpkin_protocol::java::server::play::SUseItemOn;
 use pumpkin_util::math::boundingbox::BoundingBox;
 use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::BlockStateId;
 use pumpkin_world::item::ItemStack;
 use pumpkin_world::world::{BlockAccessor, BlockFlags, BlockRegistryExt};
 use rustc_hash::FxHashMap;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 use tokio::sync::Mutex;
 
 use super::BlockIsReplacing;
@@ -157,6 +164,7 @@ pub fn default_registry() -> Arc<BlockRegistry> {
     // Blocks
     manager.register(AnvilBlock);
     manager.register(BedBlock);
+    manager.register(BubbleColumnBlock);
     manager.register(SaplingBlock);
     manager.register(CactusBlock);
     manager.register(CarpetBlock);
@@ -182,6 +190,7 @@ pub fn default_registry() -> Arc<BlockRegistry> {
     manager.register(GlassPaneBlock);
     manager.register(GlazedTerracottaBlock);
     manager.register(HayBlock);
+    manager.register(HoneyBlock);
     manager.register(GrindstoneBlock);
     manager.register(IronBarsBlock);
     manager.register(InfestedBlock);
@@ -193,7 +202,9 @@ pub fn default_registry() -> Arc<BlockRegistry> {
     manager.register(SignBlock);
     manager.register(SlabBlock);
     manager.register(SlimeBlock);
+    manager.register(SmithingTableBlock);
     manager.register(StairBlock);
+    manager.register(StonecutterBlock);
     manager.register(ShortPlantBlock);
     manager.register(DryVegetationBlock);
     manager.register(LilyPadBlock);
@@ -275,6 +286,7 @@ pub fn default_registry() -> Arc<BlockRegistry> {
     manager.register(RepeaterBlock);
     manager.register(ComparatorBlock);
     manager.register(TargetBlock);
+    manager.register(DaylightDetectorBlock);
     manager.register(BarrelBlock);
     manager.register(HopperBlock);
     manager.register(ShulkerBoxBlock);
@@ -318,6 +330,33 @@ impl BlockActionResult {
     }
 }
 
+/// The path cost of blocks without an entry in [`PATH_COSTS`].
+pub const DEFAULT_PATH_COST: f32 = 1.0;
+
+/// Path costs of the blocks that differ from [`DEFAULT_PATH_COST`], indexed by block id.
+static PATH_COSTS: LazyLock<Box<[f32]>> = LazyLock::new(|| {
+    let costs = [
+        (&Block::LAVA, f32::MAX),
+        (&Block::FIRE, f32::MAX),
+        (&Block::SOUL_FIRE, f32::MAX),
+        (&Block::WATER, 8.0),
+        (&Block::SOUL_SAND, 2.5),
+        (&Block::HONEY_BLOCK, 2.5),
+        (&Block::COBWEB, 5.0),
+        (&Block::SLIME_BLOCK, 0.5),
+    ];
+    let len = costs
+        .iter()
+        .map(|(block, _)| usize::from(block.id) + 1)
+        .max()
+        .unwrap_or(0);
+    let mut table = vec![DEFAULT_PATH_COST; len];
+    for (block, cost) in costs {
+        table[usize::from(block.id)] = cost;
+    }
+    table.into_boxed_slice()
+});
+
 #[derive(Default)]
 pub struct BlockRegistry {
     blocks: FxHashMap<u16, Arc<dyn BlockBehaviour>>,
@@ -661,6 +700,25 @@ impl BlockRegistry {
         }
     }
 
+    pub async fn on_block_formed(
+        &self,
+        world: &Arc<World>,
+        position: &BlockPos,
+        block: &Block,
+        cause: FormCause,
+    ) {
+        if let Some(pumpkin_block) = self.get_pumpkin_block(block.id) {
+            pumpkin_block
+                .on_block_formed(OnBlockFormedArgs {
+                    world,
+                    block,
+                    position,
+                    cause,
+                })
+                .await;
+        }
+    }
+
     pub async fn on_landed_upon(
         &self,
         block: &Block,
@@ -696,6 +754,72 @@ impl BlockRegistry {
         }
     }
 
+    pub async fn on_stepped_on(
+        &self,
+        block: &Block,
+        world: &Arc<World>,
+        entity: &dyn EntityBase,
+        position: &BlockPos,
+        state: &BlockState,
+    ) {
+        if let Some(pumpkin_block) = self.get_pumpkin_block(block.id) {
+            pumpkin_block
+                .on_stepped_on(OnSteppedOnArgs {
+                    world,
+                    block,
+                    state,
+                    position,
+                    entity,
+                })
+                .await;
+        }
+    }
+
+    pub async fn on_walked_over(
+        &self,
+        block: &Block,
+        world: &Arc<World>,
+        entity: &dyn EntityBase,
+        position: &BlockPos,
+        state: &BlockState,
+    ) {
+        if let Some(pumpkin_block) = self.get_pumpkin_block(block.id) {
+            pumpkin_block
+                .on_walked_over(OnWalkedOverArgs {
+                    world,
+                    block,
+                    state,
+                    position,
+                    entity,
+                })
+                .await;
+        }
+    }
+
+    pub async fn on_projectile_hit(
+        &self,
+        world: &Arc<World>,
+        projectile: &dyn EntityBase,
+        position: &BlockPos,
+        face: BlockDirection,
+        hit_pos: Vector3<f64>,
+    ) {
+        let (block, state) = world.get_block_and_state(position).await;
+        if let Some(pumpkin_block) = self.get_pumpkin_block(block.id) {
+            pumpkin_block
+                .on_projectile_hit(OnProjectileHitArgs {
+                    world,
+                    block,
+                    state,
+                    position,
+                    face,
+                    hit_pos,
+                    projectile,
+                })
+                .await;
+        }
+    }
+
     pub async fn broken(
         &self,
         world: &Arc<World>,
@@ -863,6 +987,19 @@ impl BlockRegistry {
         }
     }
 
+    /// How costly it is for a mob to path through the block. Air costs nothing since the mob
+    /// drops through it, and `f32::MAX` means the block can't be passed at all.
+    #[must_use]
+    pub fn get_path_cost(block: &Block, state: &BlockState) -> f32 {
+        if state.is_air() {
+            return 0.0;
+        }
+        PATH_COSTS
+            .get(usize::from(block.id))
+            .copied()
+            .unwrap_or(DEFAULT_PATH_COST)
+    }
+
     #[must_use]
     pub fn get_pumpkin_block(&self, block: u16) -> Option<&Arc<dyn BlockBehaviour>> {
         self.blocks.get(&block)
@@ -966,3 +1103,23 @@ impl BlockRegistry {
         BoundingBox::full_block()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::Block;
+
+    use super::{BlockRegistry, DEFAULT_PATH_COST};
+
+    fn cost(block: &Block) -> f32 {
+        BlockRegistry::get_path_cost(block, block.default_state)
+    }
+
+    #[test]
+    fn path_costs() {
+        assert_eq!(cost(&Block::STONE), DEFAULT_PATH_COST);
+        assert_eq!(cost(&Block::AIR), 0.0);
+        assert_eq!(cost(&Block::LAVA), f32::MAX);
+        assert!(cost(&Block::SOUL_SAND) > DEFAULT_PATH_COST);
+        assert!(cost(&Block::SLIME_BLOCK) < DEFAULT_PATH_COST);
+    }
+}
@@ -2,14 +2,14 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::command::CommandSender;
-use crate::command::args::ConsumeResult;
+use crate::command::args::{ConsumeResult, SuggestResult};
 use crate::command::dispatcher::CommandError;
 use crate::command::tree::RawArgs;
 use crate::entity::EntityBase;
 use crate::server::Server;
 use pumpkin_data::entity::EntityType;
 use pumpkin_nbt::compound::NbtCompound;
-use pumpkin_protocol::java::client::play::{ArgumentType, SuggestionProviders};
+use pumpkin_protocol::java::client::play::{ArgumentType, CommandSuggestion, SuggestionProviders};
 use pumpkin_util::GameMode;
 use tracing::debug;
 use uuid::Uuid;
@@ -44,6 +44,47 @@ pub enum ComparableValueCondition<T> {
     Between(T, T),
 }
 
+impl<T: PartialOrd> ComparableValueCondition<T> {
+    #[must_use]
+    pub fn matches(&self, value: &T) -> bool {
+        match self {
+            Self::Equals(expected) => value == expected,
+            Self::NotEquals(expected) => value != expected,
+            Self::GreaterThan(min) => value > min,
+            Self::LessThan(max) => value < max,
+            Self::GreaterThanOrEquals(min) => value >= min,
+            Self::LessThanOrEquals(max) => value <= max,
+            Self::Between(min, max) => value >= min && value <= max,
+        }
+    }
+}
+
+impl FromStr for ComparableValueCondition<f64> {
+    type Err = String;
+
+    /// Parses a range like `5`, `..5`, `5..` or `1..5`, where both ends are inclusive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |bound: &str| {
+            bound
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid number {bound}"))
+        };
+        match s.split_once("..") {
+            None => Ok(Self::Equals(parse(s)?)),
+            Some(("", "")) => Err("Range without bounds".to_string()),
+            Some(("", max)) => Ok(Self::LessThanOrEquals(parse(max)?)),
+            Some((min, "")) => Ok(Self::GreaterThanOrEquals(parse(min)?)),
+            Some((min, max)) => {
+                let (min, max) = (parse(min)?, parse(max)?);
+                if min > max {
+                    return Err(format!("Range minimum {min} is above its maximum {max}"));
+                }
+                Ok(Self::Between(min, max))
+            }
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum EntityFilterSort {
     Arbitrary,
@@ -88,7 +129,8 @@ impl FromStr for EntityFilter {
         match key {
             "type" => {
                 let entity_type =
-                    EntityType::from_name(value).ok_or(format!("Invalid entity type {value}"))?;
+                    EntityType::from_name(value.strip_prefix("minecraft:").unwrap_or(value))
+                        .ok_or(format!("Invalid entity type {value}"))?;
                 Ok(Self::Type(if negate {
                     ValueCondition::NotEquals(entity_type)
                 } else {
@@ -117,7 +159,31 @@ impl FromStr for EntityFilter {
                 }
                 Ok(Self::Sort(sort))
             }
-            _ => todo!("{key}"),
+            "name" => Ok(Self::Name(if negate {
+                ValueCondition::NotEquals(value.to_string())
+            } else {
+                ValueCondition::Equals(value.to_string())
+            })),
+            "gamemode" => {
+                let gamemode =
+                    GameMode::from_str(value).map_err(|_| format!("Invalid game mode {value}"))?;
+                Ok(Self::Gamemode(if negate {
+                    ValueCondition::NotEquals(gamemode)
+                } else {
+                    ValueCondition::Equals(gamemode)
+                }))
+            }
+            "distance" => {
+                if negate {
+                    return Err("Negation of distance is not allowed".to_string());
+                }
+                if value.contains('-') {
+                    return Err("Distance cannot be negative".to_string());
+                }
+                Ok(Self::Distance(value.parse()?))
+            }
+            // todo: tag and the remaining filters
+            _ => Err(format!("Unsupported entity filter {key}")),
         }
     }
 }
@@ -234,6 +300,74 @@ impl FromStr for TargetSelector {
     }
 }
 
+/// Server side tab completion for target selectors, eg. `@e[type=minecraft:zombie,limit=1]`.
+pub struct EntitySelectorSuggester;
+
+impl EntitySelectorSuggester {
+    const SELECTORS: [&str; 6] = ["@a", "@e", "@n", "@p", "@r", "@s"];
+    const OPTIONS: [&str; 6] = ["distance", "gamemode", "limit", "name", "sort", "type"];
+    const GAMEMODES: [&str; 4] = ["adventure", "creative", "spectator", "survival"];
+    const SORTS: [&str; 4] = ["arbitrary", "furthest", "nearest", "random"];
+
+    /// Suggests completions for the selector being typed as the last word of `input`.
+    #[must_use]
+    pub fn suggest(server: &Server, input: &str) -> Vec<CommandSuggestion> {
+        let player_names: Vec<String> = server
+            .get_all_players()
+            .iter()
+            .map(|player| player.gameprofile.name.clone())
+            .collect();
+        let word = input.rsplit(char::is_whitespace).next().unwrap_or_default();
+        Self::suggest_word(word, &player_names)
+    }
+
+    fn suggest_word(word: &str, player_names: &[String]) -> Vec<CommandSuggestion> {
+        let Some(open) = word.find('[') else {
+            return Self::SELECTORS
+                .into_iter()
+                .chain(player_names.iter().map(String::as_str))
+                .filter(|candidate| candidate.starts_with(word))
+                .map(|candidate| CommandSuggestion::new(candidate.to_string(), None))
+                .collect();
+        };
+        if word.ends_with(']') || !Self::SELECTORS.contains(&&word[..open]) {
+            return Vec::new();
+        }
+
+        // Everything before the option that is being typed is kept as is
+        let option_start = word.rfind(',').unwrap_or(open) + 1;
+        let (prefix, option) = word.split_at(option_start);
+        let Some((key, value)) = option.split_once('=') else {
+            return Self::OPTIONS
+                .into_iter()
+                .filter(|key| key.starts_with(option))
+                .map(|key| CommandSuggestion::new(format!("{prefix}{key}="), None))
+                .collect();
+        };
+
+        let (negation, value) = value
+            .strip_prefix('!')
+            .map_or(("", value), |value| ("!", value));
+        let values: Vec<String> = match key {
+            "type" => (0..=u16::MAX)
+                .map_while(EntityType::from_raw)
+                .map(|entity_type| format!("minecraft:{}", entity_type.resource_name))
+                .collect(),
+            "name" => player_names.to_vec(),
+            "gamemode" => Self::GAMEMODES.map(String::from).to_vec(),
+            "sort" => Self::SORTS.map(String::from).to_vec(),
+            _ => return Vec::new(),
+        };
+        values
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(value))
+            .map(|candidate| {
+                CommandSuggestion::new(format!("{prefix}{key}={negation}{candidate}"), None)
+            })
+            .collect()
+    }
+}
+
 /// todo: implement (currently just calls [`super::arg_player::PlayerArgumentConsumer`])
 ///
 /// For selecting zero, one or multiple entities, eg. using @s, a player name, @a or @e
@@ -246,7 +380,7 @@ impl GetClientSideArgParser for EntitiesArgumentConsumer {
     }
 
     fn get_client_side_suggestion_type_override(&self) -> Option<SuggestionProviders> {
-        None
+        Some(SuggestionProviders::AskServer)
     }
 }
 
@@ -279,6 +413,15 @@ impl ArgumentConsumer for EntitiesArgumentConsumer {
             Some(Arg::Entities(entities))
         })
     }
+
+    fn suggest<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        server: &'a Server,
+        input: &'a str,
+    ) -> SuggestResult<'a> {
+        Box::pin(async move { Ok(Some(EntitySelectorSuggester::suggest(server, input))) })
+    }
 }
 
 impl DefaultNameArgConsumer for EntitiesArgumentConsumer {
@@ -297,3 +440,90 @@ impl<'a> FindArg<'a> for EntitiesArgumentConsumer {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{ComparableValueCondition, EntityFilter, EntitySelectorSuggester};
+
+    fn suggest(word: &str) -> Vec<String> {
+        EntitySelectorSuggester::suggest_word(word, &["Alex".to_string(), "Steve".to_string()])
+            .into_iter()
+            .map(|suggestion| suggestion.suggestion)
+            .collect()
+    }
+
+    #[test]
+    fn selectors_and_players() {
+        assert_eq!(suggest("@"), ["@a", "@e", "@n", "@p", "@r", "@s"]);
+        assert_eq!(suggest("St"), ["Steve"]);
+    }
+
+    #[test]
+    fn options() {
+        assert_eq!(suggest("@e[t"), ["@e[type="]);
+        assert_eq!(suggest("@e[limit=1,g"), ["@e[limit=1,gamemode="]);
+    }
+
+    #[test]
+    fn option_values() {
+        assert!(suggest("@e[type=").contains(&"@e[type=minecraft:zombie".to_string()));
+        assert_eq!(
+            suggest("@e[type=!minecraft:zombie_h"),
+            ["@e[type=!minecraft:zombie_horse"]
+        );
+        assert_eq!(suggest("@a[name=A"), ["@a[name=Alex"]);
+        assert_eq!(
+            suggest("@a[gamemode=s"),
+            ["@a[gamemode=spectator", "@a[gamemode=survival"]
+        );
+    }
+
+    #[test]
+    fn every_suggested_option_parses() {
+        let values = [
+            ("distance", "..5"),
+            ("gamemode", "creative"),
+            ("limit", "1"),
+            ("name", "Alex"),
+            ("sort", "nearest"),
+            ("type", "minecraft:zombie"),
+        ];
+        for option in suggest("@e[") {
+            let key = option
+                .strip_prefix("@e[")
+                .and_then(|option| option.strip_suffix('='))
+                .unwrap();
+            let (_, value) = values.iter().find(|(name, _)| *name == key).unwrap();
+            assert!(
+                format!("{key}={value}").parse::<EntityFilter>().is_ok(),
+                "{key} does not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn distance_ranges() {
+        let distance = |range: &str| match format!("distance={range}").parse::<EntityFilter>() {
+            Ok(EntityFilter::Distance(condition)) => Some(condition),
+            _ => None,
+        };
+        let within = |range: &str, value: f64| distance(range).unwrap().matches(&value);
+        assert!(within("5", 5.0) && !within("5", 4.0));
+        assert!(within("..5", 5.0) && !within("..5", 5.5));
+        assert!(within("5..", 5.0) && !within("5..", 4.5));
+        assert!(within("1..5", 1.0) && within("1..5", 5.0) && !within("1..5", 0.5));
+        assert!(distance("5..1").is_none());
+        assert!(distance("..").is_none());
+        assert!(distance("-1..").is_none());
+        assert!(distance("!5").is_none());
+        assert!(matches!(
+            "..2.5".parse::<ComparableValueCondition<f64>>(),
+            Ok(ComparableValueCondition::LessThanOrEquals(max)) if (max - 2.5).abs() < f64::EPSILON
+        ));
+    }
+
+    #[test]
+    fn closed_selector() {
+        assert!(suggest("@e[limit=1]").is_empty());
+    }
+}
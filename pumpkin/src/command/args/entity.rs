@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use crate::command::CommandSender;
-use crate::command::args::ConsumeResult;
+use crate::command::args::entities::EntitySelectorSuggester;
 use crate::command::args::entities::TargetSelector;
+use crate::command::args::{ConsumeResult, SuggestResult};
 use crate::command::dispatcher::CommandError;
 use crate::command::tree::RawArgs;
 use crate::entity::EntityBase;
@@ -29,7 +30,7 @@ impl GetClientSideArgParser for EntityArgumentConsumer {
     }
 
     fn get_client_side_suggestion_type_override(&self) -> Option<SuggestionProviders> {
-        None
+        Some(SuggestionProviders::AskServer)
     }
 }
 
@@ -66,6 +67,15 @@ impl ArgumentConsumer for EntityArgumentConsumer {
             entities.into_iter().next().map(Arg::Entity)
         })
     }
+
+    fn suggest<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        server: &'a Server,
+        input: &'a str,
+    ) -> SuggestResult<'a> {
+        Box::pin(async move { Ok(Some(EntitySelectorSuggester::suggest(server, input))) })
+    }
 }
 
 impl DefaultNameArgConsumer for EntityArgumentConsumer {
@@ -0,0 +1,96 @@
+use pumpkin_protocol::java::client::play::{ArgumentType, SuggestionProviders};
+
+use crate::command::CommandSender;
+use crate::command::args::{
+    Arg, ArgumentConsumer, ConsumeResult, DefaultNameArgConsumer, FindArg, GetClientSideArgParser,
+};
+use crate::command::dispatcher::CommandError;
+use crate::command::tree::RawArgs;
+use crate::server::Server;
+
+/// Parses an integer range like `1..6`, `5`, `..10` or `3..`.
+/// Returns the (min, max) bounds, where a missing bound is `None`.
+fn parse_int_range(s: &str) -> Option<(Option<i32>, Option<i32>)> {
+    let parse_bound = |bound: &str| {
+        if bound.is_empty() {
+            Some(None)
+        } else {
+            bound.parse::<i32>().ok().map(Some)
+        }
+    };
+
+    let (min, max) = match s.split_once("..") {
+        Some((min, max)) => (parse_bound(min)?, parse_bound(max)?),
+        None => {
+            let value = s.parse::<i32>().ok()?;
+            (Some(value), Some(value))
+        }
+    };
+    match (min, max) {
+        (None, None) => None,
+        (Some(min), Some(max)) if min > max => None,
+        range => Some(range),
+    }
+}
+
+pub struct IntRangeArgumentConsumer;
+
+impl GetClientSideArgParser for IntRangeArgumentConsumer {
+    fn get_client_side_parser(&self) -> ArgumentType<'_> {
+        ArgumentType::IntRange
+    }
+
+    fn get_client_side_suggestion_type_override(&self) -> Option<SuggestionProviders> {
+        None
+    }
+}
+
+impl ArgumentConsumer for IntRangeArgumentConsumer {
+    fn consume<'a, 'b>(
+        &'a self,
+        _sender: &'a CommandSender,
+        _server: &'a Server,
+        args: &'b mut RawArgs<'a>,
+    ) -> ConsumeResult<'a> {
+        let range = args
+            .pop()
+            .and_then(parse_int_range)
+            .map(|(min, max)| Arg::IntRange(min, max));
+
+        Box::pin(async move { range })
+    }
+}
+
+impl DefaultNameArgConsumer for IntRangeArgumentConsumer {
+    fn default_name(&self) -> &'static str {
+        "range"
+    }
+}
+
+impl<'a> FindArg<'a> for IntRangeArgumentConsumer {
+    /// (min, max)
+    type Data = (Option<i32>, Option<i32>);
+
+    fn find_arg(args: &'a super::ConsumedArgs, name: &str) -> Result<Self::Data, CommandError> {
+        match args.get(name) {
+            Some(Arg::IntRange(min, max)) => Ok((*min, *max)),
+            _ => Err(CommandError::InvalidConsumption(Some(name.to_string()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_int_range;
+
+    #[test]
+    fn parse_ranges() {
+        assert_eq!(parse_int_range("1..6"), Some((Some(1), Some(6))));
+        assert_eq!(parse_int_range("5"), Some((Some(5), Some(5))));
+        assert_eq!(parse_int_range("..10"), Some((None, Some(10))));
+        assert_eq!(parse_int_range("-3.."), Some((Some(-3), None)));
+        assert_eq!(parse_int_range(".."), None);
+        assert_eq!(parse_int_range("6..1"), None);
+        assert_eq!(parse_int_range("a..b"), None);
+    }
+}
@@ -0,0 +1,110 @@
+use pumpkin_data::data_component_impl::EquipmentSlot;
+use pumpkin_protocol::java::client::play::{ArgumentType, SuggestionProviders};
+
+use crate::{
+    command::{CommandSender, args::ConsumeResult, dispatcher::CommandError, tree::RawArgs},
+    server::Server,
+};
+
+use super::{Arg, ArgumentConsumer, DefaultNameArgConsumer, FindArg, GetClientSideArgParser};
+
+/// A slot named like `container.5`, `armor.head` or `weapon.offhand`.
+#[derive(Clone)]
+pub enum ItemSlot {
+    /// An index into a container, or into the main inventory of a player.
+    /// `hotbar.N` and `inventory.N` are resolved to the matching player inventory index.
+    Container(usize),
+    Equipment(EquipmentSlot),
+}
+
+impl ItemSlot {
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let indexed = |prefix: &str, count: usize| {
+            name.strip_prefix(prefix)
+                .and_then(|index| index.parse::<usize>().ok())
+                .filter(|index| *index < count)
+        };
+        if let Some(index) = indexed("container.", 54) {
+            return Some(Self::Container(index));
+        }
+        if let Some(index) = indexed("hotbar.", 9) {
+            return Some(Self::Container(index));
+        }
+        if let Some(index) = indexed("inventory.", 27) {
+            return Some(Self::Container(9 + index));
+        }
+        let slot = match name {
+            "weapon" | "weapon.mainhand" => EquipmentSlot::MAIN_HAND,
+            "weapon.offhand" => EquipmentSlot::OFF_HAND,
+            "armor.head" => EquipmentSlot::HEAD,
+            "armor.chest" => EquipmentSlot::CHEST,
+            "armor.legs" => EquipmentSlot::LEGS,
+            "armor.feet" => EquipmentSlot::FEET,
+            "armor.body" => EquipmentSlot::BODY,
+            "saddle" => EquipmentSlot::SADDLE,
+            _ => return None,
+        };
+        Some(Self::Equipment(slot))
+    }
+
+    /// The numeric id vanilla uses for this slot, shown in error messages.
+    #[must_use]
+    pub const fn id(&self) -> usize {
+        match self {
+            Self::Container(index) => *index,
+            Self::Equipment(slot) => match slot {
+                EquipmentSlot::MainHand(_) => 98,
+                EquipmentSlot::OffHand(_) => 99,
+                EquipmentSlot::Feet(_) => 100,
+                EquipmentSlot::Legs(_) => 101,
+                EquipmentSlot::Chest(_) => 102,
+                EquipmentSlot::Head(_) => 103,
+                EquipmentSlot::Body(_) => 105,
+                EquipmentSlot::Saddle(_) => 400,
+            },
+        }
+    }
+}
+
+pub struct ItemSlotArgumentConsumer;
+
+impl GetClientSideArgParser for ItemSlotArgumentConsumer {
+    fn get_client_side_parser(&self) -> ArgumentType<'_> {
+        ArgumentType::ItemSlot
+    }
+
+    fn get_client_side_suggestion_type_override(&self) -> Option<SuggestionProviders> {
+        None
+    }
+}
+
+impl ArgumentConsumer for ItemSlotArgumentConsumer {
+    fn consume<'a, 'b>(
+        &'a self,
+        _sender: &'a CommandSender,
+        _server: &'a Server,
+        args: &'b mut RawArgs<'a>,
+    ) -> ConsumeResult<'a> {
+        let result = args.pop().and_then(ItemSlot::from_name).map(Arg::ItemSlot);
+
+        Box::pin(async move { result })
+    }
+}
+
+impl DefaultNameArgConsumer for ItemSlotArgumentConsumer {
+    fn default_name(&self) -> &'static str {
+        "slot"
+    }
+}
+
+impl<'a> FindArg<'a> for ItemSlotArgumentConsumer {
+    type Data = &'a ItemSlot;
+
+    fn find_arg(args: &'a super::ConsumedArgs, name: &str) -> Result<Self::Data, CommandError> {
+        match args.get(name) {
+            Some(Arg::ItemSlot(data)) => Ok(data),
+            _ => Err(CommandError::InvalidConsumption(Some(name.to_string()))),
+        }
+    }
+}
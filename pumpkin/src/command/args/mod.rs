@@ -38,6 +38,8 @@ pub mod entities;
 pub mod entity;
 pub mod entity_anchor;
 pub mod gamemode;
+pub mod int_range;
+pub mod item_slot;
 pub mod message;
 pub mod players;
 pub mod position_2d;
@@ -105,6 +107,7 @@ pub enum Arg<'a> {
     Difficulty(Difficulty),
     CommandTree(CommandTree),
     Item(&'a str),
+    ItemSlot(item_slot::ItemSlot),
     ItemPredicate(&'a str),
     ResourceLocation(&'a str),
     Block(&'a str),
@@ -116,6 +119,8 @@ pub enum Arg<'a> {
     TextComponent(TextComponent),
     Time(i32),
     Num(Result<Number, NotInBounds>),
+    /// (min, max)
+    IntRange(Option<i32>, Option<i32>),
     Bool(bool),
     Simple(&'a str),
     SoundCategory(SoundCategory),
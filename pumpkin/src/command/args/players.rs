@@ -3,7 +3,8 @@ use std::sync::Arc;
 use pumpkin_protocol::java::client::play::{ArgumentType, SuggestionProviders};
 
 use crate::command::CommandSender;
-use crate::command::args::ConsumeResult;
+use crate::command::args::entities::EntitySelectorSuggester;
+use crate::command::args::{ConsumeResult, SuggestResult};
 use crate::command::dispatcher::CommandError;
 use crate::command::tree::RawArgs;
 use crate::entity::player::Player;
@@ -24,7 +25,7 @@ impl GetClientSideArgParser for PlayersArgumentConsumer {
     }
 
     fn get_client_side_suggestion_type_override(&self) -> Option<SuggestionProviders> {
-        None
+        Some(SuggestionProviders::AskServer)
     }
 }
 
@@ -71,6 +72,15 @@ impl ArgumentConsumer for PlayersArgumentConsumer {
             players.map(Arg::Players)
         })
     }
+
+    fn suggest<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        server: &'a Server,
+        input: &'a str,
+    ) -> SuggestResult<'a> {
+        Box::pin(async move { Ok(Some(EntitySelectorSuggester::suggest(server, input))) })
+    }
 }
 
 impl DefaultNameArgConsumer for PlayersArgumentConsumer {
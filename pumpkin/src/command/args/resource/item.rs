@@ -1,7 +1,11 @@
 use pumpkin_data::{
+    data_component::DataComponent,
+    data_component_impl::read_data,
     item::Item,
     tag::{RegistryKey, get_tag_ids},
+    translation,
 };
+use pumpkin_nbt::snbt::SnbtReader;
 use pumpkin_protocol::java::client::play::{ArgumentType, SuggestionProviders};
 use pumpkin_util::text::TextComponent;
 use pumpkin_world::item::ItemStack;
@@ -37,7 +41,7 @@ impl ArgumentConsumer for ItemArgumentConsumer {
         args: &mut RawArgs<'a>,
     ) -> ConsumeResult<'a> {
         let item = args.pop();
-        // TODO: When supporting data components in this argument, do it for ItemPredicateArgumentConsumer as well (both tags and items)
+        // TODO: Support component predicates in ItemPredicateArgumentConsumer as well
         match item {
             Some(s) => Box::pin(async move { Some(Arg::Item(s)) }),
             None => Box::pin(async move { None }),
@@ -52,31 +56,184 @@ impl DefaultNameArgConsumer for ItemArgumentConsumer {
 }
 
 impl<'a> FindArg<'a> for ItemArgumentConsumer {
-    type Data = (&'a str, &'static Item);
+    /// The item id as typed and a stack of one item with the parsed components applied.
+    type Data = (&'a str, ItemStack);
 
     fn find_arg(args: &'a ConsumedArgs, name: &str) -> Result<Self::Data, CommandError> {
         match args.get(name) {
-            Some(Arg::Item(name)) => Item::from_registry_key(name).map_or_else(
-                || {
-                    if name.starts_with("minecraft:") {
-                        Err(CommandError::CommandFailed(TextComponent::translate(
-                            "argument.item.id.invalid",
-                            [TextComponent::text((*name).to_string())],
-                        )))
-                    } else {
-                        Err(CommandError::CommandFailed(TextComponent::translate(
-                            "argument.item.id.invalid",
-                            [TextComponent::text("minecraft:".to_string() + *name)],
-                        )))
-                    }
-                },
-                |item| Ok((*name, item)),
-            ),
+            Some(Arg::Item(input)) => parse_item_stack(input),
             _ => Err(CommandError::InvalidConsumption(Some(name.to_string()))),
         }
     }
 }
 
+/// Parses `id[component=value,!component,...]`, where values are SNBT.
+fn parse_item_stack(input: &str) -> Result<(&str, ItemStack), CommandError> {
+    let id_len = input.find('[').unwrap_or(input.len());
+    let id = &input[..id_len];
+    let item = Item::from_registry_key(id).ok_or_else(|| invalid_item_id(id))?;
+    let mut stack = ItemStack::new(1, item);
+    if id_len < input.len() {
+        parse_components(input, id_len + 1, &mut stack)?;
+    }
+    Ok((id, stack))
+}
+
+fn invalid_item_id(id: &str) -> CommandError {
+    let id = if id.starts_with("minecraft:") {
+        id.to_string()
+    } else {
+        format!("minecraft:{id}")
+    };
+    CommandError::CommandFailed(TextComponent::translate(
+        translation::ARGUMENT_ITEM_ID_INVALID,
+        [TextComponent::text(id)],
+    ))
+}
+
+/// Parses the component list starting at `cursor`, just after the opening `[`.
+fn parse_components(
+    input: &str,
+    mut cursor: usize,
+    stack: &mut ItemStack,
+) -> Result<(), CommandError> {
+    let mut seen = Vec::new();
+    cursor = skip_whitespace(input, cursor);
+    if input[cursor..].starts_with(']') {
+        return expect_end(input, cursor + 1);
+    }
+    loop {
+        let removed = input[cursor..].starts_with('!');
+        if removed {
+            cursor += 1;
+        }
+
+        let name_start = cursor;
+        cursor += input[cursor..]
+            .find(|c: char| !is_resource_location_char(c))
+            .unwrap_or(input.len() - cursor);
+        let name = &input[name_start..cursor];
+        if name.is_empty() {
+            return Err(parse_error(
+                input,
+                cursor,
+                TextComponent::translate(translation::ARGUMENTS_ITEM_COMPONENT_EXPECTED, []),
+            ));
+        }
+        let name = if name.contains(':') {
+            name.to_string()
+        } else {
+            format!("minecraft:{name}")
+        };
+        let Some(component) = DataComponent::try_from_name(&name) else {
+            return Err(parse_error(
+                input,
+                name_start,
+                TextComponent::translate(
+                    translation::ARGUMENTS_ITEM_COMPONENT_UNKNOWN,
+                    [TextComponent::text(name)],
+                ),
+            ));
+        };
+        if seen.contains(&component) {
+            return Err(parse_error(
+                input,
+                name_start,
+                TextComponent::translate(
+                    translation::ARGUMENTS_ITEM_COMPONENT_REPEATED,
+                    [TextComponent::text(name)],
+                ),
+            ));
+        }
+        seen.push(component);
+
+        if removed {
+            stack.patch.push((component, None));
+        } else {
+            cursor = expect(input, skip_whitespace(input, cursor), '=')?;
+            let value_start = skip_whitespace(input, cursor);
+            let mut reader = SnbtReader::new(&input[value_start..]);
+            let tag = reader.read_tag().map_err(|err| {
+                parse_error(
+                    input,
+                    value_start + err.position,
+                    TextComponent::text(err.message),
+                )
+            })?;
+            cursor = value_start + reader.cursor();
+            let Some(data) = read_data(component, &tag) else {
+                return Err(parse_error(
+                    input,
+                    value_start,
+                    TextComponent::translate(
+                        translation::ARGUMENTS_ITEM_COMPONENT_MALFORMED,
+                        [
+                            TextComponent::text(name),
+                            TextComponent::text(input[value_start..cursor].to_string()),
+                        ],
+                    ),
+                ));
+            };
+            stack.patch.push((component, Some(data)));
+        }
+
+        cursor = skip_whitespace(input, cursor);
+        match input[cursor..].chars().next() {
+            Some(',') => cursor = skip_whitespace(input, cursor + 1),
+            Some(']') => return expect_end(input, cursor + 1),
+            _ => return Err(expected(input, cursor, ']')),
+        }
+    }
+}
+
+const fn is_resource_location_char(c: char) -> bool {
+    matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | ':' | '/')
+}
+
+fn skip_whitespace(input: &str, cursor: usize) -> usize {
+    input.len() - input[cursor..].trim_start().len()
+}
+
+fn expect(input: &str, cursor: usize, expected_char: char) -> Result<usize, CommandError> {
+    if input[cursor..].starts_with(expected_char) {
+        Ok(cursor + 1)
+    } else {
+        Err(expected(input, cursor, expected_char))
+    }
+}
+
+fn expect_end(input: &str, cursor: usize) -> Result<(), CommandError> {
+    if cursor == input.len() {
+        Ok(())
+    } else {
+        Err(parse_error(
+            input,
+            cursor,
+            TextComponent::translate(translation::COMMAND_EXPECTED_SEPARATOR, []),
+        ))
+    }
+}
+
+fn expected(input: &str, cursor: usize, expected_char: char) -> CommandError {
+    parse_error(
+        input,
+        cursor,
+        TextComponent::translate(
+            translation::PARSING_EXPECTED,
+            [TextComponent::text(expected_char.to_string())],
+        ),
+    )
+}
+
+/// Builds an error pointing at `cursor` within the argument.
+fn parse_error(input: &str, cursor: usize, message: TextComponent) -> CommandError {
+    CommandError::SyntaxError {
+        message,
+        argument: input.to_string(),
+        cursor,
+    }
+}
+
 pub struct ItemPredicateArgumentConsumer;
 
 pub enum ItemPredicate {
@@ -0,0 +1,170 @@
+use pumpkin_config::ChatConfig;
+use pumpkin_data::translation;
+use pumpkin_util::text::TextComponent;
+use regex::Regex;
+use tokio::sync::RwLock;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::command::{CommandError, CommandSender};
+use crate::data::{LoadJSONConfiguration, SaveJSONConfiguration, mute::MuteList};
+
+/// What should happen to a chat message after it went through the [`ChatFilter`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum FilterResult {
+    /// The message can be sent as it is.
+    Allow,
+    /// The message has to be sent with the blocked words censored.
+    Replace(String),
+    /// The message must not be sent at all.
+    Block,
+}
+
+/// Filters chat messages by the blocked patterns from the config and keeps track of muted players.
+pub struct ChatFilter {
+    blocked_patterns: Vec<Regex>,
+    block_filtered_messages: bool,
+    muted_players: RwLock<MuteList>,
+}
+
+impl ChatFilter {
+    #[must_use]
+    pub fn new(config: &ChatConfig) -> Self {
+        Self::with_mute_list(config, MuteList::load())
+    }
+
+    pub(crate) fn with_mute_list(config: &ChatConfig, mute_list: MuteList) -> Self {
+        let blocked_patterns = config
+            .blocked_patterns
+            .iter()
+            .filter_map(|pattern| {
+                Regex::new(pattern)
+                    .inspect_err(|err| {
+                        warn!("Ignoring invalid blocked chat pattern {pattern}: {err}")
+                    })
+                    .ok()
+            })
+            .collect();
+        Self {
+            blocked_patterns,
+            block_filtered_messages: config.block_filtered_messages,
+            muted_players: RwLock::new(mute_list),
+        }
+    }
+
+    /// Checks a chat message sent by `sender`. Messages of muted players are always blocked.
+    pub async fn filter(&self, message: &str, sender: Uuid) -> FilterResult {
+        if self.is_muted(sender).await {
+            return FilterResult::Block;
+        }
+        self.filter_text(message)
+    }
+
+    fn filter_text(&self, message: &str) -> FilterResult {
+        let mut censored = message.to_string();
+        for pattern in &self.blocked_patterns {
+            censored = pattern
+                .replace_all(&censored, |captures: &regex::Captures| {
+                    "*".repeat(captures[0].chars().count())
+                })
+                .into_owned();
+        }
+
+        if censored == message {
+            FilterResult::Allow
+        } else if self.block_filtered_messages {
+            FilterResult::Block
+        } else {
+            FilterResult::Replace(censored)
+        }
+    }
+
+    pub async fn is_muted(&self, uuid: Uuid) -> bool {
+        self.muted_players
+            .read()
+            .await
+            .muted_players
+            .contains(&uuid)
+    }
+
+    /// Fails commands that send chat, like `/msg` and `/me`, when `sender` is a muted player.
+    pub async fn check_not_muted(&self, sender: &CommandSender) -> Result<(), CommandError> {
+        if let Some(player) = sender.as_player()
+            && self.is_muted(player.gameprofile.id).await
+        {
+            return Err(CommandError::CommandFailed(TextComponent::translate(
+                translation::CHAT_FILTERED_FULL,
+                [],
+            )));
+        }
+        Ok(())
+    }
+
+    /// Mutes the player and saves the mute list. Returns `false` if they were already muted.
+    pub async fn mute(&self, uuid: Uuid) -> bool {
+        let mut list = self.muted_players.write().await;
+        let changed = list.muted_players.insert(uuid);
+        if changed {
+            list.save();
+        }
+        changed
+    }
+
+    /// Unmutes the player and saves the mute list. Returns `false` if they were not muted.
+    pub async fn unmute(&self, uuid: Uuid) -> bool {
+        let mut list = self.muted_players.write().await;
+        let changed = list.muted_players.remove(&uuid);
+        if changed {
+            list.save();
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_config::ChatConfig;
+
+    use super::{ChatFilter, FilterResult};
+    use crate::data::mute::MuteList;
+
+    fn filter(patterns: &[&str], block: bool) -> ChatFilter {
+        let config = ChatConfig {
+            blocked_patterns: patterns.iter().map(ToString::to_string).collect(),
+            block_filtered_messages: block,
+            ..Default::default()
+        };
+        ChatFilter::with_mute_list(&config, MuteList::default())
+    }
+
+    #[test]
+    fn clean_message_is_allowed() {
+        let filter = filter(&["(?i)pumpkin"], false);
+        assert_eq!(filter.filter_text("hello world"), FilterResult::Allow);
+    }
+
+    #[test]
+    fn matches_are_censored() {
+        let filter = filter(&["(?i)pumpkin", "b[a-z]d"], false);
+        assert_eq!(
+            filter.filter_text("a Pumpkin is not bad"),
+            FilterResult::Replace("a ******* is not ***".to_string())
+        );
+    }
+
+    #[test]
+    fn matches_are_blocked() {
+        let filter = filter(&["bad"], true);
+        assert_eq!(filter.filter_text("that is bad"), FilterResult::Block);
+        assert_eq!(filter.filter_text("that is fine"), FilterResult::Allow);
+    }
+
+    #[test]
+    fn invalid_patterns_are_ignored() {
+        let filter = filter(&["(unclosed", "bad"], false);
+        assert_eq!(
+            filter.filter_text("bad"),
+            FilterResult::Replace("***".to_string())
+        );
+    }
+}
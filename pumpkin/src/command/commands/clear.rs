@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use pumpkin_data::translation;
+use pumpkin_inventory::screen_handler::ScreenHandler;
 use pumpkin_util::text::TextComponent;
 use pumpkin_world::item::ItemStack;
 use tokio::sync::Mutex;
@@ -60,6 +61,16 @@ async fn clear_player(target: &Player, item: &ItemPredicate, max: i32) -> i32 {
     .await;
     drop(entity_equipment_lock);
 
+    // The stack held by the cursor in an open screen counts as part of the inventory
+    let screen_handler = target.current_screen_handler.lock().await.clone();
+    let cursor_stack = screen_handler
+        .lock()
+        .await
+        .get_behaviour()
+        .cursor_stack
+        .clone();
+    iter_test_and_clear([&cursor_stack], &mut count, &mut max, item, &mut is_done).await;
+
     count
 }
 
@@ -306,3 +317,44 @@ pub fn init_command_tree() -> CommandTree {
         )
         .then(require(super::super::CommandSender::is_player).execute(SelfExecutor))
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::item::Item;
+    use pumpkin_inventory::screen_handler::ScreenHandler;
+    use pumpkin_world::item::ItemStack;
+    use tempfile::tempdir;
+
+    use super::{MAX_NO_CLEAR_BUT_SIMULATE, MAX_NO_UPPER_LIMIT, clear_player};
+    use crate::command::args::resource::item::ItemPredicate;
+    use crate::entity::player::test::test_player;
+    use crate::server::test::test_server;
+    use crate::world::test::test_world_on;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cursor_stack_is_cleared() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, _connection) = test_player(&world, "Steve").await;
+
+        let screen_handler = player.current_screen_handler.lock().await.clone();
+        let cursor_stack = screen_handler
+            .lock()
+            .await
+            .get_behaviour()
+            .cursor_stack
+            .clone();
+        *cursor_stack.lock().await = ItemStack::new(5, &Item::DIRT);
+        *player.inventory().held_item().lock().await = ItemStack::new(3, &Item::DIRT);
+
+        let dirt = ItemPredicate::Item(&Item::DIRT);
+        assert_eq!(
+            clear_player(&player, &dirt, MAX_NO_CLEAR_BUT_SIMULATE).await,
+            8
+        );
+        assert_eq!(clear_player(&player, &dirt, MAX_NO_UPPER_LIMIT).await, 8);
+        assert!(cursor_stack.lock().await.is_empty());
+    }
+}
@@ -5,6 +5,7 @@ use crate::command::dispatcher::CommandError::{self, InvalidConsumption};
 use crate::command::tree::builder::argument;
 use crate::command::{CommandExecutor, CommandSender, args::ConsumedArgs, tree::CommandTree};
 
+use pumpkin_util::Difficulty;
 use pumpkin_util::text::TextComponent;
 
 const NAMES: [&str; 1] = ["difficulty"];
@@ -24,13 +25,11 @@ impl CommandExecutor for DifficultyQueryExecutor {
     ) -> CommandResult<'a> {
         Box::pin(async move {
             let difficulty = server.get_difficulty();
-            let difficulty_string = format!("{difficulty:?}").to_lowercase();
-            let translation_key = format!("options.difficulty.{difficulty_string}");
 
             sender
                 .send_message(TextComponent::translate(
                     "commands.difficulty.query",
-                    [TextComponent::translate(translation_key, [])],
+                    [difficulty_name(difficulty)],
                 ))
                 .await;
 
@@ -53,26 +52,27 @@ impl CommandExecutor for DifficultySetExecutor {
                 return Err(InvalidConsumption(Some(ARG_DIFFICULTY.into())));
             };
 
-            let difficulty_string = format!("{difficulty:?}").to_lowercase();
-            let translation_key = format!("options.difficulty.{difficulty_string}");
+            let failure = || {
+                CommandError::CommandFailed(TextComponent::translate(
+                    "commands.difficulty.failure",
+                    [difficulty_name(difficulty)],
+                ))
+            };
+            let world = sender
+                .world()
+                .or_else(|| server.worlds.load().first().cloned())
+                .ok_or_else(failure)?;
 
+            if world.get_difficulty() == difficulty || !world.set_difficulty(difficulty, true).await
             {
-                let level_info = server.level_info.load();
-
-                if level_info.difficulty == difficulty {
-                    return Err(CommandError::CommandFailed(TextComponent::translate(
-                        "commands.difficulty.failure",
-                        [TextComponent::translate(translation_key, [])],
-                    )));
-                }
+                return Err(failure());
             }
 
-            server.set_difficulty(difficulty, true).await;
-
+            // Hardcore worlds and plugins may pick a different difficulty
             sender
                 .send_message(TextComponent::translate(
                     "commands.difficulty.success",
-                    [TextComponent::translate(translation_key, [])],
+                    [difficulty_name(world.get_difficulty())],
                 ))
                 .await;
 
@@ -81,6 +81,11 @@ impl CommandExecutor for DifficultySetExecutor {
     }
 }
 
+fn difficulty_name(difficulty: Difficulty) -> TextComponent {
+    let difficulty_string = format!("{difficulty:?}").to_lowercase();
+    TextComponent::translate(format!("options.difficulty.{difficulty_string}"), [])
+}
+
 #[must_use]
 pub fn init_command_tree() -> CommandTree {
     CommandTree::new(NAMES, DESCRIPTION)
@@ -1,5 +1,4 @@
-use pumpkin_data::data_component::DataComponent::MaxStackSize;
-use pumpkin_data::data_component_impl::{MaxStackSizeImpl, get};
+use pumpkin_data::translation;
 use pumpkin_util::text::TextComponent;
 use pumpkin_util::text::hover::HoverEvent;
 use pumpkin_world::item::ItemStack;
@@ -27,6 +26,13 @@ const fn item_count_consumer() -> BoundedNumArgumentConsumer<i32> {
         .max(i32::MAX)
 }
 
+/// Like vanilla, at most 100 stacks can be given at once.
+const MAX_GIVEN_STACKS: i32 = 100;
+
+fn max_give_count(stack: &ItemStack) -> i32 {
+    i32::from(stack.get_max_stack_size().max(1)) * MAX_GIVEN_STACKS
+}
+
 struct Executor;
 
 impl CommandExecutor for Executor {
@@ -39,7 +45,7 @@ impl CommandExecutor for Executor {
         Box::pin(async move {
             let targets = PlayersArgumentConsumer.find_arg_default_name(args)?;
 
-            let (item_name, item) = ItemArgumentConsumer::find_arg(args, ARG_ITEM)?;
+            let (item_name, stack) = ItemArgumentConsumer::find_arg(args, ARG_ITEM)?;
 
             let item_count = match item_count_consumer().find_arg_default_name(args) {
                 Err(_) => 1,
@@ -58,20 +64,23 @@ impl CommandExecutor for Executor {
                 }
             };
 
+            // Components such as max_stack_size may change how many items fit in a stack
+            let max_stack = i32::from(stack.get_max_stack_size().max(1));
+            if item_count > max_give_count(&stack) {
+                return Err(CommandError::CommandFailed(TextComponent::translate(
+                    translation::COMMANDS_GIVE_FAILED_TOOMANYITEMS,
+                    [
+                        TextComponent::text(max_give_count(&stack).to_string()),
+                        stack.item.translated_name(),
+                    ],
+                )));
+            }
             for target in targets {
-                let max_stack = i32::from(
-                    item.components
-                        .iter()
-                        .find_map(|(id, component)| {
-                            (id == &MaxStackSize).then(|| get::<MaxStackSizeImpl>(*component).size)
-                        })
-                        .unwrap(),
-                );
                 let mut remaining = item_count;
 
                 while remaining > 0 {
                     let take = remaining.min(max_stack);
-                    let mut stack = ItemStack::new(take as u8, item);
+                    let mut stack = stack.copy_with_count(take as u8);
                     target.inventory().insert_stack_anywhere(&mut stack).await;
                     if !stack.is_empty() {
                         target.drop_item(stack).await;
@@ -82,11 +91,11 @@ impl CommandExecutor for Executor {
 
             let msg = if targets.len() == 1 {
                 TextComponent::translate(
-                    "commands.give.success.single",
+                    translation::COMMANDS_GIVE_SUCCESS_SINGLE,
                     [
                         TextComponent::text(item_count.to_string()),
                         TextComponent::text("[")
-                            .add_child(item.translated_name())
+                            .add_child(stack.item.translated_name())
                             .add_child(TextComponent::text("]"))
                             .hover_event(HoverEvent::ShowItem {
                                 id: item_name.to_string().into(),
@@ -97,11 +106,11 @@ impl CommandExecutor for Executor {
                 )
             } else {
                 TextComponent::translate(
-                    "commands.give.success.multiple",
+                    translation::COMMANDS_GIVE_SUCCESS_MULTIPLE,
                     [
                         TextComponent::text(item_count.to_string()),
                         TextComponent::text("[")
-                            .add_child(item.translated_name())
+                            .add_child(stack.item.translated_name())
                             .add_child(TextComponent::text("]"))
                             .hover_event(HoverEvent::ShowItem {
                                 id: item_name.to_string().into(),
@@ -127,3 +136,21 @@ pub fn init_command_tree() -> CommandTree {
         ),
     )
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::item::Item;
+    use pumpkin_world::item::ItemStack;
+
+    use super::max_give_count;
+
+    #[test]
+    fn give_count_depends_on_stack_size() {
+        assert_eq!(max_give_count(&ItemStack::new(1, &Item::STONE)), 6400);
+        assert_eq!(max_give_count(&ItemStack::new(1, &Item::ENDER_PEARL)), 1600);
+        assert_eq!(
+            max_give_count(&ItemStack::new(1, &Item::DIAMOND_SWORD)),
+            100
+        );
+    }
+}
@@ -0,0 +1,377 @@
+use std::sync::Arc;
+
+use pumpkin_data::data_component_impl::EquipmentSlot;
+use pumpkin_data::translation;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::text::TextComponent;
+use pumpkin_util::text::hover::HoverEvent;
+use pumpkin_world::inventory::Inventory;
+use pumpkin_world::item::ItemStack;
+
+use crate::command::args::bounded_num::{BoundedNumArgumentConsumer, NotInBounds};
+use crate::command::args::entities::EntitiesArgumentConsumer;
+use crate::command::args::entity::EntityArgumentConsumer;
+use crate::command::args::item_slot::{ItemSlot, ItemSlotArgumentConsumer};
+use crate::command::args::position_block::BlockPosArgumentConsumer;
+use crate::command::args::resource::item::ItemArgumentConsumer;
+use crate::command::args::{ConsumedArgs, FindArg, FindArgDefaultName};
+use crate::command::tree::CommandTree;
+use crate::command::tree::builder::{NonLeafNodeBuilder, argument, argument_default_name, literal};
+use crate::command::{CommandError, CommandExecutor, CommandResult, CommandSender};
+use crate::entity::EntityBase;
+use crate::server::Server;
+use crate::world::World;
+
+const NAMES: [&str; 1] = ["item"];
+
+const DESCRIPTION: &str = "Replaces items in the inventories of blocks and entities.";
+
+const ARG_TARGETS: &str = "targets";
+const ARG_POS: &str = "pos";
+const ARG_SLOT: &str = "slot";
+const ARG_ITEM: &str = "item";
+const ARG_SOURCE: &str = "source";
+const ARG_SOURCE_POS: &str = "sourcePos";
+const ARG_SOURCE_SLOT: &str = "sourceSlot";
+
+const fn item_count_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .name("count")
+        .min(1)
+        .max(99)
+}
+
+#[derive(Clone, Copy)]
+enum Target {
+    Entities,
+    Block,
+}
+
+#[derive(Clone, Copy)]
+enum Source {
+    Item,
+    Entity,
+    Block,
+}
+
+struct Executor(Target, Source);
+
+impl CommandExecutor for Executor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let Self(target, source) = self;
+            let stack = match source {
+                Source::Item => {
+                    let (_, stack) = ItemArgumentConsumer::find_arg(args, ARG_ITEM)?;
+                    let count = match item_count_consumer().find_arg_default_name(args) {
+                        Err(_) => 1,
+                        Ok(Ok(count)) => count,
+                        Ok(Err(err)) => {
+                            let (key, value, bound) = match err {
+                                NotInBounds::LowerBound(value, min) => {
+                                    (translation::ARGUMENT_INTEGER_LOW, value, min)
+                                }
+                                NotInBounds::UpperBound(value, max) => {
+                                    (translation::ARGUMENT_INTEGER_BIG, value, max)
+                                }
+                            };
+                            return Err(CommandError::CommandFailed(TextComponent::translate(
+                                key,
+                                [
+                                    TextComponent::text(bound.to_string()),
+                                    TextComponent::text(value.to_string()),
+                                ],
+                            )));
+                        }
+                    };
+                    stack_with_count(&stack, count)?
+                }
+                Source::Entity => {
+                    let source = EntityArgumentConsumer::find_arg(args, ARG_SOURCE)?;
+                    let slot = ItemSlotArgumentConsumer::find_arg(args, ARG_SOURCE_SLOT)?;
+                    get_entity_stack(source.as_ref(), slot)
+                        .await
+                        .ok_or_else(|| {
+                            no_such_slot(translation::COMMANDS_ITEM_SOURCE_NO_SUCH_SLOT, slot)
+                        })?
+                }
+                Source::Block => {
+                    let pos = BlockPosArgumentConsumer::find_arg(args, ARG_SOURCE_POS)?;
+                    let slot = ItemSlotArgumentConsumer::find_arg(args, ARG_SOURCE_SLOT)?;
+                    let world = sender_world(sender, server)?;
+                    let inventory = block_inventory(&world, &pos).await.ok_or_else(|| {
+                        not_a_container(translation::COMMANDS_ITEM_SOURCE_NOT_A_CONTAINER, &pos)
+                    })?;
+                    let index = container_index(inventory.as_ref(), slot).ok_or_else(|| {
+                        no_such_slot(translation::COMMANDS_ITEM_SOURCE_NO_SUCH_SLOT, slot)
+                    })?;
+                    inventory.get_stack(index).await.lock().await.clone()
+                }
+            };
+
+            let slot = ItemSlotArgumentConsumer::find_arg(args, ARG_SLOT)?;
+            match target {
+                Target::Entities => {
+                    let targets = EntitiesArgumentConsumer::find_arg(args, ARG_TARGETS)?;
+                    let mut changed = Vec::new();
+                    for target in targets {
+                        if set_entity_stack(target.as_ref(), slot, stack.clone()).await {
+                            changed.push(target);
+                        }
+                    }
+
+                    let msg = match changed.as_slice() {
+                        [] => {
+                            return Err(no_such_slot(
+                                translation::COMMANDS_ITEM_TARGET_NO_CHANGES,
+                                slot,
+                            ));
+                        }
+                        [target] => TextComponent::translate(
+                            translation::COMMANDS_ITEM_ENTITY_SET_SUCCESS_SINGLE,
+                            [target.get_display_name().await, item_text(&stack)],
+                        ),
+                        changed => TextComponent::translate(
+                            translation::COMMANDS_ITEM_ENTITY_SET_SUCCESS_MULTIPLE,
+                            [
+                                TextComponent::text(changed.len().to_string()),
+                                item_text(&stack),
+                            ],
+                        ),
+                    };
+                    sender.send_message(msg).await;
+                    Ok(changed.len() as i32)
+                }
+                Target::Block => {
+                    let pos = BlockPosArgumentConsumer::find_arg(args, ARG_POS)?;
+                    let world = sender_world(sender, server)?;
+                    let inventory = block_inventory(&world, &pos).await.ok_or_else(|| {
+                        not_a_container(translation::COMMANDS_ITEM_TARGET_NOT_A_CONTAINER, &pos)
+                    })?;
+                    let index = container_index(inventory.as_ref(), slot).ok_or_else(|| {
+                        no_such_slot(translation::COMMANDS_ITEM_TARGET_NO_SUCH_SLOT, slot)
+                    })?;
+                    inventory.set_stack(index, stack.clone()).await;
+                    inventory.mark_dirty();
+
+                    sender
+                        .send_message(TextComponent::translate(
+                            translation::COMMANDS_ITEM_BLOCK_SET_SUCCESS,
+                            [
+                                TextComponent::text(pos.0.x.to_string()),
+                                TextComponent::text(pos.0.y.to_string()),
+                                TextComponent::text(pos.0.z.to_string()),
+                                item_text(&stack),
+                            ],
+                        ))
+                        .await;
+                    Ok(1)
+                }
+            }
+        })
+    }
+}
+
+/// A copy of `stack` with `count` items, which have to fit in a single stack.
+fn stack_with_count(stack: &ItemStack, count: i32) -> Result<ItemStack, CommandError> {
+    let max_stack_size = stack.get_max_stack_size();
+    if count > i32::from(max_stack_size) {
+        return Err(CommandError::CommandFailed(TextComponent::translate(
+            translation::ARGUMENTS_ITEM_OVERSTACKED,
+            [
+                stack.item.translated_name(),
+                TextComponent::text(max_stack_size.to_string()),
+            ],
+        )));
+    }
+    Ok(stack.copy_with_count(count as u8))
+}
+
+fn sender_world(sender: &CommandSender, server: &Server) -> Result<Arc<World>, CommandError> {
+    sender.world().map_or_else(
+        || {
+            server
+                .worlds
+                .load()
+                .first()
+                .cloned()
+                .ok_or(CommandError::InvalidRequirement)
+        },
+        Ok,
+    )
+}
+
+async fn block_inventory(world: &World, pos: &BlockPos) -> Option<Arc<dyn Inventory>> {
+    world.get_block_entity(pos).await?.get_inventory()
+}
+
+/// Blocks only have numbered container slots.
+fn container_index(inventory: &dyn Inventory, slot: &ItemSlot) -> Option<usize> {
+    match slot {
+        ItemSlot::Container(index) if *index < inventory.size() => Some(*index),
+        _ => None,
+    }
+}
+
+async fn get_entity_stack(entity: &dyn EntityBase, slot: &ItemSlot) -> Option<ItemStack> {
+    if let Some(player) = entity.get_player() {
+        let inventory = player.inventory();
+        let stack = match slot {
+            ItemSlot::Container(index) => inventory.main_inventory.get(*index)?.clone(),
+            ItemSlot::Equipment(EquipmentSlot::MainHand(_)) => inventory.held_item(),
+            ItemSlot::Equipment(slot) => inventory.entity_equipment.lock().await.get(slot),
+        };
+        return Some(stack.lock().await.clone());
+    }
+
+    let living = entity.get_living_entity()?;
+    let ItemSlot::Equipment(slot) = slot else {
+        return None;
+    };
+    let stack = living.entity_equipment.lock().await.get(slot);
+    Some(stack.lock().await.clone())
+}
+
+/// Returns whether the entity has the slot.
+async fn set_entity_stack(entity: &dyn EntityBase, slot: &ItemSlot, stack: ItemStack) -> bool {
+    if let Some(player) = entity.get_player() {
+        let inventory = player.inventory();
+        match slot {
+            ItemSlot::Container(index) => {
+                let Some(slot) = inventory.main_inventory.get(*index) else {
+                    return false;
+                };
+                *slot.lock().await = stack;
+            }
+            ItemSlot::Equipment(EquipmentSlot::MainHand(_)) => {
+                *inventory.held_item().lock().await = stack;
+            }
+            ItemSlot::Equipment(slot) => {
+                inventory
+                    .entity_equipment
+                    .lock()
+                    .await
+                    .put(slot, stack)
+                    .await;
+            }
+        }
+        return true;
+    }
+
+    let Some(living) = entity.get_living_entity() else {
+        return false;
+    };
+    let ItemSlot::Equipment(slot) = slot else {
+        return false;
+    };
+    living
+        .entity_equipment
+        .lock()
+        .await
+        .put(slot, stack.clone())
+        .await;
+    living
+        .send_equipment_changes(&[(slot.clone(), stack)])
+        .await;
+    true
+}
+
+fn no_such_slot(key: &str, slot: &ItemSlot) -> CommandError {
+    CommandError::CommandFailed(TextComponent::translate(
+        key,
+        [TextComponent::text(slot.id().to_string())],
+    ))
+}
+
+fn not_a_container(key: &str, pos: &BlockPos) -> CommandError {
+    CommandError::CommandFailed(TextComponent::translate(
+        key,
+        [
+            TextComponent::text(pos.0.x.to_string()),
+            TextComponent::text(pos.0.y.to_string()),
+            TextComponent::text(pos.0.z.to_string()),
+        ],
+    ))
+}
+
+fn item_text(stack: &ItemStack) -> TextComponent {
+    TextComponent::text("[")
+        .add_child(stack.item.translated_name())
+        .add_child(TextComponent::text("]"))
+        .hover_event(HoverEvent::ShowItem {
+            id: format!("minecraft:{}", stack.item.registry_key).into(),
+            count: Some(i32::from(stack.item_count)),
+        })
+}
+
+/// The `with` and `from` branches shared by entity and block targets.
+fn slot_node(target: Target) -> NonLeafNodeBuilder {
+    argument(ARG_SLOT, ItemSlotArgumentConsumer)
+        .then(
+            literal("with").then(
+                argument(ARG_ITEM, ItemArgumentConsumer)
+                    .execute(Executor(target, Source::Item))
+                    .then(
+                        argument_default_name(item_count_consumer())
+                            .execute(Executor(target, Source::Item)),
+                    ),
+            ),
+        )
+        .then(
+            literal("from")
+                .then(
+                    literal("entity").then(
+                        argument(ARG_SOURCE, EntityArgumentConsumer).then(
+                            argument(ARG_SOURCE_SLOT, ItemSlotArgumentConsumer)
+                                .execute(Executor(target, Source::Entity)),
+                        ),
+                    ),
+                )
+                .then(
+                    literal("block").then(
+                        argument(ARG_SOURCE_POS, BlockPosArgumentConsumer).then(
+                            argument(ARG_SOURCE_SLOT, ItemSlotArgumentConsumer)
+                                .execute(Executor(target, Source::Block)),
+                        ),
+                    ),
+                ),
+        )
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        literal("replace")
+            .then(literal("entity").then(
+                argument(ARG_TARGETS, EntitiesArgumentConsumer).then(slot_node(Target::Entities)),
+            ))
+            .then(
+                literal("block").then(
+                    argument(ARG_POS, BlockPosArgumentConsumer).then(slot_node(Target::Block)),
+                ),
+            ),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::item::Item;
+    use pumpkin_world::item::ItemStack;
+
+    use super::stack_with_count;
+
+    #[test]
+    fn count_has_to_fit_in_a_stack() {
+        let stone = ItemStack::new(1, &Item::STONE);
+        assert_eq!(stack_with_count(&stone, 64).unwrap().item_count, 64);
+        assert!(stack_with_count(&stone, 65).is_err());
+
+        let pearl = ItemStack::new(1, &Item::ENDER_PEARL);
+        assert_eq!(stack_with_count(&pearl, 16).unwrap().item_count, 16);
+        assert!(stack_with_count(&pearl, 17).is_err());
+    }
+}
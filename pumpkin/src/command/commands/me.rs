@@ -27,6 +27,7 @@ impl CommandExecutor for Executor {
             let Some(Arg::Msg(msg)) = args.get(ARG_MESSAGE) else {
                 return Err(InvalidConsumption(Some(ARG_MESSAGE.into())));
             };
+            server.chat_filter.check_not_muted(sender).await?;
 
             server
                 .broadcast_message(
@@ -25,12 +25,15 @@ mod gamemode;
 mod gamerule;
 mod give;
 mod help;
+mod item;
 mod kick;
 mod kill;
 mod list;
 mod me;
 mod msg;
+mod mute;
 mod op;
+mod packetcapture;
 mod pardon;
 mod pardonip;
 mod particle;
@@ -38,8 +41,10 @@ mod playsound;
 mod plugin;
 mod plugins;
 mod pumpkin;
+mod random;
 mod rotate;
 mod say;
+mod schedule;
 mod seed;
 mod setblock;
 mod setidletimeout;
@@ -54,6 +59,7 @@ mod tick;
 mod time;
 mod title;
 mod transfer;
+mod unmute;
 mod weather;
 mod whitelist;
 mod worldborder;
@@ -73,6 +79,7 @@ pub async fn default_dispatcher(
     dispatcher.register(list::init_command_tree(), "minecraft:command.list");
     dispatcher.register(me::init_command_tree(), "minecraft:command.me");
     dispatcher.register(msg::init_command_tree(), "minecraft:command.msg");
+    dispatcher.register(random::init_command_tree(), "minecraft:command.random");
     // Two
     dispatcher.register(kill::init_command_tree(), "minecraft:command.kill");
     dispatcher.register(
@@ -89,6 +96,7 @@ pub async fn default_dispatcher(
     dispatcher.register(give::init_command_tree(), "minecraft:command.give");
     dispatcher.register(enchant::init_command_tree(), "minecraft:command.enchant");
     dispatcher.register(clear::init_command_tree(), "minecraft:command.clear");
+    dispatcher.register(item::init_command_tree(), "minecraft:command.item");
     dispatcher.register(setblock::init_command_tree(), "minecraft:command.setblock");
     dispatcher.register(seed::init_command_tree(), "minecraft:command.seed");
     dispatcher.register(fill::init_command_tree(), "minecraft:command.fill");
@@ -132,10 +140,13 @@ pub async fn default_dispatcher(
         "minecraft:command.spawnpoint",
     );
     dispatcher.register(data::init_command_tree(), "minecraft:command.data");
+    dispatcher.register(schedule::init_command_tree(), "minecraft:command.schedule");
     // Three
     dispatcher.register(op::init_command_tree(), "minecraft:command.op");
     dispatcher.register(deop::init_command_tree(), "minecraft:command.deop");
     dispatcher.register(kick::init_command_tree(), "minecraft:command.kick");
+    dispatcher.register(mute::init_command_tree(), "pumpkin:command.mute");
+    dispatcher.register(unmute::init_command_tree(), "pumpkin:command.unmute");
     dispatcher.register(plugin::init_command_tree(), "pumpkin:command.plugin");
     dispatcher.register(plugins::init_command_tree(), "pumpkin:command.plugins");
     dispatcher.register(ban::init_command_tree(), "minecraft:command.ban");
@@ -154,6 +165,10 @@ pub async fn default_dispatcher(
     );
     // Four
     dispatcher.register(stop::init_command_tree(), "minecraft:command.stop");
+    dispatcher.register(
+        packetcapture::init_command_tree(),
+        "pumpkin:command.packetcapture",
+    );
 
     dispatcher
 }
@@ -211,6 +226,13 @@ fn register_level_0_permissions(registry: &mut PermissionRegistry) {
             PermissionDefault::Allow,
         ))
         .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "minecraft:command.random",
+            "Draws a random value, or rolls one for everyone to see",
+            PermissionDefault::Allow,
+        ))
+        .unwrap();
 }
 
 #[expect(clippy::too_many_lines)]
@@ -265,6 +287,13 @@ fn register_level_2_permissions(registry: &mut PermissionRegistry) {
             PermissionDefault::Op(PermissionLvl::Two),
         ))
         .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "minecraft:command.item",
+            "Replaces items in the inventories of blocks and entities",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .unwrap();
     registry
         .register_permission(Permission::new(
             "minecraft:command.setblock",
@@ -419,6 +448,20 @@ fn register_level_2_permissions(registry: &mut PermissionRegistry) {
             PermissionDefault::Op(PermissionLvl::Two),
         ))
         .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "minecraft:command.schedule",
+            "Delays the execution of a function",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "pumpkin:bypass.idle_timeout",
+            "Prevents the player from being kicked for being idle",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .unwrap();
 }
 
 #[expect(clippy::too_many_lines)]
@@ -452,6 +495,20 @@ fn register_level_3_permissions(registry: &mut PermissionRegistry) {
             PermissionDefault::Op(PermissionLvl::Three),
         ))
         .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "pumpkin:command.mute",
+            "Prevents players from sending chat messages",
+            PermissionDefault::Op(PermissionLvl::Three),
+        ))
+        .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "pumpkin:command.unmute",
+            "Allows muted players to chat again",
+            PermissionDefault::Op(PermissionLvl::Three),
+        ))
+        .unwrap();
     registry
         .register_permission(Permission::new(
             "pumpkin:command.plugin",
@@ -540,4 +597,11 @@ fn register_level_4_permissions(registry: &mut PermissionRegistry) {
             PermissionDefault::Op(PermissionLvl::Four),
         ))
         .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "pumpkin:command.packetcapture",
+            "Records the packets sent to players",
+            PermissionDefault::Op(PermissionLvl::Four),
+        ))
+        .unwrap();
 }
@@ -13,6 +13,7 @@ use crate::command::{
     },
 };
 use crate::entity::EntityBase;
+use crate::world::chat::{ChatMessage, send_chat};
 use CommandError::InvalidConsumption;
 
 const NAMES: [&str; 3] = ["msg", "tell", "w"];
@@ -27,7 +28,7 @@ impl CommandExecutor for Executor {
     fn execute<'a>(
         &'a self,
         sender: &'a CommandSender,
-        _server: &'a crate::server::Server,
+        server: &'a crate::server::Server,
         args: &'a ConsumedArgs<'a>,
     ) -> CommandResult<'a> {
         Box::pin(async move {
@@ -36,26 +37,34 @@ impl CommandExecutor for Executor {
             };
             let targets = PlayersArgumentConsumer.find_arg_default_name(args)?;
             let player = sender.as_player().ok_or(CommandError::InvalidRequirement)?;
+            server.chat_filter.check_not_muted(sender).await?;
 
+            let message = TextComponent::text(msg.clone());
+            let sender_name = player.get_display_name().await;
             for target in targets {
-                player
-                    .send_message(
-                        &TextComponent::text(msg.clone()),
+                let target_name = target.get_display_name().await;
+                let whisper = |chat_type, bedrock_source| ChatMessage {
+                    content: &message,
+                    chat_type,
+                    sender_name: &sender_name,
+                    target_name: Some(&target_name),
+                    signed: None,
+                    bedrock_message: msg.clone(),
+                    bedrock_source,
+                };
+                send_chat(
+                    std::slice::from_ref(&player),
+                    &whisper(
                         MSG_COMMAND_OUTGOING,
-                        &player.get_display_name().await,
-                        Some(&target.get_display_name().await),
-                    )
-                    .await;
-            }
-            for target in targets {
-                target
-                    .send_message(
-                        &TextComponent::text(msg.clone()),
-                        MSG_COMMAND_INCOMING,
-                        &player.get_display_name().await,
-                        Some(&target.get_display_name().await),
-                    )
-                    .await;
+                        format!("{} -> {}", player.gameprofile.name, target.gameprofile.name),
+                    ),
+                )
+                .await;
+                send_chat(
+                    std::slice::from_ref(target),
+                    &whisper(MSG_COMMAND_INCOMING, player.gameprofile.name.clone()),
+                )
+                .await;
             }
 
             Ok(targets.len() as i32)
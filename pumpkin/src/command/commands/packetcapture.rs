@@ -0,0 +1,157 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use pumpkin_util::text::TextComponent;
+use pumpkin_util::text::color::NamedColor;
+
+use crate::command::args::bounded_num::{BoundedNumArgumentConsumer, Number};
+use crate::command::args::players::PlayersArgumentConsumer;
+use crate::command::args::{Arg, ConsumedArgs, GetCloned};
+use crate::command::dispatcher::CommandError;
+use crate::command::tree::CommandTree;
+use crate::command::tree::builder::{argument, literal};
+use crate::command::{CommandExecutor, CommandResult, CommandSender};
+use crate::net::ClientPlatform;
+
+const NAMES: [&str; 1] = ["packetcapture"];
+
+const DESCRIPTION: &str = "Records the packets sent to players into a file for debugging.";
+
+const ARG_TARGETS: &str = "targets";
+const ARG_MAX_MEGABYTES: &str = "max_megabytes";
+
+const CAPTURE_FOLDER: &str = "packet-captures";
+const DEFAULT_MAX_MEGABYTES: i32 = 64;
+
+const fn max_megabytes_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new()
+        .min(1)
+        .name(ARG_MAX_MEGABYTES)
+}
+
+struct StartExecutor;
+
+impl CommandExecutor for StartExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        _server: &'a crate::server::Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let Some(Arg::Players(targets)) = args.get(&ARG_TARGETS) else {
+                return Err(CommandError::InvalidConsumption(Some(ARG_TARGETS.into())));
+            };
+            let max_megabytes = match args.get_cloned(&ARG_MAX_MEGABYTES) {
+                Some(Arg::Num(Ok(Number::I32(max_megabytes)))) => max_megabytes,
+                None => DEFAULT_MAX_MEGABYTES,
+                _ => {
+                    return Err(CommandError::InvalidConsumption(Some(
+                        ARG_MAX_MEGABYTES.into(),
+                    )));
+                }
+            };
+            let max_bytes = u64::from(max_megabytes.unsigned_abs()) * 1024 * 1024;
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_secs());
+
+            let mut started = 0;
+            for target in targets {
+                let ClientPlatform::Java(client) = &target.client else {
+                    sender
+                        .send_message(
+                            TextComponent::text(format!(
+                                "{} is not a Java player",
+                                target.gameprofile.name
+                            ))
+                            .color_named(NamedColor::Red),
+                        )
+                        .await;
+                    continue;
+                };
+
+                let path = PathBuf::from(CAPTURE_FOLDER)
+                    .join(format!("{}-{timestamp}.bin", target.gameprofile.name));
+                match client.start_packet_capture(
+                    path.clone(),
+                    max_bytes,
+                    target.world().server.clone(),
+                ) {
+                    Ok(()) => {
+                        started += 1;
+                        sender
+                            .send_message(TextComponent::text(format!(
+                                "Capturing packets of {} to {}",
+                                target.gameprofile.name,
+                                path.display()
+                            )))
+                            .await;
+                    }
+                    Err(err) => {
+                        return Err(CommandError::CommandFailed(TextComponent::text(format!(
+                            "Failed to create {}: {err}",
+                            path.display()
+                        ))));
+                    }
+                }
+            }
+
+            Ok(started)
+        })
+    }
+}
+
+struct StopExecutor;
+
+impl CommandExecutor for StopExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        _server: &'a crate::server::Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let Some(Arg::Players(targets)) = args.get(&ARG_TARGETS) else {
+                return Err(CommandError::InvalidConsumption(Some(ARG_TARGETS.into())));
+            };
+
+            let mut stopped = 0;
+            for target in targets {
+                let ClientPlatform::Java(client) = &target.client else {
+                    continue;
+                };
+                if let Some(path) = client.stop_packet_capture() {
+                    stopped += 1;
+                    sender
+                        .send_message(TextComponent::text(format!(
+                            "Stopped capturing packets of {}, saved to {}",
+                            target.gameprofile.name,
+                            path.display()
+                        )))
+                        .await;
+                }
+            }
+
+            if stopped == 0 {
+                return Err(CommandError::CommandFailed(TextComponent::text(
+                    "No packet capture is running for the targets",
+                )));
+            }
+            Ok(stopped)
+        })
+    }
+}
+
+#[must_use]
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(ARG_TARGETS, PlayersArgumentConsumer)
+            .then(
+                literal("start").execute(StartExecutor).then(
+                    argument(ARG_MAX_MEGABYTES, max_megabytes_consumer()).execute(StartExecutor),
+                ),
+            )
+            .then(literal("stop").execute(StopExecutor)),
+    )
+}
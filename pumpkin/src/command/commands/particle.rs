@@ -1,4 +1,5 @@
 use pumpkin_util::{math::vector3::Vector3, text::TextComponent};
+use pumpkin_world::block::entities::BlockEntity;
 
 use crate::command::{
     CommandError, CommandExecutor, CommandResult, CommandSender,
@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use pumpkin_data::translation;
+use pumpkin_util::{PermissionLvl, text::TextComponent};
+
+use crate::command::{
+    CommandError, CommandExecutor, CommandResult, CommandSender,
+    args::{
+        ConsumedArgs, FindArg, FindArgDefaultName, bool::BoolArgConsumer,
+        bounded_num::BoundedNumArgumentConsumer, int_range::IntRangeArgumentConsumer,
+        resource_location::ResourceLocationArgumentConsumer,
+    },
+    tree::{
+        CommandTree,
+        builder::{NonLeafNodeBuilder, argument, argument_default_name, literal, require},
+    },
+};
+use crate::world::{World, random_sequences::SequenceSettings};
+
+const NAMES: [&str; 1] = ["random"];
+
+const DESCRIPTION: &str = "Draws a random value or rolls one for everyone to see.";
+
+const ARG_RANGE: &str = "range";
+const ARG_SEQUENCE: &str = "sequence";
+const ARG_INCLUDE_WORLD_SEED: &str = "includeWorldSeed";
+const ARG_INCLUDE_SEQUENCE_ID: &str = "includeSequenceId";
+
+const fn seed_consumer() -> BoundedNumArgumentConsumer<i32> {
+    BoundedNumArgumentConsumer::new().name("seed")
+}
+
+/// Sequence ids are resource locations, so a missing namespace means `minecraft`.
+fn sequence_id(id: &str) -> String {
+    if id.contains(':') {
+        id.to_string()
+    } else {
+        format!("minecraft:{id}")
+    }
+}
+
+fn sender_world(sender: &CommandSender, server: &crate::server::Server) -> Option<Arc<World>> {
+    sender
+        .world()
+        .or_else(|| server.worlds.load().first().cloned())
+}
+
+struct DrawExecutor {
+    /// Whether the result is announced to all players (`roll`) or only to the sender (`value`).
+    roll: bool,
+}
+
+impl CommandExecutor for DrawExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a crate::server::Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let (min, max) = IntRangeArgumentConsumer::find_arg(args, ARG_RANGE)?;
+            let min = min.unwrap_or(i32::MIN);
+            let max = max.unwrap_or(i32::MAX);
+
+            let range_size = i64::from(max) - i64::from(min);
+            if range_size == 0 {
+                return Err(CommandError::CommandFailed(TextComponent::translate(
+                    translation::COMMANDS_RANDOM_ERROR_RANGE_TOO_SMALL,
+                    [],
+                )));
+            }
+            if range_size >= i64::from(i32::MAX) {
+                return Err(CommandError::CommandFailed(TextComponent::translate(
+                    translation::COMMANDS_RANDOM_ERROR_RANGE_TOO_LARGE,
+                    [],
+                )));
+            }
+
+            let sequence = ResourceLocationArgumentConsumer::find_arg(args, ARG_SEQUENCE)
+                .ok()
+                .map(sequence_id);
+            let world = sender_world(sender, server).ok_or(CommandError::InvalidRequirement)?;
+            let value = world
+                .get_random_sequence(sequence.as_deref())
+                .next_between_inclusive(min, max);
+
+            if self.roll {
+                let message = TextComponent::translate(
+                    translation::COMMANDS_RANDOM_ROLL,
+                    [
+                        TextComponent::text(sender.to_string()),
+                        TextComponent::text(value.to_string()),
+                        TextComponent::text(min.to_string()),
+                        TextComponent::text(max.to_string()),
+                    ],
+                );
+                for player in server.get_all_players() {
+                    player.send_system_message(&message).await;
+                }
+            } else {
+                sender
+                    .send_message(TextComponent::translate(
+                        translation::COMMANDS_RANDOM_SAMPLE_SUCCESS,
+                        [TextComponent::text(value.to_string())],
+                    ))
+                    .await;
+            }
+
+            Ok(value)
+        })
+    }
+}
+
+struct ResetExecutor {
+    /// Whether all sequences are reset (`*`) instead of a single one.
+    all: bool,
+}
+
+impl CommandExecutor for ResetExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a crate::server::Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let settings = match seed_consumer().find_arg_default_name(args) {
+                Ok(Ok(salt)) => Some(SequenceSettings {
+                    salt,
+                    include_world_seed: BoolArgConsumer::find_arg(args, ARG_INCLUDE_WORLD_SEED)
+                        .unwrap_or(true),
+                    include_sequence_id: BoolArgConsumer::find_arg(args, ARG_INCLUDE_SEQUENCE_ID)
+                        .unwrap_or(true),
+                }),
+                Ok(Err(_)) => {
+                    return Err(CommandError::CommandFailed(TextComponent::translate(
+                        "parsing.int.invalid",
+                        [TextComponent::text(i32::MAX.to_string())],
+                    )));
+                }
+                Err(_) => None,
+            };
+            let world = sender_world(sender, server).ok_or(CommandError::InvalidRequirement)?;
+            let sequences = &world.random_sequences;
+
+            if self.all {
+                let count = settings.map_or_else(
+                    || sequences.clear(),
+                    |settings| sequences.clear_and_set_defaults(settings),
+                );
+                sender
+                    .send_message(TextComponent::translate(
+                        translation::COMMANDS_RANDOM_RESET_ALL_SUCCESS,
+                        [TextComponent::text(count.to_string())],
+                    ))
+                    .await;
+                return Ok(count as i32);
+            }
+
+            let id = sequence_id(ResourceLocationArgumentConsumer::find_arg(
+                args,
+                ARG_SEQUENCE,
+            )?);
+            if let Some(settings) = settings {
+                sequences.reset_with(&id, settings);
+            } else {
+                sequences.reset(&id);
+            }
+            sender
+                .send_message(TextComponent::translate(
+                    translation::COMMANDS_RANDOM_RESET_SUCCESS,
+                    [TextComponent::text(id)],
+                ))
+                .await;
+            Ok(1)
+        })
+    }
+}
+
+fn draw(name: &'static str, roll: bool) -> NonLeafNodeBuilder {
+    literal(name).then(
+        argument(ARG_RANGE, IntRangeArgumentConsumer)
+            .execute(DrawExecutor { roll })
+            .then(
+                require(|sender| sender.has_permission_lvl(PermissionLvl::Two)).then(
+                    argument(ARG_SEQUENCE, ResourceLocationArgumentConsumer)
+                        .execute(DrawExecutor { roll }),
+                ),
+            ),
+    )
+}
+
+fn reset_settings(all: bool) -> NonLeafNodeBuilder {
+    argument_default_name(seed_consumer())
+        .execute(ResetExecutor { all })
+        .then(
+            argument(ARG_INCLUDE_WORLD_SEED, BoolArgConsumer)
+                .execute(ResetExecutor { all })
+                .then(
+                    argument(ARG_INCLUDE_SEQUENCE_ID, BoolArgConsumer)
+                        .execute(ResetExecutor { all }),
+                ),
+        )
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(draw("value", false))
+        .then(draw("roll", true))
+        .then(
+            require(|sender| sender.has_permission_lvl(PermissionLvl::Two)).then(
+                literal("reset")
+                    .then(
+                        literal("*")
+                            .execute(ResetExecutor { all: true })
+                            .then(reset_settings(true)),
+                    )
+                    .then(
+                        argument(ARG_SEQUENCE, ResourceLocationArgumentConsumer)
+                            .execute(ResetExecutor { all: false })
+                            .then(reset_settings(false)),
+                    ),
+            ),
+        )
+}
@@ -10,6 +10,7 @@ use crate::command::args::{ConsumedArgs, FindArg};
 use crate::command::tree::CommandTree;
 use crate::command::tree::builder::{argument, literal};
 use crate::command::{CommandExecutor, CommandResult, CommandSender};
+use crate::entity::TeleportCause;
 
 const NAMES: [&str; 1] = ["rotate"];
 const DESCRIPTION: &str = "Changes the rotation of an entity.";
@@ -81,7 +82,13 @@ async fn rotate_entity(
     let pos = entity.pos.load();
     let world = entity.world.load_full();
     target
-        .teleport(pos, Some(final_yaw), Some(final_pitch), world)
+        .teleport(
+            pos,
+            Some(final_yaw),
+            Some(final_pitch),
+            world,
+            TeleportCause::Command,
+        )
         .await;
 }
 
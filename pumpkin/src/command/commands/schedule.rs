@@ -0,0 +1,142 @@
+use pumpkin_data::translation;
+use pumpkin_util::text::TextComponent;
+
+use crate::command::{
+    CommandError, CommandExecutor, CommandResult, CommandSender,
+    args::{
+        ConsumedArgs, FindArg, resource_location::ResourceLocationArgumentConsumer,
+        time::TimeArgumentConsumer,
+    },
+    tree::{
+        CommandTree,
+        builder::{argument, literal},
+    },
+};
+use crate::world::scheduled_callbacks::{ScheduleError, ScheduleMode};
+
+const NAMES: [&str; 1] = ["schedule"];
+
+const DESCRIPTION: &str = "Delays the execution of a function.";
+
+const ARG_FUNCTION: &str = "function";
+const ARG_TIME: &str = "time";
+
+/// Function ids are resource locations, so a missing namespace means `minecraft`.
+fn function_id(id: &str) -> String {
+    if id.contains(':') {
+        id.to_string()
+    } else {
+        format!("minecraft:{id}")
+    }
+}
+
+struct ScheduleExecutor(ScheduleMode);
+
+impl CommandExecutor for ScheduleExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a crate::server::Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let id = function_id(ResourceLocationArgumentConsumer::find_arg(
+                args,
+                ARG_FUNCTION,
+            )?);
+            let ticks = TimeArgumentConsumer::find_arg(args, ARG_TIME)?;
+
+            // The game time is shared by all worlds, so the overworld keeps the schedule
+            let world = server
+                .worlds
+                .load()
+                .first()
+                .cloned()
+                .ok_or(CommandError::InvalidRequirement)?;
+            let trigger_time = match world
+                .schedule_named_callback(id.clone(), i64::from(ticks), self.0)
+                .await
+            {
+                Ok(trigger_time) => trigger_time,
+                Err(ScheduleError::SameTick) => {
+                    return Err(CommandError::CommandFailed(TextComponent::translate(
+                        translation::COMMANDS_SCHEDULE_SAME_TICK,
+                        [],
+                    )));
+                }
+                Err(err @ ScheduleError::QueueFull) => {
+                    return Err(CommandError::CommandFailed(TextComponent::text(
+                        err.to_string(),
+                    )));
+                }
+            };
+
+            sender
+                .send_message(TextComponent::translate(
+                    translation::COMMANDS_SCHEDULE_CREATED_FUNCTION,
+                    [
+                        TextComponent::text(id),
+                        TextComponent::text(ticks.to_string()),
+                        TextComponent::text(trigger_time.to_string()),
+                    ],
+                ))
+                .await;
+            Ok(trigger_time as i32)
+        })
+    }
+}
+
+struct ClearExecutor;
+
+impl CommandExecutor for ClearExecutor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a crate::server::Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let id = function_id(ResourceLocationArgumentConsumer::find_arg(
+                args,
+                ARG_FUNCTION,
+            )?);
+            let count = server.scheduled_callbacks.clear(&id);
+            if count == 0 {
+                return Err(CommandError::CommandFailed(TextComponent::translate(
+                    translation::COMMANDS_SCHEDULE_CLEARED_FAILURE,
+                    [TextComponent::text(id)],
+                )));
+            }
+
+            sender
+                .send_message(TextComponent::translate(
+                    translation::COMMANDS_SCHEDULE_CLEARED_SUCCESS,
+                    [
+                        TextComponent::text(count.to_string()),
+                        TextComponent::text(id),
+                    ],
+                ))
+                .await;
+            Ok(count as i32)
+        })
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(
+            literal("function").then(
+                argument(ARG_FUNCTION, ResourceLocationArgumentConsumer).then(
+                    argument(ARG_TIME, TimeArgumentConsumer)
+                        .execute(ScheduleExecutor(ScheduleMode::Replace))
+                        .then(literal("append").execute(ScheduleExecutor(ScheduleMode::Append)))
+                        .then(literal("replace").execute(ScheduleExecutor(ScheduleMode::Replace))),
+                ),
+            ),
+        )
+        .then(
+            literal("clear").then(
+                argument(ARG_FUNCTION, ResourceLocationArgumentConsumer).execute(ClearExecutor),
+            ),
+        )
+}
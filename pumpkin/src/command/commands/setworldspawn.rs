@@ -1,5 +1,3 @@
-use std::sync::Arc;
-
 use crate::command::CommandResult;
 use crate::command::dispatcher::CommandError::InvalidConsumption;
 use crate::command::{
@@ -11,7 +9,6 @@ use crate::command::{
     dispatcher::CommandError,
     tree::{CommandTree, builder::argument},
 };
-use crate::server::Server;
 use pumpkin_data::dimension::Dimension;
 use pumpkin_data::translation;
 use pumpkin_util::{math::position::BlockPos, text::TextComponent};
@@ -30,7 +27,7 @@ impl CommandExecutor for NoArgsWorldSpawnExecutor {
     fn execute<'a>(
         &'a self,
         sender: &'a CommandSender,
-        server: &'a crate::server::Server,
+        _server: &'a crate::server::Server,
         _args: &'a ConsumedArgs<'a>,
     ) -> CommandResult<'a> {
         Box::pin(async move {
@@ -45,7 +42,7 @@ impl CommandExecutor for NoArgsWorldSpawnExecutor {
                 )));
             };
             let block_pos = player.position();
-            setworldspawn(sender, server, block_pos.to_block_pos(), 0.0, 0.0).await
+            setworldspawn(sender, block_pos.to_block_pos(), 0.0, 0.0).await
         })
     }
 }
@@ -56,7 +53,7 @@ impl CommandExecutor for DefaultWorldSpawnExecutor {
     fn execute<'a>(
         &'a self,
         sender: &'a CommandSender,
-        server: &'a crate::server::Server,
+        _server: &'a crate::server::Server,
         args: &'a ConsumedArgs<'a>,
     ) -> CommandResult<'a> {
         Box::pin(async move {
@@ -64,7 +61,7 @@ impl CommandExecutor for DefaultWorldSpawnExecutor {
                 return Err(InvalidConsumption(Some(ARG_BLOCK_POS.into())));
             };
 
-            setworldspawn(sender, server, *block_pos, 0.0, 0.0).await
+            setworldspawn(sender, *block_pos, 0.0, 0.0).await
         })
     }
 }
@@ -75,7 +72,7 @@ impl CommandExecutor for AngleWorldSpawnExecutor {
     fn execute<'a>(
         &'a self,
         sender: &'a CommandSender,
-        server: &'a crate::server::Server,
+        _server: &'a crate::server::Server,
         args: &'a ConsumedArgs<'a>,
     ) -> CommandResult<'a> {
         Box::pin(async move {
@@ -89,14 +86,13 @@ impl CommandExecutor for AngleWorldSpawnExecutor {
                 return Err(InvalidConsumption(Some(ARG_ANGLE.into())));
             };
 
-            setworldspawn(sender, server, *block_pos, *yaw, *pitch).await
+            setworldspawn(sender, *block_pos, *yaw, *pitch).await
         })
     }
 }
 
 async fn setworldspawn(
     sender: &CommandSender,
-    server: &Server,
     block_pos: BlockPos,
     yaw: f32,
     pitch: f32,
@@ -113,17 +109,12 @@ async fn setworldspawn(
         )));
     }
 
-    let current_info = server.level_info.load();
-
-    let mut new_info = (**current_info).clone();
-
-    new_info.spawn_x = block_pos.0.x;
-    new_info.spawn_y = block_pos.0.y;
-    new_info.spawn_z = block_pos.0.z;
-    new_info.spawn_yaw = yaw;
-    new_info.spawn_pitch = pitch;
-
-    server.level_info.store(Arc::new(new_info));
+    if world.set_spawn_point(block_pos, yaw, pitch).await.is_err() {
+        return Err(CommandError::CommandFailed(TextComponent::translate(
+            translation::ARGUMENT_POS_OUTOFWORLD,
+            [],
+        )));
+    }
 
     sender
         .send_message(TextComponent::translate(
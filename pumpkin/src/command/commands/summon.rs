@@ -1,5 +1,6 @@
 use pumpkin_data::translation;
 use pumpkin_util::{math::vector3::Vector3, text::TextComponent};
+use pumpkin_world::block::entities::BlockEntity;
 use uuid::Uuid;
 
 use crate::{
@@ -13,7 +13,7 @@ use crate::command::args::rotation::RotationArgumentConsumer;
 use crate::command::tree::CommandTree;
 use crate::command::tree::builder::{argument, literal};
 use crate::command::{CommandExecutor, CommandSender};
-use crate::entity::EntityBase;
+use crate::entity::{EntityBase, TeleportCause};
 use crate::world::World;
 
 const NAMES: [&str; 2] = ["teleport", "tp"];
@@ -79,7 +79,7 @@ impl CommandExecutor for EntitiesToEntityExecutor {
                 let world = base_entity.world.load_full();
                 target
                     .clone()
-                    .teleport(pos, yaw.into(), pitch.into(), world)
+                    .teleport(pos, yaw.into(), pitch.into(), world, TeleportCause::Command)
                     .await;
             }
 
@@ -121,7 +121,13 @@ impl CommandExecutor for EntitiesToPosFacingPosExecutor {
             for target in targets {
                 target
                     .clone()
-                    .teleport(pos, Some(yaw), Some(pitch), world.clone())
+                    .teleport(
+                        pos,
+                        Some(yaw),
+                        Some(pitch),
+                        world.clone(),
+                        TeleportCause::Command,
+                    )
                     .await;
             }
 
@@ -161,6 +167,7 @@ impl CommandExecutor for EntitiesToPosFacingEntityExecutor {
                         Some(yaw),
                         Some(pitch),
                         facing_entity.get_entity().world.load_full(),
+                        TeleportCause::Command,
                     )
                     .await;
             }
@@ -198,7 +205,13 @@ impl CommandExecutor for EntitiesToPosWithRotationExecutor {
             for target in targets {
                 target
                     .clone()
-                    .teleport(pos, Some(yaw), Some(pitch), world.clone())
+                    .teleport(
+                        pos,
+                        Some(yaw),
+                        Some(pitch),
+                        world.clone(),
+                        TeleportCause::Command,
+                    )
                     .await;
             }
 
@@ -239,7 +252,13 @@ impl CommandExecutor for EntitiesToPosExecutor {
                 let pitch = target.get_entity().pitch.load();
                 target
                     .clone()
-                    .teleport(pos, Some(yaw), Some(pitch), world.clone())
+                    .teleport(
+                        pos,
+                        Some(yaw),
+                        Some(pitch),
+                        world.clone(),
+                        TeleportCause::Command,
+                    )
                     .await;
             }
 
@@ -274,7 +293,7 @@ impl CommandExecutor for SelfToEntityExecutor {
                     }
                     player
                         .clone()
-                        .teleport(pos, Some(yaw), Some(pitch), world)
+                        .teleport(pos, Some(yaw), Some(pitch), world, TeleportCause::Command)
                         .await;
 
                     Ok(1)
@@ -310,7 +329,13 @@ impl CommandExecutor for SelfToPosExecutor {
                     }
                     player
                         .clone()
-                        .teleport(pos, Some(yaw), Some(pitch), player.world().clone())
+                        .teleport(
+                            pos,
+                            Some(yaw),
+                            Some(pitch),
+                            player.world().clone(),
+                            TeleportCause::Command,
+                        )
                         .await;
 
                     Ok(1)
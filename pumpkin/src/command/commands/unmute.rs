@@ -0,0 +1,56 @@
+use pumpkin_util::text::TextComponent;
+use pumpkin_util::text::color::NamedColor;
+
+use crate::command::args::players::PlayersArgumentConsumer;
+use crate::command::args::{Arg, ConsumedArgs};
+use crate::command::tree::CommandTree;
+use crate::command::tree::builder::argument;
+use crate::command::{CommandError, CommandResult};
+use crate::command::{CommandExecutor, CommandSender};
+use crate::entity::EntityBase;
+use CommandError::InvalidConsumption;
+
+const NAMES: [&str; 1] = ["unmute"];
+const DESCRIPTION: &str = "Allows muted players to send chat messages again.";
+
+const ARG_TARGETS: &str = "targets";
+
+struct Executor;
+
+impl CommandExecutor for Executor {
+    fn execute<'a>(
+        &'a self,
+        sender: &'a CommandSender,
+        server: &'a crate::server::Server,
+        args: &'a ConsumedArgs<'a>,
+    ) -> CommandResult<'a> {
+        Box::pin(async move {
+            let Some(Arg::Players(targets)) = args.get(&ARG_TARGETS) else {
+                return Err(InvalidConsumption(Some(ARG_TARGETS.into())));
+            };
+
+            let mut count = 0;
+            for target in targets {
+                if !server.chat_filter.unmute(target.gameprofile.id).await {
+                    continue;
+                }
+                count += 1;
+                let msg =
+                    TextComponent::text("Unmuted: ").add_child(target.get_display_name().await);
+                sender.send_message(msg.color_named(NamedColor::Blue)).await;
+            }
+
+            if count == 0 {
+                return Err(CommandError::CommandFailed(TextComponent::text(
+                    "Nothing changed. The player is not muted",
+                )));
+            }
+            Ok(count)
+        })
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION)
+        .then(argument(ARG_TARGETS, PlayersArgumentConsumer).execute(Executor))
+}
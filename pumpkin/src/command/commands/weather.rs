@@ -41,15 +41,13 @@ impl CommandExecutor for Executor {
                     .cloned()
                     .ok_or(CommandError::InvalidRequirement)?
             };
-            let mut weather = world.weather.lock().await;
-
             match self.mode {
                 WeatherMode::Clear => {
                     let processed_duration =
                         duration.unwrap_or_else(|| rand::random_range(12_000..=180_000));
 
-                    weather
-                        .set_weather_parameters(&world, processed_duration, 0, false, false)
+                    world
+                        .set_weather_parameters(processed_duration, 0, false, false)
                         .await;
                     sender
                         .send_message(TextComponent::translate(
@@ -62,8 +60,8 @@ impl CommandExecutor for Executor {
                     let processed_duration =
                         duration.unwrap_or_else(|| rand::random_range(12_000..=24_000));
 
-                    weather
-                        .set_weather_parameters(&world, 0, processed_duration, true, false)
+                    world
+                        .set_weather_parameters(0, processed_duration, true, false)
                         .await;
                     sender
                         .send_message(TextComponent::translate(
@@ -76,8 +74,8 @@ impl CommandExecutor for Executor {
                     let processed_duration =
                         duration.unwrap_or_else(|| rand::random_range(3_600..=15_600));
 
-                    weather
-                        .set_weather_parameters(&world, 0, processed_duration, true, true)
+                    world
+                        .set_weather_parameters(0, processed_duration, true, true)
                         .await;
                     sender
                         .send_message(TextComponent::translate(
@@ -1,3 +1,4 @@
+use pumpkin_data::translation;
 use pumpkin_protocol::java::client::play::CommandSuggestion;
 use pumpkin_util::text::TextComponent;
 use rustc_hash::FxHashMap;
@@ -7,7 +8,7 @@ use super::args::ConsumedArgs;
 
 use crate::command::CommandSender;
 use crate::command::dispatcher::CommandError::{
-    CommandFailed, InvalidConsumption, InvalidRequirement, PermissionDenied,
+    CommandFailed, InvalidConsumption, InvalidRequirement, PermissionDenied, SyntaxError,
 };
 use crate::command::tree::{Command, CommandTree, NodeType, RawArgs};
 use crate::server::Server;
@@ -27,6 +28,13 @@ pub enum CommandError {
     /// A general error occurred during command execution that doesn't fit into
     /// more specific `CommandError` variants.
     CommandFailed(TextComponent),
+    /// The argument `argument` could not be parsed at `cursor`, a byte offset into it. The error
+    /// points at the same place in the whole command when shown.
+    SyntaxError {
+        message: TextComponent,
+        argument: String,
+        cursor: usize,
+    },
 }
 
 impl CommandError {
@@ -52,10 +60,44 @@ impl CommandError {
                 )
             }
             CommandFailed(s) => s,
+            SyntaxError {
+                message,
+                argument,
+                cursor,
+            } => {
+                // Arguments are slices of the command, so the first match is where it starts
+                let cursor = cmd.find(&argument).unwrap_or_default() + cursor;
+                syntax_error_component(cmd, cursor.min(cmd.len()), message)
+            }
         }
     }
 }
 
+/// Vanilla's parse error, pointing at `cursor` and showing up to 10 characters of `cmd` before it.
+fn syntax_error_component(cmd: &str, cursor: usize, message: TextComponent) -> TextComponent {
+    let context_start = cmd[..cursor]
+        .char_indices()
+        .rev()
+        .nth(9)
+        .map_or(0, |(index, _)| index);
+    let mut context = String::new();
+    if context_start > 0 {
+        context.push_str("...");
+    }
+    context.push_str(&cmd[context_start..cursor]);
+    TextComponent::translate(
+        translation::COMMAND_CONTEXT_PARSE_ERROR,
+        [
+            message,
+            TextComponent::text(cursor.to_string()),
+            TextComponent::text(context).add_child(TextComponent::translate(
+                translation::COMMAND_CONTEXT_HERE,
+                [],
+            )),
+        ],
+    )
+}
+
 #[derive(Default)]
 pub struct CommandDispatcher {
     pub commands: FxHashMap<String, Command>,
@@ -126,7 +168,7 @@ impl CommandDispatcher {
                     debug!("Permission denied for command \"{cmd}\"");
                     return Vec::new();
                 }
-                Err(CommandFailed(_)) => {
+                Err(CommandFailed(_) | SyntaxError { .. }) => {
                     debug!("Command failed");
                     return Vec::new();
                 }
@@ -442,7 +484,25 @@ mod test {
     use pumpkin_util::permission::PermissionRegistry;
     use tokio::sync::RwLock;
 
+    use pumpkin_util::text::TextComponent;
+
+    use super::{CommandDispatcher, CommandError};
     use crate::command::{commands::default_dispatcher, tree::CommandTree};
+
+    #[test]
+    fn syntax_error_points_into_the_command() {
+        let cmd = "give @s stone[foo=1]";
+        let (_, args) = CommandDispatcher::split_parts(cmd).unwrap();
+        let error = CommandError::SyntaxError {
+            message: TextComponent::text("Unknown component"),
+            argument: args[0].to_string(),
+            cursor: "stone[".len(),
+        };
+        let text = error.into_component(cmd).get_text();
+        assert!(text.contains("14"), "{text}");
+        assert!(text.contains("@s stone["), "{text}");
+    }
+
     #[tokio::test]
     async fn dynamic_command() {
         let config = BasicConfiguration::default();
@@ -17,6 +17,7 @@ use pumpkin_world::block::entities::BlockEntity;
 use pumpkin_world::block::entities::command_block::CommandBlockEntity;
 
 pub mod args;
+pub mod chat_filter;
 pub mod client_suggestions;
 pub mod commands;
 pub mod dispatcher;
@@ -49,7 +50,7 @@ pub enum CommandSender {
     ///
     /// Contains the block entity responsible for the command and the
     /// world context it exists in for coordinate-relative execution (e.g., `~ ~ ~`).
-    CommandBlock(Arc<dyn BlockEntity>, Arc<World>),
+    CommandBlock(Arc<CommandBlockEntity>, Arc<World>),
 }
 
 impl fmt::Display for CommandSender {
@@ -74,9 +75,7 @@ impl CommandSender {
             Self::Console => println!("{}", text.to_pretty_console()),
             Self::Player(c) => c.send_system_message(&text).await,
             Self::Rcon(s) => s.lock().await.push(text.to_pretty_console()),
-            Self::CommandBlock(block_entity, _) => {
-                let command_entity: &CommandBlockEntity =
-                    block_entity.as_any().downcast_ref().unwrap();
+            Self::CommandBlock(command_entity, _) => {
                 let mut last_output = command_entity.last_output.lock().await;
 
                 let now = time::OffsetDateTime::now_utc();
@@ -89,8 +88,7 @@ impl CommandSender {
     }
 
     pub fn set_success_count(&self, count: u32) {
-        if let Self::CommandBlock(c, _) = self {
-            let block: &CommandBlockEntity = c.as_any().downcast_ref().unwrap();
+        if let Self::CommandBlock(block, _) = self {
             block
                 .success_count
                 .store(count, std::sync::atomic::Ordering::SeqCst);
@@ -11,6 +11,7 @@ pub mod op;
 pub mod banlist_serializer;
 pub mod banned_ip;
 pub mod banned_player;
+pub mod mute;
 pub mod player_server;
 pub mod whitelist;
 
@@ -46,6 +47,11 @@ pub trait LoadJSONConfiguration {
             fs::create_dir(&data_dir).expect("Failed to create data root folder");
         }
         let path = data_dir.join(Self::get_path());
+        if let Some(folder) = path.parent()
+            && !folder.exists()
+        {
+            fs::create_dir_all(folder).expect("Failed to create data folder");
+        }
 
         let config = if path.exists() {
             let file_content = fs::read_to_string(&path).unwrap_or_else(|_| {
@@ -91,6 +97,11 @@ pub trait SaveJSONConfiguration: LoadJSONConfiguration {
             fs::create_dir(&data_dir).expect("Failed to create data root folder");
         }
         let path = data_dir.join(Self::get_path());
+        if let Some(folder) = path.parent()
+            && !folder.exists()
+        {
+            fs::create_dir_all(folder).expect("Failed to create data folder");
+        }
 
         let content = match serde_json::to_string_pretty(self) {
             Ok(content) => content,
@@ -0,0 +1,21 @@
+use std::{collections::HashSet, path::Path};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{LoadJSONConfiguration, SaveJSONConfiguration};
+
+#[derive(Deserialize, Serialize, Default)]
+#[serde(transparent)]
+pub struct MuteList {
+    pub muted_players: HashSet<Uuid>,
+}
+
+impl LoadJSONConfiguration for MuteList {
+    fn get_path() -> &'static Path {
+        Path::new("ops/mutes.json")
+    }
+    fn validate(&self) {}
+}
+
+impl SaveJSONConfiguration for MuteList {}
@@ -137,6 +137,11 @@ impl ServerPlayerData {
                 }
                 Ok(Some(data))
             }
+            // Going on with default data would overwrite the file once the player is saved
+            Err(e @ PlayerDataError::UnsupportedVersion(_)) => {
+                error!("Refusing to load player data for {uuid}: {e}");
+                Err(e)
+            }
             Err(e) => {
                 if self.storage.is_save_enabled() {
                     // Only log as error if player data saving is enabled
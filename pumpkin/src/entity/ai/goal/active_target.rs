@@ -4,7 +4,7 @@ use crate::entity::ai::goal::track_target::TrackTargetGoal;
 use crate::entity::ai::target_predicate::TargetPredicate;
 use crate::entity::living::LivingEntity;
 use crate::entity::mob::Mob;
-use crate::entity::{EntityBase, mob::MobEntity, player::Player};
+use crate::entity::{EntityBase, mob::MobEntity};
 use crate::world::World;
 use pumpkin_data::entity::EntityType;
 use rand::RngExt;
@@ -66,41 +66,53 @@ impl ActiveTargetGoal {
         })
     }
 
-    fn find_closest_target(&mut self, mob: &MobEntity) {
-        let world = mob.living_entity.entity.world.load();
+    fn find_closest_target(&mut self, mob: &dyn Mob) {
+        let mob_entity = mob.get_mob_entity();
+        let world = mob_entity.living_entity.entity.world.load();
+        let pos = mob_entity.living_entity.entity.pos.load();
         if self.target_type == &EntityType::PLAYER {
-            let potential_player = world
-                .get_closest_player(
-                    mob.living_entity.entity.pos.load(),
-                    TrackTargetGoal::get_follow_range(mob),
-                )
-                .map(|p: Arc<Player>| p as Arc<dyn EntityBase>);
-            if let Some(potential_entity) = potential_player
-                && let Some(living) = potential_entity.get_living_entity()
-                && self
-                    .target_predicate
-                    .test(&world, Some(&mob.living_entity), living)
-            {
-                self.target = Some(potential_entity);
-                return;
-            }
+            let mut players =
+                world.get_nearby_players(pos, TrackTargetGoal::get_follow_range(mob_entity));
+            players.sort_by(|a, b| {
+                let a = a
+                    .living_entity
+                    .entity
+                    .pos
+                    .load()
+                    .squared_distance_to_vec(&pos);
+                let b = b
+                    .living_entity
+                    .entity
+                    .pos
+                    .load()
+                    .squared_distance_to_vec(&pos);
+                a.total_cmp(&b)
+            });
+            self.target = players
+                .into_iter()
+                .find(|player| {
+                    world.is_hostile_to_player(mob, player)
+                        && self.target_predicate.test(
+                            &world,
+                            Some(&mob_entity.living_entity),
+                            &player.living_entity,
+                        )
+                })
+                .map(|player| player as Arc<dyn EntityBase>);
         } else {
-            let potential_entity = world.get_closest_entity(
-                mob.living_entity.entity.pos.load(),
-                TrackTargetGoal::get_follow_range(mob),
-                Some(&[self.target_type]),
-            );
-            if let Some(potential_entity) = potential_entity
-                && let Some(living) = potential_entity.get_living_entity()
-                && self
-                    .target_predicate
-                    .test(&world, Some(&mob.living_entity), living)
-            {
-                self.target = Some(potential_entity);
-                return;
-            }
+            self.target = world
+                .get_closest_entity(
+                    pos,
+                    TrackTargetGoal::get_follow_range(mob_entity),
+                    Some(&[self.target_type]),
+                )
+                .filter(|entity| {
+                    entity.get_living_entity().is_some_and(|living| {
+                        self.target_predicate
+                            .test(&world, Some(&mob_entity.living_entity), living)
+                    })
+                });
         }
-        self.target = None;
     }
 }
 
@@ -112,7 +124,7 @@ impl Goal for ActiveTargetGoal {
             {
                 return false;
             }
-            self.find_closest_target(mob.get_mob_entity());
+            self.find_closest_target(mob);
             self.target.is_some()
         })
     }
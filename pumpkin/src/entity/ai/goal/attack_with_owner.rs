@@ -0,0 +1,101 @@
+use std::sync::Arc;
+use std::sync::atomic::Ordering::Relaxed;
+
+use super::{Controls, Goal};
+use crate::entity::EntityBase;
+use crate::entity::ai::goal::GoalFuture;
+use crate::entity::ai::goal::track_target::TrackTargetGoal;
+use crate::entity::ai::target_predicate::TargetPredicate;
+use crate::entity::mob::Mob;
+
+/// Makes a tamed mob target whatever its owner last attacked.
+pub struct AttackWithOwnerGoal {
+    track_target_goal: TrackTargetGoal,
+    target: Option<Arc<dyn EntityBase>>,
+    last_attacking_time: i32,
+    target_predicate: TargetPredicate,
+}
+
+impl Default for AttackWithOwnerGoal {
+    fn default() -> Self {
+        Self {
+            track_target_goal: TrackTargetGoal::with_default(false),
+            target: None,
+            last_attacking_time: 0,
+            target_predicate: TargetPredicate::create_attackable().ignore_visibility(),
+        }
+    }
+}
+
+impl Goal for AttackWithOwnerGoal {
+    fn can_start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async move {
+            let mob_entity = mob.get_mob_entity();
+            let Some(tameable) = mob.get_mob_tameable() else {
+                return false;
+            };
+            if tameable.is_sitting() {
+                return false;
+            }
+            let Some(owner) = tameable.get_owner(&mob_entity.living_entity.entity) else {
+                return false;
+            };
+            let owner_living = &owner.living_entity;
+
+            let attacking_time = owner_living.last_attacking_time.load(Relaxed);
+            if attacking_time == self.last_attacking_time {
+                return false;
+            }
+
+            let target_id = owner_living.last_attacking_id.load(Relaxed);
+            if target_id == 0 {
+                return false;
+            }
+
+            let world = mob_entity.living_entity.entity.world.load();
+            let Some(target) = world.get_entity_by_id(target_id) else {
+                return false;
+            };
+            if !tameable.can_attack_with_owner(target.as_ref()) {
+                return false;
+            }
+
+            let Some(target_living) = target.get_living_entity() else {
+                return false;
+            };
+            if !self
+                .target_predicate
+                .test(&world, Some(&mob_entity.living_entity), target_living)
+            {
+                return false;
+            }
+
+            self.last_attacking_time = attacking_time;
+            self.target = Some(target);
+            true
+        })
+    }
+
+    fn should_continue<'a>(&'a self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async { self.track_target_goal.should_continue(mob).await })
+    }
+
+    fn start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async {
+            let mob_entity = mob.get_mob_entity();
+            (*mob_entity.target.lock().await).clone_from(&self.target);
+            self.track_target_goal.start(mob).await;
+        })
+    }
+
+    fn stop<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async {
+            self.target = None;
+            self.track_target_goal.stop(mob).await;
+        })
+    }
+
+    fn controls(&self) -> Controls {
+        self.track_target_goal.controls()
+    }
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use pumpkin_data::BlockDirection;
+use pumpkin_util::{
+    GameMode,
+    math::{position::BlockPos, vector3::Vector3},
+};
+use rand::RngExt;
+
+use super::{Controls, Goal, GoalFuture, to_goal_ticks};
+use crate::entity::{TeleportCause, ai::pathfinder::NavigatorGoal, mob::Mob, player::Player};
+
+/// Squared distance from which a pet teleports to its owner instead of walking.
+const TELEPORT_DISTANCE_SQUARED: f64 = 144.0;
+const TELEPORT_ATTEMPTS: usize = 10;
+
+/// Makes a tamed mob follow its owner and teleport to them when it falls too far behind.
+pub struct FollowOwnerGoal {
+    goal_control: Controls,
+    owner: Option<Arc<Player>>,
+    speed: f64,
+    min_distance: f64,
+    max_distance: f64,
+    update_countdown_ticks: i32,
+}
+
+impl FollowOwnerGoal {
+    /// The mob starts following once it is `min_distance` blocks away from its owner and stops
+    /// again once it is within `max_distance` blocks.
+    #[must_use]
+    pub fn new(speed: f64, min_distance: f64, max_distance: f64) -> Self {
+        Self {
+            goal_control: Controls::MOVE | Controls::LOOK,
+            owner: None,
+            speed,
+            min_distance,
+            max_distance,
+            update_countdown_ticks: 0,
+        }
+    }
+
+    fn owner_distance_squared(mob: &dyn Mob, owner: &Player) -> f64 {
+        mob.get_mob_entity()
+            .living_entity
+            .entity
+            .pos
+            .load()
+            .squared_distance_to_vec(&owner.living_entity.entity.pos.load())
+    }
+
+    /// Tries a few random spots around the owner and teleports to the first safe one.
+    async fn try_teleport_near(mob: &dyn Mob, owner: &Player) {
+        let owner_pos = owner.living_entity.entity.block_pos.load();
+        for _ in 0..TELEPORT_ATTEMPTS {
+            let (dx, dy, dz) = {
+                let mut rng = mob.get_random();
+                (
+                    rng.random_range(-3i32..=3),
+                    rng.random_range(-1i32..=1),
+                    rng.random_range(-3i32..=3),
+                )
+            };
+            // Don't land right on top of the owner
+            if dx.abs() < 2 && dz.abs() < 2 {
+                continue;
+            }
+
+            let pos = owner_pos.offset(Vector3::new(dx, dy, dz));
+            if Self::can_teleport_to(mob, &pos).await {
+                let mob_entity = mob.get_mob_entity();
+                let entity = &mob_entity.living_entity.entity;
+                entity
+                    .teleport(
+                        Vector3::new(
+                            f64::from(pos.0.x) + 0.5,
+                            f64::from(pos.0.y),
+                            f64::from(pos.0.z) + 0.5,
+                        ),
+                        None,
+                        None,
+                        entity.world.load_full(),
+                        TeleportCause::Unknown,
+                    )
+                    .await;
+                mob_entity.navigator.lock().await.stop();
+                return;
+            }
+        }
+    }
+
+    async fn can_teleport_to(mob: &dyn Mob, pos: &BlockPos) -> bool {
+        let world = mob.get_mob_entity().living_entity.entity.world.load();
+        world
+            .get_block_state(&pos.down())
+            .await
+            .is_side_solid(BlockDirection::Up)
+            && world.get_block_state(pos).await.is_air()
+            && world.get_block_state(&pos.up()).await.is_air()
+    }
+}
+
+impl Goal for FollowOwnerGoal {
+    fn can_start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async move {
+            let Some(tameable) = mob.get_mob_tameable() else {
+                return false;
+            };
+            if tameable.is_sitting() {
+                return false;
+            }
+            let Some(owner) = tameable.get_owner(&mob.get_mob_entity().living_entity.entity) else {
+                return false;
+            };
+            if owner.gamemode.load() == GameMode::Spectator
+                || Self::owner_distance_squared(mob, &owner) < self.min_distance * self.min_distance
+            {
+                return false;
+            }
+
+            self.owner = Some(owner);
+            true
+        })
+    }
+
+    fn should_continue<'a>(&'a self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async move {
+            let Some(owner) = &self.owner else {
+                return false;
+            };
+            if mob
+                .get_mob_tameable()
+                .is_none_or(|tameable| tameable.is_sitting())
+            {
+                return false;
+            }
+            let navigator = mob.get_mob_entity().navigator.lock().await;
+            !navigator.is_idle()
+                && Self::owner_distance_squared(mob, owner) > self.max_distance * self.max_distance
+        })
+    }
+
+    fn start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async move {
+            self.update_countdown_ticks = 0;
+            if let Some(owner) = &self.owner {
+                let pos = mob.get_mob_entity().living_entity.entity.pos.load();
+                let mut navigator = mob.get_mob_entity().navigator.lock().await;
+                navigator.set_progress(NavigatorGoal::new(
+                    pos,
+                    owner.living_entity.entity.pos.load(),
+                    self.speed,
+                ));
+            }
+        })
+    }
+
+    fn stop<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async move {
+            self.owner = None;
+            mob.get_mob_entity().navigator.lock().await.stop();
+        })
+    }
+
+    fn tick<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async move {
+            let Some(owner) = self.owner.clone() else {
+                return;
+            };
+            let mob_entity = mob.get_mob_entity();
+            let owner_entity = &owner.living_entity.entity;
+
+            mob_entity
+                .look_control
+                .lock()
+                .await
+                .look_at_position(mob, owner_entity.get_eye_pos());
+
+            self.update_countdown_ticks -= 1;
+            if self.update_countdown_ticks > 0 {
+                return;
+            }
+            self.update_countdown_ticks = to_goal_ticks(10);
+
+            if Self::owner_distance_squared(mob, &owner) >= TELEPORT_DISTANCE_SQUARED {
+                Self::try_teleport_near(mob, &owner).await;
+            } else {
+                let pos = mob_entity.living_entity.entity.pos.load();
+                let mut navigator = mob_entity.navigator.lock().await;
+                navigator.set_progress(NavigatorGoal::new(
+                    pos,
+                    owner_entity.pos.load(),
+                    self.speed,
+                ));
+            }
+        })
+    }
+
+    fn controls(&self) -> Controls {
+        self.goal_control
+    }
+}
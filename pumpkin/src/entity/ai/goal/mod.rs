@@ -3,15 +3,19 @@ use std::{any::TypeId, ops::BitOr, pin::Pin, ptr};
 
 pub mod active_target;
 pub mod ambient_stand;
+pub mod attack_with_owner;
 pub mod creeper_ignite;
+pub mod follow_owner;
 pub mod goal_selector;
 pub mod look_around;
 pub mod look_at_entity;
 pub mod melee_attack;
 pub mod move_to_target_pos;
 pub mod revenge;
+pub mod sit;
 pub mod step_and_destroy_block;
 pub mod swim;
+pub mod track_owner_attacker;
 mod track_target;
 pub mod wander_around;
 pub mod zombie_attack;
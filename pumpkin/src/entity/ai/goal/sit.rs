@@ -0,0 +1,52 @@
+use std::sync::atomic::Ordering;
+
+use super::{Controls, Goal, GoalFuture};
+use crate::entity::mob::Mob;
+
+/// Keeps a tamed mob in place while its owner ordered it to sit.
+pub struct SitGoal {
+    goal_control: Controls,
+}
+
+impl Default for SitGoal {
+    fn default() -> Self {
+        Self {
+            goal_control: Controls::MOVE | Controls::JUMP,
+        }
+    }
+}
+
+impl SitGoal {
+    fn is_ordered_to_sit(mob: &dyn Mob) -> bool {
+        mob.get_mob_tameable()
+            .is_some_and(|tameable| tameable.is_tamed() && tameable.is_sitting())
+    }
+}
+
+impl Goal for SitGoal {
+    fn can_start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async move {
+            let living = &mob.get_mob_entity().living_entity;
+            if living.entity.touching_water.load(Ordering::Relaxed)
+                || !living.entity.on_ground.load(Ordering::Relaxed)
+            {
+                return false;
+            }
+            Self::is_ordered_to_sit(mob)
+        })
+    }
+
+    fn should_continue<'a>(&'a self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async move { Self::is_ordered_to_sit(mob) })
+    }
+
+    fn start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async move {
+            mob.get_mob_entity().navigator.lock().await.stop();
+        })
+    }
+
+    fn controls(&self) -> Controls {
+        self.goal_control
+    }
+}
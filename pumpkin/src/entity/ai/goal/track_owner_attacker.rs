@@ -0,0 +1,101 @@
+use std::sync::Arc;
+use std::sync::atomic::Ordering::Relaxed;
+
+use super::{Controls, Goal};
+use crate::entity::EntityBase;
+use crate::entity::ai::goal::GoalFuture;
+use crate::entity::ai::goal::track_target::TrackTargetGoal;
+use crate::entity::ai::target_predicate::TargetPredicate;
+use crate::entity::mob::Mob;
+
+/// Makes a tamed mob target whatever last attacked its owner.
+pub struct TrackOwnerAttackerGoal {
+    track_target_goal: TrackTargetGoal,
+    attacker: Option<Arc<dyn EntityBase>>,
+    last_attacked_time: i32,
+    target_predicate: TargetPredicate,
+}
+
+impl Default for TrackOwnerAttackerGoal {
+    fn default() -> Self {
+        Self {
+            track_target_goal: TrackTargetGoal::with_default(false),
+            attacker: None,
+            last_attacked_time: 0,
+            target_predicate: TargetPredicate::create_attackable().ignore_visibility(),
+        }
+    }
+}
+
+impl Goal for TrackOwnerAttackerGoal {
+    fn can_start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async move {
+            let mob_entity = mob.get_mob_entity();
+            let Some(tameable) = mob.get_mob_tameable() else {
+                return false;
+            };
+            if tameable.is_sitting() {
+                return false;
+            }
+            let Some(owner) = tameable.get_owner(&mob_entity.living_entity.entity) else {
+                return false;
+            };
+            let owner_living = &owner.living_entity;
+
+            let attacked_time = owner_living.last_attacked_time.load(Relaxed);
+            if attacked_time == self.last_attacked_time {
+                return false;
+            }
+
+            let attacker_id = owner_living.last_attacker_id.load(Relaxed);
+            if attacker_id == 0 {
+                return false;
+            }
+
+            let world = mob_entity.living_entity.entity.world.load();
+            let Some(attacker) = world.get_entity_by_id(attacker_id) else {
+                return false;
+            };
+            if !tameable.can_attack_with_owner(attacker.as_ref()) {
+                return false;
+            }
+
+            let Some(attacker_living) = attacker.get_living_entity() else {
+                return false;
+            };
+            if !self
+                .target_predicate
+                .test(&world, Some(&mob_entity.living_entity), attacker_living)
+            {
+                return false;
+            }
+
+            self.last_attacked_time = attacked_time;
+            self.attacker = Some(attacker);
+            true
+        })
+    }
+
+    fn should_continue<'a>(&'a self, mob: &'a dyn Mob) -> GoalFuture<'a, bool> {
+        Box::pin(async { self.track_target_goal.should_continue(mob).await })
+    }
+
+    fn start<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async {
+            let mob_entity = mob.get_mob_entity();
+            (*mob_entity.target.lock().await).clone_from(&self.attacker);
+            self.track_target_goal.start(mob).await;
+        })
+    }
+
+    fn stop<'a>(&'a mut self, mob: &'a dyn Mob) -> GoalFuture<'a, ()> {
+        Box::pin(async {
+            self.attacker = None;
+            self.track_target_goal.stop(mob).await;
+        })
+    }
+
+    fn controls(&self) -> Controls {
+        self.track_target_goal.controls()
+    }
+}
@@ -158,7 +158,7 @@ impl Navigator {
             for mut neighbor in neighbors_vec {
                 let step_cost = current.distance(&neighbor);
                 neighbor.walked_dist = current.walked_dist + step_cost;
-                let tentative_g = current.g + step_cost + neighbor.cost_malus;
+                let tentative_g = current.g + step_cost * neighbor.floor_cost + neighbor.cost_malus;
 
                 let in_heap = open_set.contains(&neighbor);
                 if neighbor.walked_dist < FOLLOW_RANGE
@@ -25,6 +25,8 @@ pub struct Node {
     pub closed: bool,
     pub walked_dist: f32,
     pub cost_malus: f32,
+    // Multiplies the cost of stepping onto this node, set from the block it stands on
+    pub floor_cost: f32,
     pub path_type: PathType,
 }
 
@@ -40,6 +42,7 @@ impl Default for Node {
             closed: false,
             walked_dist: 0.0,
             cost_malus: 0.0,
+            floor_cost: 1.0,
             path_type: PathType::Blocked,
         }
     }
@@ -66,6 +69,7 @@ impl Node {
             closed: self.closed,
             walked_dist: self.walked_dist,
             cost_malus: self.cost_malus,
+            floor_cost: self.floor_cost,
             path_type: self.path_type,
         }
     }
@@ -1,11 +1,12 @@
 use pumpkin_data::{
-    Block,
+    Block, BlockState,
     fluid::Fluid,
     tag::{self, Taggable},
 };
-use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
 
 use crate::{
+    block::registry::{BlockRegistry, DEFAULT_PATH_COST},
     entity::ai::pathfinder::{
         node::{Coordinate, PathType},
         path_type_cache::PathTypeCache,
@@ -46,14 +47,14 @@ impl PathfindingContext {
         self.mob_position
     }
 
-    pub async fn get_path_type_from_state(&mut self, pos: Vector3<i32>) -> PathType {
+    pub fn get_path_type_from_state(&mut self, pos: Vector3<i32>) -> PathType {
         if let Some(ref cache) = self.path_type_cache
             && let Some(pt) = cache.get(pos)
         {
             return pt;
         }
 
-        let pt = self.compute_path_type_from_state(pos).await;
+        let pt = self.compute_path_type_from_state(pos);
 
         if let Some(ref mut cache) = self.path_type_cache {
             cache.insert(pos, pt);
@@ -62,13 +63,15 @@ impl PathfindingContext {
         pt
     }
 
-    /// Classifies a block position into a `PathType` for pathfinding.
-    pub async fn compute_path_type_from_state(&self, pos: Vector3<i32>) -> PathType {
-        let block_pos = pos.as_blockpos();
-
-        let block = self.world.get_block(&block_pos).await;
-        let state_id = self.world.get_block_state_id(&block_pos).await;
-        let state = self.world.get_block_state(&block_pos).await;
+    /// Classifies a block position into a `PathType` for pathfinding. Positions in unloaded
+    /// chunks are blocked, so paths never lead into them.
+    #[must_use]
+    pub fn compute_path_type_from_state(&self, pos: Vector3<i32>) -> PathType {
+        let Some(state) = self.world.get_block_state_if_loaded(&pos.as_blockpos()) else {
+            return PathType::Blocked;
+        };
+        let state_id = state.id;
+        let block = Block::from_state_id(state_id);
 
         if block.id == Block::AIR.id
             || block.id == Block::VOID_AIR.id
@@ -161,13 +164,11 @@ impl PathfindingContext {
     }
 
     /// Wraps the raw block type with below-check and neighbor danger scanning for OPEN nodes.
-    pub async fn get_land_node_type(&mut self, pos: Vector3<i32>) -> PathType {
-        let raw_type = self.get_path_type_from_state(pos).await;
+    pub fn get_land_node_type(&mut self, pos: Vector3<i32>) -> PathType {
+        let raw_type = self.get_path_type_from_state(pos);
 
         if raw_type == PathType::Open {
-            let below_type = self
-                .get_path_type_from_state(Vector3::new(pos.x, pos.y - 1, pos.z))
-                .await;
+            let below_type = self.get_path_type_from_state(Vector3::new(pos.x, pos.y - 1, pos.z));
             return match below_type {
                 PathType::Open | PathType::Water | PathType::Lava | PathType::Walkable => {
                     PathType::Open
@@ -178,10 +179,7 @@ impl PathfindingContext {
                 PathType::PowderSnow => PathType::DangerPowderSnow,
                 PathType::DamageCautious => PathType::DamageCautious,
                 PathType::Trapdoor => PathType::DangerTrapdoor,
-                _ => {
-                    self.get_node_type_from_neighbors(pos, PathType::Walkable)
-                        .await
-                }
+                _ => self.get_node_type_from_neighbors(pos, PathType::Walkable),
             };
         }
 
@@ -189,7 +187,7 @@ impl PathfindingContext {
     }
 
     /// Scans a 3x3x3 neighborhood for danger blocks and returns the appropriate danger type.
-    pub async fn get_node_type_from_neighbors(
+    pub fn get_node_type_from_neighbors(
         &mut self,
         pos: Vector3<i32>,
         fallback: PathType,
@@ -201,9 +199,11 @@ impl PathfindingContext {
                         continue;
                     }
 
-                    let neighbor_type = self
-                        .get_path_type_from_state(Vector3::new(pos.x + dx, pos.y + dy, pos.z + dz))
-                        .await;
+                    let neighbor_type = self.get_path_type_from_state(Vector3::new(
+                        pos.x + dx,
+                        pos.y + dy,
+                        pos.z + dz,
+                    ));
 
                     if neighbor_type == PathType::DamageOther {
                         return PathType::DangerOther;
@@ -224,19 +224,32 @@ impl PathfindingContext {
         fallback
     }
 
-    pub async fn has_collisions(&mut self, pos: Vector3<i32>) -> bool {
+    /// Whether `pos` is a full cube. Positions in unloaded chunks count as colliding.
+    pub fn has_collisions(&mut self, pos: Vector3<i32>) -> bool {
         if let Some(&cached) = self.collision_cache.get(&pos) {
             return cached;
         }
 
-        let block_pos = pos.as_blockpos();
-        let state = self.world.get_block_state(&block_pos).await;
-        let has_collision = state.is_full_cube();
+        let has_collision = self
+            .world
+            .get_block_state_if_loaded(&pos.as_blockpos())
+            .is_none_or(BlockState::is_full_cube);
 
         self.collision_cache.insert(pos, has_collision);
         has_collision
     }
 
+    /// How costly it is to walk on the block below `pos`. Unloaded positions cost the default.
+    #[must_use]
+    pub fn get_floor_path_cost(&self, pos: Vector3<i32>) -> f32 {
+        let below = BlockPos::new(pos.x, pos.y - 1, pos.z);
+        self.world
+            .get_block_state_if_loaded(&below)
+            .map_or(DEFAULT_PATH_COST, |state| {
+                BlockRegistry::get_path_cost(Block::from_state_id(state.id), state)
+            })
+    }
+
     pub fn clear_caches(&mut self) {
         if let Some(ref mut cache) = self.path_type_cache {
             cache.clear();
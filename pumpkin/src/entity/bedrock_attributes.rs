@@ -0,0 +1,206 @@
+use std::sync::Mutex;
+use std::sync::atomic::Ordering;
+
+use pumpkin_data::attributes::Attributes;
+use pumpkin_protocol::bedrock::client::update_artributes::{Attribute, CUpdateAttributes};
+use pumpkin_protocol::codec::{var_uint::VarUInt, var_ulong::VarULong};
+
+use super::player::Player;
+use crate::net::ClientPlatform;
+
+/// The highest level the Bedrock client accepts.
+const MAX_EXPERIENCE_LEVEL: f32 = 24791.0;
+
+const HEALTH: usize = 0;
+const HUNGER: usize = 1;
+const SATURATION: usize = 2;
+const EXPERIENCE: usize = 3;
+const LEVEL: usize = 4;
+
+const NAMES: [&str; 5] = [
+    "minecraft:health",
+    "minecraft:player.hunger",
+    "minecraft:player.saturation",
+    "minecraft:player.experience",
+    "minecraft:player.level",
+];
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct AttributeValue {
+    min: f32,
+    max: f32,
+    current: f32,
+    default_max: f32,
+    default: f32,
+}
+
+impl AttributeValue {
+    const fn new(max: f32, current: f32, default_max: f32, default: f32) -> Self {
+        Self {
+            min: 0.0,
+            max,
+            current,
+            default_max,
+            default,
+        }
+    }
+
+    fn to_attribute(self, name: &str) -> Attribute {
+        Attribute {
+            min_value: self.min,
+            max_value: self.max,
+            current_value: self.current,
+            default_min_value: 0.0,
+            default_max_value: self.default_max,
+            default_value: self.default,
+            name: name.to_string(),
+            modifiers_list_size: VarUInt(0),
+        }
+    }
+}
+
+/// Bedrock clients show health, hunger and experience through attributes instead of the packets
+/// Java clients get. The values are compared with what was sent last once per tick, so all the
+/// changes made during a tick end up in a single packet.
+#[derive(Default)]
+pub struct BedrockAttributeSync {
+    sent: Mutex<[Option<AttributeValue>; NAMES.len()]>,
+}
+
+impl BedrockAttributeSync {
+    fn current_values(player: &Player) -> [AttributeValue; NAMES.len()] {
+        let health = player.living_entity.health.load();
+        let max_health = player.living_entity.get_max_health().max(health);
+        let default_max_health = Attributes::MAX_HEALTH.default_value as f32;
+        let mut values = [AttributeValue::new(0.0, 0.0, 0.0, 0.0); NAMES.len()];
+        values[HEALTH] = AttributeValue::new(
+            max_health,
+            health.max(0.0),
+            default_max_health,
+            default_max_health,
+        );
+        values[HUNGER] = AttributeValue::new(
+            20.0,
+            f32::from(player.hunger_manager.level.load()),
+            20.0,
+            20.0,
+        );
+        values[SATURATION] =
+            AttributeValue::new(20.0, player.hunger_manager.saturation.load(), 20.0, 5.0);
+        values[EXPERIENCE] = AttributeValue::new(
+            1.0,
+            player.experience_progress.load().clamp(0.0, 1.0),
+            1.0,
+            0.0,
+        );
+        values[LEVEL] = AttributeValue::new(
+            MAX_EXPERIENCE_LEVEL,
+            (player.experience_level.load(Ordering::Relaxed) as f32)
+                .clamp(0.0, MAX_EXPERIENCE_LEVEL),
+            MAX_EXPERIENCE_LEVEL,
+            0.0,
+        );
+        values
+    }
+
+    /// The attributes that changed since they were last returned, marking them as sent.
+    pub fn take_changed(&self, player: &Player) -> Vec<Attribute> {
+        let current = Self::current_values(player);
+        let mut sent = self.sent.lock().unwrap();
+        changed_values(&mut sent, &current)
+            .into_iter()
+            .map(|index| current[index].to_attribute(NAMES[index]))
+            .collect()
+    }
+
+    /// Makes the next sync send every attribute, e.g. after the client respawned.
+    pub fn invalidate(&self) {
+        *self.sent.lock().unwrap() = [None; NAMES.len()];
+    }
+
+    /// Sends the attributes that changed to Bedrock clients. Does nothing for Java clients.
+    pub async fn tick(&self, player: &Player) {
+        let ClientPlatform::Bedrock(client) = &player.client else {
+            return;
+        };
+        let attributes = self.take_changed(player);
+        if attributes.is_empty() {
+            return;
+        }
+        client
+            .send_game_packet(&CUpdateAttributes {
+                runtime_id: VarULong(player.entity_id() as u64),
+                attributes,
+                player_tick: VarULong(0),
+            })
+            .await;
+    }
+}
+
+/// The indices of the values that differ from `sent`, which is updated to match `current`.
+fn changed_values(sent: &mut [Option<AttributeValue>], current: &[AttributeValue]) -> Vec<usize> {
+    let mut changed = Vec::new();
+    for (index, (sent, &current)) in sent.iter_mut().zip(current).enumerate() {
+        if *sent != Some(current) {
+            *sent = Some(current);
+            changed.push(index);
+        }
+    }
+    changed
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_protocol::bedrock::client::update_artributes::CUpdateAttributes;
+    use pumpkin_protocol::packet::Packet;
+    use tempfile::tempdir;
+
+    use super::{AttributeValue, HEALTH, NAMES, changed_values};
+    use crate::entity::player::test::test_bedrock_player;
+    use crate::server::test::test_server;
+    use crate::world::test::test_world_on;
+
+    #[test]
+    fn only_changed_values_are_sent() {
+        let mut sent = [None; 2];
+        let mut current = [
+            AttributeValue::new(20.0, 20.0, 20.0, 20.0),
+            AttributeValue::new(20.0, 5.0, 20.0, 5.0),
+        ];
+        assert_eq!(changed_values(&mut sent, &current), [0, 1]);
+        assert!(changed_values(&mut sent, &current).is_empty());
+
+        current[0].current = 14.0;
+        assert_eq!(changed_values(&mut sent, &current), [0]);
+
+        // A new maximum has to be sent as well, e.g. for extra hearts
+        current[0].max = 24.0;
+        assert_eq!(changed_values(&mut sent, &current), [0]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn max_health_change_is_resent() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, mut connection) = test_bedrock_player(&world, "Alex").await;
+        player.bedrock_attributes.take_changed(&player);
+        connection.received_packet_ids().await;
+
+        player.living_entity.set_max_health(30.0).await;
+        let changed = player.bedrock_attributes.take_changed(&player);
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].name, NAMES[HEALTH]);
+        assert!((changed[0].max_value - 30.0).abs() < f32::EPSILON);
+
+        player.living_entity.set_max_health(24.0).await;
+        player.bedrock_attributes.tick(&player).await;
+        assert!(
+            connection
+                .received_packet_ids()
+                .await
+                .contains(&CUpdateAttributes::PACKET_ID)
+        );
+    }
+}
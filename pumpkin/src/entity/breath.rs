@@ -1,162 +1,248 @@
-use crate::entity::EntityBase;
+use crate::entity::living::LivingEntity;
 use crate::entity::player::Player;
+use crate::entity::{Entity, EntityBase};
+use pumpkin_data::Block;
+use pumpkin_data::Enchantment;
 use pumpkin_data::damage::DamageType;
+use pumpkin_data::data_component_impl::EquipmentSlot;
 use pumpkin_data::effect::StatusEffect;
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::item::Item;
 use pumpkin_data::meta_data_type::MetaDataType;
+use pumpkin_data::potion::Effect;
 use pumpkin_data::tag;
 use pumpkin_data::tag::Taggable;
 use pumpkin_data::tracked_data::TrackedData;
 use pumpkin_protocol::codec::var_int::VarInt;
 use pumpkin_protocol::java::client::play::Metadata;
-use pumpkin_util::GameMode;
 use pumpkin_util::math::position::BlockPos;
+use rand::RngExt;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::atomic::Ordering;
+
+/// Air regained per tick while breathing.
+const AIR_RECOVERY: i32 = 4;
+/// Damage is dealt once the air supply reaches this value, after which it starts over at 0.
+const SUFFOCATION_THRESHOLD: i32 = -20;
+/// How long the turtle helmet lets its wearer breathe after leaving the water.
+const TURTLE_HELMET_BREATHING_TICKS: i32 = 200;
+
+/// Returns the air left after one tick under water. Each level of Respiration gives a
+/// `level / (level + 1)` chance to keep the current air.
+#[must_use]
+pub fn next_air_underwater(air: i32, respiration_level: i32) -> i32 {
+    if respiration_level > 0 && rand::rng().random_range(0..=respiration_level) > 0 {
+        air
+    } else {
+        air - 1
+    }
+}
 
-pub struct BreathManager {
-    pub air_supply: AtomicI32,
-    pub drowning_tick: AtomicI32,
+/// The Respiration level of the helmet worn by `entity`.
+pub async fn respiration_level(entity: &LivingEntity) -> i32 {
+    let equipment = entity.entity_equipment.lock().await;
+    let helmet = equipment.get(&EquipmentSlot::HEAD);
+    let helmet = helmet.lock().await;
+    helmet.get_enchantment_level(&Enchantment::RESPIRATION)
 }
 
-impl Default for BreathManager {
-    fn default() -> Self {
-        Self {
-            air_supply: AtomicI32::new(300),
-            drowning_tick: AtomicI32::new(0),
-        }
+/// The most air an entity of this type can hold.
+#[must_use]
+pub fn max_air_supply(entity_type: &EntityType) -> i32 {
+    if entity_type == &EntityType::DOLPHIN {
+        4800
+    } else if entity_type == &EntityType::AXOLOTL {
+        6000
+    } else {
+        Entity::MAX_BREATH_TICKS
     }
 }
 
-impl BreathManager {
-    pub async fn tick(&self, player: &Arc<Player>) {
-        let mode = player.gamemode.load();
-
-        if matches!(mode, GameMode::Creative | GameMode::Spectator) {
-            let prev = self.air_supply.load(Ordering::Relaxed);
-            let new_air = (prev + 4).min(300);
-            if new_air != prev {
-                self.air_supply.store(new_air, Ordering::Relaxed);
-                self.send_air_supply(player).await;
-            }
-            self.drowning_tick.store(0, Ordering::Relaxed);
-            return;
-        }
+/// Whether the entity runs out of air on land instead of under water.
+#[must_use]
+pub fn dries_out_on_land(entity_type: &EntityType) -> bool {
+    entity_type == &EntityType::DOLPHIN || entity_type == &EntityType::AXOLOTL
+}
 
-        if !player.world().level_info.load().game_rules.drowning_damage {
-            return;
-        }
+/// Whether the entity's eyes are below the surface of the water around it, using the water
+/// height found by the last fluid update.
+#[must_use]
+pub fn is_eye_in_water(entity: &Entity) -> bool {
+    entity.touching_water.load(Ordering::Relaxed)
+        && entity.pos.load().y + entity.water_height.load() > entity.get_eye_y()
+}
 
-        if player
-            .living_entity
-            .has_effect(&StatusEffect::WATER_BREATHING)
-            .await
-        {
-            if self.air_supply.swap(300, Ordering::Relaxed) != 300 {
-                self.send_air_supply(player).await;
-            }
-            self.drowning_tick.store(0, Ordering::Relaxed);
-            return;
-        }
+/// Bubble columns keep refilling the air of entities inside them.
+async fn is_eye_in_bubble_column(entity: &Entity) -> bool {
+    let pos = entity.pos.load();
+    let eye_pos = BlockPos::floored(pos.x, entity.get_eye_y(), pos.z);
+    entity.world.load().get_block(&eye_pos).await == &Block::BUBBLE_COLUMN
+}
 
-        if self.is_eye_in_water(player).await {
-            let prev = self.air_supply.fetch_sub(1, Ordering::Relaxed);
-            let new_air = (prev - 1).max(0);
-            if new_air != prev {
-                self.send_air_supply(player).await;
-            }
+/// The turtle helmet tops Water Breathing up to its duration, but never replaces a longer one,
+/// e.g. from a potion.
+fn should_apply_turtle_breathing(current: Option<&Effect>) -> bool {
+    current.is_none_or(|effect| effect.duration < TURTLE_HELMET_BREATHING_TICKS)
+}
 
-            if new_air <= 0 {
-                let t = self.drowning_tick.fetch_add(1, Ordering::Relaxed) + 1;
+/// Water Breathing and Conduit Power both stop the air supply from going down.
+pub async fn has_water_breathing(entity: &LivingEntity) -> bool {
+    entity.has_effect(&StatusEffect::WATER_BREATHING).await
+        || entity.has_effect(&StatusEffect::CONDUIT_POWER).await
+}
 
-                if t >= 20 {
-                    self.drowning_tick.store(0, Ordering::Relaxed);
-                    player
-                        .living_entity
-                        .damage(player.as_ref(), 2.0, DamageType::DROWN)
-                        .await;
-                }
-            }
+/// Updates the air supply of a living entity for one tick and deals drowning (or drying out)
+/// damage once it has run out. `can_lose_air` is false for entities that can't take damage,
+/// like players in creative mode.
+pub async fn tick_air_supply(living: &LivingEntity, caller: &dyn EntityBase, can_lose_air: bool) {
+    let entity = &living.entity;
+    let max_air = max_air_supply(entity.entity_type);
+    let prev = entity.breath_ticks.load(Ordering::Relaxed);
+
+    let air = if dries_out_on_land(entity.entity_type) {
+        if entity.touching_water.load(Ordering::Relaxed) || !can_lose_air {
+            max_air
+        } else if prev - 1 <= SUFFOCATION_THRESHOLD {
+            caller.damage(caller, 2.0, DamageType::DRY_OUT).await;
+            0
         } else {
-            let prev = self.air_supply.load(Ordering::Relaxed);
-            let new_air = (prev + 4).min(300);
-            if new_air != prev {
-                self.air_supply.store(new_air, Ordering::Relaxed);
-                self.send_air_supply(player).await;
-            }
-            self.drowning_tick.store(0, Ordering::Relaxed);
+            prev - 1
         }
-    }
-
-    async fn is_eye_in_water(&self, player: &Player) -> bool {
-        let e = &player.living_entity.entity;
-        let pos = e.pos.load();
-        let eye_y = e.get_eye_y();
-
-        let bp = BlockPos::new(
-            pos.x.floor() as i32,
-            eye_y.floor() as i32,
-            pos.z.floor() as i32,
-        );
-        let world = player.world();
-
-        let (fluid, state) = world.get_fluid_and_fluid_state(&bp).await;
-
-        let mut in_water_fluid = fluid.has_tag(&tag::Fluid::MINECRAFT_WATER);
-
-        if !in_water_fluid {
-            let state_here = world.get_block_state(&bp).await;
-            if !state_here.is_solid() {
-                let above = BlockPos::new(bp.0.x, bp.0.y + 1, bp.0.z);
-                let (fluid_above_x, _) = world.get_fluid_and_fluid_state(&above).await;
-                if fluid_above_x.has_tag(&tag::Fluid::MINECRAFT_WATER) {
-                    in_water_fluid = true;
+    } else if is_eye_in_water(entity) && !is_eye_in_bubble_column(entity).await {
+        if !can_lose_air
+            || entity.is_tagged(&tag::EntityType::MINECRAFT_CAN_BREATHE_UNDER_WATER)
+            || has_water_breathing(living).await
+        {
+            prev
+        } else {
+            let air = next_air_underwater(prev, respiration_level(living).await);
+            if air <= SUFFOCATION_THRESHOLD {
+                let drowning_damage = entity
+                    .world
+                    .load()
+                    .level_info
+                    .load()
+                    .game_rules
+                    .drowning_damage;
+                if drowning_damage {
+                    caller.damage(caller, 2.0, DamageType::DROWN).await;
                 }
+                0
+            } else {
+                air
             }
         }
+    } else if prev < max_air {
+        (prev + AIR_RECOVERY).min(max_air)
+    } else {
+        prev
+    };
+
+    if air != prev {
+        entity.breath_ticks.store(air, Ordering::Relaxed);
+        send_air_supply(entity).await;
+    }
+}
 
-        if !in_water_fluid {
-            return false;
+/// Syncs the air supply so the client can render the bubble bar.
+pub async fn send_air_supply(entity: &Entity) {
+    let air = entity.breath_ticks.load(Ordering::Relaxed).max(0);
+    entity
+        .send_meta_data(&[Metadata::new(
+            TrackedData::DATA_AIR,
+            MetaDataType::Integer,
+            VarInt(air),
+        )])
+        .await;
+}
+
+/// The player specific parts of breathing. The air supply itself is the entity's
+/// `breath_ticks`, which is ticked by the living entity and saved as `Air`.
+#[derive(Default)]
+pub struct BreathManager;
+
+impl BreathManager {
+    /// Ticks the player's air supply and gives a player wearing a turtle helmet a short Water
+    /// Breathing effect after surfacing.
+    pub async fn tick(&self, player: &Arc<Player>) {
+        let living = &player.living_entity;
+        if living.dead.load(Ordering::Relaxed) {
+            return;
         }
+        let invulnerable = player.abilities.lock().await.invulnerable;
+        tick_air_supply(living, player.as_ref(), !invulnerable).await;
 
-        let above = BlockPos::new(bp.0.x, bp.0.y + 1, bp.0.z);
-        let (fluid_above, _) = world.get_fluid_and_fluid_state(&above).await;
+        if is_eye_in_water(&living.entity) {
+            return;
+        }
 
-        let surface_y = if fluid_above.has_tag(&tag::Fluid::MINECRAFT_WATER) {
-            f64::from(bp.0.y as f32 + 1.0)
-        } else {
-            let height: f32 = if state.is_still {
-                1.0
-            } else {
-                let lvl = i32::from(state.level);
-                if lvl >= 8 {
-                    1.0
-                } else {
-                    ((8 - lvl).clamp(1, 8) as f32) / 8.0
-                }
-            };
-            f64::from(bp.0.y as f32 + height)
+        let wears_turtle_helmet = {
+            let equipment = living.entity_equipment.lock().await;
+            let helmet = equipment.get(&EquipmentSlot::HEAD);
+            helmet.lock().await.item == &Item::TURTLE_HELMET
         };
+        if wears_turtle_helmet
+            && should_apply_turtle_breathing(
+                living
+                    .get_effect(&StatusEffect::WATER_BREATHING)
+                    .await
+                    .as_ref(),
+            )
+        {
+            player
+                .add_effect(Effect {
+                    effect_type: &StatusEffect::WATER_BREATHING,
+                    duration: TURTLE_HELMET_BREATHING_TICKS,
+                    amplifier: 0,
+                    ambient: false,
+                    show_particles: false,
+                    show_icon: true,
+                    blend: false,
+                })
+                .await;
+        }
+    }
 
-        surface_y > eye_y
+    pub async fn reset(&self, player: &Player) {
+        let entity = &player.living_entity.entity;
+        entity
+            .breath_ticks
+            .store(Entity::MAX_BREATH_TICKS, Ordering::Relaxed);
+        send_air_supply(entity).await;
     }
+}
 
-    pub async fn send_air_supply(&self, player: &Player) {
-        let air = self.air_supply.load(Ordering::Relaxed).clamp(0, 300);
-
-        player
-            .living_entity
-            .entity
-            .send_meta_data(&[Metadata::new(
-                TrackedData::DATA_AIR,
-                MetaDataType::Integer,
-                VarInt(air),
-            )])
-            .await;
+#[cfg(test)]
+mod test {
+    use pumpkin_data::effect::StatusEffect;
+    use pumpkin_data::potion::Effect;
+
+    use super::{TURTLE_HELMET_BREATHING_TICKS, should_apply_turtle_breathing};
+
+    fn water_breathing(duration: i32) -> Effect {
+        Effect {
+            effect_type: &StatusEffect::WATER_BREATHING,
+            duration,
+            amplifier: 0,
+            ambient: false,
+            show_particles: true,
+            show_icon: true,
+            blend: false,
+        }
     }
 
-    pub async fn reset(&self, player: &Player) {
-        self.air_supply.store(300, Ordering::Relaxed);
-        self.send_air_supply(player).await;
-        self.drowning_tick.store(0, Ordering::Relaxed);
+    #[test]
+    fn turtle_helmet_keeps_longer_water_breathing() {
+        assert!(should_apply_turtle_breathing(None));
+        assert!(should_apply_turtle_breathing(Some(&water_breathing(
+            TURTLE_HELMET_BREATHING_TICKS - 1
+        ))));
+        // A potion lasting 3 minutes isn't cut down to 10 seconds
+        assert!(!should_apply_turtle_breathing(Some(&water_breathing(
+            3 * 60 * 20
+        ))));
+        assert!(!should_apply_turtle_breathing(Some(&water_breathing(
+            TURTLE_HELMET_BREATHING_TICKS
+        ))));
     }
 }
@@ -1,22 +1,37 @@
-use core::f32;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::entity::{Entity, EntityBase, EntityBaseFuture, NBTStorage, living::LivingEntity};
-use pumpkin_data::{damage::DamageType, meta_data_type::MetaDataType, tracked_data::TrackedData};
+use crate::entity::{
+    Entity, EntityBase, EntityBaseFuture, NBTStorage, NbtFuture, living::LivingEntity,
+};
+use crossbeam::atomic::AtomicCell;
+use pumpkin_data::{
+    damage::DamageType, entity::EntityType, meta_data_type::MetaDataType, tracked_data::TrackedData,
+};
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
 use pumpkin_protocol::java::client::play::Metadata;
-use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+
+const EXPLOSION_POWER: f32 = 6.0;
 
 pub struct EndCrystalEntity {
     entity: Entity,
+    /// Whether the bedrock base is rendered below the crystal
+    show_bottom: AtomicBool,
+    /// The block the crystal's healing beam points at
+    beam_target: AtomicCell<Option<BlockPos>>,
 }
 
 impl EndCrystalEntity {
     pub const fn new(entity: Entity) -> Self {
-        Self { entity }
+        Self {
+            entity,
+            show_bottom: AtomicBool::new(true),
+            beam_target: AtomicCell::new(None),
+        }
     }
-}
 
-impl EndCrystalEntity {
     pub async fn set_show_bottom(&self, show_bottom: bool) {
+        self.show_bottom.store(show_bottom, Ordering::Relaxed);
         self.entity
             .send_meta_data(&[Metadata::new(
                 TrackedData::DATA_SHOW_BOTTOM,
@@ -25,9 +40,46 @@ impl EndCrystalEntity {
             )])
             .await;
     }
+
+    /// Points the healing beam at `target`. Used for the crystals on top of the obsidian pillars.
+    pub async fn set_beam_target(&self, target: Option<BlockPos>) {
+        self.beam_target.store(target);
+        self.entity
+            .send_meta_data(&[Metadata::new(
+                TrackedData::DATA_BEAM_TARGET,
+                MetaDataType::OptionalBlockPos,
+                target,
+            )])
+            .await;
+    }
 }
 
-impl NBTStorage for EndCrystalEntity {}
+impl NBTStorage for EndCrystalEntity {
+    fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.entity.write_nbt(nbt).await;
+            nbt.put_bool("ShowBottom", self.show_bottom.load(Ordering::Relaxed));
+            if let Some(target) = self.beam_target.load() {
+                nbt.put(
+                    "BeamTarget",
+                    NbtTag::IntArray(vec![target.0.x, target.0.y, target.0.z]),
+                );
+            }
+        })
+    }
+
+    fn read_nbt_non_mut<'a>(&'a self, nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.entity.read_nbt_non_mut(nbt).await;
+            if let Some(show_bottom) = nbt.get_bool("ShowBottom") {
+                self.show_bottom.store(show_bottom, Ordering::Relaxed);
+            }
+            if let Some([x, y, z]) = nbt.get_int_array("BeamTarget") {
+                self.beam_target.store(Some(BlockPos::new(*x, *y, *z)));
+            }
+        })
+    }
+}
 
 impl EntityBase for EndCrystalEntity {
     fn get_entity(&self) -> &Entity {
@@ -38,26 +90,56 @@ impl EntityBase for EndCrystalEntity {
         None
     }
 
+    fn init_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async {
+            self.entity
+                .send_meta_data(&[
+                    Metadata::new(
+                        TrackedData::DATA_SHOW_BOTTOM,
+                        MetaDataType::Boolean,
+                        self.show_bottom.load(Ordering::Relaxed),
+                    ),
+                    Metadata::new(
+                        TrackedData::DATA_BEAM_TARGET,
+                        MetaDataType::OptionalBlockPos,
+                        self.beam_target.load(),
+                    ),
+                ])
+                .await;
+        })
+    }
+
+    /// End crystals have no health, any damage destroys them with an explosion.
+    /// Explosions destroy nearby crystals the same way, so they go off in chains.
     fn damage_with_context<'a>(
         &'a self,
         _caller: &'a dyn EntityBase,
         _amount: f32,
-        damage_type: DamageType,
+        _damage_type: DamageType,
         _position: Option<Vector3<f64>>,
         _source: Option<&'a dyn EntityBase>,
-        _cause: Option<&'a dyn EntityBase>,
+        cause: Option<&'a dyn EntityBase>,
     ) -> EntityBaseFuture<'a, bool> {
         Box::pin(async move {
-            if damage_type != DamageType::EXPLOSION {
-                self.entity
-                    .world
-                    .load()
-                    .explode(self.entity.pos.load(), 6.0)
-                    .await;
+            if cause
+                .is_some_and(|cause| cause.get_entity().entity_type == &EntityType::ENDER_DRAGON)
+            {
+                return false;
+            }
+            // Already destroyed, e.g. by the explosion of this crystal's own chain
+            if self.entity.removed.swap(true, Ordering::SeqCst) {
+                return false;
             }
 
-            // TODO
+            let world = self.entity.world.load_full();
+            let source = world.get_entity_by_id(self.entity.entity_id);
+            let igniter =
+                cause.and_then(|cause| world.get_player_by_id(cause.get_entity().entity_id));
             self.entity.remove().await;
+            world
+                .explode_attributed(self.entity.pos.load(), EXPLOSION_POWER, source, igniter)
+                .await;
+            // TODO: Notify the dragon fight when this was one of the pillar crystals
             true
         })
     }
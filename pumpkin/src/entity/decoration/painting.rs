@@ -4,6 +4,7 @@ use std::sync::atomic::Ordering;
 use crate::entity::{
     Entity, EntityBase, EntityBaseFuture, NBTStorage, NbtFuture, living::LivingEntity,
 };
+use pumpkin_data::BlockDirection;
 use pumpkin_data::damage::DamageType;
 use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_util::math::vector3::Vector3;
@@ -28,7 +29,7 @@ impl NBTStorage for PaintingEntity {
     fn read_nbt_non_mut<'a>(&'a self, _nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
         Box::pin(async {
             // TODO
-            self.entity.data.store(3, Ordering::Relaxed);
+            self.entity.set_spawn_data_direction(BlockDirection::South);
         })
     }
 }
@@ -1,10 +1,11 @@
 use pumpkin_data::damage::DamageType;
 use pumpkin_data::entity::EntityType;
+use pumpkin_data::item::Item;
 use pumpkin_data::meta_data_type::MetaDataType;
 use pumpkin_data::{Block, tracked_data::TrackedData};
 use pumpkin_protocol::java::client::play::Metadata;
-use pumpkin_util::math::position::BlockPos;
-use pumpkin_world::{BlockStateId, world::BlockFlags};
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
+use pumpkin_world::{BlockStateId, item::ItemStack, world::BlockFlags};
 use std::sync::{Arc, atomic::Ordering};
 
 use crate::{
@@ -19,6 +20,9 @@ pub struct FallingEntity {
 }
 
 impl FallingEntity {
+    /// The downward velocity a falling block starts with
+    const INITIAL_FALL_SPEED: f64 = 0.04;
+
     pub const fn new(entity: Entity, block_state_id: BlockStateId) -> Self {
         Self {
             entity,
@@ -39,10 +43,37 @@ impl FallingEntity {
 
         let position = position.0.to_f64().add_raw(0.5, 0.0, 0.5);
         let entity = Entity::new(world.clone(), position, &EntityType::FALLING_BLOCK);
-        entity.data.store(i32::from(block_state), Ordering::Relaxed);
+        entity.set_spawn_data_block_state(block_state);
+        entity
+            .velocity
+            .store(Vector3::new(0.0, -Self::INITIAL_FALL_SPEED, 0.0));
         let entity = Arc::new(Self::new(entity, block_state));
         world.spawn_entity(entity).await;
     }
+
+    /// Places the falling block where it landed. Blocks in the way which break instantly are
+    /// destroyed and dropped, otherwise the falling block drops as an item.
+    #[expect(clippy::float_cmp)]
+    async fn land(&self, world: &Arc<World>, position: &BlockPos) {
+        let state = world.get_block_state(position).await;
+        if !state.is_air() && !state.is_liquid() {
+            if state.replaceable() || (!state.is_solid() && state.hardness == 0.0) {
+                world
+                    .break_block(position, None, BlockFlags::NOTIFY_ALL)
+                    .await;
+            } else {
+                let falling_block = Block::from_state_id(self.block_state_id);
+                if let Some(item) = Item::from_id(falling_block.item_id) {
+                    world.drop_stack(position, ItemStack::new(1, item)).await;
+                }
+                return;
+            }
+        }
+
+        world
+            .set_block_state(position, self.block_state_id, BlockFlags::NOTIFY_ALL)
+            .await;
+    }
 }
 
 impl NBTStorage for FallingEntity {}
@@ -67,16 +98,10 @@ impl EntityBase for FallingEntity {
             entity.tick_block_collisions(&caller, server).await;
             if entity.on_ground.load(Ordering::Relaxed) {
                 entity.velocity.store(velo.multiply(0.7, -0.5, 0.7));
-                entity
-                    .world
-                    .load()
-                    .set_block_state(
-                        &self.entity.block_pos.load(),
-                        self.block_state_id,
-                        BlockFlags::NOTIFY_ALL,
-                    )
-                    .await;
+                let world = entity.world.load_full();
+                self.land(&world, &entity.block_pos.load()).await;
                 entity.remove().await;
+                return;
             }
 
             entity.velocity.store(velo.multiply(0.98, 0.98, 0.98));
@@ -31,12 +31,19 @@ impl Default for HungerManager {
 
 impl HungerManager {
     pub async fn tick(&self, player: &Arc<Player>) {
-        let mut level = self.level.load();
+        let old_level = self.level.load();
+        let mut level = old_level;
         let mut saturation = self.saturation.load();
         let mut exhaustion = self.exhaustion.load();
         let mut timer = self.tick_timer.load();
 
-        let difficulty = player.world().level_info.load().difficulty;
+        let (difficulty, natural_regen) = {
+            let level_info = player.world().level_info.load();
+            (
+                level_info.difficulty,
+                level_info.game_rules.natural_health_regeneration,
+            )
+        };
         let health = player.living_entity.health.load();
         let can_heal = player.can_food_heal();
 
@@ -54,8 +61,6 @@ impl HungerManager {
             needs_sync = true;
         }
 
-        let natural_regen = true; // TODO: GameRule check
-
         if natural_regen && saturation > 0.0 && can_heal && level >= 20 {
             timer += 1;
             if timer >= 10 {
@@ -101,6 +106,9 @@ impl HungerManager {
             self.tick_timer.store(timer);
         }
 
+        if level != old_level {
+            player.on_food_level_change(old_level, level).await;
+        }
         if needs_sync {
             player.send_health().await;
         }
@@ -127,6 +135,9 @@ impl HungerManager {
         self.level.store(new_level);
         self.saturation.store(new_sat);
 
+        if new_level != current_level {
+            player.on_food_level_change(current_level, new_level).await;
+        }
         player.send_health().await;
     }
 
@@ -169,3 +180,123 @@ impl NBTStorage for HungerManager {
 }
 
 impl NBTStorageInit for HungerManager {}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::Ordering::Relaxed;
+
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::{vector2::Vector2, vector3::Vector3};
+    use tempfile::tempdir;
+
+    use crate::entity::player::test::test_player;
+    use crate::entity::{Entity, EntityBase, living::LivingEntity};
+    use crate::server::test::test_server;
+    use crate::world::test::{load_empty_chunk, test_world_on};
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn attacking_exhausts() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        load_empty_chunk(&world, Vector2::new(0, 0));
+        let (player, _connection) = test_player(&world, "Steve").await;
+        player
+            .living_entity
+            .entity
+            .set_pos(Vector3::new(0.5, 64.0, 0.5));
+        let zombie: Arc<dyn EntityBase> = Arc::new(LivingEntity::new(Entity::new(
+            world.clone(),
+            Vector3::new(1.5, 64.0, 0.5),
+            &EntityType::ZOMBIE,
+        )));
+        world.spawn_entity(zombie.clone()).await;
+
+        player.attack(zombie).await;
+        assert_close(player.hunger_manager.exhaustion.load(), 0.1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn swimming_exhausts_in_every_direction() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, _connection) = test_player(&world, "Steve").await;
+        let entity = &player.living_entity.entity;
+        let one_block_down = Vector3::new(0.0, -1.0, 0.0);
+
+        // Falling on land doesn't make players hungry
+        entity.on_ground.store(true, Relaxed);
+        player.progress_motion(one_block_down).await;
+        assert_close(player.hunger_manager.exhaustion.load(), 0.0);
+
+        entity.touching_water.store(true, Relaxed);
+        player.progress_motion(one_block_down).await;
+        assert_close(player.hunger_manager.exhaustion.load(), 0.01);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn natural_regeneration_follows_the_game_rule() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, _connection) = test_player(&world, "Steve").await;
+        player.living_entity.health.store(10.0);
+        world.level_info.rcu(|level_info| {
+            let mut level_info = (**level_info).clone();
+            level_info.game_rules.natural_health_regeneration = false;
+            level_info
+        });
+
+        for _ in 0..10 {
+            player.hunger_manager.tick(&player).await;
+        }
+        assert_close(player.living_entity.health.load(), 10.0);
+        assert_close(player.hunger_manager.saturation.load(), 5.0);
+
+        world.level_info.rcu(|level_info| {
+            let mut level_info = (**level_info).clone();
+            level_info.game_rules.natural_health_regeneration = true;
+            level_info
+        });
+        for _ in 0..10 {
+            player.hunger_manager.tick(&player).await;
+        }
+        // The saturation is spent on healing
+        assert_close(player.living_entity.health.load(), 10.0 + 5.0 / 6.0);
+        assert_close(player.hunger_manager.saturation.load(), 0.0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn getting_hungry_stops_sprinting() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, _connection) = test_player(&world, "Steve").await;
+        let hunger = &player.hunger_manager;
+        let entity = &player.living_entity.entity;
+        entity.set_sprinting(true).await;
+        hunger.saturation.store(0.0);
+
+        hunger.level.store(8);
+        hunger.exhaustion.store(4.5);
+        hunger.tick(&player).await;
+        assert_eq!(hunger.level.load(), 7);
+        assert!(entity.sprinting.load(Relaxed));
+
+        // Sprinting stops once the food level drops to 6
+        hunger.exhaustion.store(4.5);
+        hunger.tick(&player).await;
+        assert_eq!(hunger.level.load(), 6);
+        assert!(!entity.sprinting.load(Relaxed));
+    }
+}
@@ -5,8 +5,8 @@ use pumpkin_data::tracked_data::TrackedData;
 use pumpkin_inventory::build_equipment_slots;
 use pumpkin_inventory::player::player_inventory::PlayerInventory;
 use pumpkin_inventory::screen_handler::InventoryPlayer;
-use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::{GameMode, Hand};
 use std::mem;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
@@ -17,19 +17,26 @@ use std::sync::atomic::{
 use std::{collections::HashMap, sync::atomic::AtomicI32};
 use tracing::warn;
 
-use super::{Entity, NBTStorage};
-use super::{EntityBase, NBTStorageInit};
+use super::{Entity, Flag, NBTStorage, VOID_DAMAGE, breath};
+use super::{EntityBase, NBTStorageInit, push_apart};
 use crate::block::OnLandedUponArgs;
 use crate::entity::{EntityBaseFuture, NbtFuture};
+use crate::plugin::entity::entity_death::EntityDeathEvent;
 use crate::server::Server;
+use crate::world::World;
 use crate::world::loot::{LootContextParameters, LootTableExt};
 use crossbeam::atomic::AtomicCell;
+use pumpkin_data::attributes::Attributes;
 use pumpkin_data::damage::DeathMessageType;
-use pumpkin_data::data_component_impl::{DeathProtectionImpl, EquipmentSlot, FoodImpl};
+use pumpkin_data::data_component_impl::{
+    AttributeModifiersImpl, BlocksAttacksImpl, DeathProtectionImpl, EquipmentSlot, FoodImpl,
+    Operation,
+};
 use pumpkin_data::effect::StatusEffect;
 use pumpkin_data::entity::{EntityPose, EntityStatus, EntityType};
+use pumpkin_data::item::Item;
 use pumpkin_data::sound::SoundCategory;
-use pumpkin_data::{Block, translation};
+use pumpkin_data::{Block, Enchantment, translation};
 use pumpkin_data::{damage::DamageType, sound::Sound};
 use pumpkin_inventory::entity_equipment::EntityEquipment;
 use pumpkin_nbt::compound::NbtCompound;
@@ -47,6 +54,20 @@ use pumpkin_util::text::TextComponent;
 use pumpkin_world::item::ItemStack;
 use tokio::sync::Mutex;
 
+/// Shields only block once they have been raised for this many ticks.
+const SHIELD_BLOCK_DELAY_TICKS: i32 = 5;
+/// Blocked hits at least this strong damage the shield.
+const SHIELD_DAMAGE_THRESHOLD: f32 = 3.0;
+/// How many extra frozen ticks snowfall thaws every tick.
+const SNOW_THAW_TICKS: i32 = 2;
+/// The effects a totem of undying gives after clearing all others: the effect, its duration in
+/// ticks and its amplifier.
+const TOTEM_EFFECTS: [(&StatusEffect, i32, u8); 3] = [
+    (&StatusEffect::REGENERATION, 900, 1),
+    (&StatusEffect::ABSORPTION, 100, 1),
+    (&StatusEffect::FIRE_RESISTANCE, 800, 0),
+];
+
 /// Represents a living entity within the game world.
 ///
 /// This struct encapsulates the core properties and behaviors of living entities, including players, mobs, and other creatures.
@@ -59,6 +80,8 @@ pub struct LivingEntity {
     pub last_damage_taken: AtomicCell<f32>,
     /// The current health level of the entity.
     pub health: AtomicCell<f32>,
+    /// The value of the max health attribute.
+    max_health: AtomicCell<f32>,
     pub item_use_time: AtomicI32,
     pub item_in_use: Mutex<Option<ItemStack>>,
     pub death_time: AtomicU8,
@@ -86,6 +109,12 @@ pub struct LivingEntity {
     pub last_attacker_id: AtomicI32,
     /// The tick at which this entity was last attacked (entity age).
     pub last_attacked_time: AtomicI32,
+    /// The entity ID of the entity that this living entity last attacked.
+    pub last_attacking_id: AtomicI32,
+    /// The tick at which this entity last attacked another entity (entity age).
+    pub last_attacking_time: AtomicI32,
+    /// How many ticks the entity has been gliding with an elytra for
+    gliding_ticks: AtomicI32,
 
     water_movement_speed_multiplier: f32,
     livings_flags: AtomicU8,
@@ -115,12 +144,16 @@ impl LivingEntity {
         };
         // TODO: Extract default MOVEMENT_SPEED Entity Attribute
         let default_movement_speed = 0.25;
-        let health = entity.entity_type.max_health.unwrap_or(20.0);
+        let health = entity
+            .entity_type
+            .max_health
+            .unwrap_or(Attributes::MAX_HEALTH.default_value as f32);
         Self {
             entity,
             hurt_cooldown: AtomicI32::new(0),
             last_damage_taken: AtomicCell::new(0.0),
             health: AtomicCell::new(health),
+            max_health: AtomicCell::new(health),
             fall_distance: AtomicCell::new(0.0),
             death_time: AtomicU8::new(0),
             dead: AtomicBool::new(false),
@@ -136,6 +169,9 @@ impl LivingEntity {
             climbing_pos: AtomicCell::new(None),
             last_attacker_id: AtomicI32::new(0),
             last_attacked_time: AtomicI32::new(0),
+            last_attacking_id: AtomicI32::new(0),
+            last_attacking_time: AtomicI32::new(0),
+            gliding_ticks: AtomicI32::new(0),
             movement_input: AtomicCell::new(Vector3::default()),
             movement_speed: AtomicCell::new(default_movement_speed),
             water_movement_speed_multiplier,
@@ -182,10 +218,82 @@ impl LivingEntity {
             .store(stack.get_max_use_time(), Ordering::Relaxed);
         *self.item_in_use.lock().await = Some(stack);
         self.set_living_flag(Self::USING_ITEM_FLAG, true).await;
-        self.set_living_flag(Self::OFF_HAND_ACTIVE_FLAG, hand == Hand::Left)
+        self.set_living_flag(Self::OFF_HAND_ACTIVE_FLAG, hand == Hand::Off)
             .await;
     }
 
+    /// Whether the entity is holding up an item that blocks attacks, like a shield. Shields only
+    /// start blocking a few ticks after being raised.
+    pub async fn is_blocking(&self) -> bool {
+        let used_ticks = |stack: &ItemStack| {
+            stack.get_max_use_time() - self.item_use_time.load(Ordering::Relaxed)
+        };
+        self.item_in_use.lock().await.as_ref().is_some_and(|stack| {
+            stack.get_data_component::<BlocksAttacksImpl>().is_some()
+                && used_ticks(stack) >= SHIELD_BLOCK_DELAY_TICKS
+        })
+    }
+
+    /// Blocks an attack coming from `source_pos` if the entity is blocking and facing it. The
+    /// shield takes durability damage for strong hits and breaks once it runs out.
+    async fn try_block_attack(
+        &self,
+        caller: &dyn EntityBase,
+        amount: f32,
+        damage_type: DamageType,
+        source_pos: Option<Vector3<f64>>,
+    ) -> bool {
+        let Some(source_pos) = source_pos else {
+            return false;
+        };
+        if amount <= 0.0 || bypasses_shield(damage_type) || !self.is_blocking().await {
+            return false;
+        }
+
+        let pos = self.entity.pos.load();
+        let look = Vector3::rotation_vector(0.0, f64::from(self.entity.yaw.load()));
+        if look.x * (source_pos.x - pos.x) + look.z * (source_pos.z - pos.z) <= 0.0 {
+            return false;
+        }
+
+        let world = self.entity.world.load();
+        world
+            .play_sound(Sound::ItemShieldBlock, SoundCategory::Players, &pos)
+            .await;
+
+        if amount >= SHIELD_DAMAGE_THRESHOLD {
+            let hand = self.active_hand();
+            let stack = self.get_stack_in_hand(caller, hand).await;
+            let mut stack = stack.lock().await;
+            stack.damage_item_with_context(1 + amount.floor() as i32, false);
+            let updated_stack = stack.clone();
+            drop(stack);
+
+            if let Some(player) = caller.get_player() {
+                player
+                    .sync_hand_slot(player.inventory.hand_slot(hand), updated_stack.clone())
+                    .await;
+            }
+            if updated_stack.is_empty() {
+                self.clear_active_hand().await;
+                world
+                    .play_sound(Sound::ItemShieldBreak, SoundCategory::Players, &pos)
+                    .await;
+            }
+        }
+        true
+    }
+
+    /// The hand holding the item that is being used.
+    #[must_use]
+    pub fn active_hand(&self) -> Hand {
+        if self.livings_flags.load(Ordering::Relaxed) & Self::OFF_HAND_ACTIVE_FLAG == 0 {
+            Hand::Main
+        } else {
+            Hand::Off
+        }
+    }
+
     async fn set_living_flag(&self, flag: u8, value: bool) {
         let index = flag;
         let mut b = self.livings_flags.load(Ordering::Relaxed);
@@ -233,6 +341,22 @@ impl LivingEntity {
         self.entity.entity_id
     }
 
+    /// The health the entity has when fully healed.
+    #[must_use]
+    pub fn get_max_health(&self) -> f32 {
+        self.max_health.load()
+    }
+
+    /// Changes the max health attribute. Health above the new maximum is lost.
+    pub async fn set_max_health(&self, max_health: f32) {
+        let max_health = max_health.max(1.0);
+        self.max_health.store(max_health);
+        let health = self.health.load();
+        if health > max_health {
+            self.set_health(max_health).await;
+        }
+    }
+
     pub async fn add_effect(&self, effect: Effect) {
         self.active_effects
             .lock()
@@ -354,8 +478,7 @@ impl LivingEntity {
 
     pub fn is_immune_to_fall_damage(&self) -> bool {
         self.entity
-            .entity_type
-            .has_tag(&tag::EntityType::MINECRAFT_FALL_DAMAGE_IMMUNE)
+            .is_tagged(&tag::EntityType::MINECRAFT_FALL_DAMAGE_IMMUNE)
     }
 
     async fn get_effective_gravity(&self, caller: &Arc<dyn EntityBase>) -> f64 {
@@ -445,6 +568,8 @@ impl LivingEntity {
             self.fall_distance.store(0.0);
         }
 
+        self.tick_gliding(caller.as_ref()).await;
+
         let touching_water = self.entity.touching_water.load(SeqCst);
 
         // Strider is the only entity that has canWalkOnFluid = false
@@ -454,9 +579,10 @@ impl LivingEntity {
             && self.entity.entity_type != &EntityType::STRIDER
         {
             self.travel_in_fluid(caller.clone(), touching_water).await;
+        } else if self.entity.fall_flying.load(Relaxed) && caller.get_player().is_none() {
+            // Players glide on their own, see `Player::handle_glide_movement`
+            self.travel_gliding(caller.clone()).await;
         } else {
-            // TODO: Gliding
-
             self.travel_in_air(caller.clone()).await;
         }
 
@@ -467,6 +593,154 @@ impl LivingEntity {
         if suffocating {
             self.damage(&*caller, 1.0, DamageType::IN_WALL).await;
         }
+
+        self.tick_cramming(caller.as_ref()).await;
+    }
+
+    /// Pushes away the entities overlapping with this one. Vanilla: `LivingEntity.tickCramming()`
+    async fn tick_cramming(&self, caller: &dyn EntityBase) {
+        if !caller.pushes_entities() {
+            return;
+        }
+        let bounding_box = self.entity.bounding_box.load();
+        let others = self.entity.world.load().get_all_at_box(&bounding_box);
+        for other in others {
+            push_apart(caller, other.as_ref()).await;
+        }
+    }
+
+    /// Vanilla: `LivingEntity.travelGliding()`
+    async fn travel_gliding(&self, caller: Arc<dyn EntityBase>) {
+        let velocity = self.entity.velocity.load();
+        if velocity.y > -0.5 {
+            self.fall_distance.store(1.0);
+        }
+
+        let gravity = self.get_effective_gravity(&caller).await;
+        let pitch = self.entity.pitch.load();
+        let look = Vector3::rotation_vector(f64::from(pitch), f64::from(self.entity.yaw.load()));
+        self.entity
+            .velocity
+            .store(glide_velocity(velocity, look, pitch, gravity));
+
+        self.make_move(caller.clone()).await;
+
+        let speed_after = self.entity.velocity.load().horizontal_length();
+        self.check_glide_collision(caller.as_ref(), velocity.horizontal_length(), speed_after)
+            .await;
+    }
+
+    /// Vanilla: `LivingEntity.checkGlidingCollision()`, flying into a wall hurts depending on
+    /// how much horizontal speed was lost.
+    pub async fn check_glide_collision(
+        &self,
+        caller: &dyn EntityBase,
+        speed_before: f64,
+        speed_after: f64,
+    ) {
+        let damage = ((speed_before - speed_after) * 10.0 - 3.0) as f32;
+        if damage > 0.0 {
+            self.entity
+                .play_sound(Self::get_fall_sound(damage as i32))
+                .await;
+            self.damage(caller, damage, DamageType::FLY_INTO_WALL).await;
+        }
+    }
+
+    fn chest_slot(&self) -> Option<(usize, &EquipmentSlot)> {
+        self.equipment_slots
+            .iter()
+            .find(|(_, slot)| matches!(slot, EquipmentSlot::Chest(_)))
+            .map(|(index, slot)| (*index, slot))
+    }
+
+    /// Whether the stack lets its wearer glide.
+    /// Vanilla checks the `glider` component, which only the elytra has by default.
+    fn can_glide_with(stack: &ItemStack) -> bool {
+        stack.item.id == Item::ELYTRA.id && !stack.will_break_next_use()
+    }
+
+    /// Vanilla: `LivingEntity.canGlide()`
+    pub async fn can_glide(&self) -> bool {
+        if self.entity.on_ground.load(Relaxed)
+            || self.entity.has_vehicle().await
+            || self.has_effect(&StatusEffect::LEVITATION).await
+        {
+            return false;
+        }
+        let Some((_, slot)) = self.chest_slot() else {
+            return false;
+        };
+        let stack = self.entity_equipment.lock().await.get(slot);
+        Self::can_glide_with(&stack.lock().await)
+    }
+
+    /// Vanilla: `PlayerEntity.checkGliding()`, returns whether the entity started gliding.
+    pub async fn try_start_gliding(&self) -> bool {
+        if self.entity.fall_flying.load(Relaxed)
+            || self.entity.touching_water.load(SeqCst)
+            || !self.can_glide().await
+        {
+            return false;
+        }
+        self.entity.set_fall_flying(true).await;
+        true
+    }
+
+    /// Also tells clients that started gliding on their own that they aren't.
+    pub async fn stop_gliding(&self) {
+        self.entity.fall_flying.store(false, Relaxed);
+        self.entity.set_flag(Flag::FallFlying, false).await;
+    }
+
+    /// Vanilla: `LivingEntity.tickGliding()`, ends the glide once the entity can't glide anymore
+    /// and wears down the elytra once per second of flight.
+    async fn tick_gliding(&self, caller: &dyn EntityBase) {
+        if !self.entity.fall_flying.load(Relaxed) {
+            self.gliding_ticks.store(0, Relaxed);
+            return;
+        }
+        if self.entity.touching_water.load(SeqCst) || !self.can_glide().await {
+            self.gliding_ticks.store(0, Relaxed);
+            self.stop_gliding().await;
+            return;
+        }
+
+        let ticks = self.gliding_ticks.fetch_add(1, Relaxed) + 1;
+        if ticks % 20 == 0 {
+            self.damage_glider(caller).await;
+        }
+    }
+
+    async fn damage_glider(&self, caller: &dyn EntityBase) {
+        let player = caller.get_player();
+        if player.is_some_and(|player| player.gamemode.load() == GameMode::Creative) {
+            return;
+        }
+        let Some((slot_index, slot)) = self.chest_slot() else {
+            return;
+        };
+
+        let equipment = self.entity_equipment.lock().await.get(slot);
+        let updated_stack = {
+            let mut stack = equipment.lock().await;
+            // Unbreaking makes the elytra skip some of these
+            if !stack.damage_item(1) {
+                return;
+            }
+            stack.clone()
+        };
+
+        if let Some(player) = player {
+            player
+                .enqueue_slot_set_packet(&CSetPlayerInventory::new(
+                    (slot_index as i32).into(),
+                    &ItemStackSerializer::from(updated_stack.clone()),
+                ))
+                .await;
+        }
+        self.send_equipment_changes(&[(slot.clone(), updated_stack)])
+            .await;
     }
 
     async fn travel_in_air(&self, caller: Arc<dyn EntityBase>) {
@@ -958,24 +1232,51 @@ impl LivingEntity {
                     EntityStatus::PlayDeathSoundOrAddProjectileHitParticles,
                 )
                 .await;
+            let looting_modifier = if let Some(player) = cause.and_then(EntityBase::get_player) {
+                let level = player
+                    .inventory
+                    .held_item()
+                    .lock()
+                    .await
+                    .get_enchantment_level(&Enchantment::LOOTING);
+                u8::try_from(level.max(0)).unwrap_or(u8::MAX)
+            } else {
+                0
+            };
             let params = LootContextParameters {
                 killed_by_player: cause.map(|c| c.get_entity().entity_type == &EntityType::PLAYER),
+                looting_modifier,
                 ..Default::default()
             };
 
-            self.drop_loot(params).await;
+            let mut drops = self.collect_loot(params);
             self.entity.pose.store(EntityPose::Dying);
 
-            let block_pos = self.entity.block_pos.load();
+            // Players drop their whole inventory, including the equipment, on their own
+            if self.entity.entity_type != &EntityType::PLAYER {
+                for slot in self.equipment_slots.values() {
+                    let item = {
+                        let lock = self.entity_equipment.lock().await;
+                        let equipment = lock.get(slot);
+                        let mut item_lock = equipment.lock().await;
+                        mem::replace(&mut *item_lock, ItemStack::EMPTY.clone())
+                    };
+                    if !item.is_empty() {
+                        drops.push(item);
+                    }
+                }
+            }
 
-            for slot in self.equipment_slots.values() {
-                let item = {
-                    let lock = self.entity_equipment.lock().await;
-                    let equipment = lock.get(slot);
-                    let mut item_lock = equipment.lock().await;
-                    mem::replace(&mut *item_lock, ItemStack::EMPTY.clone())
-                };
-                world.drop_stack(&block_pos, item).await;
+            if let Some(server) = world.server.upgrade() {
+                drops = server
+                    .plugin_manager
+                    .fire(EntityDeathEvent::new(dyn_self.clone(), drops))
+                    .await
+                    .drops;
+            }
+            let block_pos = self.entity.block_pos.load();
+            for stack in drops {
+                world.drop_stack(&block_pos, stack).await;
             }
 
             let show_death_messages = { world.level_info.load().game_rules.show_death_messages };
@@ -992,12 +1293,66 @@ impl LivingEntity {
         }
     }
 
-    async fn drop_loot(&self, params: LootContextParameters) {
-        if let Some(loot_table) = &self.get_entity().entity_type.loot_table {
-            let pos = self.entity.block_pos.load();
-            for stack in loot_table.get_loot(params) {
-                self.entity.world.load().drop_stack(&pos, stack).await;
+    fn collect_loot(&self, params: LootContextParameters) -> Vec<ItemStack> {
+        self.get_entity()
+            .entity_type
+            .loot_table
+            .as_ref()
+            .map(|loot_table| loot_table.get_loot(params))
+            .unwrap_or_default()
+    }
+
+    /// Updates the air supply of a mob and deals drowning damage once it has run out.
+    /// Players tick their air in the `BreathManager` instead.
+    pub async fn drowning_tick(&self, caller: &dyn EntityBase) {
+        breath::tick_air_supply(self, caller, true).await;
+    }
+
+    /// Rain puts out the entity if it burns and can see the sky. In biomes cold enough for snow
+    /// it snows instead, which thaws a frozen entity faster. Entities in chunks that aren't
+    /// loaded are skipped instead of loading them.
+    pub async fn tick_rain_extinguish(&self, world: &World) {
+        if self.entity.fire_ticks.load(Relaxed) <= 0 && self.entity.frozen_ticks.load(Relaxed) <= 0
+        {
+            return;
+        }
+        if !world.is_raining_hard() {
+            return;
+        }
+        let pos = self.entity.block_pos.load();
+        if world.can_see_sky_if_loaded(&pos) != Some(true) {
+            return;
+        }
+        let Some(biome) = world.get_rough_biome_if_loaded(&pos) else {
+            return;
+        };
+        if !biome.weather.has_precipitation() {
+            return;
+        }
+
+        let temperature = biome.weather.compute_temperature(
+            f64::from(pos.0.x),
+            pos.0.y,
+            f64::from(pos.0.z),
+            world.sea_level,
+        );
+        if temperature >= 0.15 {
+            if self.entity.fire_ticks.load(Relaxed) > 0 {
+                self.entity.extinguish().await;
             }
+            return;
+        }
+        let frozen_ticks = self.entity.frozen_ticks.load(Relaxed);
+        if frozen_ticks > 0 {
+            let frozen_ticks = (frozen_ticks - SNOW_THAW_TICKS).max(0);
+            self.entity.frozen_ticks.store(frozen_ticks, Relaxed);
+            self.entity
+                .send_meta_data(&[Metadata::new(
+                    TrackedData::DATA_FROZEN_TICKS,
+                    MetaDataType::Integer,
+                    VarInt(frozen_ticks),
+                )])
+                .await;
         }
     }
 
@@ -1020,13 +1375,21 @@ impl LivingEntity {
     }
 
     async fn try_use_death_protector(&self, caller: &dyn EntityBase) -> bool {
+        // Like vanilla, the main hand is checked before the off hand
         for hand in Hand::all() {
             let stack = self.get_stack_in_hand(caller, hand).await;
             let mut stack = stack.lock().await;
-            // TODO: effects...
             if stack.get_data_component::<DeathProtectionImpl>().is_some() {
-                stack.clear();
+                stack.decrement(1);
+                let remaining = stack.clone();
+                drop(stack);
                 self.set_health(1.0).await;
+                if let Some(player) = caller.get_player() {
+                    player
+                        .sync_hand_slot(player.inventory.hand_slot(hand), remaining)
+                        .await;
+                }
+                self.apply_totem_effects(caller).await;
                 self.entity
                     .world
                     .load()
@@ -1039,6 +1402,60 @@ impl LivingEntity {
         false
     }
 
+    async fn apply_totem_effects(&self, caller: &dyn EntityBase) {
+        let effects = TOTEM_EFFECTS.map(|(effect_type, duration, amplifier)| Effect {
+            effect_type,
+            duration,
+            amplifier,
+            ambient: false,
+            show_particles: true,
+            show_icon: true,
+            blend: false,
+        });
+        // Players have to be told about the effects, so they go through the player's methods
+        if let Some(player) = caller.get_player() {
+            player.remove_all_effects().await;
+            for effect in effects {
+                player.add_effect(effect).await;
+            }
+            return;
+        }
+        let active: Vec<_> = self.active_effects.lock().await.keys().copied().collect();
+        for effect_type in active {
+            self.remove_effect(effect_type).await;
+        }
+        for effect in effects {
+            self.add_effect(effect).await;
+        }
+    }
+
+    /// The total armor and armor toughness given by the worn armor pieces.
+    async fn armor_values(&self) -> (f32, f32) {
+        let mut armor = 0.0;
+        let mut toughness = 0.0;
+        for slot in self.equipment_slots.values() {
+            if !slot.is_armor_slot() {
+                continue;
+            }
+            let equipment = self.entity_equipment.lock().await.get(slot);
+            let stack = equipment.lock().await;
+            let Some(modifiers) = stack.get_data_component::<AttributeModifiersImpl>() else {
+                continue;
+            };
+            for modifier in modifiers.attribute_modifiers.iter() {
+                if modifier.operation != Operation::AddValue {
+                    continue;
+                }
+                if modifier.r#type == &Attributes::ARMOR {
+                    armor += modifier.amount;
+                } else if modifier.r#type == &Attributes::ARMOR_TOUGHNESS {
+                    toughness += modifier.amount;
+                }
+            }
+        }
+        (armor as f32, toughness as f32)
+    }
+
     async fn damage_armor_items(&self, caller: &dyn EntityBase, damage_amount: f32) {
         let armor_damage = (damage_amount / 4.0).floor().max(1.0) as i32;
         let mut equipment_updates = Vec::new();
@@ -1096,8 +1513,8 @@ impl LivingEntity {
         hand: Hand,
     ) -> Arc<Mutex<ItemStack>> {
         match hand {
-            Hand::Left => self.off_hand_item().await,
-            Hand::Right => self.held_item(caller).await,
+            Hand::Main => self.held_item(caller).await,
+            Hand::Off => self.off_hand_item().await,
         }
     }
 
@@ -1121,22 +1538,20 @@ impl LivingEntity {
     pub async fn reset_state(&self) {
         self.entity.reset_state().await;
 
-        // Restore to maximum health for this entity type
-        let max_health = self.entity.entity_type.max_health.unwrap_or(20.0);
-        self.set_health(max_health).await;
+        // Restore to maximum health
+        self.set_health(self.get_max_health()).await;
 
         // Give a short grace period of invulnerability after respawn
         self.hurt_cooldown.store(20, Relaxed);
         self.last_damage_taken.store(0f32);
 
-        self.entity.portal_cooldown.store(0, Relaxed);
+        self.entity.set_portal_cooldown(0);
         *self.entity.portal_manager.lock().await = None;
 
         // Clear fall/fire state
         self.fall_distance.store(0f32);
         self.death_time.store(0, Relaxed);
-        self.entity.extinguish();
-        self.entity.fire_ticks.store(0, Relaxed);
+        self.entity.extinguish().await;
 
         // Clear velocity and movement input to remove persisted momentum
         self.entity.velocity.store(Vector3::default());
@@ -1258,6 +1673,15 @@ impl EntityBase for LivingEntity {
                 return false; // Fire resistance
             }
 
+            let source_pos =
+                position.or_else(|| source.map(|source| source.get_entity().pos.load()));
+            if self
+                .try_block_attack(caller, amount, damage_type, source_pos)
+                .await
+            {
+                return false;
+            }
+
             let world = self.entity.world.load();
 
             // These damage types bypass the hurt cooldown and death protection
@@ -1281,9 +1705,20 @@ impl EntityBase for LivingEntity {
             self.last_damage_taken.store(amount);
             damage_amount = damage_amount.max(0.0);
 
-            let config = &world.server.upgrade().unwrap().advanced_config.pvp;
+            // Armor is worn down by the damage before it is reduced
+            let armor_damage = damage_amount;
+            let bypasses_armor = bypasses_armor(damage_type);
+            if !bypasses_armor {
+                let (armor, toughness) = self.armor_values().await;
+                damage_amount = armor_damage_reduction(damage_amount, armor, toughness);
+            }
+
+            let hurt_animation = world
+                .server
+                .upgrade()
+                .is_some_and(|server| server.advanced_config.pvp.hurt_animation);
 
-            if config.hurt_animation {
+            if hurt_animation {
                 let entity_id = VarInt(self.entity.entity_id);
                 let hurt_yaw = source.map_or(0.0, |source| {
                     let src = source.get_entity().pos.load();
@@ -1333,6 +1768,14 @@ impl EntityBase for LivingEntity {
                         .store(attacker.get_entity().entity_id, Relaxed);
                     self.last_attacked_time
                         .store(self.entity.age.load(Relaxed), Relaxed);
+                    if let Some(attacker) = attacker.get_living_entity() {
+                        attacker
+                            .last_attacking_id
+                            .store(self.entity.entity_id, Relaxed);
+                        attacker
+                            .last_attacking_time
+                            .store(attacker.entity.age.load(Relaxed), Relaxed);
+                    }
                 }
                 //self.on_actually_hurt(damage_amount, damage_type).await;
                 self.set_health(new_health).await;
@@ -1345,8 +1788,8 @@ impl EntityBase for LivingEntity {
                 self.on_death(damage_type, source, cause).await;
             }
 
-            if damage_amount > 0.0 {
-                self.damage_armor_items(caller, damage_amount).await;
+            if armor_damage > 0.0 && !bypasses_armor {
+                self.damage_armor_items(caller, armor_damage).await;
             }
 
             true
@@ -1356,7 +1799,7 @@ impl EntityBase for LivingEntity {
     fn tick_in_void<'a>(&'a self, dyn_self: &'a dyn EntityBase) -> EntityBaseFuture<'a, ()> {
         Box::pin(async move {
             dyn_self
-                .damage(dyn_self, 4.0, DamageType::OUT_OF_WORLD)
+                .damage(dyn_self, VOID_DAMAGE, DamageType::OUT_OF_WORLD)
                 .await;
         })
     }
@@ -1373,6 +1816,7 @@ impl EntityBase for LivingEntity {
     ) -> EntityBaseFuture<'a, ()> {
         Box::pin(async move {
             self.entity.tick(caller.clone(), server).await;
+            self.tick_rain_extinguish(&self.entity.world.load()).await;
             // Only tick movement if the entity is alive. This prevents a dead "corpse"
             // from continuing to be simulated (accumulating fall_distance/velocity).
             if !self.dead.load(Relaxed) && self.health.load() > 0.0 {
@@ -1383,6 +1827,9 @@ impl EntityBase for LivingEntity {
                 self.entity.send_pos_rot().await;
             }
             self.tick_effects().await;
+            if caller.get_player().is_none() && !self.dead.load(Relaxed) {
+                self.drowning_tick(caller.as_ref()).await;
+            }
             // Current active item
             {
                 let item_in_use = self.item_in_use.lock().await.clone();
@@ -1399,12 +1846,15 @@ impl EntityBase for LivingEntity {
                             .await;
                     }
                     if let Some(player) = caller.get_player() {
+                        let hand = self.active_hand();
+                        let stack = player.inventory.get_stack_in_hand(hand).await;
+                        let mut stack = stack.lock().await;
+                        stack.decrement_unless_creative(player.gamemode.load(), 1);
+                        let updated_stack = stack.clone();
+                        drop(stack);
                         player
-                            .inventory
-                            .held_item()
-                            .lock()
-                            .await
-                            .decrement_unless_creative(player.gamemode.load(), 1);
+                            .sync_hand_slot(player.inventory.hand_slot(hand), updated_stack)
+                            .await;
                     }
 
                     self.clear_active_hand().await;
@@ -1441,3 +1891,368 @@ impl EntityBase for LivingEntity {
         self
     }
 }
+
+/// Damage types in the `bypasses_shield` tag can't be blocked.
+fn bypasses_shield(damage_type: DamageType) -> bool {
+    tag::DamageType::MINECRAFT_BYPASSES_SHIELD
+        .0
+        .iter()
+        .any(|name| DamageType::from_name(name) == Some(damage_type))
+}
+
+/// Damage types in the `bypasses_armor` tag ignore armor and don't wear it down.
+fn bypasses_armor(damage_type: DamageType) -> bool {
+    tag::DamageType::MINECRAFT_BYPASSES_ARMOR
+        .0
+        .iter()
+        .any(|name| DamageType::from_name(name) == Some(damage_type))
+}
+
+/// Vanilla: `DamageUtil.getDamageLeft()`, the damage left after armor. Toughness makes armor
+/// hold up better against strong hits; armor always blocks at least a fifth of its value and at
+/// most 80% of the damage.
+#[must_use]
+pub fn armor_damage_reduction(raw: f32, defense: f32, toughness: f32) -> f32 {
+    // Not `clamp`, which panics for more than 100 armor
+    let effective_defense = (defense - 4.0 * raw / (toughness + 8.0))
+        .max(defense / 5.0)
+        .min(20.0);
+    raw * (1.0 - effective_defense / 25.0)
+}
+
+/// Vanilla: `LivingEntity.calcGlidingVelocity()`, looking down trades height for speed and
+/// pulling up trades speed for height.
+fn glide_velocity(
+    mut velocity: Vector3<f64>,
+    look: Vector3<f64>,
+    pitch: f32,
+    gravity: f64,
+) -> Vector3<f64> {
+    let pitch = f64::from(pitch.to_radians());
+    let look_horizontal = look.horizontal_length();
+    let speed = velocity.horizontal_length();
+    let lift = pitch.cos() * pitch.cos();
+
+    velocity.y += gravity * lift.mul_add(0.75, -1.0);
+    if velocity.y < 0.0 && look_horizontal > 0.0 {
+        let dive = velocity.y * -0.1 * lift;
+        velocity.x += look.x * dive / look_horizontal;
+        velocity.y += dive;
+        velocity.z += look.z * dive / look_horizontal;
+    }
+    if pitch < 0.0 && look_horizontal > 0.0 {
+        let climb = speed * -pitch.sin() * 0.04;
+        velocity.x -= look.x * climb / look_horizontal;
+        velocity.y += climb * 3.2;
+        velocity.z -= look.z * climb / look_horizontal;
+    }
+    if look_horizontal > 0.0 {
+        velocity.x += (look.x / look_horizontal).mul_add(speed, -velocity.x) * 0.1;
+        velocity.z += (look.z / look_horizontal).mul_add(speed, -velocity.z) * 0.1;
+    }
+    Vector3::new(velocity.x * 0.99, velocity.y * 0.98, velocity.z * 0.99)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::Ordering::Relaxed;
+
+    use pumpkin_data::biome::Biome;
+    use pumpkin_data::damage::DamageType;
+    use pumpkin_data::data_component_impl::EquipmentSlot;
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::effect::StatusEffect;
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_data::item::Item;
+    use pumpkin_data::potion::Effect;
+    use pumpkin_util::math::{vector2::Vector2, vector3::Vector3};
+    use pumpkin_world::item::ItemStack;
+    use tempfile::tempdir;
+
+    use super::{LivingEntity, TOTEM_EFFECTS, armor_damage_reduction};
+    use crate::entity::player::Player;
+    use crate::entity::player::test::test_player;
+    use crate::entity::projectile::firework_rocket::FireworkRocketEntity;
+    use crate::entity::{Entity, EntityBase};
+    use crate::plugin::entity::entity_death::EntityDeathEvent;
+    use crate::plugin::test::count_all;
+    use crate::server::test::test_server;
+    use crate::world::World;
+    use crate::world::test::{load_empty_chunk, load_empty_chunk_in_biome, test_world_on};
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn armor_reduces_damage_like_vanilla() {
+        // No armor
+        assert_close(armor_damage_reduction(10.0, 0.0, 0.0), 10.0);
+        // Iron chestplate alone: 6 - 10 / 2 = 1 is below the minimum of 6 / 5
+        assert_close(armor_damage_reduction(10.0, 6.0, 0.0), 9.52);
+        // Full iron armor
+        assert_close(armor_damage_reduction(10.0, 15.0, 0.0), 6.0);
+        // Full diamond armor
+        assert_close(armor_damage_reduction(10.0, 20.0, 8.0), 3.0);
+    }
+
+    #[test]
+    fn armor_reduction_is_clamped() {
+        // Huge hits still get the minimum reduction
+        assert_close(armor_damage_reduction(100.0, 10.0, 0.0), 92.0);
+        // At most 80% is blocked, however much armor there is
+        assert_close(armor_damage_reduction(1.0, 30.0, 20.0), 0.2);
+    }
+
+    /// A player high up in the air wearing an elytra.
+    async fn airborne_player_with_elytra(world: &Arc<World>) -> Arc<Player> {
+        load_empty_chunk(world, Vector2::new(0, 0));
+        let (player, _connection) = test_player(world, "Steve").await;
+        let entity = &player.living_entity.entity;
+        entity.set_pos(Vector3::new(0.5, 200.0, 0.5));
+        entity.on_ground.store(false, Relaxed);
+        player
+            .living_entity
+            .entity_equipment
+            .lock()
+            .await
+            .put(&EquipmentSlot::CHEST, ItemStack::new(1, &Item::ELYTRA))
+            .await;
+        player
+    }
+
+    async fn worn_elytra_damage(player: &Player) -> i32 {
+        let equipment = player.living_entity.entity_equipment.lock().await;
+        let chest = equipment.get(&EquipmentSlot::CHEST);
+        drop(equipment);
+        chest.lock().await.get_damage()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn gliding_needs_an_airborne_elytra() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let player = airborne_player_with_elytra(&world).await;
+        let living = &player.living_entity;
+
+        living.entity.on_ground.store(true, Relaxed);
+        assert!(!living.try_start_gliding().await);
+
+        living.entity.on_ground.store(false, Relaxed);
+        assert!(living.try_start_gliding().await);
+        assert!(living.entity.fall_flying.load(Relaxed));
+
+        // An elytra about to break can't be used
+        let equipment = living.entity_equipment.lock().await;
+        let chest = equipment.get(&EquipmentSlot::CHEST);
+        drop(equipment);
+        {
+            let mut elytra = chest.lock().await;
+            let max_damage = elytra.get_max_damage().unwrap();
+            elytra.set_damage(max_damage - 1);
+        }
+        living.tick_gliding(player.as_ref()).await;
+        assert!(!living.entity.fall_flying.load(Relaxed));
+        assert!(!living.try_start_gliding().await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn gliding_wears_the_elytra_once_per_second() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let player = airborne_player_with_elytra(&world).await;
+        assert!(player.living_entity.try_start_gliding().await);
+
+        for _ in 0..19 {
+            player.living_entity.tick_gliding(player.as_ref()).await;
+        }
+        assert_eq!(worn_elytra_damage(&player).await, 0);
+        player.living_entity.tick_gliding(player.as_ref()).await;
+        assert_eq!(worn_elytra_damage(&player).await, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn rockets_boost_gliding_shooters() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let player = airborne_player_with_elytra(&world).await;
+        assert!(player.living_entity.try_start_gliding().await);
+        let entity = &player.living_entity.entity;
+        entity.set_rotation(0.0, 0.0);
+        entity.velocity.store(Vector3::new(0.0, 0.0, 0.0));
+
+        let rocket = Entity::new(
+            world.clone(),
+            entity.pos.load(),
+            &EntityType::FIREWORK_ROCKET,
+        );
+        let rocket = Arc::new(
+            FireworkRocketEntity::new_shot(
+                rocket,
+                entity,
+                &ItemStack::new(1, &Item::FIREWORK_ROCKET),
+            )
+            .await,
+        );
+        rocket.tick(rocket.clone(), &server).await;
+
+        // Looking straight ahead, a standing start is boosted to 0.85 blocks per tick
+        let velocity = entity.velocity.load();
+        assert!((velocity.length() - 0.85).abs() < 1e-6, "{velocity:?}");
+        assert!(velocity.y.abs() < 1e-6);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn gliding_raises_the_movement_speed_limit() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let player = airborne_player_with_elytra(&world).await;
+        let fifteen_blocks = Vector3::new(15.0, 0.0, 0.0);
+        let twenty_blocks = Vector3::new(0.0, -20.0, 0.0);
+
+        assert!(player.moved_too_quickly(fifteen_blocks).await);
+
+        assert!(player.living_entity.try_start_gliding().await);
+        assert!(!player.moved_too_quickly(fifteen_blocks).await);
+        assert!(player.moved_too_quickly(twenty_blocks).await);
+
+        world.level_info.rcu(|level_info| {
+            let mut level_info = (**level_info).clone();
+            level_info.game_rules.elytra_movement_check = false;
+            level_info
+        });
+        assert!(!player.moved_too_quickly(twenty_blocks).await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn rain_puts_out_burning_mobs() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        load_empty_chunk_in_biome(&world, Vector2::new(0, 0), &Biome::PLAINS);
+        let zombie = LivingEntity::new(Entity::new(
+            world.clone(),
+            Vector3::new(0.5, 64.0, 0.5),
+            &EntityType::ZOMBIE,
+        ));
+        // This one is in a chunk that isn't loaded
+        let far_zombie = LivingEntity::new(Entity::new(
+            world.clone(),
+            Vector3::new(1000.5, 64.0, 0.5),
+            &EntityType::ZOMBIE,
+        ));
+        zombie.entity.set_on_fire_for_ticks(100);
+        far_zombie.entity.set_on_fire_for_ticks(100);
+
+        zombie.tick_rain_extinguish(&world).await;
+        assert_eq!(zombie.entity.fire_ticks.load(Relaxed), 100);
+
+        {
+            let mut weather = world.weather.lock().await;
+            weather.raining = true;
+            weather.rain_level = 1.0;
+        }
+        world.tick_weather().await;
+        assert!(world.is_raining_hard());
+
+        zombie.tick_rain_extinguish(&world).await;
+        far_zombie.tick_rain_extinguish(&world).await;
+        assert_eq!(zombie.entity.fire_ticks.load(Relaxed), 0);
+        assert_eq!(far_zombie.entity.fire_ticks.load(Relaxed), 100);
+        assert!(world.get_chunk_if_loaded(Vector2::new(62, 0)).is_none());
+    }
+
+    /// Asserts that the entity has exactly the totem's effects.
+    async fn assert_totem_effects(living: &LivingEntity) {
+        let effects = living.active_effects.lock().await;
+        assert_eq!(effects.len(), TOTEM_EFFECTS.len());
+        for (effect_type, duration, amplifier) in TOTEM_EFFECTS {
+            let effect = &effects[effect_type];
+            assert_eq!(effect.duration, duration);
+            assert_eq!(effect.amplifier, amplifier);
+        }
+    }
+
+    fn poison() -> Effect {
+        Effect {
+            effect_type: &StatusEffect::POISON,
+            duration: 200,
+            amplifier: 0,
+            ambient: false,
+            show_particles: true,
+            show_icon: true,
+            blend: false,
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn totem_saves_mobs_without_a_death_event() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        load_empty_chunk(&world, Vector2::new(0, 0));
+        let deaths = count_all::<EntityDeathEvent>(&server).await;
+        let zombie = Arc::new(LivingEntity::new(Entity::new(
+            world.clone(),
+            Vector3::new(0.5, 64.0, 0.5),
+            &EntityType::ZOMBIE,
+        )));
+        world.spawn_entity(zombie.clone()).await;
+        zombie
+            .entity_equipment
+            .lock()
+            .await
+            .put(
+                &EquipmentSlot::OFF_HAND,
+                ItemStack::new(1, &Item::TOTEM_OF_UNDYING),
+            )
+            .await;
+        zombie.add_effect(poison()).await;
+
+        assert!(
+            zombie
+                .damage(zombie.as_ref(), 100.0, DamageType::GENERIC)
+                .await
+        );
+        assert!(!zombie.dead.load(Relaxed));
+        assert_close(zombie.health.load(), 1.0);
+        assert!(zombie.off_hand_item().await.lock().await.is_empty());
+        assert_totem_effects(&zombie).await;
+        assert_eq!(deaths.count(), 0);
+
+        // Without another totem the next lethal hit kills it
+        zombie.hurt_cooldown.store(0, Relaxed);
+        zombie
+            .damage(zombie.as_ref(), 100.0, DamageType::GENERIC)
+            .await;
+        assert!(zombie.dead.load(Relaxed));
+        assert_eq!(deaths.count(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn totem_saves_players() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        load_empty_chunk(&world, Vector2::new(0, 0));
+        let (player, _connection) = test_player(&world, "Steve").await;
+        *player.inventory.held_item().lock().await = ItemStack::new(1, &Item::TOTEM_OF_UNDYING);
+        player.add_effect(poison()).await;
+
+        player
+            .damage(player.as_ref(), 100.0, DamageType::GENERIC)
+            .await;
+        assert!(!player.living_entity.dead.load(Relaxed));
+        assert_close(player.living_entity.health.load(), 1.0);
+        assert!(player.inventory.held_item().lock().await.is_empty());
+        assert_totem_effects(&player.living_entity).await;
+    }
+}
@@ -97,7 +97,7 @@ impl CreeperEntity {
             .await;
     }
 
-    async fn explode(&self) {
+    async fn explode(&self, caller: &Arc<dyn EntityBase>) {
         let entity = &self.mob_entity.living_entity.entity;
         let radius = self.explosion_radius.load(Ordering::Relaxed) as f32;
         let multiplier = if self.charged.load(Ordering::Relaxed) {
@@ -111,7 +111,9 @@ impl CreeperEntity {
             .store(true, Ordering::Relaxed);
         let world = entity.world.load();
         let pos = entity.pos.load();
-        world.explode(pos, radius * multiplier).await;
+        world
+            .explode_attributed(pos, radius * multiplier, Some(caller.clone()), None)
+            .await;
         // TODO: spawn area effect cloud with potion effects
         entity.remove().await;
     }
@@ -160,7 +162,7 @@ impl Mob for CreeperEntity {
         &self.mob_entity
     }
 
-    fn mob_tick<'a>(&'a self, _caller: &'a Arc<dyn EntityBase>) -> EntityBaseFuture<'a, ()> {
+    fn mob_tick<'a>(&'a self, caller: &'a Arc<dyn EntityBase>) -> EntityBaseFuture<'a, ()> {
         Box::pin(async move {
             let entity = &self.mob_entity.living_entity.entity;
             if !entity.is_alive() {
@@ -198,7 +200,7 @@ impl Mob for CreeperEntity {
 
             if new_fuse >= fuse_time {
                 self.current_fuse_time.store(fuse_time, Ordering::Relaxed);
-                self.explode().await;
+                self.explode(caller).await;
             }
         })
     }
@@ -2,14 +2,19 @@ use super::{Entity, EntityBase, NBTStorage, ai::pathfinder::Navigator, living::L
 use crate::entity::EntityBaseFuture;
 use crate::entity::ai::control::look_control::LookControl;
 use crate::entity::ai::goal::goal_selector::GoalSelector;
+use crate::entity::passive::horse::HorseEntity;
+use crate::entity::passive::tameable::Tameable;
 use crate::entity::player::Player;
 use crate::server::Server;
 use crate::world::World;
 use crossbeam::atomic::AtomicCell;
 use pumpkin_data::damage::DamageType;
+use pumpkin_data::data_component_impl::EquipmentSlot;
+use pumpkin_data::entity::MobCategory;
 use pumpkin_data::meta_data_type::MetaDataType;
 use pumpkin_data::tracked_data::TrackedData;
 use pumpkin_protocol::java::client::play::{CHeadRot, CUpdateEntityRot, Metadata};
+use pumpkin_util::Difficulty;
 use pumpkin_util::math::boundingbox::BoundingBox;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
@@ -139,7 +144,7 @@ impl MobEntity {
             return;
         }
 
-        target
+        if !target
             .damage_with_context(
                 target,
                 ZOMBIE_ATTACK_DAMAGE,
@@ -148,7 +153,21 @@ impl MobEntity {
                 Some(caller),
                 Some(caller),
             )
-            .await;
+            .await
+        {
+            return;
+        }
+
+        let weapon = self
+            .living_entity
+            .entity_equipment
+            .lock()
+            .await
+            .get(&EquipmentSlot::MAIN_HAND);
+        let fire_aspect_level = Entity::get_fire_aspect_level(Some(&*weapon.lock().await));
+        target
+            .get_entity()
+            .set_on_fire_from_fire_aspect(fire_aspect_level);
     }
 
     async fn get_attack_box(&self, attack_range: f64) -> BoundingBox {
@@ -230,6 +249,19 @@ pub trait Mob: EntityBase + Send + Sync {
     ) -> EntityBaseFuture<'a, bool> {
         Box::pin(async { false })
     }
+
+    fn get_mob_horse(self: Arc<Self>) -> Option<Arc<HorseEntity>> {
+        None
+    }
+
+    fn get_mob_tameable(&self) -> Option<&Tameable> {
+        None
+    }
+
+    /// Sends the mob specific tracked data once the mob is spawned.
+    fn mob_init_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async {})
+    }
 }
 
 impl<T: Mob + Send + 'static> EntityBase for T {
@@ -241,6 +273,15 @@ impl<T: Mob + Send + 'static> EntityBase for T {
         Box::pin(async move {
             let mob_entity = self.get_mob_entity();
 
+            // Monsters don't stay around in Peaceful
+            let entity = &mob_entity.living_entity.entity;
+            if entity.entity_type.category == &MobCategory::MONSTER
+                && entity.world.load().get_difficulty() == Difficulty::Peaceful
+            {
+                entity.remove().await;
+                return;
+            }
+
             self.mob_tick(&caller).await;
 
             // AI runs before physics (vanilla order: goals → navigator → look → physics)
@@ -270,6 +311,10 @@ impl<T: Mob + Send + 'static> EntityBase for T {
             let mut look_control = mob_entity.look_control.lock().await;
             look_control.tick(self).await;
             drop(look_control);
+            mob_entity
+                .living_entity
+                .entity
+                .clamp_head_rotation(self.get_max_head_rotation());
 
             mob_entity.living_entity.tick(caller, server).await;
 
@@ -288,7 +333,7 @@ impl<T: Mob + Send + 'static> EntityBase for T {
             if yaw.abs_diff(last_yaw) >= 1 || pitch.abs_diff(last_pitch) >= 1 {
                 let world = entity.world.load();
                 world
-                    .broadcast_packet_all(&CUpdateEntityRot::new(
+                    .broadcast_packet_all_shared(CUpdateEntityRot::new(
                         entity.entity_id.into(),
                         yaw,
                         pitch,
@@ -302,7 +347,7 @@ impl<T: Mob + Send + 'static> EntityBase for T {
             if head_yaw.abs_diff(last_head_yaw) >= 1 {
                 let world = entity.world.load();
                 world
-                    .broadcast_packet_all(&CHeadRot::new(entity.entity_id.into(), head_yaw))
+                    .broadcast_packet_all_shared(CHeadRot::new(entity.entity_id.into(), head_yaw))
                     .await;
                 mob_entity.last_sent_head_yaw.store(head_yaw, Relaxed);
             }
@@ -319,6 +364,13 @@ impl<T: Mob + Send + 'static> EntityBase for T {
         cause: Option<&'a dyn EntityBase>,
     ) -> EntityBaseFuture<'a, bool> {
         Box::pin(async move {
+            // Owners can't hurt their own pets
+            if let Some(tameable) = self.get_mob_tameable()
+                && cause.is_some_and(|cause| tameable.is_owner(cause.get_entity()))
+            {
+                return false;
+            }
+
             let damaged = self
                 .get_mob_entity()
                 .living_entity
@@ -331,6 +383,25 @@ impl<T: Mob + Send + 'static> EntityBase for T {
         })
     }
 
+    fn init_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            let entity = &self.get_mob_entity().living_entity.entity;
+
+            // If the internal age is negative, it's a baby
+            if entity.age.load(Relaxed) < 0 {
+                entity
+                    .send_meta_data(&[Metadata::new(
+                        TrackedData::DATA_BABY,
+                        MetaDataType::Boolean,
+                        true,
+                    )])
+                    .await;
+            }
+
+            self.mob_init_data_tracker().await;
+        })
+    }
+
     fn interact<'a>(
         &'a self,
         player: &'a Player,
@@ -347,6 +418,14 @@ impl<T: Mob + Send + 'static> EntityBase for T {
         Some(&self.get_mob_entity().living_entity)
     }
 
+    fn get_horse(self: Arc<Self>) -> Option<Arc<HorseEntity>> {
+        self.get_mob_horse()
+    }
+
+    fn get_tameable(&self) -> Option<&Tameable> {
+        self.get_mob_tameable()
+    }
+
     fn as_nbt_storage(&self) -> &dyn NBTStorage {
         self
     }
@@ -404,3 +483,59 @@ pub trait PathAwareEntity: Mob + Send + Sync {
         1.0
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::Difficulty;
+    use pumpkin_util::math::vector2::Vector2;
+    use pumpkin_util::math::vector3::Vector3;
+    use tempfile::tempdir;
+    use uuid::Uuid;
+
+    use crate::entity::r#type::from_type;
+    use crate::server::test::test_server;
+    use crate::world::World;
+    use crate::world::test::{load_empty_chunk, test_world_on};
+
+    fn is_in_world(world: &World, uuid: Uuid) -> bool {
+        world
+            .entities
+            .load()
+            .iter()
+            .any(|entity| entity.get_entity().entity_uuid == uuid)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn peaceful_removes_monsters_on_their_first_tick() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        load_empty_chunk(&world, Vector2::new(0, 0));
+        let zombie_uuid = Uuid::new_v4();
+        let zombie = from_type(
+            &EntityType::ZOMBIE,
+            Vector3::new(4.5, 64.0, 4.5),
+            &world,
+            zombie_uuid,
+        )
+        .await;
+        let cow_uuid = Uuid::new_v4();
+        let cow = from_type(
+            &EntityType::COW,
+            Vector3::new(10.5, 64.0, 10.5),
+            &world,
+            cow_uuid,
+        )
+        .await;
+        world.spawn_entity(zombie.clone()).await;
+        world.spawn_entity(cow.clone()).await;
+        assert!(world.set_difficulty(Difficulty::Peaceful, false).await);
+
+        zombie.tick(zombie.clone(), &server).await;
+        cow.tick(cow.clone(), &server).await;
+        assert!(!is_in_world(&world, zombie_uuid));
+        assert!(is_in_world(&world, cow_uuid));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::entity::item::ItemEntity;
 use crate::net::ClientPlatform;
+use crate::plugin::entity::entity_teleport::EntityTeleportEvent;
 use crate::world::World;
 use crate::{
     server::Server,
@@ -9,15 +10,17 @@ use arc_swap::ArcSwap;
 use bytes::BufMut;
 use crossbeam::atomic::AtomicCell;
 use living::LivingEntity;
+use passive::{horse::HorseEntity, tameable::Tameable};
 use player::Player;
 use pumpkin_data::BlockState;
 use pumpkin_data::block_properties::{EnumVariants, Integer0To15, blocks_movement};
 use pumpkin_data::dimension::Dimension;
 use pumpkin_data::fluid::Fluid;
+use pumpkin_data::item::Item;
 use pumpkin_data::meta_data_type::MetaDataType;
 use pumpkin_data::tag::{self, Taggable};
 use pumpkin_data::tracked_data::TrackedData;
-use pumpkin_data::{Block, BlockDirection};
+use pumpkin_data::{Block, BlockDirection, Enchantment};
 use pumpkin_data::{
     block_properties::{Facing, HorizontalFacing},
     damage::DamageType,
@@ -29,8 +32,8 @@ use pumpkin_protocol::java::client::play::{CUpdateEntityPos, CUpdateEntityPosRot
 use pumpkin_protocol::{
     codec::var_int::VarInt,
     java::client::play::{
-        CEntityPositionSync, CEntityVelocity, CHeadRot, CSetEntityMetadata, CSpawnEntity,
-        CUpdateEntityRot, Metadata,
+        CEntityPositionSync, CEntityVelocity, CHeadRot, CSetEntityLink, CSetEntityMetadata,
+        CSpawnEntity, CUpdateEntityRot, Metadata,
     },
 };
 use pumpkin_util::math::vector3::Axis;
@@ -45,6 +48,7 @@ use pumpkin_util::math::{
 use pumpkin_util::text::TextComponent;
 use pumpkin_util::text::hover::HoverEvent;
 use pumpkin_util::version::MinecraftVersion;
+use pumpkin_world::BlockStateId;
 use pumpkin_world::item::ItemStack;
 use serde::Serialize;
 use std::collections::BTreeMap;
@@ -52,7 +56,7 @@ use std::pin::Pin;
 use std::sync::{
     Arc,
     atomic::{
-        AtomicBool, AtomicI32, AtomicU32,
+        AtomicBool, AtomicI32, AtomicU8, AtomicU32,
         Ordering::{self, Relaxed},
     },
 };
@@ -60,6 +64,7 @@ use tokio::sync::Mutex;
 use uuid::Uuid;
 
 pub mod ai;
+pub mod bedrock_attributes;
 pub mod boss;
 pub mod breath;
 pub mod decoration;
@@ -82,7 +87,248 @@ pub mod predicate;
 
 pub type EntityBaseFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 
-pub type TeleportFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+pub type TeleportFuture = Pin<Box<dyn Future<Output = bool> + Send>>;
+
+/// Why an entity is being teleported, passed on to `EntityTeleportEvent` handlers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TeleportCause {
+    /// A command like `/tp` or `/rotate`.
+    Command,
+    /// A nether or end portal.
+    Portal,
+    /// Getting off a vehicle.
+    Dismount,
+    /// A thrown ender pearl landing.
+    EnderPearl,
+    /// Anything else, e.g. a pet following its owner.
+    Unknown,
+}
+
+/// How far below the bottom of the world an entity has to be to be in the void.
+const VOID_DEPTH: f64 = 64.0;
+/// The damage living entities take every tick while in the void.
+pub const VOID_DAMAGE: f32 = 4.0;
+
+/// What happens to an entity for each tick it spends in the void.
+#[derive(Debug, PartialEq)]
+enum VoidAction {
+    Damage(f32),
+    Remove,
+}
+
+impl VoidAction {
+    /// Living entities take void damage until they die, everything else is removed right away.
+    const fn for_entity(is_living: bool) -> Self {
+        if is_living {
+            Self::Damage(VOID_DAMAGE)
+        } else {
+            Self::Remove
+        }
+    }
+}
+
+fn is_in_void(y: f64, min_y: i32) -> bool {
+    y < f64::from(min_y) - VOID_DEPTH
+}
+
+fn is_undead_type(entity_type: &EntityType) -> bool {
+    entity_type.has_tag(&tag::EntityType::MINECRAFT_UNDEAD)
+}
+
+fn is_aquatic_type(entity_type: &EntityType) -> bool {
+    entity_type.has_tag(&tag::EntityType::MINECRAFT_AQUATIC)
+}
+
+/// Whether entities of this type push each other apart: mobs, players, boats and minecarts.
+/// Vanilla: `Entity.isPushable()`
+fn is_pushable_type(entity_type: &EntityType) -> bool {
+    entity_type.mob
+        || entity_type == &EntityType::PLAYER
+        || entity_type.has_tag(&tag::EntityType::C_BOATS)
+        || entity_type.has_tag(&tag::EntityType::C_MINECARTS)
+}
+
+/// How much an entity inside `block` is slowed down, for blocks that entities get stuck in other
+/// than cobwebs, which slow entities down in [`CobwebBlock`](crate::block::blocks::cobweb::CobwebBlock).
+/// `in_block` is the block at the entity's position, living entities only sink into powder snow
+/// once their feet are in it. The exclusions for flying players, spiders and withers are applied
+/// by [`Entity::slow_movement`].
+fn stuck_in_block_multiplier(
+    block: &Block,
+    entity_type: &EntityType,
+    is_living: bool,
+    in_block: &Block,
+) -> Option<Vector3<f64>> {
+    if block == &Block::SWEET_BERRY_BUSH {
+        (is_living && entity_type != &EntityType::FOX && entity_type != &EntityType::BEE)
+            .then(|| Vector3::new(0.8, 0.75, 0.8))
+    } else if block == &Block::POWDER_SNOW {
+        (!is_living || in_block == &Block::POWDER_SNOW).then(|| Vector3::new(0.9, 1.5, 0.9))
+    } else {
+        None
+    }
+}
+
+/// The body yaw that brings `head_yaw` back within `max_head_yaw_diff` degrees of the body, or
+/// `None` if it already is. The body is only turned as far as needed, so it trails the head by
+/// exactly the maximum offset.
+fn clamped_body_yaw(head_yaw: f32, body_yaw: f32, max_head_yaw_diff: f32) -> Option<f32> {
+    let diff = wrap_degrees(head_yaw - body_yaw);
+    (diff.abs() > max_head_yaw_diff)
+        .then(|| head_yaw - diff.clamp(-max_head_yaw_diff, max_head_yaw_diff))
+}
+
+/// The pose a mob switches to when it starts or stops swimming. Mobs only crouch when there was
+/// no room to stand up after swimming, so they try to stand up again as well.
+/// Other poses like sleeping are kept.
+const fn swimming_pose_change(current: EntityPose, swimming: bool) -> Option<EntityPose> {
+    match (current, swimming) {
+        (EntityPose::Standing | EntityPose::Crouching, true) => Some(EntityPose::Swimming),
+        (EntityPose::Swimming | EntityPose::Crouching, false) => Some(EntityPose::Standing),
+        _ => None,
+    }
+}
+
+/// Whether an entity that swims when `moving` (usually sprinting) should be swimming.
+const fn should_swim(moving: bool, touching_water: bool, has_vehicle: bool) -> bool {
+    moving && touching_water && !has_vehicle
+}
+
+/// The flags byte with `flag` set to `value`, keeping the other flags.
+const fn with_flag(flags: u8, flag: Flag, value: bool) -> u8 {
+    let bit = 1 << flag as u8;
+    if value { flags | bit } else { flags & !bit }
+}
+
+/// The horizontal velocity an entity at `pushed` gets when overlapping with one at `pusher`,
+/// or `None` if they are too close to tell which way to push. Vanilla: `Entity.pushAwayFrom()`
+fn soft_collision_push(pusher: Vector3<f64>, pushed: Vector3<f64>) -> Option<Vector3<f64>> {
+    let dx = pushed.x - pusher.x;
+    let dz = pushed.z - pusher.z;
+    let distance = dx.abs().max(dz.abs());
+    if distance < 0.01 {
+        return None;
+    }
+    let distance = distance.sqrt();
+    let scale = (1.0 / distance).min(1.0) * 0.05 / distance;
+    Some(Vector3::new(dx * scale, 0.0, dz * scale))
+}
+
+/// The result of moving a bounding box through the block collisions along the way.
+struct MovementCollision {
+    /// The movement clipped to where the box hits a block.
+    movement: Vector3<f64>,
+    /// The block the box landed on. Only set when moving down, hitting a ceiling doesn't put
+    /// the entity on the ground.
+    supporting_block_pos: Option<BlockPos>,
+    horizontal_collision: bool,
+}
+
+/// Clips `movement` of `bounding_box` against `collisions`, first along the Y axis and then
+/// horizontally. `block_positions` pairs the end index of each block's shapes in `collisions`
+/// with the block's position, as returned by [`World::get_block_collisions`].
+#[expect(clippy::float_cmp)]
+fn collide_movement(
+    bounding_box: &BoundingBox,
+    movement: Vector3<f64>,
+    collisions: &[BoundingBox],
+    block_positions: Vec<(usize, BlockPos)>,
+) -> MovementCollision {
+    let mut adjusted_movement = movement;
+    let mut supporting_block_pos = None;
+
+    if movement.get_axis(Axis::Y) != 0.0 && !collisions.is_empty() {
+        let mut max_time = 1.0;
+
+        let mut positions = block_positions.into_iter();
+
+        let (mut collisions_len, mut position) = positions.next().unwrap();
+
+        for (i, inert_box) in collisions.iter().enumerate() {
+            if i == collisions_len {
+                (collisions_len, position) = positions.next().unwrap();
+            }
+
+            if let Some(collision_time) = bounding_box.calculate_collision_time(
+                inert_box,
+                adjusted_movement,
+                Axis::Y,
+                max_time,
+            ) {
+                max_time = collision_time;
+
+                supporting_block_pos = Some(position);
+            }
+        }
+
+        if max_time != 1.0 {
+            let changed_component = adjusted_movement.get_axis(Axis::Y) * max_time;
+
+            adjusted_movement.set_axis(Axis::Y, changed_component);
+        }
+
+        if movement.get_axis(Axis::Y) > 0.0 {
+            supporting_block_pos = None;
+        }
+    }
+
+    let mut horizontal_collision = false;
+
+    for axis in Axis::horizontal() {
+        if movement.get_axis(axis) == 0.0 {
+            continue;
+        }
+
+        let mut max_time = 1.0;
+
+        for inert_box in collisions {
+            if let Some(collision_time) =
+                bounding_box.calculate_collision_time(inert_box, adjusted_movement, axis, max_time)
+            {
+                max_time = collision_time;
+            }
+        }
+
+        if max_time != 1.0 {
+            let changed_component = adjusted_movement.get_axis(axis) * max_time;
+
+            adjusted_movement.set_axis(axis, changed_component);
+
+            horizontal_collision = true;
+        }
+    }
+
+    MovementCollision {
+        movement: adjusted_movement,
+        supporting_block_pos,
+        horizontal_collision,
+    }
+}
+
+/// Pushes two overlapping entities apart, as long as `pusher` pushes entities and `pushed` can
+/// be pushed. Returns whether anything was pushed.
+pub async fn push_apart(pusher: &dyn EntityBase, pushed: &dyn EntityBase) -> bool {
+    let (pusher_entity, pushed_entity) = (pusher.get_entity(), pushed.get_entity());
+    if !pushed_entity.can_be_pushed_by(pusher_entity)
+        || !pusher.pushes_entities()
+        || !pushed.can_be_pushed()
+        || pusher_entity.no_clip.load(Relaxed)
+        || pushed_entity.no_clip.load(Relaxed)
+    {
+        return false;
+    }
+    let Some(push) = soft_collision_push(pusher_entity.pos.load(), pushed_entity.pos.load()) else {
+        return false;
+    };
+    // Players move themselves, their client handles being pushed
+    if pushed.get_player().is_none() {
+        pushed_entity.add_velocity(push).await;
+    }
+    if pusher.get_player().is_none() && pusher.can_be_pushed() {
+        pusher_entity.add_velocity(-push).await;
+    }
+    true
+}
 
 pub trait EntityBase: Send + Sync + NBTStorage {
     /// Called every tick for this entity.
@@ -125,6 +371,8 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         })
     }
 
+    /// Returns whether the entity was teleported, which is not the case when an event handler
+    /// cancelled it.
     // This method takes ownership of Arc<Self>, so the lifetime bounds are different.
     fn teleport(
         self: Arc<Self>,
@@ -132,14 +380,15 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         yaw: Option<f32>,
         pitch: Option<f32>,
         world: Arc<World>,
+        cause: TeleportCause,
     ) -> TeleportFuture
     where
         Self: 'static,
     {
         Box::pin(async move {
             self.get_entity()
-                .teleport(position, yaw, pitch, world)
-                .await;
+                .teleport(position, yaw, pitch, world, cause)
+                .await
         })
     }
 
@@ -156,8 +405,18 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         0.0
     }
 
-    fn tick_in_void<'a>(&'a self, _dyn_self: &'a dyn EntityBase) -> EntityBaseFuture<'a, ()> {
-        Box::pin(async move { self.get_entity().remove().await })
+    /// Called every tick while the entity is below the world.
+    fn tick_in_void<'a>(&'a self, dyn_self: &'a dyn EntityBase) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            match VoidAction::for_entity(self.get_living_entity().is_some()) {
+                VoidAction::Damage(amount) => {
+                    dyn_self
+                        .damage(dyn_self, amount, DamageType::OUT_OF_WORLD)
+                        .await;
+                }
+                VoidAction::Remove => self.get_entity().remove().await,
+            }
+        })
     }
 
     /// Returns if damage was successful or not
@@ -182,6 +441,24 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         false
     }
 
+    /// Whether this entity pushes the entities it overlaps with. Only living entities, boats and
+    /// minecarts do, so e.g. items and experience orbs never displace mobs.
+    fn pushes_entities(&self) -> bool {
+        self.can_be_pushed()
+    }
+
+    /// Whether other entities can push this one away right now. Which types push each other at
+    /// all is decided by [`Entity::can_be_pushed_by`]. Vanilla: `Entity.isPushable()`
+    fn can_be_pushed(&self) -> bool {
+        let entity = self.get_entity();
+        is_pushable_type(entity.entity_type)
+            && entity.is_alive()
+            && self
+                .get_living_entity()
+                .is_none_or(|living| !living.dead.load(Relaxed))
+            && !self.is_spectator()
+    }
+
     fn can_hit(&self) -> bool {
         false
     }
@@ -241,6 +518,14 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         None
     }
 
+    fn get_horse(self: Arc<Self>) -> Option<Arc<HorseEntity>> {
+        None
+    }
+
+    fn get_tameable(&self) -> Option<&Tameable> {
+        None
+    }
+
     fn get_player(&self) -> Option<&Player> {
         None
     }
@@ -328,6 +613,11 @@ impl RemovalReason {
 
 static CURRENT_ID: AtomicI32 = AtomicI32::new(0);
 
+/// The distance at which a leash snaps.
+pub const MAX_LEASH_DISTANCE: f64 = 10.0;
+/// The distance at which a leash starts pulling the leashed entity.
+const LEASH_ELASTIC_DISTANCE: f64 = 6.0;
+
 /// Represents a non-living Entity (e.g. Item, Egg, Snowball...)
 pub struct Entity {
     /// A unique identifier for the entity
@@ -359,6 +649,10 @@ pub struct Entity {
     pub invisible: AtomicBool,
     /// Indicates whether the entity is flying due to a fall
     pub fall_flying: AtomicBool,
+    /// Indicates whether the entity is swimming
+    pub swimming: AtomicBool,
+    /// The flags byte last sent to clients, see [`Flag`]
+    flags: AtomicU8,
     /// The entity's current velocity vector, aka knockback
     pub velocity: AtomicCell<Vector3<f64>>,
     /// Tracks a horizontal collision
@@ -396,24 +690,35 @@ pub struct Entity {
     /// The number of ticks the entity has been frozen (in powder snow)
     /// Max is 140 ticks (7 seconds). Increases by 1/tick in powder snow, decreases by 2/tick outside.
     pub frozen_ticks: AtomicI32,
+    /// The remaining air of the entity, decreases while its head is under water.
+    /// Max is 300 ticks (15 seconds), below 0 the entity starts drowning.
+    pub breath_ticks: AtomicI32,
     pub removal_reason: AtomicCell<Option<RemovalReason>>,
     // The passengers that entity has
     pub passengers: Mutex<Vec<Arc<dyn EntityBase>>>,
     /// The vehicle that entity is in
     pub vehicle: Mutex<Option<Arc<dyn EntityBase>>>,
+    /// The entity holding this entity's leash
+    pub leash_holder: Mutex<Option<Arc<dyn EntityBase>>>,
+    /// The UUID of the leash holder, kept so the leash can be restored from NBT
+    leash_holder_uuid: AtomicCell<Option<Uuid>>,
     /// The age of the entity in ticks. Negative values indicate a baby.
     pub age: AtomicI32,
 
-    pub first_loaded_chunk_position: AtomicCell<Option<Vector3<i32>>>,
+    /// The chunk the entity's data was last stored in, so it can be removed from there once the
+    /// entity is saved in another chunk.
+    pub first_loaded_chunk_position: AtomicCell<Option<Vector2<i32>>>,
 
-    pub portal_cooldown: AtomicU32,
+    /// Ticks until the entity can use a portal again, see [`Self::reset_portal_cooldown`].
+    portal_cooldown: AtomicU32,
 
     pub portal_manager: Mutex<Option<Mutex<PortalManager>>>,
     /// Custom name for the entity
     pub custom_name: Option<TextComponent>,
     /// Indicates whether the entity's custom name is visible
     pub custom_name_visible: bool,
-    /// The data send in the Entity Spawn packet
+    /// The data send in the Entity Spawn packet. Its meaning depends on the entity type,
+    /// prefer the `set_spawn_data_*` setters over writing it directly
     pub data: AtomicI32,
     /// If true, the entity cannot collide with anything (e.g. spectator)
     pub no_clip: AtomicBool,
@@ -474,6 +779,8 @@ impl Entity {
             world: ArcSwap::new(world),
             sprinting: AtomicBool::new(false),
             fall_flying: AtomicBool::new(false),
+            swimming: AtomicBool::new(false),
+            flags: AtomicU8::new(0),
             yaw: AtomicCell::new(0.0),
             head_yaw: AtomicCell::new(0.0),
             body_yaw: AtomicCell::new(0.0),
@@ -494,9 +801,12 @@ impl Entity {
             fire_ticks: AtomicI32::new(-1),
             has_visual_fire: AtomicBool::new(false),
             frozen_ticks: AtomicI32::new(0),
+            breath_ticks: AtomicI32::new(Self::MAX_BREATH_TICKS),
             removal_reason: AtomicCell::new(None),
             passengers: Mutex::new(Vec::new()),
             vehicle: Mutex::new(None),
+            leash_holder: Mutex::new(None),
+            leash_holder_uuid: AtomicCell::new(None),
             age: AtomicI32::new(0),
             portal_cooldown: AtomicU32::new(0),
             portal_manager: Mutex::new(None),
@@ -572,12 +882,17 @@ impl Entity {
         let pos = self.pos.load();
         if pos != new_position {
             self.pos.store(new_position);
-            self.bounding_box.store(BoundingBox::new_from_pos(
+            let bounding_box = BoundingBox::new_from_pos(
                 new_position.x,
                 new_position.y,
                 new_position.z,
                 &self.entity_dimension.load(),
-            ));
+            );
+            self.bounding_box.store(bounding_box);
+            self.world
+                .load()
+                .entity_sections
+                .update(self.entity_id, new_position, &bounding_box);
 
             let floor_x = new_position.x.floor() as i32;
             let floor_y = new_position.y.floor() as i32;
@@ -645,7 +960,7 @@ impl Entity {
 
         self.world
             .load()
-            .broadcast_packet_all(&CUpdateEntityRot::new(
+            .broadcast_packet_all_shared(CUpdateEntityRot::new(
                 self.entity_id.into(),
                 yaw,
                 pitch as u8,
@@ -659,19 +974,30 @@ impl Entity {
     pub async fn send_head_rot(&self, head_yaw: u8) {
         self.world
             .load()
-            .broadcast_packet_all(&CHeadRot::new(self.entity_id.into(), head_yaw))
+            .broadcast_packet_all_shared(CHeadRot::new(self.entity_id.into(), head_yaw))
             .await;
     }
 
-    fn default_portal_cooldown(&self) -> u32 {
-        if self.entity_type == &EntityType::PLAYER {
+    /// Ticks until the entity can use a portal again.
+    #[must_use]
+    pub fn get_portal_cooldown(&self) -> u32 {
+        self.portal_cooldown.load(Ordering::Relaxed)
+    }
+
+    pub fn set_portal_cooldown(&self, ticks: u32) {
+        self.portal_cooldown.store(ticks, Ordering::Relaxed);
+    }
+
+    /// Starts the cooldown after using a portal: 10 ticks for players and 300 for other
+    /// entities, like vanilla.
+    pub fn reset_portal_cooldown(&self) {
+        self.set_portal_cooldown(if self.entity_type == &EntityType::PLAYER {
             10
         } else {
             300
-        }
+        });
     }
 
-    #[expect(clippy::float_cmp)]
     async fn adjust_movement_for_collisions(&self, movement: Vector3<f64>) -> Vector3<f64> {
         self.on_ground.store(false, Ordering::SeqCst);
 
@@ -691,85 +1017,16 @@ impl Entity {
             .get_block_collisions(bounding_box.stretch(movement))
             .await;
 
-        if collisions.is_empty() {
-            return movement;
-        }
-
-        let mut adjusted_movement = movement;
-
-        // Y-Axis adjustment
-
-        if movement.get_axis(Axis::Y) != 0.0 {
-            let mut max_time = 1.0;
-
-            let mut positions = block_positions.into_iter();
-
-            let (mut collisions_len, mut position) = positions.next().unwrap();
-
-            let mut supporting_block_pos = None;
-
-            for (i, inert_box) in collisions.iter().enumerate() {
-                if i == collisions_len {
-                    (collisions_len, position) = positions.next().unwrap();
-                }
-
-                if let Some(collision_time) = bounding_box.calculate_collision_time(
-                    inert_box,
-                    adjusted_movement,
-                    Axis::Y,
-                    max_time,
-                ) {
-                    max_time = collision_time;
-
-                    supporting_block_pos = Some(position);
-                }
-            }
-
-            if max_time != 1.0 {
-                let changed_component = adjusted_movement.get_axis(Axis::Y) * max_time;
-
-                adjusted_movement.set_axis(Axis::Y, changed_component);
-            }
-
-            self.on_ground
-                .store(supporting_block_pos.is_some(), Ordering::SeqCst);
-
-            self.supporting_block_pos.store(supporting_block_pos);
-        }
-
-        let mut horizontal_collision = false;
-
-        for axis in Axis::horizontal() {
-            if movement.get_axis(axis) == 0.0 {
-                continue;
-            }
-
-            let mut max_time = 1.0;
-
-            for inert_box in &collisions {
-                if let Some(collision_time) = bounding_box.calculate_collision_time(
-                    inert_box,
-                    adjusted_movement,
-                    axis,
-                    max_time,
-                ) {
-                    max_time = collision_time;
-                }
-            }
-
-            if max_time != 1.0 {
-                let changed_component = adjusted_movement.get_axis(axis) * max_time;
-
-                adjusted_movement.set_axis(axis, changed_component);
-
-                horizontal_collision = true;
-            }
-        }
+        let collision = collide_movement(&bounding_box, movement, &collisions, block_positions);
 
+        self.on_ground
+            .store(collision.supporting_block_pos.is_some(), Ordering::SeqCst);
+        self.supporting_block_pos
+            .store(collision.supporting_block_pos);
         self.horizontal_collision
-            .store(horizontal_collision, Ordering::SeqCst);
+            .store(collision.horizontal_collision, Ordering::SeqCst);
 
-        adjusted_movement
+        collision.movement
     }
 
     /// Applies knockback to the entity, following vanilla Minecraft's mechanics.
@@ -943,10 +1200,16 @@ impl Entity {
                 .await;
 
             if bounding_box.intersects(&collision_shape.at_pos(pos)) {
+                self.get_stuck_in(caller.get_living_entity().is_some(), block, state)
+                    .await;
                 world
                     .block_registry
                     .on_entity_collision(block, &world, caller.as_ref(), &pos, state, server)
                     .await;
+                world
+                    .block_registry
+                    .on_walked_over(block, &world, caller.as_ref(), &pos, state)
+                    .await;
             }
         }
 
@@ -978,7 +1241,7 @@ impl Entity {
 
         self.world
             .load()
-            .broadcast_packet_all(&CUpdateEntityPosRot::new(
+            .broadcast_packet_all_shared(CUpdateEntityPosRot::new(
                 self.entity_id.into(),
                 Vector3::new(converted.x, converted.y, converted.z),
                 yaw,
@@ -1009,7 +1272,7 @@ impl Entity {
 
         self.world
             .load()
-            .broadcast_packet_all(&CUpdateEntityPos::new(
+            .broadcast_packet_all_shared(CUpdateEntityPos::new(
                 self.entity_id.into(),
                 Vector3::new(converted.x, converted.y, converted.z),
                 self.on_ground.load(Relaxed),
@@ -1058,7 +1321,7 @@ impl Entity {
 
                     if fluid.id != Fluid::EMPTY.id {
                         let marginal_height =
-                            f64::from(state.height) + f64::from(y) - bounding_box.min.y;
+                            world.get_fluid_height_at(&pos).await - bounding_box.min.y;
 
                         if marginal_height >= 0.0 {
                             let i = usize::from(
@@ -1335,25 +1598,38 @@ impl Entity {
 
         let velocity_multiplier = f64::from(self.get_velocity_multiplier().await);
 
-        self.velocity.store(final_move * velocity_multiplier);
+        let mut velocity = final_move * velocity_multiplier;
+        if motion.y != final_move.y {
+            // Keep the velocity from before the collision so the block below can bounce it
+            velocity.y = motion.y;
+        }
+        self.velocity.store(velocity);
 
+        let on_ground = self.on_ground.load(Ordering::SeqCst);
         if let Some(living) = caller.get_living_entity() {
             living
-                .fall(
-                    caller.clone(),
-                    final_move.y,
-                    self.on_ground.load(Ordering::SeqCst),
-                    false,
-                )
+                .fall(caller.clone(), final_move.y, on_ground, false)
                 .await;
         }
 
+        let world = self.world.load();
+        let (landing_pos, landing_block, landing_state) = self.get_block_with_y_offset(0.2).await;
         if motion.y != final_move.y {
-            let world = self.world.load();
-            let block = self.get_block_with_y_offset(0.2).await.1;
             world
                 .block_registry
-                .update_entity_movement_after_fall_on(block, caller.as_ref())
+                .update_entity_movement_after_fall_on(landing_block, caller.as_ref())
+                .await;
+        }
+        if on_ground {
+            world
+                .block_registry
+                .on_stepped_on(
+                    landing_block,
+                    &world,
+                    caller.as_ref(),
+                    &landing_pos,
+                    landing_state,
+                )
                 .await;
         }
     }
@@ -1415,7 +1691,7 @@ impl Entity {
     }
 
     async fn tick_portal(&self, caller: &Arc<dyn EntityBase>) {
-        if self.portal_cooldown.load(Ordering::Relaxed) > 0 {
+        if self.get_portal_cooldown() > 0 {
             self.portal_cooldown.fetch_sub(1, Ordering::Relaxed);
         }
         let mut manager_guard = self.portal_manager.lock().await;
@@ -1423,8 +1699,7 @@ impl Entity {
         if let Some(pmanager_mutex) = manager_guard.as_ref() {
             let mut portal_manager = pmanager_mutex.lock().await;
             if portal_manager.tick() {
-                self.portal_cooldown
-                    .store(self.default_portal_cooldown(), Ordering::Relaxed);
+                self.reset_portal_cooldown();
                 let pos = self.pos.load();
                 let current_yaw = self.yaw.load();
                 let dimensions = self.entity_dimension.load();
@@ -1487,16 +1762,22 @@ impl Entity {
                     (target_pos.0.to_f64(), None)
                 };
 
-                // Teleport the main entity
-                caller
+                // Teleport the main entity, and all passengers recursively along with it
+                if caller
                     .clone()
-                    .teleport(teleport_pos, new_yaw, None, dest_world.clone())
-                    .await;
-
-                // Teleport all passengers recursively along with the vehicle
-                let yaw_delta = new_yaw.map(|y| y - current_yaw);
-                Self::teleport_passengers_recursive(self, teleport_pos, yaw_delta, &dest_world)
-                    .await;
+                    .teleport(
+                        teleport_pos,
+                        new_yaw,
+                        None,
+                        dest_world.clone(),
+                        TeleportCause::Portal,
+                    )
+                    .await
+                {
+                    let yaw_delta = new_yaw.map(|y| y - current_yaw);
+                    Self::teleport_passengers_recursive(self, teleport_pos, yaw_delta, &dest_world)
+                        .await;
+                }
             } else if portal_manager.ticks_in_portal == 0 {
                 should_remove = true;
             }
@@ -1518,17 +1799,23 @@ impl Entity {
             for passenger in passengers {
                 let passenger_entity = passenger.get_entity();
                 let passenger_yaw = yaw_delta.map(|delta| passenger_entity.yaw.load() + delta);
-                passenger_entity.portal_cooldown.store(
-                    passenger_entity.default_portal_cooldown(),
-                    Ordering::Relaxed,
-                );
+                passenger_entity.reset_portal_cooldown();
 
                 // Get nested passengers before teleporting
                 let nested_passengers = passenger_entity.passengers.lock().await.clone();
 
-                passenger
-                    .teleport(position, passenger_yaw, None, dest_world.clone())
-                    .await;
+                if !passenger
+                    .teleport(
+                        position,
+                        passenger_yaw,
+                        None,
+                        dest_world.clone(),
+                        TeleportCause::Portal,
+                    )
+                    .await
+                {
+                    continue;
+                }
 
                 // Recursively teleport nested passengers
                 for nested in nested_passengers {
@@ -1551,9 +1838,8 @@ impl Entity {
             return;
         }
 
-        if self.portal_cooldown.load(Ordering::Relaxed) > 0 {
-            self.portal_cooldown
-                .store(self.default_portal_cooldown(), Ordering::Relaxed);
+        if self.get_portal_cooldown() > 0 {
+            self.reset_portal_cooldown();
             return;
         }
 
@@ -1618,9 +1904,27 @@ impl Entity {
         }
     }
 
-    /// Extinguishes this entity.
-    pub fn extinguish(&self) {
+    /// Extinguishes this entity and removes the flames right away instead of on the next tick.
+    pub async fn extinguish(&self) {
         self.fire_ticks.store(0, Ordering::Relaxed);
+        self.set_on_fire(false).await;
+    }
+
+    /// The Fire Aspect level of a weapon, or 0 without a weapon.
+    #[must_use]
+    pub fn get_fire_aspect_level(item: Option<&ItemStack>) -> u8 {
+        item.map_or(0, |item| {
+            item.get_enchantment_level(&Enchantment::FIRE_ASPECT)
+                .clamp(0, i32::from(u8::MAX)) as u8
+        })
+    }
+
+    /// Sets this entity on fire after it was hit with a Fire Aspect weapon, for 4 seconds per
+    /// level. Fire immune entities are not affected.
+    pub fn set_on_fire_from_fire_aspect(&self, fire_aspect_level: u8) {
+        if fire_aspect_level > 0 && !self.entity_type.fire_immune {
+            self.set_on_fire_for(f32::from(fire_aspect_level) * 4.0);
+        }
     }
 
     pub fn set_on_fire_for(&self, seconds: f32) {
@@ -1640,16 +1944,37 @@ impl Entity {
     /// Freeze damage is dealt every 40 ticks when fully frozen
     const FREEZE_DAMAGE_INTERVAL: i32 = 40;
 
+    /// Maximum air supply (15 seconds)
+    pub const MAX_BREATH_TICKS: i32 = 300;
+
     /// Check if the entity is currently in powder snow
     pub async fn is_in_powder_snow(&self) -> bool {
         let block_pos = self.block_pos.load();
         self.world.load().get_block(&block_pos).await == &Block::POWDER_SNOW
     }
 
+    /// Check if this entity's type is in the given entity type tag
+    #[must_use]
+    pub fn is_tagged(&self, tag: &tag::Tag) -> bool {
+        self.entity_type.has_tag(tag)
+    }
+
+    /// Undead mobs are hurt by Instant Health, healed by Instant Damage and take extra damage
+    /// from Smite
+    #[must_use]
+    pub fn is_undead(&self) -> bool {
+        is_undead_type(self.entity_type)
+    }
+
+    /// Aquatic mobs take extra damage from Impaling
+    #[must_use]
+    pub fn is_aquatic(&self) -> bool {
+        is_aquatic_type(self.entity_type)
+    }
+
     /// Check if this entity type is immune to freezing
     pub fn is_freeze_immune(&self) -> bool {
-        self.entity_type
-            .has_tag(&tag::EntityType::MINECRAFT_FREEZE_IMMUNE_ENTITY_TYPES)
+        self.is_tagged(&tag::EntityType::MINECRAFT_FREEZE_IMMUNE_ENTITY_TYPES)
     }
 
     /// Ticks the frozen state of the entity.
@@ -1703,11 +2028,38 @@ impl Entity {
         self.pitch.store(pitch.clamp(-90.0, 90.0) % 360.0);
     }
 
+    /// Keeps the head from turning more than `max_head_yaw_diff` degrees away from the body,
+    /// rotating the body along with the head when it does.
+    pub fn clamp_head_rotation(&self, max_head_yaw_diff: f32) {
+        if let Some(body_yaw) = clamped_body_yaw(
+            self.head_yaw.load(),
+            self.body_yaw.load(),
+            max_head_yaw_diff,
+        ) {
+            self.body_yaw.store(body_yaw);
+        }
+    }
+
     /// Removes the `Entity` from their current `World`
     pub async fn remove(&self) {
         self.world.load().remove_entity(self).await;
     }
 
+    /// Falling blocks: the state of the falling block.
+    pub fn set_spawn_data_block_state(&self, state_id: BlockStateId) {
+        self.data.store(i32::from(state_id), Relaxed);
+    }
+
+    /// Projectiles, e.g. arrows, thrown items and fishing bobbers: the entity id of the owner.
+    pub fn set_spawn_data_entity_id(&self, entity_id: i32) {
+        self.data.store(entity_id, Relaxed);
+    }
+
+    /// Paintings and item frames: the direction the entity is facing.
+    pub fn set_spawn_data_direction(&self, direction: BlockDirection) {
+        self.data.store(i32::from(direction.to_index()), Relaxed);
+    }
+
     pub fn create_spawn_packet(&self) -> CSpawnEntity {
         let entity_loc = self.pos.load();
         let entity_vel = self.velocity.load();
@@ -1905,28 +2257,31 @@ impl Entity {
         self.set_flag(Flag::Sprinting, sprinting).await;
     }
 
-    pub fn check_fall_flying(&self) -> bool {
-        !self.on_ground.load(Relaxed)
-    }
-
     pub async fn set_fall_flying(&self, fall_flying: bool) {
         assert!(self.fall_flying.load(Relaxed) != fall_flying);
         self.fall_flying.store(fall_flying, Relaxed);
         self.set_flag(Flag::FallFlying, fall_flying).await;
     }
 
-    async fn set_flag(&self, flag: Flag, value: bool) {
-        let index = flag as u8;
-        let mut b = 0i8;
-        if value {
-            b |= 1 << index;
-        } else {
-            b &= !(1 << index);
+    pub async fn set_swimming(&self, swimming: bool) {
+        if self.swimming.swap(swimming, Relaxed) != swimming {
+            self.set_flag(Flag::Swimming, swimming).await;
         }
+    }
+
+    async fn set_flag(&self, flag: Flag, value: bool) {
+        let previous = self
+            .flags
+            .fetch_update(Relaxed, Relaxed, |flags| {
+                Some(with_flag(flags, flag, value))
+            })
+            .unwrap();
+        let flags = with_flag(previous, flag, value);
+        // All flags share one byte, so the others have to be sent along
         self.send_meta_data(&[Metadata::new(
             TrackedData::DATA_FLAGS,
             MetaDataType::Byte,
-            b,
+            flags as i8,
         )])
         .await;
     }
@@ -1975,6 +2330,10 @@ impl Entity {
             let dimension = Self::get_entity_dimensions(pose);
             self.bounding_box.store(aabb);
             self.entity_dimension.store(dimension);
+            self.world
+                .load()
+                .entity_sections
+                .update(self.entity_id, position, &aabb);
             let pose = pose as i32;
             self.send_meta_data(&[Metadata::new(
                 TrackedData::DATA_POSE,
@@ -1985,6 +2344,30 @@ impl Entity {
         }
     }
 
+    /// Vanilla: `Entity.updateSwimming()`, also switching the pose like players do. Dolphins and
+    /// drowned swim whenever they are in water, other mobs only while sprinting.
+    /// Players update their pose in [`Player::update_player_pose`] instead.
+    async fn update_swimming(&self) {
+        let swimmer =
+            self.entity_type == &EntityType::DOLPHIN || self.entity_type == &EntityType::DROWNED;
+        let swimming = should_swim(
+            swimmer || self.sprinting.load(Relaxed),
+            self.touching_water.load(Relaxed),
+            self.has_vehicle().await,
+        );
+        self.set_swimming(swimming).await;
+        let Some(pose) = swimming_pose_change(self.pose.load(), swimming) else {
+            return;
+        };
+
+        // `set_pose` keeps the old pose when the new one doesn't fit. Like players, a mob
+        // without room to stand up crouches, or keeps swimming if that doesn't fit either
+        self.set_pose(pose).await;
+        if pose == EntityPose::Standing && self.pose.load() == EntityPose::Swimming {
+            self.set_pose(EntityPose::Crouching).await;
+        }
+    }
+
     pub fn is_invulnerable_to(&self, damage_type: &DamageType) -> bool {
         *damage_type != DamageType::GENERIC_KILL
             && *damage_type != DamageType::OUT_OF_WORLD
@@ -2044,13 +2427,36 @@ impl Entity {
         }
     }
 
+    /// Moves the entity and sends its new position to all players, unless an
+    /// `EntityTeleportEvent` handler cancels it. Returns whether the entity was moved.
     async fn teleport(
         &self,
         position: Vector3<f64>,
         yaw: Option<f32>,
         pitch: Option<f32>,
         _world: Arc<World>,
-    ) {
+        cause: TeleportCause,
+    ) -> bool {
+        let world = self.world.load_full();
+        let mut position = position;
+        if let Some(server) = world.server.upgrade()
+            && let Some(entity) = world.get_entity_by_id(self.entity_id)
+        {
+            let event = server
+                .plugin_manager
+                .fire(EntityTeleportEvent::new(
+                    entity,
+                    self.pos.load(),
+                    position,
+                    cause,
+                ))
+                .await;
+            if event.cancelled {
+                return false;
+            }
+            position = event.to;
+        }
+
         // Update server-side position and bounding box
         self.set_pos(position);
         if let Some(yaw) = yaw {
@@ -2061,7 +2467,7 @@ impl Entity {
         }
         self.world
             .load()
-            .broadcast_packet_all(&CEntityPositionSync::new(
+            .broadcast_packet_all_shared(CEntityPositionSync::new(
                 self.entity_id.into(),
                 position,
                 Vector3::new(0.0, 0.0, 0.0),
@@ -2070,6 +2476,7 @@ impl Entity {
                 self.on_ground.load(Ordering::SeqCst),
             ))
             .await;
+        true
     }
 
     pub fn get_eye_pos(&self) -> Vector3<f64> {
@@ -2093,6 +2500,15 @@ impl Entity {
         !self.is_removed()
     }
 
+    /// Whether `pusher` pushes this entity away when they overlap, going by their types. Mobs,
+    /// players, boats and minecarts push each other, while e.g. items and experience orbs neither
+    /// push nor get pushed. Vanilla: `EntitySelector.pushableBy()`
+    pub fn can_be_pushed_by(&self, pusher: &Self) -> bool {
+        self.entity_id != pusher.entity_id
+            && is_pushable_type(self.entity_type)
+            && is_pushable_type(pusher.entity_type)
+    }
+
     pub async fn has_passengers(&self) -> bool {
         !self.passengers.lock().await.is_empty()
     }
@@ -2102,8 +2518,85 @@ impl Entity {
         vehicle.is_some()
     }
 
+    /// Seats `passenger` on `vehicle`, linking both sides.
+    pub async fn add_passenger(vehicle: &Arc<dyn EntityBase>, passenger: Arc<dyn EntityBase>) {
+        *passenger.get_entity().vehicle.lock().await = Some(vehicle.clone());
+        vehicle.get_entity().passengers.lock().await.push(passenger);
+    }
+
+    /// Attaches this entity's leash to `holder`, or detaches it when `None`.
+    pub async fn set_leash_holder(&self, holder: Option<Arc<dyn EntityBase>>) {
+        let holder_id = holder.as_ref().map_or(-1, |h| h.get_entity().entity_id);
+        self.leash_holder_uuid
+            .store(holder.as_ref().map(|h| h.get_entity().entity_uuid));
+        *self.leash_holder.lock().await = holder;
+        self.world
+            .load()
+            .broadcast_packet_all(&CSetEntityLink::new(self.entity_id, holder_id))
+            .await;
+    }
+
+    /// The packet attaching this entity's leash, for players that start tracking it.
+    pub async fn leash_link_packet(&self) -> Option<CSetEntityLink> {
+        self.leash_holder
+            .lock()
+            .await
+            .as_ref()
+            .map(|holder| CSetEntityLink::new(self.entity_id, holder.get_entity().entity_id))
+    }
+
+    pub async fn is_leashed(&self) -> bool {
+        self.leash_holder.lock().await.is_some()
+    }
+
+    /// Detaches the leash and drops the lead.
+    pub async fn break_leash(&self) {
+        self.set_leash_holder(None).await;
+        let world = self.world.load_full();
+        world
+            .drop_stack(&self.block_pos.load(), ItemStack::new(1, &Item::LEAD))
+            .await;
+    }
+
+    async fn tick_leash(&self) {
+        let holder = self.leash_holder.lock().await.clone();
+        let Some(holder) = holder else {
+            // The holder may not have been loaded yet when we were read from NBT. It can only
+            // hold the leash from within the leash's reach, so only the entities around us are
+            // searched.
+            if let Some(uuid) = self.leash_holder_uuid.load()
+                && let Some(holder) = self
+                    .world
+                    .load()
+                    .entity_sections
+                    .get_in_radius(self.pos.load(), MAX_LEASH_DISTANCE)
+                    .into_iter()
+                    .find(|entity| entity.get_entity().entity_uuid == uuid)
+            {
+                self.set_leash_holder(Some(holder)).await;
+            }
+            return;
+        };
+
+        let holder = holder.get_entity();
+        if !holder.is_alive() || !Arc::ptr_eq(&holder.world.load(), &self.world.load()) {
+            self.break_leash().await;
+            return;
+        }
+
+        let offset = holder.pos.load() - self.pos.load();
+        let distance = offset.length();
+        if distance > MAX_LEASH_DISTANCE {
+            self.break_leash().await;
+        } else if distance > LEASH_ELASTIC_DISTANCE {
+            // Pull harder the further the leash is stretched
+            let pull = offset.normalize() * ((distance - LEASH_ELASTIC_DISTANCE) * 0.1);
+            self.add_velocity(pull).await;
+        }
+    }
+
     pub async fn check_out_of_world(&self, dyn_self: &dyn EntityBase) {
-        if self.pos.load().y < f64::from(self.world.load().dimension.min_y) - 64.0 {
+        if is_in_void(self.pos.load().y, self.world.load().dimension.min_y) {
             dyn_self.tick_in_void(dyn_self).await;
         }
     }
@@ -2111,8 +2604,17 @@ impl Entity {
     pub async fn reset_state(&self) {
         self.pose.store(EntityPose::Standing);
         self.fall_flying.store(false, Relaxed);
-        self.extinguish();
-        self.set_on_fire(false).await;
+        self.extinguish().await;
+    }
+
+    /// Slows the entity down if `block`, which it is inside of, is one that entities get stuck in.
+    async fn get_stuck_in(&self, is_living: bool, block: &Block, state: &BlockState) {
+        let in_block = self.world.load().get_block(&self.block_pos.load()).await;
+        if let Some(multiplier) =
+            stuck_in_block_multiplier(block, self.entity_type, is_living, in_block)
+        {
+            self.slow_movement(state, multiplier).await;
+        }
     }
 
     pub async fn slow_movement(&self, state: &BlockState, multiplier: Vector3<f64>) {
@@ -2181,12 +2683,27 @@ impl NBTStorage for Entity {
                 NbtTag::List(vec![self.yaw.load().into(), self.pitch.load().into()]),
             );
             nbt.put_short("Fire", self.fire_ticks.load(Relaxed) as i16);
+            nbt.put_short("Air", self.breath_ticks.load(Relaxed) as i16);
             nbt.put_bool("OnGround", self.on_ground.load(Relaxed));
             nbt.put_bool("Invulnerable", self.invulnerable.load(Relaxed));
-            nbt.put_int("PortalCooldown", self.portal_cooldown.load(Relaxed) as i32);
+            nbt.put_int("PortalCooldown", self.get_portal_cooldown() as i32);
             if self.has_visual_fire.load(Relaxed) {
                 nbt.put_bool("HasVisualFire", true);
             }
+            if let Some(holder) = self.leash_holder_uuid.load() {
+                let uuid = holder.as_u128();
+                let mut leash = NbtCompound::new();
+                leash.put(
+                    "UUID",
+                    NbtTag::IntArray(vec![
+                        (uuid >> 96) as i32,
+                        ((uuid >> 64) & 0xFFFF_FFFF) as i32,
+                        ((uuid >> 32) & 0xFFFF_FFFF) as i32,
+                        (uuid & 0xFFFF_FFFF) as i32,
+                    ]),
+                );
+                nbt.put_component("leash", leash);
+            }
 
             // todo more...
         })
@@ -2200,7 +2717,8 @@ impl NBTStorage for Entity {
             let z = position[2].extract_double().unwrap_or(0.0);
             let pos = Vector3::new(x, y, z);
             self.set_pos(pos);
-            self.first_loaded_chunk_position.store(Some(pos.to_i32()));
+            self.first_loaded_chunk_position
+                .store(Some(self.block_pos.load().chunk_position()));
             let velocity = nbt.get_list("Motion").unwrap();
             let x = velocity[0].extract_double().unwrap_or(0.0);
             let y = velocity[1].extract_double().unwrap_or(0.0);
@@ -2213,14 +2731,26 @@ impl NBTStorage for Entity {
             self.head_yaw.store(yaw);
             self.fire_ticks
                 .store(i32::from(nbt.get_short("Fire").unwrap_or(0)), Relaxed);
+            if let Some(air) = nbt.get_short("Air") {
+                self.breath_ticks.store(i32::from(air), Relaxed);
+            }
             self.on_ground
                 .store(nbt.get_bool("OnGround").unwrap_or(false), Relaxed);
             self.invulnerable
                 .store(nbt.get_bool("Invulnerable").unwrap_or(false), Relaxed);
-            self.portal_cooldown
-                .store(nbt.get_int("PortalCooldown").unwrap_or(0) as u32, Relaxed);
+            self.set_portal_cooldown(nbt.get_int("PortalCooldown").unwrap_or(0) as u32);
             self.has_visual_fire
                 .store(nbt.get_bool("HasVisualFire").unwrap_or(false), Relaxed);
+            if let Some([a, b, c, d]) = nbt
+                .get_compound("leash")
+                .and_then(|leash| leash.get_int_array("UUID"))
+            {
+                let uuid = (u128::from(*a as u32) << 96)
+                    | (u128::from(*b as u32) << 64)
+                    | (u128::from(*c as u32) << 32)
+                    | u128::from(*d as u32);
+                self.leash_holder_uuid.store(Some(Uuid::from_u128(uuid)));
+            }
             // todo more...
         })
     }
@@ -2236,13 +2766,20 @@ impl EntityBase for Entity {
             self.update_last_pos();
             self.tick_portal(&caller).await;
             self.update_fluid_state(&caller).await;
+            if caller.get_player().is_none() {
+                self.update_swimming().await;
+            }
             self.check_out_of_world(&*caller).await;
+            self.tick_leash().await;
+            if self.touching_water.load(Ordering::Relaxed) {
+                self.extinguish().await;
+            }
             let fire_ticks = self.fire_ticks.load(Ordering::Relaxed);
             if fire_ticks > 0 {
                 if self.entity_type.fire_immune {
                     self.fire_ticks.store(fire_ticks - 4, Ordering::Relaxed);
                     if self.fire_ticks.load(Ordering::Relaxed) < 0 {
-                        self.extinguish();
+                        self.extinguish().await;
                     }
                 } else {
                     if fire_ticks % 20 == 0 {
@@ -2266,12 +2803,13 @@ impl EntityBase for Entity {
         yaw: Option<f32>,
         pitch: Option<f32>,
         world: Arc<World>,
+        cause: TeleportCause,
     ) -> TeleportFuture {
         // TODO: handle world change
         Box::pin(async move {
             self.get_entity()
-                .teleport(position, yaw, pitch, world)
-                .await;
+                .teleport(position, yaw, pitch, world, cause)
+                .await
         })
     }
 
@@ -2341,3 +2879,486 @@ pub enum Flag {
     /// Indicates if the entity is flying due to a fall.
     FallFlying = 7,
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::entity::EntityType;
+
+    use pumpkin_data::entity::EntityPose;
+
+    use pumpkin_data::Enchantment;
+    use pumpkin_data::item::Item;
+    use pumpkin_world::item::ItemStack;
+
+    use arc_swap::ArcSwap;
+    use pumpkin_data::block_properties::{
+        BlockProperties, OakStairsLikeProperties, ResinBrickSlabLikeProperties, SlabType,
+    };
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::{Block, BlockState};
+    use pumpkin_util::math::boundingbox::{BoundingBox, EntityDimensions};
+    use pumpkin_util::math::position::BlockPos;
+    use pumpkin_util::math::vector2::Vector2;
+    use pumpkin_util::math::vector3::Vector3;
+    use pumpkin_util::math::wrap_degrees;
+    use pumpkin_util::world_seed::Seed;
+    use pumpkin_world::world_info::LevelData;
+    use std::sync::Arc;
+    use std::sync::atomic::Ordering::Relaxed;
+    use tempfile::tempdir;
+
+    use super::{
+        Entity, EntityBase, Flag, MAX_LEASH_DISTANCE, TeleportCause, VOID_DAMAGE, VOID_DEPTH,
+        VoidAction, clamped_body_yaw, collide_movement, is_aquatic_type, is_in_void,
+        is_undead_type, push_apart, should_swim, stuck_in_block_multiplier, swimming_pose_change,
+        with_flag,
+    };
+    use crate::entity::living::LivingEntity;
+    use crate::entity::player::test::test_player;
+    use crate::entity::r#type::from_type;
+    use crate::plugin::entity::entity_teleport::EntityTeleportEvent;
+    use crate::plugin::test::cancel_all;
+    use crate::server::test::test_server;
+    use crate::world::World;
+    use crate::world::test::{load_empty_chunk, test_world, test_world_on};
+    use pumpkin_protocol::java::client::play::CEntityPositionSync;
+
+    fn slab(slab_type: SlabType) -> &'static BlockState {
+        let mut props = ResinBrickSlabLikeProperties::default(&Block::OAK_SLAB);
+        props.r#type = slab_type;
+        BlockState::from_id(props.to_state_id(&Block::OAK_SLAB))
+    }
+
+    /// Collects the collisions like `World::get_block_collisions` does, with only `blocks` in
+    /// the world.
+    fn block_collisions(
+        blocks: &[(BlockPos, &'static BlockState)],
+        bounding_box: &BoundingBox,
+    ) -> (Vec<BoundingBox>, Vec<(usize, BlockPos)>) {
+        let mut collisions = Vec::new();
+        let mut positions = Vec::new();
+        for (pos, state) in blocks {
+            if World::check_collision(bounding_box, *pos, state, true, |shape| {
+                collisions.push(*shape);
+            }) {
+                positions.push((collisions.len(), *pos));
+            }
+        }
+        (collisions, positions)
+    }
+
+    /// Lets a player fall from `pos` onto `blocks` for a few seconds. Returns the height they
+    /// came to rest at and whether they are on the ground.
+    fn drop_player(
+        blocks: &[(BlockPos, &'static BlockState)],
+        mut pos: Vector3<f64>,
+    ) -> (f64, bool) {
+        let dimensions = EntityDimensions::new(0.6, 1.8, 1.62);
+        let mut velocity_y = 0.0;
+        let mut on_ground = false;
+        for _ in 0..100 {
+            velocity_y = (velocity_y - 0.08) * 0.98;
+            let bounding_box = BoundingBox::new_from_pos(pos.x, pos.y, pos.z, &dimensions);
+            let movement = Vector3::new(0.0, velocity_y, 0.0);
+            let (collisions, positions) = block_collisions(blocks, &bounding_box.stretch(movement));
+            let collision = collide_movement(&bounding_box, movement, &collisions, positions);
+            on_ground = collision.supporting_block_pos.is_some();
+            if on_ground {
+                velocity_y = 0.0;
+            }
+            pos += collision.movement;
+        }
+        (pos.y, on_ground)
+    }
+
+    #[test]
+    fn entity_stands_on_half_blocks() {
+        let origin = BlockPos::new(0, 64, 0);
+        let stairs = BlockState::from_id(
+            OakStairsLikeProperties::default(&Block::OAK_STAIRS).to_state_id(&Block::OAK_STAIRS),
+        );
+        // The default stairs face north, so their upper step covers the northern half
+        let cases = [
+            (slab(SlabType::Bottom), Vector3::new(0.5, 0.0, 0.5), 64.5),
+            (slab(SlabType::Top), Vector3::new(0.5, 0.0, 0.5), 65.0),
+            (stairs, Vector3::new(0.5, 0.0, 0.25), 65.0),
+            (stairs, Vector3::new(0.5, 0.0, 0.9), 64.5),
+        ];
+        for (state, offset, expected) in cases {
+            for start in [65.0, 66.3, 70.123] {
+                let (y, on_ground) =
+                    drop_player(&[(origin, state)], offset.add_raw(0.0, start, 0.0));
+                assert!(
+                    (y - expected).abs() < 1e-7,
+                    "landed at {y} instead of {expected}"
+                );
+                assert!(on_ground);
+            }
+        }
+    }
+
+    #[test]
+    fn entity_sunk_into_slab_by_rounding_error_is_pushed_out() {
+        let (y, on_ground) = drop_player(
+            &[(BlockPos::new(0, 64, 0), slab(SlabType::Bottom))],
+            Vector3::new(0.5, 64.499_999_999_99, 0.5),
+        );
+        assert!((y - 64.5).abs() < 1e-9, "fell to {y}");
+        assert!(on_ground);
+    }
+
+    #[test]
+    fn hitting_ceiling_is_not_ground() {
+        let dimensions = EntityDimensions::new(0.6, 1.8, 1.62);
+        // The head is right below an upper slab
+        let bounding_box = BoundingBox::new_from_pos(0.5, 62.7, 0.5, &dimensions);
+        let jump = Vector3::new(0.0, 0.42, 0.0);
+        let (collisions, positions) = block_collisions(
+            &[(BlockPos::new(0, 64, 0), slab(SlabType::Top))],
+            &bounding_box.stretch(jump),
+        );
+        let collision = collide_movement(&bounding_box, jump, &collisions, positions);
+        assert!(collision.movement.y < 1e-6);
+        assert!(collision.supporting_block_pos.is_none());
+    }
+
+    #[test]
+    fn body_follows_head_turned_beyond_limit() {
+        let body_yaw = clamped_body_yaw(120.0, 0.0, 75.0).unwrap();
+        assert!((body_yaw - 45.0).abs() < 1.0e-4);
+
+        // Turning the other way across the -180/180 seam
+        let body_yaw = clamped_body_yaw(-170.0, 100.0, 75.0).unwrap();
+        assert!((wrap_degrees(-170.0 - body_yaw) - 75.0).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn body_stays_when_head_within_limit() {
+        assert!(clamped_body_yaw(70.0, 0.0, 75.0).is_none());
+        assert!(clamped_body_yaw(-179.0, 179.0, 75.0).is_none());
+    }
+
+    #[test]
+    fn living_entity_takes_void_damage() {
+        assert!(is_in_void(-129.0, -64));
+        assert_eq!(
+            VoidAction::for_entity(true),
+            VoidAction::Damage(VOID_DAMAGE)
+        );
+    }
+
+    #[test]
+    fn non_living_entity_is_removed() {
+        assert_eq!(VoidAction::for_entity(false), VoidAction::Remove);
+    }
+
+    #[test]
+    fn void_starts_below_world() {
+        assert!(!is_in_void(-64.0, -64));
+        assert!(!is_in_void(-128.0, -64));
+        assert!(is_in_void(-128.5, -64));
+    }
+
+    #[test]
+    fn zombie_is_undead() {
+        assert!(is_undead_type(&EntityType::ZOMBIE));
+        assert!(!is_aquatic_type(&EntityType::ZOMBIE));
+    }
+
+    #[test]
+    fn squid_is_aquatic() {
+        assert!(is_aquatic_type(&EntityType::SQUID));
+        assert!(!is_undead_type(&EntityType::SQUID));
+    }
+
+    #[test]
+    fn sprinting_in_water_starts_swimming() {
+        assert!(swimming_pose_change(EntityPose::Standing, true) == Some(EntityPose::Swimming));
+        assert!(swimming_pose_change(EntityPose::Swimming, true).is_none());
+    }
+
+    #[test]
+    fn swimming_sets_flag_bit_4() {
+        let on_fire = with_flag(0, Flag::OnFire, true);
+        let swimming = with_flag(on_fire, Flag::Swimming, should_swim(true, true, false));
+        assert_eq!(swimming, 0b1_0001);
+
+        // Leaving water clears the swimming bit only
+        let left_water = with_flag(swimming, Flag::Swimming, should_swim(true, false, false));
+        assert_eq!(left_water, on_fire);
+        assert!(!should_swim(true, true, true));
+    }
+
+    #[test]
+    fn leaving_water_stops_swimming() {
+        assert!(swimming_pose_change(EntityPose::Swimming, false) == Some(EntityPose::Standing));
+        assert!(swimming_pose_change(EntityPose::Sleeping, false).is_none());
+        assert!(swimming_pose_change(EntityPose::Sleeping, true).is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn mob_stands_up_once_there_is_room() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        let chunk = load_empty_chunk(&world, Vector2::new(0, 0));
+        // Leaves room to crouch below, but not to stand
+        chunk
+            .section
+            .set_block_absolute_y(0, 65, 0, slab(SlabType::Top).id);
+
+        let zombie = Entity::new(world, Vector3::new(0.5, 64.0, 0.5), &EntityType::ZOMBIE);
+        zombie.set_pose(EntityPose::Swimming).await;
+        zombie.update_swimming().await;
+        assert!(zombie.pose.load() == EntityPose::Crouching);
+
+        // Staying under the slab doesn't change anything
+        zombie.update_swimming().await;
+        assert!(zombie.pose.load() == EntityPose::Crouching);
+
+        chunk
+            .section
+            .set_block_absolute_y(0, 65, 0, Block::AIR.default_state.id);
+        zombie.update_swimming().await;
+        assert!(zombie.pose.load() == EntityPose::Standing);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn mob_below_the_world_takes_void_damage() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        let y = f64::from(Dimension::OVERWORLD.min_y) - VOID_DEPTH;
+
+        let zombie = from_type(
+            &EntityType::ZOMBIE,
+            Vector3::new(0.5, y, 0.5),
+            &world,
+            uuid::Uuid::new_v4(),
+        )
+        .await;
+        let living = zombie.get_living_entity().unwrap();
+        let health = living.health.load();
+
+        // Right at the void depth it is still safe
+        zombie
+            .get_entity()
+            .check_out_of_world(zombie.as_ref())
+            .await;
+        assert_eq!(living.health.load(), health);
+
+        zombie.get_entity().set_pos(Vector3::new(0.5, y - 1.0, 0.5));
+        zombie
+            .get_entity()
+            .check_out_of_world(zombie.as_ref())
+            .await;
+        assert_eq!(living.health.load(), health - VOID_DAMAGE);
+        // Void damage ignores the hurt cooldown
+        zombie
+            .get_entity()
+            .check_out_of_world(zombie.as_ref())
+            .await;
+        assert_eq!(living.health.load(), health - 2.0 * VOID_DAMAGE);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn loaded_leash_finds_its_holder_and_snaps_when_stretched() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        load_empty_chunk(&world, Vector2::new(0, 0));
+
+        let holder = from_type(
+            &EntityType::ZOMBIE,
+            Vector3::new(2.5, 64.0, 0.5),
+            &world,
+            uuid::Uuid::new_v4(),
+        )
+        .await;
+        world.spawn_entity(holder.clone()).await;
+        let holder = holder.get_entity();
+        let leashed = Entity::new(world, Vector3::new(0.5, 64.0, 0.5), &EntityType::PIG);
+
+        // Holders that aren't loaded are waited for
+        leashed.leash_holder_uuid.store(Some(uuid::Uuid::new_v4()));
+        leashed.tick_leash().await;
+        assert!(!leashed.is_leashed().await);
+
+        leashed.leash_holder_uuid.store(Some(holder.entity_uuid));
+        leashed.tick_leash().await;
+        let link = leashed.leash_link_packet().await.unwrap();
+        assert_eq!(link.attached_entity_id, leashed.entity_id);
+        assert_eq!(link.holding_entity_id, holder.entity_id);
+
+        holder.set_pos(Vector3::new(1.5 + MAX_LEASH_DISTANCE, 64.0, 0.5));
+        leashed.tick_leash().await;
+        assert!(!leashed.is_leashed().await);
+        assert!(leashed.leash_holder_uuid.load().is_none());
+        assert!(leashed.leash_link_packet().await.is_none());
+    }
+
+    #[test]
+    fn fire_aspect_level_is_read_from_weapon() {
+        let mut sword = ItemStack::new(1, &Item::DIAMOND_SWORD);
+        assert_eq!(Entity::get_fire_aspect_level(Some(&sword)), 0);
+
+        sword.enchant(&Enchantment::FIRE_ASPECT, 2);
+        assert_eq!(Entity::get_fire_aspect_level(Some(&sword)), 2);
+        assert_eq!(Entity::get_fire_aspect_level(None), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn mob_sinks_into_powder_snow_once_its_feet_are_in_it() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        let chunk = load_empty_chunk(&world, Vector2::new(0, 0));
+        let powder_snow = Block::POWDER_SNOW.default_state;
+        // Only the head touches the powder snow
+        chunk.section.set_block_absolute_y(0, 65, 0, powder_snow.id);
+
+        let zombie = Entity::new(world, Vector3::new(0.5, 64.0, 0.5), &EntityType::ZOMBIE);
+        zombie
+            .get_stuck_in(true, &Block::POWDER_SNOW, powder_snow)
+            .await;
+        assert_eq!(zombie.movement_multiplier.load(), Vector3::default());
+
+        chunk.section.set_block_absolute_y(0, 64, 0, powder_snow.id);
+        zombie
+            .get_stuck_in(true, &Block::POWDER_SNOW, powder_snow)
+            .await;
+        assert_eq!(
+            zombie.movement_multiplier.load(),
+            Vector3::new(0.9, 1.5, 0.9)
+        );
+    }
+
+    #[test]
+    fn entities_are_not_stuck_in_solid_blocks() {
+        assert_eq!(
+            stuck_in_block_multiplier(&Block::STONE, &EntityType::ZOMBIE, true, &Block::STONE),
+            None
+        );
+    }
+
+    #[test]
+    fn foxes_are_not_slowed_by_sweet_berry_bushes() {
+        assert!(
+            stuck_in_block_multiplier(
+                &Block::SWEET_BERRY_BUSH,
+                &EntityType::FOX,
+                true,
+                &Block::AIR
+            )
+            .is_none()
+        );
+        assert!(
+            stuck_in_block_multiplier(
+                &Block::SWEET_BERRY_BUSH,
+                &EntityType::ITEM,
+                false,
+                &Block::AIR
+            )
+            .is_none()
+        );
+        assert!(
+            stuck_in_block_multiplier(
+                &Block::SWEET_BERRY_BUSH,
+                &EntityType::ZOMBIE,
+                true,
+                &Block::SWEET_BERRY_BUSH
+            )
+            .is_some()
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn boats_and_minecarts_push_and_get_pushed() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        let at = |x| Vector3::new(x, 64.0, 0.0);
+
+        let boat = Entity::new(world.clone(), at(0.0), &EntityType::OAK_BOAT);
+        let minecart = Entity::new(world.clone(), at(0.3), &EntityType::MINECART);
+        let zombie = LivingEntity::new(Entity::new(world.clone(), at(0.6), &EntityType::ZOMBIE));
+        let item = Entity::new(world, at(0.3), &EntityType::ITEM);
+
+        assert!(boat.can_be_pushed_by(&minecart) && minecart.can_be_pushed_by(&boat));
+        assert!(boat.can_be_pushed_by(&zombie.entity) && zombie.entity.can_be_pushed_by(&boat));
+        assert!(!boat.can_be_pushed_by(&boat));
+        assert!(!boat.can_be_pushed_by(&item) && !item.can_be_pushed_by(&minecart));
+
+        // Both sides of the pair move apart
+        assert!(push_apart(&zombie, &minecart).await);
+        assert!(minecart.velocity.load().x < 0.0);
+        assert!(zombie.entity.velocity.load().x > 0.0);
+        assert!(push_apart(&boat, &minecart).await);
+        assert!(!push_apart(&item, &boat).await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cancelled_teleport_leaves_the_entity_in_place() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        load_empty_chunk(&world, Vector2::new(0, 0));
+        let (_player, mut connection) = test_player(&world, "Steve").await;
+        let start = Vector3::new(4.5, 64.0, 4.5);
+        let zombie = from_type(&EntityType::ZOMBIE, start, &world, uuid::Uuid::new_v4()).await;
+        world.spawn_entity(zombie.clone()).await;
+        connection.received_packet_ids().await;
+        cancel_all::<EntityTeleportEvent>(&server).await;
+
+        let teleported = zombie
+            .clone()
+            .teleport(
+                Vector3::new(8.5, 64.0, 8.5),
+                None,
+                None,
+                world.clone(),
+                TeleportCause::Command,
+            )
+            .await;
+
+        assert!(!teleported);
+        assert_eq!(zombie.get_entity().pos.load(), start);
+        assert!(!connection.received::<CEntityPositionSync>().await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn concurrent_flag_changes_keep_each_other() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        let entity = Arc::new(Entity::new(
+            world,
+            Vector3::new(0.5, 64.0, 0.5),
+            &EntityType::ZOMBIE,
+        ));
+
+        let flags = [
+            Flag::OnFire,
+            Flag::Sneaking,
+            Flag::Sprinting,
+            Flag::Swimming,
+            Flag::Invisible,
+            Flag::Glowing,
+        ];
+        let tasks: Vec<_> = flags
+            .into_iter()
+            .map(|flag| {
+                let entity = entity.clone();
+                tokio::spawn(async move { entity.set_flag(flag, true).await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let expected = flags
+            .into_iter()
+            .fold(0, |flags, flag| with_flag(flags, flag, true));
+        assert_eq!(entity.flags.load(Relaxed), expected);
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::{Arc, Weak};
+
+use pumpkin_data::{
+    entity::EntityType,
+    tag::{self, Taggable},
+    tracked_data::TrackedId,
+};
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_world::item::ItemStack;
+
+use crate::entity::{
+    Entity, EntityBaseFuture, NBTStorage, NbtFuture,
+    ai::goal::{
+        follow_owner::FollowOwnerGoal, look_around::LookAroundGoal,
+        look_at_entity::LookAtEntityGoal, sit::SitGoal, swim::SwimGoal,
+        wander_around::WanderAroundGoal,
+    },
+    mob::{Mob, MobEntity},
+    passive::tameable::{Collar, Tameable},
+    player::Player,
+};
+
+const TAME_CHANCE: u32 = 3;
+
+/// The generated tracked data only knows the collar index of wolves, cats keep theirs after the
+/// variant, sleeping and head down data.
+const DATA_CAT_COLLAR_COLOR: TrackedId = TrackedId {
+    latest: 22,
+    v1_21_7: 22,
+};
+
+pub struct CatEntity {
+    pub mob_entity: MobEntity,
+    pub tameable: Tameable,
+    pub collar: Collar,
+}
+
+impl CatEntity {
+    pub async fn new(entity: Entity) -> Arc<Self> {
+        let mob_entity = MobEntity::new(entity);
+        let cat = Self {
+            mob_entity,
+            tameable: Tameable::default(),
+            collar: Collar::new(DATA_CAT_COLLAR_COLOR),
+        };
+        let mob_arc = Arc::new(cat);
+        let mob_weak: Weak<dyn Mob> = {
+            let mob_arc: Arc<dyn Mob> = mob_arc.clone();
+            Arc::downgrade(&mob_arc)
+        };
+
+        {
+            let mut goal_selector = mob_arc.mob_entity.goals_selector.lock().await;
+
+            goal_selector.add_goal(1, Box::new(SwimGoal::default()));
+            goal_selector.add_goal(2, Box::new(SitGoal::default()));
+            goal_selector.add_goal(6, Box::new(FollowOwnerGoal::new(1.0, 10.0, 5.0)));
+            goal_selector.add_goal(11, Box::new(WanderAroundGoal::new(0.8)));
+            goal_selector.add_goal(
+                12,
+                LookAtEntityGoal::with_default(mob_weak, &EntityType::PLAYER, 10.0),
+            );
+            goal_selector.add_goal(12, Box::new(LookAroundGoal::default()));
+        };
+
+        mob_arc
+    }
+}
+
+impl NBTStorage for CatEntity {
+    fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity.living_entity.entity.write_nbt(nbt).await;
+            self.tameable.write_nbt(nbt);
+            self.collar.write_nbt(nbt);
+        })
+    }
+
+    fn read_nbt_non_mut<'a>(&'a self, nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity
+                .living_entity
+                .entity
+                .read_nbt_non_mut(nbt)
+                .await;
+            self.tameable.read_nbt(nbt);
+            self.collar.read_nbt(nbt);
+        })
+    }
+}
+
+impl Mob for CatEntity {
+    fn get_mob_entity(&self) -> &MobEntity {
+        &self.mob_entity
+    }
+
+    fn get_mob_tameable(&self) -> Option<&Tameable> {
+        Some(&self.tameable)
+    }
+
+    fn mob_init_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async {
+            let entity = &self.mob_entity.living_entity.entity;
+            self.tameable.send_meta_data(entity).await;
+            self.collar.send_meta_data(entity).await;
+        })
+    }
+
+    fn mob_interact<'a>(
+        &'a self,
+        player: &'a Player,
+        item_stack: &'a mut ItemStack,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            if self.tameable.is_tamed() {
+                if self.tameable.is_owner(&player.living_entity.entity)
+                    && self
+                        .collar
+                        .try_dye(&self.mob_entity.living_entity.entity, player, item_stack)
+                        .await
+                {
+                    return true;
+                }
+                return self.tameable.toggle_sitting(&self.mob_entity, player).await;
+            }
+
+            if item_stack.item.has_tag(&tag::Item::MINECRAFT_CAT_FOOD) {
+                self.tameable
+                    .try_tame(&self.mob_entity, player, item_stack, TAME_CHANCE)
+                    .await;
+                return true;
+            }
+
+            false
+        })
+    }
+}
@@ -0,0 +1,317 @@
+use std::{
+    any::Any,
+    array::from_fn,
+    pin::Pin,
+    sync::{
+        Arc, Weak,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use pumpkin_data::{data_component_impl::EquipmentSlot, entity::EntityType, item::Item};
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+use pumpkin_world::{
+    inventory::{Clearable, Inventory, InventoryFuture, split_stack},
+    item::ItemStack,
+};
+use tokio::sync::Mutex;
+
+use crate::entity::{
+    Entity, EntityBaseFuture, NBTStorage, NbtFuture,
+    ai::goal::{look_around::LookAroundGoal, look_at_entity::LookAtEntityGoal},
+    mob::{Mob, MobEntity},
+    player::Player,
+};
+
+/// A horse, donkey, mule, skeleton horse or zombie horse.
+///
+/// Which equipment a horse accepts is decided by its entity type, only donkeys and mules can carry a chest.
+pub struct HorseEntity {
+    pub mob_entity: MobEntity,
+    pub has_chest: AtomicBool,
+    /// The saddle, body armor and chest contents, in that order
+    pub equipment: Arc<HorseInventory>,
+    this: Weak<Self>,
+}
+
+impl HorseEntity {
+    pub const CHEST_COLUMNS: u8 = 5;
+
+    pub async fn new(entity: Entity) -> Arc<Self> {
+        let mob_entity = MobEntity::new(entity);
+        let mob_arc = Arc::new_cyclic(|this: &Weak<Self>| Self {
+            mob_entity,
+            has_chest: AtomicBool::new(false),
+            equipment: Arc::new(HorseInventory::new(this.clone())),
+            this: this.clone(),
+        });
+        let mob_weak: Weak<dyn Mob> = {
+            let mob_arc: Arc<dyn Mob> = mob_arc.clone();
+            Arc::downgrade(&mob_arc)
+        };
+
+        {
+            let mut goal_selector = mob_arc.mob_entity.goals_selector.lock().await;
+
+            // TODO
+            goal_selector.add_goal(
+                6,
+                LookAtEntityGoal::with_default(mob_weak, &EntityType::PLAYER, 6.0),
+            );
+            goal_selector.add_goal(7, Box::new(LookAroundGoal::default()));
+        };
+
+        mob_arc
+    }
+
+    #[must_use]
+    pub fn can_have_chest(&self) -> bool {
+        let entity_type = self.mob_entity.living_entity.entity.entity_type;
+        entity_type == &EntityType::DONKEY || entity_type == &EntityType::MULE
+    }
+
+    /// The amount of chest columns shown in the horse screen.
+    #[must_use]
+    pub fn inventory_columns(&self) -> u8 {
+        if self.has_chest.load(Ordering::Relaxed) {
+            Self::CHEST_COLUMNS
+        } else {
+            0
+        }
+    }
+
+    /// Applies the saddle and body armor of the inventory to the horse and shows them to other players.
+    pub async fn update_equipment(&self) {
+        let living_entity = &self.mob_entity.living_entity;
+        let saddle = self
+            .equipment
+            .get_stack(HorseInventory::SADDLE_SLOT)
+            .await
+            .lock()
+            .await
+            .clone();
+        let armor = self
+            .equipment
+            .get_stack(HorseInventory::ARMOR_SLOT)
+            .await
+            .lock()
+            .await
+            .clone();
+
+        {
+            let mut equipment = living_entity.entity_equipment.lock().await;
+            equipment.put(&EquipmentSlot::SADDLE, saddle.clone()).await;
+            equipment.put(&EquipmentSlot::BODY, armor.clone()).await;
+        }
+
+        living_entity
+            .send_equipment_changes(&[
+                (EquipmentSlot::SADDLE, saddle),
+                (EquipmentSlot::BODY, armor),
+            ])
+            .await;
+    }
+}
+
+impl NBTStorage for HorseEntity {
+    fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity.living_entity.entity.write_nbt(nbt).await;
+
+            let mut equipment = NbtCompound::new();
+            for (slot, name) in [
+                (HorseInventory::SADDLE_SLOT, "saddle"),
+                (HorseInventory::ARMOR_SLOT, "body"),
+            ] {
+                let stack = self.equipment.items[slot].lock().await;
+                if !stack.is_empty() {
+                    let mut item = NbtCompound::new();
+                    stack.write_item_stack(&mut item);
+                    equipment.put_component(name, item);
+                }
+            }
+            nbt.put_component("equipment", equipment);
+
+            if self.can_have_chest() {
+                let has_chest = self.has_chest.load(Ordering::Relaxed);
+                nbt.put_bool("ChestedHorse", has_chest);
+                if has_chest {
+                    let mut items = Vec::new();
+                    for (i, stack) in self.equipment.items[HorseInventory::CHEST_START..]
+                        .iter()
+                        .enumerate()
+                    {
+                        let stack = stack.lock().await;
+                        if !stack.is_empty() {
+                            let mut item = NbtCompound::new();
+                            item.put_byte("Slot", i as i8);
+                            stack.write_item_stack(&mut item);
+                            items.push(NbtTag::Compound(item));
+                        }
+                    }
+                    nbt.put_list("Items", items);
+                }
+            }
+        })
+    }
+
+    fn read_nbt_non_mut<'a>(&'a self, nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity
+                .living_entity
+                .entity
+                .read_nbt_non_mut(nbt)
+                .await;
+
+            if let Some(equipment) = nbt.get_compound("equipment") {
+                for (slot, name) in [
+                    (HorseInventory::SADDLE_SLOT, "saddle"),
+                    (HorseInventory::ARMOR_SLOT, "body"),
+                ] {
+                    if let Some(stack) = equipment
+                        .get_compound(name)
+                        .and_then(ItemStack::read_item_stack)
+                    {
+                        *self.equipment.items[slot].lock().await = stack;
+                    }
+                }
+            }
+
+            if self.can_have_chest() && nbt.get_bool("ChestedHorse") == Some(true) {
+                self.has_chest.store(true, Ordering::Relaxed);
+                for tag in nbt.get_list("Items").unwrap_or_default() {
+                    if let Some(item) = tag.extract_compound()
+                        && let Some(slot) = item.get_byte("Slot")
+                        && let Some(stack) = self
+                            .equipment
+                            .items
+                            .get(HorseInventory::CHEST_START + slot as usize)
+                        && let Some(item_stack) = ItemStack::read_item_stack(item)
+                    {
+                        *stack.lock().await = item_stack;
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl Mob for HorseEntity {
+    fn get_mob_entity(&self) -> &MobEntity {
+        &self.mob_entity
+    }
+
+    fn get_mob_horse(self: Arc<Self>) -> Option<Arc<Self>> {
+        Some(self)
+    }
+
+    fn mob_interact<'a>(
+        &'a self,
+        player: &'a Player,
+        item_stack: &'a mut ItemStack,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            if item_stack.item == &Item::CHEST
+                && self.can_have_chest()
+                && !self.has_chest.load(Ordering::Relaxed)
+            {
+                self.has_chest.store(true, Ordering::Relaxed);
+                item_stack.decrement_unless_creative(player.gamemode.load(), 1);
+                return true;
+            }
+
+            if !player.living_entity.entity.sneaking.load(Ordering::Relaxed) {
+                return false;
+            }
+            let Some(horse) = self.this.upgrade() else {
+                return false;
+            };
+            player.send_open_horse_inventory(horse).await;
+            true
+        })
+    }
+}
+
+/// The inventory of a horse. Slot 0 holds the saddle, slot 1 the body armor and the
+/// remaining slots the chest of donkeys and mules.
+pub struct HorseInventory {
+    pub items: [Arc<Mutex<ItemStack>>; Self::INVENTORY_SIZE],
+    horse: Weak<HorseEntity>,
+}
+
+impl HorseInventory {
+    pub const SADDLE_SLOT: usize = 0;
+    pub const ARMOR_SLOT: usize = 1;
+    pub const CHEST_START: usize = 2;
+    pub const INVENTORY_SIZE: usize = Self::CHEST_START + HorseEntity::CHEST_COLUMNS as usize * 3;
+
+    fn new(horse: Weak<HorseEntity>) -> Self {
+        Self {
+            items: from_fn(|_| Arc::new(Mutex::new(ItemStack::EMPTY.clone()))),
+            horse,
+        }
+    }
+}
+
+impl Inventory for HorseInventory {
+    fn size(&self) -> usize {
+        self.items.len()
+    }
+
+    fn is_empty(&self) -> InventoryFuture<'_, bool> {
+        Box::pin(async move {
+            for slot in &self.items {
+                if !slot.lock().await.is_empty() {
+                    return false;
+                }
+            }
+
+            true
+        })
+    }
+
+    fn get_stack(&self, slot: usize) -> InventoryFuture<'_, Arc<Mutex<ItemStack>>> {
+        Box::pin(async move { self.items[slot].clone() })
+    }
+
+    fn remove_stack(&self, slot: usize) -> InventoryFuture<'_, ItemStack> {
+        Box::pin(async move {
+            let mut removed = ItemStack::EMPTY.clone();
+            let mut guard = self.items[slot].lock().await;
+            std::mem::swap(&mut removed, &mut *guard);
+            removed
+        })
+    }
+
+    fn remove_stack_specific(&self, slot: usize, amount: u8) -> InventoryFuture<'_, ItemStack> {
+        Box::pin(async move { split_stack(&self.items, slot, amount).await })
+    }
+
+    fn set_stack(&self, slot: usize, stack: ItemStack) -> InventoryFuture<'_, ()> {
+        Box::pin(async move {
+            *self.items[slot].lock().await = stack;
+        })
+    }
+
+    fn on_close(&self) -> InventoryFuture<'_, ()> {
+        Box::pin(async move {
+            if let Some(horse) = self.horse.upgrade() {
+                horse.update_equipment().await;
+            }
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl Clearable for HorseInventory {
+    fn clear(&self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            for item in &self.items {
+                *item.lock().await = ItemStack::EMPTY.clone();
+            }
+        })
+    }
+}
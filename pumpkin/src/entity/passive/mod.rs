@@ -1,3 +1,7 @@
+pub mod cat;
+pub mod horse;
 pub mod iron_golem;
+pub mod parrot;
 pub mod snow_golem;
+pub mod tameable;
 pub mod wolf;
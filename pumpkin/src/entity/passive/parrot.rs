@@ -0,0 +1,118 @@
+use std::sync::{Arc, Weak};
+
+use pumpkin_data::{
+    entity::EntityType,
+    tag::{self, Taggable},
+};
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_world::item::ItemStack;
+
+use crate::entity::{
+    Entity, EntityBaseFuture, NBTStorage, NbtFuture,
+    ai::goal::{
+        follow_owner::FollowOwnerGoal, look_around::LookAroundGoal,
+        look_at_entity::LookAtEntityGoal, sit::SitGoal, swim::SwimGoal,
+        wander_around::WanderAroundGoal,
+    },
+    mob::{Mob, MobEntity},
+    passive::tameable::Tameable,
+    player::Player,
+};
+
+const TAME_CHANCE: u32 = 10;
+
+pub struct ParrotEntity {
+    pub mob_entity: MobEntity,
+    pub tameable: Tameable,
+}
+
+impl ParrotEntity {
+    pub async fn new(entity: Entity) -> Arc<Self> {
+        let mob_entity = MobEntity::new(entity);
+        let parrot = Self {
+            mob_entity,
+            tameable: Tameable::default(),
+        };
+        let mob_arc = Arc::new(parrot);
+        let mob_weak: Weak<dyn Mob> = {
+            let mob_arc: Arc<dyn Mob> = mob_arc.clone();
+            Arc::downgrade(&mob_arc)
+        };
+
+        {
+            let mut goal_selector = mob_arc.mob_entity.goals_selector.lock().await;
+
+            // TODO: fly instead of walking
+            goal_selector.add_goal(0, Box::new(SwimGoal::default()));
+            goal_selector.add_goal(
+                1,
+                LookAtEntityGoal::with_default(mob_weak, &EntityType::PLAYER, 8.0),
+            );
+            goal_selector.add_goal(2, Box::new(SitGoal::default()));
+            goal_selector.add_goal(2, Box::new(FollowOwnerGoal::new(1.0, 10.0, 1.0)));
+            goal_selector.add_goal(2, Box::new(WanderAroundGoal::new(1.0)));
+            goal_selector.add_goal(3, Box::new(LookAroundGoal::default()));
+        };
+
+        mob_arc
+    }
+}
+
+impl NBTStorage for ParrotEntity {
+    fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity.living_entity.entity.write_nbt(nbt).await;
+            self.tameable.write_nbt(nbt);
+        })
+    }
+
+    fn read_nbt_non_mut<'a>(&'a self, nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity
+                .living_entity
+                .entity
+                .read_nbt_non_mut(nbt)
+                .await;
+            self.tameable.read_nbt(nbt);
+        })
+    }
+}
+
+impl Mob for ParrotEntity {
+    fn get_mob_entity(&self) -> &MobEntity {
+        &self.mob_entity
+    }
+
+    fn get_mob_tameable(&self) -> Option<&Tameable> {
+        Some(&self.tameable)
+    }
+
+    fn mob_init_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async {
+            self.tameable
+                .send_meta_data(&self.mob_entity.living_entity.entity)
+                .await;
+        })
+    }
+
+    fn mob_interact<'a>(
+        &'a self,
+        player: &'a Player,
+        item_stack: &'a mut ItemStack,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            if self.tameable.is_tamed() {
+                return self.tameable.toggle_sitting(&self.mob_entity, player).await;
+            }
+
+            if item_stack.item.has_tag(&tag::Item::MINECRAFT_PARROT_FOOD) {
+                self.tameable
+                    .try_tame(&self.mob_entity, player, item_stack, TAME_CHANCE)
+                    .await;
+                return true;
+            }
+
+            false
+        })
+    }
+}
@@ -0,0 +1,263 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicI8, AtomicU8, Ordering},
+};
+
+use crossbeam::atomic::AtomicCell;
+use pumpkin_data::{
+    entity::{EntityStatus, EntityType},
+    meta_data_type::MetaDataType,
+    tag::{self, Taggable},
+    tracked_data::{TrackedData, TrackedId},
+};
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+use pumpkin_protocol::{codec::var_int::VarInt, java::client::play::Metadata};
+use pumpkin_world::{block::entities::sign::DyeColor, item::ItemStack};
+use rand::RngExt;
+use uuid::Uuid;
+
+use crate::entity::{Entity, EntityBase, mob::MobEntity, player::Player};
+
+/// The owner and sitting state shared by wolves, cats and parrots.
+#[derive(Default)]
+pub struct Tameable {
+    owner: AtomicCell<Option<Uuid>>,
+    /// Whether the owner ordered the mob to sit
+    sitting: AtomicBool,
+    flags: AtomicU8,
+}
+
+impl Tameable {
+    const SITTING_FLAG: u8 = 1;
+    const TAMED_FLAG: u8 = 4;
+
+    #[must_use]
+    pub fn owner(&self) -> Option<Uuid> {
+        self.owner.load()
+    }
+
+    #[must_use]
+    pub fn is_tamed(&self) -> bool {
+        self.owner.load().is_some()
+    }
+
+    #[must_use]
+    pub fn is_sitting(&self) -> bool {
+        self.sitting.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    pub fn is_owner(&self, entity: &Entity) -> bool {
+        self.owner.load() == Some(entity.entity_uuid)
+    }
+
+    /// Returns the owner if they are in the same world as the mob.
+    #[must_use]
+    pub fn get_owner(&self, entity: &Entity) -> Option<Arc<Player>> {
+        entity.world.load().get_player_by_uuid(self.owner.load()?)
+    }
+
+    /// Whether a tamed mob may attack `target` together with its owner.
+    /// Pets never attack their owner or other pets of the same owner.
+    #[must_use]
+    pub fn can_attack_with_owner(&self, target: &dyn EntityBase) -> bool {
+        let entity = target.get_entity();
+        if self.is_owner(entity)
+            || entity.entity_type == &EntityType::CREEPER
+            || entity.entity_type == &EntityType::GHAST
+        {
+            return false;
+        }
+        target
+            .get_tameable()
+            .is_none_or(|tameable| !tameable.is_tamed() || tameable.owner() != self.owner())
+    }
+
+    pub async fn set_owner(&self, entity: &Entity, owner: Option<Uuid>) {
+        self.owner.store(owner);
+        self.set_flag(Self::TAMED_FLAG, owner.is_some());
+        self.send_meta_data(entity).await;
+    }
+
+    /// Orders the mob to sit or to stand up, a sitting mob stops moving and forgets its target.
+    pub async fn set_sitting(&self, mob: &MobEntity, sitting: bool) {
+        self.sitting.store(sitting, Ordering::Relaxed);
+        self.set_flag(Self::SITTING_FLAG, sitting);
+        let entity = &mob.living_entity.entity;
+        entity
+            .send_meta_data(&[Metadata::new(
+                TrackedData::DATA_TAMEABLE_FLAGS,
+                MetaDataType::Byte,
+                self.flags.load(Ordering::Relaxed),
+            )])
+            .await;
+
+        if sitting {
+            mob.living_entity.jumping.store(false, Ordering::Relaxed);
+            mob.navigator.lock().await.stop();
+            *mob.target.lock().await = None;
+        }
+    }
+
+    /// Feeds the taming item to an untamed mob, which gets tamed by the player with a chance of `1 / chance`.
+    /// Shows hearts on success and smoke otherwise.
+    pub async fn try_tame(
+        &self,
+        mob: &MobEntity,
+        player: &Player,
+        item_stack: &mut ItemStack,
+        chance: u32,
+    ) {
+        item_stack.decrement_unless_creative(player.gamemode.load(), 1);
+
+        let entity = &mob.living_entity.entity;
+        let world = entity.world.load();
+        if rand::rng().random_range(0..chance) == 0 {
+            self.set_owner(entity, Some(player.living_entity.entity.entity_uuid))
+                .await;
+            self.set_sitting(mob, true).await;
+            world
+                .send_entity_status(entity, EntityStatus::AddPositivePlayerReactionParticles)
+                .await;
+        } else {
+            world
+                .send_entity_status(entity, EntityStatus::AddNegativePlayerReactionParticles)
+                .await;
+        }
+    }
+
+    /// Lets the owner toggle whether the mob sits. Returns `false` if the player does not own the mob.
+    pub async fn toggle_sitting(&self, mob: &MobEntity, player: &Player) -> bool {
+        if !self.is_owner(&player.living_entity.entity) {
+            return false;
+        }
+        self.set_sitting(mob, !self.is_sitting()).await;
+        true
+    }
+
+    /// Sends the tamed and sitting flags and the owner to all players.
+    pub async fn send_meta_data(&self, entity: &Entity) {
+        entity
+            .send_meta_data(&[Metadata::new(
+                TrackedData::DATA_TAMEABLE_FLAGS,
+                MetaDataType::Byte,
+                self.flags.load(Ordering::Relaxed),
+            )])
+            .await;
+        entity
+            .send_meta_data(&[Metadata::new(
+                TrackedData::DATA_OWNER_UUID,
+                MetaDataType::LazyEntityReference,
+                self.owner.load(),
+            )])
+            .await;
+    }
+
+    pub fn write_nbt(&self, nbt: &mut NbtCompound) {
+        if let Some(owner) = self.owner.load() {
+            let uuid = owner.as_u128();
+            nbt.put(
+                "Owner",
+                NbtTag::IntArray(vec![
+                    (uuid >> 96) as i32,
+                    ((uuid >> 64) & 0xFFFF_FFFF) as i32,
+                    ((uuid >> 32) & 0xFFFF_FFFF) as i32,
+                    (uuid & 0xFFFF_FFFF) as i32,
+                ]),
+            );
+        }
+        nbt.put_bool("Sitting", self.is_sitting());
+    }
+
+    pub fn read_nbt(&self, nbt: &NbtCompound) {
+        if let Some([a, b, c, d]) = nbt.get_int_array("Owner") {
+            let uuid = (u128::from(*a as u32) << 96)
+                | (u128::from(*b as u32) << 64)
+                | (u128::from(*c as u32) << 32)
+                | u128::from(*d as u32);
+            self.owner.store(Some(Uuid::from_u128(uuid)));
+            self.set_flag(Self::TAMED_FLAG, true);
+        }
+        let sitting = nbt.get_bool("Sitting").unwrap_or(false);
+        self.sitting.store(sitting, Ordering::Relaxed);
+        self.set_flag(Self::SITTING_FLAG, sitting);
+    }
+
+    fn set_flag(&self, flag: u8, value: bool) {
+        if value {
+            self.flags.fetch_or(flag, Ordering::Relaxed);
+        } else {
+            self.flags.fetch_and(!flag, Ordering::Relaxed);
+        }
+    }
+}
+
+/// The dyeable collar of wolves and cats.
+pub struct Collar {
+    color: AtomicI8,
+    tracked_data: TrackedId,
+}
+
+impl Collar {
+    #[must_use]
+    pub const fn new(tracked_data: TrackedId) -> Self {
+        Self {
+            color: AtomicI8::new(DyeColor::Red as i8),
+            tracked_data,
+        }
+    }
+
+    #[must_use]
+    pub fn color(&self) -> DyeColor {
+        DyeColor::from(self.color.load(Ordering::Relaxed))
+    }
+
+    pub async fn set_color(&self, entity: &Entity, color: DyeColor) {
+        self.color.store(color as i8, Ordering::Relaxed);
+        self.send_meta_data(entity).await;
+    }
+
+    /// Dyes the collar with the held dye. Returns whether the dye was used.
+    pub async fn try_dye(
+        &self,
+        entity: &Entity,
+        player: &Player,
+        item_stack: &mut ItemStack,
+    ) -> bool {
+        if !item_stack.item.has_tag(&tag::Item::C_DYES) {
+            return false;
+        }
+        let Some(color_name) = item_stack.item.registry_key.strip_suffix("_dye") else {
+            return false;
+        };
+        let color = DyeColor::from(color_name) as i8;
+        if color == self.color.load(Ordering::Relaxed) {
+            return false;
+        }
+
+        self.color.store(color, Ordering::Relaxed);
+        self.send_meta_data(entity).await;
+        item_stack.decrement_unless_creative(player.gamemode.load(), 1);
+        true
+    }
+
+    pub async fn send_meta_data(&self, entity: &Entity) {
+        entity
+            .send_meta_data(&[Metadata::new(
+                self.tracked_data,
+                MetaDataType::Integer,
+                VarInt(i32::from(self.color.load(Ordering::Relaxed))),
+            )])
+            .await;
+    }
+
+    pub fn write_nbt(&self, nbt: &mut NbtCompound) {
+        nbt.put_byte("CollarColor", self.color.load(Ordering::Relaxed));
+    }
+
+    pub fn read_nbt(&self, nbt: &NbtCompound) {
+        if let Some(color) = nbt.get_byte("CollarColor") {
+            self.color.store(color, Ordering::Relaxed);
+        }
+    }
+}
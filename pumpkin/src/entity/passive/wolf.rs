@@ -1,21 +1,38 @@
 use std::sync::{Arc, Weak};
 
-use pumpkin_data::entity::EntityType;
+use pumpkin_data::{entity::EntityType, item::Item, tracked_data::TrackedData};
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_world::item::ItemStack;
 
 use crate::entity::{
-    Entity, NBTStorage,
-    ai::goal::{look_around::LookAroundGoal, look_at_entity::LookAtEntityGoal},
+    Entity, EntityBaseFuture, NBTStorage, NbtFuture,
+    ai::goal::{
+        attack_with_owner::AttackWithOwnerGoal, follow_owner::FollowOwnerGoal,
+        look_around::LookAroundGoal, look_at_entity::LookAtEntityGoal,
+        melee_attack::MeleeAttackGoal, revenge::RevengeGoal, sit::SitGoal, swim::SwimGoal,
+        track_owner_attacker::TrackOwnerAttackerGoal, wander_around::WanderAroundGoal,
+    },
     mob::{Mob, MobEntity},
+    passive::tameable::{Collar, Tameable},
+    player::Player,
 };
 
+const TAME_CHANCE: u32 = 3;
+
 pub struct WolfEntity {
     pub mob_entity: MobEntity,
+    pub tameable: Tameable,
+    pub collar: Collar,
 }
 
 impl WolfEntity {
     pub async fn new(entity: Entity) -> Arc<Self> {
         let mob_entity = MobEntity::new(entity);
-        let wolf = Self { mob_entity };
+        let wolf = Self {
+            mob_entity,
+            tameable: Tameable::default(),
+            collar: Collar::new(TrackedData::DATA_COLLAR_COLOR),
+        };
         let mob_arc = Arc::new(wolf);
         let mob_weak: Weak<dyn Mob> = {
             let mob_arc: Arc<dyn Mob> = mob_arc.clone();
@@ -24,23 +41,93 @@ impl WolfEntity {
 
         {
             let mut goal_selector = mob_arc.mob_entity.goals_selector.lock().await;
+            let mut target_selector = mob_arc.mob_entity.target_selector.lock().await;
 
-            // TODO
+            goal_selector.add_goal(1, Box::new(SwimGoal::default()));
+            goal_selector.add_goal(2, Box::new(SitGoal::default()));
+            goal_selector.add_goal(5, Box::new(MeleeAttackGoal::new(1.0, true)));
+            goal_selector.add_goal(6, Box::new(FollowOwnerGoal::new(1.0, 10.0, 2.0)));
+            goal_selector.add_goal(8, Box::new(WanderAroundGoal::new(1.0)));
             goal_selector.add_goal(
-                8,
+                10,
                 LookAtEntityGoal::with_default(mob_weak, &EntityType::PLAYER, 8.0),
             );
-            goal_selector.add_goal(8, Box::new(LookAroundGoal::default()));
+            goal_selector.add_goal(10, Box::new(LookAroundGoal::default()));
+
+            target_selector.add_goal(1, Box::new(TrackOwnerAttackerGoal::default()));
+            target_selector.add_goal(2, Box::new(AttackWithOwnerGoal::default()));
+            target_selector.add_goal(3, Box::new(RevengeGoal::new(true)));
         };
 
         mob_arc
     }
 }
 
-impl NBTStorage for WolfEntity {}
+impl NBTStorage for WolfEntity {
+    fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity.living_entity.entity.write_nbt(nbt).await;
+            self.tameable.write_nbt(nbt);
+            self.collar.write_nbt(nbt);
+        })
+    }
+
+    fn read_nbt_non_mut<'a>(&'a self, nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
+        Box::pin(async {
+            self.mob_entity
+                .living_entity
+                .entity
+                .read_nbt_non_mut(nbt)
+                .await;
+            self.tameable.read_nbt(nbt);
+            self.collar.read_nbt(nbt);
+        })
+    }
+}
 
 impl Mob for WolfEntity {
     fn get_mob_entity(&self) -> &MobEntity {
         &self.mob_entity
     }
+
+    fn get_mob_tameable(&self) -> Option<&Tameable> {
+        Some(&self.tameable)
+    }
+
+    fn mob_init_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async {
+            let entity = &self.mob_entity.living_entity.entity;
+            self.tameable.send_meta_data(entity).await;
+            self.collar.send_meta_data(entity).await;
+        })
+    }
+
+    fn mob_interact<'a>(
+        &'a self,
+        player: &'a Player,
+        item_stack: &'a mut ItemStack,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            if self.tameable.is_tamed() {
+                if self.tameable.is_owner(&player.living_entity.entity)
+                    && self
+                        .collar
+                        .try_dye(&self.mob_entity.living_entity.entity, player, item_stack)
+                        .await
+                {
+                    return true;
+                }
+                return self.tameable.toggle_sitting(&self.mob_entity, player).await;
+            }
+
+            if item_stack.item == &Item::BONE {
+                self.tameable
+                    .try_tame(&self.mob_entity, player, item_stack, TAME_CHANCE)
+                    .await;
+                return true;
+            }
+
+            false
+        })
+    }
 }
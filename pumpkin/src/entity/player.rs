@@ -1,7 +1,6 @@
 use core::f32;
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::f64::consts::TAU;
-use std::mem;
 use std::num::NonZeroU8;
 use std::sync::atomic::{AtomicBool, AtomicI32, AtomicI64, AtomicU8, AtomicU32, Ordering};
 use std::sync::{Arc, Weak};
@@ -15,6 +14,7 @@ use pumpkin_data::meta_data_type::MetaDataType;
 use pumpkin_data::tracked_data::TrackedData;
 use pumpkin_inventory::player::ender_chest_inventory::EnderChestInventory;
 use pumpkin_protocol::bedrock::client::level_chunk::CLevelChunk;
+use pumpkin_protocol::bedrock::client::respawn::CRespawn;
 use pumpkin_protocol::bedrock::client::set_time::CSetTime;
 use pumpkin_protocol::bedrock::client::update_abilities::{
     Ability, AbilityLayer, CUpdateAbilities,
@@ -22,7 +22,8 @@ use pumpkin_protocol::bedrock::client::update_abilities::{
 use pumpkin_protocol::bedrock::server::text::SText;
 use pumpkin_protocol::codec::item_stack_seralizer::ItemStackSerializer;
 use pumpkin_world::chunk::{ChunkData, ChunkEntityData};
-use pumpkin_world::inventory::Inventory;
+use pumpkin_world::data::migration::{PUMPKIN_DATA_VERSION, PUMPKIN_DATA_VERSION_KEY};
+use pumpkin_world::inventory::{Clearable, Inventory};
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
 use tracing::{debug, warn};
@@ -30,14 +31,18 @@ use uuid::Uuid;
 
 use pumpkin_data::block_properties::{BlockProperties, EnumVariants, HorizontalFacing};
 use pumpkin_data::damage::DamageType;
-use pumpkin_data::data_component_impl::{AttributeModifiersImpl, Operation};
-use pumpkin_data::data_component_impl::{EquipmentSlot, EquippableImpl, ToolImpl};
+use pumpkin_data::data_component_impl::{AttributeModifiersImpl, MapIdImpl, Operation};
+use pumpkin_data::data_component_impl::{
+    CanBreakImpl, CanPlaceOnImpl, EquipmentSlot, EquippableImpl, ToolImpl,
+};
 use pumpkin_data::effect::StatusEffect;
 use pumpkin_data::entity::{EntityPose, EntityStatus, EntityType};
+use pumpkin_data::item::Item;
 use pumpkin_data::particle::Particle;
 use pumpkin_data::sound::{Sound, SoundCategory};
 use pumpkin_data::tag::Taggable;
 use pumpkin_data::{Block, BlockState, Enchantment, tag, translation};
+use pumpkin_inventory::horse_screen_handler::HorseScreenHandler;
 use pumpkin_inventory::player::{
     player_inventory::PlayerInventory, player_screen_handler::PlayerScreenHandler,
 };
@@ -50,18 +55,20 @@ use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_nbt::tag::NbtTag;
 use pumpkin_protocol::IdOr;
 use pumpkin_protocol::codec::var_int::VarInt;
+use pumpkin_protocol::codec::var_ulong::VarULong;
 use pumpkin_protocol::java::client::play::{
     Animation, CAcknowledgeBlockChange, CActionBar, CChangeDifficulty, CChunkBatchEnd,
     CChunkBatchStart, CChunkData, CCloseContainer, CCombatDeath, CCustomPayload,
-    CDisguisedChatMessage, CEntityAnimation, CEntityPositionSync, CGameEvent, CKeepAlive,
-    COpenScreen, CParticle, CPlayerAbilities, CPlayerInfoUpdate, CPlayerPosition,
-    CPlayerSpawnPosition, CRespawn, CSetContainerContent, CSetContainerProperty, CSetContainerSlot,
-    CSetCursorItem, CSetEquipment, CSetExperience, CSetHealth, CSetPlayerInventory,
-    CSetSelectedSlot, CSoundEffect, CStopSound, CSubtitle, CSystemChatMessage, CTitleAnimation,
-    CTitleText, CUnloadChunk, CUpdateMobEffect, CUpdateTime, GameEvent, Metadata, PlayerAction,
-    PlayerInfoFlags, PreviousMessage,
+    CDisguisedChatMessage, CEntityAnimation, CEntityPositionSync, CGameEvent, CKeepAlive, CMapData,
+    CMountScreenOpen, COpenBook, COpenScreen, CParticle, CPlayerAbilities, CPlayerInfoUpdate,
+    CPlayerPosition, CPlayerSpawnPosition, CRespawn, CSetContainerContent, CSetContainerProperty,
+    CSetContainerSlot, CSetCursorItem, CSetEquipment, CSetExperience, CSetHealth,
+    CSetPlayerInventory, CSetSelectedSlot, CSoundEffect, CStopSound, CSubtitle, CSystemChatMessage,
+    CTitleAnimation, CTitleText, CUnloadChunk, CUpdateMobEffect, CUpdateTime, GameEvent, Metadata,
+    PlayerAction, PlayerInfoFlags, PreviousMessage,
 };
-use pumpkin_protocol::java::server::play::SClickSlot;
+use pumpkin_protocol::java::server::play::{SClickSlot, SContainerButtonClick};
+use pumpkin_protocol::packet::MultiVersionJavaPacket;
 use pumpkin_util::math::{
     boundingbox::BoundingBox, experience, position::BlockPos, vector2::Vector2, vector3::Vector3,
 };
@@ -71,25 +78,33 @@ use pumpkin_util::text::TextComponent;
 use pumpkin_util::text::click::ClickEvent;
 use pumpkin_util::text::hover::HoverEvent;
 use pumpkin_util::{GameMode, Hand};
-use pumpkin_world::biome;
 use pumpkin_world::cylindrical_chunk_iterator::Cylindrical;
 use pumpkin_world::item::ItemStack;
 use pumpkin_world::level::{Level, SyncChunk, SyncEntityChunk};
+use pumpkin_world::world::SimpleWorld;
 
 use crate::block;
 use crate::block::blocks::bed::BedBlock;
 use crate::command::client_suggestions;
 use crate::command::dispatcher::CommandDispatcher;
-use crate::entity::{EntityBaseFuture, NbtFuture, TeleportFuture};
+use crate::entity::experience_orb::ExperienceOrbEntity;
+use crate::entity::passive::horse::HorseEntity;
+use crate::entity::{EntityBaseFuture, NbtFuture, TeleportCause, TeleportFuture};
+use crate::item::ItemActionResult;
 use crate::net::{ClientPlatform, GameProfile};
 use crate::net::{DisconnectReason, PlayerConfig};
+use crate::plugin::entity::entity_teleport::EntityTeleportEvent;
 use crate::plugin::player::player_change_world::PlayerChangeWorldEvent;
+use crate::plugin::player::player_death_drops::PlayerDeathDropsEvent;
 use crate::plugin::player::player_gamemode_change::PlayerGamemodeChangeEvent;
+use crate::plugin::player::player_idle_kick::PlayerIdleKickEvent;
+use crate::plugin::player::player_swap_hand_items::PlayerSwapHandItemsEvent;
 use crate::plugin::player::player_teleport::PlayerTeleportEvent;
 use crate::server::Server;
 use crate::world::World;
 
-use super::breath::BreathManager;
+use super::bedrock_attributes::BedrockAttributeSync;
+use super::breath::{BreathManager, is_eye_in_water};
 use super::combat::{self, AttackType, player_attack_sound};
 use super::hunger::HungerManager;
 use super::item::ItemEntity;
@@ -135,7 +150,8 @@ pub struct ChunkManager {
     center: Vector2<i32>,
     view_distance: u8,
     chunk_listener: Receiver<(Vector2<i32>, SyncChunk)>,
-    chunk_sent: HashMap<Vector2<i32>, Weak<ChunkData>>,
+    /// The chunks queued or sent to the player, with their send generation at that time.
+    chunk_sent: HashMap<Vector2<i32>, (Weak<ChunkData>, u64)>,
     chunk_queue: BinaryHeap<HeapNode>,
     entity_chunk_queue: VecDeque<(Vector2<i32>, SyncEntityChunk)>,
     batches_sent_since_ack: BatchState,
@@ -174,11 +190,23 @@ impl ChunkManager {
         &self.world
     }
 
+    /// A chunk only has to be sent again if it was reloaded or its send generation was bumped.
+    /// Other changes already reached the player through block updates.
     fn should_enqueue_chunk(&mut self, position: Vector2<i32>, chunk: &SyncChunk) -> bool {
-        self.chunk_sent
-            .insert(position, Arc::downgrade(chunk))
-            .and_then(|old_chunk| old_chunk.upgrade())
-            .is_none_or(|old_chunk| !Arc::ptr_eq(&old_chunk, chunk))
+        let generation = chunk.send_generation.get();
+        let already_sent = self
+            .chunk_sent
+            .insert(position, (Arc::downgrade(chunk), generation))
+            .is_some_and(|(old_chunk, old_generation)| {
+                old_generation == generation
+                    && old_chunk
+                        .upgrade()
+                        .is_some_and(|old_chunk| Arc::ptr_eq(&old_chunk, chunk))
+            });
+        if already_sent {
+            self.world.chunk_send_stats.record_skipped_resend();
+        }
+        !already_sent
     }
 
     #[must_use]
@@ -262,6 +290,7 @@ impl ChunkManager {
 
         for pos in loading_chunks {
             if !self.chunk_sent.contains_key(pos)
+                && !level.chunk_listener.is_chunk_held(pos)
                 && let Some(chunk) = level.loaded_chunks.get(pos)
             {
                 self.push_chunk(*pos, chunk.value().clone());
@@ -402,10 +431,16 @@ pub struct Player {
     pub breath_manager: BreathManager,
     /// Manages the player's hunger level.
     pub hunger_manager: HungerManager,
+    /// Keeps the health, hunger and experience shown by Bedrock clients up to date.
+    pub bedrock_attributes: BedrockAttributeSync,
     /// The ID of the currently open container (if any).
     pub open_container: AtomicCell<Option<u64>>,
     /// The block position of the currently open container screen (if any).
     pub open_container_pos: AtomicCell<Option<BlockPos>>,
+    /// The lectern whose book and quill the player is editing (if any).
+    pub editing_lectern: AtomicCell<Option<BlockPos>>,
+    /// The horizontal distance covered by the last movement packet, used while gliding.
+    last_glide_speed: AtomicCell<f64>,
     /// The item currently being held by the player.
     pub carried_item: Mutex<Option<ItemStack>>,
     /// The player's abilities and special powers.
@@ -440,6 +475,13 @@ pub struct Player {
     pub ping: AtomicU32,
     /// The amount of ticks since the player's last attack.
     pub last_attacked_ticks: AtomicU32,
+    /// The ID of the filled map the player is holding (if any).
+    pub held_map_id: AtomicCell<Option<u32>>,
+    /// The sequence of the latest main hand right-click packet, if it did something. The client
+    /// sends the off hand packets of the same click afterwards, which are then ignored.
+    consumed_main_hand_sequence: AtomicCell<Option<i32>>,
+    /// The pixels of the held map as last sent to the client, used to only send changes.
+    sent_map_colors: Mutex<Option<Box<[u8]>>>,
     /// The player's last known experience level.
     pub last_sent_xp: AtomicI32,
     pub last_sent_health: AtomicI32,
@@ -457,6 +499,8 @@ pub struct Player {
     pub experience_progress: AtomicCell<f32>,
     /// The player's total experience points.
     pub experience_points: AtomicI32,
+    /// The player's score, shown on the death screen. Grows with every experience point gained.
+    pub score: AtomicI32,
     pub experience_pick_up_delay: Mutex<u32>,
     pub chunk_manager: Mutex<ChunkManager>,
     pub has_played_before: AtomicBool,
@@ -520,9 +564,12 @@ impl Player {
             breath_manager: BreathManager::default(),
             // TODO: Load this from previous instance
             hunger_manager: HungerManager::default(),
+            bedrock_attributes: BedrockAttributeSync::default(),
             current_block_destroy_stage: AtomicI32::new(-1),
             open_container: AtomicCell::new(None),
             open_container_pos: AtomicCell::new(None),
+            editing_lectern: AtomicCell::new(None),
+            last_glide_speed: AtomicCell::new(0.0),
             tick_counter: AtomicI32::new(0),
             packet_sequence: AtomicI32::new(-1),
             start_mining_time: AtomicI32::new(0),
@@ -551,6 +598,9 @@ impl Player {
             last_action_time: AtomicCell::new(std::time::Instant::now()),
             ping: AtomicU32::new(0),
             last_attacked_ticks: AtomicU32::new(0),
+            held_map_id: AtomicCell::new(None),
+            consumed_main_hand_sequence: AtomicCell::new(None),
+            sent_map_colors: Mutex::new(None),
             client_loaded: AtomicBool::new(false),
             client_loaded_timeout: AtomicU32::new(60),
             // Minecraft has no way to change the default permission level of new players.
@@ -570,6 +620,7 @@ impl Player {
             experience_level: AtomicI32::new(0),
             experience_progress: AtomicCell::new(0.0),
             experience_points: AtomicI32::new(0),
+            score: AtomicI32::new(0),
             // Default to sending 16 chunks per tick.
             chunk_manager: Mutex::new(ChunkManager::new(
                 16,
@@ -730,6 +781,11 @@ impl Player {
             return;
         }
 
+        self.add_exhaustion(0.1).await;
+
+        let fire_aspect_level = Entity::get_fire_aspect_level(Some(&*item_stack.lock().await));
+        victim_entity.set_on_fire_from_fire_aspect(fire_aspect_level);
+
         if victim.get_living_entity().is_some() {
             let mut knockback_strength = 1.0;
             player_attack_sound(&pos, &world, attack_type).await;
@@ -1415,11 +1471,13 @@ impl Player {
 
         if let Some(chunk_of_chunks) = chunk_of_chunks {
             let chunk_count = chunk_of_chunks.len();
+            let world = self.world();
             match &self.client {
                 ClientPlatform::Java(java_client) => {
                     java_client.send_packet_now(&CChunkBatchStart).await;
                     for chunk in chunk_of_chunks {
                         // log::debug!("send chunk {:?}", chunk.position);
+                        world.chunk_send_stats.record_full_send();
                         // TODO: Can we check if we still need to send the chunk? Like if it's a fast moving
                         // player or something.
                         java_client.send_packet_now(&CChunkData(&chunk)).await;
@@ -1430,6 +1488,7 @@ impl Player {
                 }
                 ClientPlatform::Bedrock(bedrock_client) => {
                     for chunk in chunk_of_chunks {
+                        world.chunk_send_stats.record_full_send();
                         bedrock_client
                             .send_game_packet(&CLevelChunk {
                                 dimension: 0,
@@ -1479,10 +1538,14 @@ impl Player {
         self.update_player_pose().await;
         self.breath_manager.tick(self).await;
         self.hunger_manager.tick(self).await;
+        if self.tick_counter.load(Ordering::Relaxed) % 5 == 0 {
+            self.send_map_update_for_held_item(server).await;
+        }
 
         // experience handling
         self.tick_experience().await;
         self.tick_health().await;
+        self.bedrock_attributes.tick(self).await;
 
         // Timeout/keep alive handling
         self.tick_client_load_timeout();
@@ -1491,14 +1554,34 @@ impl Player {
         let now = Instant::now();
         let idle_timeout_minutes = server.player_idle_timeout.load(Ordering::Relaxed);
         if idle_timeout_minutes > 0 {
+            if server.basic_config.idle_timeout_exempt_solo && !server.has_n_players(2) {
+                // Keep the timer fresh so the player isn't kicked as soon as someone else joins
+                self.update_last_action_time();
+            }
             let idle_duration = now.duration_since(self.last_action_time.load());
-            if idle_duration >= Duration::from_secs(idle_timeout_minutes as u64 * 60) {
-                self.kick(
-                    DisconnectReason::KickedForIdle,
-                    TextComponent::translate(translation::MULTIPLAYER_DISCONNECT_IDLING, []),
-                )
-                .await;
-                return;
+            if idle_duration >= Duration::from_secs(idle_timeout_minutes as u64 * 60)
+                && !self
+                    .has_permission(server, "pumpkin:bypass.idle_timeout")
+                    .await
+            {
+                send_cancellable! {{
+                    server;
+                    PlayerIdleKickEvent::new(
+                        self.clone(),
+                        idle_duration,
+                        TextComponent::translate(translation::MULTIPLAYER_DISCONNECT_IDLING, []),
+                    );
+
+                    'after: {
+                        self.kick(DisconnectReason::KickedForIdle, event.kick_message)
+                            .await;
+                        return;
+                    }
+
+                    'cancelled: {
+                        self.update_last_action_time();
+                    }
+                }}
             }
         }
 
@@ -1552,9 +1635,64 @@ impl Player {
         }
     }
 
+    /// Vanilla: the "moved too quickly" check of `ServerGamePacketListenerImpl.handleMovePlayer()`.
+    /// Gliding players may cover more distance per packet, and aren't checked at all when the
+    /// `elytra_movement_check` game rule is off. Nothing is checked while a teleport is pending.
+    pub async fn moved_too_quickly(&self, delta_pos: Vector3<f64>) -> bool {
+        if self.awaiting_teleport.lock().await.is_some() {
+            return false;
+        }
+        let entity = &self.living_entity.entity;
+        let gliding = entity.fall_flying.load(Ordering::Relaxed);
+        if gliding
+            && !self
+                .world()
+                .level_info
+                .load()
+                .game_rules
+                .elytra_movement_check
+        {
+            return false;
+        }
+        let limit = if gliding { 300.0 } else { 100.0 };
+        delta_pos.length_squared() - entity.velocity.load().length_squared() > limit
+    }
+
+    /// Gliding players move on their own, so the server follows their movement to do what
+    /// vanilla's `travelGliding()` does server-side: keep the fall distance low while not
+    /// diving and hurt players that slam into a wall.
+    pub async fn handle_glide_movement(&self, delta_pos: Vector3<f64>, horizontal_collision: bool) {
+        let speed = delta_pos.horizontal_length();
+        let last_speed = self.last_glide_speed.swap(speed);
+        if !self
+            .living_entity
+            .entity
+            .fall_flying
+            .load(Ordering::Relaxed)
+        {
+            return;
+        }
+
+        if delta_pos.y > -0.5 {
+            self.living_entity.fall_distance.store(1.0);
+        }
+        if horizontal_collision {
+            self.living_entity
+                .check_glide_collision(self, last_speed, speed)
+                .await;
+        }
+    }
+
     pub async fn progress_motion(&self, delta_pos: Vector3<f64>) {
-        // TODO: Swimming, gliding...
-        if self.living_entity.entity.on_ground.load(Ordering::Relaxed) {
+        // TODO: Gliding...
+        let entity = &self.living_entity.entity;
+        if entity.touching_water.load(Ordering::Relaxed) {
+            // Players in water get tired from moving in any direction, not only horizontally
+            let delta = (delta_pos.length() * 100.0).round() as f32;
+            if delta > 0.0 {
+                self.add_exhaustion(0.01 * delta * 0.01).await;
+            }
+        } else if entity.on_ground.load(Ordering::Relaxed) {
             let delta = (delta_pos.horizontal_length() * 100.0).round() as f32;
             if delta > 0.0 {
                 if self.living_entity.entity.sprinting.load(Ordering::Relaxed) {
@@ -1799,13 +1937,15 @@ impl Player {
     }
 
     /// Teleports the player to a different world or dimension with an optional position, yaw, and pitch.
+    /// Returns whether the player changed worlds, which is not the case when an event handler
+    /// cancelled it.
     pub async fn teleport_world(
         self: &Arc<Self>,
         new_world: Arc<World>,
         position: Vector3<f64>,
         yaw: Option<f32>,
         pitch: Option<f32>,
-    ) {
+    ) -> bool {
         let current_world = self.living_entity.entity.world.load_full();
         let yaw = yaw.unwrap_or(new_world.level_info.load().spawn_yaw);
         let pitch = pitch.unwrap_or(new_world.level_info.load().spawn_pitch);
@@ -1838,6 +1978,7 @@ impl Player {
                     new_list.push(player.clone());
                     new_list
                 });
+                new_world.entity_sections.insert(player.clone() as Arc<dyn EntityBase>);
                 self.unload_watched_chunks(&current_world).await;
 
                 self.chunk_manager.lock().await.change_world(&current_world.level, new_world.clone());
@@ -1855,13 +1996,13 @@ impl Player {
                     .send_packet_now(&CRespawn::new(
                         (new_world.dimension.id).into(),
                         new_world.dimension.minecraft_name.to_string(),
-                        biome::hash_seed(new_world.level.seed.0), // seed
+                        new_world.client_hashed_seed(),
                         self.gamemode.load() as u8,
-                        self.gamemode.load() as i8,
+                        self.previous_gamemode_id(),
                         false,
                         false,
                         Some((death_dimension, death_location)),
-                        VarInt(self.get_entity().portal_cooldown.load(Ordering::Relaxed) as i32),
+                        VarInt(self.get_entity().get_portal_cooldown() as i32),
                         new_world.sea_level.into(),
                         1,
                     )).await;
@@ -1882,6 +2023,10 @@ impl Player {
                 self.send_health().await;
 
                 new_world.send_world_info(&player, position, yaw, pitch).await;
+                true
+            }
+            'cancelled: {
+                false
             }
         }}
     }
@@ -1956,9 +2101,36 @@ impl Player {
         self.last_action_time.store(std::time::Instant::now());
     }
 
+    /// Starts handling a right-click packet with `hand` and `sequence`. Returns `false` if the
+    /// packet has to be ignored because the main hand already handled the click.
+    ///
+    /// Every click starts with the main hand packets and the client numbers its packets in
+    /// order, so an off hand packet belongs to the click of the latest main hand packet before it.
+    #[must_use]
+    pub fn start_hand_interaction(&self, hand: Hand, sequence: i32) -> bool {
+        match hand {
+            Hand::Main => {
+                self.consumed_main_hand_sequence.store(None);
+                true
+            }
+            Hand::Off => self
+                .consumed_main_hand_sequence
+                .load()
+                .is_none_or(|main_hand_sequence| main_hand_sequence > sequence),
+        }
+    }
+
+    /// Remembers the outcome of a right-click packet so the off hand is only used when the main
+    /// hand passes, matching vanilla's interaction order.
+    pub fn record_hand_interaction(&self, hand: Hand, sequence: i32, result: ItemActionResult) {
+        if hand == Hand::Main && result.consumes_action() {
+            self.consumed_main_hand_sequence.store(Some(sequence));
+        }
+    }
+
     pub fn can_food_heal(&self) -> bool {
         let health = self.living_entity.health.load();
-        let max_health = 20.0; // TODO
+        let max_health = self.living_entity.get_max_health();
         health > 0.0 && health < max_health
     }
 
@@ -1969,6 +2141,15 @@ impl Player {
         self.hunger_manager.add_exhaustion(exhaustion);
     }
 
+    /// Called whenever the food level changes, after the new level was stored. Players can't
+    /// sprint with 6 food or less, just like in vanilla.
+    pub async fn on_food_level_change(&self, old: u8, new: u8) {
+        let entity = &self.living_entity.entity;
+        if new < old && new <= 6 && entity.sprinting.load(Ordering::Relaxed) {
+            entity.set_sprinting(false).await;
+        }
+    }
+
     pub async fn heal(&self, additional_health: f32) {
         self.living_entity.heal(additional_health).await;
         self.send_health().await;
@@ -2021,16 +2202,9 @@ impl Player {
 
         let keep_inventory = { self.world().level_info.load().game_rules.keep_inventory };
 
+        // Drop everything now rather than on respawn, so nothing is lost when leaving on the death screen
         if !keep_inventory {
-            for item in &self.inventory().main_inventory {
-                let mut lock = item.lock().await;
-                self.world()
-                    .drop_stack(
-                        &block_pos,
-                        mem::replace(&mut *lock, ItemStack::EMPTY.clone()),
-                    )
-                    .await;
-            }
+            self.drop_inventory_on_death(block_pos).await;
         }
 
         // Reset air supply & drowning ticks on death
@@ -2039,6 +2213,72 @@ impl Player {
         self.client
             .send_packet_now(&CCombatDeath::new(self.entity_id().into(), &death_msg))
             .await;
+
+        // Bedrock clients show the death screen once their health is 0 and they get told to wait
+        // for a spawn point. Pressing respawn sends a respawn packet back.
+        if let ClientPlatform::Bedrock(client) = &self.client {
+            self.bedrock_attributes.tick(self).await;
+            let spawn = {
+                let info = self.world().level_info.load();
+                Vector3::new(
+                    info.spawn_x as f32,
+                    info.spawn_y as f32,
+                    info.spawn_z as f32,
+                )
+            };
+            client
+                .send_game_packet(&CRespawn {
+                    position: spawn,
+                    state: CRespawn::STATE_SEARCHING_FOR_SPAWN,
+                    runtime_id: VarULong(self.entity_id() as u64),
+                })
+                .await;
+        }
+    }
+
+    /// Drops the inventory and part of the experience where the player died. Items with Curse of
+    /// Vanishing are destroyed instead, while Curse of Binding only matters as long as the player
+    /// is alive.
+    async fn drop_inventory_on_death(&self, block_pos: BlockPos) {
+        let world = self.world();
+        let Some(server) = world.server.upgrade() else {
+            return;
+        };
+        let Some(player) = world.get_player_by_uuid(self.gameprofile.id) else {
+            return;
+        };
+
+        let mut drops = Vec::new();
+        for slot in 0..self.inventory.size() {
+            let stack = self.inventory.get_stack(slot).await;
+            let stack = stack.lock().await;
+            if !stack.is_empty() && stack.get_enchantment_level(&Enchantment::VANISHING_CURSE) == 0
+            {
+                drops.push(stack.clone());
+            }
+        }
+        let experience = if self.gamemode.load() == GameMode::Spectator {
+            0
+        } else {
+            (self.experience_level.load(Ordering::Relaxed).max(0) as u32 * 7).min(100)
+        };
+
+        send_cancellable! {{
+            server;
+            PlayerDeathDropsEvent::new(player, drops, experience);
+
+            'after: {
+                self.inventory.clear().await;
+                for stack in event.drops {
+                    world.drop_stack(&block_pos, stack).await;
+                }
+                if event.experience > 0 {
+                    ExperienceOrbEntity::spawn(&world, self.position(), event.experience).await;
+                }
+                // Any experience that wasn't dropped is lost
+                self.set_experience(0, 0.0, 0).await;
+            }
+        }}
     }
 
     pub async fn set_gamemode(self: &Arc<Self>, gamemode: GameMode) -> bool {
@@ -2064,20 +2304,27 @@ impl Player {
 
             'after: {
                 let gamemode = event.new_gamemode;
-                self.gamemode.store(gamemode);
-                // TODO: Fix this when mojang fixes it
-                // This is intentional to keep the pure vanilla mojang experience
-                // self.previous_gamemode.store(self.previous_gamemode.load());
-                {
+                let previous_gamemode = self.gamemode.swap(gamemode);
+                self.previous_gamemode.store(Some(previous_gamemode));
+                let stopped_flying = {
                     // Use another scope so that we instantly unlock `abilities`.
                     let mut abilities = self.abilities.lock().await;
+                    let was_flying = abilities.flying;
                     abilities.set_for_gamemode(gamemode);
+                    was_flying && !abilities.flying
                 };
                 self.send_abilities_update().await;
 
+                if stopped_flying {
+                    // Start tracking the fall from where the player stopped flying
+                    self.living_entity.fall_distance.store(0.0);
+                }
+                self.get_entity()
+                    .no_clip
+                    .store(gamemode == GameMode::Spectator, Ordering::Relaxed);
+
                 if gamemode == GameMode::Creative {
-                    self.get_entity().extinguish();
-                    self.get_entity().set_on_fire(false).await;
+                    self.get_entity().extinguish().await;
                 }
 
                 // Stop elytra flight and reset sneaking when switching to spectator mode
@@ -2123,6 +2370,48 @@ impl Player {
         }}
     }
 
+    /// The previous game mode as sent in the login and respawn packets, `-1` if there is none.
+    #[must_use]
+    pub fn previous_gamemode_id(&self) -> i8 {
+        self.previous_gamemode
+            .load()
+            .map_or(-1, |gamemode| gamemode as i8)
+    }
+
+    /// Whether the player's game mode keeps them from modifying blocks in general.
+    pub async fn is_block_modification_restricted(&self) -> bool {
+        match self.gamemode.load() {
+            GameMode::Spectator => true,
+            GameMode::Adventure => !self.abilities.lock().await.allow_modify_world,
+            GameMode::Survival | GameMode::Creative => false,
+        }
+    }
+
+    /// Whether the player's game mode keeps them from breaking `block`. Adventure mode players
+    /// can only break the blocks matched by the held item's `can_break` component.
+    pub async fn is_block_break_restricted(&self, block: &Block) -> bool {
+        self.is_block_modification_restricted().await
+            && (self.gamemode.load() != GameMode::Adventure
+                || !self
+                    .inventory
+                    .held_item()
+                    .lock()
+                    .await
+                    .get_data_component::<CanBreakImpl>()
+                    .is_some_and(|can_break| can_break.predicate.matches(block)))
+    }
+
+    /// Whether the player's game mode keeps them from using `stack` on the clicked `block`.
+    /// Adventure mode players can only use items on the blocks matched by their `can_place_on`
+    /// component.
+    pub async fn is_block_place_restricted(&self, stack: &ItemStack, block: &Block) -> bool {
+        self.is_block_modification_restricted().await
+            && (self.gamemode.load() != GameMode::Adventure
+                || !stack
+                    .get_data_component::<CanPlaceOnImpl>()
+                    .is_some_and(|can_place_on| can_place_on.predicate.matches(block)))
+    }
+
     /// Send the player's skin layers and used hand to all players.
     pub async fn send_client_information(&self) {
         let config = self.config.load();
@@ -2153,8 +2442,23 @@ impl Player {
                 .is_correct_for_drops(block)
     }
 
-    pub async fn get_mining_speed(&self, block: &'static Block) -> f32 {
-        let mut speed = self.inventory.held_item().lock().await.get_speed(block);
+    /// Returns the breaking progress per tick on the block at `position` with the held item. The
+    /// block breaks once the progress reaches 1.0, so a value of 1.0 breaks it instantly and 0.0
+    /// means it can't be broken.
+    pub async fn get_block_destroy_speed(&self, position: &BlockPos) -> f64 {
+        let world = self.world();
+        let block = world.get_block(position).await;
+        let held = self.inventory.held_item().lock().await.clone();
+        let speed = world
+            .get_block_destroy_speed(block, position, Some(&held))
+            .await;
+        (speed * f64::from(self.get_mining_speed_multiplier().await)).min(1.0)
+    }
+
+    /// How much faster than usual the player mines, due to status effects and being under water
+    /// or in the air.
+    pub async fn get_mining_speed_multiplier(&self) -> f32 {
+        let mut speed = 1.0;
         // Haste
         if self.living_entity.has_effect(&StatusEffect::HASTE).await
             || self
@@ -2178,7 +2482,18 @@ impl Player {
             };
             speed *= fatigue_speed;
         }
-        // TODO: Handle when in water
+        // Aqua Affinity lifts the penalty for mining under water
+        if is_eye_in_water(&self.living_entity.entity) {
+            let aqua_affinity = {
+                let equipment = self.living_entity.entity_equipment.lock().await;
+                let helmet = equipment.get(&EquipmentSlot::HEAD);
+                let helmet = helmet.lock().await;
+                helmet.get_enchantment_level(&Enchantment::AQUA_AFFINITY)
+            };
+            if aqua_affinity == 0 {
+                speed /= 5.0;
+            }
+        }
         if !self.living_entity.entity.on_ground.load(Ordering::Relaxed) {
             speed /= 5.0;
         }
@@ -2226,6 +2541,44 @@ impl Player {
         }
     }
 
+    /// Opens the book screen for the book held in `hand`.
+    ///
+    /// A book and quill opens the editor, a written book opens the reader.
+    pub async fn send_book(&self, book_item: &ItemStack, hand: Hand) {
+        if book_item.item != &Item::WRITABLE_BOOK && book_item.item != &Item::WRITTEN_BOOK {
+            return;
+        }
+        self.editing_lectern.store(None);
+        if let ClientPlatform::Java(java) = &self.client {
+            java.enqueue_packet(&COpenBook::new(VarInt(hand as i32)))
+                .await;
+        }
+    }
+
+    /// Opens the book lying on the lectern at `position`.
+    ///
+    /// The client can only open a held book, so the book is shown in the selected slot while
+    /// the screen opens and the held item is sent again right after.
+    pub async fn open_lectern_book(&self, book: &ItemStack, position: BlockPos) {
+        let slot = i32::from(self.inventory.get_selected_slot());
+        self.enqueue_slot_set_packet(&CSetPlayerInventory::new(
+            slot.into(),
+            &ItemStackSerializer::from(book.clone()),
+        ))
+        .await;
+        self.send_book(book, Hand::Main).await;
+
+        let held = self.inventory.held_item().lock().await.clone();
+        self.enqueue_slot_set_packet(&CSetPlayerInventory::new(
+            slot.into(),
+            &ItemStackSerializer::from(held),
+        ))
+        .await;
+        // Edits of a book and quill are written back to the lectern
+        self.editing_lectern
+            .store((book.item == &Item::WRITABLE_BOOK).then_some(position));
+    }
+
     pub async fn drop_item(&self, item_stack: ItemStack) {
         let item_pos = self.living_entity.entity.pos.load()
             + Vector3::new(0.0, self.living_entity.entity.get_eye_height() - 0.3, 0.0);
@@ -2253,6 +2606,64 @@ impl Player {
         self.world().spawn_entity(item_entity).await;
     }
 
+    /// Sends the contents of the filled map in the player's main hand (if any).
+    ///
+    /// Only pixels that changed since the last update are sent, and only while the player is
+    /// within the area shown on the map. The player marker is always updated.
+    pub async fn send_map_update_for_held_item(&self, server: &Server) {
+        let map_id = {
+            let stack = self.inventory.held_item();
+            let stack = stack.lock().await;
+            if stack.item == &Item::FILLED_MAP {
+                stack
+                    .get_data_component::<MapIdImpl>()
+                    .and_then(|map_id| u32::try_from(map_id.id).ok())
+            } else {
+                None
+            }
+        };
+
+        let mut sent_colors = self.sent_map_colors.lock().await;
+        if self.held_map_id.swap(map_id) != map_id {
+            *sent_colors = None;
+        }
+        let Some(map_id) = map_id else {
+            return;
+        };
+        let Some(map) = server.maps.get(map_id as i32).await else {
+            return;
+        };
+
+        let entity = &self.living_entity.entity;
+        let pos = entity.pos.load();
+        let in_map_dimension = self.world().dimension.minecraft_name == map.dimension;
+        let decoration = if map.tracking_position && in_map_dimension {
+            map.player_decoration(pos.x, pos.z, entity.yaw.load())
+        } else {
+            None
+        };
+
+        let patch = if in_map_dimension && map.covers(pos.x, pos.z) {
+            let patch = map.patch_since(sent_colors.as_deref());
+            if patch.is_some() {
+                *sent_colors = Some(map.colors.clone());
+            }
+            patch
+        } else {
+            None
+        };
+
+        self.client
+            .enqueue_packet(&CMapData::new(
+                VarInt(map_id as i32),
+                map.scale as i8,
+                map.locked,
+                Some(decoration.into_iter().collect()),
+                patch,
+            ))
+            .await;
+    }
+
     pub async fn drop_held_item(&self, drop_stack: bool) {
         // Do not hold both item stack and screen handler locks at the same time.
         let (dropped_stack, updated_stack, selected_slot) = {
@@ -2285,14 +2696,40 @@ impl Player {
         }
     }
 
-    pub async fn swap_item(&self) {
-        let (main_hand_item, off_hand_item) = self.inventory.swap_item().await;
-        let equipment = &[
-            (EquipmentSlot::MAIN_HAND, main_hand_item),
-            (EquipmentSlot::OFF_HAND, off_hand_item),
-        ];
-        self.living_entity.send_equipment_changes(equipment).await;
-        // todo this.player.stopUsingItem();
+    /// Exchanges the items in the main hand and the off hand, unless a plugin cancels it.
+    pub async fn swap_item(self: &Arc<Self>, server: &Server) {
+        let main_hand_slot = self.inventory.hand_slot(Hand::Main);
+        let main_hand_item = self.inventory.held_item().lock().await.clone();
+        let off_hand_item = self.inventory.off_hand_item().await.lock().await.clone();
+
+        send_cancellable! {{
+            server;
+            PlayerSwapHandItemsEvent::new(
+                self.clone(),
+                off_hand_item.clone(),
+                main_hand_item.clone(),
+            );
+
+            'after: {
+                self.living_entity.clear_active_hand().await;
+                self.inventory
+                    .set_stack(main_hand_slot, event.main_hand_item.clone())
+                    .await;
+                self.inventory
+                    .set_stack(PlayerInventory::OFF_HAND_SLOT, event.off_hand_item.clone())
+                    .await;
+                self.sync_hand_slot(main_hand_slot, event.main_hand_item).await;
+                self.sync_hand_slot(PlayerInventory::OFF_HAND_SLOT, event.off_hand_item)
+                    .await;
+            }
+
+            'cancelled: {
+                // The client already swapped the items locally
+                self.sync_hand_slot(main_hand_slot, main_hand_item).await;
+                self.sync_hand_slot(PlayerInventory::OFF_HAND_SLOT, off_hand_item)
+                    .await;
+            }
+        }}
     }
 
     pub async fn send_system_message(&self, text: &TextComponent) {
@@ -2482,6 +2919,9 @@ impl Player {
 
     /// Add experience points to the player.
     pub async fn add_experience_points(&self, added_points: i32) {
+        if added_points > 0 {
+            self.score.fetch_add(added_points, Ordering::Relaxed);
+        }
         let current_level = self.experience_level.load(Ordering::Relaxed);
         let current_points = self.experience_points.load(Ordering::Relaxed);
         let total_exp = experience::points_to_level(current_level) + current_points;
@@ -2674,6 +3114,54 @@ impl Player {
         }
     }
 
+    /// Opens the saddle, body armor and chest screen of the given horse.
+    pub async fn send_open_horse_inventory(&self, horse: Arc<HorseEntity>) {
+        let ClientPlatform::Java(java) = &self.client else {
+            return;
+        };
+        if !CMountScreenOpen::supports_version(java.version.load()) {
+            return;
+        }
+
+        if !self
+            .current_screen_handler
+            .lock()
+            .await
+            .lock()
+            .await
+            .as_any()
+            .is::<PlayerScreenHandler>()
+        {
+            self.close_handled_screen().await;
+        }
+
+        self.increment_screen_handler_sync_id();
+        let sync_id = self.screen_handler_sync_id.load(Ordering::Relaxed);
+
+        let entity = &horse.mob_entity.living_entity.entity;
+        let chest_columns = horse.inventory_columns();
+        let inventory: Arc<dyn Inventory> = horse.equipment.clone();
+        let screen_handler = HorseScreenHandler::new(
+            sync_id,
+            &self.inventory,
+            inventory,
+            entity.entity_type,
+            chest_columns,
+        )
+        .await;
+
+        java.enqueue_packet(&CMountScreenOpen::new(
+            sync_id.into(),
+            chest_columns.into(),
+            entity.entity_id,
+        ))
+        .await;
+
+        let screen_handler: Arc<Mutex<dyn ScreenHandler>> = Arc::new(Mutex::new(screen_handler));
+        self.on_screen_handler_opened(screen_handler.clone()).await;
+        *self.current_screen_handler.lock().await = screen_handler;
+    }
+
     pub async fn on_slot_click(&self, packet: SClickSlot) {
         self.update_last_action_time();
         let screen_handler = self.current_screen_handler.lock().await;
@@ -2738,6 +3226,35 @@ impl Player {
         }
     }
 
+    pub async fn on_button_click(&self, packet: SContainerButtonClick) {
+        self.update_last_action_time();
+        if self.gamemode.load() == GameMode::Spectator {
+            return;
+        }
+
+        let screen_handler = self.current_screen_handler.lock().await;
+        let mut screen_handler = screen_handler.lock().await;
+        if i32::from(screen_handler.sync_id()) != packet.window_id.0 {
+            return;
+        }
+
+        if !screen_handler.can_use(self) {
+            warn!(
+                "Player {} interacted with invalid menu {:?}",
+                self.gameprofile.name,
+                screen_handler.window_type()
+            );
+            return;
+        }
+
+        if screen_handler
+            .on_button_click(self, packet.button_id.0)
+            .await
+        {
+            screen_handler.send_content_updates().await;
+        }
+    }
+
     /// Check if the player has a specific permission
     pub async fn has_permission(&self, server: &Server, node: &str) -> bool {
         let perm_manager = server.permission_manager.read().await;
@@ -2750,14 +3267,24 @@ impl Player {
         self.gamemode.load() == GameMode::Creative
     }
 
+    /// Whether the player is sneaking while under any level of Invisibility, which makes them
+    /// much harder for mobs to notice.
+    pub async fn is_crouching_invisible(&self) -> bool {
+        self.living_entity.entity.sneaking.load(Ordering::Relaxed)
+            && self
+                .living_entity
+                .has_effect(&StatusEffect::INVISIBILITY)
+                .await
+    }
+
     /// Swing the hand of the player
     pub async fn swing_hand(&self, hand: Hand, all: bool) {
         let world = self.world();
         let entity_id = VarInt(self.entity_id());
 
         let animation = match hand {
-            Hand::Left => Animation::SwingMainArm,
-            Hand::Right => Animation::SwingOffhand,
+            Hand::Main => Animation::SwingMainArm,
+            Hand::Off => Animation::SwingOffhand,
         };
 
         let packet = CEntityAnimation::new(entity_id, animation);
@@ -2781,6 +3308,7 @@ impl NBTStorage for Player {
     fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
         Box::pin(async move {
             nbt.put_int("DataVersion", DATA_VERSION);
+            nbt.put_int(PUMPKIN_DATA_VERSION_KEY, PUMPKIN_DATA_VERSION);
             self.living_entity.write_nbt(nbt).await;
             self.inventory.write_nbt(nbt).await;
             self.ender_chest_inventory.write_nbt(nbt).await;
@@ -2792,6 +3320,7 @@ impl NBTStorage for Player {
                 experience::points_to_level(self.experience_level.load(Ordering::Relaxed))
                     + self.experience_points.load(Ordering::Relaxed);
             nbt.put_int("XpTotal", total_exp);
+            nbt.put_int("Score", self.score.load(Ordering::Relaxed));
             nbt.put_byte("playerGameType", self.gamemode.load() as i8);
             if let Some(previous_gamemode) = self.previous_gamemode.load() {
                 nbt.put_byte("previousPlayerGameType", previous_gamemode as i8);
@@ -2819,10 +3348,13 @@ impl NBTStorage for Player {
             self.ender_chest_inventory.read_nbt_non_mut(nbt).await;
             self.abilities.lock().await.read_nbt(nbt).await;
 
-            self.gamemode.store(
-                GameMode::try_from(nbt.get_byte("playerGameType").unwrap_or(0))
-                    .unwrap_or(GameMode::Survival),
-            );
+            let gamemode = GameMode::try_from(nbt.get_byte("playerGameType").unwrap_or(0))
+                .unwrap_or(GameMode::Survival);
+            self.gamemode.store(gamemode);
+            self.living_entity
+                .entity
+                .no_clip
+                .store(gamemode == GameMode::Spectator, Ordering::Relaxed);
 
             self.previous_gamemode.store(
                 nbt.get_byte("previousPlayerGameType")
@@ -2844,6 +3376,8 @@ impl NBTStorage for Player {
             self.experience_level.store(level, Ordering::Relaxed);
             self.experience_progress.store(progress);
             self.experience_points.store(points, Ordering::Relaxed);
+            self.score
+                .store(nbt.get_int("Score").unwrap_or(0), Ordering::Relaxed);
 
             // Load any saved spawnpoint data (SpawnX/SpawnY/SpawnZ, SpawnDimension, SpawnForced)
             if let (Some(x), Some(y), Some(z)) = (
@@ -3058,13 +3592,28 @@ impl EntityBase for Player {
         yaw: Option<f32>,
         pitch: Option<f32>,
         world: Arc<World>,
+        cause: TeleportCause,
     ) -> TeleportFuture {
         Box::pin(async move {
+            let server = self.world().server.upgrade().unwrap();
+            let event = server
+                .plugin_manager
+                .fire(EntityTeleportEvent::new(
+                    self.clone(),
+                    self.living_entity.entity.pos.load(),
+                    position,
+                    cause,
+                ))
+                .await;
+            if event.cancelled {
+                return false;
+            }
+            let position = event.to;
+
             if Arc::ptr_eq(&world, &self.world()) {
                 // Same world
                 let yaw = yaw.unwrap_or(self.living_entity.entity.yaw.load());
                 let pitch = pitch.unwrap_or(self.living_entity.entity.pitch.load());
-                let server = self.world().server.upgrade().unwrap();
                 send_cancellable! {{
                     server;
                     PlayerTeleportEvent {
@@ -3089,10 +3638,14 @@ impl EntityBase for Player {
                                 entity.on_ground.load(Ordering::SeqCst),
                             ))
                             .await;
+                        true
+                    }
+                    'cancelled: {
+                        false
                     }
                 }}
             } else {
-                self.teleport_world(world, position, yaw, pitch).await;
+                self.teleport_world(world, position, yaw, pitch).await
             }
         })
     }
@@ -3238,6 +3791,7 @@ impl Abilities {
                 self.invulnerable = false;
             }
         }
+        self.allow_modify_world = !matches!(gamemode, GameMode::Adventure | GameMode::Spectator);
     }
 }
 
@@ -3526,4 +4080,209 @@ impl InventoryPlayer for Player {
             }
         })
     }
+
+    fn play_container_sound(&self, sound: Sound) -> PlayerFuture<'_, ()> {
+        Box::pin(async move {
+            let world = self.world();
+            if let Some(position) = self.open_container_pos.load() {
+                world
+                    .play_block_sound(sound, SoundCategory::Blocks, position)
+                    .await;
+            } else {
+                world
+                    .play_sound(sound, SoundCategory::Blocks, &self.position())
+                    .await;
+            }
+        })
+    }
+
+    fn spawn_container_experience(&self, amount: u32) -> PlayerFuture<'_, ()> {
+        Box::pin(async move {
+            let position = self
+                .open_container_pos
+                .load()
+                .map_or_else(|| self.position(), |position| position.to_centered_f64());
+            self.world().spawn_experience_orbs(position, amount).await;
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::io::Cursor;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use pumpkin_protocol::bedrock::RAKNET_GAME_PACKET;
+    use pumpkin_protocol::bedrock::frame_set::FrameSet;
+    use pumpkin_protocol::bedrock::packet_decoder::UDPNetworkDecoder;
+    use pumpkin_protocol::packet::MultiVersionJavaPacket;
+    use pumpkin_util::GameMode;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::{TcpListener, TcpStream, UdpSocket};
+    use uuid::Uuid;
+
+    use super::Player;
+    use crate::net::bedrock::BedrockClient;
+    use crate::net::java::JavaClient;
+    use crate::net::{ClientPlatform, GameProfile, PlayerConfig};
+    use crate::world::World;
+
+    /// The other end of a test player's connection.
+    pub(crate) struct TestConnection {
+        stream: TcpStream,
+        buf: Vec<u8>,
+    }
+
+    impl TestConnection {
+        /// Returns the ids of the packets received until nothing arrives for a while.
+        pub(crate) async fn received_packet_ids(&mut self) -> Vec<i32> {
+            let mut ids = Vec::new();
+            let mut chunk = [0; 64 * 1024];
+            while let Ok(Ok(read)) =
+                tokio::time::timeout(Duration::from_millis(200), self.stream.read(&mut chunk)).await
+            {
+                if read == 0 {
+                    break;
+                }
+                self.buf.extend_from_slice(&chunk[..read]);
+                // Frames are neither compressed nor encrypted: length, packet id, data
+                let mut start = 0;
+                while let Some((length, header)) = read_var_int(&self.buf[start..]) {
+                    let length = length as usize;
+                    if self.buf.len() - start < header + length {
+                        break;
+                    }
+                    let (id, _) = read_var_int(&self.buf[start + header..]).unwrap();
+                    ids.push(id);
+                    start += header + length;
+                }
+                self.buf.drain(..start);
+            }
+            ids
+        }
+
+        /// Whether a `P` packet was received until nothing arrived for a while.
+        pub(crate) async fn received<P: MultiVersionJavaPacket>(&mut self) -> bool {
+            self.received_packet_ids()
+                .await
+                .contains(&P::PACKET_ID.latest_id)
+        }
+    }
+
+    /// The other end of a Bedrock test player's connection.
+    pub(crate) struct BedrockTestConnection {
+        socket: UdpSocket,
+    }
+
+    impl BedrockTestConnection {
+        /// Returns the ids of the game packets received until nothing arrives for a while.
+        /// Packets split over several frames are skipped.
+        pub(crate) async fn received_packet_ids(&mut self) -> Vec<i32> {
+            let mut ids = Vec::new();
+            let mut decoder = UDPNetworkDecoder::new();
+            let mut datagram = [0; 64 * 1024];
+            while let Ok(Ok(read)) =
+                tokio::time::timeout(Duration::from_millis(200), self.socket.recv(&mut datagram))
+                    .await
+            {
+                if !(0x80..0x8d).contains(&datagram[0]) {
+                    continue;
+                }
+                let Ok(frame_set) = FrameSet::read(&mut &datagram[1..read]) else {
+                    continue;
+                };
+                for frame in frame_set.frames {
+                    if frame.split_size > 0
+                        || frame.payload.first().map(|id| i32::from(*id))
+                            != Some(RAKNET_GAME_PACKET)
+                    {
+                        continue;
+                    }
+                    let mut payload = Cursor::new(frame.payload);
+                    payload.set_position(1);
+                    if let Ok(packet) = decoder.get_game_packet(payload) {
+                        ids.push(packet.id);
+                    }
+                }
+            }
+            ids
+        }
+    }
+
+    /// Returns the value and the size of the `VarInt` at the start of `buf`.
+    fn read_var_int(buf: &[u8]) -> Option<(i32, usize)> {
+        let mut value = 0;
+        for (i, byte) in buf.iter().take(5).enumerate() {
+            value |= i32::from(byte & 0x7F) << (7 * i);
+            if byte & 0x80 == 0 {
+                return Some((value, i + 1));
+            }
+        }
+        None
+    }
+
+    /// A survival player in `world`, connected to a Java client over loopback. The world must
+    /// belong to a server.
+    pub(crate) async fn test_player(
+        world: &Arc<World>,
+        name: &str,
+    ) -> (Arc<Player>, TestConnection) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let (remote, accepted) = tokio::join!(
+            TcpStream::connect(listener.local_addr().unwrap()),
+            listener.accept()
+        );
+        let (stream, address) = accepted.unwrap();
+        let mut client = JavaClient::new(stream, address, 0);
+        client.start_outgoing_packet_task();
+
+        let player = add_test_player(world, ClientPlatform::Java(client), name).await;
+        let connection = TestConnection {
+            stream: remote.unwrap(),
+            buf: Vec::new(),
+        };
+        (player, connection)
+    }
+
+    /// A survival player in `world`, connected to a Bedrock client over loopback. The world
+    /// must belong to a server.
+    pub(crate) async fn test_bedrock_player(
+        world: &Arc<World>,
+        name: &str,
+    ) -> (Arc<Player>, BedrockTestConnection) {
+        let server_socket = Arc::new(UdpSocket::bind("127.0.0.1:0").await.unwrap());
+        let remote = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let mut client =
+            BedrockClient::new(server_socket, remote.local_addr().unwrap(), Arc::default());
+        client.start_outgoing_packet_task();
+
+        let player = add_test_player(world, ClientPlatform::Bedrock(Arc::new(client)), name).await;
+        (player, BedrockTestConnection { socket: remote })
+    }
+
+    async fn add_test_player(
+        world: &Arc<World>,
+        client: ClientPlatform,
+        name: &str,
+    ) -> Arc<Player> {
+        let profile = GameProfile {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            properties: Vec::new(),
+            profile_actions: None,
+        };
+        let player = Arc::new(
+            Player::new(
+                client,
+                profile,
+                PlayerConfig::default(),
+                world.clone(),
+                GameMode::Survival,
+            )
+            .await,
+        );
+        world.add_player(player.clone()).unwrap();
+        player
+    }
 }
@@ -3,7 +3,8 @@ use std::sync::atomic::AtomicBool;
 
 use crate::{
     entity::{
-        Entity, EntityBase, EntityBaseFuture, NBTStorage, projectile::ThrownItemEntity,
+        Entity, EntityBase, EntityBaseFuture, NBTStorage,
+        projectile::{ProjectileHit, ThrownItemEntity},
         r#type::from_type,
     },
     server::Server,
@@ -16,6 +17,7 @@ use pumpkin_protocol::codec::item_stack_seralizer::ItemStackSerializer;
 use pumpkin_protocol::java::client::play::Metadata;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::item::ItemStack;
+use rand::RngExt;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
@@ -118,12 +120,21 @@ impl EntityBase for EggEntity {
                 )
                 .await;
 
-            // Decide spawn count per probabilities:
-            // r == 0 -> spawn 4 (1/256)
-            // r in 1..31 -> spawn 1 (31/256)
-            // else -> 0
-            let r: u8 = rand::random(); // 0..=255
-            let to_spawn = if r == 0 { 4usize } else { usize::from(r < 32) };
+            if let ProjectileHit::Entity { ref entity, .. } = hit {
+                self.thrown.damage_hit_entity(entity.as_ref(), 0.0).await;
+            }
+
+            // One in 8 eggs hatches a chicken, one in 32 of those hatches four
+            let to_spawn = {
+                let mut rng = rand::rng();
+                if rng.random_range(0..8) != 0 {
+                    0
+                } else if rng.random_range(0..32) == 0 {
+                    4
+                } else {
+                    1
+                }
+            };
 
             // Spawn chickens in a separate task to prevent stack overflow
             if to_spawn > 0 {
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+use crate::{
+    entity::{
+        Entity, EntityBase, EntityBaseFuture, NBTStorage, TeleportCause,
+        projectile::{ProjectileHit, ThrownItemEntity},
+        r#type::from_type,
+    },
+    server::Server,
+};
+use pumpkin_data::BlockDirection;
+use pumpkin_data::damage::DamageType;
+use pumpkin_data::entity::{EntityStatus, EntityType};
+use pumpkin_data::sound::{Sound, SoundCategory};
+use pumpkin_util::math::vector3::Vector3;
+use rand::RngExt;
+use uuid::Uuid;
+
+const TELEPORT_DAMAGE: f32 = 5.0;
+const ENDERMITE_CHANCE: f32 = 0.05;
+/// Distance kept between the thrower and the face of the hit block
+const FACE_OFFSET: f64 = 0.31;
+
+pub struct EnderPearlEntity {
+    pub thrown: ThrownItemEntity,
+}
+
+impl EnderPearlEntity {
+    pub async fn new_shot(entity: Entity, shooter: &Entity) -> Self {
+        let thrown = ThrownItemEntity::new(entity, shooter);
+        thrown
+            .entity
+            .set_velocity(Vector3::new(0.0, 0.1, 0.0))
+            .await;
+        Self { thrown }
+    }
+
+    /// Where the thrower lands, moved out of the hit block so they don't end up inside it.
+    fn teleport_target(hit: &ProjectileHit, owner_height: f64) -> Vector3<f64> {
+        let pos = hit.hit_pos();
+        match hit.face() {
+            Some(BlockDirection::Down) => pos.add_raw(0.0, -owner_height, 0.0),
+            Some(
+                face @ (BlockDirection::North
+                | BlockDirection::South
+                | BlockDirection::West
+                | BlockDirection::East),
+            ) => {
+                let offset = face.to_offset();
+                pos.add_raw(
+                    f64::from(offset.x) * FACE_OFFSET,
+                    0.0,
+                    f64::from(offset.z) * FACE_OFFSET,
+                )
+            }
+            Some(BlockDirection::Up) | None => pos,
+        }
+    }
+}
+
+impl NBTStorage for EnderPearlEntity {}
+
+impl EntityBase for EnderPearlEntity {
+    fn tick<'a>(
+        &'a self,
+        caller: Arc<dyn EntityBase>,
+        server: &'a Server,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move { self.thrown.process_tick(caller, server).await })
+    }
+
+    fn get_entity(&self) -> &Entity {
+        self.thrown.get_entity()
+    }
+
+    fn get_living_entity(&self) -> Option<&crate::entity::living::LivingEntity> {
+        None
+    }
+
+    fn as_nbt_storage(&self) -> &dyn NBTStorage {
+        self
+    }
+
+    fn on_hit(&self, hit: ProjectileHit) -> EntityBaseFuture<'_, ()> {
+        Box::pin(async move {
+            let world = self.get_entity().world.load_full();
+
+            world
+                .send_entity_status(
+                    self.get_entity(),
+                    EntityStatus::PlayDeathSoundOrAddProjectileHitParticles,
+                )
+                .await;
+
+            if let ProjectileHit::Entity { ref entity, .. } = hit {
+                self.thrown.damage_hit_entity(entity.as_ref(), 0.0).await;
+            }
+
+            // The pearl is wasted if the thrower went offline, changed dimension or died
+            let Some(owner) = self
+                .thrown
+                .owner_id
+                .and_then(|id| world.get_player_by_id(id))
+            else {
+                return;
+            };
+            if owner.living_entity.dead.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let owner_entity = &owner.living_entity.entity;
+            let owner_pos = owner_entity.pos.load();
+            let spawn_endermite = world.level_info.load().game_rules.spawn_mobs
+                && rand::rng().random::<f32>() < ENDERMITE_CHANCE;
+            if spawn_endermite {
+                let endermite =
+                    from_type(&EntityType::ENDERMITE, owner_pos, &world, Uuid::new_v4()).await;
+                endermite
+                    .get_entity()
+                    .set_rotation(owner_entity.yaw.load(), owner_entity.pitch.load());
+                world.spawn_entity(endermite).await;
+            }
+
+            let target = Self::teleport_target(&hit, f64::from(owner_entity.height()));
+            owner
+                .clone()
+                .teleport(target, None, None, world.clone(), TeleportCause::EnderPearl)
+                .await;
+            world
+                .play_sound(Sound::EntityPlayerTeleport, SoundCategory::Players, &target)
+                .await;
+
+            owner.living_entity.fall_distance.store(0.0);
+            owner
+                .damage(owner.as_ref(), TELEPORT_DAMAGE, DamageType::ENDER_PEARL)
+                .await;
+        })
+    }
+}
@@ -3,12 +3,16 @@ use crate::{
     server::Server,
     world::World,
 };
-use pumpkin_data::{entity::EntityStatus, meta_data_type::MetaDataType, tracked_data::TrackedData};
+use pumpkin_data::{
+    damage::DamageType, data_component_impl::FireworksImpl, entity::EntityStatus,
+    meta_data_type::MetaDataType, tracked_data::TrackedData,
+};
 use pumpkin_protocol::{codec::optional_int::OptionalInt, java::client::play::Metadata};
 use pumpkin_util::{
     math::vector3::Vector3,
     random::{RandomGenerator, RandomImpl, get_seed, xoroshiro128::Xoroshiro},
 };
+use pumpkin_world::item::ItemStack;
 use std::sync::atomic::AtomicBool;
 use std::sync::{
     Arc,
@@ -20,10 +24,19 @@ pub struct FireworkRocketEntity {
     shooter_id: Option<i32>,
     life: AtomicU32,
     life_time: AtomicU32,
+    /// How many explosions the rocket's `fireworks` component has
+    explosions: u16,
 }
 
 impl FireworkRocketEntity {
-    pub async fn new(entity: Entity) -> Self {
+    /// Vanilla: rockets fly for `10 * (1 + flight_duration)` ticks plus some randomness.
+    fn life_time(random: &mut RandomGenerator, fireworks: Option<&FireworksImpl>) -> u32 {
+        let flight = 1 + fireworks.map_or(0, |fireworks| fireworks.flight_duration);
+        (10 * flight + random.next_bounded_i32(6) + random.next_bounded_i32(7)) as u32
+    }
+
+    pub async fn new(entity: Entity, stack: &ItemStack) -> Self {
+        let fireworks = stack.get_data_component::<FireworksImpl>();
         let mut random = RandomGenerator::Xoroshiro(Xoroshiro::from_seed(get_seed()));
 
         entity
@@ -42,13 +55,13 @@ impl FireworkRocketEntity {
             },
             shooter_id: None,
             life: 0.into(),
-            // TODO
-            life_time: (10 + random.next_bounded_i32(6) as u32 + random.next_bounded_i32(7) as u32)
-                .into(),
+            life_time: Self::life_time(&mut random, fireworks).into(),
+            explosions: fireworks.map_or(0, |fireworks| fireworks.explosions.len() as u16),
         }
     }
 
-    pub async fn new_shot(entity: Entity, shooter: &Entity) -> Self {
+    pub async fn new_shot(entity: Entity, shooter: &Entity, stack: &ItemStack) -> Self {
+        let fireworks = stack.get_data_component::<FireworksImpl>();
         let mut random = RandomGenerator::Xoroshiro(Xoroshiro::from_seed(get_seed()));
 
         // Set random initial velocity
@@ -68,8 +81,8 @@ impl FireworkRocketEntity {
             entity: thrown,
             shooter_id: Some(shooter.entity_id),
             life: 0.into(),
-            life_time: (10 + random.next_bounded_i32(6) as u32 + random.next_bounded_i32(7) as u32)
-                .into(),
+            life_time: Self::life_time(&mut random, fireworks).into(),
+            explosions: fireworks.map_or(0, |fireworks| fireworks.explosions.len() as u16),
         };
 
         // Set shooter metadata
@@ -92,7 +105,16 @@ impl FireworkRocketEntity {
             .send_entity_status(entity, EntityStatus::ExplodeFireworkClient)
             .await;
 
-        // TODO: Explode/colors
+        // A rocket with explosions hurts the player it is boosting
+        if self.explosions > 0
+            && let Some(shooter) = self.shooter_id.and_then(|id| world.get_entity_by_id(id))
+        {
+            let damage = 5.0 + f32::from(self.explosions * 2);
+            shooter
+                .damage(shooter.as_ref(), damage, DamageType::FIREWORKS)
+                .await;
+        }
+        // TODO: Explode/colors, damage nearby entities
 
         entity.remove().await;
     }
@@ -115,8 +137,8 @@ impl EntityBase for FireworkRocketEntity {
 
             if let Some(shooter_id) = self.shooter_id {
                 // Check if the player who fired this rocket still exists in the world
-                if let Some(shooter) = world.get_entity_by_id(shooter_id) {
-                    let shooter = shooter.get_entity();
+                if let Some(shooter_base) = world.get_entity_by_id(shooter_id) {
+                    let shooter = shooter_base.get_entity();
 
                     // Logic for boosting Elytra flight
                     if shooter.fall_flying.load(Ordering::Relaxed) {
@@ -126,7 +148,12 @@ impl EntityBase for FireworkRocketEntity {
                         let new_shooter_vel =
                             shooter_vel + (rotation * 0.1 + (rotation * 1.5 - shooter_vel) * 0.5);
 
-                        shooter.set_velocity(new_shooter_vel).await;
+                        if shooter_base.get_player().is_some() {
+                            // The client boosts itself with its own copy of the rocket
+                            shooter.velocity.store(new_shooter_vel);
+                        } else {
+                            shooter.set_velocity(new_shooter_vel).await;
+                        }
 
                         entity.set_pos(shooter.pos.load());
                         entity.set_velocity(new_shooter_vel).await;
@@ -1,6 +1,7 @@
 use super::{Entity, EntityBase, NBTStorage, living::LivingEntity};
 use crate::server::Server;
 use pumpkin_data::BlockDirection;
+use pumpkin_data::damage::DamageType;
 use pumpkin_data::entity::EntityType;
 use pumpkin_protocol::java::client::play::CEntityVelocity;
 use pumpkin_util::math::boundingbox::BoundingBox;
@@ -10,6 +11,7 @@ use std::{
     sync::atomic::{AtomicBool, Ordering},
 };
 pub mod egg;
+pub mod ender_pearl;
 pub mod firework_rocket;
 pub mod snowball;
 pub mod wind_charge;
@@ -20,6 +22,7 @@ pub fn is_projectile(entity_type: &EntityType) -> bool {
         || *entity_type == EntityType::SNOWBALL
         || *entity_type == EntityType::FIREWORK_ROCKET
         || *entity_type == EntityType::WIND_CHARGE
+        || *entity_type == EntityType::ENDER_PEARL
 }
 
 pub struct ThrownItemEntity {
@@ -33,7 +36,8 @@ impl ThrownItemEntity {
     pub fn new(entity: Entity, owner: &Entity) -> Self {
         let mut owner_pos = owner.pos.load();
         owner_pos.y = owner.get_eye_height() - 0.1;
-        entity.pos.store(owner_pos);
+        entity.set_pos(owner_pos);
+        entity.set_spawn_data_entity_id(owner.entity_id);
         Self {
             entity,
             owner_id: Some(owner.entity_id),
@@ -42,6 +46,12 @@ impl ThrownItemEntity {
         }
     }
 
+    /// Returns the entity that threw this projectile if it is still in the same world.
+    #[must_use]
+    pub fn get_owner(&self) -> Option<Arc<dyn EntityBase>> {
+        self.entity.world.load().get_entity_by_id(self.owner_id?)
+    }
+
     pub fn set_velocity_from(
         &self,
         _shooter: &Entity,
@@ -194,12 +204,37 @@ impl ThrownItemEntity {
                 return;
             }
 
+            if let ProjectileHit::Block {
+                pos, face, hit_pos, ..
+            } = &h
+            {
+                world
+                    .block_registry
+                    .on_projectile_hit(&world, caller.as_ref(), pos, *face, *hit_pos)
+                    .await;
+            }
+
             // Just trigger hit effects and remove
             caller.on_hit(h).await;
             entity.remove().await;
         }
     }
 
+    /// Applies the knockback of a thrown item to the hit entity, with `damage` attributed to the thrower.
+    pub async fn damage_hit_entity(&self, target: &dyn EntityBase, damage: f32) {
+        let owner = self.get_owner();
+        target
+            .damage_with_context(
+                target,
+                damage,
+                DamageType::THROWN,
+                Some(self.entity.pos.load()),
+                Some(&self.entity),
+                owner.as_deref(),
+            )
+            .await;
+    }
+
     /// Returns if collision should be skipped (e.g. owner or projectile vs projectile)
     fn should_skip_collision(&self, self_ent: &Entity, other: &Arc<dyn EntityBase>) -> bool {
         let other_ent = other.get_entity();
@@ -6,7 +6,6 @@ use crate::{
     entity::{Entity, EntityBase, EntityBaseFuture, NBTStorage, projectile::ThrownItemEntity},
     server::Server,
 };
-use pumpkin_data::damage::DamageType;
 use pumpkin_data::entity::{EntityStatus, EntityType};
 use pumpkin_util::math::vector3::Vector3;
 
@@ -75,18 +74,11 @@ impl EntityBase for SnowballEntity {
                 )
                 .await;
 
-            // Handle entity-specific damage
+            // Snowballs only hurt blazes, but always knock the hit entity back
             if let ProjectileHit::Entity { ref entity, .. } = hit {
-                let entity_clone = entity.clone();
-
-                tokio::spawn(async move {
-                    let is_blaze = entity_clone.get_entity().entity_type.id == EntityType::BLAZE.id;
-                    let damage = if is_blaze { 3.0 } else { 0.0 }; // Only damage blazes
-
-                    entity_clone
-                        .damage(entity_clone.as_ref(), damage, DamageType::THROWN)
-                        .await;
-                });
+                let is_blaze = entity.get_entity().entity_type.id == EntityType::BLAZE.id;
+                let damage = if is_blaze { 3.0 } else { 0.0 };
+                self.thrown.damage_hit_entity(entity.as_ref(), damage).await;
             }
         })
     }
@@ -1,4 +1,4 @@
-use super::{Entity, EntityBase, NBTStorage, living::LivingEntity};
+use super::{Entity, EntityBase, NBTStorage, living::LivingEntity, player::Player};
 use crate::{entity::EntityBaseFuture, server::Server};
 use core::f32;
 use pumpkin_data::{Block, meta_data_type::MetaDataType, tracked_data::TrackedData};
@@ -19,16 +19,24 @@ pub struct TNTEntity {
     entity: Entity,
     power: f32,
     fuse: AtomicU32,
+    /// The player who lit the TNT, the explosion damage is attributed to them
+    igniter: Option<Arc<Player>>,
 }
 
 impl TNTEntity {
-    pub const fn new(entity: Entity, power: f32, fuse: u32) -> Self {
+    pub const fn new(entity: Entity, power: f32, fuse: u32, igniter: Option<Arc<Player>>) -> Self {
         Self {
             entity,
             power,
             fuse: AtomicU32::new(fuse),
+            igniter,
         }
     }
+
+    #[must_use]
+    pub const fn igniter(&self) -> Option<&Arc<Player>> {
+        self.igniter.as_ref()
+    }
 }
 
 impl NBTStorage for TNTEntity {}
@@ -66,7 +74,12 @@ impl EntityBase for TNTEntity {
                 self.entity
                     .world
                     .load()
-                    .explode(self.entity.pos.load(), self.power)
+                    .explode_attributed(
+                        self.entity.pos.load(),
+                        self.power,
+                        Some(caller),
+                        self.igniter.clone(),
+                    )
                     .await;
             } else {
                 entity.update_fluid_state(&caller).await;
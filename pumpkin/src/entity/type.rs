@@ -24,7 +24,10 @@ use crate::{
             zombie::{ZombieEntity, drowned::DrownedEntity, husk::HuskEntity},
             zombie_villager::ZombieVillagerEntity,
         },
-        passive::{iron_golem::IronGolemEntity, snow_golem::SnowGolemEntity, wolf::WolfEntity},
+        passive::{
+            cat::CatEntity, horse::HorseEntity, iron_golem::IronGolemEntity, parrot::ParrotEntity,
+            snow_golem::SnowGolemEntity, wolf::WolfEntity,
+        },
     },
     world::World,
 };
@@ -58,6 +61,16 @@ pub async fn from_type(
         id if id == EntityType::SNOW_GOLEM.id => SnowGolemEntity::new(entity).await,
         id if id == EntityType::IRON_GOLEM.id => IronGolemEntity::new(entity).await,
         id if id == EntityType::WOLF.id => WolfEntity::new(entity).await,
+        id if id == EntityType::CAT.id => CatEntity::new(entity).await,
+        id if id == EntityType::PARROT.id => ParrotEntity::new(entity).await,
+        id if id == EntityType::HORSE.id
+            || id == EntityType::DONKEY.id
+            || id == EntityType::MULE.id
+            || id == EntityType::SKELETON_HORSE.id
+            || id == EntityType::ZOMBIE_HORSE.id =>
+        {
+            HorseEntity::new(entity).await
+        }
         id if id == EntityType::WITHER.id => WitherEntity::new(entity).await,
         id if id == EntityType::ARMOR_STAND.id => Arc::new(ArmorStandEntity::new(entity)),
         id if id == EntityType::PAINTING.id => Arc::new(PaintingEntity::new(entity)),
@@ -1,5 +1,7 @@
+use pumpkin_data::translation;
 use pumpkin_inventory::InventoryError;
 use pumpkin_protocol::ser::ReadingError;
+use pumpkin_util::text::TextComponent;
 use pumpkin_world::data::player_data::PlayerDataError;
 use std::fmt::Display;
 use tracing::Level;
@@ -15,7 +17,8 @@ pub trait PumpkinError: Send + std::error::Error + Display {
 
     fn severity(&self) -> Level;
 
-    fn client_kick_reason(&self) -> Option<String>;
+    /// The reason shown on the client's disconnect screen, `None` to use a generic one.
+    fn client_kick_reason(&self) -> Option<TextComponent>;
 }
 
 impl<ErrorType: PumpkinError + 'static> From<ErrorType> for Box<dyn PumpkinError> {
@@ -50,7 +53,7 @@ impl PumpkinError for InventoryError {
         }
     }
 
-    fn client_kick_reason(&self) -> Option<String> {
+    fn client_kick_reason(&self) -> Option<TextComponent> {
         None
     }
 }
@@ -64,7 +67,7 @@ impl PumpkinError for ReadingError {
         Level::ERROR
     }
 
-    fn client_kick_reason(&self) -> Option<String> {
+    fn client_kick_reason(&self) -> Option<TextComponent> {
         None
     }
 }
@@ -78,10 +81,10 @@ impl PumpkinError for PlayerDataError {
         Level::WARN
     }
 
-    fn client_kick_reason(&self) -> Option<String> {
-        match self {
-            Self::Io(err) => Some(format!("Failed to load player data: {err}")),
-            Self::Nbt(err) => Some(format!("Failed to parse player data: {err}")),
-        }
+    fn client_kick_reason(&self) -> Option<TextComponent> {
+        Some(TextComponent::translate(
+            translation::MULTIPLAYER_DISCONNECT_INVALID_PLAYER_DATA,
+            [],
+        ))
     }
 }
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use crate::entity::Entity;
 use crate::entity::decoration::armor_stand::ArmorStandEntity;
 use crate::entity::player::Player;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
@@ -47,7 +47,7 @@ impl ItemBehaviour for ArmorStandItem {
         _cursor_pos: Vector3<f32>,
         _block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
             let position = Self::calculate_placement_position(&location, face).to_f64();
@@ -92,7 +92,9 @@ impl ItemBehaviour for ArmorStandItem {
                 let armor_stand = ArmorStandEntity::new(entity);
 
                 world.spawn_entity(Arc::new(armor_stand)).await;
+                return ItemActionResult::Success;
             }
+            ItemActionResult::Pass
         })
     }
 
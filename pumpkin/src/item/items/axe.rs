@@ -1,7 +1,7 @@
 use std::pin::Pin;
 
 use crate::entity::player::Player;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use pumpkin_data::BlockDirection;
 use pumpkin_data::block_properties::BlockProperties;
@@ -32,7 +32,7 @@ impl ItemBehaviour for AxeItem {
         _cursor_pos: Vector3<f32>,
         block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             // I tried to follow mojang order of doing things.
             let world = player.world();
@@ -83,9 +83,13 @@ impl ItemBehaviour for AxeItem {
                 false
             };
 
-            if changed && player.gamemode.load() != GameMode::Creative {
+            if !changed {
+                return ItemActionResult::Pass;
+            }
+            if player.gamemode.load() != GameMode::Creative {
                 item.damage_item_with_context(1, false);
             }
+            ItemActionResult::Success
         })
     }
 
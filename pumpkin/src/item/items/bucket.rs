@@ -2,7 +2,7 @@ use std::{pin::Pin, sync::Arc};
 
 use crate::{
     entity::player::Player,
-    item::{ItemBehaviour, ItemMetadata},
+    item::{ItemActionResult, ItemBehaviour, ItemMetadata},
 };
 use pumpkin_data::{
     Block,
@@ -12,7 +12,7 @@ use pumpkin_data::{
     sound::{Sound, SoundCategory},
 };
 use pumpkin_util::{
-    GameMode,
+    GameMode, Hand,
     math::{position::BlockPos, vector3::Vector3},
 };
 use pumpkin_world::{inventory::Inventory, item::ItemStack, tick::TickPriority, world::BlockFlags};
@@ -98,7 +98,8 @@ impl ItemBehaviour for EmptyBucketItem {
         &'a self,
         _block: &'a Item,
         player: &'a Player,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        hand: Hand,
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
             let (start_pos, end_pos) = get_start_and_end_pos(player);
@@ -119,7 +120,7 @@ impl ItemBehaviour for EmptyBucketItem {
 
             let Some((block_pos, direction)) = world.raycast(start_pos, end_pos, checker).await
             else {
-                return;
+                return ItemActionResult::Pass;
             };
 
             let (block, state) = world.get_block_and_state_id(&block_pos).await;
@@ -177,7 +178,7 @@ impl ItemBehaviour for EmptyBucketItem {
                         )
                         .await;
                 } else {
-                    return;
+                    return ItemActionResult::Pass;
                 }
             }
 
@@ -191,7 +192,7 @@ impl ItemBehaviour for EmptyBucketItem {
                 //Check if player already has the item in their inventory
                 for i in 0..player.inventory.main_inventory.len() {
                     if player.inventory.main_inventory[i].lock().await.item.id == item.id {
-                        return;
+                        return ItemActionResult::Success;
                     }
                 }
                 //If not, add it to the inventory
@@ -204,9 +205,10 @@ impl ItemBehaviour for EmptyBucketItem {
                 let item_stack = ItemStack::new(1, item);
                 player
                     .inventory
-                    .set_stack(player.inventory.get_selected_slot().into(), item_stack)
+                    .set_stack(player.inventory.hand_slot(hand), item_stack)
                     .await;
             }
+            ItemActionResult::Success
         })
     }
 
@@ -220,7 +222,8 @@ impl ItemBehaviour for FilledBucketItem {
         &'a self,
         item: &'a Item,
         player: &'a Player,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        hand: Hand,
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
             let (start_pos, end_pos) = get_start_and_end_pos(player);
@@ -233,7 +236,7 @@ impl ItemBehaviour for FilledBucketItem {
             };
 
             let Some((pos, direction)) = world.raycast(start_pos, end_pos, checker).await else {
-                return;
+                return ItemActionResult::Pass;
             };
 
             if item.id != Item::LAVA_BUCKET.id && world.dimension == Dimension::THE_NETHER {
@@ -246,7 +249,7 @@ impl ItemBehaviour for FilledBucketItem {
                         (rand::random::<f32>() - rand::random::<f32>()).mul_add(0.8, 2.6),
                     )
                     .await;
-                return;
+                return ItemActionResult::Success;
             }
             let (block, state) = world.get_block_and_state_id(&pos).await;
             if waterlogged_check(block, state).is_some() && item.id == Item::WATER_BUCKET.id {
@@ -264,7 +267,7 @@ impl ItemBehaviour for FilledBucketItem {
 
                 if waterlogged_check(block, state.id).is_some() {
                     if item.id == Item::LAVA_BUCKET.id {
-                        return;
+                        return ItemActionResult::Pass;
                     }
                     let state_id = set_waterlogged(block, state.id, true);
 
@@ -296,7 +299,7 @@ impl ItemBehaviour for FilledBucketItem {
                         )
                         .await;
                 } else {
-                    return;
+                    return ItemActionResult::Pass;
                 }
             }
 
@@ -305,9 +308,10 @@ impl ItemBehaviour for FilledBucketItem {
                 let item_stack = ItemStack::new(1, &Item::BUCKET);
                 player
                     .inventory
-                    .set_stack(player.inventory.get_selected_slot().into(), item_stack)
+                    .set_stack(player.inventory.hand_slot(hand), item_stack)
                     .await;
             }
+            ItemActionResult::Success
         })
     }
 
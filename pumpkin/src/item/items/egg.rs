@@ -4,10 +4,11 @@ use std::sync::Arc;
 use crate::entity::Entity;
 use crate::entity::player::Player;
 use crate::entity::projectile::egg::EggEntity;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::sound::Sound;
+use pumpkin_util::Hand;
 use pumpkin_world::item::ItemStack;
 
 pub struct EggItem;
@@ -25,7 +26,8 @@ impl ItemBehaviour for EggItem {
         &'a self,
         _block: &'a Item,
         player: &'a Player,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        hand: Hand,
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let position = player.position();
             let world = player.world();
@@ -38,7 +40,13 @@ impl ItemBehaviour for EggItem {
                 .await;
 
             // Capture the held item stack and pass it to the thrown egg entity
-            let item_stack: ItemStack = player.inventory.held_item().lock().await.clone();
+            let item_stack: ItemStack = player
+                .inventory
+                .get_stack_in_hand(hand)
+                .await
+                .lock()
+                .await
+                .clone();
 
             let entity = Entity::new(world.clone(), position, &EntityType::EGG);
             let egg = EggEntity::new_shot(entity, &player.living_entity.entity).await;
@@ -51,6 +59,7 @@ impl ItemBehaviour for EggItem {
             egg.thrown
                 .set_velocity_from(&player.living_entity.entity, pitch, yaw, 0.0, POWER, 1.0);
             world.spawn_entity(Arc::new(egg)).await;
+            ItemActionResult::Success
         })
     }
 
@@ -4,11 +4,12 @@ use std::sync::Arc;
 use crate::entity::Entity;
 use crate::entity::decoration::end_crystal::EndCrystalEntity;
 use crate::entity::player::Player;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::{Block, BlockDirection};
+use pumpkin_util::math::boundingbox::BoundingBox;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::item::ItemStack;
@@ -31,20 +32,42 @@ impl ItemBehaviour for EndCrystalItem {
         _cursor_pos: Vector3<f32>,
         _block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
             let block = world.get_block(&location).await;
             if block != &Block::OBSIDIAN && block != &Block::BEDROCK {
-                return;
+                return ItemActionResult::Pass;
             }
 
+            // The crystal needs two blocks of free space above the base
             let location = location.up();
-            let entity = Entity::new(world.clone(), location.to_f64(), &EntityType::END_CRYSTAL);
+            if !world.get_block_state(&location).await.is_air() {
+                return ItemActionResult::Pass;
+            }
+            let (x, y, z) = (
+                f64::from(location.0.x),
+                f64::from(location.0.y),
+                f64::from(location.0.z),
+            );
+            let space = BoundingBox::new(
+                Vector3::new(x, y, z),
+                Vector3::new(x + 1.0, y + 2.0, z + 1.0),
+            );
+            if !world.get_all_at_box(&space).is_empty() {
+                return ItemActionResult::Pass;
+            }
+
+            let entity = Entity::new(
+                world.clone(),
+                Vector3::new(x + 0.5, y, z + 0.5),
+                &EntityType::END_CRYSTAL,
+            );
             let end_crystal = Arc::new(EndCrystalEntity::new(entity));
-            world.spawn_entity(end_crystal.clone()).await;
             end_crystal.set_show_bottom(false).await;
+            world.spawn_entity(end_crystal).await;
             item.decrement_unless_creative(player.gamemode.load(), 1);
+            ItemActionResult::Success
         })
     }
 
@@ -2,13 +2,16 @@ use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::{server::Server, world::portal::end::EndPortal};
+use pumpkin_data::block_properties::{BlockProperties, EndPortalFrameLikeProperties};
+use pumpkin_data::world::WorldEvent;
 use pumpkin_data::{Block, BlockDirection, item::Item};
+use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::item::ItemStack;
 use pumpkin_world::world::BlockFlags;
 
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::{entity::player::Player, world::World};
 
 pub struct EnderEyeItem;
@@ -22,21 +25,24 @@ impl ItemMetadata for EnderEyeItem {
 impl ItemBehaviour for EnderEyeItem {
     fn use_on_block<'a>(
         &'a self,
-        _item: &'a mut ItemStack,
+        item: &'a mut ItemStack,
         player: &'a Player,
         location: BlockPos,
         _face: BlockDirection,
         _cursor_pos: Vector3<f32>,
         block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             if block.id != Block::END_PORTAL_FRAME.id {
-                return;
+                return ItemActionResult::Pass;
             }
 
             let world = player.world();
             let state_id = world.get_block_state_id(&location).await;
+            if EndPortalFrameLikeProperties::from_state_id(state_id, block).eye {
+                return ItemActionResult::Pass;
+            }
             let original_props = block.properties(state_id).unwrap().to_props();
 
             let props: Vec<(&str, &str)> = original_props
@@ -54,8 +60,13 @@ impl ItemBehaviour for EnderEyeItem {
             world
                 .set_block_state(&location, new_state_id, BlockFlags::empty())
                 .await;
+            item.decrement_unless_creative(player.gamemode.load(), 1);
+            world
+                .sync_world_event(WorldEvent::EndPortalFrameFilled, location, 0)
+                .await;
 
             EndPortal::get_new_portal(&world, location).await;
+            ItemActionResult::Success
         })
     }
 
@@ -63,7 +74,8 @@ impl ItemBehaviour for EnderEyeItem {
         &'a self,
         _item: &'a Item,
         player: &'a Player,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        _hand: Hand,
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
             let (start_pos, end_pos) = self.get_start_and_end_pos(player);
@@ -74,14 +86,18 @@ impl ItemBehaviour for EnderEyeItem {
 
             let Some((block_pos, _direction)) = world.raycast(start_pos, end_pos, checker).await
             else {
-                return;
+                return ItemActionResult::Pass;
             };
 
             let block = world.get_block(&block_pos).await;
 
-            if block == &Block::END_PORTAL_FRAME {}
+            if block == &Block::END_PORTAL_FRAME {
+                // The frame is filled by `use_on_block` instead
+                return ItemActionResult::Pass;
+            }
+            //TODO Throw the Ender Eye in the direction of the stronghold.
+            ItemActionResult::Pass
         })
-        //TODO Throw the Ender Eye in the direction of the stronghold.
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -0,0 +1,70 @@
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::entity::Entity;
+use crate::entity::player::Player;
+use crate::entity::projectile::ender_pearl::EnderPearlEntity;
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::item::Item;
+use pumpkin_data::sound::Sound;
+use pumpkin_util::Hand;
+
+pub struct EnderPearlItem;
+
+impl ItemMetadata for EnderPearlItem {
+    fn ids() -> Box<[u16]> {
+        [Item::ENDER_PEARL.id].into()
+    }
+}
+
+const POWER: f32 = 1.5;
+
+impl ItemBehaviour for EnderPearlItem {
+    fn normal_use<'a>(
+        &'a self,
+        _block: &'a Item,
+        player: &'a Player,
+        hand: Hand,
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
+        Box::pin(async move {
+            // TODO: Respect the 20 tick item cooldown once cooldowns are implemented
+
+            let position = player.position();
+            let world = player.world();
+            world
+                .play_sound(
+                    Sound::EntityEnderPearlThrow,
+                    pumpkin_data::sound::SoundCategory::Neutral,
+                    &position,
+                )
+                .await;
+            let entity = Entity::new(world.clone(), position, &EntityType::ENDER_PEARL);
+            let pearl = EnderPearlEntity::new_shot(entity, &player.living_entity.entity).await;
+            let yaw = player.living_entity.entity.yaw.load();
+            let pitch = player.living_entity.entity.pitch.load();
+            pearl.thrown.set_velocity_from(
+                &player.living_entity.entity,
+                pitch,
+                yaw,
+                0.0,
+                POWER,
+                1.0,
+            );
+            world.spawn_entity(Arc::new(pearl)).await;
+
+            player
+                .inventory
+                .get_stack_in_hand(hand)
+                .await
+                .lock()
+                .await
+                .decrement_unless_creative(player.gamemode.load(), 1);
+            ItemActionResult::Success
+        })
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
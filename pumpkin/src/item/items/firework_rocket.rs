@@ -5,12 +5,13 @@ use std::sync::atomic::Ordering;
 use crate::entity::player::Player;
 use crate::entity::projectile::firework_rocket::FireworkRocketEntity;
 use crate::entity::{Entity, EntityBase};
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use pumpkin_data::Block;
 use pumpkin_data::BlockDirection;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
+use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::item::ItemStack;
@@ -26,14 +27,14 @@ impl ItemMetadata for FireworkRocketItem {
 impl ItemBehaviour for FireworkRocketItem {
     fn use_on_block<'a>(
         &'a self,
-        _item: &'a mut ItemStack,
+        item: &'a mut ItemStack,
         player: &'a Player,
         location: BlockPos,
         _face: BlockDirection,
         cursor_pos: Vector3<f32>,
         _block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
             let entity = Entity::new(
@@ -45,8 +46,10 @@ impl ItemBehaviour for FireworkRocketItem {
                 ),
                 &EntityType::FIREWORK_ROCKET,
             );
-            let entity = FireworkRocketEntity::new(entity).await;
+            let entity = FireworkRocketEntity::new(entity, item).await;
             world.spawn_entity(Arc::new(entity)).await;
+            item.decrement_unless_creative(player.gamemode.load(), 1);
+            ItemActionResult::Success
         })
     }
 
@@ -54,8 +57,9 @@ impl ItemBehaviour for FireworkRocketItem {
         &'a self,
         _item: &'a Item,
         player: &'a Player,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
-        Box::pin(async {
+        hand: Hand,
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
+        Box::pin(async move {
             if player.get_entity().fall_flying.load(Ordering::Relaxed) {
                 let world = player.world();
                 let entity = Entity::new(
@@ -63,9 +67,15 @@ impl ItemBehaviour for FireworkRocketItem {
                     player.get_entity().pos.load(),
                     &EntityType::FIREWORK_ROCKET,
                 );
-                let entity = FireworkRocketEntity::new_shot(entity, player.get_entity()).await;
+                let held_item = player.inventory.get_stack_in_hand(hand).await;
+                let mut stack = held_item.lock().await;
+                let entity =
+                    FireworkRocketEntity::new_shot(entity, player.get_entity(), &stack).await;
                 world.spawn_entity(Arc::new(entity)).await;
+                stack.decrement_unless_creative(player.gamemode.load(), 1);
+                return ItemActionResult::Success;
             }
+            ItemActionResult::Pass
         })
     }
 
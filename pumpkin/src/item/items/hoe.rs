@@ -1,7 +1,7 @@
 use crate::entity::Entity;
 use crate::entity::item::ItemEntity;
 use crate::entity::player::Player;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use pumpkin_data::BlockDirection;
 use pumpkin_data::entity::EntityType;
@@ -33,7 +33,7 @@ impl ItemBehaviour for HoeItem {
         _cursor_pos: Vector3<f32>,
         block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             // Yes, Minecraft does hardcode these
             if block == &Block::GRASS_BLOCK
@@ -95,10 +95,14 @@ impl ItemBehaviour for HoeItem {
                     world.spawn_entity(item_entity).await;
                 }
 
-                if changed && player.gamemode.load() != GameMode::Creative {
-                    item.damage_item_with_context(1, false);
+                if changed {
+                    if player.gamemode.load() != GameMode::Creative {
+                        item.damage_item_with_context(1, false);
+                    }
+                    return ItemActionResult::Success;
                 }
             }
+            ItemActionResult::Pass
         })
     }
 
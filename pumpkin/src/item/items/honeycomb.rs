@@ -5,7 +5,7 @@ use std::sync::atomic::Ordering;
 use crate::block::UseWithItemArgs;
 use crate::block::registry::BlockActionResult;
 use crate::entity::player::Player;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use pumpkin_data::BlockDirection;
 use pumpkin_data::block_properties::BlockProperties;
@@ -39,7 +39,7 @@ impl ItemBehaviour for HoneyCombItem {
         _cursor_pos: Vector3<f32>,
         block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
 
@@ -76,7 +76,9 @@ impl ItemBehaviour for HoneyCombItem {
                 world
                     .set_block_state(&location, new_state_id, BlockFlags::NOTIFY_ALL)
                     .await;
+                return ItemActionResult::Success;
             }
+            ItemActionResult::Pass
         })
     }
 
@@ -13,7 +13,7 @@ use pumpkin_world::world::BlockFlags;
 
 use crate::entity::player::Player;
 use crate::item::items::ignite::ignition::Ignition;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use crate::world::World;
 
@@ -35,9 +35,9 @@ impl ItemBehaviour for FireChargeItem {
         _cursor_pos: Vector3<f32>,
         block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
-            Ignition::ignite_block(
+            let ignited = Ignition::ignite_block(
                 |world: Arc<World>, pos: BlockPos, new_state_id: u16| async move {
                     world
                         .set_block_state(&pos, new_state_id, BlockFlags::NOTIFY_ALL)
@@ -53,6 +53,11 @@ impl ItemBehaviour for FireChargeItem {
                 block,
             )
             .await;
+            if ignited {
+                ItemActionResult::Success
+            } else {
+                ItemActionResult::Pass
+            }
         })
     }
 
@@ -1,4 +1,5 @@
 use crate::entity::player::Player;
+use crate::item::ItemActionResult;
 use crate::item::ItemBehaviour;
 use crate::item::ItemMetadata;
 use crate::server::Server;
@@ -33,7 +34,7 @@ impl ItemBehaviour for FlintAndSteelItem {
         _cursor_pos: Vector3<f32>,
         block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let ignited = Ignition::ignite_block(
                 |world: Arc<World>, pos: BlockPos, new_state_id: u16| async move {
@@ -48,9 +49,13 @@ impl ItemBehaviour for FlintAndSteelItem {
             )
             .await;
 
-            if ignited && player.gamemode.load() != pumpkin_util::GameMode::Creative {
+            if !ignited {
+                return ItemActionResult::Pass;
+            }
+            if player.gamemode.load() != pumpkin_util::GameMode::Creative {
                 item.damage_item_with_context(1, false);
             }
+            ItemActionResult::Success
         })
     }
 
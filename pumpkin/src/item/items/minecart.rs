@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use crate::entity::Entity;
 use crate::entity::player::Player;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use pumpkin_data::BlockDirection;
 use pumpkin_data::block_properties::{
@@ -57,12 +57,12 @@ impl ItemBehaviour for MinecartItem {
         _cursor_pos: Vector3<f32>,
         block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
 
             if !block.has_tag(&tag::Block::MINECRAFT_RAILS) {
-                return;
+                return ItemActionResult::Pass;
             }
             let state_id = world.get_block_state_id(&location).await;
             let is_ascending = if PoweredRailLikeProperties::handles_block_id(block.id) {
@@ -83,6 +83,7 @@ impl ItemBehaviour for MinecartItem {
                 entity_type,
             ));
             world.spawn_entity(entity).await;
+            ItemActionResult::Success
         })
     }
 
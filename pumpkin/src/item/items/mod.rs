@@ -5,6 +5,7 @@ pub mod dye;
 pub mod egg;
 pub mod end_crystal;
 pub mod ender_eye;
+pub mod ender_pearl;
 pub mod firework_rocket;
 pub mod glowing_ink_sac;
 pub mod hoe;
@@ -35,6 +36,7 @@ use bucket::{EmptyBucketItem, FilledBucketItem};
 use dye::DyeItem;
 use egg::EggItem;
 use ender_eye::EnderEyeItem;
+use ender_pearl::EnderPearlItem;
 use glowing_ink_sac::GlowingInkSacItem;
 use hoe::HoeItem;
 use honeycomb::HoneyCombItem;
@@ -69,6 +71,7 @@ pub fn default_registry() -> Arc<ItemRegistry> {
     manager.register(HoneyCombItem);
     manager.register(NameTagItem);
     manager.register(EnderEyeItem);
+    manager.register(EnderPearlItem);
     manager.register(FireChargeItem);
     manager.register(DyeItem);
     manager.register(FireworkRocketItem);
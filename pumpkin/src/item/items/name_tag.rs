@@ -29,8 +29,9 @@ impl ItemBehaviour for NameTagItem {
             if entity.entity_type.saveable
                 && let Some(name) = item.get_data_component::<CustomNameImpl>()
             {
-                // TODO
-                entity.set_custom_name(TextComponent::text(name.name)).await;
+                entity
+                    .set_custom_name(TextComponent::text(name.name.clone()))
+                    .await;
                 item.decrement_unless_creative(player.gamemode.load(), 1);
             }
         })
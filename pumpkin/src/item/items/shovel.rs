@@ -1,7 +1,7 @@
 use std::pin::Pin;
 
 use crate::entity::player::Player;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use pumpkin_data::BlockDirection;
 use pumpkin_data::block_properties::{BlockProperties, CampfireLikeProperties};
@@ -33,7 +33,7 @@ impl ItemBehaviour for ShovelItem {
         _cursor_pos: Vector3<f32>,
         block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
             // Yes, Minecraft does hardcode these
@@ -90,9 +90,13 @@ impl ItemBehaviour for ShovelItem {
                 }
             }
 
-            if changed && player.gamemode.load() != GameMode::Creative {
+            if !changed {
+                return ItemActionResult::Pass;
+            }
+            if player.gamemode.load() != GameMode::Creative {
                 item.damage_item_with_context(1, false);
             }
+            ItemActionResult::Success
         })
     }
 
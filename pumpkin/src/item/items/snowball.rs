@@ -4,10 +4,11 @@ use std::sync::Arc;
 use crate::entity::Entity;
 use crate::entity::player::Player;
 use crate::entity::projectile::snowball::SnowballEntity;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::sound::Sound;
+use pumpkin_util::Hand;
 
 pub struct SnowBallItem;
 
@@ -24,7 +25,8 @@ impl ItemBehaviour for SnowBallItem {
         &'a self,
         _block: &'a Item,
         player: &'a Player,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        _hand: Hand,
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let position = player.position();
             let world = player.world();
@@ -48,6 +50,7 @@ impl ItemBehaviour for SnowBallItem {
                 1.0,
             );
             world.spawn_entity(Arc::new(snowball)).await;
+            ItemActionResult::Success
         })
     }
 
@@ -1,14 +1,16 @@
 use std::pin::Pin;
+use std::sync::Arc;
 
 use crate::entity::player::Player;
 use crate::entity::r#type::from_type;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use pumpkin_data::entity::entity_from_egg;
 use pumpkin_data::{Block, BlockDirection};
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_util::math::wrap_degrees;
+use pumpkin_world::block::entities::BlockEntity;
 use pumpkin_world::block::entities::mob_spawner::MobSpawnerBlockEntity;
 use pumpkin_world::item::ItemStack;
 use uuid::Uuid;
@@ -31,20 +33,20 @@ impl ItemBehaviour for SpawnEggItem {
         _cursor_pos: Vector3<f32>,
         _block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             if let Some(entity_type) = entity_from_egg(item.item.id) {
                 let world = player.world();
 
-                if let Some(block_entity) = player.world().get_block_entity(&location).await
-                    && let Some(spawner) = block_entity
-                        .as_any()
-                        .downcast_ref::<MobSpawnerBlockEntity>()
+                if let Some(spawner) = world
+                    .get_block_entity_data::<MobSpawnerBlockEntity>(&location)
+                    .await
                 {
                     spawner.set_entity_type(entity_type);
+                    let block_entity: Arc<dyn BlockEntity> = spawner;
                     world.update_block_entity(&block_entity).await;
                     item.decrement_unless_creative(player.gamemode.load(), 1);
-                    return;
+                    return ItemActionResult::Success;
                 }
                 let pos = BlockPos(location.0 + face.to_offset());
                 let pos = Vector3::new(
@@ -64,7 +66,9 @@ impl ItemBehaviour for SpawnEggItem {
                 world.spawn_entity(mob).await;
                 item.decrement_unless_creative(player.gamemode.load(), 1);
                 // TODO: send/configure additional commands/data based on the type of entity (horse, slime, etc)
+                return ItemActionResult::Success;
             }
+            ItemActionResult::Pass
         })
     }
 
@@ -5,11 +5,12 @@ use crate::entity::player::Player;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::sound::Sound;
+use pumpkin_util::Hand;
 
 use crate::entity::Entity;
 use crate::entity::projectile::ThrownItemEntity;
 use crate::entity::projectile::wind_charge::WindChargeEntity;
-use crate::item::{ItemBehaviour, ItemMetadata};
+use crate::item::{ItemActionResult, ItemBehaviour, ItemMetadata};
 
 pub struct WindChargeItem;
 
@@ -26,7 +27,8 @@ impl ItemBehaviour for WindChargeItem {
         &'a self,
         _block: &'a Item,
         player: &'a Player,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        _hand: Hand,
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
             let position = player.position();
@@ -61,6 +63,7 @@ impl ItemBehaviour for WindChargeItem {
             world
                 .spawn_entity(Arc::new(WindChargeEntity::new(wind_charge)))
                 .await;
+            ItemActionResult::Success
         })
     }
 
@@ -11,10 +11,30 @@ use crate::server::Server;
 use pumpkin_data::Block;
 use pumpkin_data::BlockDirection;
 use pumpkin_data::item::Item;
+use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::item::ItemStack;
 
+/// The outcome of using an item, deciding whether the next interaction in vanilla's order
+/// (main hand on block, main hand, off hand on block, off hand) still runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemActionResult {
+    /// The item was used | Same as SUCCESS in vanilla
+    Success,
+    /// The item is being used over time, like food or a shield | Same as CONSUME in vanilla
+    Consume,
+    /// The item did nothing, so the next interaction is tried | Same as PASS in vanilla
+    Pass,
+}
+
+impl ItemActionResult {
+    #[must_use]
+    pub const fn consumes_action(&self) -> bool {
+        matches!(self, Self::Success | Self::Consume)
+    }
+}
+
 pub trait ItemMetadata {
     fn ids() -> Box<[u16]>;
 }
@@ -24,8 +44,9 @@ pub trait ItemBehaviour: Send + Sync {
         &'a self,
         _item: &'a Item,
         _player: &'a Player,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
-        Box::pin(async {})
+        _hand: Hand,
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
+        Box::pin(async { ItemActionResult::Pass })
     }
 
     #[expect(clippy::too_many_arguments)]
@@ -38,8 +59,8 @@ pub trait ItemBehaviour: Send + Sync {
         _cursor_pos: Vector3<f32>,
         _block: &'a Block,
         _server: &'a Server,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
-        Box::pin(async {})
+    ) -> Pin<Box<dyn Future<Output = ItemActionResult> + Send + 'a>> {
+        Box::pin(async { ItemActionResult::Pass })
     }
 
     fn use_on_entity<'a>(
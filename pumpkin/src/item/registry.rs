@@ -4,13 +4,14 @@ use crate::server::Server;
 use pumpkin_data::Block;
 use pumpkin_data::BlockDirection;
 use pumpkin_data::item::Item;
+use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::item::ItemStack;
 use rustc_hash::FxHashMap;
 use std::sync::Arc;
 
-use super::{ItemBehaviour, ItemMetadata};
+use super::{ItemActionResult, ItemBehaviour, ItemMetadata};
 
 #[derive(Default)]
 pub struct ItemRegistry {
@@ -26,11 +27,12 @@ impl ItemRegistry {
         }
     }
 
-    pub async fn on_use(&self, item: &Item, player: &Player) {
+    pub async fn on_use(&self, item: &Item, player: &Player, hand: Hand) -> ItemActionResult {
         let pumpkin_item = self.get_pumpkin_item(item.id);
         if let Some(pumpkin_item) = pumpkin_item {
-            pumpkin_item.normal_use(item, player).await;
+            return pumpkin_item.normal_use(item, player, hand).await;
         }
+        ItemActionResult::Pass
     }
 
     #[expect(clippy::too_many_arguments)]
@@ -43,13 +45,14 @@ impl ItemRegistry {
         cursor_pos: Vector3<f32>,
         block: &Block,
         server: &Server,
-    ) {
+    ) -> ItemActionResult {
         let pumpkin_item = self.get_pumpkin_item(stack.item.id);
         if let Some(pumpkin_item) = pumpkin_item {
-            pumpkin_item
+            return pumpkin_item
                 .use_on_block(stack, player, location, face, cursor_pos, block, server)
                 .await;
         }
+        ItemActionResult::Pass
     }
 
     pub async fn use_on_entity(
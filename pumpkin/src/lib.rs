@@ -10,6 +10,7 @@ use crate::net::{lan_broadcast::LANBroadcast, query, rcon::RCONServer};
 use crate::server::{Server, ticker::Ticker};
 use plugin::server::server_command::ServerCommandEvent;
 use pumpkin_config::{AdvancedConfiguration, BasicConfiguration};
+use pumpkin_data::translation;
 use pumpkin_macros::send_cancellable;
 use pumpkin_util::text::TextComponent;
 use rustyline::Editor;
@@ -352,7 +353,8 @@ impl PumpkinServer {
             error!("Error saving all players during shutdown: {e}");
         }
 
-        let kick_message = TextComponent::text("Server stopped");
+        let kick_message =
+            TextComponent::translate(translation::MULTIPLAYER_DISCONNECT_SERVER_SHUTDOWN, []);
         for player in self.server.get_all_players() {
             player
                 .kick(DisconnectReason::Shutdown, kick_message.clone())
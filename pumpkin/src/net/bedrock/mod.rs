@@ -39,6 +39,7 @@ use pumpkin_protocol::{
             request_chunk_radius::SRequestChunkRadius,
             request_network_settings::SRequestNetworkSettings,
             resource_pack_response::SResourcePackResponse,
+            respawn::SRespawn,
             text::SText,
         },
     },
@@ -221,15 +222,32 @@ impl BedrockClient {
         packet: &P,
         write: impl Write,
     ) -> Result<(), Error> {
+        let packet_payload = Self::serialize_game_packet_payload(packet)?;
+        self.write_game_packet_payload(P::PACKET_ID as u16, &packet_payload, write)
+            .await
+    }
+
+    /// Serializes only the packet body, which is identical for every client and can
+    /// therefore be shared when broadcasting. The game packet header, compression and
+    /// RakNet framing are applied per client when sending.
+    pub fn serialize_game_packet_payload<P: BClientPacket>(packet: &P) -> Result<Bytes, Error> {
         let mut packet_payload = Vec::new();
         packet.write_packet(&mut packet_payload)?;
+        Ok(packet_payload.into())
+    }
 
+    pub async fn write_game_packet_payload(
+        &self,
+        packet_id: u16,
+        packet_payload: &[u8],
+        write: impl Write,
+    ) -> Result<(), Error> {
         // TODO
         self.network_writer.lock().await.write_game_packet(
-            P::PACKET_ID as u16,
+            packet_id,
             SubClient::Main,
             SubClient::Main,
-            &packet_payload,
+            packet_payload,
             write,
         )
     }
@@ -255,6 +273,18 @@ impl BedrockClient {
             .await;
     }
 
+    /// Sends a game packet whose body was already serialized with
+    /// [`Self::serialize_game_packet_payload`]. Sequence and ordering numbers are still
+    /// assigned per client.
+    pub async fn send_game_packet_payload(&self, packet_id: u16, packet_payload: &[u8]) {
+        let mut packet_buf = Vec::new();
+        self.write_game_packet_payload(packet_id, packet_payload, &mut packet_buf)
+            .await
+            .unwrap();
+        self.send_framed_packet_data(packet_buf, RakReliability::Unreliable)
+            .await;
+    }
+
     pub async fn write_game_packet_to_set<P: BClientPacket>(
         &self,
         packet: &P,
@@ -546,6 +576,10 @@ impl BedrockClient {
                 self.handle_chat_command(player, server, SCommandRequest::read(reader).unwrap())
                     .await;
             }
+            SRespawn::PACKET_ID => {
+                self.handle_respawn(player, SRespawn::read(reader).unwrap())
+                    .await;
+            }
             _ => {
                 warn!("Bedrock: Received Unknown Game packet: {}", packet.id);
             }
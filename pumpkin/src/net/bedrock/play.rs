@@ -6,28 +6,33 @@ use std::{
 use pumpkin_macros::send_cancellable;
 use pumpkin_protocol::{
     bedrock::{
-        client::{chunk_radius_update::CChunkRadiusUpdate, container_open::CContainerOpen},
+        client::{
+            chunk_radius_update::CChunkRadiusUpdate, container_open::CContainerOpen,
+            play_status::CPlayStatus, respawn::CRespawn,
+        },
         server::{
             command_request::SCommandRequest,
             container_close::SContainerClose,
             interaction::{Action, SInteraction},
             player_auth_input::{InputData, SPlayerAuthInput},
             request_chunk_radius::SRequestChunkRadius,
+            respawn::SRespawn,
             text::SText,
         },
     },
-    codec::{bedrock_block_pos::NetworkPos, var_int::VarInt, var_long::VarLong},
-    java::client::play::CSystemChatMessage,
+    codec::{
+        bedrock_block_pos::NetworkPos, var_int::VarInt, var_long::VarLong, var_ulong::VarULong,
+    },
 };
-use pumpkin_util::{math::position::BlockPos, text::TextComponent};
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
 
 use crate::{
     command::CommandSender,
     entity::{EntityBase, player::Player},
     net::{DisconnectReason, bedrock::BedrockClient},
-    plugin::player::{player_chat::PlayerChatEvent, player_command_send::PlayerCommandSendEvent},
-    server::{Server, seasonal_events},
-    world::chunker::{self},
+    plugin::player::player_command_send::PlayerCommandSendEvent,
+    server::Server,
+    world::{chat, chunker},
 };
 use tracing::{debug, info};
 
@@ -113,6 +118,32 @@ impl BedrockClient {
         }
     }
 
+    /// The player pressed the respawn button on the death screen.
+    pub async fn handle_respawn(&self, player: &Arc<Player>, packet: SRespawn) {
+        if !packet.is_ready_to_spawn() || player.living_entity.health.load() > 0.0 {
+            return;
+        }
+        player.world().clone().respawn_player(player, false).await;
+
+        {
+            let mut abilities = player.abilities.lock().await;
+            abilities.set_for_gamemode(player.gamemode.load());
+        }
+        player.send_abilities_update().await;
+
+        let position = player.position();
+        self.send_game_packet(&CRespawn {
+            position: Vector3::new(position.x as f32, position.y as f32, position.z as f32),
+            state: CRespawn::STATE_READY_TO_SPAWN,
+            runtime_id: VarULong(player.entity_id() as u64),
+        })
+        .await;
+        player.bedrock_attributes.invalidate();
+        player.bedrock_attributes.tick(player).await;
+        self.send_game_packet(&CPlayStatus::PlayerSpawn).await;
+        player.set_client_loaded(true);
+    }
+
     pub async fn handle_interaction(&self, _player: &Arc<Player>, packet: SInteraction) {
         if matches!(packet.action, Action::OpenInventory) {
             self.send_game_packet(&CContainerOpen {
@@ -137,46 +168,7 @@ impl BedrockClient {
     }
 
     pub async fn handle_chat_message(&self, server: &Server, player: &Arc<Player>, packet: SText) {
-        let gameprofile = &player.gameprofile;
-
-        send_cancellable! {{
-            server;
-            PlayerChatEvent::new(player.clone(), packet.message, vec![]);
-
-            'after: {
-                info!("<chat> {}: {}", gameprofile.name, event.message);
-
-                let config = &server.advanced_config;
-
-                let message = match seasonal_events::modify_chat_message(&event.message, config) {
-                    Some(m) => m,
-                    None => event.message.clone(),
-                };
-
-                let decorated_message = TextComponent::chat_decorated(
-                    &config.chat.format,
-                    &gameprofile.name,
-                    &message,
-                );
-
-                let entity = &player.living_entity.entity;
-                if server.basic_config.allow_chat_reports {
-                    //TODO Alex help, what is this?
-                    //world.broadcast_secure_player_chat(player, &message, decorated_message).await;
-                } else {
-                    let je_packet = CSystemChatMessage::new(
-                        &decorated_message,
-                        false,
-                    );
-
-                    let be_packet = SText::new(
-                        message, gameprofile.name.clone()
-                    );
-
-                    entity.world.load().broadcast_editioned(&je_packet, &be_packet).await;
-                }
-            }
-        }}
+        chat::send_player_chat(server, player, packet.message, None).await;
     }
 
     pub async fn handle_chat_command(
@@ -0,0 +1,110 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Weak, atomic::Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bytes::Bytes;
+use pumpkin_protocol::java::capture::{CaptureRecord, write_capture_header};
+use pumpkin_util::version::MinecraftVersion;
+use tokio::{
+    io::{AsyncWriteExt, BufWriter},
+    sync::mpsc::{UnboundedSender, unbounded_channel},
+};
+use tracing::{debug, warn};
+
+use crate::server::Server;
+
+/// Records the clientbound packets of one client to a file.
+///
+/// Recording only sends the packet over a channel; a separate task redacts chat signatures and
+/// writes the records. The capture stops once the file reaches its size limit or when it is
+/// dropped, e.g. because the client disconnected.
+pub struct PacketCapture {
+    path: PathBuf,
+    sender: UnboundedSender<CaptureRecord>,
+    server: Weak<Server>,
+}
+
+impl PacketCapture {
+    /// Creates the capture file and starts the writer task.
+    pub fn start(path: PathBuf, max_bytes: u64, server: Weak<Server>) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut header = Vec::new();
+        write_capture_header(&mut header).map_err(std::io::Error::other)?;
+        let file = std::fs::File::create(&path)?;
+
+        let (sender, mut receiver) = unbounded_channel::<CaptureRecord>();
+        let task_path = path.clone();
+        tokio::spawn(async move {
+            let mut writer = BufWriter::new(tokio::fs::File::from_std(file));
+            let mut written = header.len() as u64;
+            let mut result = writer.write_all(&header).await;
+
+            let mut buf = Vec::new();
+            while result.is_ok()
+                && let Some(mut record) = receiver.recv().await
+            {
+                record.redact_chat_signatures();
+                buf.clear();
+                if let Err(err) = record.write(&mut buf) {
+                    warn!("Failed to encode captured packet: {err}");
+                    continue;
+                }
+                written += buf.len() as u64;
+                if written > max_bytes {
+                    warn!(
+                        "Packet capture {} reached its size limit of {max_bytes} bytes",
+                        task_path.display()
+                    );
+                    break;
+                }
+                result = writer.write_all(&buf).await;
+            }
+            // Stops further records from being queued
+            receiver.close();
+
+            if let Err(err) = result.and(writer.flush().await) {
+                warn!(
+                    "Failed to write packet capture {}: {err}",
+                    task_path.display()
+                );
+            }
+            debug!("Packet capture {} finished", task_path.display());
+        });
+
+        Ok(Self {
+            path,
+            sender,
+            server,
+        })
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Queues a serialized packet for the capture. Returns `false` once the capture has stopped.
+    pub fn record(&self, version: MinecraftVersion, packet: Bytes) -> bool {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| {
+                time.as_secs() * 1000 + u64::from(time.subsec_millis())
+            });
+        let tick = self
+            .server
+            .upgrade()
+            .map_or(0, |server| server.tick_count.load(Ordering::Relaxed));
+
+        match CaptureRecord::from_packet(timestamp, tick, version, packet) {
+            Ok(record) => self.sender.send(record).is_ok(),
+            Err(err) => {
+                warn!("Failed to capture packet: {err}");
+                true
+            }
+        }
+    }
+}
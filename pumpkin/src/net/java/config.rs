@@ -10,6 +10,7 @@ use crate::{
 };
 use core::str;
 use pumpkin_data::registry::Registry;
+use pumpkin_data::translation;
 use pumpkin_protocol::{
     ConnectionState,
     java::{
@@ -20,7 +21,7 @@ use pumpkin_protocol::{
         },
     },
 };
-use pumpkin_util::{Hand, text::TextComponent, version::MinecraftVersion};
+use pumpkin_util::{Arm, text::TextComponent, version::MinecraftVersion};
 use tracing::{debug, trace, warn};
 
 const BRAND_CHANNEL_PREFIX: &str = "minecraft:brand";
@@ -32,15 +33,16 @@ impl JavaClient {
     ) {
         debug!("Handling client settings");
         if client_information.view_distance <= 0 {
-            self.kick(TextComponent::text(
-                "Cannot have zero or negative view distance!",
+            self.kick(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                [],
             ))
             .await;
             return;
         }
 
         if let (Ok(main_hand), Ok(chat_mode)) = (
-            Hand::try_from(client_information.main_hand.0),
+            Arm::try_from(client_information.main_hand.0),
             ChatMode::try_from(client_information.chat_mode.0),
         ) {
             *self.config.lock().await = Some(PlayerConfig {
@@ -55,8 +57,11 @@ impl JavaClient {
                 server_listing: client_information.server_listing,
             });
         } else {
-            self.kick(TextComponent::text("Invalid hand or chat type"))
-                .await;
+            self.kick(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                [],
+            ))
+            .await;
         }
     }
 
@@ -66,7 +71,13 @@ impl JavaClient {
             debug!("Got a client brand");
             match str::from_utf8(&plugin_message.data) {
                 Ok(brand) => *self.brand.lock().await = Some(brand.to_string()),
-                Err(e) => self.kick(TextComponent::text(e.to_string())).await,
+                Err(_) => {
+                    self.kick(TextComponent::translate(
+                        translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                        [],
+                    ))
+                    .await;
+                }
             }
         }
     }
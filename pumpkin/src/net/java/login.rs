@@ -43,8 +43,11 @@ impl JavaClient {
         }
 
         if !is_valid_player_name(&login_start.name) {
-            self.kick(TextComponent::text("Invalid characters in username"))
-                .await;
+            self.kick(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PLAYER_DATA,
+                [],
+            ))
+            .await;
             return;
         }
         // Default game profile, when no online mode
@@ -67,7 +70,13 @@ impl JavaClient {
                         self.finish_login(&profile).await;
                         *gameprofile = Some(profile);
                     }
-                    Err(error) => self.kick(TextComponent::text(error.to_string())).await,
+                    Err(error) => {
+                        self.kick(TextComponent::translate(
+                            translation::DISCONNECT_LOGINFAILEDINFO,
+                            [TextComponent::text(error.to_string())],
+                        ))
+                        .await;
+                    }
                 }
             }
         } else {
@@ -117,14 +126,22 @@ impl JavaClient {
             .unwrap();
 
         if let Err(error) = self.set_encryption(&shared_secret).await {
-            self.kick(TextComponent::text(error.to_string())).await;
+            self.kick(TextComponent::translate(
+                translation::DISCONNECT_LOGINFAILEDINFO,
+                [TextComponent::text(error.to_string())],
+            ))
+            .await;
             return;
         }
 
         let mut gameprofile = self.gameprofile.lock().await;
 
         let Some(profile) = gameprofile.as_mut() else {
-            self.kick(TextComponent::text("No `GameProfile`")).await;
+            self.kick(TextComponent::translate(
+                translation::DISCONNECT_LOGINFAILEDINFO,
+                [TextComponent::text("No `GameProfile`")],
+            ))
+            .await;
             return;
         };
 
@@ -145,7 +162,10 @@ impl JavaClient {
                             translation::MULTIPLAYER_DISCONNECT_UNVERIFIED_USERNAME,
                             [],
                         ),
-                        e => TextComponent::text(e.to_string()),
+                        e => TextComponent::translate(
+                            translation::DISCONNECT_LOGINFAILEDINFO,
+                            [TextComponent::text(e.to_string())],
+                        ),
                     })
                     .await;
                 }
@@ -290,7 +310,13 @@ impl JavaClient {
                     *address = new_address;
                     drop(address);
                 }
-                Err(error) => self.kick(TextComponent::text(error.to_string())).await,
+                Err(error) => {
+                    self.kick(TextComponent::translate(
+                        translation::DISCONNECT_LOGINFAILEDINFO,
+                        [TextComponent::text(error.to_string())],
+                    ))
+                    .await;
+                }
             }
         }
     }
@@ -1,17 +1,26 @@
+use std::collections::VecDeque;
 use std::net::SocketAddr;
-use std::{io::Write, sync::Arc};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use std::{
+    io::Write,
+    sync::{Arc, Weak},
+};
 
+use arc_swap::ArcSwapOption;
 use bytes::Bytes;
 use crossbeam::atomic::AtomicCell;
 use pumpkin_config::networking::compression::CompressionInfo;
 use pumpkin_data::packet::CURRENT_MC_PROTOCOL;
+use pumpkin_data::translation;
 use pumpkin_protocol::java::server::play::{
     SChangeGameMode, SChatCommand, SChatMessage, SChunkBatch, SClickSlot, SClientCommand,
     SClientInformationPlay, SClientTickEnd, SCloseContainer, SCommandSuggestion, SConfirmTeleport,
-    SCookieResponse as SPCookieResponse, SCustomPayload, SInteract, SKeepAlive, SPickItemFromBlock,
-    SPlayPingRequest, SPlayerAbilities, SPlayerAction, SPlayerCommand, SPlayerInput, SPlayerLoaded,
-    SPlayerPosition, SPlayerPositionRotation, SPlayerRotation, SPlayerSession, SSetCommandBlock,
-    SSetCreativeSlot, SSetHeldItem, SSetPlayerGround, SSwingArm, SUpdateSign, SUseItem, SUseItemOn,
+    SContainerButtonClick, SCookieResponse as SPCookieResponse, SCustomPayload, SEditBook,
+    SInteract, SKeepAlive, SPickItemFromBlock, SPlayPingRequest, SPlayerAbilities, SPlayerAction,
+    SPlayerCommand, SPlayerInput, SPlayerLoaded, SPlayerPosition, SPlayerPositionRotation,
+    SPlayerRotation, SPlayerSession, SSetCommandBlock, SSetCreativeSlot, SSetHeldItem,
+    SSetPlayerGround, SSwingArm, SUpdateSign, SUseItem, SUseItemOn,
 };
 use pumpkin_protocol::packet::MultiVersionJavaPacket;
 use pumpkin_protocol::{
@@ -47,13 +56,17 @@ use tokio::{
     sync::{Mutex, oneshot},
 };
 use tokio::{
-    sync::mpsc::{Receiver, Sender, error::TryRecvError},
+    sync::mpsc::{
+        Receiver, Sender,
+        error::{TryRecvError, TrySendError},
+    },
     task::JoinHandle,
 };
 use tokio_util::sync::CancellationToken;
 use tokio_util::task::TaskTracker;
 use tracing::{debug, error, warn};
 
+pub mod capture;
 pub mod config;
 pub mod handshake;
 pub mod login;
@@ -64,10 +77,19 @@ use crate::entity::player::Player;
 use crate::net::{GameProfile, PlayerConfig};
 use crate::plugin::player::player_custom_payload::PlayerCustomPayloadEvent;
 use crate::{error::PumpkinError, net::EncryptionError, server::Server};
+use capture::PacketCapture;
+
+/// The maximum amount of packets waiting in a client's outgoing queue. Packets that don't fit
+/// wait in an overflow buffer.
+const OUTGOING_QUEUE_CAPACITY: usize = 1024;
+/// How long a client's outgoing queue may stay full before the client is disconnected, because
+/// it is not reading fast enough. Short bursts, like teleporting into unsent chunks, are fine.
+const OUTGOING_QUEUE_GRACE_PERIOD: Duration = Duration::from_secs(5);
 
 pub struct JavaClient {
     pub id: u64,
-    pub version: AtomicCell<MinecraftVersion>,
+    /// Shared with the outgoing packet task, which serializes deferred packets for this version.
+    pub version: Arc<AtomicCell<MinecraftVersion>>,
     /// The client's game profile information.
     pub gameprofile: Mutex<Option<GameProfile>>,
     /// The client's configuration settings, Optional
@@ -84,10 +106,13 @@ pub struct JavaClient {
     tasks: TaskTracker,
     /// An notifier that is triggered when this client is closed.
     close_token: CancellationToken,
-    /// A normal-priority queue of serialized packets to send to the network.
+    /// A bounded, in-order queue of packets to send to the network.
     outgoing_packet_queue_send: Sender<OutgoingPacket>,
-    /// A normal-priority queue of serialized packets to send to the network.
+    /// A bounded, in-order queue of packets to send to the network.
     outgoing_packet_queue_recv: Option<Receiver<OutgoingPacket>>,
+    /// Packets that didn't fit in the outgoing queue. Shared with the outgoing packet task, which
+    /// moves them into the queue as it makes room.
+    outgoing_overflow: Arc<Mutex<OutgoingOverflow>>,
     /// A high-priority queue of serialized packets to send to the network.
     outgoing_packet_priority_send: Sender<OutgoingPacket>,
     /// A high-priority queue of serialized packets to send to the network.
@@ -96,6 +121,8 @@ pub struct JavaClient {
     network_writer: Arc<Mutex<TCPNetworkEncoder<BufWriter<OwnedWriteHalf>>>>,
     /// The packet decoder for incoming packets.
     network_reader: Mutex<TCPNetworkDecoder<BufReader<OwnedReadHalf>>>,
+    /// Records outgoing packets while a capture is running. Shared with the outgoing packet task.
+    packet_capture: Arc<ArcSwapOption<PacketCapture>>,
 }
 
 pub enum PacketHandlerResult {
@@ -104,32 +131,84 @@ pub enum PacketHandlerResult {
     ReadyToPlay(GameProfile, PlayerConfig),
 }
 
+/// A clientbound packet which is serialized by the outgoing packet task instead of the caller.
+trait DeferredPacket: Send + Sync {
+    fn serialize(&self, version: MinecraftVersion) -> Result<Bytes, WritingError>;
+
+    fn name(&self) -> &'static str;
+}
+
+impl<P: ClientPacket + Send + Sync> DeferredPacket for P {
+    fn serialize(&self, version: MinecraftVersion) -> Result<Bytes, WritingError> {
+        JavaClient::serialize_packet_for_version(self, version)
+    }
+
+    fn name(&self) -> &'static str {
+        std::any::type_name::<P>()
+    }
+}
+
+enum OutgoingPayload {
+    /// Packet data which was already serialized, e.g. once for all recipients on the same version.
+    Encoded(Bytes),
+    /// A packet shared between recipients, serialized for the client's version when it is written.
+    Deferred(Arc<dyn DeferredPacket>),
+}
+
 struct OutgoingPacket {
-    data: Bytes,
+    payload: OutgoingPayload,
+    /// Notified once the packet has been flushed.
     completion: Option<oneshot::Sender<()>>,
 }
 
 impl OutgoingPacket {
-    const fn normal(data: Bytes) -> Self {
+    const fn normal(payload: OutgoingPayload) -> Self {
         Self {
-            data,
+            payload,
             completion: None,
         }
     }
 
-    const fn high_priority(data: Bytes, completion: oneshot::Sender<()>) -> Self {
+    const fn high_priority(payload: OutgoingPayload, completion: oneshot::Sender<()>) -> Self {
         Self {
-            data,
+            payload,
             completion: Some(completion),
         }
     }
 }
 
+/// The packets waiting for room in the outgoing queue, in order, and since when they are waiting.
+#[derive(Default)]
+struct OutgoingOverflow {
+    packets: VecDeque<OutgoingPacket>,
+    full_since: Option<Instant>,
+}
+
+impl OutgoingOverflow {
+    /// Moves waiting packets into the queue until it is full again.
+    fn refill(&mut self, queue: &Sender<OutgoingPacket>) {
+        while let Some(packet) = self.packets.pop_front() {
+            if let Err(TrySendError::Full(packet) | TrySendError::Closed(packet)) =
+                queue.try_send(packet)
+            {
+                self.packets.push_front(packet);
+                return;
+            }
+        }
+        self.full_since = None;
+    }
+
+    fn is_past_grace_period(&self) -> bool {
+        self.full_since
+            .is_some_and(|since| since.elapsed() >= OUTGOING_QUEUE_GRACE_PERIOD)
+    }
+}
+
 impl JavaClient {
     #[must_use]
     pub fn new(tcp_stream: TcpStream, address: SocketAddr, id: u64) -> Self {
         let (read, write) = tcp_stream.into_split();
-        let (send, recv) = tokio::sync::mpsc::channel(128);
+        let (send, recv) = tokio::sync::mpsc::channel(OUTGOING_QUEUE_CAPACITY);
         let (priority_send, priority_recv) = tokio::sync::mpsc::channel(128);
         Self {
             id,
@@ -142,12 +221,16 @@ impl JavaClient {
             tasks: TaskTracker::new(),
             outgoing_packet_queue_send: send,
             outgoing_packet_queue_recv: Some(recv),
+            outgoing_overflow: Arc::new(Mutex::new(OutgoingOverflow::default())),
             outgoing_packet_priority_send: priority_send,
             outgoing_packet_priority_recv: Some(priority_recv),
-            version: AtomicCell::new(MinecraftVersion::from_protocol(CURRENT_MC_PROTOCOL)),
+            version: Arc::new(AtomicCell::new(MinecraftVersion::from_protocol(
+                CURRENT_MC_PROTOCOL,
+            ))),
             network_writer: Arc::new(Mutex::new(TCPNetworkEncoder::new(BufWriter::new(write)))),
             network_reader: Mutex::new(TCPNetworkDecoder::new(BufReader::new(read))),
             brand: Mutex::new(None),
+            packet_capture: Arc::new(ArcSwapOption::empty()),
         }
     }
     pub async fn set_encryption(
@@ -200,12 +283,15 @@ impl JavaClient {
                     }
                 }
                 Err(error) => {
-                    let text = format!("Error while reading incoming packet {error}");
                     debug!(
                         "Failed to read incoming packet with id {}: {}",
                         packet.id, error
                     );
-                    self.kick(TextComponent::text(text)).await;
+                    self.kick(TextComponent::translate(
+                        translation::DISCONNECT_PACKETERROR,
+                        [],
+                    ))
+                    .await;
                 }
             }
         }
@@ -218,14 +304,15 @@ impl JavaClient {
                 Ok(()) => {}
                 Err(e) => {
                     if e.is_kick() {
-                        if let Some(kick_reason) = e.client_kick_reason() {
-                            self.kick(TextComponent::text(kick_reason)).await;
-                        } else {
-                            self.kick(TextComponent::text(format!(
-                                "Error while handling incoming packet {e}"
-                            )))
-                            .await;
-                        }
+                        let kick_reason = e.client_kick_reason().unwrap_or_else(|| {
+                            TextComponent::translate(
+                                translation::DISCONNECT_GENERICREASON,
+                                [TextComponent::text(format!(
+                                    "Error while handling incoming packet {e}"
+                                ))],
+                            )
+                        });
+                        self.kick(kick_reason).await;
                     }
                     e.log();
                 }
@@ -269,21 +356,83 @@ impl JavaClient {
     ///
     /// * `packet`: A reference to a packet object implementing the `ClientPacket` trait.
     pub async fn enqueue_packet_data(&self, packet_data: Bytes) {
-        if let Err(err) = self
-            .outgoing_packet_queue_send
-            .send(OutgoingPacket::normal(packet_data))
-            .await
-        {
-            // This is expected to fail if we are closed
-            if !self.close_token.is_cancelled() {
-                error!(
-                    "Failed to add packet to the outgoing packet queue for client {}: {}",
-                    self.id, err
-                );
+        self.enqueue_outgoing(OutgoingPacket::normal(OutgoingPayload::Encoded(
+            packet_data,
+        )))
+        .await;
+    }
+
+    /// Queues a packet which is shared with other recipients. Unlike `enqueue_packet`, the packet
+    /// is serialized by the outgoing packet task, so the caller does no encoding work.
+    pub async fn enqueue_packet_shared<P: ClientPacket + Send + Sync + 'static>(
+        &self,
+        packet: Arc<P>,
+    ) {
+        self.enqueue_outgoing(OutgoingPacket::normal(OutgoingPayload::Deferred(packet)))
+            .await;
+    }
+
+    /// Pushes a packet into the outgoing queue without waiting for room, so a slow client never
+    /// stalls the tick. While the queue is full, packets wait in the overflow buffer. A client
+    /// whose queue stays full for longer than the grace period gets disconnected.
+    async fn enqueue_outgoing(&self, packet: OutgoingPacket) {
+        let mut overflow = self.outgoing_overflow.lock().await;
+        if overflow.packets.is_empty() {
+            match self.outgoing_packet_queue_send.try_send(packet) {
+                Ok(()) => return,
+                Err(TrySendError::Full(packet)) => {
+                    overflow.packets.push_back(packet);
+                    overflow.full_since = Some(Instant::now());
+                }
+                Err(TrySendError::Closed(_)) => {
+                    // This is expected to fail if we are closed
+                    if !self.close_token.is_cancelled() {
+                        error!(
+                            "Failed to add packet to the outgoing packet queue for client {}",
+                            self.id
+                        );
+                        // The stream is in an unknown state
+                        self.close();
+                    }
+                    return;
+                }
             }
+        } else {
+            // Queue behind the waiting packets to keep the order
+            overflow.packets.push_back(packet);
+            overflow.refill(&self.outgoing_packet_queue_send);
+        }
+        if overflow.is_past_grace_period() && !self.close_token.is_cancelled() {
+            warn!(
+                "Outgoing packet queue for client {} was full for too long, disconnecting",
+                self.id
+            );
+            overflow.packets.clear();
+            drop(overflow);
+            self.close();
         }
     }
 
+    /// Starts recording all clientbound packets to `path`, replacing a running capture.
+    /// The capture stops once the file would exceed `max_bytes` or the client disconnects.
+    pub fn start_packet_capture(
+        &self,
+        path: PathBuf,
+        max_bytes: u64,
+        server: Weak<Server>,
+    ) -> std::io::Result<()> {
+        let capture = PacketCapture::start(path, max_bytes, server)?;
+        self.packet_capture.store(Some(Arc::new(capture)));
+        Ok(())
+    }
+
+    /// Stops the running packet capture, returning the path it was written to.
+    pub fn stop_packet_capture(&self) -> Option<PathBuf> {
+        self.packet_capture
+            .swap(None)
+            .map(|capture| capture.path().to_path_buf())
+    }
+
     pub async fn await_close_interrupt(&self) {
         self.close_token.cancelled().await;
     }
@@ -301,8 +450,11 @@ impl JavaClient {
                     Err(err) => {
                         if !matches!(err, PacketDecodeError::ConnectionClosed) {
                             warn!("Failed to decode packet from client {}: {}", self.id, err);
-                            let text = format!("Error while reading incoming packet {err}");
-                            self.kick(TextComponent::text(text)).await;
+                            self.kick(TextComponent::translate(
+                                translation::DISCONNECT_PACKETERROR,
+                                [],
+                            ))
+                            .await;
                         }
                         None
                     }
@@ -314,20 +466,24 @@ impl JavaClient {
     pub async fn kick(&self, reason: TextComponent) {
         match self.connection_state.load() {
             ConnectionState::Login => {
-                // TextComponent implements Serialize and writes in bytes instead of String, that's the reasib we only use content
+                // Before login finishes the reason is sent as a JSON string, afterwards as NBT.
+                // TextComponent's own Serialize writes NBT, so serialize the inner content
                 self.send_packet_now(&CLoginDisconnect::new(
                     serde_json::to_string(&reason.0).unwrap_or_else(|_| String::new()),
                 ))
                 .await;
             }
             ConnectionState::Config => {
-                self.send_packet_now(&CConfigDisconnect::new(&reason.get_text()))
-                    .await;
+                self.send_packet_now(&CConfigDisconnect::new(&reason)).await;
             }
             ConnectionState::Play => self.send_packet_now(&CPlayDisconnect::new(&reason)).await,
             _ => {}
         }
-        debug!("Closing connection for {}", self.id);
+        debug!(
+            "Closing connection for {}: {}",
+            self.id,
+            reason.to_pretty_console()
+        );
         self.close();
     }
 
@@ -343,7 +499,10 @@ impl JavaClient {
 
         if let Err(err) = self
             .outgoing_packet_priority_send
-            .send(OutgoingPacket::high_priority(packet, completion_tx))
+            .send(OutgoingPacket::high_priority(
+                OutgoingPayload::Encoded(packet),
+                completion_tx,
+            ))
             .await
         {
             // It is expected that the packet will fail if we are closed
@@ -475,6 +634,8 @@ impl JavaClient {
         }
     }
 
+    /// Spawns the task which serializes, compresses, encrypts and writes all queued packets in
+    /// order, keeping that work off the world tick.
     pub fn start_outgoing_packet_task(&mut self) {
         const MAX_BATCH_SIZE: usize = 64;
 
@@ -486,8 +647,12 @@ impl JavaClient {
             .outgoing_packet_priority_recv
             .take()
             .expect("This was set in the new fn");
+        let queue = self.outgoing_packet_queue_send.clone();
+        let overflow = self.outgoing_overflow.clone();
         let close_token = self.close_token.clone();
         let writer = self.network_writer.clone();
+        let version = self.version.clone();
+        let packet_capture = self.packet_capture.clone();
         let id = self.id;
         self.spawn_task(async move {
             while !close_token.is_cancelled() {
@@ -498,32 +663,54 @@ impl JavaClient {
                     res = packet_receiver.recv() => res,
                 };
 
-                let Some(packet_data) = recv_result else {
+                let Some(packet) = recv_result else {
                     break;
                 };
 
                 let mut packet_batch = Vec::with_capacity(MAX_BATCH_SIZE);
-                packet_batch.push(packet_data);
+                packet_batch.push(packet);
 
                 while packet_batch.len() < MAX_BATCH_SIZE {
                     match priority_packet_receiver.try_recv() {
-                        Ok(packet_data) => {
-                            packet_batch.push(packet_data);
+                        Ok(packet) => {
+                            packet_batch.push(packet);
                             continue;
                         }
                         Err(TryRecvError::Disconnected | TryRecvError::Empty) => {}
                     }
 
                     match packet_receiver.try_recv() {
-                        Ok(packet_data) => packet_batch.push(packet_data),
+                        Ok(packet) => packet_batch.push(packet),
                         Err(TryRecvError::Disconnected | TryRecvError::Empty) => break,
                     }
                 }
 
+                let version = version.load();
+                let capture = packet_capture.load_full();
                 let mut writer = writer.lock().await;
                 let mut send_failed = false;
                 for packet in &packet_batch {
-                    if let Err(err) = writer.write_packet(packet.data.clone()).await {
+                    let packet_data = match &packet.payload {
+                        OutgoingPayload::Encoded(data) => data.clone(),
+                        OutgoingPayload::Deferred(packet) => match packet.serialize(version) {
+                            Ok(data) => data,
+                            Err(err) => {
+                                error!(
+                                    "Failed to serialize packet {} for client {id}: {err}",
+                                    packet.name()
+                                );
+                                continue;
+                            }
+                        },
+                    };
+                    if capture
+                        .as_ref()
+                        .is_some_and(|capture| !capture.record(version, packet_data.clone()))
+                    {
+                        // The capture reached its size limit
+                        packet_capture.compare_and_swap(&capture, None);
+                    }
+                    if let Err(err) = writer.write_packet(packet_data).await {
                         send_failed = true;
                         // It is expected that the packet will fail if we are closed
                         if !close_token.is_cancelled() {
@@ -552,6 +739,8 @@ impl JavaClient {
                         let _ = completion.send(());
                     }
                 }
+                // Room was made, so let waiting packets in
+                overflow.lock().await.refill(&queue);
             }
         });
     }
@@ -567,6 +756,7 @@ impl JavaClient {
     /// This function does not attempt to send any disconnect packets to the client.
     pub fn close(&self) {
         self.close_token.cancel();
+        self.packet_capture.store(None);
     }
 
     pub fn is_closed(&self) -> bool {
@@ -745,8 +935,13 @@ impl JavaClient {
             id if id == SClickSlot::PACKET_ID => {
                 player.on_slot_click(SClickSlot::read(payload)?).await;
             }
+            id if id == SContainerButtonClick::PACKET_ID => {
+                player
+                    .on_button_click(SContainerButtonClick::read(payload)?)
+                    .await;
+            }
             id if id == SSetHeldItem::PACKET_ID => {
-                self.handle_set_held_item(player, SSetHeldItem::read(payload)?)
+                self.handle_set_held_item(player, SSetHeldItem::read(payload)?, server)
                     .await;
             }
             id if id == SSetCreativeSlot::PACKET_ID => {
@@ -757,6 +952,10 @@ impl JavaClient {
                 self.handle_swing_arm(player, SSwingArm::read(payload)?)
                     .await;
             }
+            id if id == SEditBook::PACKET_ID => {
+                self.handle_edit_book(player, SEditBook::read(payload)?)
+                    .await;
+            }
             id if id == SUpdateSign::PACKET_ID => {
                 self.handle_sign_update(player, SUpdateSign::read(payload)?)
                     .await;
@@ -804,3 +1003,65 @@ impl JavaClient {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::time::Instant;
+
+    use bytes::Bytes;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::{TcpListener, TcpStream};
+
+    use super::{JavaClient, OUTGOING_QUEUE_CAPACITY, OUTGOING_QUEUE_GRACE_PERIOD};
+
+    /// A client without a running outgoing packet task, and the other end of its connection.
+    async fn connect() -> (JavaClient, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let (remote, accepted) = tokio::join!(
+            TcpStream::connect(listener.local_addr().unwrap()),
+            listener.accept()
+        );
+        let (stream, address) = accepted.unwrap();
+        (JavaClient::new(stream, address, 0), remote.unwrap())
+    }
+
+    fn numbered(i: usize) -> Bytes {
+        Bytes::copy_from_slice(&u16::try_from(i).unwrap().to_be_bytes())
+    }
+
+    #[tokio::test]
+    async fn full_queue_keeps_packets_in_order_until_they_are_sent() {
+        let (mut client, mut remote) = connect().await;
+        let total = OUTGOING_QUEUE_CAPACITY + 10;
+        for i in 0..total {
+            client.enqueue_packet_data(numbered(i)).await;
+        }
+        assert!(!client.is_closed());
+        assert_eq!(client.outgoing_overflow.lock().await.packets.len(), 10);
+
+        client.start_outgoing_packet_task();
+        for i in 0..total {
+            // Uncompressed frames: the length, then the packet data
+            assert_eq!(remote.read_u8().await.unwrap(), 2);
+            assert_eq!(usize::from(remote.read_u16().await.unwrap()), i);
+        }
+        assert!(!client.is_closed());
+        let overflow = client.outgoing_overflow.lock().await;
+        assert!(overflow.packets.is_empty());
+        assert!(overflow.full_since.is_none());
+    }
+
+    #[tokio::test]
+    async fn queue_full_past_the_grace_period_disconnects() {
+        let (client, _remote) = connect().await;
+        for i in 0..=OUTGOING_QUEUE_CAPACITY {
+            client.enqueue_packet_data(numbered(i)).await;
+        }
+        assert!(!client.is_closed());
+
+        client.outgoing_overflow.lock().await.full_since =
+            Instant::now().checked_sub(OUTGOING_QUEUE_GRACE_PERIOD);
+        client.enqueue_packet_data(numbered(0)).await;
+        assert!(client.is_closed());
+    }
+}
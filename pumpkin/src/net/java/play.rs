@@ -1,9 +1,9 @@
-use pumpkin_protocol::bedrock::server::text::SText;
-use pumpkin_util::{Hand, PermissionLvl};
+use pumpkin_util::{Arm, Hand, PermissionLvl};
 use rsa::pkcs1v15::{Signature as RsaPkcs1v15Signature, VerifyingKey};
 use rsa::signature::Verifier;
 use sha1::Sha1;
 use std::num::NonZeroU8;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -17,22 +17,26 @@ use crate::command::CommandSender;
 use crate::entity::EntityBase;
 use crate::entity::player::{ChatMode, ChatSession, Player};
 use crate::error::PumpkinError;
+use crate::item::ItemActionResult;
 use crate::log_at_level;
 use crate::net::PlayerConfig;
 use crate::net::java::JavaClient;
 use crate::plugin::block::block_place::BlockPlaceEvent;
-use crate::plugin::player::player_chat::PlayerChatEvent;
 use crate::plugin::player::player_command_send::PlayerCommandSendEvent;
 use crate::plugin::player::player_interact_entity_event::PlayerInteractEntityEvent;
 use crate::plugin::player::player_interact_event::{InteractAction, PlayerInteractEvent};
 use crate::plugin::player::player_interact_unknown_entity_event::PlayerInteractUnknownEntityEvent;
 use crate::plugin::player::player_move::PlayerMoveEvent;
-use crate::server::{Server, seasonal_events};
-use crate::world::{World, chunker};
+use crate::server::Server;
+use crate::world::{World, chat, chunker};
 use pumpkin_data::block_properties::{
     BlockProperties, CommandBlockLikeProperties, WaterLikeProperties,
 };
-use pumpkin_data::data_component_impl::{ConsumableImpl, EquipmentSlot, EquippableImpl, FoodImpl};
+use pumpkin_data::data_component::DataComponent;
+use pumpkin_data::data_component_impl::{
+    BlocksAttacksImpl, ConsumableImpl, EquipmentSlot, EquippableImpl, FoodImpl,
+    WritableBookContentImpl, WrittenBookContentImpl,
+};
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::sound::{Sound, SoundCategory};
@@ -41,28 +45,33 @@ use pumpkin_inventory::InventoryError;
 use pumpkin_inventory::player::player_inventory::PlayerInventory;
 use pumpkin_inventory::screen_handler::{InventoryPlayer, ScreenHandler};
 use pumpkin_macros::send_cancellable;
+use pumpkin_protocol::codec::item_stack_seralizer::ItemStackSerializer;
 use pumpkin_protocol::codec::var_int::VarInt;
 use pumpkin_protocol::java::client::play::{
     CBlockUpdate, CCommandSuggestions, CEntityPositionSync, CHeadRot, COpenSignEditor,
-    CPingResponse, CPlayerInfoUpdate, CPlayerPosition, CSetSelectedSlot, CSystemChatMessage,
+    CPingResponse, CPlayerInfoUpdate, CPlayerPosition, CSetPlayerInventory, CSetSelectedSlot,
     CUpdateEntityPos, CUpdateEntityPosRot, CUpdateEntityRot, InitChat, PlayerAction,
 };
 use pumpkin_protocol::java::server::play::{
-    Action, ActionType, CommandBlockMode, FLAG_ON_GROUND, SChangeGameMode, SChatCommand,
-    SChatMessage, SChunkBatch, SClientCommand, SClientInformationPlay, SCloseContainer,
-    SCommandSuggestion, SConfirmTeleport, SCookieResponse as SPCookieResponse, SInteract,
-    SKeepAlive, SPickItemFromBlock, SPlayPingRequest, SPlayerAbilities, SPlayerAction,
-    SPlayerCommand, SPlayerInput, SPlayerPosition, SPlayerPositionRotation, SPlayerRotation,
-    SPlayerSession, SSetCommandBlock, SSetCreativeSlot, SSetHeldItem, SSetPlayerGround, SSwingArm,
-    SUpdateSign, SUseItem, SUseItemOn, Status,
+    Action, ActionType, CommandBlockMode, FLAG_IN_WALL, FLAG_ON_GROUND, SChangeGameMode,
+    SChatCommand, SChatMessage, SChunkBatch, SClientCommand, SClientInformationPlay,
+    SCloseContainer, SCommandSuggestion, SConfirmTeleport, SCookieResponse as SPCookieResponse,
+    SEditBook, SInteract, SKeepAlive, SPickItemFromBlock, SPlayPingRequest, SPlayerAbilities,
+    SPlayerAction, SPlayerCommand, SPlayerInput, SPlayerPosition, SPlayerPositionRotation,
+    SPlayerRotation, SPlayerSession, SSetCommandBlock, SSetCreativeSlot, SSetHeldItem,
+    SSetPlayerGround, SSwingArm, SUpdateSign, SUseItem, SUseItemOn, Status,
 };
 use pumpkin_util::math::boundingbox::BoundingBox;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_util::math::{polynomial_rolling_hash, position::BlockPos, wrap_degrees};
 use pumpkin_util::text::color::NamedColor;
+use pumpkin_util::translation::Locale;
 use pumpkin_util::{GameMode, text::TextComponent};
+use pumpkin_world::block::entities::BlockEntity;
 use pumpkin_world::block::entities::command_block::CommandBlockEntity;
+use pumpkin_world::block::entities::lectern::LecternBlockEntity;
 use pumpkin_world::block::entities::sign::SignBlockEntity;
+use pumpkin_world::inventory::Inventory;
 use pumpkin_world::item::ItemStack;
 use pumpkin_world::world::BlockFlags;
 use tokio::sync::Mutex;
@@ -70,6 +79,9 @@ use tokio::sync::Mutex;
 /// In secure chat mode, Player will be kicked if they send a chat message with a timestamp that is older than this (in ms)
 /// Vanilla: 2 minutes
 const CHAT_MESSAGE_MAX_AGE: i64 = 1000 * 60 * 2;
+/// Share of the expected digging time a player has to spend on a block before finishing it.
+/// The rest is grace for latency and for status effects the prediction doesn't know about.
+const MIN_DIG_TIME_RATIO: f64 = 0.7;
 
 #[derive(Debug, Error)]
 pub enum BlockPlacingError {
@@ -101,11 +113,13 @@ impl PumpkinError for BlockPlacingError {
         }
     }
 
-    fn client_kick_reason(&self) -> Option<String> {
+    fn client_kick_reason(&self) -> Option<TextComponent> {
         match self {
             Self::BlockOutOfReach | Self::BlockOutOfWorld | Self::InvalidGamemode => None,
-            Self::InvalidBlockFace => Some("Invalid block face".into()),
-            Self::InvalidHand => Some("Invalid hand".into()),
+            Self::InvalidBlockFace | Self::InvalidHand => Some(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                [],
+            )),
         }
     }
 }
@@ -139,53 +153,22 @@ impl PumpkinError for ChatError {
         Level::WARN
     }
 
-    fn client_kick_reason(&self) -> Option<String> {
-        match self {
-            Self::OversizedMessage => Some("Chat message too long".into()),
-            Self::IllegalCharacters => Some(
-                TextComponent::translate(
-                    translation::MULTIPLAYER_DISCONNECT_ILLEGAL_CHARACTERS,
-                    [],
-                )
-                .get_text(),
-            ),
-            Self::UnsignedChat => Some(
-                TextComponent::translate(translation::MULTIPLAYER_DISCONNECT_UNSIGNED_CHAT, [])
-                    .get_text(),
-            ),
-            Self::TooManyPendingChats => Some(
-                TextComponent::translate(
-                    translation::MULTIPLAYER_DISCONNECT_TOO_MANY_PENDING_CHATS,
-                    [],
-                )
-                .get_text(),
-            ),
-            Self::ChatValidationFailed => Some(
-                TextComponent::translate(
-                    translation::MULTIPLAYER_DISCONNECT_CHAT_VALIDATION_FAILED,
-                    [],
-                )
-                .get_text(),
-            ),
-            Self::OutOfOrderChat => Some(
-                TextComponent::translate(translation::MULTIPLAYER_DISCONNECT_OUT_OF_ORDER_CHAT, [])
-                    .get_text(),
-            ),
-            Self::ExpiredPublicKey => Some(
-                TextComponent::translate(
-                    translation::MULTIPLAYER_DISCONNECT_EXPIRED_PUBLIC_KEY,
-                    [],
-                )
-                .get_text(),
-            ),
-            Self::InvalidPublicKey => Some(
-                TextComponent::translate(
-                    translation::MULTIPLAYER_DISCONNECT_INVALID_PUBLIC_KEY_SIGNATURE,
-                    [],
-                )
-                .get_text(),
-            ),
-        }
+    fn client_kick_reason(&self) -> Option<TextComponent> {
+        let key = match self {
+            Self::OversizedMessage => translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+            Self::IllegalCharacters => translation::MULTIPLAYER_DISCONNECT_ILLEGAL_CHARACTERS,
+            Self::UnsignedChat => translation::MULTIPLAYER_DISCONNECT_UNSIGNED_CHAT,
+            Self::TooManyPendingChats => translation::MULTIPLAYER_DISCONNECT_TOO_MANY_PENDING_CHATS,
+            Self::ChatValidationFailed => {
+                translation::MULTIPLAYER_DISCONNECT_CHAT_VALIDATION_FAILED
+            }
+            Self::OutOfOrderChat => translation::MULTIPLAYER_DISCONNECT_OUT_OF_ORDER_CHAT,
+            Self::ExpiredPublicKey => translation::MULTIPLAYER_DISCONNECT_EXPIRED_PUBLIC_KEY,
+            Self::InvalidPublicKey => {
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PUBLIC_KEY_SIGNATURE
+            }
+        };
+        Some(TextComponent::translate(key, []))
     }
 }
 
@@ -207,11 +190,16 @@ impl JavaClient {
                 *awaiting_teleport = None;
                 drop(awaiting_teleport);
             } else {
-                self.kick(TextComponent::text("Wrong teleport id")).await;
+                self.kick(TextComponent::translate(
+                    translation::MULTIPLAYER_DISCONNECT_INVALID_PLAYER_MOVEMENT,
+                    [],
+                ))
+                .await;
             }
         } else {
-            self.kick(TextComponent::text(
-                "Send Teleport confirm, but we did not teleport",
+            self.kick(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PLAYER_MOVEMENT,
+                [],
             ))
             .await;
         }
@@ -323,9 +311,17 @@ impl JavaClient {
                 let pos = event.to;
                 let entity = &player.living_entity.entity;
                 let last_pos = entity.pos.load();
+                if player.moved_too_quickly(pos.sub(&last_pos)).await {
+                    warn!("{} moved too quickly! {:?}", player.gameprofile.name, pos.sub(&last_pos));
+                    player.request_teleport(last_pos, entity.yaw.load(), entity.pitch.load()).await;
+                    return;
+                }
                 player.living_entity.entity.set_pos(pos);
 
                 let height_difference = pos.y - last_pos.y;
+                player
+                    .handle_glide_movement(pos.sub(&last_pos), packet.collision & FLAG_IN_WALL != 0)
+                    .await;
                 if entity.on_ground.load(Ordering::Relaxed) && packet.collision & FLAG_ON_GROUND == 0 && height_difference > 0.0 {
                     player.jump().await;
                 }
@@ -436,9 +432,17 @@ impl JavaClient {
                 let pos = event.to;
                 let entity = &player.living_entity.entity;
                 let last_pos = entity.pos.load();
+                if player.moved_too_quickly(pos.sub(&last_pos)).await {
+                    warn!("{} moved too quickly! {:?}", player.gameprofile.name, pos.sub(&last_pos));
+                    player.request_teleport(last_pos, entity.yaw.load(), entity.pitch.load()).await;
+                    return;
+                }
                 player.living_entity.entity.set_pos(pos);
 
                 let height_difference = pos.y - last_pos.y;
+                player
+                    .handle_glide_movement(pos.sub(&last_pos), (packet.collision & FLAG_IN_WALL) != 0)
+                    .await;
                 if entity.on_ground.load(Ordering::Relaxed)
                     && (packet.collision & FLAG_ON_GROUND) != 0
                     && height_difference > 0.0
@@ -686,15 +690,17 @@ impl JavaClient {
             return;
         }
         let pos = command.pos;
-        if let Some(block_entity) = player.world().get_block_entity(&pos).await {
-            if block_entity.resource_location() != CommandBlockEntity::ID {
-                warn!("Client tried to change Command block but not Command block entity found");
-                return;
-            }
-
+        if let Some(old_command_block) = player
+            .world()
+            .get_block_entity_data::<CommandBlockEntity>(&pos)
+            .await
+        {
             let Ok(command_block_mode) = CommandBlockMode::try_from(command.mode) else {
-                self.kick(TextComponent::text("Invalid Command block mode"))
-                    .await;
+                self.kick(TextComponent::translate(
+                    translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                    [],
+                ))
+                .await;
                 return;
             };
 
@@ -708,9 +714,6 @@ impl JavaClient {
                 CommandBlockMode::Impulse => Block::COMMAND_BLOCK,
             };
 
-            let old_command_block: &CommandBlockEntity =
-                block_entity.as_any().downcast_ref().unwrap();
-
             props.conditional = command.flags & 0x2 != 0;
 
             let new_state_id = props.to_state_id(&block_type);
@@ -765,6 +768,8 @@ impl JavaClient {
                     )
                     .await;
             }
+        } else {
+            warn!("Client tried to change Command block but not Command block entity found");
         }
     }
 
@@ -792,19 +797,27 @@ impl JavaClient {
                 }
                 Action::LeaveBed => player.wake_up().await,
 
-                Action::StartHorseJump | Action::StopHorseJump | Action::OpenVehicleInventory => {
+                Action::OpenVehicleInventory => {
+                    let vehicle = entity.vehicle.lock().await.clone();
+                    if let Some(horse) = vehicle.and_then(EntityBase::get_horse) {
+                        player.send_open_horse_inventory(horse).await;
+                    }
+                }
+                Action::StartHorseJump | Action::StopHorseJump => {
                     debug!("todo");
                 }
                 Action::StartFlyingElytra => {
-                    let fall_flying = entity.check_fall_flying();
-                    if entity.fall_flying.load(Ordering::Relaxed) != fall_flying {
-                        entity.set_fall_flying(fall_flying).await;
+                    if !player.living_entity.try_start_gliding().await {
+                        player.living_entity.stop_gliding().await;
                     }
-                } // TODO
+                }
             }
         } else {
-            self.kick(TextComponent::text("Invalid player command"))
-                .await;
+            self.kick(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                [],
+            ))
+            .await;
         }
     }
 
@@ -818,7 +831,11 @@ impl JavaClient {
     pub async fn handle_swing_arm(&self, player: &Arc<Player>, swing_arm: SSwingArm) {
         player.update_last_action_time();
         let Ok(hand) = Hand::try_from(swing_arm.hand.0) else {
-            self.kick(TextComponent::text("Invalid hand")).await;
+            self.kick(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                [],
+            ))
+            .await;
             return;
         };
 
@@ -892,48 +909,18 @@ impl JavaClient {
             if err.is_kick()
                 && let Some(reason) = err.client_kick_reason()
             {
-                self.kick(TextComponent::text(reason)).await;
+                self.kick(reason).await;
             }
             return;
         }
 
-        send_cancellable! {{
-            server;
-            PlayerChatEvent::new(player.clone(), chat_message.message.clone(), vec![]);
-
-            'after: {
-                info!("<chat> {}: {}", gameprofile.name, event.message);
-
-                let config = &server.advanced_config;
-
-                let message = match seasonal_events::modify_chat_message(&event.message, config) {
-                    Some(m) => m,
-                    None => event.message.clone(),
-                };
-
-                let decorated_message = TextComponent::chat_decorated(
-                    &config.chat.format,
-                    &gameprofile.name,
-                    &message,
-                );
-
-                let entity = &player.living_entity.entity;
-                let world = entity.world.load_full();
-                if server.basic_config.allow_chat_reports {
-                    world.broadcast_secure_player_chat(player, &chat_message, &decorated_message).await;
-                } else {
-                    let je_packet = CSystemChatMessage::new(
-                        &decorated_message,
-                        false,
-                    );
-                    let be_packet = SText::new(
-                        message, player.gameprofile.name.clone()
-                    );
-
-                    world.broadcast_editioned(&je_packet, &be_packet).await;
-                }
-            }
-        }}
+        chat::send_player_chat(
+            server,
+            player,
+            chat_message.message.clone(),
+            Some(&chat_message),
+        )
+        .await;
     }
 
     /// Runs all vanilla checks for a valid chat message
@@ -1017,7 +1004,7 @@ impl JavaClient {
             if err.is_kick()
                 && let Some(reason) = err.client_kick_reason()
             {
-                self.kick(TextComponent::text(reason)).await;
+                self.kick(reason).await;
             }
             return;
         }
@@ -1092,12 +1079,13 @@ impl JavaClient {
         client_information: SClientInformationPlay,
     ) {
         if let (Ok(main_hand), Ok(chat_mode)) = (
-            Hand::try_from(client_information.main_hand.0),
+            Arm::try_from(client_information.main_hand.0),
             ChatMode::try_from(client_information.chat_mode.0),
         ) {
             if client_information.view_distance <= 0 {
-                self.kick(TextComponent::text(
-                    "Cannot have zero or negative view distance!",
+                self.kick(TextComponent::translate(
+                    translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                    [],
                 ))
                 .await;
                 return;
@@ -1159,8 +1147,11 @@ impl JavaClient {
                 player.send_client_information().await;
             }
         } else {
-            self.kick(TextComponent::text("Invalid hand or chat type"))
-                .await;
+            self.kick(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                [],
+            ))
+            .await;
         }
     }
 
@@ -1190,8 +1181,11 @@ impl JavaClient {
                 debug!("todo");
             }
             _ => {
-                self.kick(TextComponent::text("Invalid client status"))
-                    .await;
+                self.kick(TextComponent::translate(
+                    translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                    [],
+                ))
+                .await;
             }
         }
     }
@@ -1214,7 +1208,11 @@ impl JavaClient {
             player_entity.set_sneaking(sneaking).await;
         }
         let Ok(action) = ActionType::try_from(interact.r#type.0) else {
-            self.kick(TextComponent::text("Invalid action type")).await;
+            self.kick(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                [],
+            ))
+            .await;
             return;
         };
 
@@ -1339,9 +1337,11 @@ impl JavaClient {
 
                     let inventory = player.inventory();
                     let held = inventory.held_item();
-                    if !server
-                        .item_registry
-                        .can_mine(held.lock().await.item, player)
+                    if player.is_block_break_restricted(block).await
+                        || world.is_ceiling_mining_prevented(position)
+                        || !server
+                            .item_registry
+                            .can_mine(held.lock().await.item, player)
                     {
                         self.enqueue_packet(&CBlockUpdate::new(
                             position,
@@ -1422,7 +1422,6 @@ impl JavaClient {
                     self.update_sequence(player, player_action.sequence.0);
                 }
                 Status::FinishedDigging => {
-                    // TODO: do validation
                     let location = player_action.position;
                     if !player.can_interact_with_block_at(&location, 1.0) {
                         warn!(
@@ -1437,10 +1436,36 @@ impl JavaClient {
                     let entity = &player.living_entity.entity;
                     let world = entity.world.load_full();
 
-                    player.mining.store(false, Ordering::Relaxed);
+                    let was_mining = player.mining.swap(false, Ordering::Relaxed)
+                        && *player.mining_pos.lock().await == location;
                     world.set_block_breaking(entity, location, -1).await;
 
                     let (block, state) = world.get_block_and_state(&location).await;
+                    if player.is_block_break_restricted(block).await {
+                        self.enqueue_packet(&CBlockUpdate::new(
+                            location,
+                            VarInt(i32::from(state.id)),
+                        ))
+                        .await;
+                        self.update_sequence(player, player_action.sequence.0);
+                        return;
+                    }
+
+                    if was_mining && player.gamemode.load() != GameMode::Creative {
+                        let speed = player.get_block_destroy_speed(&location).await;
+                        let elapsed_ticks = player.tick_counter.load(Ordering::Relaxed)
+                            - player.start_mining_time.load(Ordering::Relaxed);
+                        let expected_ticks = (1.0 / speed).ceil();
+                        // Blocks with a speed of 0 have no expected break time
+                        if speed > 0.0
+                            && f64::from(elapsed_ticks) < expected_ticks * MIN_DIG_TIME_RATIO
+                        {
+                            warn!(
+                                "Player {0} broke {1} at {2} after {elapsed_ticks} ticks, expected at least {expected_ticks}",
+                                player.gameprofile.name, block.name, location
+                            );
+                        }
+                    }
                     let block_drop = player.gamemode.load() != GameMode::Creative
                         && player.can_harvest(state, block).await;
 
@@ -1475,13 +1500,19 @@ impl JavaClient {
                     player.living_entity.clear_active_hand().await;
                 }
                 Status::SwapItem => {
-                    player.swap_item().await;
+                    player.swap_item(server).await;
                 }
                 Status::SpearJab => {
                     debug!("todo");
                 }
             },
-            Err(_) => self.kick(TextComponent::text("Invalid status")).await,
+            Err(_) => {
+                self.kick(TextComponent::translate(
+                    translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                    [],
+                ))
+                .await
+            }
         }
     }
 
@@ -1497,8 +1528,9 @@ impl JavaClient {
             );
             player.wait_for_keep_alive.store(false, Ordering::Relaxed);
         } else {
-            self.kick(TextComponent::text(
-                "Timeout, The server probably has a deadlock!",
+            self.kick(TextComponent::translate(
+                translation::DISCONNECT_TIMEOUT,
+                [],
             ))
             .await;
         }
@@ -1519,14 +1551,28 @@ impl JavaClient {
         player: &Player,
         player_abilities: SPlayerAbilities,
     ) {
-        let mut abilities = player.abilities.lock().await;
+        let wants_flying = player_abilities.flags & 0x02 != 0;
+        let rejected = {
+            let mut abilities = player.abilities.lock().await;
+
+            // Flight is granted by the game mode, or explicitly through the abilities (e.g. by a plugin)
+            let may_fly = player.gamemode.load().allows_flying() || abilities.allow_flying;
+            let flying = wants_flying && may_fly;
+            if flying {
+                player.living_entity.fall_distance.store(0.0);
+            }
+            abilities.flying = flying;
+            wants_flying && !may_fly
+        };
 
-        // Set the flying ability
-        let flying = player_abilities.flags & 0x02 != 0 && abilities.allow_flying;
-        if flying {
-            player.living_entity.fall_distance.store(0.0);
+        if rejected {
+            // The client toggled flight on its own, so resync it with the authoritative abilities
+            debug!(
+                "{} tried to fly without being allowed to",
+                player.gameprofile.name
+            );
+            player.send_abilities_update().await;
         }
-        abilities.flying = flying;
     }
 
     pub async fn handle_play_ping_request(&self, request: SPlayPingRequest) {
@@ -1544,13 +1590,21 @@ impl JavaClient {
             return Ok(());
         }
         player.update_last_action_time();
-        self.update_sequence(player, use_item_on.sequence.0);
+        let sequence = use_item_on.sequence.0;
+        self.update_sequence(player, sequence);
 
         let position = use_item_on.position;
         let cursor_pos = use_item_on.cursor_pos;
 
         let mut should_try_decrement = false;
 
+        let Ok(hand) = Hand::try_from(use_item_on.hand.0) else {
+            return Err(BlockPlacingError::InvalidHand);
+        };
+        if !player.start_hand_interaction(hand, sequence) {
+            return Ok(());
+        }
+
         if !player.can_interact_with_block_at(&position, 1.0) {
             // TODO: maybe log?
             return Err(BlockPlacingError::BlockOutOfReach);
@@ -1560,10 +1614,6 @@ impl JavaClient {
             return Err(BlockPlacingError::InvalidBlockFace);
         };
 
-        let Ok(hand) = Hand::try_from(use_item_on.hand.0) else {
-            return Err(BlockPlacingError::InvalidHand);
-        };
-
         //TODO this.player.resetLastActionTime();
         //TODO this.gameModeForPlayer == GameType.SPECTATOR
 
@@ -1572,7 +1622,7 @@ impl JavaClient {
         let off_hand_item = inventory.off_hand_item().await;
         let held_item_empty = held_item.lock().await.is_empty();
         let off_hand_item_empty = off_hand_item.lock().await.is_empty();
-        let item = if matches!(hand, Hand::Left) {
+        let item = if matches!(hand, Hand::Main) {
             held_item
         } else {
             off_hand_item
@@ -1604,14 +1654,11 @@ impl JavaClient {
                 if matches!(result, BlockActionResult::SuccessServer) {
                     player.swing_hand(hand, true).await;
                 }
+                player.record_hand_interaction(hand, sequence, ItemActionResult::Success);
                 return Ok(());
             }
         }
-        let slot_index = if matches!(hand, Hand::Left) {
-            inventory.get_selected_slot() as usize
-        } else {
-            PlayerInventory::OFF_HAND_SLOT
-        };
+        let slot_index = inventory.hand_slot(hand);
 
         let mut stack = item.lock().await;
 
@@ -1621,9 +1668,14 @@ impl JavaClient {
             return Ok(());
         }
 
+        // Like vanilla, adventure mode restricts every item used on a block, not just block items
+        if player.is_block_place_restricted(&stack, block).await {
+            return Err(BlockPlacingError::InvalidGamemode);
+        }
+
         let before = stack.clone();
 
-        server
+        let mut result = server
             .item_registry
             .use_on_block(
                 &mut stack, player, position, face, cursor_pos, block, server,
@@ -1632,11 +1684,17 @@ impl JavaClient {
 
         // Check if the item is a block, because not every item can be placed :D
         let item_id = stack.item.id;
-        if let Some(block) = Block::from_item_id(item_id) {
+        if !result.consumes_action()
+            && let Some(block) = Block::from_item_id(item_id)
+        {
             should_try_decrement = self
                 .run_is_block_place(player, block, server, use_item_on, position, face)
                 .await?;
+            if should_try_decrement {
+                result = ItemActionResult::Success;
+            }
         }
+        player.record_hand_interaction(hand, sequence, result);
 
         if should_try_decrement {
             // TODO: Config
@@ -1709,10 +1767,10 @@ impl JavaClient {
 
     pub async fn handle_sign_update(&self, player: &Player, sign_data: SUpdateSign) {
         let world = player.living_entity.entity.world.load_full();
-        let Some(block_entity) = world.get_block_entity(&sign_data.location).await else {
-            return;
-        };
-        let Some(sign_entity) = block_entity.as_any().downcast_ref::<SignBlockEntity>() else {
+        let Some(sign_entity) = world
+            .get_block_entity_data::<SignBlockEntity>(&sign_data.location)
+            .await
+        else {
             return;
         };
         if sign_entity.is_waxed.load(Ordering::Relaxed) {
@@ -1732,9 +1790,110 @@ impl JavaClient {
             sign_data.line_4,
         ];
         *sign_entity.currently_editing_player.lock().await = None;
+        let block_entity: Arc<dyn BlockEntity> = sign_entity;
         world.update_block_entity(&block_entity).await;
     }
 
+    pub async fn handle_edit_book(&self, player: &Player, edit_book: SEditBook) {
+        // Vanilla's client limits, a modified client may send more
+        const MAX_PAGE_LINES: usize = 14;
+        const MAX_PAGE_CHARS: usize = 384;
+
+        player.update_last_action_time();
+        let slot = edit_book.slot.0;
+        if !(0..=8).contains(&slot) && slot != PlayerInventory::OFF_HAND_SLOT as i32 {
+            return;
+        }
+
+        let locale = Locale::from_str(&player.config.load().locale).unwrap_or(Locale::EnUs);
+        for (index, page) in edit_book.pages.iter().enumerate() {
+            let lines = page.lines().count();
+            let chars = page.chars().count();
+            if lines > MAX_PAGE_LINES || chars > MAX_PAGE_CHARS {
+                player
+                    .send_system_message(
+                        &TextComponent::custom(
+                            "pumpkin",
+                            "book.page_too_long",
+                            locale,
+                            [index + 1, lines, MAX_PAGE_LINES, chars, MAX_PAGE_CHARS]
+                                .map(|number| TextComponent::text(number.to_string())),
+                        )
+                        .color_named(NamedColor::Red),
+                    )
+                    .await;
+                return;
+            }
+        }
+
+        let title = edit_book.title.as_deref().map(str::trim);
+        if title.is_some_and(str::is_empty) {
+            player
+                .send_system_message(
+                    &TextComponent::custom("pumpkin", "book.empty_title", locale, vec![])
+                        .color_named(NamedColor::Red),
+                )
+                .await;
+            return;
+        }
+
+        // A book and quill opened on a lectern is shown in the selected slot while editing
+        let lectern = match player.editing_lectern.take() {
+            Some(position) if slot == i32::from(player.inventory().get_selected_slot()) => {
+                player
+                    .world()
+                    .get_block_entity_data::<LecternBlockEntity>(&position)
+                    .await
+            }
+            _ => None,
+        };
+
+        let slot = slot as usize;
+        let stack = match &lectern {
+            Some(lectern) => lectern.book_stack(),
+            None => player.inventory().get_stack(slot).await,
+        };
+        let mut stack = stack.lock().await;
+        if stack.item != &Item::WRITABLE_BOOK {
+            return;
+        }
+
+        if let Some(title) = title {
+            let mut written_book = ItemStack::new(1, &Item::WRITTEN_BOOK);
+            written_book.patch = stack
+                .patch
+                .iter()
+                .filter(|(id, _)| *id != DataComponent::WritableBookContent)
+                .cloned()
+                .collect();
+            written_book.set_data_component(WrittenBookContentImpl {
+                title: title.to_string(),
+                author: player.gameprofile.name.clone(),
+                generation: 0,
+                pages: edit_book.pages,
+                resolved: true,
+            });
+            *stack = written_book;
+        } else {
+            stack.set_data_component(WritableBookContentImpl {
+                pages: edit_book.pages,
+            });
+        }
+
+        let updated_stack = stack.clone();
+        drop(stack);
+        if let Some(lectern) = lectern {
+            lectern.mark_dirty();
+            return;
+        }
+        player
+            .enqueue_slot_set_packet(&CSetPlayerInventory::new(
+                (slot as i32).into(),
+                &ItemStackSerializer::from(updated_stack),
+            ))
+            .await;
+    }
+
     pub async fn handle_use_item(
         &self,
         player: &Arc<Player>,
@@ -1748,15 +1907,19 @@ impl JavaClient {
 
         let inventory = player.inventory();
         let Ok(hand) = Hand::try_from(use_item.hand.0) else {
-            self.kick(TextComponent::text("InvalidHand")).await;
+            self.kick(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                [],
+            ))
+            .await;
             return;
         };
-        self.update_sequence(player, use_item.sequence.0);
-        let item_in_hand = if hand == Hand::Left {
-            inventory.held_item()
-        } else {
-            inventory.off_hand_item().await
-        };
+        let sequence = use_item.sequence.0;
+        self.update_sequence(player, sequence);
+        if !player.start_hand_interaction(hand, sequence) {
+            return;
+        }
+        let item_in_hand = inventory.get_stack_in_hand(hand).await;
 
         let hit_result = player
             .world()
@@ -1790,25 +1953,28 @@ impl JavaClient {
                 None,
             )
         };
+        let mut result = ItemActionResult::Pass;
         let mut held = item_in_hand.lock().await;
         if held.get_data_component::<ConsumableImpl>().is_some() {
             // If its food we want to make sure we can actually consume it
-            if let Some(food) = held.get_data_component::<FoodImpl>() {
-                if player.abilities.lock().await.invulnerable
-                    || food.can_always_eat
-                    || player.hunger_manager.level.load() < 20
-                {
-                    player
-                        .living_entity
-                        .set_active_hand(hand, held.clone())
-                        .await;
-                }
-            } else {
+            let can_consume = held
+                .get_data_component::<FoodImpl>()
+                .is_none_or(|food| food.can_always_eat || player.hunger_manager.level.load() < 20)
+                || player.abilities.lock().await.invulnerable;
+            if can_consume {
                 player
                     .living_entity
                     .set_active_hand(hand, held.clone())
                     .await;
+                result = ItemActionResult::Consume;
             }
+        } else if held.get_data_component::<BlocksAttacksImpl>().is_some() {
+            // Shields block for as long as they are held up
+            player
+                .living_entity
+                .set_active_hand(hand, held.clone())
+                .await;
+            result = ItemActionResult::Consume;
         }
         if let Some(equippable) = held.get_data_component::<EquippableImpl>() {
             // If it can be equipped we want to make sure we can actually equip it
@@ -1826,6 +1992,7 @@ impl JavaClient {
                 *held = equip_item.clone();
                 *equip_item = binding;
             }
+            result = ItemActionResult::Success;
         }
         drop(held);
 
@@ -1838,16 +2005,29 @@ impl JavaClient {
             server;
             event;
             'after: {
-                server.item_registry.on_use(item_for_use, player).await;
+                if item_for_use == &Item::WRITABLE_BOOK || item_for_use == &Item::WRITTEN_BOOK {
+                    let book = item_in_hand.lock().await.clone();
+                    player.send_book(&book, hand).await;
+                    result = ItemActionResult::Success;
+                }
+                let item_result = server.item_registry.on_use(item_for_use, player, hand).await;
+                if !result.consumes_action() {
+                    result = item_result;
+                }
             }
         }}
+        player.record_hand_interaction(hand, sequence, result);
     }
 
-    pub async fn handle_set_held_item(&self, player: &Player, held: SSetHeldItem) {
+    pub async fn handle_set_held_item(&self, player: &Player, held: SSetHeldItem, server: &Server) {
         player.update_last_action_time();
         let slot = held.slot;
         if !(0..=8).contains(&slot) {
-            self.kick(TextComponent::text("Invalid held slot")).await;
+            self.kick(TextComponent::translate(
+                translation::MULTIPLAYER_DISCONNECT_INVALID_PACKET,
+                [],
+            ))
+            .await;
             return;
         }
         let inv = player.inventory();
@@ -1855,6 +2035,7 @@ impl JavaClient {
         let stack = inv.held_item().lock().await.clone();
         let equipment = &[(EquipmentSlot::MAIN_HAND, stack)];
         player.living_entity.send_equipment_changes(equipment).await;
+        player.send_map_update_for_held_item(server).await;
     }
 
     pub async fn handle_set_creative_slot(
@@ -2043,13 +2224,6 @@ impl JavaClient {
     ) -> Result<bool, BlockPlacingError> {
         let entity = &player.living_entity.entity;
 
-        match player.gamemode.load() {
-            GameMode::Spectator | GameMode::Adventure => {
-                return Err(BlockPlacingError::InvalidGamemode);
-            }
-            _ => {}
-        }
-
         let clicked_block_pos = BlockPos(location.0);
         let world = entity.world.load_full();
 
@@ -2147,21 +2321,23 @@ impl JavaClient {
                 }
             };
 
-        if !server
-            .block_registry
-            .can_place_at(
-                Some(server),
-                Some(&*world),
-                &*world,
-                Some(player),
-                block,
-                block.default_state,
+        if !world
+            .can_place_block(
                 &final_block_pos,
-                Some(final_face),
+                block,
+                final_face,
+                Some(player),
                 Some(&use_item_on),
             )
             .await
         {
+            // The client already predicted the placement, so undo it
+            let state_id = world.get_block_state_id(&final_block_pos).await;
+            self.send_packet_now(&CBlockUpdate::new(
+                final_block_pos,
+                VarInt(i32::from(state_id)),
+            ))
+            .await;
             return Ok(false);
         }
 
@@ -2194,6 +2370,13 @@ impl JavaClient {
             BlockPlaceEvent::new(player.clone(), block, clicked_block, final_block_pos, true);
         let event = server.plugin_manager.fire::<BlockPlaceEvent>(event).await;
         if event.cancelled {
+            // Undo the client's prediction like above
+            let state_id = world.get_block_state_id(&final_block_pos).await;
+            self.send_packet_now(&CBlockUpdate::new(
+                final_block_pos,
+                VarInt(i32::from(state_id)),
+            ))
+            .await;
             return Ok(false);
         }
 
@@ -2221,3 +2404,331 @@ impl JavaClient {
             .await;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::Block;
+    use pumpkin_data::block_properties::{BlockFace, BlockProperties, LeverLikeProperties};
+    use pumpkin_data::data_component::DataComponent;
+    use pumpkin_data::data_component_impl::{
+        AdventureModePredicate, BlockPredicate, BlockSet, CanBreakImpl, CanPlaceOnImpl,
+        DataComponentImpl,
+    };
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::item::Item;
+    use pumpkin_protocol::codec::var_int::VarInt;
+    use pumpkin_protocol::java::client::play::CBlockUpdate;
+    use pumpkin_protocol::java::server::play::{SPlayerAction, SUseItemOn};
+    use pumpkin_util::GameMode;
+    use pumpkin_util::math::{position::BlockPos, vector2::Vector2, vector3::Vector3};
+    use pumpkin_world::item::ItemStack;
+    use pumpkin_world::world::BlockFlags;
+    use tempfile::tempdir;
+
+    use crate::entity::player::Player;
+    use crate::entity::player::test::{TestConnection, test_player};
+    use crate::net::ClientPlatform;
+    use crate::net::java::play::BlockPlacingError;
+    use crate::plugin::block::block_place::BlockPlaceEvent;
+    use crate::plugin::test::cancel_all;
+    use crate::server::test::test_server;
+    use crate::world::World;
+    use crate::world::test::{load_empty_chunk, test_world_on};
+    use std::sync::Arc;
+
+    fn place_on_top(position: BlockPos, hand: i32, sequence: i32) -> SUseItemOn {
+        SUseItemOn {
+            hand: VarInt(hand),
+            position,
+            face: VarInt(1),
+            cursor_pos: Vector3::new(0.5, 1.0, 0.5),
+            inside_block: false,
+            is_against_world_border: false,
+            sequence: VarInt(sequence),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cancelled_block_place_changes_nothing() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let chunk = load_empty_chunk(&world, Vector2::new(0, 0));
+        chunk
+            .section
+            .set_block_absolute_y(0, 63, 0, Block::STONE.default_state.id);
+
+        let (player, mut connection) = test_player(&world, "Steve").await;
+        player.set_client_loaded(true);
+        player
+            .living_entity
+            .entity
+            .set_pos(Vector3::new(0.5, 64.0, 2.5));
+        *player.inventory().held_item().lock().await = ItemStack::new(4, &Item::DIRT);
+        let ClientPlatform::Java(client) = &player.client else {
+            unreachable!()
+        };
+
+        client
+            .handle_use_item_on(
+                &player,
+                place_on_top(BlockPos::new(0, 63, 0), 0, 1),
+                &server,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            world.get_block(&BlockPos::new(0, 64, 0)).await.id,
+            Block::DIRT.id
+        );
+        connection.received_packet_ids().await;
+
+        cancel_all::<BlockPlaceEvent>(&server).await;
+        client
+            .handle_use_item_on(
+                &player,
+                place_on_top(BlockPos::new(0, 64, 0), 0, 2),
+                &server,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            world.get_block(&BlockPos::new(0, 65, 0)).await.id,
+            Block::AIR.id
+        );
+        assert_eq!(player.inventory().held_item().lock().await.item_count, 3);
+        // The client predicted the placement, so it has to be told the block is still air
+        assert!(connection.received::<CBlockUpdate>().await);
+    }
+
+    /// A loaded player standing next to stone blocks at (0, 63, 0) and (1, 63, 0).
+    async fn player_next_to_stone(world: &Arc<World>) -> (Arc<Player>, TestConnection) {
+        let chunk = load_empty_chunk(world, Vector2::new(0, 0));
+        for x in 0..2 {
+            chunk
+                .section
+                .set_block_absolute_y(x, 63, 0, Block::STONE.default_state.id);
+        }
+        let (player, connection) = test_player(world, "Steve").await;
+        player.set_client_loaded(true);
+        player
+            .living_entity
+            .entity
+            .set_pos(Vector3::new(0.5, 64.0, 2.5));
+        (player, connection)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn swapping_hands_exchanges_the_stacks() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, _connection) = player_next_to_stone(&world).await;
+        let inventory = player.inventory();
+        *inventory.held_item().lock().await = ItemStack::new(4, &Item::DIRT);
+        *inventory.off_hand_item().await.lock().await = ItemStack::new(1, &Item::SHIELD);
+        let ClientPlatform::Java(client) = &player.client else {
+            unreachable!()
+        };
+
+        let swap = SPlayerAction {
+            status: VarInt(6),
+            position: BlockPos::new(0, 0, 0),
+            face: 0,
+            sequence: VarInt(0),
+        };
+        client.handle_player_action(&player, swap, &server).await;
+
+        assert_eq!(inventory.held_item().lock().await.item.id, Item::SHIELD.id);
+        let off_hand = inventory.off_hand_item().await.lock().await.clone();
+        assert_eq!(off_hand.item.id, Item::DIRT.id);
+        assert_eq!(off_hand.item_count, 4);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn off_hand_places_when_the_main_hand_does_nothing() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, _connection) = player_next_to_stone(&world).await;
+        let inventory = player.inventory();
+        *inventory.held_item().lock().await = ItemStack::new(1, &Item::DIAMOND_SWORD);
+        *inventory.off_hand_item().await.lock().await = ItemStack::new(4, &Item::DIRT);
+        let ClientPlatform::Java(client) = &player.client else {
+            unreachable!()
+        };
+
+        // The client tries the main hand first and the off hand when it passes
+        for (hand, sequence) in [(0, 1), (1, 2)] {
+            client
+                .handle_use_item_on(
+                    &player,
+                    place_on_top(BlockPos::new(0, 63, 0), hand, sequence),
+                    &server,
+                )
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            world.get_block(&BlockPos::new(0, 64, 0)).await.id,
+            Block::DIRT.id
+        );
+        assert_eq!(inventory.held_item().lock().await.item_count, 1);
+        assert_eq!(inventory.off_hand_item().await.lock().await.item_count, 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn main_hand_placement_suppresses_the_off_hand() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, _connection) = player_next_to_stone(&world).await;
+        let inventory = player.inventory();
+        *inventory.held_item().lock().await = ItemStack::new(4, &Item::DIRT);
+        *inventory.off_hand_item().await.lock().await = ItemStack::new(4, &Item::COBBLESTONE);
+        let ClientPlatform::Java(client) = &player.client else {
+            unreachable!()
+        };
+
+        client
+            .handle_use_item_on(
+                &player,
+                place_on_top(BlockPos::new(0, 63, 0), 0, 1),
+                &server,
+            )
+            .await
+            .unwrap();
+        // An off hand packet of the same click is ignored
+        client
+            .handle_use_item_on(
+                &player,
+                place_on_top(BlockPos::new(1, 63, 0), 1, 2),
+                &server,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            world.get_block(&BlockPos::new(0, 64, 0)).await.id,
+            Block::DIRT.id
+        );
+        assert_eq!(
+            world.get_block(&BlockPos::new(1, 64, 0)).await.id,
+            Block::AIR.id
+        );
+        assert_eq!(inventory.off_hand_item().await.lock().await.item_count, 4);
+    }
+
+    fn matching(blocks: BlockSet) -> AdventureModePredicate {
+        AdventureModePredicate {
+            predicates: vec![BlockPredicate {
+                blocks: Some(blocks),
+            }],
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn adventure_mode_places_only_on_can_place_on_blocks() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, _connection) = player_next_to_stone(&world).await;
+        player.set_gamemode(GameMode::Adventure).await;
+        let held_item = player.inventory().held_item();
+        *held_item.lock().await = ItemStack::new(4, &Item::DIRT);
+        let ClientPlatform::Java(client) = &player.client else {
+            unreachable!()
+        };
+
+        let result = client
+            .handle_use_item_on(
+                &player,
+                place_on_top(BlockPos::new(0, 63, 0), 0, 1),
+                &server,
+            )
+            .await;
+        assert!(matches!(result, Err(BlockPlacingError::InvalidGamemode)));
+        assert_eq!(
+            world.get_block(&BlockPos::new(0, 64, 0)).await.id,
+            Block::AIR.id
+        );
+
+        let can_place_on = CanPlaceOnImpl {
+            predicate: matching(BlockSet::Blocks(vec![&Block::STONE])),
+        };
+        *held_item.lock().await = ItemStack::new_with_component(
+            4,
+            &Item::DIRT,
+            vec![(DataComponent::CanPlaceOn, Some(can_place_on.to_dyn()))],
+        );
+        client
+            .handle_use_item_on(
+                &player,
+                place_on_top(BlockPos::new(0, 63, 0), 0, 2),
+                &server,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            world.get_block(&BlockPos::new(0, 64, 0)).await.id,
+            Block::DIRT.id
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn adventure_mode_breaks_only_can_break_blocks() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, _connection) = player_next_to_stone(&world).await;
+        player.set_gamemode(GameMode::Adventure).await;
+        let held_item = player.inventory().held_item();
+
+        *held_item.lock().await = ItemStack::new(1, &Item::IRON_PICKAXE);
+        assert!(player.is_block_break_restricted(&Block::STONE).await);
+
+        let can_break = CanBreakImpl {
+            predicate: matching(BlockSet::Tag("minecraft:mineable/pickaxe".to_string())),
+        };
+        *held_item.lock().await = ItemStack::new_with_component(
+            1,
+            &Item::IRON_PICKAXE,
+            vec![(DataComponent::CanBreak, Some(can_break.to_dyn()))],
+        );
+        assert!(!player.is_block_break_restricted(&Block::STONE).await);
+        assert!(player.is_block_break_restricted(&Block::DIRT).await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn adventure_mode_still_uses_blocks() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (player, _connection) = player_next_to_stone(&world).await;
+        player.set_gamemode(GameMode::Adventure).await;
+        let lever = BlockPos::new(1, 64, 0);
+        let mut props = LeverLikeProperties::default(&Block::LEVER);
+        props.face = BlockFace::Floor;
+        world
+            .set_block_state(
+                &lever,
+                props.to_state_id(&Block::LEVER),
+                BlockFlags::NOTIFY_ALL,
+            )
+            .await;
+        let ClientPlatform::Java(client) = &player.client else {
+            unreachable!()
+        };
+
+        let mut use_lever = place_on_top(lever, 0, 1);
+        use_lever.face = VarInt(2);
+        client
+            .handle_use_item_on(&player, use_lever, &server)
+            .await
+            .unwrap();
+
+        let state = world.get_block_state_id(&lever).await;
+        assert!(LeverLikeProperties::from_state_id(state, &Block::LEVER).powered);
+    }
+}
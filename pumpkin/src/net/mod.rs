@@ -12,7 +12,7 @@ use crate::{
 
 use pumpkin_data::translation;
 use pumpkin_protocol::{ClientPacket, Property};
-use pumpkin_util::{Hand, ProfileAction, text::TextComponent};
+use pumpkin_util::{Arm, ProfileAction, text::TextComponent};
 use serde::Deserialize;
 use sha1::Digest;
 use sha2::Sha256;
@@ -60,8 +60,8 @@ pub struct PlayerConfig {
     pub chat_colors: bool,
     /// The player's skin configuration options.
     pub skin_parts: u8,
-    /// The player's dominant hand (left or right).
-    pub main_hand: Hand,
+    /// The arm holding the player's main hand.
+    pub main_hand: Arm,
     /// Whether text filtering is enabled.
     pub text_filtering: bool,
     /// Whether the player wants to appear in the server list.
@@ -76,7 +76,7 @@ impl Default for PlayerConfig {
             chat_mode: ChatMode::Enabled,
             chat_colors: true,
             skin_parts: 0,
-            main_hand: Hand::Right,
+            main_hand: Arm::Right,
             text_filtering: false,
             server_listing: false,
         }
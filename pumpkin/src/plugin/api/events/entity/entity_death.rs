@@ -0,0 +1,40 @@
+use pumpkin_macros::Event;
+use pumpkin_world::item::ItemStack;
+use std::sync::Arc;
+
+use crate::entity::EntityBase;
+
+use super::EntityEvent;
+
+/// An event that occurs when a living entity dies and is about to drop its loot and equipment.
+///
+/// The death itself cannot be cancelled, but plugins can change what is dropped through `drops`.
+/// Players drop their inventory separately, see `PlayerDeathDropsEvent`.
+#[derive(Event, Clone)]
+pub struct EntityDeathEvent {
+    /// The entity that died.
+    pub entity: Arc<dyn EntityBase>,
+
+    /// The stacks dropped where the entity died.
+    pub drops: Vec<ItemStack>,
+}
+
+impl EntityDeathEvent {
+    /// Creates a new instance of `EntityDeathEvent`.
+    ///
+    /// # Arguments
+    /// - `entity`: The entity that died.
+    /// - `drops`: The stacks dropped where the entity died.
+    ///
+    /// # Returns
+    /// A new instance of `EntityDeathEvent`.
+    pub const fn new(entity: Arc<dyn EntityBase>, drops: Vec<ItemStack>) -> Self {
+        Self { entity, drops }
+    }
+}
+
+impl EntityEvent for EntityDeathEvent {
+    fn get_entity(&self) -> &Arc<dyn EntityBase> {
+        &self.entity
+    }
+}
@@ -0,0 +1,46 @@
+use pumpkin_macros::{Event, cancellable};
+use std::sync::Arc;
+
+use crate::entity::EntityBase;
+use crate::world::World;
+
+use super::EntityEvent;
+
+/// An event that occurs before an entity is added to a world.
+///
+/// If the event is cancelled, the entity is neither added to the world nor sent to any player.
+///
+/// This event contains information about the world and the entity being spawned.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct EntitySpawnEvent {
+    /// The world the entity is spawned in.
+    pub world: Arc<World>,
+
+    /// The entity being spawned.
+    pub entity: Arc<dyn EntityBase>,
+}
+
+impl EntitySpawnEvent {
+    /// Creates a new instance of `EntitySpawnEvent`.
+    ///
+    /// # Arguments
+    /// - `world`: The world the entity is spawned in.
+    /// - `entity`: The entity being spawned.
+    ///
+    /// # Returns
+    /// A new instance of `EntitySpawnEvent`.
+    pub const fn new(world: Arc<World>, entity: Arc<dyn EntityBase>) -> Self {
+        Self {
+            world,
+            entity,
+            cancelled: false,
+        }
+    }
+}
+
+impl EntityEvent for EntitySpawnEvent {
+    fn get_entity(&self) -> &Arc<dyn EntityBase> {
+        &self.entity
+    }
+}
@@ -0,0 +1,61 @@
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_util::math::vector3::Vector3;
+use std::sync::Arc;
+
+use crate::entity::{EntityBase, TeleportCause};
+
+use super::EntityEvent;
+
+/// An event that occurs before an entity, including a player, is teleported.
+///
+/// If the event is cancelled, the entity stays where it is and no packet is sent.
+///
+/// This event contains information about the entity, where it teleports from and to, and why.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct EntityTeleportEvent {
+    /// The entity being teleported.
+    pub entity: Arc<dyn EntityBase>,
+
+    /// The position from which the entity is teleported.
+    pub from: Vector3<f64>,
+
+    /// The position to which the entity is teleported.
+    pub to: Vector3<f64>,
+
+    /// What caused the teleport.
+    pub cause: TeleportCause,
+}
+
+impl EntityTeleportEvent {
+    /// Creates a new instance of `EntityTeleportEvent`.
+    ///
+    /// # Arguments
+    /// - `entity`: The entity being teleported.
+    /// - `from`: The position from which the entity is teleported.
+    /// - `to`: The position to which the entity is teleported.
+    /// - `cause`: What caused the teleport.
+    ///
+    /// # Returns
+    /// A new instance of `EntityTeleportEvent`.
+    pub const fn new(
+        entity: Arc<dyn EntityBase>,
+        from: Vector3<f64>,
+        to: Vector3<f64>,
+        cause: TeleportCause,
+    ) -> Self {
+        Self {
+            entity,
+            from,
+            to,
+            cause,
+            cancelled: false,
+        }
+    }
+}
+
+impl EntityEvent for EntityTeleportEvent {
+    fn get_entity(&self) -> &Arc<dyn EntityBase> {
+        &self.entity
+    }
+}
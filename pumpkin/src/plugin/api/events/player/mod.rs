@@ -2,7 +2,9 @@ pub mod player_change_world;
 pub mod player_chat;
 pub mod player_command_send;
 pub mod player_custom_payload;
+pub mod player_death_drops;
 pub mod player_gamemode_change;
+pub mod player_idle_kick;
 pub mod player_interact_entity_event;
 pub mod player_interact_event;
 pub mod player_interact_unknown_entity_event;
@@ -10,6 +12,7 @@ pub mod player_join;
 pub mod player_leave;
 pub mod player_login;
 pub mod player_move;
+pub mod player_swap_hand_items;
 pub mod player_teleport;
 
 use std::sync::Arc;
@@ -0,0 +1,52 @@
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_world::item::ItemStack;
+use std::sync::Arc;
+
+use crate::entity::player::Player;
+
+use super::PlayerEvent;
+
+/// An event that occurs when a player dies without `keepInventory` and is about to drop their
+/// inventory and experience.
+///
+/// If the event is cancelled, the player keeps their inventory and experience.
+///
+/// This event contains information about the player and what they are about to drop.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct PlayerDeathDropsEvent {
+    /// The player who died.
+    pub player: Arc<Player>,
+
+    /// The stacks dropped where the player died. Items with Curse of Vanishing are not included.
+    pub drops: Vec<ItemStack>,
+
+    /// The experience points dropped as orbs, the rest of the player's experience is lost.
+    pub experience: u32,
+}
+
+impl PlayerDeathDropsEvent {
+    /// Creates a new instance of `PlayerDeathDropsEvent`.
+    ///
+    /// # Arguments
+    /// - `player`: A reference to the player who died.
+    /// - `drops`: The stacks dropped where the player died.
+    /// - `experience`: The experience points dropped as orbs.
+    ///
+    /// # Returns
+    /// A new instance of `PlayerDeathDropsEvent`.
+    pub const fn new(player: Arc<Player>, drops: Vec<ItemStack>, experience: u32) -> Self {
+        Self {
+            player,
+            drops,
+            experience,
+            cancelled: false,
+        }
+    }
+}
+
+impl PlayerEvent for PlayerDeathDropsEvent {
+    fn get_player(&self) -> &Arc<Player> {
+        &self.player
+    }
+}
@@ -0,0 +1,56 @@
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_util::text::TextComponent;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::entity::player::Player;
+
+use super::PlayerEvent;
+
+/// An event that occurs when a player is about to be kicked for being idle.
+///
+/// If the event is cancelled, the player will not be kicked and their idle timer is reset.
+///
+/// This event contains information about the player, how long they have been idle and the kick message.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct PlayerIdleKickEvent {
+    /// The player who is idle.
+    pub player: Arc<Player>,
+
+    /// How long the player has been idle.
+    pub idle_duration: Duration,
+
+    /// The message shown to the player when they are kicked.
+    pub kick_message: TextComponent,
+}
+
+impl PlayerIdleKickEvent {
+    /// Creates a new instance of `PlayerIdleKickEvent`.
+    ///
+    /// # Arguments
+    /// - `player`: A reference to the idle player.
+    /// - `idle_duration`: How long the player has been idle.
+    /// - `kick_message`: The message shown to the player when they are kicked.
+    ///
+    /// # Returns
+    /// A new instance of `PlayerIdleKickEvent`.
+    pub const fn new(
+        player: Arc<Player>,
+        idle_duration: Duration,
+        kick_message: TextComponent,
+    ) -> Self {
+        Self {
+            player,
+            idle_duration,
+            kick_message,
+            cancelled: false,
+        }
+    }
+}
+
+impl PlayerEvent for PlayerIdleKickEvent {
+    fn get_player(&self) -> &Arc<Player> {
+        &self.player
+    }
+}
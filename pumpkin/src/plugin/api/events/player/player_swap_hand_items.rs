@@ -0,0 +1,53 @@
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_world::item::ItemStack;
+use std::sync::Arc;
+
+use crate::entity::player::Player;
+
+use super::PlayerEvent;
+
+/// An event that occurs when a player swaps the items in their main hand and off hand.
+///
+/// Plugins can change the items that end up in each hand, or cancel the swap.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct PlayerSwapHandItemsEvent {
+    /// The player swapping their hand items.
+    pub player: Arc<Player>,
+
+    /// The item that will be in the main hand after the swap.
+    pub main_hand_item: ItemStack,
+
+    /// The item that will be in the off hand after the swap.
+    pub off_hand_item: ItemStack,
+}
+
+impl PlayerSwapHandItemsEvent {
+    /// Creates a new instance of `PlayerSwapHandItemsEvent`.
+    ///
+    /// # Arguments
+    /// - `player`: A reference to the player swapping their hand items.
+    /// - `main_hand_item`: The item that will be in the main hand after the swap.
+    /// - `off_hand_item`: The item that will be in the off hand after the swap.
+    ///
+    /// # Returns
+    /// A new instance of `PlayerSwapHandItemsEvent`.
+    pub const fn new(
+        player: Arc<Player>,
+        main_hand_item: ItemStack,
+        off_hand_item: ItemStack,
+    ) -> Self {
+        Self {
+            player,
+            main_hand_item,
+            off_hand_item,
+            cancelled: false,
+        }
+    }
+}
+
+impl PlayerEvent for PlayerSwapHandItemsEvent {
+    fn get_player(&self) -> &Arc<Player> {
+        &self.player
+    }
+}
@@ -1,18 +1,35 @@
 use crate::world::World;
 use pumpkin_macros::{Event, cancellable};
+use pumpkin_util::math::vector2::Vector2;
 use pumpkin_world::chunk::ChunkData;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 
 /// An event that occurs when a chunk is loaded in a world.
 ///
-/// This event contains information about the world and the chunk being loaded.
+/// If the event is cancelled, the chunk is not added to the world: it is not sent to players and
+/// its entities are not spawned until it unloads and is loaded again.
 #[cancellable]
 #[derive(Event, Clone)]
 pub struct ChunkLoad {
     /// The world in which the chunk is being loaded.
     pub world: Arc<World>,
 
-    /// The chunk data being loaded, wrapped in a read-write lock for safe concurrent access.
-    pub chunk: Arc<RwLock<ChunkData>>,
+    /// The position of the chunk.
+    pub position: Vector2<i32>,
+
+    /// The chunk data being loaded.
+    pub chunk: Arc<ChunkData>,
+}
+
+impl ChunkLoad {
+    /// Creates a new instance of `ChunkLoad`.
+    #[must_use]
+    pub const fn new(world: Arc<World>, position: Vector2<i32>, chunk: Arc<ChunkData>) -> Self {
+        Self {
+            world,
+            position,
+            chunk,
+            cancelled: false,
+        }
+    }
 }
@@ -0,0 +1,24 @@
+use crate::world::World;
+use pumpkin_macros::Event;
+use pumpkin_util::math::vector2::Vector2;
+use std::sync::Arc;
+
+/// An event that occurs after a chunk has been unloaded from a world.
+///
+/// The chunk is no longer available to the world, so this event cannot be cancelled.
+#[derive(Event, Clone)]
+pub struct ChunkUnload {
+    /// The world from which the chunk was unloaded.
+    pub world: Arc<World>,
+
+    /// The position of the chunk.
+    pub position: Vector2<i32>,
+}
+
+impl ChunkUnload {
+    /// Creates a new instance of `ChunkUnload`.
+    #[must_use]
+    pub const fn new(world: Arc<World>, position: Vector2<i32>) -> Self {
+        Self { world, position }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::world::World;
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_util::Difficulty;
+use std::sync::Arc;
+
+/// An event that occurs before the difficulty of a world changes.
+///
+/// Plugins can cancel the change or pick a different difficulty through `new`.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct DifficultyChangeEvent {
+    /// The world whose difficulty is changing.
+    pub world: Arc<World>,
+
+    /// The difficulty before the change.
+    pub old: Difficulty,
+
+    /// The difficulty after the change.
+    pub new: Difficulty,
+}
+
+impl DifficultyChangeEvent {
+    /// Creates a new instance of `DifficultyChangeEvent`.
+    #[must_use]
+    pub const fn new(world: Arc<World>, old: Difficulty, new: Difficulty) -> Self {
+        Self {
+            world,
+            old,
+            new,
+            cancelled: false,
+        }
+    }
+}
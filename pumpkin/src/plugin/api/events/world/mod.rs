@@ -1,3 +1,6 @@
 pub mod chunk_load;
 pub mod chunk_save;
 pub mod chunk_send;
+pub mod chunk_unload;
+pub mod difficulty_change;
+pub mod weather_change;
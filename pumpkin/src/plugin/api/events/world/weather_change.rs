@@ -0,0 +1,32 @@
+use crate::world::World;
+use pumpkin_macros::{Event, cancellable};
+use std::sync::Arc;
+
+/// An event that occurs before rain or thunder starts or stops in a world.
+///
+/// Plugins can cancel the change or pick a different weather through `raining` and `thundering`.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct WeatherChangeEvent {
+    /// The world whose weather is changing.
+    pub world: Arc<World>,
+
+    /// Whether it will be raining after the change.
+    pub raining: bool,
+
+    /// Whether it will be thundering after the change.
+    pub thundering: bool,
+}
+
+impl WeatherChangeEvent {
+    /// Creates a new instance of `WeatherChangeEvent`.
+    #[must_use]
+    pub const fn new(world: Arc<World>, raining: bool, thundering: bool) -> Self {
+        Self {
+            world,
+            raining,
+            thundering,
+            cancelled: false,
+        }
+    }
+}
@@ -620,28 +620,117 @@ impl PluginManager {
             .push(Box::new(typed_handler));
     }
 
+    /// Whether any handler is registered for events of type `E`.
+    ///
+    /// Callers can use this to skip building events that are expensive to construct,
+    /// e.g. ones carrying a list of drops.
+    pub async fn has_listeners<E: Payload + Send + Sync + 'static>(&self) -> bool {
+        self.handlers
+            .read()
+            .await
+            .get(&E::get_name_static())
+            .is_some_and(|handlers| !handlers.is_empty())
+    }
+
     /// Fire an event to all registered handlers
+    ///
+    /// Events are fired on the task performing the action, which is the world tick for world
+    /// and entity events and the player's packet handler for player actions. They are fired
+    /// before the action is applied, so a cancelled event leaves no partially applied state
+    /// behind. Blocking handlers run first and may modify or cancel the event, non-blocking
+    /// handlers then observe the final event concurrently.
+    ///
+    /// Handlers may be async, but must not wait on world locks (e.g. the chunk or entity locks),
+    /// as the code firing the event might already hold them.
     pub async fn fire<E: Payload + Send + Sync + 'static>(&self, mut event: E) -> E {
-        if let Some(server) = self.server.read().await.as_ref() {
-            let handlers = self.handlers.read().await;
-            if let Some(handlers) = handlers.get(&E::get_name_static()) {
-                let (blocking, non_blocking): (Vec<_>, Vec<_>) =
-                    handlers.iter().partition(|h| h.is_blocking());
-
-                // Process blocking handlers first
-                for handler in blocking {
-                    handler.handle_blocking_dyn(server, &mut event).await;
-                }
+        let handlers = self.handlers.read().await;
+        // Nothing to allocate or await when no one is listening
+        let Some(handlers) = handlers
+            .get(&E::get_name_static())
+            .filter(|handlers| !handlers.is_empty())
+        else {
+            return event;
+        };
+        let server = self.server.read().await;
+        let Some(server) = server.as_ref() else {
+            return event;
+        };
 
-                // Process non-blocking handlers
-                join_all(
-                    non_blocking
-                        .into_iter()
-                        .map(|h| h.handle_dyn(server, &event)),
-                )
-                .await;
-            }
+        // Process blocking handlers first
+        for handler in handlers.iter().filter(|handler| handler.is_blocking()) {
+            handler.handle_blocking_dyn(server, &mut event).await;
         }
+
+        // Process non-blocking handlers
+        join_all(
+            handlers
+                .iter()
+                .filter(|handler| !handler.is_blocking())
+                .map(|handler| handler.handle_dyn(server, &event)),
+        )
+        .await;
+
         event
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::{BoxFuture, Cancellable, EventHandler, EventPriority, Payload};
+    use crate::server::Server;
+
+    /// Cancels every event it handles.
+    pub(crate) struct CancelEvent;
+
+    impl<E: Payload + Cancellable> EventHandler<E> for CancelEvent {
+        fn handle_blocking<'a>(
+            &'a self,
+            _server: &'a Arc<Server>,
+            event: &'a mut E,
+        ) -> BoxFuture<'a, ()> {
+            Box::pin(async move { event.set_cancelled(true) })
+        }
+    }
+
+    /// Cancels every `E` fired on `server`.
+    pub(crate) async fn cancel_all<E: Payload + Cancellable + 'static>(server: &Server) {
+        server
+            .plugin_manager
+            .register::<E, _>(Arc::new(CancelEvent), EventPriority::Normal, true)
+            .await;
+    }
+
+    /// Counts the events it handles.
+    #[derive(Default)]
+    pub(crate) struct CountEvents(AtomicUsize);
+
+    impl CountEvents {
+        pub(crate) fn count(&self) -> usize {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+
+    impl<E: Payload> EventHandler<E> for CountEvents {
+        fn handle_blocking<'a>(
+            &'a self,
+            _server: &'a Arc<Server>,
+            _event: &'a mut E,
+        ) -> BoxFuture<'a, ()> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Box::pin(async {})
+        }
+    }
+
+    /// Counts every `E` fired on `server`.
+    pub(crate) async fn count_all<E: Payload + 'static>(server: &Server) -> Arc<CountEvents> {
+        let counter = Arc::new(CountEvents::default());
+        server
+            .plugin_manager
+            .register::<E, _>(counter.clone(), EventPriority::Normal, true)
+            .await;
+        counter
+    }
+}
@@ -97,7 +97,7 @@ impl CachedStatus {
             .take(MAX_SAMPLE_PLAYERS)
             .map(|(id, name)| Sample {
                 name: name.clone(),
-                id: id.to_string(),
+                id: *id,
             })
             .collect()
     }
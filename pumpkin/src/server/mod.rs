@@ -1,4 +1,5 @@
 use crate::block::registry::BlockRegistry;
+use crate::command::chat_filter::ChatFilter;
 use crate::command::commands::default_dispatcher;
 use crate::command::commands::defaultgamemode::DefaultGamemode;
 use crate::data::VanillaData;
@@ -12,6 +13,8 @@ use crate::plugin::player::player_login::PlayerLoginEvent;
 use crate::plugin::server::server_broadcast::ServerBroadcastEvent;
 use crate::server::tick_rate_manager::ServerTickRateManager;
 use crate::world::custom_bossbar::CustomBossbars;
+use crate::world::map_data::MapStorage;
+use crate::world::scheduled_callbacks::ScheduledCallbacks;
 use crate::{command::dispatcher::CommandDispatcher, entity::player::Player, world::World};
 use arc_swap::ArcSwap;
 use connection_cache::{CachedBranding, CachedStatus};
@@ -26,7 +29,6 @@ use tracing::{debug, error, info, warn};
 use crate::command::CommandSender;
 use pumpkin_macros::send_cancellable;
 use pumpkin_protocol::java::client::login::CEncryptionRequest;
-use pumpkin_protocol::java::client::play::CChangeDifficulty;
 use pumpkin_protocol::{ClientPacket, java::client::config::CPluginMessage};
 use pumpkin_util::Difficulty;
 use pumpkin_util::math::vector3::Vector3;
@@ -65,6 +67,8 @@ pub struct Server {
     pub advanced_config: AdvancedConfiguration,
 
     pub data: VanillaData,
+    /// Filters chat messages and keeps track of muted players
+    pub chat_filter: ChatFilter,
 
     /// Plugin manager
     pub plugin_manager: Arc<PluginManager>,
@@ -97,6 +101,10 @@ pub struct Server {
     pub mojang_public_keys: ArcSwap<Vec<RsaPublicKey>>,
     /// The server's custom bossbars
     pub bossbars: Mutex<CustomBossbars>,
+    /// Filled maps, shared by all worlds like in vanilla.
+    pub maps: MapStorage,
+    /// Callbacks scheduled with `/schedule`, saved in `level.dat`. Plugins bind handlers here.
+    pub scheduled_callbacks: Arc<ScheduledCallbacks>,
     /// The default gamemode when a player joins the server (reset every restart)
     pub defaultgamemode: Mutex<DefaultGamemode>,
     /// Manages player data storage
@@ -147,7 +155,8 @@ impl Server {
                 // If it doesn't exist, just make a new one
                 WorldInfoError::InfoNotFound => (),
                 WorldInfoError::UnsupportedDataVersion(_version)
-                | WorldInfoError::UnsupportedLevelVersion(_version) => {
+                | WorldInfoError::UnsupportedLevelVersion(_version)
+                | WorldInfoError::UnsupportedPumpkinDataVersion(_version) => {
                     error!("Failed to load world info!");
                     error!("{error}");
                     panic!("Unsupported world version! See the logs for more info.");
@@ -173,12 +182,16 @@ impl Server {
             }
         };
 
+        let new_world = matches!(level_info, Err(WorldInfoError::InfoNotFound));
         let level_info = level_info.unwrap_or_else(|err| {
             warn!("Failed to get level_info, using default instead: {err}");
             LevelData::default(basic_config.seed)
         });
 
         let seed = level_info.world_gen_settings.seed;
+        let scheduled_callbacks = Arc::new(ScheduledCallbacks::from_events(
+            &level_info.scheduled_events,
+        ));
         let level_info = Arc::new(ArcSwap::new(Arc::new(level_info)));
 
         let listing = Mutex::new(CachedStatus::new(&basic_config));
@@ -209,10 +222,14 @@ impl Server {
             }
         });
 
+        let player_idle_timeout = basic_config.player_idle_timeout;
+        let chat_filter = ChatFilter::new(&advanced_config.chat);
+
         let server = Self {
             basic_config,
             advanced_config,
             data: vanilla_data,
+            chat_filter,
             plugin_manager: Arc::new(PluginManager::new()),
             permission_manager: Arc::new(RwLock::new(PermissionManager::new(
                 permission_registry.clone(),
@@ -232,6 +249,8 @@ impl Server {
             listing,
             branding: CachedBranding::new(),
             bossbars: Mutex::new(CustomBossbars::new()),
+            maps: MapStorage::new(world_path.join("data")),
+            scheduled_callbacks,
             defaultgamemode,
             player_data_storage,
             white_list,
@@ -241,7 +260,7 @@ impl Server {
             tick_count: AtomicI32::new(0),
             tasks: TaskTracker::new(),
             server_guid: rand::random(),
-            player_idle_timeout: AtomicI32::new(0),
+            player_idle_timeout: AtomicI32::new(player_idle_timeout),
             mojang_public_keys: ArcSwap::from_pointee(Vec::new()),
             world_info_writer: Arc::new(AnvilLevelInfo),
             level_info,
@@ -261,6 +280,7 @@ impl Server {
             let path = world_path.clone();
             let registry = block_registry.clone();
             let l_info = server.level_info.clone(); // Access from struct
+            let scheduled_callbacks = server.scheduled_callbacks.clone();
             let weak = Arc::downgrade(&server);
             let config = Arc::new(server.advanced_config.world.clone());
 
@@ -274,6 +294,7 @@ impl Server {
                 World::load(
                     into_level(dim, &config, path, registry.clone(), seed),
                     l_info,
+                    scheduled_callbacks,
                     dim,
                     registry,
                     weak,
@@ -294,6 +315,14 @@ impl Server {
             Arc::new(nether.expect("Nether panicked")),
             Arc::new(end.expect("End panicked")),
         ];
+        if server.advanced_config.world.force_upgrade {
+            for world in &worlds_vec {
+                world.level.force_upgrade().await;
+            }
+        }
+        if new_world {
+            worlds_vec[0].find_initial_spawn().await;
+        }
         server.worlds.store(Arc::new(worlds_vec));
         if let Ok(k) = keys {
             server.mojang_public_keys.store(Arc::new(k));
@@ -360,7 +389,20 @@ impl Server {
     ) -> Option<(Arc<Player>, Arc<World>)> {
         let gamemode = self.defaultgamemode.lock().await.gamemode;
 
-        let (world, nbt) = if let Ok(Some(data)) = self.player_data_storage.load_data(&profile.id) {
+        let data = match self.player_data_storage.load_data(&profile.id) {
+            Ok(data) => data,
+            Err(error) => {
+                // The player's data can't be read, so don't let them play and overwrite it
+                client
+                    .kick(
+                        DisconnectReason::Kicked,
+                        TextComponent::text(format!("Failed to load your player data: {error}")),
+                    )
+                    .await;
+                return None;
+            }
+        };
+        let (world, nbt) = if let Some(data) = data {
             if let Some(dimension_key) = data.get_string("Dimension") {
                 if let Some(dimension) = Dimension::from_name(dimension_key) {
                     let world = self.get_world_from_dimension(dimension);
@@ -457,16 +499,50 @@ impl Server {
         for world in self.worlds.load().iter() {
             world.shutdown().await;
         }
-        let level_data = self.level_info.load();
+        if let Some(world) = self.worlds.load().first() {
+            let time = world.level_time.lock().await.world_age;
+            self.set_level_time(time);
+        }
         // then lets save the world info
+        self.save_level_info();
+        info!("Completed worlds");
+    }
 
+    fn set_level_time(&self, time: i64) {
+        self.level_info.rcu(|current_info| {
+            let mut new_info = (**current_info).clone();
+            new_info.time = time;
+            new_info
+        });
+    }
+
+    /// Saves the level data with the game `time` of the overworld, like
+    /// [`Server::save_level_info`] but without blocking the calling task.
+    pub fn autosave_level_info(self: &Arc<Self>, time: i64) -> JoinHandle<()> {
+        self.set_level_time(time);
+        let server = self.clone();
+        self.spawn_task(async move {
+            if let Err(err) = tokio::task::spawn_blocking(move || server.save_level_info()).await {
+                error!("Failed to save level.dat: {err}");
+            }
+        })
+    }
+
+    /// Writes the current level data to `level.dat`, logging any failure.
+    pub fn save_level_info(&self) {
+        let scheduled_events = self.scheduled_callbacks.to_events();
+        self.level_info.rcu(|current_info| {
+            let mut new_info = (**current_info).clone();
+            new_info.scheduled_events.clone_from(&scheduled_events);
+            new_info
+        });
+        let level_data = self.level_info.load();
         if let Err(err) = self
             .world_info_writer
             .write_world_info(&level_data, &self.basic_config.get_world_path())
         {
             error!("Failed to save level.dat: {err}");
         }
-        info!("Completed worlds");
     }
 
     /// Broadcasts a packet to all players in all worlds.
@@ -503,6 +579,11 @@ impl Server {
         }}
     }
 
+    /// Sends a system message to every player in every world, e.g. to warn about a restart.
+    pub async fn broadcast_system_message(&self, message: &TextComponent) {
+        World::send_system_message(&self.get_all_players(), message).await;
+    }
+
     /// Gets the current difficulty of the server.
     pub fn get_difficulty(&self) -> Difficulty {
         self.level_info.load().difficulty
@@ -510,43 +591,14 @@ impl Server {
 
     /// Sets the difficulty of the server.
     ///
-    /// This function updates the difficulty level of the server and broadcasts the change to all players.
-    /// It also iterates through all worlds to ensure the difficulty is applied consistently.
-    /// If `force_update` is `Some(true)`, the difficulty will be set regardless of the current state.
-    /// If `force_update` is `Some(false)` or `None`, the difficulty will only be updated if it is not locked.
-    ///
-    /// # Arguments
-    ///
-    /// * `difficulty`: The new difficulty level to set. This should be one of the variants of the `Difficulty` enum.
-    /// * `force_update`: An optional boolean that, if set to `Some(true)`, forces the difficulty to be updated even if it is currently locked.
-    ///
-    /// # Note
-    ///
-    /// This function does not handle the actual mob spawn options update, which is a TODO item for future implementation.
-    pub async fn set_difficulty(&self, difficulty: Difficulty, force_update: bool) {
-        let current_info = self.level_info.load();
-        if current_info.difficulty_locked && !force_update {
-            return;
-        }
-
-        let new_difficulty = if self.basic_config.hardcore {
-            Difficulty::Hard
-        } else {
-            difficulty
+    /// All worlds share the same level data, so this goes through [`World::set_difficulty`] of
+    /// the first world. If `force_update` is set, the difficulty is changed even if it is locked.
+    /// Returns whether the difficulty was changed.
+    pub async fn set_difficulty(&self, difficulty: Difficulty, force_update: bool) -> bool {
+        let Some(world) = self.worlds.load().first().cloned() else {
+            return false;
         };
-
-        let mut new_info = (**current_info).clone();
-
-        new_info.difficulty = new_difficulty;
-        let locked = new_info.difficulty_locked;
-        self.level_info.store(Arc::new(new_info));
-
-        for world in self.worlds.load().iter() {
-            world.set_difficulty(difficulty);
-        }
-
-        self.broadcast_packet_all(&CChangeDifficulty::new(difficulty as u8, locked))
-            .await;
+        world.set_difficulty(difficulty, force_update).await
     }
 
     /// Searches for a player by their username across all worlds.
@@ -718,6 +770,7 @@ impl Server {
         // First, flush pending block updates and synced block events to clients
         for world in self.worlds.load().iter() {
             world.flush_block_updates().await;
+            world.flush_light_updates().await;
             world.flush_synced_block_events().await;
         }
 
@@ -792,6 +845,43 @@ impl Server {
         *self.tick_times_nanos.lock().await
     }
 
+    /// Checks the filters which are not handled by `select_entities` itself. Distances are
+    /// measured from `center`.
+    fn matches_entity_filter(
+        entity: &dyn EntityBase,
+        filter: &EntityFilter,
+        center: Vector3<f64>,
+    ) -> bool {
+        match filter {
+            EntityFilter::Distance(condition) => condition.matches(
+                &entity
+                    .get_entity()
+                    .pos
+                    .load()
+                    .squared_distance_to_vec(&center)
+                    .sqrt(),
+            ),
+            EntityFilter::Name(condition) => {
+                let name = entity.get_name().get_text();
+                match condition {
+                    ValueCondition::Equals(expected) => name == *expected,
+                    ValueCondition::NotEquals(expected) => name != *expected,
+                }
+            }
+            // Only players have a game mode
+            EntityFilter::Gamemode(condition) => {
+                let gamemode = entity.get_player().map(|player| player.gamemode.load());
+                match condition {
+                    ValueCondition::Equals(expected) => gamemode == Some(*expected),
+                    ValueCondition::NotEquals(expected) => {
+                        gamemode.is_some_and(|gamemode| gamemode != *expected)
+                    }
+                }
+            }
+            _ => true,
+        }
+    }
+
     #[expect(clippy::too_many_lines)]
     #[expect(clippy::option_if_let_else)]
     pub fn select_entities(
@@ -884,7 +974,14 @@ impl Server {
             (type_excluded.is_empty() || !type_excluded.contains(&e.get_entity().entity_type))
                 && (type_included.is_empty() || type_included.contains(&e.get_entity().entity_type))
         });
-        let iter = type_filtered;
+        // todo: command context
+        let center = source.and_then(CommandSender::position).unwrap_or_default();
+        let iter = type_filtered.filter(|e| {
+            target_selector
+                .conditions
+                .iter()
+                .all(|condition| Self::matches_entity_filter(e.as_ref(), condition, center))
+        });
         match target_selector
             .get_sort()
             .unwrap_or(EntityFilterSort::Arbitrary)
@@ -915,12 +1012,6 @@ impl Server {
                     return vec![];
                 }
                 // sort entities first
-                // todo: command context
-                let center = if let Some(source) = source {
-                    source.position().unwrap_or_default()
-                } else {
-                    Vector3::default()
-                };
                 let mut entities = iter.collect::<Vec<_>>();
                 entities.sort_by(|a, b| {
                     let a_distance = a.get_entity().pos.load().squared_distance_to_vec(&center);
@@ -943,3 +1034,135 @@ impl Server {
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, AtomicI32, AtomicI64};
+    use std::time::Duration;
+
+    use arc_swap::ArcSwap;
+    use pumpkin_config::{AdvancedConfiguration, BasicConfiguration};
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_util::permission::{PermissionManager, PermissionRegistry};
+    use pumpkin_world::world_info::LevelData;
+    use pumpkin_world::world_info::anvil::AnvilLevelInfo;
+    use tempfile::{TempDir, tempdir};
+    use tokio::sync::{Mutex, OnceCell, RwLock};
+    use tokio_util::task::TaskTracker;
+
+    use super::Server;
+    use super::connection_cache::{CachedBranding, CachedStatus};
+    use super::tick_rate_manager::ServerTickRateManager;
+    use crate::command::chat_filter::ChatFilter;
+    use crate::command::commands::default_dispatcher;
+    use crate::command::commands::defaultgamemode::DefaultGamemode;
+    use crate::data::VanillaData;
+    use crate::data::mute::MuteList;
+    use crate::data::player_server::ServerPlayerData;
+    use crate::entity::player::test::{test_bedrock_player, test_player};
+    use crate::plugin::PluginManager;
+    use crate::world::custom_bossbar::CustomBossbars;
+    use crate::world::map_data::MapStorage;
+    use crate::world::scheduled_callbacks::ScheduledCallbacks;
+    use crate::world::test::test_world_on;
+    use pumpkin_protocol::bedrock::server::text::SText;
+    use pumpkin_protocol::java::client::play::CSystemChatMessage;
+    use pumpkin_protocol::packet::Packet;
+    use pumpkin_util::text::TextComponent;
+
+    /// A server without worlds or network that keeps its files in `dir`. Plugin events fired
+    /// through it reach the handlers registered on its plugin manager.
+    pub(crate) async fn test_server(dir: &TempDir) -> Arc<Server> {
+        test_server_with(dir, AdvancedConfiguration::default()).await
+    }
+
+    /// Like [`test_server`], but with the given advanced configuration.
+    pub(crate) async fn test_server_with(
+        dir: &TempDir,
+        advanced_config: AdvancedConfiguration,
+    ) -> Arc<Server> {
+        let basic_config = BasicConfiguration {
+            default_level_name: dir.path().to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let permission_registry = Arc::new(RwLock::new(PermissionRegistry::new()));
+        let command_dispatcher =
+            RwLock::new(default_dispatcher(&permission_registry, &basic_config).await);
+        let level_info = LevelData::default(basic_config.seed);
+        let server = Arc::new(Server {
+            data: VanillaData {
+                banned_ip_list: RwLock::default(),
+                banned_player_list: RwLock::default(),
+                operator_config: RwLock::default(),
+                whitelist_config: RwLock::default(),
+            },
+            chat_filter: ChatFilter::with_mute_list(&advanced_config.chat, MuteList::default()),
+            plugin_manager: Arc::new(PluginManager::new()),
+            permission_manager: Arc::new(RwLock::new(PermissionManager::new(
+                permission_registry.clone(),
+            ))),
+            permission_registry,
+            key_store: OnceCell::new(),
+            listing: Mutex::new(CachedStatus::new(&basic_config)),
+            branding: CachedBranding::new(),
+            command_dispatcher,
+            block_registry: crate::block::registry::default_registry(),
+            item_registry: crate::item::items::default_registry(),
+            worlds: ArcSwap::from_pointee(vec![]),
+            dimensions: vec![Dimension::OVERWORLD, Dimension::THE_NETHER],
+            container_id: 0.into(),
+            mojang_public_keys: ArcSwap::from_pointee(Vec::new()),
+            bossbars: Mutex::new(CustomBossbars::new()),
+            maps: MapStorage::new(dir.path().join("data")),
+            scheduled_callbacks: Arc::new(ScheduledCallbacks::default()),
+            defaultgamemode: Mutex::new(DefaultGamemode {
+                gamemode: basic_config.default_gamemode,
+            }),
+            player_data_storage: ServerPlayerData::new(
+                dir.path().join("playerdata"),
+                Duration::from_secs(60),
+                false,
+            ),
+            white_list: AtomicBool::new(false),
+            tick_rate_manager: Arc::new(ServerTickRateManager::new(basic_config.tps)),
+            tick_times_nanos: Mutex::new([0; 100]),
+            aggregated_tick_times_nanos: AtomicI64::new(0),
+            tick_count: AtomicI32::new(0),
+            server_guid: 0,
+            player_idle_timeout: AtomicI32::new(0),
+            tasks: TaskTracker::new(),
+            level_info: Arc::new(ArcSwap::from_pointee(level_info)),
+            world_info_writer: Arc::new(AnvilLevelInfo),
+            _locker: Arc::new(None),
+            basic_config,
+            advanced_config,
+        });
+        server.plugin_manager.set_server(server.clone()).await;
+        server
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn system_message_reaches_every_world_and_edition() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let overworld = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let nether = test_world_on(&server, &dir, Dimension::THE_NETHER);
+        let (_steve, mut java) = test_player(&overworld, "Steve").await;
+        let (_alex, mut bedrock) = test_bedrock_player(&nether, "Alex").await;
+        java.received_packet_ids().await;
+        bedrock.received_packet_ids().await;
+
+        server
+            .broadcast_system_message(&TextComponent::text("Restarting soon"))
+            .await;
+
+        assert!(java.received::<CSystemChatMessage>().await);
+        assert!(
+            bedrock
+                .received_packet_ids()
+                .await
+                .contains(&SText::PACKET_ID)
+        );
+    }
+}
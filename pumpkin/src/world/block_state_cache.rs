@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_world::BlockStateId;
+
+/// Upper bound of cached positions, further reads go straight to the chunk until the next tick.
+const MAX_ENTRIES: usize = 4096;
+
+/// A small cache of block states read during the current tick.
+///
+/// Block ticks and neighbor updates tend to read the same positions several times, each read
+/// locking the chunk. The cache is cleared at the start of every tick and entries are removed
+/// whenever the block at their position is set.
+#[derive(Default)]
+pub struct BlockStateCache {
+    inner: Mutex<CacheInner>,
+}
+
+#[derive(Default)]
+struct CacheInner {
+    states: HashMap<BlockPos, BlockStateId>,
+    /// Incremented on every invalidation, so a read that raced with a write isn't cached.
+    generation: u64,
+}
+
+impl BlockStateCache {
+    /// Returns the cached state at `position`, or the generation to pass to [`Self::insert`]
+    /// after reading the state from the chunk.
+    pub fn get(&self, position: &BlockPos) -> Result<BlockStateId, u64> {
+        let inner = self.inner.lock().unwrap();
+        inner.states.get(position).copied().ok_or(inner.generation)
+    }
+
+    /// Returns the cached state at `position`, or awaits `read` for the state in the chunk and
+    /// caches it.
+    pub async fn get_or_read<F>(&self, position: &BlockPos, read: F) -> BlockStateId
+    where
+        F: Future<Output = BlockStateId>,
+    {
+        match self.get(position) {
+            Ok(state_id) => state_id,
+            Err(generation) => {
+                let state_id = read.await;
+                self.insert(*position, state_id, generation);
+                state_id
+            }
+        }
+    }
+
+    /// Caches a state read from the chunk, unless a block was set since the read started.
+    pub fn insert(&self, position: BlockPos, state_id: BlockStateId, generation: u64) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.generation == generation && inner.states.len() < MAX_ENTRIES {
+            inner.states.insert(position, state_id);
+        }
+    }
+
+    pub fn invalidate(&self, position: &BlockPos) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.states.remove(position);
+        inner.generation = inner.generation.wrapping_add(1);
+    }
+
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.states.clear();
+        inner.generation = inner.generation.wrapping_add(1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_util::math::position::BlockPos;
+
+    use super::BlockStateCache;
+
+    #[test]
+    fn invalidate_removes_entry() {
+        let cache = BlockStateCache::default();
+        let pos = BlockPos::new(1, 64, 1);
+        let generation = cache.get(&pos).unwrap_err();
+        cache.insert(pos, 10, generation);
+        assert_eq!(cache.get(&pos), Ok(10));
+
+        cache.invalidate(&pos);
+        assert!(cache.get(&pos).is_err());
+    }
+
+    #[test]
+    fn read_racing_a_write_is_not_cached() {
+        let cache = BlockStateCache::default();
+        let pos = BlockPos::new(0, 0, 0);
+        let generation = cache.get(&pos).unwrap_err();
+        // The block is set while the old state is being read from the chunk
+        cache.invalidate(&pos);
+        cache.insert(pos, 10, generation);
+        assert!(cache.get(&pos).is_err());
+    }
+}
@@ -1,6 +1,8 @@
 use crate::entity::player::Player;
 use pumpkin_protocol::java::client::play::{BosseventAction, CBossEvent};
+use pumpkin_util::math::vector3::Vector3;
 use pumpkin_util::text::TextComponent;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 #[derive(Clone, PartialEq, Eq)]
@@ -39,6 +41,8 @@ pub struct Bossbar {
     pub color: BossbarColor,
     pub division: BossbarDivisions,
     pub flags: BossbarFlags,
+    /// Whether each player currently sees the bar, for bars only shown to nearby players.
+    pub subscriptions: HashMap<Uuid, bool>,
 }
 
 impl Bossbar {
@@ -53,8 +57,29 @@ impl Bossbar {
             color: BossbarColor::White,
             division: BossbarDivisions::NoDivision,
             flags: BossbarFlags::NoFlags,
+            subscriptions: HashMap::new(),
         }
     }
+
+    /// Whether the player with the given UUID currently sees this bar.
+    #[must_use]
+    pub fn is_subscribed(&self, uuid: &Uuid) -> bool {
+        self.subscriptions.get(uuid).copied().unwrap_or(false)
+    }
+}
+
+/// A bossbar that is only shown to players within `range` blocks of `center`.
+pub struct RangedBossbar {
+    pub bossbar: Bossbar,
+    pub center: Vector3<f64>,
+    pub range: f64,
+}
+
+impl RangedBossbar {
+    #[must_use]
+    pub fn is_in_range(&self, pos: &Vector3<f64>) -> bool {
+        pos.squared_distance_to_vec(&self.center) <= self.range * self.range
+    }
 }
 
 /// Extra methods for [`Player`] to send and manage the bossbar.
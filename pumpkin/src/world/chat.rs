@@ -0,0 +1,301 @@
+use std::sync::Arc;
+
+use pumpkin_data::{translation, world::RAW};
+use pumpkin_macros::send_cancellable;
+use pumpkin_protocol::{
+    bedrock::server::text::SText,
+    codec::var_int::VarInt,
+    java::{
+        client::play::{CDisguisedChatMessage, CPlayerChatMessage, FilterType},
+        server::play::SChatMessage,
+    },
+};
+use pumpkin_util::text::TextComponent;
+use tracing::info;
+
+use crate::{
+    command::chat_filter::FilterResult,
+    entity::player::Player,
+    net::ClientPlatform,
+    plugin::player::player_chat::PlayerChatEvent,
+    server::{Server, seasonal_events},
+};
+
+/// A chat message as it is sent to the players of both editions.
+pub struct ChatMessage<'a> {
+    /// What Java players see. Player chat is already decorated with the sender's name.
+    pub content: &'a TextComponent,
+    /// The Java chat type from the `chat_type` registry.
+    pub chat_type: u8,
+    pub sender_name: &'a TextComponent,
+    pub target_name: Option<&'a TextComponent>,
+    /// The sender and their signed message. Java players get a signed player chat message if
+    /// this is set, a disguised one otherwise.
+    pub signed: Option<(&'a Arc<Player>, &'a SChatMessage)>,
+    /// Bedrock players see this plain text next to `bedrock_source`.
+    pub bedrock_message: String,
+    pub bedrock_source: String,
+}
+
+/// Handles chat typed by a player on either edition: runs the chat event, the chat filter and
+/// the decoration, then sends the message to the event's recipients, or to everyone in the
+/// sender's world if there are none.
+///
+/// `signed` is the message a Java player sent. It is only passed on while the message matches
+/// its signature, so a plugin or the chat filter changing it falls back to unsigned chat.
+pub async fn send_player_chat(
+    server: &Server,
+    sender: &Arc<Player>,
+    message: String,
+    signed: Option<&SChatMessage>,
+) {
+    let gameprofile = &sender.gameprofile;
+    let signed_by_client = signed.is_some_and(|signed| signed.signature.is_some());
+
+    send_cancellable! {{
+        server;
+        PlayerChatEvent::new(sender.clone(), message, vec![]);
+
+        'after: {
+            info!("<chat> {}: {}", gameprofile.name, event.message);
+
+            let (filtered, censored) = match server.chat_filter.filter(&event.message, gameprofile.id).await {
+                FilterResult::Allow => (event.message.clone(), false),
+                FilterResult::Replace(censored) => (censored, true),
+                FilterResult::Block => {
+                    sender
+                        .send_system_message(&TextComponent::translate(
+                            translation::CHAT_FILTERED_FULL,
+                            [],
+                        ))
+                        .await;
+                    if signed_by_client {
+                        skip_signed_message(sender).await;
+                    }
+                    return;
+                }
+            };
+
+            let config = &server.advanced_config;
+            let message = seasonal_events::modify_chat_message(&filtered, config).unwrap_or(filtered);
+            let decorated_message =
+                TextComponent::chat_decorated(&config.chat.format, &gameprofile.name, &message);
+
+            let signed = signed.filter(|signed| {
+                server.basic_config.allow_chat_reports
+                    && !censored
+                    && signed.signature.is_some()
+                    && signed.message == message
+            });
+            let sender_name = TextComponent::text(gameprofile.name.clone());
+            let chat = ChatMessage {
+                content: &decorated_message,
+                chat_type: RAW, // Custom registry chat_type with no sender name
+                sender_name: &sender_name,
+                target_name: None,
+                signed: signed.map(|signed| (sender, signed)),
+                bedrock_message: message,
+                bedrock_source: gameprofile.name.clone(),
+            };
+
+            if event.recipients.is_empty() {
+                let world = sender.world();
+                send_chat(&world.players.load(), &chat).await;
+            } else {
+                send_chat(&event.recipients, &chat).await;
+            }
+            if signed_by_client && chat.signed.is_none() {
+                skip_signed_message(sender).await;
+            }
+        }
+
+        'cancelled: {
+            if signed_by_client {
+                skip_signed_message(sender).await;
+            }
+        }
+    }}
+}
+
+/// The client counts every message it signs, so the chain has to move on when a signed message
+/// is sent unsigned or not at all.
+async fn skip_signed_message(sender: &Player) {
+    sender.chat_session.lock().await.messages_sent += 1;
+}
+
+/// Sends `chat` to each of the recipients in the format of their edition, keeping track of the
+/// signatures Java players have seen for signed messages.
+pub async fn send_chat(recipients: &[Arc<Player>], chat: &ChatMessage<'_>) {
+    let java_packet = CDisguisedChatMessage::new(
+        chat.content,
+        (chat.chat_type + 1).into(),
+        chat.sender_name,
+        chat.target_name,
+    );
+    let bedrock_packet = SText::new(chat.bedrock_message.clone(), chat.bedrock_source.clone());
+
+    let signed = match chat.signed {
+        Some((sender, message)) => {
+            let messages_sent = sender.chat_session.lock().await.messages_sent;
+            let last_seen = sender.signature_cache.lock().await.last_seen.clone();
+            Some((sender, message, messages_sent, last_seen))
+        }
+        None => None,
+    };
+
+    for recipient in recipients {
+        match &recipient.client {
+            ClientPlatform::Java(client) => {
+                let Some((sender, message, messages_sent, last_seen)) = &signed else {
+                    client.enqueue_packet(&java_packet).await;
+                    continue;
+                };
+                let messages_received = recipient.chat_session.lock().await.messages_received;
+                client
+                    .enqueue_packet(&CPlayerChatMessage::new(
+                        VarInt(messages_received),
+                        sender.gameprofile.id,
+                        VarInt(*messages_sent),
+                        message.signature.clone(),
+                        message.message.clone(),
+                        message.timestamp,
+                        message.salt,
+                        last_seen.indexed_for(recipient).await,
+                        Some(chat.content.clone()),
+                        FilterType::PassThrough,
+                        (chat.chat_type + 1).into(),
+                        TextComponent::text(""), // Not needed since we're injecting the name in the message for custom formatting
+                        None,
+                    ))
+                    .await;
+
+                let mut signature_cache = recipient.signature_cache.lock().await;
+                if let Some(signature) = &message.signature {
+                    signature_cache.add_seen_signature(signature);
+                }
+                if recipient.gameprofile.id != sender.gameprofile.id {
+                    // Sender may update recipient on signatures recipient hasn't seen
+                    signature_cache.cache_signatures(last_seen.as_ref());
+                }
+                drop(signature_cache);
+                recipient.chat_session.lock().await.messages_received += 1;
+            }
+            ClientPlatform::Bedrock(client) => client.send_game_packet(&bedrock_packet).await,
+        }
+    }
+
+    if let Some((sender, ..)) = signed {
+        sender.chat_session.lock().await.messages_sent += 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::world::RAW;
+    use pumpkin_protocol::bedrock::server::text::SText;
+    use pumpkin_protocol::codec::var_int::VarInt;
+    use pumpkin_protocol::java::client::play::{CDisguisedChatMessage, CPlayerChatMessage};
+    use pumpkin_protocol::java::server::play::SChatMessage;
+    use pumpkin_protocol::packet::Packet;
+    use pumpkin_util::text::TextComponent;
+    use tempfile::tempdir;
+
+    use super::{ChatMessage, send_chat, send_player_chat};
+    use crate::entity::player::Player;
+    use crate::entity::player::test::{test_bedrock_player, test_player};
+    use crate::plugin::player::player_chat::PlayerChatEvent;
+    use crate::plugin::test::cancel_all;
+    use crate::server::test::test_server;
+    use crate::world::test::test_world_on;
+
+    /// A message as a Java client signs it.
+    fn signed(message: &str) -> SChatMessage {
+        SChatMessage {
+            message: message.to_string(),
+            timestamp: 0,
+            salt: 0,
+            signature: Some(vec![7; 256].into_boxed_slice()),
+            message_count: VarInt(0),
+            acknowledged: Box::new([0; 3]),
+            checksum: 0,
+        }
+    }
+
+    async fn messages_sent(player: &Player) -> i32 {
+        player.chat_session.lock().await.messages_sent
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn chat_reaches_both_editions() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (steve, mut java) = test_player(&world, "Steve").await;
+        let (_alex, mut bedrock) = test_bedrock_player(&world, "Alex").await;
+        java.received_packet_ids().await;
+        bedrock.received_packet_ids().await;
+
+        send_player_chat(&server, &steve, "hello".to_string(), None).await;
+
+        assert!(java.received::<CDisguisedChatMessage>().await);
+        assert!(
+            bedrock
+                .received_packet_ids()
+                .await
+                .contains(&SText::PACKET_ID)
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn signed_chat_moves_the_chain_of_every_player() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (steve, _steve_connection) = test_player(&world, "Steve").await;
+        let (alex, mut alex_connection) = test_player(&world, "Alex").await;
+        alex_connection.received_packet_ids().await;
+
+        let message = signed("hello");
+        let content = TextComponent::text("<Steve> hello");
+        let sender_name = TextComponent::text("Steve");
+        let chat = ChatMessage {
+            content: &content,
+            chat_type: RAW,
+            sender_name: &sender_name,
+            target_name: None,
+            signed: Some((&steve, &message)),
+            bedrock_message: "hello".to_string(),
+            bedrock_source: "Steve".to_string(),
+        };
+        send_chat(&world.players.load(), &chat).await;
+
+        assert!(alex_connection.received::<CPlayerChatMessage>().await);
+        assert_eq!(messages_sent(&steve).await, 1);
+        assert_eq!(messages_sent(&alex).await, 0);
+        for player in [&steve, &alex] {
+            assert_eq!(player.chat_session.lock().await.messages_received, 1);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn signed_messages_sent_unsigned_or_not_at_all_are_skipped() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (steve, _connection) = test_player(&world, "Steve").await;
+
+        // Chat reports are off, so the message goes out unsigned
+        assert!(!server.basic_config.allow_chat_reports);
+        send_player_chat(&server, &steve, "hello".to_string(), Some(&signed("hello"))).await;
+        assert_eq!(messages_sent(&steve).await, 1);
+
+        // Unsigned messages aren't part of the chain
+        send_player_chat(&server, &steve, "hello".to_string(), None).await;
+        assert_eq!(messages_sent(&steve).await, 1);
+
+        cancel_all::<PlayerChatEvent>(&server).await;
+        send_player_chat(&server, &steve, "again".to_string(), Some(&signed("again"))).await;
+        assert_eq!(messages_sent(&steve).await, 2);
+    }
+}
@@ -1,4 +1,10 @@
-use std::{num::NonZeroU8, sync::Arc};
+use std::{
+    num::NonZeroU8,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
 
 use pumpkin_protocol::{
     bedrock::client::network_chunk_publisher_update::CNetworkChunkPublisherUpdate,
@@ -11,6 +17,34 @@ use crate::{
     net::ClientPlatform,
 };
 
+/// Counts how often chunks were sent to players in full, and how often a full resend was skipped
+/// because the player already had the current version of the chunk.
+#[derive(Default)]
+pub struct ChunkSendStats {
+    full_sends: AtomicU64,
+    skipped_resends: AtomicU64,
+}
+
+impl ChunkSendStats {
+    pub fn record_full_send(&self) {
+        self.full_sends.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_skipped_resend(&self) {
+        self.skipped_resends.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn full_sends(&self) -> u64 {
+        self.full_sends.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    pub fn skipped_resends(&self) -> u64 {
+        self.skipped_resends.load(Ordering::Relaxed)
+    }
+}
+
 pub fn get_view_distance(player: &Player) -> NonZeroU8 {
     let server = player.world().server.upgrade().unwrap();
     player.config.load().view_distance.clamp(
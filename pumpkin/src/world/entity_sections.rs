@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use pumpkin_util::math::{boundingbox::BoundingBox, get_section_cord, vector3::Vector3};
+
+use crate::entity::EntityBase;
+
+/// How far bounding boxes are assumed to reach out of the position they are indexed by, like
+/// vanilla's entity sections. Larger entities raise it for the whole index.
+const MIN_REACH: f64 = 2.0;
+
+/// Something kept in an [`EntitySectionIndex`].
+pub trait SectionIndexed: Clone {
+    fn index_id(&self) -> i32;
+    fn index_position(&self) -> Vector3<f64>;
+    fn index_bounding_box(&self) -> BoundingBox;
+}
+
+impl SectionIndexed for Arc<dyn EntityBase> {
+    fn index_id(&self) -> i32 {
+        self.get_entity().entity_id
+    }
+
+    fn index_position(&self) -> Vector3<f64> {
+        self.get_entity().pos.load()
+    }
+
+    fn index_bounding_box(&self) -> BoundingBox {
+        self.get_entity().bounding_box.load()
+    }
+}
+
+/// The entities of a world bucketed by the chunk section their position is in.
+///
+/// Box and radius queries only visit the sections around them instead of every entity in the
+/// world. Entities are moved between sections by [`Self::update`] whenever their position
+/// changes.
+pub struct EntitySectionIndex<T> {
+    inner: RwLock<IndexInner<T>>,
+}
+
+struct IndexInner<T> {
+    sections: HashMap<Vector3<i32>, Vec<T>>,
+    /// The section each indexed entity is in, keyed by entity id.
+    entity_sections: HashMap<i32, Vector3<i32>>,
+    /// How far any bounding box reaches out of the position it is indexed by. Only grows.
+    reach: f64,
+}
+
+impl<T> Default for EntitySectionIndex<T> {
+    fn default() -> Self {
+        Self {
+            inner: RwLock::new(IndexInner {
+                sections: HashMap::new(),
+                entity_sections: HashMap::new(),
+                reach: MIN_REACH,
+            }),
+        }
+    }
+}
+
+fn section_of(position: Vector3<f64>) -> Vector3<i32> {
+    Vector3::new(
+        get_section_cord(position.x.floor() as i32),
+        get_section_cord(position.y.floor() as i32),
+        get_section_cord(position.z.floor() as i32),
+    )
+}
+
+/// How far `bounding_box` reaches out of `position` in any direction.
+fn reach_of(position: Vector3<f64>, bounding_box: &BoundingBox) -> f64 {
+    [
+        position.x - bounding_box.min.x,
+        bounding_box.max.x - position.x,
+        position.y - bounding_box.min.y,
+        bounding_box.max.y - position.y,
+        position.z - bounding_box.min.z,
+        bounding_box.max.z - position.z,
+    ]
+    .into_iter()
+    .fold(0.0, f64::max)
+}
+
+impl<T> IndexInner<T> {
+    /// Calls `f` for each entity whose position is in a section overlapping `min` to `max`.
+    fn for_each_in_sections(&self, min: Vector3<f64>, max: Vector3<f64>, mut f: impl FnMut(&T)) {
+        let min = section_of(min);
+        let max = section_of(max);
+        let section_count = [max.x - min.x, max.y - min.y, max.z - min.z]
+            .into_iter()
+            .fold(1i64, |count, length| {
+                count.saturating_mul(i64::from(length) + 1)
+            });
+
+        // Huge queries are cheaper by going through the sections that have entities
+        if section_count > self.sections.len() as i64 {
+            for (section, entities) in &self.sections {
+                if (min.x..=max.x).contains(&section.x)
+                    && (min.y..=max.y).contains(&section.y)
+                    && (min.z..=max.z).contains(&section.z)
+                {
+                    entities.iter().for_each(&mut f);
+                }
+            }
+            return;
+        }
+        for x in min.x..=max.x {
+            for y in min.y..=max.y {
+                for z in min.z..=max.z {
+                    if let Some(entities) = self.sections.get(&Vector3::new(x, y, z)) {
+                        entities.iter().for_each(&mut f);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T: SectionIndexed> IndexInner<T> {
+    fn remove(&mut self, id: i32) -> Option<T> {
+        let section = self.entity_sections.remove(&id)?;
+        let entities = self.sections.get_mut(&section)?;
+        let index = entities.iter().position(|entity| entity.index_id() == id)?;
+        let entity = entities.swap_remove(index);
+        if entities.is_empty() {
+            self.sections.remove(&section);
+        }
+        Some(entity)
+    }
+}
+
+impl<T: SectionIndexed> EntitySectionIndex<T> {
+    /// Adds `entity` to the section of its current position, replacing it if it is already
+    /// indexed.
+    pub fn insert(&self, entity: T) {
+        let mut inner = self.inner.write().unwrap();
+        let id = entity.index_id();
+        inner.remove(id);
+        let position = entity.index_position();
+        inner.reach = inner
+            .reach
+            .max(reach_of(position, &entity.index_bounding_box()));
+        let section = section_of(position);
+        inner.entity_sections.insert(id, section);
+        inner.sections.entry(section).or_default().push(entity);
+    }
+
+    /// Removes the entity with the id `id`, returning it if it was indexed.
+    pub fn remove(&self, id: i32) -> Option<T> {
+        self.inner.write().unwrap().remove(id)
+    }
+
+    /// Moves the entity with the id `id` to the section of its new position. Does nothing if it
+    /// isn't indexed, e.g. because it was not spawned yet.
+    pub fn update(&self, id: i32, position: Vector3<f64>, bounding_box: &BoundingBox) {
+        let section = section_of(position);
+        let reach = reach_of(position, bounding_box);
+        {
+            let inner = self.inner.read().unwrap();
+            match inner.entity_sections.get(&id) {
+                Some(current) if *current == section && reach <= inner.reach => return,
+                Some(_) => {}
+                None => return,
+            }
+        }
+
+        let mut inner = self.inner.write().unwrap();
+        inner.reach = inner.reach.max(reach);
+        let Some(current) = inner.entity_sections.get(&id).copied() else {
+            return;
+        };
+        if current == section {
+            return;
+        }
+        if let Some(entity) = inner.remove(id) {
+            inner.entity_sections.insert(id, section);
+            inner.sections.entry(section).or_default().push(entity);
+        }
+    }
+
+    /// Gets all entities whose bounding box intersects `aabb`.
+    pub fn get_in_box(&self, aabb: &BoundingBox) -> Vec<T> {
+        let inner = self.inner.read().unwrap();
+        let reach = Vector3::new(inner.reach, inner.reach, inner.reach);
+        let mut found = Vec::new();
+        inner.for_each_in_sections(aabb.min.sub(&reach), aabb.max.add(&reach), |entity| {
+            if entity.index_bounding_box().intersects(aabb) {
+                found.push(entity.clone());
+            }
+        });
+        found
+    }
+
+    /// Gets all entities whose position is within `radius` of `center`.
+    pub fn get_in_radius(&self, center: Vector3<f64>, radius: f64) -> Vec<T> {
+        let inner = self.inner.read().unwrap();
+        let radius_squared = radius.powi(2);
+        let extent = Vector3::new(radius, radius, radius);
+        let mut found = Vec::new();
+        inner.for_each_in_sections(center.sub(&extent), center.add(&extent), |entity| {
+            if entity.index_position().squared_distance_to_vec(&center) <= radius_squared {
+                found.push(entity.clone());
+            }
+        });
+        found
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+    use std::sync::Arc;
+
+    use crossbeam::atomic::AtomicCell;
+    use pumpkin_util::math::{
+        boundingbox::{BoundingBox, EntityDimensions},
+        vector3::Vector3,
+    };
+    use pumpkin_util::random::{RandomImpl, xoroshiro128::Xoroshiro};
+
+    use super::{EntitySectionIndex, SectionIndexed};
+
+    #[derive(Clone)]
+    struct TestEntity(Arc<(i32, AtomicCell<Vector3<f64>>, EntityDimensions)>);
+
+    impl TestEntity {
+        fn set_pos(&self, index: &EntitySectionIndex<Self>, position: Vector3<f64>) {
+            self.0.1.store(position);
+            index.update(self.0.0, position, &self.index_bounding_box());
+        }
+    }
+
+    impl SectionIndexed for TestEntity {
+        fn index_id(&self) -> i32 {
+            self.0.0
+        }
+
+        fn index_position(&self) -> Vector3<f64> {
+            self.0.1.load()
+        }
+
+        fn index_bounding_box(&self) -> BoundingBox {
+            let position = self.0.1.load();
+            BoundingBox::new_from_pos(position.x, position.y, position.z, &self.0.2)
+        }
+    }
+
+    fn random_position(rng: &mut Xoroshiro, range: f64) -> Vector3<f64> {
+        Vector3::new(
+            (rng.next_f64() - 0.5) * range,
+            (rng.next_f64() - 0.5) * range,
+            (rng.next_f64() - 0.5) * range,
+        )
+    }
+
+    fn ids(entities: impl IntoIterator<Item = TestEntity>) -> BTreeSet<i32> {
+        entities
+            .into_iter()
+            .map(|entity| entity.index_id())
+            .collect()
+    }
+
+    #[test]
+    fn queries_match_brute_force() {
+        let mut rng = Xoroshiro::from_seed(42);
+        for layout in 0..20 {
+            let index = EntitySectionIndex::default();
+            let entities: Vec<_> = (0..200)
+                .map(|id| {
+                    // Every tenth entity is as large as an ender dragon
+                    let size = if id % 10 == 0 { 16.0 } else { 2.0 };
+                    let dimensions =
+                        EntityDimensions::new(rng.next_f32() * size, rng.next_f32() * size, 0.0);
+                    let position = random_position(&mut rng, 96.0);
+                    TestEntity(Arc::new((id, AtomicCell::new(position), dimensions)))
+                })
+                .collect();
+            entities
+                .iter()
+                .cloned()
+                .for_each(|entity| index.insert(entity));
+
+            // Move some across sections and remove others
+            for entity in entities.iter().step_by(3) {
+                entity.set_pos(&index, random_position(&mut rng, 96.0));
+            }
+            let removed: BTreeSet<_> = (0..200).filter(|id| id % 7 == layout % 7).collect();
+            for id in &removed {
+                assert!(index.remove(*id).is_some());
+            }
+            let live: Vec<_> = entities
+                .iter()
+                .filter(|entity| !removed.contains(&entity.index_id()))
+                .cloned()
+                .collect();
+
+            for _ in 0..50 {
+                let corner = random_position(&mut rng, 128.0);
+                let size = random_position(&mut rng, 64.0);
+                let aabb = BoundingBox::new(
+                    corner,
+                    corner.add(&Vector3::new(size.x.abs(), size.y.abs(), size.z.abs())),
+                );
+                let expected = live
+                    .iter()
+                    .filter(|entity| entity.index_bounding_box().intersects(&aabb))
+                    .cloned();
+                assert_eq!(ids(index.get_in_box(&aabb)), ids(expected));
+
+                let radius = rng.next_f64() * 40.0;
+                let expected = live
+                    .iter()
+                    .filter(|entity| {
+                        entity.index_position().squared_distance_to_vec(&corner) <= radius.powi(2)
+                    })
+                    .cloned();
+                assert_eq!(ids(index.get_in_radius(corner, radius)), ids(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn unindexed_entity_is_not_added_by_moving() {
+        let index = EntitySectionIndex::default();
+        let entity = TestEntity(Arc::new((
+            1,
+            AtomicCell::new(Vector3::default()),
+            EntityDimensions::new(0.6, 1.8, 1.62),
+        )));
+        entity.set_pos(&index, Vector3::new(100.0, 0.0, 0.0));
+        let everywhere = BoundingBox::new(
+            Vector3::new(-1000.0, -1000.0, -1000.0),
+            Vector3::new(1000.0, 1000.0, 1000.0),
+        );
+        assert!(index.get_in_box(&everywhere).is_empty());
+
+        index.insert(entity.clone());
+        entity.set_pos(&index, Vector3::new(-100.0, 0.0, 0.0));
+        assert_eq!(ids(index.get_in_box(&everywhere)), BTreeSet::from([1]));
+        assert!(index.remove(1).is_some());
+        assert!(index.get_in_box(&everywhere).is_empty());
+    }
+}
@@ -6,7 +6,7 @@ use rustc_hash::FxHashMap;
 
 use crate::{
     block::{ExplodeArgs, drop_loot},
-    entity::{Entity, EntityBase},
+    entity::{Entity, EntityBase, player::Player},
     world::loot::LootContextParameters,
 };
 
@@ -15,11 +15,47 @@ use super::{BlockFlags, World};
 pub struct Explosion {
     power: f32,
     pos: Vector3<f64>,
+    /// The entity that exploded, e.g. a creeper or primed TNT
+    source: Option<Arc<dyn EntityBase>>,
+    /// The player who caused the explosion, e.g. by igniting TNT
+    igniter: Option<Arc<Player>>,
 }
 impl Explosion {
     #[must_use]
-    pub const fn new(power: f32, pos: Vector3<f64>) -> Self {
-        Self { power, pos }
+    pub const fn new(
+        power: f32,
+        pos: Vector3<f64>,
+        source: Option<Arc<dyn EntityBase>>,
+        igniter: Option<Arc<Player>>,
+    ) -> Self {
+        Self {
+            power,
+            pos,
+            source,
+            igniter,
+        }
+    }
+
+    #[must_use]
+    pub fn source(&self) -> Option<&dyn EntityBase> {
+        self.source.as_deref()
+    }
+
+    /// The entity the explosion damage is attributed to. This is the igniter if there is one,
+    /// otherwise the source if it is a living entity (like a creeper).
+    #[must_use]
+    pub fn causing_entity(&self) -> Option<&dyn EntityBase> {
+        if let Some(igniter) = &self.igniter {
+            return Some(igniter.as_ref());
+        }
+        self.source
+            .as_deref()
+            .filter(|source| source.get_living_entity().is_some())
+    }
+
+    #[must_use]
+    pub fn damage_type(&self) -> DamageType {
+        explosion_damage_type(self.source.is_some(), self.causing_entity().is_some())
     }
     async fn get_blocks_to_destroy(
         &self,
@@ -121,9 +157,15 @@ impl Explosion {
                 * self.power as f64
                 + 1.0) as f32;
 
-            // TODO: damage type
             entity
-                .damage(entity_base.as_ref(), damage, DamageType::EXPLOSION)
+                .damage_with_context(
+                    entity_base.as_ref(),
+                    damage,
+                    self.damage_type(),
+                    Some(self.pos),
+                    self.source(),
+                    self.causing_entity(),
+                )
                 .await;
 
             // Calculate and apply knockback
@@ -205,9 +247,12 @@ impl Explosion {
     pub async fn explode(&self, world: &Arc<World>) -> u32 {
         let blocks = self.get_blocks_to_destroy(world).await;
         self.damage_entities(world).await;
+        // Before the contents of containers are dropped, so they can't be taken out twice
+        world
+            .close_container_screens_where(|pos| blocks.contains_key(pos))
+            .await;
         for (pos, (block, state)) in &blocks {
             world.set_block_state(pos, 0, BlockFlags::NOTIFY_ALL).await;
-            world.close_container_screens_at(pos).await;
 
             let pumpkin_block = world.block_registry.get_pumpkin_block(block.id);
 
@@ -233,3 +278,73 @@ impl Explosion {
         blocks.len() as u32
     }
 }
+
+/// Vanilla only uses the player explosion damage type (`"was blown up by ..."`) when both the
+/// exploding entity and the entity causing the explosion are known.
+#[must_use]
+pub const fn explosion_damage_type(has_source: bool, has_causing_entity: bool) -> DamageType {
+    if has_source && has_causing_entity {
+        DamageType::PLAYER_EXPLOSION
+    } else {
+        DamageType::EXPLOSION
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arc_swap::ArcSwap;
+    use pumpkin_data::Block;
+    use pumpkin_data::damage::DamageType;
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::item::Item;
+    use pumpkin_util::math::{position::BlockPos, vector2::Vector2, vector3::Vector3};
+    use pumpkin_util::world_seed::Seed;
+    use pumpkin_world::block::entities::chest::ChestBlockEntity;
+    use pumpkin_world::item::ItemStack;
+    use pumpkin_world::world_info::LevelData;
+    use tempfile::tempdir;
+
+    use super::{BlockFlags, Explosion, explosion_damage_type};
+    use crate::world::test::{load_empty_chunk, test_world};
+
+    #[test]
+    fn unattributed_explosion() {
+        let explosion = Explosion::new(4.0, Vector3::new(0.0, 64.0, 0.0), None, None);
+        assert!(explosion.source().is_none());
+        assert!(explosion.causing_entity().is_none());
+        assert!(explosion.damage_type() == DamageType::EXPLOSION);
+    }
+
+    #[test]
+    fn attributed_damage_type() {
+        assert!(explosion_damage_type(true, true) == DamageType::PLAYER_EXPLOSION);
+        assert!(explosion_damage_type(true, false) == DamageType::EXPLOSION);
+        assert!(explosion_damage_type(false, false) == DamageType::EXPLOSION);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn exploded_chest_drops_its_contents() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        load_empty_chunk(&world, Vector2::new(0, 0));
+
+        let pos = BlockPos::new(8, 70, 8);
+        world
+            .set_block_state(&pos, Block::CHEST.default_state.id, BlockFlags::NOTIFY_ALL)
+            .await;
+        let chest = Arc::new(ChestBlockEntity::new(pos));
+        *chest.items[0].lock().await = ItemStack::new(5, &Item::DIAMOND);
+        world.add_block_entity(chest.clone()).await;
+
+        let explosion = Explosion::new(2.0, Vector3::new(8.5, 70.5, 8.5), None, None);
+        assert!(explosion.explode(&world).await > 0);
+
+        assert!(world.get_block_state(&pos).await.is_air());
+        assert!(world.get_block_entity(&pos).await.is_none());
+        // The contents were moved out of the chest into item entities
+        assert!(chest.items[0].lock().await.is_empty());
+    }
+}
@@ -14,6 +14,8 @@ pub struct LootContextParameters {
     pub explosion_radius: Option<f32>,
     pub block_state: Option<&'static BlockState>,
     pub killed_by_player: Option<bool>,
+    /// The Looting level of the weapon the entity was killed with, `0` if there was none.
+    pub looting_modifier: u8,
 }
 
 pub trait LootTableExt {
@@ -126,6 +128,18 @@ impl LootPoolEntryExt for LootPoolEntry {
                             }
                         }
                     }
+                    LootFunctionTypes::EnchantedCountIncrease { count, limit } => {
+                        if params.looting_modifier > 0 {
+                            for stack in &mut stacks {
+                                stack.item_count = apply_looting_count_increase(
+                                    stack.item_count,
+                                    params.looting_modifier,
+                                    count.generate(),
+                                    *limit,
+                                );
+                            }
+                        }
+                    }
                     LootFunctionTypes::ApplyBonus {
                         enchantment: _,
                         formula: _,
@@ -139,7 +153,6 @@ impl LootPoolEntryExt for LootPoolEntry {
                         block: _,
                         properties: _,
                     }
-                    | LootFunctionTypes::EnchantedCountIncrease
                     | LootFunctionTypes::SetOminousBottleAmplifier
                     | LootFunctionTypes::SetPotion
                     | LootFunctionTypes::FurnaceSmelt
@@ -154,6 +167,36 @@ impl LootPoolEntryExt for LootPoolEntry {
     }
 }
 
+/// Grows a drop by `per_level` items for each level of Looting, e.g. a uniform roll between 0
+/// and 1 per level for most mob drops.
+fn apply_looting_count_increase(
+    item_count: u8,
+    looting_level: u8,
+    per_level: f32,
+    limit: Option<i32>,
+) -> u8 {
+    let bonus = (f32::from(looting_level) * per_level).round() as u8;
+    let count = item_count.saturating_add(bonus);
+    match limit {
+        Some(limit) if limit > 0 => count.min(u8::try_from(limit).unwrap_or(u8::MAX)),
+        _ => count,
+    }
+}
+
+/// The chance of a rare drop, like a wither skeleton skull, to drop at the given Looting level.
+fn looting_chance(
+    looting_level: u8,
+    unenchanted_chance: f32,
+    base: f32,
+    per_level_above_first: f32,
+) -> f32 {
+    if looting_level == 0 {
+        unenchanted_chance
+    } else {
+        f32::from(looting_level - 1).mul_add(per_level_above_first, base)
+    }
+}
+
 trait LootPoolEntryTypesExt {
     fn get_stacks(&self, params: &LootContextParameters) -> Vec<ItemStack>;
 }
@@ -198,6 +241,19 @@ impl LootConditionExt for LootCondition {
                 true
             }
             Self::KilledByPlayer => params.killed_by_player.unwrap_or(false),
+            Self::RandomChanceWithEnchantedBonus {
+                unenchanted_chance,
+                base,
+                per_level_above_first,
+            } => {
+                let chance = looting_chance(
+                    params.looting_modifier,
+                    *unenchanted_chance,
+                    *base,
+                    *per_level_above_first,
+                );
+                rand::rng().random::<f32>() < chance
+            }
             Self::BlockStateProperty {
                 block: _,
                 properties,
@@ -246,3 +302,67 @@ impl LootFunctionNumberProviderExt for LootFunctionNumberProvider {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_util::loot_table::{
+        ItemEntry, LootFunction, LootFunctionNumberProvider, LootFunctionTypes, LootPoolEntry,
+        LootPoolEntryTypes,
+    };
+
+    use super::{
+        LootContextParameters, LootPoolEntryExt, apply_looting_count_increase, looting_chance,
+    };
+
+    const ROTTEN_FLESH: LootPoolEntry = LootPoolEntry {
+        content: LootPoolEntryTypes::Item(ItemEntry {
+            name: "minecraft:rotten_flesh",
+        }),
+        conditions: None,
+        functions: Some(&[LootFunction {
+            content: LootFunctionTypes::EnchantedCountIncrease {
+                count: LootFunctionNumberProvider::Constant { value: 1.0 },
+                limit: None,
+            },
+            conditions: None,
+        }]),
+    };
+
+    fn drop_count(entry: &LootPoolEntry, looting_modifier: u8) -> u8 {
+        let params = LootContextParameters {
+            looting_modifier,
+            ..Default::default()
+        };
+        entry.get_loot(&params).unwrap()[0].item_count
+    }
+
+    #[test]
+    fn looting_increases_common_drops() {
+        assert_eq!(drop_count(&ROTTEN_FLESH, 0), 1);
+        assert_eq!(drop_count(&ROTTEN_FLESH, 1), 2);
+        assert_eq!(drop_count(&ROTTEN_FLESH, 2), 3);
+        assert_eq!(drop_count(&ROTTEN_FLESH, 3), 4);
+    }
+
+    #[test]
+    fn looting_count_increase_respects_roll_and_limit() {
+        // A roll of 0 adds nothing, whatever the level
+        assert_eq!(apply_looting_count_increase(1, 3, 0.0, None), 1);
+        // Half an item per level is rounded
+        assert_eq!(apply_looting_count_increase(1, 1, 0.5, None), 2);
+        assert_eq!(apply_looting_count_increase(1, 3, 0.5, None), 3);
+        // Capped at the limit, where a limit of 0 means there is none
+        assert_eq!(apply_looting_count_increase(0, 3, 1.0, Some(1)), 1);
+        assert_eq!(apply_looting_count_increase(2, 3, 1.0, Some(0)), 5);
+    }
+
+    #[test]
+    fn looting_increases_rare_drop_chance() {
+        // Wither skeleton skull
+        let chance = |level| looting_chance(level, 0.025, 0.035, 0.01);
+        assert!((chance(0) - 0.025).abs() < f32::EPSILON);
+        assert!((chance(1) - 0.035).abs() < f32::EPSILON);
+        assert!((chance(2) - 0.045).abs() < f32::EPSILON);
+        assert!((chance(3) - 0.055).abs() < f32::EPSILON);
+    }
+}
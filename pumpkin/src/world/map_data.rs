@@ -0,0 +1,316 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+use pumpkin_protocol::{
+    codec::var_int::VarInt,
+    java::client::play::{MapDecoration, MapPatch},
+};
+use thiserror::Error;
+use tracing::error;
+
+/// Maps are 128x128 pixels.
+pub const MAP_SIZE: usize = 128;
+
+/// IDs in the `minecraft:map_decoration_type` registry.
+const DECORATION_PLAYER: i32 = 0;
+const DECORATION_PLAYER_OFF_MAP: i32 = 6;
+const DECORATION_PLAYER_OFF_LIMITS: i32 = 7;
+
+#[derive(Debug, Error)]
+pub enum MapDataError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("NBT error: {0}")]
+    Nbt(String),
+}
+
+/// The contents of a filled map, stored in `data/map_<id>.dat`.
+pub struct MapData {
+    pub center_x: i32,
+    pub center_z: i32,
+    /// From 0 for a fully zoomed-in map (1 block per pixel) to 4 for a fully zoomed-out map.
+    pub scale: u8,
+    /// The dimension the map shows, e.g. `minecraft:overworld`.
+    pub dimension: String,
+    pub locked: bool,
+    /// Whether player markers are shown.
+    pub tracking_position: bool,
+    /// Whether players far outside the map are still shown at its edge.
+    pub unlimited_tracking: bool,
+    /// Map color IDs, row by row.
+    pub colors: Box<[u8]>,
+}
+
+impl MapData {
+    /// Reads a `map_<id>.dat` file in the vanilla format.
+    pub fn load_nbt(path: &Path) -> Result<Self, MapDataError> {
+        let file = File::open(path)?;
+        let nbt = pumpkin_nbt::nbt_compress::read_gzip_compound_tag(file)
+            .map_err(|err| MapDataError::Nbt(err.to_string()))?;
+        Ok(Self::from_nbt(&nbt))
+    }
+
+    fn from_nbt(root: &NbtCompound) -> Self {
+        let empty = NbtCompound::new();
+        let data = root.get_compound("data").unwrap_or(&empty);
+
+        let mut colors = vec![0; MAP_SIZE * MAP_SIZE].into_boxed_slice();
+        if let Some(NbtTag::ByteArray(stored)) = data.get("colors")
+            && stored.len() == colors.len()
+        {
+            colors.copy_from_slice(stored);
+        }
+
+        Self {
+            center_x: data.get_int("xCenter").unwrap_or(0),
+            center_z: data.get_int("zCenter").unwrap_or(0),
+            scale: data.get_byte("scale").unwrap_or(0).clamp(0, 4) as u8,
+            dimension: data
+                .get_string("dimension")
+                .unwrap_or("minecraft:overworld")
+                .to_string(),
+            locked: data.get_bool("locked").unwrap_or(false),
+            tracking_position: data.get_bool("trackingPosition").unwrap_or(true),
+            unlimited_tracking: data.get_bool("unlimitedTracking").unwrap_or(false),
+            colors,
+        }
+    }
+
+    /// Converts a world position to map pixels relative to the map's center.
+    fn to_map_pixels(&self, x: f64, z: f64) -> (f64, f64) {
+        let blocks_per_pixel = f64::from(1 << self.scale);
+        (
+            (x - f64::from(self.center_x)) / blocks_per_pixel,
+            (z - f64::from(self.center_z)) / blocks_per_pixel,
+        )
+    }
+
+    /// Whether the position lies within the area shown on the map.
+    #[must_use]
+    pub fn covers(&self, x: f64, z: f64) -> bool {
+        let (x, z) = self.to_map_pixels(x, z);
+        (-64.0..64.0).contains(&x) && (-64.0..64.0).contains(&z)
+    }
+
+    /// The marker for a player at the given position, or None if the player is too far away to
+    /// be shown.
+    #[must_use]
+    pub fn player_decoration(&self, x: f64, z: f64, yaw: f32) -> Option<MapDecoration> {
+        let (map_x, map_z) = self.to_map_pixels(x, z);
+        let to_icon = |pixel: f64| (pixel * 2.0 + 0.5) as i8;
+        let limit = 63.0;
+
+        if (-limit..=limit).contains(&map_x) && (-limit..=limit).contains(&map_z) {
+            let yaw = yaw + if yaw < 0.0 { -8.0 } else { 8.0 };
+            return Some(MapDecoration {
+                decoration_type: VarInt(DECORATION_PLAYER),
+                x: to_icon(map_x),
+                z: to_icon(map_z),
+                rotation: ((yaw * 16.0 / 360.0) as i32 & 15) as i8,
+            });
+        }
+
+        let decoration_type = if map_x.abs() < 320.0 && map_z.abs() < 320.0 {
+            DECORATION_PLAYER_OFF_MAP
+        } else if self.unlimited_tracking {
+            DECORATION_PLAYER_OFF_LIMITS
+        } else {
+            return None;
+        };
+        let clamp_to_edge = |pixel: f64| {
+            if pixel <= -limit {
+                i8::MIN
+            } else if pixel >= limit {
+                i8::MAX
+            } else {
+                to_icon(pixel)
+            }
+        };
+        Some(MapDecoration {
+            decoration_type: VarInt(decoration_type),
+            x: clamp_to_edge(map_x),
+            z: clamp_to_edge(map_z),
+            rotation: 0,
+        })
+    }
+
+    /// The smallest rectangle containing every pixel that differs from `sent`, or all pixels if
+    /// nothing was sent yet. Returns None if nothing changed.
+    #[must_use]
+    pub fn patch_since(&self, sent: Option<&[u8]>) -> Option<MapPatch> {
+        let (start_x, start_z, end_x, end_z) = match sent {
+            Some(sent) => changed_bounds(sent, &self.colors)?,
+            None => (0, 0, MAP_SIZE - 1, MAP_SIZE - 1),
+        };
+        let width = end_x - start_x + 1;
+        let height = end_z - start_z + 1;
+
+        let mut colors = Vec::with_capacity(width * height);
+        for z in start_z..=end_z {
+            let row = z * MAP_SIZE;
+            colors.extend_from_slice(&self.colors[row + start_x..=row + end_x]);
+        }
+        Some(MapPatch {
+            start_x: start_x as u8,
+            start_z: start_z as u8,
+            width: width as u8,
+            height: height as u8,
+            colors,
+        })
+    }
+}
+
+/// The inclusive (`min_x`, `min_z`, `max_x`, `max_z`) bounds of the pixels that differ.
+fn changed_bounds(old: &[u8], new: &[u8]) -> Option<(usize, usize, usize, usize)> {
+    let mut bounds: Option<(usize, usize, usize, usize)> = None;
+    for (index, _) in old
+        .iter()
+        .zip(new)
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+    {
+        let (x, z) = (index % MAP_SIZE, index / MAP_SIZE);
+        bounds = Some(bounds.map_or((x, z, x, z), |(min_x, min_z, max_x, max_z)| {
+            (min_x.min(x), min_z.min(z), max_x.max(x), max_z.max(z))
+        }));
+    }
+    bounds
+}
+
+/// Loads filled maps on demand and keeps them around, keyed by map ID.
+pub struct MapStorage {
+    folder: PathBuf,
+    /// None for IDs without a map file so they aren't looked up again.
+    maps: Mutex<HashMap<i32, Option<Arc<MapData>>>>,
+}
+
+impl MapStorage {
+    #[must_use]
+    pub fn new(folder: PathBuf) -> Self {
+        Self {
+            folder,
+            maps: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the map with the given ID, reading it from disk the first time. The file is read on
+    /// the blocking thread pool so callers on the tick task don't stall.
+    pub async fn get(&self, id: i32) -> Option<Arc<MapData>> {
+        let cached = self.maps.lock().unwrap().get(&id).cloned();
+        if let Some(map) = cached {
+            return map;
+        }
+        let path = self.folder.join(format!("map_{id}.dat"));
+        let map = tokio::task::spawn_blocking(move || {
+            if !path.exists() {
+                return None;
+            }
+            MapData::load_nbt(&path)
+                .inspect_err(|e| error!("Failed to load map {id}: {e}"))
+                .ok()
+                .map(Arc::new)
+        })
+        .await
+        .unwrap_or_else(|e| {
+            error!("Failed to load map {id}: {e}");
+            None
+        });
+        self.maps.lock().unwrap().entry(id).or_insert(map).clone()
+    }
+
+    /// Forgets the cached map with the given ID, so the next [`MapStorage::get`] reads its file
+    /// again, e.g. after the file was replaced.
+    pub fn invalidate(&self, id: i32) {
+        self.maps.lock().unwrap().remove(&id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs::File, path::Path};
+
+    use pumpkin_nbt::compound::NbtCompound;
+    use tempfile::tempdir;
+
+    use super::{MAP_SIZE, MapData, MapStorage, changed_bounds};
+
+    fn map() -> MapData {
+        MapData {
+            center_x: 64,
+            center_z: 64,
+            scale: 0,
+            dimension: "minecraft:overworld".to_string(),
+            locked: false,
+            tracking_position: true,
+            unlimited_tracking: false,
+            colors: vec![0; MAP_SIZE * MAP_SIZE].into_boxed_slice(),
+        }
+    }
+
+    fn write_map(folder: &Path, id: i32, center_x: i32) {
+        let mut data = NbtCompound::new();
+        data.put_int("xCenter", center_x);
+        data.put_int("zCenter", 0);
+        let mut root = NbtCompound::new();
+        root.put_component("data", data);
+        let file = File::create(folder.join(format!("map_{id}.dat"))).unwrap();
+        pumpkin_nbt::nbt_compress::write_gzip_compound_tag(root, file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn invalidated_maps_are_read_again() {
+        let dir = tempdir().unwrap();
+        let storage = MapStorage::new(dir.path().to_path_buf());
+        assert!(storage.get(1).await.is_none());
+
+        write_map(dir.path(), 1, 64);
+        assert!(storage.get(1).await.is_none());
+        storage.invalidate(1);
+        assert_eq!(storage.get(1).await.unwrap().center_x, 64);
+
+        write_map(dir.path(), 1, 128);
+        assert_eq!(storage.get(1).await.unwrap().center_x, 64);
+        storage.invalidate(1);
+        assert_eq!(storage.get(1).await.unwrap().center_x, 128);
+    }
+
+    #[test]
+    fn patch_covers_changed_pixels() {
+        let mut map = map();
+        let sent = map.colors.clone();
+        assert!(map.patch_since(Some(&sent)).is_none());
+
+        map.colors[3 * MAP_SIZE + 10] = 4;
+        map.colors[5 * MAP_SIZE + 2] = 7;
+        assert_eq!(changed_bounds(&sent, &map.colors), Some((2, 3, 10, 5)));
+
+        let patch = map.patch_since(Some(&sent)).unwrap();
+        assert_eq!((patch.start_x, patch.start_z), (2, 3));
+        assert_eq!((patch.width, patch.height), (9, 3));
+        assert_eq!(patch.colors[8], 4);
+        assert_eq!(patch.colors[2 * 9], 7);
+
+        let full = map.patch_since(None).unwrap();
+        assert_eq!(full.colors.len(), MAP_SIZE * MAP_SIZE);
+    }
+
+    #[test]
+    fn player_marker_position() {
+        let map = map();
+        assert!(map.covers(0.0, 127.9));
+        assert!(!map.covers(-0.1, 64.0));
+
+        let center = map.player_decoration(64.0, 64.0, 0.0).unwrap();
+        assert_eq!((center.decoration_type.0, center.x, center.z), (0, 0, 0));
+
+        let off_map = map.player_decoration(64.0 + 200.0, 64.0, 0.0).unwrap();
+        assert_eq!((off_map.decoration_type.0, off_map.x), (6, i8::MAX));
+
+        assert!(map.player_decoration(64.0 + 1000.0, 64.0, 0.0).is_none());
+    }
+}
@@ -1,10 +1,12 @@
 use std::pin::Pin;
 use std::sync::atomic::Ordering::Relaxed;
-use std::sync::{Arc, Weak};
+use std::sync::{Arc, LazyLock, Weak};
 use std::{
-    collections::{BTreeMap, HashMap},
-    sync::atomic::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
 };
+use thiserror::Error;
 use tracing::{debug, error, info, trace, warn};
 
 pub mod chunker;
@@ -16,36 +18,58 @@ pub mod time;
 use crate::block::RandomTickArgs;
 use crate::world::loot::LootContextParameters;
 use crate::{
-    block::BlockEvent, entity::experience_orb::ExperienceOrbEntity, entity::item::ItemEntity,
+    block::BlockEvent, block::blocks::falling::FallingBlock,
+    entity::experience_orb::ExperienceOrbEntity, entity::falling::FallingEntity,
+    entity::item::ItemEntity,
 };
 use crate::{
     block::{
-        self,
+        self, FormCause,
+        blocks::snow,
         registry::BlockRegistry,
         {OnNeighborUpdateArgs, OnScheduledTickArgs},
     },
     command::client_suggestions,
-    entity::{Entity, EntityBase, player::Player, r#type::from_type},
+    entity::{Entity, EntityBase, NbtFuture, RemovalReason, player::Player, r#type::from_type},
     error::PumpkinError,
-    net::{ClientPlatform, java::JavaClient},
+    net::{ClientPlatform, bedrock::BedrockClient, java::JavaClient},
     plugin::{
         block::block_break::BlockBreakEvent,
+        entity::entity_spawn::EntitySpawnEvent,
         player::{player_join::PlayerJoinEvent, player_leave::PlayerLeaveEvent},
+        world::{
+            chunk_load::ChunkLoad, chunk_unload::ChunkUnload,
+            difficulty_change::DifficultyChangeEvent,
+        },
     },
     server::Server,
 };
 use arc_swap::ArcSwap;
+use block_state_cache::BlockStateCache;
 use border::Worldborder;
-use bytes::BufMut;
+use bossbar::{Bossbar, RangedBossbar};
+use bytes::{BufMut, Bytes};
+use chunker::ChunkSendStats;
+use crossbeam::atomic::AtomicCell;
+use crossbeam::channel::Receiver;
 use explosion::Explosion;
+use futures::future::join_all;
+use portal::end::EndPortal;
 use pumpkin_config::BasicConfiguration;
-use pumpkin_data::block_properties::is_air;
+use pumpkin_data::biome::Biome;
+use pumpkin_data::block_properties::{
+    BlockProperties, EndPortalFrameLikeProperties, EnumVariants, Integer1To8, SnowLikeProperties,
+    is_air,
+};
 use pumpkin_data::chunk_gen_settings::GenerationSettings;
 use pumpkin_data::data_component_impl::EquipmentSlot;
 use pumpkin_data::dimension::Dimension;
-use pumpkin_data::entity::MobCategory;
+use pumpkin_data::entity::{EntityPose, MobCategory, SpawnLocation};
 use pumpkin_data::fluid::{Falling, FluidProperties, FluidState};
 use pumpkin_data::meta_data_type::MetaDataType;
+use pumpkin_data::tag::Fluid::{MINECRAFT_LAVA, MINECRAFT_WATER};
+use pumpkin_data::tag::Taggable;
+use pumpkin_data::tag::WorldgenBiome::MINECRAFT_IS_OCEAN;
 use pumpkin_data::tracked_data::TrackedData;
 use pumpkin_data::{
     Block,
@@ -53,19 +77,23 @@ use pumpkin_data::{
     fluid::Fluid,
     particle::Particle,
     sound::{Sound, SoundCategory},
-    world::{RAW, WorldEvent},
+    world::WorldEvent,
 };
 use pumpkin_data::{BlockDirection, BlockState, translation};
 use pumpkin_inventory::screen_handler::InventoryPlayer;
-use pumpkin_nbt::{compound::NbtCompound, to_bytes_unnamed};
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag, to_bytes_unnamed};
 use pumpkin_protocol::bedrock::client::set_actor_data::{
     CSetActorData, EntityMetadata, MetadataValue, PropertySyncData, entity_data_flag,
     entity_data_key,
 };
 use pumpkin_protocol::bedrock::client::start_game::CStartGame;
 use pumpkin_protocol::bedrock::frame_set::FrameSet;
-use pumpkin_protocol::java::client::play::CPlayerSpawnPosition;
+use pumpkin_protocol::bedrock::server::text::SText;
+use pumpkin_protocol::java::client::play::{
+    CChangeDifficulty, CPlayerSpawnPosition, CSystemChatMessage,
+};
 use pumpkin_protocol::java::client::play::{CSetEntityMetadata, Metadata};
+use pumpkin_protocol::java::server::play::SUseItemOn;
 use pumpkin_protocol::{
     BClientPacket, ClientPacket, IdOr, SoundEvent,
     bedrock::{
@@ -77,7 +105,6 @@ use pumpkin_protocol::{
             update_artributes::{Attribute, CUpdateAttributes},
         },
         network_item::NetworkItemDescriptor,
-        server::text::SText,
     },
     codec::{
         bedrock_block_pos::NetworkPos, var_long::VarLong, var_uint::VarUInt, var_ulong::VarULong,
@@ -86,11 +113,9 @@ use pumpkin_protocol::{
         self,
         client::play::{
             CBlockEntityData, CEntityStatus, CGameEvent, CLogin, CMultiBlockUpdate,
-            CPlayerChatMessage, CPlayerInfoUpdate, CRemoveEntities, CRemovePlayerInfo,
-            CSetSelectedSlot, CSoundEffect, CSpawnEntity, FilterType, GameEvent, InitChat,
-            PlayerAction, PlayerInfoFlags,
+            CPlayerInfoUpdate, CRemoveEntities, CRemovePlayerInfo, CSetSelectedSlot, CSoundEffect,
+            CSpawnEntity, CUpdateRecipes, GameEvent, InitChat, PlayerAction, PlayerInfoFlags,
         },
-        server::play::SChatMessage,
     },
 };
 use pumpkin_protocol::{
@@ -100,52 +125,84 @@ use pumpkin_protocol::{
 use pumpkin_protocol::{
     codec::var_int::VarInt,
     java::client::play::{
-        CBlockUpdate, CChunkBatchEnd, CChunkBatchStart, CChunkData, CDisguisedChatMessage,
-        CExplosion, CRespawn, CSetBlockDestroyStage, CWorldEvent,
+        CBlockUpdate, CChunkBatchEnd, CChunkBatchStart, CChunkData, CExplosion, CRespawn,
+        CSetBlockDestroyStage, CWorldEvent,
     },
 };
 use pumpkin_util::resource_location::ResourceLocation;
 use pumpkin_util::text::{TextComponent, color::NamedColor};
 use pumpkin_util::version::MinecraftVersion;
 use pumpkin_util::{
-    Difficulty,
+    Difficulty, GameMode,
     math::{boundingbox::BoundingBox, position::BlockPos, vector3::Vector3},
 };
 use pumpkin_util::{
     math::{position::chunk_section_from_pos, vector2::Vector2},
     random::{RandomImpl, get_seed, xoroshiro128::Xoroshiro},
 };
-use pumpkin_world::inventory::Clearable;
+use pumpkin_world::chunk_system::ChunkLoading;
+use pumpkin_world::lighting::LightLayer;
 use pumpkin_world::world::{GetBlockError, WorldFuture};
 use pumpkin_world::{
     BlockStateId, CURRENT_BEDROCK_MC_VERSION, biome, block::entities::BlockEntity,
     chunk::io::Dirtiable, inventory::Inventory, item::ItemStack, world::SimpleWorld,
 };
 use pumpkin_world::{chunk::ChunkData, world::BlockAccessor};
-use pumpkin_world::{level::Level, tick::TickPriority};
+use pumpkin_world::{
+    level::{Level, SyncChunk},
+    tick::TickPriority,
+};
 use pumpkin_world::{world::BlockFlags, world_info::LevelData};
 use rand::seq::SliceRandom;
 use rand::{RngExt, rng};
+use random_sequences::{RandomHandle, RandomSequences};
+use scheduled_callbacks::{ScheduleError, ScheduleMode, ScheduledCallbacks};
 use scoreboard::Scoreboard;
 use time::LevelTime;
 use tokio::sync::Mutex;
 
+pub mod block_state_cache;
 pub mod border;
 pub mod bossbar;
+pub mod chat;
 pub mod custom_bossbar;
+pub mod entity_sections;
+pub mod map_data;
 pub mod natural_spawner;
+pub mod random_sequences;
+pub mod scheduled_callbacks;
 pub mod scoreboard;
 pub mod weather;
 
-use crate::world::natural_spawner::{SpawnState, spawn_for_chunk};
+use crate::world::entity_sections::EntitySectionIndex;
+use crate::world::natural_spawner::{
+    SpawnState, SpawnSurroundings, is_block_dangerous, is_valid_empty_spawn_block,
+    is_valid_spawn_floor, spawn_for_chunk,
+};
 use pumpkin_config::lighting::LightingEngineConfig;
 use pumpkin_data::effect::StatusEffect;
-use pumpkin_world::chunk::ChunkHeightmapType::MotionBlocking;
+use pumpkin_world::chunk::ChunkHeightmapType::{self, MotionBlocking, MotionBlockingNoLeaves};
 use uuid::Uuid;
 use weather::Weather;
 
 type FlowingFluidProperties = pumpkin_data::fluid::FlowingWaterLikeFluidProperties;
 
+/// The largest radius of spawn chunks, the same limit as vanilla's `spawnChunkRadius`.
+const MAX_SPAWN_CHUNK_RADIUS: u8 = 32;
+
+/// Players further away than this from a block don't see it being broken.
+const BLOCK_BREAKING_RANGE: f64 = 32.0;
+
+/// The spawn search gives up after checking this many columns, so a spawn surrounded by water
+/// can't stall joining players.
+const MAX_SPAWN_CANDIDATES: usize = 1024;
+/// The height of the Nether's bedrock ceiling.
+const NETHER_CEILING_Y: i32 = 127;
+/// How many rings of biome samples the initial spawn search looks at around the origin.
+const SPAWN_BIOME_SAMPLE_RADIUS: i32 = 4;
+/// The distance in blocks between two biome samples of the initial spawn search.
+const SPAWN_BIOME_SAMPLE_SPACING: i32 = 64;
+
 impl PumpkinError for GetBlockError {
     fn is_kick(&self) -> bool {
         false
@@ -155,11 +212,22 @@ impl PumpkinError for GetBlockError {
         tracing::Level::WARN
     }
 
-    fn client_kick_reason(&self) -> Option<String> {
+    fn client_kick_reason(&self) -> Option<TextComponent> {
         None
     }
 }
 
+/// Reasons why a world spawn point could not be set.
+#[derive(Debug, Error)]
+pub enum SetSpawnPointError {
+    #[error("Spawn point {0} is outside of the world border")]
+    OutOfWorld(BlockPos),
+    #[error("Spawn point {0} is above the build limit")]
+    AboveSkyLimit(BlockPos),
+    #[error("Spawn point {0} is below the void")]
+    BelowVoid(BlockPos),
+}
+
 /// Represents a Minecraft world, containing entities, players, and the underlying level data.
 ///
 /// Each dimension (Overworld, Nether, End) typically has its own `World`.
@@ -180,8 +248,14 @@ pub struct World {
     /// A map of active entities within the world, keyed by their unique UUID.
     /// This does not include players.
     pub entities: ArcSwap<Vec<Arc<dyn EntityBase>>>,
+    /// The entities and players bucketed by chunk section, for box and radius queries.
+    pub entity_sections: EntitySectionIndex<Arc<dyn EntityBase>>,
     /// The world's scoreboard, used for tracking scores, objectives, and display information.
     pub scoreboard: Mutex<Scoreboard>,
+    /// Named random sequences, e.g. used by `/random`.
+    pub random_sequences: RandomSequences,
+    /// Callbacks scheduled with `/schedule`, shared by all worlds like the level data.
+    pub scheduled_callbacks: Arc<ScheduledCallbacks>,
     /// The world's worldborder, defining the playable area and controlling its expansion or contraction.
     pub worldborder: Mutex<Worldborder>,
     /// The world's time, including counting ticks for weather, time cycles, and statistics.
@@ -192,14 +266,32 @@ pub struct World {
     pub min_y: i32,
     /// The world's weather, including rain and thunder levels.
     pub weather: Mutex<Weather>,
+    /// Whether it rains heavily enough to put out burning entities. Updated once per tick
+    /// after the weather, so entities don't each have to lock it.
+    raining_hard: AtomicBool,
+    /// The chunk and radius of the ticket keeping the spawn chunks loaded.
+    spawn_chunk_ticket: AtomicCell<Option<(Vector2<i32>, u8)>>,
     /// Block Behaviour
     pub block_registry: Arc<BlockRegistry>,
     pub server: Weak<Server>,
     synced_block_event_queue: Mutex<Vec<BlockEvent>>,
     /// A map of unsent block changes, keyed by block position.
     unsent_block_changes: Mutex<HashMap<BlockPos, u16>>,
+    /// The latest unsent breaking stage and the breaker's UUID, keyed by breaking entity and block.
+    unsent_block_breaking: Mutex<HashMap<(i32, BlockPos), (uuid::Uuid, i8)>>,
     /// POI storage for fast portal lookups
     pub portal_poi: Mutex<portal::PortalPoiStorage>,
+    /// Block states read during the current tick, if enabled in the config.
+    block_state_cache: Option<BlockStateCache>,
+    /// Full chunk sends to players in this world, and the resends that were skipped.
+    pub chunk_send_stats: ChunkSendStats,
+    /// Bossbars only shown to nearby players, keyed by the bossbar's UUID.
+    ranged_bossbars: Mutex<HashMap<Uuid, RangedBossbar>>,
+    /// Chunks unloaded by the chunk system, turned into plugin events every tick.
+    unloaded_chunks: Receiver<Vector2<i32>>,
+    /// Chunks loaded by the chunk system, held back until their `ChunkLoad` event is fired.
+    /// `None` without a server, then chunks are added right away.
+    loaded_chunks: Option<Receiver<(Vector2<i32>, SyncChunk)>>,
 }
 
 impl PartialEq for World {
@@ -215,6 +307,7 @@ impl World {
     pub fn load(
         level: Arc<Level>,
         level_info: Arc<ArcSwap<LevelData>>,
+        scheduled_callbacks: Arc<ScheduledCallbacks>,
         dimension: Dimension,
         block_registry: Arc<BlockRegistry>,
         server: Weak<Server>,
@@ -225,24 +318,158 @@ impl World {
         // Load portal POI from disk (PoiStorage::new automatically loads from disk if files exist)
         let portal_poi = portal::PortalPoiStorage::new(&level.level_folder.root_folder);
 
-        Self {
+        let scoreboard = Scoreboard::load_nbt(&Self::scoreboard_path(&level)).unwrap_or_else(|e| {
+            error!("Failed to load scoreboard: {e}");
+            Scoreboard::default()
+        });
+        let random_sequences =
+            RandomSequences::load_nbt(&Self::random_sequences_path(&level), level.seed.0)
+                .unwrap_or_else(|e| {
+                    error!("Failed to load random sequences: {e}");
+                    RandomSequences::new(level.seed.0)
+                });
+
+        let block_state_cache = server
+            .upgrade()
+            .is_some_and(|server| server.advanced_config.world.block_state_cache)
+            .then(BlockStateCache::default);
+        let spawn_chunk_radius = server
+            .upgrade()
+            .map_or(0, |server| server.advanced_config.world.spawn_chunk_radius);
+        let unloaded_chunks = level.chunk_listener.add_unload_listener();
+        let loaded_chunks = server
+            .upgrade()
+            .map(|_| level.chunk_listener.set_load_gate());
+        // The game time is shared by all worlds and restored so scheduled callbacks still line up
+        let mut level_time = LevelTime::new();
+        level_time.world_age = level_info.load().time;
+
+        let world = Self {
             uuid: Uuid::new_v4(),
             level,
             level_info,
             players: ArcSwap::new(Arc::new(Vec::new())),
             entities: ArcSwap::new(Arc::new(Vec::new())),
-            scoreboard: Mutex::new(Scoreboard::default()),
+            entity_sections: EntitySectionIndex::default(),
+            scoreboard: Mutex::new(scoreboard),
+            random_sequences,
+            scheduled_callbacks,
             worldborder: Mutex::new(Worldborder::new(0.0, 0.0, 5.999_996_8E7, 0, 5, 300)),
-            level_time: Mutex::new(LevelTime::new()),
+            level_time: Mutex::new(level_time),
             dimension,
             weather: Mutex::new(Weather::new()),
+            raining_hard: AtomicBool::new(false),
+            spawn_chunk_ticket: AtomicCell::new(None),
             block_registry,
             sea_level: generation_settings.sea_level,
             min_y: i32::from(generation_settings.shape.min_y),
             synced_block_event_queue: Mutex::new(Vec::new()),
             unsent_block_changes: Mutex::new(HashMap::new()),
+            unsent_block_breaking: Mutex::new(HashMap::new()),
             portal_poi: Mutex::new(portal_poi),
+            block_state_cache,
+            chunk_send_stats: ChunkSendStats::default(),
+            ranged_bossbars: Mutex::new(HashMap::new()),
+            unloaded_chunks,
+            loaded_chunks,
             server,
+        };
+        world.update_spawn_chunks(spawn_chunk_radius);
+        world
+    }
+
+    /// Keeps the chunks within `radius` of the spawn point loaded and ticked even when no player
+    /// is near them, like vanilla's spawn chunks. Only the overworld has spawn chunks. Calling
+    /// this again moves them to the current spawn point, a radius of 0 unloads them.
+    pub fn update_spawn_chunks(&self, radius: u8) {
+        let ticket = (radius > 0 && self.dimension == Dimension::OVERWORLD).then(|| {
+            let info = self.level_info.load();
+            (
+                Vector2::new(info.spawn_x >> 4, info.spawn_z >> 4),
+                radius.min(MAX_SPAWN_CHUNK_RADIUS),
+            )
+        });
+        let old_ticket = self.spawn_chunk_ticket.swap(ticket);
+        if old_ticket == ticket {
+            return;
+        }
+
+        let mut chunk_loading = self.level.chunk_loading.lock().unwrap();
+        // The new ticket is added first so chunks both tickets cover aren't unloaded in between
+        if let Some((center, radius)) = ticket {
+            chunk_loading.add_ticket(center, ChunkLoading::get_level_from_view_distance(radius));
+        }
+        if let Some((center, radius)) = old_ticket {
+            chunk_loading.remove_ticket(center, ChunkLoading::get_level_from_view_distance(radius));
+        }
+        chunk_loading.send_change();
+    }
+
+    /// The chunks kept loaded around the spawn point, see [`Self::update_spawn_chunks`].
+    #[must_use]
+    pub fn get_spawn_chunks(&self) -> Vec<Vector2<i32>> {
+        let Some((center, radius)) = self.spawn_chunk_ticket.load() else {
+            return Vec::new();
+        };
+        let radius = i32::from(radius);
+        (-radius..=radius)
+            .flat_map(|x| (-radius..=radius).map(move |z| center.add_raw(x, z)))
+            .collect()
+    }
+
+    fn scoreboard_path(level: &Level) -> PathBuf {
+        level
+            .level_folder
+            .root_folder
+            .join("data")
+            .join("scoreboard.dat")
+    }
+
+    fn random_sequences_path(level: &Level) -> PathBuf {
+        level
+            .level_folder
+            .root_folder
+            .join("data")
+            .join("random_sequences.dat")
+    }
+
+    /// Returns the random sequence named `id`, creating it from the world seed if needed.
+    /// `None` gives a sequence that is not derived from the world seed.
+    pub fn get_random_sequence(&self, id: Option<&str>) -> RandomHandle {
+        self.random_sequences.get(id)
+    }
+
+    /// Schedules the callback bound to `id` to run in `delay_ticks` game ticks and returns the
+    /// game time it will run at. Handlers are looked up when the callback is due, so `id`
+    /// doesn't need one yet.
+    pub async fn schedule_named_callback(
+        &self,
+        id: ResourceLocation,
+        delay_ticks: i64,
+        mode: ScheduleMode,
+    ) -> Result<i64, ScheduleError> {
+        if delay_ticks <= 0 {
+            return Err(ScheduleError::SameTick);
+        }
+        let trigger_time = self.level_time.lock().await.world_age + delay_ticks;
+        self.scheduled_callbacks.schedule(id, trigger_time, mode)?;
+        Ok(trigger_time)
+    }
+
+    /// Runs the scheduled callbacks that are due. Only the overworld does this, like in vanilla.
+    async fn run_scheduled_callbacks(&self) {
+        if self.dimension != Dimension::OVERWORLD {
+            return;
+        }
+        let Some(server) = self.server.upgrade() else {
+            return;
+        };
+        let time = self.level_time.lock().await.world_age;
+        for (id, handler) in self.scheduled_callbacks.take_due(time) {
+            match handler {
+                Some(handler) => handler.run(&server, &id).await,
+                None => warn!("Dropping scheduled callback {id}: nothing is registered for it"),
+            }
         }
     }
 
@@ -253,6 +480,35 @@ impl World {
             .unwrap_or_default()
     }
 
+    /// Returns the hashed seed sent to Java clients in the login and respawn packets.
+    ///
+    /// If `hide_seed` is enabled, a random value that stays the same until the server restarts
+    /// is sent instead so clients can't learn anything about the real seed.
+    pub fn client_hashed_seed(&self) -> i64 {
+        static OBFUSCATED_SEED: LazyLock<i64> = LazyLock::new(|| rng().random());
+
+        if self.is_seed_hidden() {
+            *OBFUSCATED_SEED
+        } else {
+            biome::hash_seed(self.level.seed.0)
+        }
+    }
+
+    /// Returns the seed sent to Bedrock clients in the start game packet.
+    pub fn client_bedrock_seed(&self) -> u64 {
+        if self.is_seed_hidden() {
+            0
+        } else {
+            self.level.seed.0
+        }
+    }
+
+    fn is_seed_hidden(&self) -> bool {
+        self.server
+            .upgrade()
+            .is_some_and(|s| s.advanced_config.world.hide_seed)
+    }
+
     /// Get the world folder name (e.g., `world`, `world_nether`, `world_the_end`).
     /// Falls back to "world" if the name cannot be determined.
     pub fn get_world_name(&self) -> &str {
@@ -275,19 +531,47 @@ impl World {
             error!("Failed to save portal POI: {e}");
         }
 
+        let save_result = self
+            .scoreboard
+            .lock()
+            .await
+            .save_nbt(&Self::scoreboard_path(&self.level));
+        if let Err(e) = save_result {
+            error!("Failed to save scoreboard: {e}");
+        }
+
+        let save_result = self
+            .random_sequences
+            .save_nbt(&Self::random_sequences_path(&self.level));
+        if let Err(e) = save_result {
+            error!("Failed to save random sequences: {e}");
+        }
+
         self.level.shutdown().await;
     }
 
     async fn save_entity(&self, entity: &Arc<dyn EntityBase>) {
+        // Players are saved in playerdata instead
+        if entity.get_player().is_some() {
+            return;
+        }
+        let base_entity = entity.get_entity();
+        if !should_save_entity(
+            base_entity.removal_reason.load(),
+            base_entity.has_vehicle().await,
+        ) {
+            // Don't leave an older copy behind that would be loaded again
+            self.remove_entity_data(base_entity).await;
+            return;
+        }
+
         // First lets see if the entity was saved on an other chunk, and if the current chunk does not match we remove it
         // Otherwise we just update the nbt data
-        let base_entity = entity.get_entity();
         let uuid = base_entity.entity_uuid;
         let current_chunk_coordinate = base_entity.block_pos.load().chunk_position();
         let mut nbt = NbtCompound::new();
-        entity.write_nbt(&mut nbt).await;
+        write_entity_with_passengers(entity, &mut nbt).await;
         if let Some(old_chunk) = base_entity.first_loaded_chunk_position.load() {
-            let old_chunk = old_chunk.to_vec2_i32();
             let chunk = self.level.get_entity_chunk(old_chunk).await;
             chunk.mark_dirty(true);
             let mut data = chunk.data.lock().await;
@@ -304,19 +588,20 @@ impl World {
         let mut data = chunk.data.lock().await;
         data.insert(uuid, nbt);
         chunk.mark_dirty(true);
+        base_entity
+            .first_loaded_chunk_position
+            .store(Some(current_chunk_coordinate));
     }
 
     async fn remove_entity_data(&self, entity: &Entity) {
         let current_chunk_coordinate = entity.block_pos.load().chunk_position();
         if let Some(old_chunk) = entity.first_loaded_chunk_position.load() {
-            let old_chunk = old_chunk.to_vec2_i32();
             let chunk = self.level.get_entity_chunk(old_chunk).await;
             chunk.mark_dirty(true);
-            if old_chunk == current_chunk_coordinate {
-                chunk.data.lock().await.remove(&entity.entity_uuid);
-            } else {
+            chunk.data.lock().await.remove(&entity.entity_uuid);
+            if old_chunk != current_chunk_coordinate {
+                // The entity may have been saved in the chunk it is in now as well
                 let chunk = self.level.get_entity_chunk(current_chunk_coordinate).await;
-                // The chunk has changed, lets remove the entity from the old chunk
                 chunk.data.lock().await.remove(&entity.entity_uuid);
                 chunk.mark_dirty(true);
             }
@@ -342,18 +627,114 @@ impl World {
         .await;
     }
 
-    pub fn get_difficulty(&self, difficulty: Difficulty) {
-        let current_info = self.level_info.load();
-        let mut new_info = (**current_info).clone();
-        new_info.difficulty = difficulty;
-        self.level_info.store(Arc::new(new_info));
+    #[must_use]
+    pub fn get_difficulty(&self) -> Difficulty {
+        self.level_info.load().difficulty
+    }
+
+    /// Whether the difficulty can't be changed without forcing it, either because the world is
+    /// hardcore or because it was locked by a player.
+    #[must_use]
+    pub fn is_difficulty_locked(&self) -> bool {
+        self.level_info.load().difficulty_locked
+            || self
+                .server
+                .upgrade()
+                .is_some_and(|server| server.basic_config.hardcore)
+    }
+
+    /// Changes the difficulty, notifies all players and writes it to `level.dat`.
+    ///
+    /// Hardcore worlds always stay on hard. Unless `force_update` is set, nothing happens while
+    /// the difficulty is locked. Returns whether the difficulty was changed.
+    pub async fn set_difficulty(
+        self: &Arc<Self>,
+        difficulty: Difficulty,
+        force_update: bool,
+    ) -> bool {
+        if !force_update && self.is_difficulty_locked() {
+            return false;
+        }
+        let Some(server) = self.server.upgrade() else {
+            return false;
+        };
+
+        let difficulty = if server.basic_config.hardcore {
+            Difficulty::Hard
+        } else {
+            difficulty
+        };
+        let event = server
+            .plugin_manager
+            .fire(DifficultyChangeEvent::new(
+                self.clone(),
+                self.get_difficulty(),
+                difficulty,
+            ))
+            .await;
+        if event.cancelled {
+            return false;
+        }
+
+        self.level_info.rcu(|current_info| {
+            let mut new_info = (**current_info).clone();
+            new_info.difficulty = event.new;
+            new_info
+        });
+        let locked = self.level_info.load().difficulty_locked;
+
+        // The level data is shared by all worlds, so every player sees the new difficulty
+        server
+            .broadcast_packet_all(&CChangeDifficulty::new(event.new as u8, locked))
+            .await;
+        server.save_level_info();
+        true
     }
 
-    pub fn set_difficulty(&self, difficulty: Difficulty) {
-        let current_info = self.level_info.load();
-        let mut new_info = (**current_info).clone();
-        new_info.difficulty = difficulty;
-        self.level_info.store(Arc::new(new_info));
+    /// Sets the world spawn point and notifies all players in this world.
+    ///
+    /// The new spawn is written to `level.dat` immediately so it survives a crash.
+    pub async fn set_spawn_point(
+        &self,
+        position: BlockPos,
+        yaw: f32,
+        pitch: f32,
+    ) -> Result<(), SetSpawnPointError> {
+        if !Self::is_valid_horizontally(position) {
+            return Err(SetSpawnPointError::OutOfWorld(position));
+        }
+        if position.0.y > self.get_top_y() {
+            return Err(SetSpawnPointError::AboveSkyLimit(position));
+        }
+        if position.0.y < self.get_bottom_y() {
+            return Err(SetSpawnPointError::BelowVoid(position));
+        }
+
+        self.level_info.rcu(|current_info| {
+            let mut new_info = (**current_info).clone();
+            new_info.spawn_x = position.0.x;
+            new_info.spawn_y = position.0.y;
+            new_info.spawn_z = position.0.z;
+            new_info.spawn_yaw = yaw;
+            new_info.spawn_pitch = pitch;
+            new_info
+        });
+        if let Some((_, radius)) = self.spawn_chunk_ticket.load() {
+            self.update_spawn_chunks(radius);
+        }
+
+        self.broadcast_packet_all(&CPlayerSpawnPosition::new(
+            position,
+            yaw,
+            pitch,
+            self.dimension.minecraft_name.to_owned(),
+        ))
+        .await;
+
+        if let Some(server) = self.server.upgrade() {
+            server.save_level_info();
+        }
+        Ok(())
     }
 
     pub async fn add_synced_block_event(&self, pos: BlockPos, r#type: u8, data: u8) {
@@ -410,7 +791,30 @@ impl World {
         packet: &P,
         recipients_by_version: BTreeMap<MinecraftVersion, Vec<&JavaClient>>,
     ) {
+        for (recipients, packet_data) in Self::serialize_for_versions(packet, recipients_by_version)
+        {
+            for recipient in recipients {
+                recipient.enqueue_packet_data(packet_data.clone()).await;
+            }
+        }
+    }
+
+    /// Serializes `packet` once per version, skipping versions that don't support it.
+    fn serialize_for_versions<'a, P: ClientPacket>(
+        packet: &P,
+        recipients_by_version: BTreeMap<MinecraftVersion, Vec<&'a JavaClient>>,
+    ) -> Vec<(Vec<&'a JavaClient>, Bytes)> {
+        let mut serialized = Vec::with_capacity(recipients_by_version.len());
         for (version, recipients) in recipients_by_version {
+            if !P::supports_version(version) {
+                warn!(
+                    "Skipping packet {} for {} players on unsupported version {:?}",
+                    std::any::type_name::<P>(),
+                    recipients.len(),
+                    version
+                );
+                continue;
+            }
             let packet_data = match JavaClient::serialize_packet_for_version(packet, version) {
                 Ok(packet_data) => packet_data,
                 Err(err) => {
@@ -423,11 +827,9 @@ impl World {
                     continue;
                 }
             };
-
-            for recipient in recipients {
-                recipient.enqueue_packet_data(packet_data.clone()).await;
-            }
+            serialized.push((recipients, packet_data));
         }
+        serialized
     }
 
     /// Broadcasts a packet to all connected players within the world.
@@ -436,12 +838,57 @@ impl World {
     /// Sends the specified packet to every player currently logged in to the world.
     ///
     /// **Note:** This function acquires a lock on the `current_players` map, ensuring thread safety.
+    ///
+    /// The packet is queued with `enqueue_packet`, so each player receives it in order with
+    /// everything else queued for them. Packets sent with `send_packet_now` go through a priority
+    /// queue that is written first though, so one sent after this broadcast can still reach the
+    /// player before it. Use `broadcast_packet_now_all` when the order matters.
     pub async fn broadcast_packet_all<P: ClientPacket>(&self, packet: &P) {
         let players = self.players.load();
         let recipients_by_version = Self::collect_java_recipients_by_version(players.iter());
         Self::broadcast_java_grouped(packet, recipients_by_version).await;
     }
 
+    /// Like `broadcast_packet_all`, but sends the packet with `send_packet_now` and waits until it
+    /// has been flushed to every player. Anything sent to the players afterwards arrives after it,
+    /// while packets still waiting in their normal queue may arrive later. The players are waited
+    /// on at the same time, so a slow client only delays the return, not the other players.
+    pub async fn broadcast_packet_now_all<P: ClientPacket>(&self, packet: &P) {
+        let players = self.players.load();
+        let recipients_by_version = Self::collect_java_recipients_by_version(players.iter());
+        let sends = Self::serialize_for_versions(packet, recipients_by_version)
+            .into_iter()
+            .flat_map(|(recipients, packet_data)| {
+                recipients
+                    .into_iter()
+                    .map(move |recipient| recipient.send_packet_now_data(packet_data.clone()))
+            });
+        join_all(sends).await;
+    }
+
+    /// Broadcasts a packet to all connected players within the world without serializing it on the
+    /// calling task. Each client's outgoing packet task encodes the shared packet for its own
+    /// version, which keeps frequent broadcasts like entity movement cheap for the world tick.
+    pub async fn broadcast_packet_all_shared<P: ClientPacket + Send + Sync + 'static>(
+        &self,
+        packet: P,
+    ) {
+        let players = self.players.load();
+        let recipients_by_version = Self::collect_java_recipients_by_version(players.iter());
+        let packet = Arc::new(packet);
+        for (version, recipients) in recipients_by_version {
+            // These broadcasts happen every tick, so unsupported versions are skipped silently
+            if !P::supports_version(version) {
+                continue;
+            }
+            for recipient in recipients {
+                recipient.enqueue_packet_shared(packet.clone()).await;
+            }
+        }
+    }
+
+    /// Sends a message that isn't signed by a player to everyone in the world, see
+    /// [`chat::send_chat`].
     pub async fn broadcast_message(
         &self,
         message: &TextComponent,
@@ -449,11 +896,31 @@ impl World {
         chat_type: u8,
         target_name: Option<&TextComponent>,
     ) {
-        let be_packet = SText::new(message.clone().get_text(), sender_name.clone().get_text());
-        let je_packet =
-            CDisguisedChatMessage::new(message, (chat_type + 1).into(), sender_name, target_name);
+        let chat = chat::ChatMessage {
+            content: message,
+            chat_type,
+            sender_name,
+            target_name,
+            signed: None,
+            bedrock_message: message.clone().get_text(),
+            bedrock_source: sender_name.clone().get_text(),
+        };
+        chat::send_chat(&self.players.load(), &chat).await;
+    }
 
-        self.broadcast_editioned(&je_packet, &be_packet).await;
+    /// Sends a system message to every player in the world, whether on Java or Bedrock.
+    pub async fn broadcast_system_message(&self, message: &TextComponent) {
+        Self::send_system_message(&self.players.load(), message).await;
+    }
+
+    /// Sends a system message to each of `players`, whether on Java or Bedrock.
+    pub async fn send_system_message(players: &[Arc<Player>], message: &TextComponent) {
+        Self::broadcast_editioned_to(
+            players,
+            &CSystemChatMessage::new(message, false),
+            &SText::system_message(message.clone().get_text()),
+        )
+        .await;
     }
 
     // This should replace broadcast_packet_all at some point
@@ -462,7 +929,16 @@ impl World {
         je_packet: &J,
         be_packet: &B,
     ) {
-        let players = self.players.load();
+        Self::broadcast_editioned_to(&self.players.load(), je_packet, be_packet).await;
+    }
+
+    /// Sends the Java or Bedrock packet to each of `players`. Each packet is only serialized once
+    /// per version.
+    async fn broadcast_editioned_to<J: ClientPacket, B: BClientPacket>(
+        players: &[Arc<Player>],
+        je_packet: &J,
+        be_packet: &B,
+    ) {
         let je_recipients_by_version = Self::collect_java_recipients_by_version(players.iter());
         let mut be_recipients = Vec::new();
 
@@ -474,60 +950,24 @@ impl World {
 
         Self::broadcast_java_grouped(je_packet, je_recipients_by_version).await;
 
-        for recipient in be_recipients {
-            recipient.send_game_packet(be_packet).await;
+        if be_recipients.is_empty() {
+            return;
         }
-    }
 
-    pub async fn broadcast_secure_player_chat(
-        &self,
-        sender: &Arc<Player>,
-        chat_message: &SChatMessage,
-        decorated_message: &TextComponent,
-    ) {
-        let messages_sent: i32 = sender.chat_session.lock().await.messages_sent;
-        let sender_last_seen = {
-            let cache = sender.signature_cache.lock().await;
-            cache.last_seen.clone()
+        // Serialize the Bedrock packet body once and only apply the per-client framing
+        let packet_payload = match BedrockClient::serialize_game_packet_payload(be_packet) {
+            Ok(packet_payload) => packet_payload,
+            Err(err) => {
+                error!("Failed to serialize Bedrock packet for broadcast: {err}");
+                return;
+            }
         };
 
-        for recipient in self.players.load().iter() {
-            let messages_received: i32 = recipient.chat_session.lock().await.messages_received;
-            let packet = &CPlayerChatMessage::new(
-                VarInt(messages_received),
-                sender.gameprofile.id,
-                VarInt(messages_sent),
-                chat_message.signature.clone(),
-                chat_message.message.clone(),
-                chat_message.timestamp,
-                chat_message.salt,
-                sender_last_seen.indexed_for(recipient).await,
-                Some(decorated_message.clone()),
-                FilterType::PassThrough,
-                (RAW + 1).into(), // Custom registry chat_type with no sender name
-                TextComponent::text(""), // Not needed since we're injecting the name in the message for custom formatting
-                None,
-            );
-            recipient.client.enqueue_packet(packet).await;
-
+        for recipient in be_recipients {
             recipient
-                .signature_cache
-                .lock()
-                .await
-                .add_seen_signature(&chat_message.signature.clone().unwrap()); // Unwrap is safe because we check for None in validate_chat_message
-
-            if recipient.gameprofile.id != sender.gameprofile.id {
-                // Sender may update recipient on signatures recipient hasn't seen
-                recipient
-                    .signature_cache
-                    .lock()
-                    .await
-                    .cache_signatures(sender_last_seen.as_ref());
-            }
-            recipient.chat_session.lock().await.messages_received += 1;
+                .send_game_packet_payload(B::PACKET_ID as u16, &packet_payload)
+                .await;
         }
-
-        sender.chat_session.lock().await.messages_sent += 1;
     }
 
     /// Broadcasts a packet to all connected players within the world, excluding the specified players.
@@ -653,23 +1093,37 @@ impl World {
     pub async fn tick(self: &Arc<Self>, server: &Server) {
         let start = tokio::time::Instant::now();
 
+        if let Some(cache) = &self.block_state_cache {
+            cache.clear();
+        }
+
         // IMPORTANT: send flush_block_updates first to prevent issues with CAcknowledgeBlockChange
         self.flush_block_updates().await;
+        self.flush_light_updates().await;
         self.flush_synced_block_events().await;
+        self.flush_block_breaking().await;
         self.tick_environment().await;
+        self.run_scheduled_callbacks().await;
 
         let chunk_start = tokio::time::Instant::now();
         self.tick_chunks().await;
+        self.fire_chunk_loads(server).await;
+        self.fire_chunk_unloads(server).await;
         let chunk_elapsed = chunk_start.elapsed();
 
         let player_start = tokio::time::Instant::now();
         let players = self.players.load();
         let player_count = players.len();
         for player in players.iter() {
+            player.living_entity.entity.age.fetch_add(1, Relaxed);
             player.tick(server).await;
         }
         let player_elapsed = player_start.elapsed();
 
+        if self.level_time.lock().await.world_age % 20 == 0 {
+            self.update_ranged_bossbars().await;
+        }
+
         let entity_start = tokio::time::Instant::now();
         let entities_to_tick = self.entities.load();
         let entity_count = entities_to_tick.len();
@@ -699,13 +1153,15 @@ impl World {
         let total_elapsed = start.elapsed();
         if total_elapsed.as_millis() > 50 {
             debug!(
-                "Slow Tick [{}ms]: Chunks: {:?} | Players({}): {:?} | Entities({}): {:?}",
+                "Slow Tick [{}ms]: Chunks: {:?} | Players({}): {:?} | Entities({}): {:?} | Chunk sends: {} full, {} skipped",
                 total_elapsed.as_millis(),
                 chunk_elapsed,
                 player_count,
                 player_elapsed,
                 entity_count,
                 entity_elapsed,
+                self.chunk_send_stats.full_sends(),
+                self.chunk_send_stats.skipped_resends(),
             );
         }
     }
@@ -743,7 +1199,16 @@ impl World {
         }
     }
 
-    async fn tick_environment(&self) {
+    /// Updates the lighting for the blocks changed since the last flush. This runs every tick;
+    /// code that reads light levels right after changing blocks should call it first.
+    pub async fn flush_light_updates(&self) {
+        self.level
+            .light_engine
+            .flush_lighting_updates(&self.level)
+            .await;
+    }
+
+    async fn tick_environment(self: &Arc<Self>) {
         let mut level_time = self.level_time.lock().await;
         let (advance_time, advance_weather) = {
             let lock = self.level_info.load();
@@ -772,11 +1237,16 @@ impl World {
             if autosave > 0 && level_time.world_age % autosave == 0 {
                 self.level.should_save.store(true, Relaxed);
                 self.level.level_channel.notify();
+                // The level data, including the scheduled callbacks, is shared by all worlds
+                if self.dimension == Dimension::OVERWORLD
+                    && let Some(server) = self.server.upgrade()
+                {
+                    server.autosave_level_info(level_time.world_age);
+                }
             }
         }
 
-        let mut weather = self.weather.lock().await;
-        weather.tick_weather(self).await;
+        self.tick_weather().await;
 
         if self.should_skip_night() && level_time.is_night() {
             let time = level_time.time_of_day + 24000;
@@ -787,14 +1257,136 @@ impl World {
                 player.wake_up().await;
             }
 
-            if weather.weather_cycle_enabled && (weather.raining || weather.thundering) {
-                weather.reset_weather_cycle(self).await;
+            let reset_weather = {
+                let weather = self.weather.lock().await;
+                weather.weather_cycle_enabled && (weather.raining || weather.thundering)
+            };
+            if reset_weather {
+                self.reset_weather_cycle().await;
             }
         } else if level_time.world_age % 20 == 0 {
             level_time.send_time(self).await;
         }
     }
 
+    /// Shows `bar` only to the players within `range` blocks of `center`. Players walking into
+    /// or out of range are picked up every 20 ticks. Calling this again for the same bar updates
+    /// its area without resending it to players that already see it.
+    pub async fn broadcast_boss_bar_to_players_in_range(
+        &self,
+        bar: &Bossbar,
+        center: Vector3<f64>,
+        range: f64,
+    ) {
+        let mut ranged_bossbars = self.ranged_bossbars.lock().await;
+        let subscriptions = ranged_bossbars
+            .remove(&bar.uuid)
+            .map(|ranged| ranged.bossbar.subscriptions)
+            .unwrap_or_default();
+        let mut ranged = RangedBossbar {
+            bossbar: Bossbar {
+                subscriptions,
+                ..bar.clone()
+            },
+            center,
+            range,
+        };
+        self.update_ranged_bossbar(&mut ranged).await;
+        ranged_bossbars.insert(bar.uuid, ranged);
+    }
+
+    /// Stops showing a bossbar added with [`Self::broadcast_boss_bar_to_players_in_range`].
+    pub async fn remove_ranged_boss_bar(&self, uuid: &Uuid) {
+        let Some(ranged) = self.ranged_bossbars.lock().await.remove(uuid) else {
+            return;
+        };
+        let Some(server) = self.server.upgrade() else {
+            return;
+        };
+        for (player_uuid, subscribed) in ranged.bossbar.subscriptions {
+            if subscribed && let Some(player) = server.get_player_by_uuid(player_uuid) {
+                player.remove_bossbar(ranged.bossbar.uuid).await;
+            }
+        }
+    }
+
+    async fn update_ranged_bossbars(&self) {
+        let mut ranged_bossbars = self.ranged_bossbars.lock().await;
+        for ranged in ranged_bossbars.values_mut() {
+            self.update_ranged_bossbar(ranged).await;
+        }
+    }
+
+    /// Adds the bar for players that came into range and removes it for those that left.
+    async fn update_ranged_bossbar(&self, ranged: &mut RangedBossbar) {
+        let players = self.players.load();
+        for player in players.iter() {
+            let uuid = player.gameprofile.id;
+            let in_range = ranged.is_in_range(&player.living_entity.entity.pos.load());
+            let subscribed = ranged.bossbar.is_subscribed(&uuid);
+            if in_range && !subscribed {
+                player.send_bossbar(&ranged.bossbar).await;
+            } else if !in_range && subscribed {
+                player.remove_bossbar(ranged.bossbar.uuid).await;
+            }
+            ranged.bossbar.subscriptions.insert(uuid, in_range);
+        }
+
+        // Players that left this world are out of range too
+        let left: Vec<Uuid> = ranged
+            .bossbar
+            .subscriptions
+            .keys()
+            .filter(|uuid| !players.iter().any(|player| player.gameprofile.id == **uuid))
+            .copied()
+            .collect();
+        let server = self.server.upgrade();
+        for uuid in left {
+            if ranged.bossbar.subscriptions.remove(&uuid) == Some(true)
+                && let Some(player) = server.as_ref().and_then(|s| s.get_player_by_uuid(uuid))
+            {
+                player.remove_bossbar(ranged.bossbar.uuid).await;
+            }
+        }
+    }
+
+    /// Fires `ChunkLoad` for the chunks the chunk system loaded since the last tick, and adds the
+    /// ones whose event was not cancelled.
+    async fn fire_chunk_loads(self: &Arc<Self>, server: &Server) {
+        let Some(loaded_chunks) = &self.loaded_chunks else {
+            return;
+        };
+        while let Ok((position, chunk)) = loaded_chunks.try_recv() {
+            // It may have unloaded, or unloaded and loaded again, since
+            if !self
+                .level
+                .try_get_chunk(&position)
+                .is_some_and(|loaded| Arc::ptr_eq(&loaded, &chunk))
+            {
+                continue;
+            }
+            let event = server
+                .plugin_manager
+                .fire(ChunkLoad::new(self.clone(), position, chunk.clone()))
+                .await;
+            if event.cancelled {
+                self.level.chunk_listener.withhold_chunk(position);
+            } else {
+                self.level.chunk_listener.announce_chunk(position, &chunk);
+            }
+        }
+    }
+
+    /// Fires `ChunkUnload` for the chunks the chunk system unloaded since the last tick.
+    async fn fire_chunk_unloads(self: &Arc<Self>, server: &Server) {
+        while let Ok(position) = self.unloaded_chunks.try_recv() {
+            server
+                .plugin_manager
+                .fire(ChunkUnload::new(self.clone(), position))
+                .await;
+        }
+    }
+
     pub async fn tick_chunks(self: &Arc<Self>) {
         let tick_data = self.level.get_tick_data();
         for scheduled_tick in tick_data.block_ticks {
@@ -851,16 +1443,17 @@ impl World {
             }
         }
 
-        let mut spawn_state =
-            SpawnState::new(spawning_chunks_map.len() as i32, &self.entities, self).await; // TODO store it
+        let mut spawn_state = SpawnState::new(&spawning_chunks_map, &self.entities, self).await; // TODO store it
 
         // TODO gamerule this.spawnEnemies || this.spawnFriendlies
-        let spawn_passives = self.level_time.lock().await.time_of_day % 400 == 0;
+        let spawn_enemies = self.get_difficulty() != Difficulty::Peaceful;
+        // Animals persist, so they only get a chance to spawn every 20 seconds
+        let spawn_passives = self.level_time.lock().await.world_age % 400 == 0;
         let spawn_list: Vec<&'static MobCategory> =
             natural_spawner::get_filtered_spawning_categories(
                 &spawn_state,
                 true,
-                true,
+                spawn_enemies,
                 spawn_passives,
             );
 
@@ -894,14 +1487,12 @@ impl World {
                 for z in min.0.z..=max.0.z {
                     let pos = BlockPos::new(x, y, z);
 
-                    let (fluid, state) = self.get_fluid_and_fluid_state(&pos).await;
-
-                    if fluid.id != Fluid::EMPTY.id {
-                        let height = f64::from(state.height);
+                    let (fluid, _) = self.get_fluid_and_fluid_state(&pos).await;
 
-                        if height >= bounding_box.min.y {
-                            collisions.push(fluid);
-                        }
+                    if fluid.id != Fluid::EMPTY.id
+                        && self.get_fluid_height_at(&pos).await >= bounding_box.min.y
+                    {
+                        collisions.push(fluid);
                     }
                 }
             }
@@ -920,14 +1511,12 @@ impl World {
                 for z in min.0.z..=max.0.z {
                     let pos = BlockPos::new(x, y, z);
 
-                    let (fluid, state) = self.get_fluid_and_fluid_state(&pos).await;
+                    let (fluid, _) = self.get_fluid_and_fluid_state(&pos).await;
 
-                    if fluid.id != Fluid::EMPTY.id {
-                        let height = f64::from(state.height);
-
-                        if height >= bounding_box.min.y {
-                            return true;
-                        }
+                    if fluid.id != Fluid::EMPTY.id
+                        && self.get_fluid_height_at(&pos).await >= bounding_box.min.y
+                    {
+                        return true;
                     }
                 }
             }
@@ -1179,18 +1768,17 @@ impl World {
     ) {
         // this.level.tickThunder(chunk);
         //TODO check in simulation distance
-        let weather = self.weather.lock().await;
-        if weather.raining && weather.thundering && rng().random_range(0..100_000) == 0 {
+        // Only snapshot the weather so the lock is not held while spawning entities.
+        let (raining, thundering) = {
+            let weather = self.weather.lock().await;
+            (weather.raining, weather.thundering)
+        };
+        if raining && thundering && rng().random_range(0..100_000) == 0 {
             let rand_value = rng().random::<i32>() >> 2;
             let delta = Vector3::new(rand_value & 15, rand_value >> 16 & 15, rand_value >> 8 & 15);
             let random_pos = Vector3::new(
                 chunk_pos.x << 4,
-                chunk.heightmap.lock().unwrap().get(
-                    MotionBlocking,
-                    chunk_pos.x << 4,
-                    chunk_pos.y << 4,
-                    self.min_y,
-                ),
+                chunk.get_height(MotionBlocking, chunk_pos.x << 4, chunk_pos.y << 4),
                 chunk_pos.y << 4,
             )
             .add(&delta);
@@ -1221,7 +1809,12 @@ impl World {
                 self.spawn_entity(Arc::new(entity)).await;
             }
         }
-        drop(weather);
+
+        if rng().random_range(0..16) == 0 {
+            let x = (chunk_pos.x << 4) + rng().random_range(0..16);
+            let z = (chunk_pos.y << 4) + rng().random_range(0..16);
+            self.tick_precipitation(x, z).await;
+        }
 
         if spawn_list.is_empty() {
             return;
@@ -1230,9 +1823,171 @@ impl World {
         spawn_for_chunk(self, chunk_pos, chunk, spawn_state, spawn_list).await;
     }
 
+    /// Freezes water and lets snow fall at the top of a column, like vanilla's `tickPrecipitation`.
+    async fn tick_precipitation(self: &Arc<Self>, x: i32, z: i32) {
+        // Both positions come from the heightmap, so they can see the sky
+        let top = BlockPos::new(x, self.get_motion_blocking_height(x, z).await, z);
+        let below = top.down();
+        let biome = self.level.get_rough_biome(&top).await;
+
+        if self.is_cold_enough_to_form(biome, &below).await && self.can_water_freeze(&below).await {
+            self.set_block_state(&below, Block::ICE.default_state.id, BlockFlags::NOTIFY_ALL)
+                .await;
+            self.block_registry
+                .on_block_formed(
+                    self,
+                    &below,
+                    &Block::ICE,
+                    FormCause {
+                        by_freezing: true,
+                        by_precipitation: false,
+                    },
+                )
+                .await;
+        }
+
+        let raining = self.weather.lock().await.raining;
+        let max_layers = self
+            .level_info
+            .load()
+            .game_rules
+            .max_snow_accumulation_height
+            .clamp(0, 8) as u16;
+        if !raining
+            || max_layers == 0
+            || !biome.weather.has_precipitation()
+            || !self.is_cold_enough_to_form(biome, &top).await
+        {
+            return;
+        }
+
+        let (block, state_id) = self.get_block_and_state_id(&top).await;
+        let snow = if block == &Block::SNOW {
+            let mut props = SnowLikeProperties::from_state_id(state_id, &Block::SNOW);
+            let layers = props.layers.to_index() + 1;
+            if layers >= max_layers {
+                return;
+            }
+            props.layers = Integer1To8::from_index(layers);
+            props.to_state_id(&Block::SNOW)
+        } else if is_air(state_id) && snow::can_place_at(self.as_ref(), &top).await {
+            Block::SNOW.default_state.id
+        } else {
+            return;
+        };
+        self.set_block_state(&top, snow, BlockFlags::NOTIFY_ALL)
+            .await;
+        self.block_registry
+            .on_block_formed(
+                self,
+                &top,
+                &Block::SNOW,
+                FormCause {
+                    by_freezing: false,
+                    by_precipitation: true,
+                },
+            )
+            .await;
+    }
+
+    /// Vanilla: `Biome.shouldFreeze` with `mustBeAtEdge`. Only still water next to something other
+    /// than water freezes, so ice grows in from the shore instead of covering open water at once.
+    async fn can_water_freeze(&self, pos: &BlockPos) -> bool {
+        if self.get_block_state_id(pos).await != Block::WATER.default_state.id {
+            return false;
+        }
+        for direction in BlockDirection::horizontal() {
+            let neighbor = pos.offset(direction.to_offset());
+            if !self.get_fluid(&neighbor).await.has_tag(&MINECRAFT_WATER) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Ice and snow only form where the biome is colder than 0.15 and there is little block light.
+    async fn is_cold_enough_to_form(
+        &self,
+        biome: &pumpkin_data::biome::Biome,
+        pos: &BlockPos,
+    ) -> bool {
+        if !self.is_in_height_limit(pos.0.y) {
+            return false;
+        }
+        let temperature = biome.weather.compute_temperature(
+            f64::from(pos.0.x),
+            pos.0.y,
+            f64::from(pos.0.z),
+            self.sea_level,
+        );
+        if temperature >= 0.15 {
+            return false;
+        }
+        self.get_light_level(pos, LightLayer::Block).await < 10
+    }
+
+    /// Gets the light level of `layer` at `pos`, without the darkening of the time of day. Positions
+    /// outside of the world are dark.
+    pub async fn get_light_level(&self, pos: &BlockPos, layer: LightLayer) -> u8 {
+        let light_engine = &self.level.light_engine;
+        match layer {
+            LightLayer::Sky => light_engine.get_sky_light_level(&self.level, pos).await,
+            LightLayer::Block => light_engine.get_block_light_level(&self.level, pos).await,
+        }
+        .unwrap_or(0)
+    }
+
+    /// Checks whether an entity of the given type may naturally spawn at `pos`.
+    ///
+    /// This considers the light level, the block below and the two blocks the entity would occupy,
+    /// dangerous blocks and the fluid required by water and lava mobs.
+    pub async fn can_entities_spawn_at(&self, pos: BlockPos, entity_type: &EntityType) -> bool {
+        match entity_type.spawn_restriction.location {
+            SpawnLocation::InLava => {
+                if !self.get_fluid(&pos).await.has_tag(&MINECRAFT_LAVA) {
+                    return false;
+                }
+                // Striders spawn in lava that is open to the air above
+                if entity_type == &EntityType::STRIDER {
+                    let mut surface = pos.up();
+                    while self.get_fluid(&surface).await.has_tag(&MINECRAFT_LAVA) {
+                        surface = surface.up();
+                    }
+                    if !self.get_block_state(&surface).await.is_air() {
+                        return false;
+                    }
+                }
+            }
+            SpawnLocation::InWater => {
+                // TODO !level.getBlockState(blockPos).isRedstoneConductor(level, blockPos)
+                if !self.get_fluid(&pos).await.has_tag(&MINECRAFT_WATER) {
+                    return false;
+                }
+            }
+            SpawnLocation::OnGround | SpawnLocation::Unrestricted => {}
+        }
+
+        let surroundings = SpawnSurroundings {
+            below: self.get_block_state(&pos.down()).await,
+            at: self.get_block_state(&pos).await,
+            above: self.get_block_state(&pos.up()).await,
+            block_light: self.get_light_level(&pos, LightLayer::Block).await,
+            sky_light: self.get_light_level(&pos, LightLayer::Sky).await,
+            sky_darkness: self.get_ambient_darkness().await,
+            monster_block_light_limit: if self.dimension == Dimension::THE_NETHER {
+                15
+            } else {
+                0
+            },
+            y: pos.0.y,
+            sea_level: self.sea_level,
+        };
+        surroundings.allows(entity_type)
+    }
+
     /// Gets the y position of the first non air block from the top down
     pub async fn get_top_block(&self, position: Vector2<i32>) -> i32 {
-        for y in (self.dimension.min_y..self.dimension.height).rev() {
+        for y in (self.get_bottom_y()..=self.get_top_y()).rev() {
             let pos = BlockPos::new(position.x, y, position.y);
             let block = self.get_block_state(&pos).await;
             if block.is_air() {
@@ -1243,20 +1998,205 @@ impl World {
         self.dimension.min_y
     }
 
+    /// Vanilla: `ServerPlayerEntity.getWorldSpawnPos()`, looks for a column within `radius` of
+    /// `center` that has solid ground and room for a player. The search starts at a random
+    /// column, so players don't all spawn on the same block. At most `MAX_SPAWN_CANDIDATES`
+    /// columns are checked, if none of them is safe a platform is built at the center instead.
+    pub async fn find_safe_spawn_position(
+        self: &Arc<Self>,
+        center: BlockPos,
+        radius: i32,
+    ) -> Vector3<f64> {
+        let side = u64::from(radius.max(0).unsigned_abs()) * 2 + 1;
+        let columns = (side * side).min(i32::MAX as u64) as i32;
+        let start = rng().random_range(0..columns);
+
+        for (offset_x, offset_z) in
+            spawn_search_offsets(radius.max(0), start).take(MAX_SPAWN_CANDIDATES)
+        {
+            let x = center.0.x + offset_x;
+            let z = center.0.z + offset_z;
+            if let Some(position) = self.safe_spawn_in_column(x, z).await {
+                return position;
+            }
+        }
+
+        warn!(
+            "Found no safe spawn within {radius} blocks of {center}, building a platform instead"
+        );
+        self.force_spawn_platform(center).await
+    }
+
+    /// The position a player can spawn at in the column, if its surface is safe to stand on.
+    async fn safe_spawn_in_column(&self, x: i32, z: i32) -> Option<Vector3<f64>> {
+        let y = self.find_spawn_y(x, z).await?;
+        let below = self.get_block_state(&BlockPos::new(x, y - 1, z)).await;
+        let feet = self.get_block_state(&BlockPos::new(x, y, z)).await;
+        let head = self.get_block_state(&BlockPos::new(x, y + 1, z)).await;
+        if !is_safe_spawn(below, feet, head) {
+            return None;
+        }
+
+        let position = Vector3::new(f64::from(x) + 0.5, f64::from(y), f64::from(z) + 0.5);
+        let dimensions = Entity::get_entity_dimensions(EntityPose::Standing);
+        let bounding_box =
+            BoundingBox::new_from_pos(position.x, position.y, position.z, &dimensions);
+        self.is_space_empty(bounding_box.contract_all(1.0E-7))
+            .await
+            .then_some(position)
+    }
+
+    /// Vanilla: `SpawnLocating.findOverworldSpawn()`, returns the y above the highest block with
+    /// a solid top face in the column, unless the column is covered by a fluid. Leaves are
+    /// skipped, so players spawn below trees instead of on top of them.
+    async fn find_spawn_y(&self, x: i32, z: i32) -> Option<i32> {
+        let top = if self.dimension.has_ceiling {
+            self.sea_level
+        } else {
+            self.level
+                .get_chunk(Vector2::new(x >> 4, z >> 4))
+                .await
+                .get_height(MotionBlockingNoLeaves, x, z)
+        };
+        if top < self.get_bottom_y() {
+            return None;
+        }
+
+        for y in (self.get_bottom_y()..=top + 1).rev() {
+            let state = self.get_block_state(&BlockPos::new(x, y, z)).await;
+            if state.is_liquid() {
+                return None;
+            }
+            if state.is_side_solid(BlockDirection::Up) {
+                return Some(y + 1);
+            }
+        }
+        None
+    }
+
+    /// Builds a small stone platform with room for a player on top of the center column, for
+    /// when no column near the spawn is safe, e.g. in the middle of an ocean.
+    async fn force_spawn_platform(self: &Arc<Self>, center: BlockPos) -> Vector3<f64> {
+        let top = if self.dimension.has_ceiling {
+            self.sea_level
+        } else {
+            self.get_top_block(Vector2::new(center.0.x, center.0.z))
+                .await
+        };
+        let floor_y = top.clamp(self.get_bottom_y(), self.get_top_y() - 2);
+
+        for offset_x in -1..=1 {
+            for offset_z in -1..=1 {
+                let pos = BlockPos::new(center.0.x + offset_x, floor_y, center.0.z + offset_z);
+                self.set_block_state(&pos, Block::STONE.default_state.id, BlockFlags::NOTIFY_ALL)
+                    .await;
+            }
+        }
+        for y in floor_y + 1..=floor_y + 2 {
+            let pos = BlockPos::new(center.0.x, y, center.0.z);
+            self.set_block_state(&pos, Block::AIR.default_state.id, BlockFlags::NOTIFY_ALL)
+                .await;
+        }
+
+        Vector3::new(
+            f64::from(center.0.x) + 0.5,
+            f64::from(floor_y + 1),
+            f64::from(center.0.z) + 0.5,
+        )
+    }
+
+    /// Vanilla: `ServerWorld.setInitialSpawn()`, picks the spawn point of a new world. Biomes
+    /// are sampled outwards from the origin until one is not an ocean, then a safe column is
+    /// searched for there.
+    pub async fn find_initial_spawn(self: &Arc<Self>) {
+        let mut center = BlockPos::new(0, self.sea_level, 0);
+        for (offset_x, offset_z) in ring_offsets(SPAWN_BIOME_SAMPLE_RADIUS) {
+            let pos = BlockPos::new(
+                offset_x * SPAWN_BIOME_SAMPLE_SPACING,
+                self.sea_level,
+                offset_z * SPAWN_BIOME_SAMPLE_SPACING,
+            );
+            if !self
+                .level
+                .get_rough_biome(&pos)
+                .await
+                .has_tag(&MINECRAFT_IS_OCEAN)
+            {
+                center = pos;
+                break;
+            }
+        }
+
+        let radius = self.level_info.load().game_rules.respawn_radius as i32;
+        let position = self.find_safe_spawn_position(center, radius).await;
+        if let Err(error) = self
+            .set_spawn_point(BlockPos::floored_v(position), 0.0, 0.0)
+            .await
+        {
+            warn!("Failed to set the initial world spawn: {error}");
+        }
+    }
+
+    /// Whether nothing blocks motion above `pos`, so rain and snow fall on it.
+    pub async fn can_see_sky(&self, pos: &BlockPos) -> bool {
+        pos.0.y >= self.get_motion_blocking_height(pos.0.x, pos.0.z).await
+    }
+
     /// Gets the `MOTION_BLOCKING` heightmap value for a given XZ position.
     pub async fn get_motion_blocking_height(&self, x: i32, z: i32) -> i32 {
         let chunk_pos = Vector2::new(x >> 4, z >> 4);
         let chunk = self.level.get_chunk(chunk_pos).await;
-        chunk
-            .heightmap
-            .lock()
-            .unwrap()
-            .get(MotionBlocking, x, z, self.min_y)
+        chunk.get_height(MotionBlocking, x, z)
+    }
+
+    /// Whether it rains heavily enough to put out burning entities, as of the last weather tick.
+    #[must_use]
+    pub fn is_raining_hard(&self) -> bool {
+        self.raining_hard.load(Relaxed)
+    }
+
+    /// Like [`World::can_see_sky`], but returns `None` instead of loading the chunk.
+    #[must_use]
+    pub fn can_see_sky_if_loaded(&self, pos: &BlockPos) -> Option<bool> {
+        let chunk = self.get_chunk_if_loaded(pos.chunk_position())?;
+        Some(pos.0.y >= chunk.get_height(MotionBlocking, pos.0.x, pos.0.z))
+    }
+
+    /// Gets the biome at `pos` if its chunk is loaded, without loading it.
+    #[must_use]
+    pub fn get_rough_biome_if_loaded(&self, pos: &BlockPos) -> Option<&'static Biome> {
+        let (chunk_pos, relative) = pos.chunk_and_chunk_relative_position();
+        let chunk = self.get_chunk_if_loaded(chunk_pos)?;
+        let id = chunk.section.get_rough_biome_absolute_y(
+            relative.x as usize,
+            relative.y,
+            relative.z as usize,
+        )?;
+        Biome::from_id(id)
+    }
+
+    /// Gets the chunk at `pos` if it is loaded. Unlike [`Level::get_chunk`] this never loads or
+    /// generates it, so read-only queries like pathfinding don't pull in new chunks.
+    #[must_use]
+    pub fn get_chunk_if_loaded(&self, pos: Vector2<i32>) -> Option<Arc<ChunkData>> {
+        self.level.try_get_chunk(&pos)
+    }
+
+    /// Returns a copy of a heightmap of a loaded chunk, one absolute height per column indexed by
+    /// `z * 16 + x`. Returns `None` instead of loading the chunk if it is not loaded.
+    #[must_use]
+    pub fn get_heightmap_snapshot(
+        &self,
+        chunk: Vector2<i32>,
+        kind: ChunkHeightmapType,
+    ) -> Option<Vec<i16>> {
+        self.get_chunk_if_loaded(chunk)
+            .map(|chunk| chunk.heightmap_snapshot(kind))
     }
 
     #[allow(clippy::too_many_lines)]
     pub async fn spawn_bedrock_player(
-        &self,
+        self: &Arc<Self>,
         base_config: &BasicConfiguration,
         player: Arc<Player>,
         server: &Server,
@@ -1271,19 +2211,15 @@ impl World {
 
             (position, yaw, pitch)
         } else {
-            let spawn_position = Vector2::new(level_info.spawn_x, level_info.spawn_z);
-            let pos_y = self.get_top_block(spawn_position).await + 1; // +1 to spawn on top of the block
-
-            let position = Vector3::new(
-                f64::from(level_info.spawn_x) + 0.5,
-                f64::from(pos_y),
-                f64::from(level_info.spawn_z) + 0.5,
-            );
+            let spawn = BlockPos::new(level_info.spawn_x, level_info.spawn_y, level_info.spawn_z);
+            let position = self
+                .find_safe_spawn_position(spawn, level_info.game_rules.respawn_radius as i32)
+                .await;
             (position, level_info.spawn_yaw, level_info.spawn_pitch)
         };
         // Todo make the data less spread
         let level_settings = LevelSettings {
-            seed: self.level.seed.0,
+            seed: self.client_bedrock_seed(),
             spawn_biome_type: 0,
             custom_biome_name: String::new(),
             dimension: VarInt(0),
@@ -1337,7 +2273,7 @@ impl World {
             emote_chat_muted: false,
             game_version: CURRENT_BEDROCK_MC_VERSION.into(),
             limited_world_width: 0,
-            limited_world_height: 0,
+            limited_world_height: self.dimension.height,
             new_nether: true,
             edu_shared_uri_button_name: String::new(),
             edu_shared_uri_link_uri: String::new(),
@@ -1478,27 +2414,11 @@ impl World {
                             name: "minecraft:air".to_string(),
                             modifiers_list_size: VarUInt(0),
                         },
-                        Attribute {
-                            min_value: 0.0,
-                            max_value: 20.0,
-                            current_value: 20.0,
-                            default_min_value: 0.0,
-                            default_max_value: 20.0,
-                            default_value: 20.0,
-                            name: "minecraft:health".to_string(),
-                            modifiers_list_size: VarUInt(0),
-                        },
-                        Attribute {
-                            min_value: 0.0,
-                            max_value: 20.0,
-                            current_value: 20.0,
-                            default_min_value: 0.0,
-                            default_max_value: 20.0,
-                            default_value: 20.0,
-                            name: "minecraft:player.hunger".to_string(),
-                            modifiers_list_size: VarUInt(0),
-                        },
-                    ],
+                    ]
+                    .into_iter()
+                    // Health, hunger and experience are kept up to date from now on
+                    .chain(player.bedrock_attributes.take_changed(&player))
+                    .collect(),
                     player_tick: VarULong(0),
                 },
                 &mut frame_set,
@@ -1512,7 +2432,7 @@ impl World {
 
     #[expect(clippy::too_many_lines)]
     pub async fn spawn_java_player(
-        &self,
+        self: &Arc<Self>,
         base_config: &BasicConfiguration,
         player: &Arc<Player>,
         server: &Server,
@@ -1546,16 +2466,13 @@ impl World {
                 false,
                 (self.dimension.id).into(),
                 ResourceLocation::from(self.dimension.minecraft_name),
-                biome::hash_seed(self.level.seed.0), // seed
+                self.client_hashed_seed(),
                 gamemode as u8,
-                player
-                    .previous_gamemode
-                    .load()
-                    .map_or(-1, |gamemode| gamemode as i8),
+                player.previous_gamemode_id(),
                 false,
                 false,
                 None,
-                VarInt(player.get_entity().portal_cooldown.load(Ordering::Relaxed) as i32),
+                VarInt(player.get_entity().get_portal_cooldown() as i32),
                 self.sea_level.into(),
                 // This should stay true even when reports are disabled.
                 // It prevents the annoying popup when joining the server.
@@ -1571,6 +2488,9 @@ impl World {
 
         // Difficulty of the world
         player.send_difficulty_update().await;
+
+        // Item sets for furnace and smithing table slots, and the stonecutter recipes
+        client.enqueue_packet(&CUpdateRecipes).await;
         {
             let command_dispatcher = server.command_dispatcher.read().await;
 
@@ -1589,16 +2509,17 @@ impl World {
 
             (position, yaw, pitch)
         } else {
-            let info = &self.level_info.load();
-            let spawn_position = Vector2::new(info.spawn_x, info.spawn_z);
-            let pos_y = self.get_top_block(spawn_position).await + 1; // +1 to spawn on top of the block
-
-            let position = Vector3::new(
-                f64::from(info.spawn_x) + 0.5,
-                f64::from(pos_y),
-                f64::from(info.spawn_z) + 0.5,
-            );
-            (position, info.spawn_yaw, info.spawn_pitch)
+            let (spawn, radius, yaw, pitch) = {
+                let info = self.level_info.load();
+                (
+                    BlockPos::new(info.spawn_x, info.spawn_y, info.spawn_z),
+                    info.game_rules.respawn_radius as i32,
+                    info.spawn_yaw,
+                    info.spawn_pitch,
+                )
+            };
+            let position = self.find_safe_spawn_position(spawn, radius).await;
+            (position, yaw, pitch)
         };
 
         let velocity = player.living_entity.entity.velocity.load();
@@ -1610,9 +2531,10 @@ impl World {
 
         let gameprofile = &player.gameprofile;
         // Firstly, send an info update to our new player, so they can see their skin
-        // and also send their info to everyone else.
+        // and also send their info to everyone else. The teleport above and the game event below
+        // are sent immediately, so this has to be as well to stay between them.
         debug!("Broadcasting player info for {}", player.gameprofile.name);
-        self.broadcast_packet_all(&CPlayerInfoUpdate::new(
+        self.broadcast_packet_now_all(&CPlayerInfoUpdate::new(
             (PlayerInfoFlags::ADD_PLAYER
                 | PlayerInfoFlags::UPDATE_GAME_MODE
                 | PlayerInfoFlags::UPDATE_LISTED)
@@ -1798,6 +2720,7 @@ impl World {
             .await;
 
         self.worldborder.lock().await.init_client(client).await;
+        self.scoreboard.lock().await.init_client(client).await;
 
         // Sends initial time
         player.send_time(self).await;
@@ -1932,7 +2855,19 @@ impl World {
     }
 
     pub async fn explode(self: &Arc<Self>, position: Vector3<f64>, power: f32) {
-        let explosion = Explosion::new(power, position);
+        self.explode_attributed(position, power, None, None).await;
+    }
+
+    /// Creates an explosion whose damage is attributed to `source` (the exploding entity)
+    /// and `igniter` (the player who caused it), for death messages and plugins.
+    pub async fn explode_attributed(
+        self: &Arc<Self>,
+        position: Vector3<f64>,
+        power: f32,
+        source: Option<Arc<dyn EntityBase>>,
+        igniter: Option<Arc<Player>>,
+    ) {
+        let explosion = Explosion::new(power, position, source, igniter);
         let block_count = explosion.explode(self).await;
         let particle = if power < 2.0 {
             Particle::Explosion
@@ -1971,11 +2906,11 @@ impl World {
         let data_kept = u8::from(alive);
 
         // Copy spawn info from level_info to avoid holding lock across await
-        let (spawn_x, spawn_z, spawn_yaw, spawn_pitch, keep_inventory) = {
+        let (world_spawn, respawn_radius, spawn_yaw, spawn_pitch, keep_inventory) = {
             let info = self.level_info.load();
             (
-                info.spawn_x,
-                info.spawn_z,
+                BlockPos::new(info.spawn_x, info.spawn_y, info.spawn_z),
+                info.game_rules.respawn_radius as i32,
                 info.spawn_yaw,
                 info.spawn_pitch,
                 info.game_rules.keep_inventory,
@@ -1998,17 +2933,9 @@ impl World {
                     .send_packet_now(&CGameEvent::new(GameEvent::NoRespawnBlockAvailable, 0.0))
                     .await;
 
-                // FIXME: This spawn position calculation is incorrect. Should use vanilla's
-                // proper spawn position calculation (see #1381). The y-level calculation
-                // needs to account for spawn radius and find a safe spawn position.
-                let top = self.get_top_block(Vector2::new(spawn_x, spawn_z)).await;
-
                 (
-                    Vector3::new(
-                        f64::from(spawn_x) + 0.5,
-                        (top + 1).into(),
-                        f64::from(spawn_z) + 0.5,
-                    ),
+                    self.find_safe_spawn_position(world_spawn, respawn_radius)
+                        .await,
                     spawn_yaw,
                     spawn_pitch,
                     self.dimension,
@@ -2049,6 +2976,9 @@ impl World {
                 new_list.push(player.clone());
                 new_list
             });
+            new_world
+                .entity_sections
+                .insert(player.clone() as Arc<dyn EntityBase>);
 
             // Update chunk manager to target world
             player
@@ -2067,14 +2997,9 @@ impl World {
                 "Target world {:?} not found, using world spawn in {:?}",
                 respawn_dimension, self.dimension
             );
-            // FIXME: This spawn position calculation is incorrect. Should use vanilla's
-            // proper spawn position calculation (see #1381).
-            let top = self.get_top_block(Vector2::new(spawn_x, spawn_z)).await;
-            let fallback_pos = Vector3::new(
-                f64::from(spawn_x) + 0.5,
-                (top + 1).into(),
-                f64::from(spawn_z) + 0.5,
-            );
+            let fallback_pos = self
+                .find_safe_spawn_position(world_spawn, respawn_radius)
+                .await;
             (self.as_ref(), fallback_pos)
         } else {
             (self.as_ref(), position)
@@ -2086,13 +3011,13 @@ impl World {
             .send_packet_now(&CRespawn::new(
                 (target_world.dimension.id).into(),
                 ResourceLocation::from(target_world.dimension.minecraft_name),
-                biome::hash_seed(target_world.level.seed.0),
+                target_world.client_hashed_seed(),
                 player.gamemode.load() as u8,
-                player.gamemode.load() as i8,
+                player.previous_gamemode_id(),
                 false,
                 false,
                 Some((death_dimension, death_location)),
-                VarInt(player.get_entity().portal_cooldown.load(Ordering::Relaxed) as i32),
+                VarInt(player.get_entity().get_portal_cooldown() as i32),
                 target_world.sea_level.into(),
                 data_kept,
             ))
@@ -2122,9 +3047,9 @@ impl World {
 
         player.hunger_manager.restart();
 
+        // The inventory and experience were already dropped on death
         if !keep_inventory {
-            player.set_experience(0, 0.0, 0).await;
-            player.inventory.clear().await;
+            player.score.store(0, Relaxed);
         }
 
         // Set entity position BEFORE loading chunks, so chunks load at the right location
@@ -2141,11 +3066,13 @@ impl World {
             .await;
 
         // Ensure at least the center chunk is sent synchronously before teleport.
+        // This skips the chunk manager, as the client always needs it, even if it was sent before.
         if let crate::net::ClientPlatform::Java(java_client) = &player.client {
             let center_chunk = player.living_entity.entity.chunk_pos.load();
             let chunk = target_world.level.get_chunk(center_chunk).await;
             java_client.send_packet_now(&CChunkBatchStart).await;
             java_client.send_packet_now(&CChunkData(&chunk)).await;
+            target_world.chunk_send_stats.record_full_send();
             java_client
                 .send_packet_now(&CChunkBatchEnd::new(1u16))
                 .await;
@@ -2171,21 +3098,46 @@ impl World {
             .count();
         drop(players);
 
-        if player_count == 0 {
-            return false;
-        }
+        enough_players_sleeping(sleeping_player_count, player_count, self.sleep_percentage())
+    }
 
-        let sleep_percentage = self
-            .level_info
-            .load()
-            .game_rules
-            .players_sleeping_percentage
-            .clamp(0, 100);
-        let required_sleeping =
-            ((player_count as f64 * sleep_percentage as f64) / 100.0).ceil() as usize;
-        let required_sleeping = required_sleeping.max(1);
+    /// The percentage of players that have to sleep to skip the night in this world.
+    pub fn sleep_percentage(&self) -> i32 {
+        let level_info = self.level_info.load();
+        level_info
+            .sleep_percentages
+            .get(self.dimension.minecraft_name)
+            .copied()
+            .unwrap_or_else(|| {
+                level_info
+                    .game_rules
+                    .players_sleeping_percentage
+                    .clamp(0, 100) as i32
+            })
+    }
+
+    /// Makes this world use `pct` instead of the `playersSleepingPercentage` game rule, which
+    /// is shared by all worlds. The override is kept in the level data, so it is saved to
+    /// `level.dat`.
+    pub fn set_sleep_percentage(&self, pct: i32) {
+        self.level_info.rcu(|current_info| {
+            let mut new_info = (**current_info).clone();
+            new_info
+                .sleep_percentages
+                .insert(self.dimension.minecraft_name.to_owned(), pct);
+            new_info
+        });
+    }
 
-        sleeping_player_count >= required_sleeping
+    /// Makes this world follow the `playersSleepingPercentage` game rule again.
+    pub fn reset_sleep_percentage(&self) {
+        self.level_info.rcu(|current_info| {
+            let mut new_info = (**current_info).clone();
+            new_info
+                .sleep_percentages
+                .remove(self.dimension.minecraft_name);
+            new_info
+        });
     }
 
     // NOTE: This function doesn't actually await on anything, it just spawns two tokio tasks
@@ -2228,7 +3180,10 @@ impl World {
                 };
                 let position = Vector2::new(chunk.x, chunk.z);
 
-                let chunk = if level.is_chunk_watched(&position) {
+                // Entities of a chunk whose load was cancelled stay in it, like when unwatched
+                let chunk = if level.is_chunk_watched(&position)
+                    && !level.chunk_listener.is_chunk_withheld(&position)
+                {
                     chunk
                 } else {
                     trace!(
@@ -2238,29 +3193,20 @@ impl World {
                     let mut ids_to_remove = Vec::new();
 
                     for (uuid, entity_nbt) in chunk.data.lock().await.iter() {
-                        let Some(id) = entity_nbt.get_string("id") else {
-                            warn!("Entity has no ID");
-                            continue;
-                        };
-                        let Some(entity_type) =
-                            EntityType::from_name(id.strip_prefix("minecraft:").unwrap_or(id))
-                        else {
-                            warn!("Entity has no valid Entity Type {id}");
+                        let loaded =
+                            load_entity_with_passengers(&world, *uuid, entity_nbt, &|_| false)
+                                .await;
+                        let Some(entity) = loaded.first() else {
                             continue;
                         };
-                        // Pos is zero since it will read from nbt
-                        let entity =
-                            from_type(entity_type, Vector3::new(0.0, 0.0, 0.0), &world, *uuid)
-                                .await;
-                        entity.read_nbt_non_mut(entity_nbt).await;
                         let base_entity = entity.get_entity();
 
-                        ids_to_remove.push(VarInt(base_entity.entity_id));
+                        ids_to_remove
+                            .extend(loaded.iter().map(|e| VarInt(e.get_entity().entity_id)));
 
                         let mut nbt = NbtCompound::new();
-                        entity.write_nbt(&mut nbt).await;
+                        write_entity_with_passengers(entity, &mut nbt).await;
                         if let Some(old_chunk) = base_entity.first_loaded_chunk_position.load() {
-                            let old_chunk = old_chunk.to_vec2_i32();
                             let chunk = world.level.get_entity_chunk(old_chunk).await;
                             chunk.mark_dirty(true);
                             let base_entity = entity.get_entity();
@@ -2288,6 +3234,9 @@ impl World {
                             });
                             new_entities
                         });
+                        for id in &ids_to_remove {
+                            world.entity_sections.remove(id.0);
+                        }
                         player
                             .client
                             .enqueue_packet(&CRemoveEntities::new(&ids_to_remove))
@@ -2300,37 +3249,71 @@ impl World {
 
                 // Add all new Entities to the world
                 let mut entities_to_add: Vec<Arc<dyn EntityBase>> = Vec::new();
-
-                for (uuid, entity_nbt) in chunk.data.lock().await.iter() {
-                    let Some(id) = entity_nbt.get_string("id") else {
-                        debug!("Entity has no ID");
+                let mut duplicates = Vec::new();
+
+                let mut added: HashSet<Uuid> = HashSet::new();
+
+                let mut data = chunk.data.lock().await;
+                for (uuid, entity_nbt) in data.iter() {
+                    if let Some(loaded) = world.get_entity_by_uuid(*uuid) {
+                        if loaded.get_entity().block_pos.load().chunk_position() == position {
+                            // Another player already loaded this chunk
+                            player
+                                .client
+                                .enqueue_packet(&loaded.get_entity().create_spawn_packet())
+                                .await;
+                            if let Some(link) = loaded.get_entity().leash_link_packet().await {
+                                player.client.enqueue_packet(&link).await;
+                            }
+                        } else {
+                            warn!(
+                                "Dropping duplicate entity {uuid} in chunk {position:?}, it is already loaded elsewhere"
+                            );
+                            duplicates.push(*uuid);
+                        }
                         continue;
-                    };
-                    let Some(entity_type) =
-                        EntityType::from_name(id.strip_prefix("minecraft:").unwrap_or(id))
-                    else {
-                        warn!("Entity has no valid Entity Type {id}");
+                    }
+                    if added.contains(uuid) {
+                        warn!(
+                            "Dropping duplicate entity {uuid} in chunk {position:?}, it is already loaded as a passenger"
+                        );
+                        duplicates.push(*uuid);
                         continue;
+                    }
+                    let is_loaded = |passenger_uuid: Uuid| {
+                        added.contains(&passenger_uuid)
+                            || world.get_entity_by_uuid(passenger_uuid).is_some()
                     };
-                    // Pos is zero since it will read from nbt
-                    let entity =
-                        from_type(entity_type, Vector3::new(0.0, 0.0, 0.0), &world, *uuid).await;
-                    entity.read_nbt_non_mut(entity_nbt).await;
-                    let base_entity = entity.get_entity();
-                    player
-                        .client
-                        .enqueue_packet(&base_entity.create_spawn_packet())
-                        .await;
-                    entity.init_data_tracker().await;
-
-                    entities_to_add.push(entity);
+                    let loaded =
+                        load_entity_with_passengers(&world, *uuid, entity_nbt, &is_loaded).await;
+                    added.extend(loaded.iter().map(|entity| entity.get_entity().entity_uuid));
+                    // The vehicle comes first, so passengers are spawned after what they ride
+                    for entity in loaded {
+                        player
+                            .client
+                            .enqueue_packet(&entity.get_entity().create_spawn_packet())
+                            .await;
+                        entity.init_data_tracker().await;
+
+                        entities_to_add.push(entity);
+                    }
                 }
+                if !duplicates.is_empty() {
+                    for uuid in &duplicates {
+                        data.remove(uuid);
+                    }
+                    chunk.mark_dirty(true);
+                }
+                drop(data);
                 if !entities_to_add.is_empty() {
                     world.entities.rcu(|current_entities| {
                         let mut new_entities = (**current_entities).clone();
                         new_entities.extend(entities_to_add.iter().cloned());
                         new_entities
                     });
+                    for entity in entities_to_add {
+                        world.entity_sections.insert(entity);
+                    }
                 }
             }
 
@@ -2364,6 +3347,20 @@ impl World {
         None
     }
 
+    /// Gets an entity or player by its UUID
+    pub fn get_entity_by_uuid(&self, uuid: uuid::Uuid) -> Option<Arc<dyn EntityBase>> {
+        if let Some(entity) = self
+            .entities
+            .load()
+            .iter()
+            .find(|entity| entity.get_entity().entity_uuid == uuid)
+        {
+            return Some(entity.clone());
+        }
+        self.get_player_by_uuid(uuid)
+            .map(|player| player as Arc<dyn EntityBase>)
+    }
+
     /// Gets a `Player` by a username
     pub fn get_player_by_name(&self, name: &str) -> Option<Arc<Player>> {
         for player in self.players.load().iter() {
@@ -2376,29 +3373,27 @@ impl World {
 
     // Gets all entities at a Box
     pub fn get_all_at_box(&self, aabb: &BoundingBox) -> Vec<Arc<dyn EntityBase>> {
-        let entities_guard = self.entities.load();
-        let players_guard = self.players.load();
+        self.entity_sections.get_in_box(aabb)
+    }
 
-        entities_guard
-            .iter()
-            .map(|e| e.clone() as Arc<dyn EntityBase>)
-            .chain(
-                players_guard
-                    .iter()
-                    .map(|p| p.clone() as Arc<dyn EntityBase>),
-            )
-            .filter(|entity| entity.get_entity().bounding_box.load().intersects(aabb))
-            .collect()
+    /// Gets all entities standing on the block at `pos`, ignoring spectators. Only the bottom
+    /// quarter of the block is checked, which is where pressure plates and tripwires are.
+    pub fn find_entities_on_block(&self, pos: &BlockPos) -> Vec<Arc<dyn EntityBase>> {
+        let (x, y, z) = (f64::from(pos.0.x), f64::from(pos.0.y), f64::from(pos.0.z));
+        let aabb = BoundingBox::new(
+            Vector3::new(x + 0.0625, y, z + 0.0625),
+            Vector3::new(x + 0.9375, y + 0.25, z + 0.9375),
+        );
+        let mut entities = self.get_all_at_box(&aabb);
+        entities.retain(|entity| !entity.is_spectator());
+        entities
     }
 
     // Gets all non Player entities at a Box
     pub fn get_entities_at_box(&self, aabb: &BoundingBox) -> Vec<Arc<dyn EntityBase>> {
-        self.entities
-            .load()
-            .iter()
-            .filter(|entity| entity.get_entity().bounding_box.load().intersects(aabb))
-            .cloned()
-            .collect()
+        let mut entities = self.entity_sections.get_in_box(aabb);
+        entities.retain(|entity| entity.get_player().is_none());
+        entities
     }
 
     // Gets all Player entities at a Box
@@ -2480,17 +3475,38 @@ impl World {
         pos: Vector3<f64>,
         radius: f64,
     ) -> HashMap<uuid::Uuid, Arc<dyn EntityBase>> {
-        let radius_squared = radius.powi(2);
+        self.entity_sections
+            .get_in_radius(pos, radius)
+            .into_iter()
+            .filter(|entity| entity.get_player().is_none())
+            .map(|entity| (entity.get_entity().entity_uuid, entity))
+            .collect()
+    }
 
-        self.entities
-            .load()
-            .iter()
+    /// Gets the entities within `radius` of `pos` together with their squared distance,
+    /// closest first. The entity with the UUID `except` (e.g. the one searching) is skipped.
+    /// This does not include players.
+    pub fn get_nearby_entities_sorted(
+        &self,
+        pos: Vector3<f64>,
+        radius: f64,
+        except: Option<uuid::Uuid>,
+    ) -> Vec<(Arc<dyn EntityBase>, f64)> {
+        let mut entities: Vec<_> = self
+            .entity_sections
+            .get_in_radius(pos, radius)
+            .into_iter()
             .filter_map(|entity| {
-                let entity_pos = entity.get_entity().pos.load();
-                (entity_pos.squared_distance_to_vec(&pos) <= radius_squared)
-                    .then(|| (entity.get_entity().entity_uuid, entity.clone()))
+                let base = entity.get_entity();
+                if except == Some(base.entity_uuid) || entity.get_player().is_some() {
+                    return None;
+                }
+                let distance_squared = base.pos.load().squared_distance_to_vec(&pos);
+                Some((entity, distance_squared))
             })
-            .collect()
+            .collect();
+        entities.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        entities
     }
 
     pub fn get_closest_player(&self, pos: Vector3<f64>, radius: f64) -> Option<Arc<Player>> {
@@ -2515,6 +3531,18 @@ impl World {
             .cloned()
     }
 
+    /// Whether `entity` should go after `player`: it has to be a monster, the difficulty can't be
+    /// Peaceful and the player can't be in Creative or Spectator mode.
+    // TODO: Also check for a team relationship of NEVER_ATTACK once scoreboard teams exist
+    pub fn is_hostile_to_player(&self, entity: &dyn EntityBase, player: &Player) -> bool {
+        entity.get_entity().entity_type.category == &MobCategory::MONSTER
+            && self.level_info.load().difficulty != Difficulty::Peaceful
+            && !matches!(
+                player.gamemode.load(),
+                GameMode::Creative | GameMode::Spectator
+            )
+    }
+
     /// Gets the closest entity to a position, with optional filtering by entity type.
     ///
     /// # Arguments
@@ -2578,6 +3606,8 @@ impl World {
             new_list.push(player.clone());
             new_list
         });
+        self.entity_sections
+            .insert(player.clone() as Arc<dyn EntityBase>);
 
         let server = self.server.upgrade().unwrap();
         let current_players = self.players.load();
@@ -2640,6 +3670,7 @@ impl World {
             new_list
         });
         if let Some(ref player) = removed_player {
+            self.entity_sections.remove(player.entity_id());
             let uuid = player.gameprofile.id;
             self.broadcast_packet_all(&CRemovePlayerInfo::new(&[uuid]))
                 .await;
@@ -2673,8 +3704,22 @@ impl World {
         removed_player
     }
 
+    /// Adds the entity to the world and sends it to all players, unless an `EntitySpawnEvent`
+    /// handler cancels it.
     pub async fn spawn_entity(&self, entity: Arc<dyn EntityBase>) {
         let base_entity = entity.get_entity();
+        if let Some(server) = self.server.upgrade() {
+            let event = server
+                .plugin_manager
+                .fire(EntitySpawnEvent::new(
+                    base_entity.world.load_full(),
+                    entity.clone(),
+                ))
+                .await;
+            if event.cancelled {
+                return;
+            }
+        }
         self.broadcast_packet_all(&base_entity.create_spawn_packet())
             .await;
         entity.init_data_tracker().await;
@@ -2687,12 +3732,16 @@ impl World {
             chunk.data.lock().await.insert(base_entity.entity_uuid, nbt);
             chunk.mark_dirty(true);
         };
+        base_entity
+            .first_loaded_chunk_position
+            .store(Some(chunk_coordinate));
 
         self.entities.rcu(|current_entities| {
             let mut new_entities = (**current_entities).clone();
             new_entities.push(entity.clone());
             new_entities
         });
+        self.entity_sections.insert(entity);
     }
 
     pub async fn remove_entity(&self, entity: &Entity) {
@@ -2701,6 +3750,7 @@ impl World {
             new_entities.retain(|e| e.get_entity().entity_uuid != entity.entity_uuid);
             new_entities
         });
+        self.entity_sections.remove(entity.entity_id);
 
         self.broadcast_packet_all(&CRemoveEntities::new(&[entity.entity_id.into()]))
             .await;
@@ -2708,12 +3758,45 @@ impl World {
         self.remove_entity_data(entity).await;
     }
 
+    /// Queues the breaking stage `from` reached at `location`. Only the latest stage per entity and
+    /// block is sent on the next tick, while cancelling with a `progress` of -1 is sent right away.
     pub async fn set_block_breaking(&self, from: &Entity, location: BlockPos, progress: i32) {
-        self.broadcast_packet_except(
-            &[from.entity_uuid],
-            &CSetBlockDestroyStage::new(from.entity_id.into(), location, progress as i8),
-        )
-        .await;
+        let key = (from.entity_id, location);
+        if progress < 0 {
+            self.unsent_block_breaking.lock().await.remove(&key);
+            self.broadcast_block_destroy_progress(from.entity_uuid, from.entity_id, location, -1)
+                .await;
+            return;
+        }
+        self.unsent_block_breaking
+            .lock()
+            .await
+            .insert(key, (from.entity_uuid, progress as i8));
+    }
+
+    async fn flush_block_breaking(&self) {
+        let unsent: Vec<_> = self.unsent_block_breaking.lock().await.drain().collect();
+        for ((entity_id, location), (breaker, stage)) in unsent {
+            self.broadcast_block_destroy_progress(breaker, entity_id, location, stage)
+                .await;
+        }
+    }
+
+    /// Sends a breaking stage to the players near `location`, except the breaker who renders
+    /// their own progress.
+    pub async fn broadcast_block_destroy_progress(
+        &self,
+        breaker: uuid::Uuid,
+        entity_id: i32,
+        location: BlockPos,
+        stage: i8,
+    ) {
+        let packet = CSetBlockDestroyStage::new(entity_id.into(), location, stage);
+        for player in self.get_nearby_players(location.to_centered_f64(), BLOCK_BREAKING_RANGE) {
+            if player.gameprofile.id != breaker {
+                player.client.enqueue_packet(&packet).await;
+            }
+        }
     }
 
     /// Sets a block and returns the old block id
@@ -2724,6 +3807,15 @@ impl World {
         block_state_id: BlockStateId,
         flags: BlockFlags,
     ) -> BlockStateId {
+        if !self.is_in_height_limit(position.0.y) {
+            error!(
+                "Tried to set block state at {position}, which is outside of the world height limit ({}..={})",
+                self.get_bottom_y(),
+                self.get_top_y()
+            );
+            return Block::VOID_AIR.default_state.id;
+        }
+
         let (chunk_coordinate, relative) = position.chunk_and_chunk_relative_position();
         let level = &self.level;
         let chunk = level.get_chunk(chunk_coordinate).await;
@@ -2742,6 +3834,9 @@ impl World {
             chunk.mark_dirty(true);
         }
         drop(chunk);
+        if let Some(cache) = &self.block_state_cache {
+            cache.invalidate(position);
+        }
 
         self.unsent_block_changes
             .lock()
@@ -2847,12 +3942,8 @@ impl World {
             }
         }
 
-        let (_chunk_coordinate, _) = position.chunk_and_chunk_relative_position();
-
-        level
-            .light_engine
-            .update_lighting_at(level, *position)
-            .await;
+        // Applied by `flush_light_updates`, so changes close to each other share one propagation
+        level.light_engine.queue_lighting_update(*position);
 
         replaced_block_state_id
     }
@@ -2881,6 +3972,28 @@ impl World {
             .await;
     }
 
+    /// How much the sky light is dimmed by the time of day and the weather, from 0 at noon on a
+    /// clear day up to 11 at night.
+    pub async fn get_ambient_darkness(&self) -> u8 {
+        let sky_angle = self.level_time.lock().await.sky_angle();
+        let (rain_level, thunder_level) = {
+            let weather = self.weather.lock().await;
+            (
+                weather.rain_level,
+                weather.thunder_level * weather.rain_level,
+            )
+        };
+        let rain = 1.0 - f64::from(rain_level) * 5.0 / 16.0;
+        let thunder = 1.0 - f64::from(thunder_level) * 5.0 / 16.0;
+        let daylight = 2.0f64.mul_add(
+            (f64::from(sky_angle) * std::f64::consts::TAU)
+                .cos()
+                .clamp(-0.25, 0.25),
+            0.5,
+        );
+        ((1.0 - daylight * rain * thunder) * 11.0) as u8
+    }
+
     pub async fn is_block_tick_scheduled(&self, block_pos: &BlockPos, block: &Block) -> bool {
         self.level.is_block_tick_scheduled(block_pos, block).await
     }
@@ -2897,6 +4010,20 @@ impl World {
         flags: BlockFlags,
     ) -> Option<u16> {
         let (broken_block, broken_block_state) = self.get_block_and_state_id(position).await;
+        if let Some(player) = &cause
+            && !flags.contains(BlockFlags::FORCE_BREAK)
+            && self.is_ceiling_mining_prevented(*position)
+        {
+            // The client already removed the block
+            player
+                .client
+                .enqueue_packet(&CBlockUpdate::new(
+                    *position,
+                    VarInt(i32::from(broken_block_state)),
+                ))
+                .await;
+            return None;
+        }
         let event = BlockBreakEvent::new(cause.clone(), broken_block, *position, 0, false);
 
         let event = self
@@ -2970,6 +4097,38 @@ impl World {
         }
     }
 
+    /// Close container screens for all players viewing a container at a position `is_removed`
+    /// returns true for, e.g. before an explosion destroys the blocks.
+    pub async fn close_container_screens_where(&self, is_removed: impl Fn(&BlockPos) -> bool) {
+        let players = self.players.load();
+        for player in players.iter() {
+            if player
+                .open_container_pos
+                .load()
+                .is_some_and(|position| is_removed(&position))
+            {
+                player.close_handled_screen().await;
+            }
+        }
+    }
+
+    /// Turns the block at the given position into a falling block if there is nothing below to hold it.
+    /// Returns whether the block started falling.
+    pub async fn apply_gravity_to_block(self: &Arc<Self>, position: &BlockPos) -> bool {
+        let state_id = self.get_block_state_id(position).await;
+        if BlockState::from_id(state_id).is_air() || position.0.y <= self.min_y {
+            return false;
+        }
+
+        let (below, below_state) = self.get_block_and_state(&position.down()).await;
+        if !FallingBlock::can_fall_through(below_state, below) {
+            return false;
+        }
+
+        FallingEntity::replace_spawn(self, *position, state_id).await;
+        true
+    }
+
     pub async fn drop_stack(self: &Arc<Self>, pos: &BlockPos, stack: ItemStack) {
         let height = EntityType::ITEM.dimension[1] / 2.0;
         let spawn_pos = {
@@ -3038,6 +4197,98 @@ impl World {
         self.broadcast_packet_all(&CWorldEvent::new(world_event as i32, position, data, false))
             .await;
     }
+
+    /// Counts the End Portal Frames in the ring around `center` that hold an Eye of Ender and
+    /// face the middle of the ring. The portal opens once all 12 of them are filled.
+    pub async fn count_adjacent_portal_frames(&self, center: BlockPos) -> u8 {
+        let mut count = 0;
+        for dir in BlockDirection::horizontal() {
+            let facing = dir.to_horizontal_facing().unwrap();
+            let side = center.offset_dir(dir.to_offset(), 2);
+            for offset in -1..=1 {
+                let pos = side.offset_dir(facing.rotate_clockwise().to_offset(), offset);
+                let (block, state_id) = self.get_block_and_state_id(&pos).await;
+                if block != &Block::END_PORTAL_FRAME {
+                    continue;
+                }
+                let properties = EndPortalFrameLikeProperties::from_state_id(state_id, block);
+                if properties.eye && properties.facing == facing.opposite() {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Fills the 3x3 area inside the frames around `center` with End Portal blocks and plays
+    /// the opening sound to every player in the world.
+    pub async fn activate_end_portal(self: &Arc<Self>, center: BlockPos) {
+        for x in -1..=1 {
+            for z in -1..=1 {
+                self.set_block_state(
+                    &center.offset(Vector3::new(x, 0, z)),
+                    Block::END_PORTAL.default_state.id,
+                    BlockFlags::NOTIFY_LISTENERS,
+                )
+                .await;
+            }
+        }
+        self.broadcast_packet_all(&CWorldEvent::new(
+            WorldEvent::EndPortalOpened as i32,
+            center,
+            0,
+            true,
+        ))
+        .await;
+    }
+
+    /// Returns the middle of the End Portal ring closest to the world spawn, among the frames in
+    /// loaded chunks at most `within_range` blocks away from it horizontally. The ring does not
+    /// have to be filled, and a stronghold whose portal room is not loaded is not found.
+    pub async fn find_end_stronghold_portal(&self, within_range: f64) -> Option<BlockPos> {
+        let spawn = {
+            let info = self.level_info.load();
+            Vector3::new(f64::from(info.spawn_x), 0.0, f64::from(info.spawn_z))
+        };
+        let in_range = |pos: BlockPos| {
+            let pos = pos.to_centered_f64();
+            pos.squared_distance_to_xz(spawn.x, spawn.z) <= within_range * within_range
+        };
+
+        let mut frames = Vec::new();
+        for chunk in self.level.loaded_chunks.iter() {
+            let sections = &chunk.section;
+            let block_sections = sections.block_sections.read().unwrap();
+            for (index, section) in block_sections.iter().enumerate() {
+                let section_y = sections.min_y + 16 * index as i32;
+                let mut i = 0;
+                section.for_each(|state_id| {
+                    if Block::from_state_id(state_id) == &Block::END_PORTAL_FRAME {
+                        frames.push(BlockPos::new(
+                            chunk.x * 16 + i % 16,
+                            section_y + i / 256,
+                            chunk.z * 16 + i / 16 % 16,
+                        ));
+                    }
+                    i += 1;
+                });
+            }
+        }
+
+        let mut nearest: Option<(BlockPos, f64)> = None;
+        for frame in frames.into_iter().filter(|frame| in_range(*frame)) {
+            let Some(middle) = EndPortal::get_mid_pos(self, frame).await else {
+                continue;
+            };
+            let distance = middle
+                .to_centered_f64()
+                .squared_distance_to_xz(spawn.x, spawn.z);
+            if nearest.is_none_or(|(_, nearest)| distance < nearest) {
+                nearest = Some((middle, distance));
+            }
+        }
+        nearest.map(|(middle, _)| middle)
+    }
     #[must_use]
     pub fn is_valid(dest: BlockPos) -> bool {
         Self::is_valid_horizontally(dest) && Self::is_valid_vertically(dest.0.y)
@@ -3061,6 +4312,23 @@ impl World {
     pub fn is_in_height_limit(&self, y: i32) -> bool {
         (self.get_bottom_y()..=self.get_top_y()).contains(&y)
     }
+    /// Whether `pos` is on or above the Nether's bedrock ceiling.
+    #[must_use]
+    pub fn check_bedrock_ceiling(&self, pos: BlockPos) -> bool {
+        self.dimension == Dimension::THE_NETHER && pos.0.y >= NETHER_CEILING_Y
+    }
+
+    /// Whether players are kept from breaking the block at `pos` because it is on the Nether's
+    /// ceiling, unless the server allows it with `allow_nether_ceiling_mining`.
+    #[must_use]
+    pub fn is_ceiling_mining_prevented(&self, pos: BlockPos) -> bool {
+        self.check_bedrock_ceiling(pos)
+            && self
+                .server
+                .upgrade()
+                .is_some_and(|server| !server.advanced_config.world.allow_nether_ceiling_mining)
+    }
+
     pub const fn get_bottom_y(&self) -> i32 {
         self.dimension.min_y
     }
@@ -3132,6 +4400,17 @@ impl World {
         (block, fluid)
     }
 
+    /// Returns the world space y of the fluid surface at the given position.
+    /// Without a fluid this is the bottom of the block.
+    pub async fn get_fluid_height_at(&self, position: &BlockPos) -> f64 {
+        let (fluid, state) = self.get_fluid_and_fluid_state(position).await;
+        if fluid.id == Fluid::EMPTY.id {
+            return f64::from(position.0.y);
+        }
+        let (fluid_above, _) = self.get_fluid_and_fluid_state(&position.up()).await;
+        fluid_surface_y(position.0.y, state, fluid_above.matches_type(fluid))
+    }
+
     pub async fn get_fluid_and_fluid_state(
         &self,
         position: &BlockPos,
@@ -3164,7 +4443,14 @@ impl World {
     }
 
     pub async fn get_block_state_id(&self, position: &BlockPos) -> BlockStateId {
-        self.level.get_block_state(position).await.0
+        let Some(cache) = &self.block_state_cache else {
+            return self.level.get_block_state(position).await.0;
+        };
+        cache
+            .get_or_read(position, async {
+                self.level.get_block_state(position).await.0
+            })
+            .await
     }
 
     /// Gets the `BlockState` from the block registry. Returns Air if the block state was not found.
@@ -3173,6 +4459,84 @@ impl World {
         BlockState::from_id(id)
     }
 
+    /// Gets the block state id at `position`, or `None` if its chunk is not loaded.
+    /// See [`Self::get_chunk_if_loaded`].
+    #[must_use]
+    pub fn get_block_state_id_if_loaded(&self, position: &BlockPos) -> Option<BlockStateId> {
+        let cached = self
+            .block_state_cache
+            .as_ref()
+            .map(|cache| cache.get(position));
+        if let Some(Ok(state_id)) = cached {
+            return Some(state_id);
+        }
+        let (chunk_coordinate, relative) = position.chunk_and_chunk_relative_position();
+        let chunk = self.get_chunk_if_loaded(chunk_coordinate)?;
+        let state_id = chunk
+            .section
+            .get_block_absolute_y(relative.x as usize, relative.y, relative.z as usize)
+            .unwrap_or(Block::VOID_AIR.default_state.id);
+        if let (Some(cache), Some(Err(generation))) = (&self.block_state_cache, cached) {
+            cache.insert(*position, state_id, generation);
+        }
+        Some(state_id)
+    }
+
+    /// Gets the `BlockState` at `position`, or `None` if its chunk is not loaded.
+    #[must_use]
+    pub fn get_block_state_if_loaded(&self, position: &BlockPos) -> Option<&'static BlockState> {
+        self.get_block_state_id_if_loaded(position)
+            .map(BlockState::from_id)
+    }
+
+    /// Like [`Self::is_space_empty`], but only reads loaded chunks. Space in an unloaded chunk
+    /// counts as occupied.
+    #[must_use]
+    pub fn is_loaded_space_empty(&self, bounding_box: &BoundingBox) -> bool {
+        let min = bounding_box.min_block_pos();
+        let max = bounding_box.max_block_pos();
+
+        BlockPos::iterate(min, max).all(|pos| {
+            self.get_block_state_if_loaded(&pos).is_some_and(|state| {
+                !Self::check_collision(bounding_box, pos, state, false, |_| ())
+            })
+        })
+    }
+
+    /// Whether the block at `position` fully blocks light, e.g. for sky light exposure checks.
+    pub async fn is_block_opaque(&self, position: &BlockPos) -> bool {
+        self.get_block_state(position).await.is_opaque()
+    }
+
+    /// Whether `block` may be placed at `position` against `face`, following the block's own
+    /// placement rules (e.g. cactus needs sand below and torches need a solid backing).
+    ///
+    /// Blocks like ladders and hanging signs look at the clicked face in `use_item_on`, so it
+    /// should be passed whenever a player places the block.
+    pub async fn can_place_block(
+        &self,
+        position: &BlockPos,
+        block: &Block,
+        face: BlockDirection,
+        placer: Option<&Player>,
+        use_item_on: Option<&SUseItemOn>,
+    ) -> bool {
+        let server = self.server.upgrade();
+        self.block_registry
+            .can_place_at(
+                server.as_deref(),
+                Some(self),
+                self,
+                placer,
+                block,
+                block.default_state,
+                position,
+                Some(face),
+                use_item_on,
+            )
+            .await
+    }
+
     /// Gets the Block + Block state from the Block Registry, Returns Air if the Block state has not been found
     pub async fn get_block_and_state(
         &self,
@@ -3188,6 +4552,20 @@ impl World {
         (Block::from_state_id(id), id)
     }
 
+    /// Returns the breaking progress made per tick on `block` at `position` with `tool_item`, or
+    /// with an empty hand if there is none. The block breaks once the progress reaches 1.0, and
+    /// 0.0 means it can't be broken. The miner's status effects are not included, see
+    /// `Player::get_block_destroy_speed`.
+    pub async fn get_block_destroy_speed(
+        &self,
+        block: &'static Block,
+        position: &BlockPos,
+        tool_item: Option<&ItemStack>,
+    ) -> f64 {
+        let state = self.get_block_state(position).await;
+        f64::from(block::calc_tool_breaking(state, block, tool_item))
+    }
+
     /// Updates neighboring blocks of a block
     pub async fn update_neighbors(
         self: &Arc<Self>,
@@ -3293,6 +4671,14 @@ impl World {
         chunk.block_entities.lock().unwrap().get(block_pos).cloned()
     }
 
+    /// Returns the block entity at `block_pos` if it is a `T`.
+    pub async fn get_block_entity_data<T: BlockEntity>(
+        &self,
+        block_pos: &BlockPos,
+    ) -> Option<Arc<T>> {
+        self.get_block_entity(block_pos).await?.downcast_arc()
+    }
+
     pub async fn add_block_entity(&self, block_entity: Arc<dyn BlockEntity>) {
         let block_pos = block_entity.get_position();
         let chunk = self.level.get_chunk(block_pos.chunk_position()).await;
@@ -3439,6 +4825,20 @@ impl World {
         start_pos: Vector3<f64>,
         end_pos: Vector3<f64>,
         hit_check: impl AsyncFn(&BlockPos, &Arc<Self>) -> bool,
+    ) -> Option<(BlockPos, BlockDirection)> {
+        self.raycast_with_options(start_pos, end_pos, false, hit_check)
+            .await
+    }
+
+    /// Like `raycast`, but with `skip_origin` the block containing `start_pos` is ignored, so a
+    /// ray starting inside a block (e.g. an eye inside a waterlogged slab) continues to the first
+    /// block it enters.
+    pub async fn raycast_with_options(
+        self: &Arc<Self>,
+        start_pos: Vector3<f64>,
+        end_pos: Vector3<f64>,
+        skip_origin: bool,
+        hit_check: impl AsyncFn(&BlockPos, &Arc<Self>) -> bool,
     ) -> Option<(BlockPos, BlockDirection)> {
         if start_pos == end_pos {
             return None;
@@ -3450,11 +4850,13 @@ impl World {
 
         let mut block = BlockPos::floored(from.x, from.y, from.z);
 
-        let (collision, direction) = self.ray_outline_check(&block, from, to).await;
-        if let Some(dir) = direction
-            && collision
-        {
-            return Some((block, dir));
+        if !skip_origin {
+            let (collision, direction) = self.ray_outline_check(&block, from, to).await;
+            if let Some(dir) = direction
+                && collision
+            {
+                return Some((block, dir));
+            }
         }
 
         let difference = to.sub(&from);
@@ -3580,6 +4982,29 @@ impl pumpkin_world::world::SimpleWorld for World {
         Box::pin(async move { self.is_space_empty(bounding_box).await })
     }
 
+    fn is_player_in_range(&self, position: Vector3<f64>, range: f64) -> WorldFuture<'_, bool> {
+        Box::pin(async move {
+            self.get_nearby_players(position, range)
+                .iter()
+                .any(|player| {
+                    !player.living_entity.dead.load(Ordering::Relaxed) && !player.is_spectator()
+                })
+        })
+    }
+
+    fn count_entities_of_type(
+        &self,
+        entity_type: &'static EntityType,
+        bounding_box: BoundingBox,
+    ) -> WorldFuture<'_, usize> {
+        Box::pin(async move {
+            self.get_entities_at_box(&bounding_box)
+                .iter()
+                .filter(|entity| entity.get_entity().entity_type == entity_type)
+                .count()
+        })
+    }
+
     fn add_synced_block_event(&self, pos: BlockPos, r#type: u8, data: u8) -> WorldFuture<'_, ()> {
         Box::pin(async move {
             self.add_synced_block_event(pos, r#type, data).await;
@@ -3629,6 +5054,19 @@ impl pumpkin_world::world::SimpleWorld for World {
         })
     }
 
+    fn schedule_block_tick<'a>(
+        &'a self,
+        block: &'a Block,
+        position: BlockPos,
+        delay: u8,
+        priority: TickPriority,
+    ) -> WorldFuture<'a, ()> {
+        Box::pin(async move {
+            self.schedule_block_tick(block, position, delay, priority)
+                .await;
+        })
+    }
+
     fn play_sound<'a>(
         &'a self,
         sound: Sound,
@@ -3731,3 +5169,1117 @@ impl BlockAccessor for World {
         Box::pin(async move { self.get_block_and_state(position).await })
     }
 }
+
+/// The world space y of the top of a fluid in the block at `y`. A fluid with the same fluid above
+/// it fills the whole block, otherwise every level fills 1/9 of it.
+#[must_use]
+pub fn fluid_surface_y(y: i32, state: &FluidState, same_fluid_above: bool) -> f64 {
+    if same_fluid_above {
+        f64::from(y) + 1.0
+    } else {
+        f64::from(y) + f64::from(state.height)
+    }
+}
+
+/// Whether an entity belongs in its chunk's entity data. Entities that were killed, discarded or
+/// left the dimension must not come back on the next load, and passengers are saved by their vehicle.
+const fn should_save_entity(removal_reason: Option<RemovalReason>, has_vehicle: bool) -> bool {
+    match removal_reason {
+        Some(reason) if !reason.should_save() => false,
+        _ => !has_vehicle,
+    }
+}
+
+/// Writes `entity` with its passengers nested in a `Passengers` list, the way vanilla stores
+/// mounted entities. Players are left out, they are saved in their player data.
+fn write_entity_with_passengers<'a>(
+    entity: &'a Arc<dyn EntityBase>,
+    nbt: &'a mut NbtCompound,
+) -> NbtFuture<'a, ()> {
+    Box::pin(async move {
+        entity.write_nbt(nbt).await;
+        let passengers = entity.get_entity().passengers.lock().await.clone();
+        let mut list = Vec::new();
+        for passenger in passengers
+            .iter()
+            .filter(|passenger| passenger.get_player().is_none())
+        {
+            let mut passenger_nbt = NbtCompound::new();
+            write_entity_with_passengers(passenger, &mut passenger_nbt).await;
+            list.push(NbtTag::Compound(passenger_nbt));
+        }
+        if !list.is_empty() {
+            nbt.put_list("Passengers", list);
+        }
+    })
+}
+
+/// Creates the entity saved in `nbt` along with everything in its `Passengers` list, seated
+/// on it again. The vehicle comes first, followed by its passengers in riding order.
+///
+/// Passengers for which `is_loaded` returns true, or whose UUID comes up twice in `nbt`, are
+/// left out, so the same entity is never spawned twice.
+async fn load_entity_with_passengers(
+    world: &Arc<World>,
+    uuid: Uuid,
+    nbt: &NbtCompound,
+    is_loaded: &(dyn Fn(Uuid) -> bool + Sync),
+) -> Vec<Arc<dyn EntityBase>> {
+    let seen = std::sync::Mutex::new(HashSet::from([uuid]));
+    let is_duplicate = |passenger_uuid: Uuid| {
+        is_loaded(passenger_uuid) || !seen.lock().unwrap().insert(passenger_uuid)
+    };
+    load_entity_tree(world, uuid, nbt, &is_duplicate).await
+}
+
+fn load_entity_tree<'a>(
+    world: &'a Arc<World>,
+    uuid: Uuid,
+    nbt: &'a NbtCompound,
+    is_duplicate: &'a (dyn Fn(Uuid) -> bool + Sync),
+) -> NbtFuture<'a, Vec<Arc<dyn EntityBase>>> {
+    Box::pin(async move {
+        let Some(id) = nbt.get_string("id") else {
+            warn!("Entity has no ID");
+            return Vec::new();
+        };
+        let Some(entity_type) = EntityType::from_name(id.strip_prefix("minecraft:").unwrap_or(id))
+        else {
+            warn!("Entity has no valid Entity Type {id}");
+            return Vec::new();
+        };
+        // Pos is zero since it will read from nbt
+        let entity = from_type(entity_type, Vector3::new(0.0, 0.0, 0.0), world, uuid).await;
+        entity.read_nbt_non_mut(nbt).await;
+
+        let mut loaded = vec![entity.clone()];
+        for passenger_nbt in nbt.get_list("Passengers").unwrap_or_default() {
+            let NbtTag::Compound(passenger_nbt) = passenger_nbt else {
+                continue;
+            };
+            let Some([a, b, c, d]) = passenger_nbt.get_int_array("UUID") else {
+                warn!("Passenger of {id} has no UUID");
+                continue;
+            };
+            let passenger_uuid = Uuid::from_u128(
+                (u128::from(*a as u32) << 96)
+                    | (u128::from(*b as u32) << 64)
+                    | (u128::from(*c as u32) << 32)
+                    | u128::from(*d as u32),
+            );
+            if is_duplicate(passenger_uuid) {
+                warn!(
+                    "Dropping duplicate passenger {passenger_uuid} of {id}, it is already loaded"
+                );
+                continue;
+            }
+            let passengers =
+                load_entity_tree(world, passenger_uuid, passenger_nbt, is_duplicate).await;
+            if let Some(passenger) = passengers.first() {
+                Entity::add_passenger(&entity, passenger.clone()).await;
+            }
+            loaded.extend(passengers);
+        }
+        loaded
+    })
+}
+
+/// The column offsets vanilla's spawn search visits in the `radius` square, one per column of
+/// the square. Stepping by 17 spreads the search out instead of scanning row by row from
+/// `start`, but when the column count is a multiple of 17 the steps only land on some of the
+/// columns, repeating those and never reaching the rest, just like in vanilla.
+fn spawn_search_offsets(radius: i32, start: i32) -> impl Iterator<Item = (i32, i32)> {
+    let side = i64::from(radius) * 2 + 1;
+    let columns = (side * side).min(i64::from(i32::MAX));
+    let step = if columns <= 16 { columns - 1 } else { 17 };
+    (0..columns).map(move |i| {
+        let column = (i64::from(start) + step * i) % columns;
+        (
+            (column % side) as i32 - radius,
+            (column / side) as i32 - radius,
+        )
+    })
+}
+
+/// Offsets within `radius` ordered ring by ring, so closer offsets come first.
+fn ring_offsets(radius: i32) -> impl Iterator<Item = (i32, i32)> {
+    (0..=radius).flat_map(|ring| {
+        (-ring..=ring).flat_map(move |x| {
+            (-ring..=ring)
+                .filter(move |z| x.abs() == ring || z.abs() == ring)
+                .map(move |z| (x, z))
+        })
+    })
+}
+
+/// Whether a player can spawn standing on `below` with `feet` and `head` around them: the floor
+/// has to be solid and not hurt the player, and neither of the blocks above may be solid, fluid
+/// or harmful.
+fn is_safe_spawn(below: &BlockState, feet: &'static BlockState, head: &'static BlockState) -> bool {
+    is_valid_spawn_floor(below, &EntityType::PLAYER)
+        && !is_block_dangerous(below, &EntityType::PLAYER)
+        && [feet, head].into_iter().all(|state| {
+            is_valid_empty_spawn_block(state) && !is_block_dangerous(state, &EntityType::PLAYER)
+        })
+}
+
+/// Whether enough of the players are sleeping to skip the night. At least one player always has
+/// to sleep.
+fn enough_players_sleeping(sleeping: usize, players: usize, sleep_percentage: i32) -> bool {
+    if players == 0 {
+        return false;
+    }
+    let sleep_percentage = sleep_percentage.clamp(0, 100);
+    let required_sleeping =
+        ((players as f64 * f64::from(sleep_percentage)) / 100.0).ceil() as usize;
+    sleeping >= required_sleeping.max(1)
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::collections::HashSet;
+
+    use pumpkin_data::biome::Biome;
+    use pumpkin_data::block_properties::{BlockProperties, EndPortalFrameLikeProperties};
+    use pumpkin_data::fluid::FluidState;
+    use pumpkin_data::{Block, BlockDirection};
+
+    use std::sync::{Arc, Weak};
+
+    use arc_swap::ArcSwap;
+    use pumpkin_config::world::LevelConfig;
+    use pumpkin_data::chunk::ChunkStatus;
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_util::world_seed::Seed;
+    use pumpkin_world::chunk::format::LightContainer;
+    use pumpkin_world::chunk::{
+        ChunkData, ChunkHeightmaps, ChunkLight, ChunkSections, SendGeneration,
+    };
+    use pumpkin_world::chunk_system::ChunkLoading;
+    use pumpkin_world::{level::Level, world_info::LevelData};
+    use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
+    use tempfile::{TempDir, tempdir};
+
+    use super::scheduled_callbacks::{ScheduleMode, ScheduledCallbacks};
+    use super::{
+        World, enough_players_sleeping, fluid_surface_y, is_safe_spawn,
+        load_entity_with_passengers, ring_offsets, spawn_search_offsets,
+        write_entity_with_passengers,
+    };
+    use crate::entity::item::ItemEntity;
+    use crate::entity::living::LivingEntity;
+    use crate::entity::player::Player;
+    use crate::entity::player::test::{TestConnection, test_player};
+    use crate::entity::r#type::from_type;
+    use crate::entity::{Entity, EntityBase, RemovalReason, push_apart};
+    use crate::plugin::entity::entity_spawn::EntitySpawnEvent;
+    use crate::plugin::test::cancel_all;
+    use crate::plugin::world::chunk_load::ChunkLoad;
+    use crate::server::Server;
+    use crate::server::test::{test_server, test_server_with};
+    use pumpkin_config::AdvancedConfiguration;
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_data::item::Item;
+    use pumpkin_nbt::compound::NbtCompound;
+    use pumpkin_protocol::java::client::play::{
+        CBlockUpdate, CChangeDifficulty, CGameEvent, CSpawnEntity, GameEvent,
+    };
+    use pumpkin_protocol::packet::MultiVersionJavaPacket;
+    use pumpkin_util::math::{
+        boundingbox::BoundingBox, position::BlockPos, vector2::Vector2, vector3::Vector3,
+    };
+    use pumpkin_world::block::entities::BlockEntity;
+    use pumpkin_world::block::entities::mob_spawner::MobSpawnerBlockEntity;
+    use pumpkin_world::item::ItemStack;
+    use pumpkin_world::world::{BlockFlags, SimpleWorld};
+    use pumpkin_world::world_info::anvil::AnvilLevelInfo;
+    use pumpkin_world::world_info::{WorldInfoReader, WorldInfoWriter};
+
+    pub(crate) fn test_world(
+        dir: &TempDir,
+        level_info: &Arc<ArcSwap<LevelData>>,
+        dimension: Dimension,
+    ) -> World {
+        let block_registry = crate::block::default_registry();
+        let level = Level::from_root_folder(
+            &LevelConfig::default(),
+            dir.path().join(dimension.minecraft_name.replace(':', "_")),
+            block_registry.clone(),
+            0,
+            dimension,
+        );
+        World::load(
+            level,
+            level_info.clone(),
+            Arc::new(ScheduledCallbacks::default()),
+            dimension,
+            block_registry,
+            Weak::new(),
+        )
+    }
+
+    /// Loads a world of `server` and adds it to the server's worlds, so its events are fired.
+    pub(crate) fn test_world_on(
+        server: &Arc<Server>,
+        dir: &TempDir,
+        dimension: Dimension,
+    ) -> Arc<World> {
+        let block_registry = server.block_registry.clone();
+        let level = Level::from_root_folder(
+            &LevelConfig::default(),
+            dir.path().join(dimension.minecraft_name.replace(':', "_")),
+            block_registry.clone(),
+            0,
+            dimension,
+        );
+        let world = Arc::new(World::load(
+            level,
+            server.level_info.clone(),
+            server.scheduled_callbacks.clone(),
+            dimension,
+            block_registry,
+            Arc::downgrade(server),
+        ));
+        server.worlds.rcu(|worlds| {
+            let mut worlds = (**worlds).clone();
+            worlds.push(world.clone());
+            worlds
+        });
+        world
+    }
+
+    /// Loads an overworld chunk filled with air at `pos`, without generating it.
+    pub(crate) fn load_empty_chunk(world: &World, pos: Vector2<i32>) -> Arc<ChunkData> {
+        load_empty_chunk_in_biome(world, pos, &Biome::BADLANDS)
+    }
+
+    /// Like [`load_empty_chunk`], with every part of the chunk in `biome`.
+    pub(crate) fn load_empty_chunk_in_biome(
+        world: &World,
+        pos: Vector2<i32>,
+        biome: &Biome,
+    ) -> Arc<ChunkData> {
+        let dimension = Dimension::OVERWORLD;
+        let section_count = (dimension.height / 16) as usize;
+        let mut section = ChunkSections::new(section_count, dimension.min_y);
+        for x in 0..4 {
+            for y in 0..section_count * 4 {
+                for z in 0..4 {
+                    section.set_relative_biome(x, y, z, biome.id);
+                }
+            }
+        }
+        let chunk = Arc::new(ChunkData {
+            section,
+            heightmap: std::sync::Mutex::new(ChunkHeightmaps::new(dimension.height)),
+            x: pos.x,
+            z: pos.y,
+            block_ticks: Default::default(),
+            fluid_ticks: Default::default(),
+            block_entities: Default::default(),
+            light_engine: std::sync::Mutex::new(ChunkLight {
+                sky_light: vec![LightContainer::new_empty(15); section_count].into_boxed_slice(),
+                block_light: vec![LightContainer::new_empty(0); section_count].into_boxed_slice(),
+            }),
+            light_populated: AtomicBool::new(true),
+            status: ChunkStatus::Full,
+            dirty: AtomicBool::new(false),
+            send_generation: SendGeneration::new(),
+        });
+        world.level.loaded_chunks.insert(pos, chunk.clone());
+        chunk
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn only_water_at_the_edge_freezes() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = test_world(&dir, &level_info, Dimension::OVERWORLD);
+        let chunk = load_empty_chunk(&world, Vector2::new(0, 0));
+        for x in 4..7 {
+            for z in 4..7 {
+                chunk
+                    .section
+                    .set_block_absolute_y(x, 62, z, Block::WATER.default_state.id);
+            }
+        }
+
+        assert!(!world.can_water_freeze(&BlockPos::new(5, 62, 5)).await);
+        assert!(world.can_water_freeze(&BlockPos::new(4, 62, 5)).await);
+        assert!(world.can_water_freeze(&BlockPos::new(6, 62, 6)).await);
+        // Only water freezes
+        assert!(!world.can_water_freeze(&BlockPos::new(5, 63, 5)).await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn destroy_speed_depends_on_the_tool() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = test_world(&dir, &level_info, Dimension::OVERWORLD);
+        let chunk = load_empty_chunk(&world, Vector2::new(0, 0));
+        chunk
+            .section
+            .set_block_absolute_y(0, 62, 0, Block::STONE.default_state.id);
+        chunk
+            .section
+            .set_block_absolute_y(1, 62, 0, Block::BEDROCK.default_state.id);
+        let stone = BlockPos::new(0, 62, 0);
+        let pickaxe = ItemStack::new(1, &Item::WOODEN_PICKAXE);
+
+        // Stone needs a pickaxe to drop, so mining it by hand is slower than that
+        let by_hand = world
+            .get_block_destroy_speed(&Block::STONE, &stone, None)
+            .await;
+        assert!((by_hand - 1.0 / 1.5 / 100.0).abs() < 1e-6);
+        let with_pickaxe = world
+            .get_block_destroy_speed(&Block::STONE, &stone, Some(&pickaxe))
+            .await;
+        assert!((with_pickaxe - 2.0 / 1.5 / 30.0).abs() < 1e-6);
+        let bedrock = world
+            .get_block_destroy_speed(&Block::BEDROCK, &BlockPos::new(1, 62, 0), Some(&pickaxe))
+            .await;
+        assert!(bedrock.abs() < 1e-6);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn stronghold_portal_is_found_within_range() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = test_world(&dir, &level_info, Dimension::OVERWORLD);
+        let chunk = load_empty_chunk(&world, Vector2::new(0, 0));
+        assert_eq!(world.find_end_stronghold_portal(64.0).await, None);
+
+        // A ring of empty frames facing its middle, like the one in a stronghold
+        let center = BlockPos::new(8, 40, 8);
+        for dir in BlockDirection::horizontal() {
+            let facing = dir.to_horizontal_facing().unwrap();
+            let mut frame = EndPortalFrameLikeProperties::default(&Block::END_PORTAL_FRAME);
+            frame.facing = facing.opposite();
+            let side = center.offset_dir(dir.to_offset(), 2);
+            for offset in -1..=1 {
+                let pos = side.offset_dir(facing.rotate_clockwise().to_offset(), offset);
+                chunk.section.set_block_absolute_y(
+                    pos.0.x as usize,
+                    pos.0.y,
+                    pos.0.z as usize,
+                    frame.to_state_id(&Block::END_PORTAL_FRAME),
+                );
+            }
+        }
+
+        assert_eq!(world.find_end_stronghold_portal(64.0).await, Some(center));
+        // The world spawn is at the origin, more than 5 blocks away from every frame
+        assert_eq!(world.find_end_stronghold_portal(5.0).await, None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn sleep_percentage_is_per_world() {
+        let dir = tempdir().unwrap();
+        let mut info = LevelData::default(Seed(0));
+        info.game_rules.players_sleeping_percentage = 100;
+        let level_info = Arc::new(ArcSwap::from_pointee(info));
+        let overworld = test_world(&dir, &level_info, Dimension::OVERWORLD);
+        let nether = test_world(&dir, &level_info, Dimension::THE_NETHER);
+
+        overworld.set_sleep_percentage(50);
+        assert_eq!(overworld.sleep_percentage(), 50);
+        assert_eq!(nether.sleep_percentage(), 100);
+
+        // With one of two players asleep, only the overworld skips the night
+        assert!(enough_players_sleeping(1, 2, overworld.sleep_percentage()));
+        assert!(!enough_players_sleeping(1, 2, nether.sleep_percentage()));
+
+        // The override is part of the level data, so it survives a restart
+        AnvilLevelInfo
+            .write_world_info(&level_info.load(), dir.path())
+            .unwrap();
+        let saved = AnvilLevelInfo.read_world_info(dir.path()).unwrap();
+        let restarted = test_world(
+            &dir,
+            &Arc::new(ArcSwap::from_pointee(saved)),
+            Dimension::OVERWORLD,
+        );
+        assert_eq!(restarted.sleep_percentage(), 50);
+
+        overworld.reset_sleep_percentage();
+        assert_eq!(overworld.sleep_percentage(), 100);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn spawn_chunks_stay_loaded_without_players() {
+        let dir = tempdir().unwrap();
+        let mut info = LevelData::default(Seed(0));
+        info.spawn_x = 40;
+        info.spawn_z = -20;
+        let level_info = Arc::new(ArcSwap::from_pointee(info));
+        let overworld = test_world(&dir, &level_info, Dimension::OVERWORLD);
+        let nether = test_world(&dir, &level_info, Dimension::THE_NETHER);
+        assert!(overworld.get_spawn_chunks().is_empty());
+
+        overworld.update_spawn_chunks(1);
+        nether.update_spawn_chunks(1);
+        assert!(nether.get_spawn_chunks().is_empty());
+
+        let spawn_chunks = overworld.get_spawn_chunks();
+        assert_eq!(spawn_chunks.len(), 9);
+        assert!(spawn_chunks.contains(&Vector2::new(2, -2)));
+        {
+            // The ticket keeps them at full chunk level with no player around
+            let chunk_loading = overworld.level.chunk_loading.lock().unwrap();
+            for chunk in &spawn_chunks {
+                assert!(
+                    chunk_loading
+                        .pos_level
+                        .get(chunk)
+                        .is_some_and(|level| *level <= ChunkLoading::FULL_CHUNK_LEVEL)
+                );
+            }
+        }
+
+        overworld.update_spawn_chunks(0);
+        assert!(overworld.get_spawn_chunks().is_empty());
+        let chunk_loading = overworld.level.chunk_loading.lock().unwrap();
+        assert!(chunk_loading.pos_level.get(&Vector2::new(2, -2)).is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn item_does_not_push_mob() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+
+        let item = ItemEntity::new(
+            Entity::new(
+                world.clone(),
+                Vector3::new(0.0, 64.0, 0.0),
+                &EntityType::ITEM,
+            ),
+            ItemStack::new(1, &Item::STONE),
+        )
+        .await;
+        let zombie = LivingEntity::new(Entity::new(
+            world.clone(),
+            Vector3::new(0.3, 64.0, 0.0),
+            &EntityType::ZOMBIE,
+        ));
+        let other_zombie = LivingEntity::new(Entity::new(
+            world,
+            Vector3::new(0.6, 64.0, 0.0),
+            &EntityType::ZOMBIE,
+        ));
+
+        assert!(!item.pushes_entities() && !item.can_be_pushed());
+        assert!(!push_apart(&item, &zombie).await);
+        assert!(!push_apart(&zombie, &item).await);
+        assert_eq!(zombie.entity.velocity.load(), Vector3::default());
+
+        // Mobs still push each other apart along the line between them
+        assert!(push_apart(&zombie, &other_zombie).await);
+        assert!(zombie.entity.velocity.load().x < 0.0);
+        assert!(other_zombie.entity.velocity.load().x > 0.0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn box_queries_follow_spawned_entities() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+
+        let zombie: Arc<dyn EntityBase> = Arc::new(LivingEntity::new(Entity::new(
+            world.clone(),
+            Vector3::new(0.5, 64.0, 0.5),
+            &EntityType::ZOMBIE,
+        )));
+        let around_spawn =
+            BoundingBox::new(Vector3::new(-1.0, 63.0, -1.0), Vector3::new(2.0, 66.0, 2.0));
+        assert!(world.get_entities_at_box(&around_spawn).is_empty());
+        world.spawn_entity(zombie.clone()).await;
+        assert_eq!(world.get_entities_at_box(&around_spawn).len(), 1);
+
+        // Moving into another section is picked up by the index
+        zombie.get_entity().set_pos(Vector3::new(40.5, 64.0, 0.5));
+        assert!(world.get_entities_at_box(&around_spawn).is_empty());
+        assert_eq!(
+            world
+                .get_nearby_entities(Vector3::new(40.0, 64.0, 0.0), 2.0)
+                .len(),
+            1
+        );
+
+        world.remove_entity(zombie.get_entity()).await;
+        assert!(
+            world
+                .get_nearby_entities(Vector3::new(40.0, 64.0, 0.0), 2.0)
+                .is_empty()
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn portal_cooldown_resets_per_entity_type() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+
+        let player = Entity::new(world.clone(), Vector3::default(), &EntityType::PLAYER);
+        assert_eq!(player.get_portal_cooldown(), 0);
+        player.reset_portal_cooldown();
+        assert_eq!(player.get_portal_cooldown(), 10);
+
+        let zombie = Entity::new(world, Vector3::default(), &EntityType::ZOMBIE);
+        zombie.reset_portal_cooldown();
+        assert_eq!(zombie.get_portal_cooldown(), 300);
+        zombie.set_portal_cooldown(0);
+        assert_eq!(zombie.get_portal_cooldown(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn read_only_queries_do_not_load_chunks() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = test_world(&dir, &level_info, Dimension::OVERWORLD);
+
+        let pos = BlockPos::new(100, 64, -100);
+        assert!(world.get_block_state_id_if_loaded(&pos).is_none());
+        assert!(!world.is_loaded_space_empty(&BoundingBox::from_block(&pos)));
+        assert!(world.get_chunk_if_loaded(Vector2::new(6, -7)).is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn moved_entity_is_saved_in_its_new_chunk_only() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+
+        let zombie: Arc<dyn EntityBase> = Arc::new(LivingEntity::new(Entity::new(
+            world.clone(),
+            Vector3::new(1000.5, 64.0, -1000.5),
+            &EntityType::ZOMBIE,
+        )));
+        let uuid = zombie.get_entity().entity_uuid;
+        world.spawn_entity(zombie.clone()).await;
+        let spawn_chunk = Vector2::new(62, -63);
+        assert_eq!(
+            zombie.get_entity().first_loaded_chunk_position.load(),
+            Some(spawn_chunk)
+        );
+
+        // Cross into the next chunk before the first save
+        zombie
+            .get_entity()
+            .set_pos(Vector3::new(1010.5, 64.0, -1000.5));
+        world.save_entity(&zombie).await;
+
+        let new_chunk = Vector2::new(63, -63);
+        let old_data = world.level.get_entity_chunk(spawn_chunk).await;
+        assert!(!old_data.data.lock().await.contains_key(&uuid));
+        let new_data = world.level.get_entity_chunk(new_chunk).await;
+        assert!(new_data.data.lock().await.contains_key(&uuid));
+        assert_eq!(
+            zombie.get_entity().first_loaded_chunk_position.load(),
+            Some(new_chunk)
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn killed_entity_is_not_saved_on_shutdown() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+
+        let zombie: Arc<dyn EntityBase> = Arc::new(LivingEntity::new(Entity::new(
+            world.clone(),
+            Vector3::new(0.5, 64.0, 0.5),
+            &EntityType::ZOMBIE,
+        )));
+        let uuid = zombie.get_entity().entity_uuid;
+        world.spawn_entity(zombie.clone()).await;
+        world.save_entity(&zombie).await;
+
+        // The entity is killed, but still in the entity list when the world shuts down
+        zombie
+            .get_entity()
+            .removal_reason
+            .store(Some(RemovalReason::Killed));
+        world.save_entity(&zombie).await;
+        let data = world.level.get_entity_chunk(Vector2::new(0, 0)).await;
+        assert!(!data.data.lock().await.contains_key(&uuid));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn mounted_entity_is_saved_with_its_vehicle() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+
+        let chicken: Arc<dyn EntityBase> = Arc::new(LivingEntity::new(Entity::new(
+            world.clone(),
+            Vector3::new(0.5, 64.0, 0.5),
+            &EntityType::CHICKEN,
+        )));
+        let zombie: Arc<dyn EntityBase> = Arc::new(LivingEntity::new(Entity::new(
+            world.clone(),
+            Vector3::new(0.5, 64.0, 0.5),
+            &EntityType::ZOMBIE,
+        )));
+        let chicken_uuid = chicken.get_entity().entity_uuid;
+        let zombie_uuid = zombie.get_entity().entity_uuid;
+        world.spawn_entity(chicken.clone()).await;
+        world.spawn_entity(zombie.clone()).await;
+        Entity::add_passenger(&chicken, zombie.clone()).await;
+
+        world.save_entity(&zombie).await;
+        world.save_entity(&chicken).await;
+
+        // Only the vehicle has an entry, the rider is stored inside it
+        let chunk = world.level.get_entity_chunk(Vector2::new(0, 0)).await;
+        let data = chunk.data.lock().await;
+        assert!(!data.contains_key(&zombie_uuid));
+        let saved = data.get(&chicken_uuid).unwrap().clone();
+        drop(data);
+
+        let loaded = load_entity_with_passengers(&world, chicken_uuid, &saved, &|_| false).await;
+        assert_eq!(loaded.len(), 2);
+        let (vehicle, rider) = (&loaded[0], &loaded[1]);
+        assert_eq!(vehicle.get_entity().entity_uuid, chicken_uuid);
+        assert_eq!(vehicle.get_entity().entity_type, &EntityType::CHICKEN);
+        assert_eq!(rider.get_entity().entity_uuid, zombie_uuid);
+        assert_eq!(rider.get_entity().entity_type, &EntityType::ZOMBIE);
+        let rider_vehicle = rider.get_entity().vehicle.lock().await.clone().unwrap();
+        assert_eq!(rider_vehicle.get_entity().entity_uuid, chicken_uuid);
+        let passengers = vehicle.get_entity().passengers.lock().await;
+        assert_eq!(passengers.len(), 1);
+        assert_eq!(passengers[0].get_entity().entity_uuid, zombie_uuid);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn loaded_passengers_are_not_loaded_again() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        let mut riders: Vec<Arc<dyn EntityBase>> = Vec::new();
+        for entity_type in [
+            &EntityType::CHICKEN,
+            &EntityType::ZOMBIE,
+            &EntityType::SKELETON,
+        ] {
+            riders.push(Arc::new(LivingEntity::new(Entity::new(
+                world.clone(),
+                Vector3::new(0.5, 64.0, 0.5),
+                entity_type,
+            ))));
+        }
+        // A skeleton riding a zombie riding a chicken
+        Entity::add_passenger(&riders[0], riders[1].clone()).await;
+        Entity::add_passenger(&riders[1], riders[2].clone()).await;
+        let [chicken_uuid, zombie_uuid, skeleton_uuid] =
+            [0, 1, 2].map(|i| riders[i].get_entity().entity_uuid);
+        let mut saved = NbtCompound::new();
+        write_entity_with_passengers(&riders[0], &mut saved).await;
+
+        let loaded = load_entity_with_passengers(&world, chicken_uuid, &saved, &|uuid| {
+            uuid == skeleton_uuid
+        })
+        .await;
+        let uuids: Vec<_> = loaded
+            .iter()
+            .map(|entity| entity.get_entity().entity_uuid)
+            .collect();
+        assert_eq!(uuids, [chicken_uuid, zombie_uuid]);
+        assert!(loaded[1].get_entity().passengers.lock().await.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn only_the_nether_has_a_bedrock_ceiling() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let overworld = test_world(&dir, &level_info, Dimension::OVERWORLD);
+        let nether = test_world(&dir, &level_info, Dimension::THE_NETHER);
+
+        assert!(nether.check_bedrock_ceiling(BlockPos::new(0, 127, 0)));
+        assert!(nether.check_bedrock_ceiling(BlockPos::new(30, 200, -4)));
+        assert!(!nether.check_bedrock_ceiling(BlockPos::new(0, 126, 0)));
+        assert!(!overworld.check_bedrock_ceiling(BlockPos::new(0, 127, 0)));
+    }
+
+    /// Places bedrock on the Nether ceiling, lets `player` break it and returns whether it broke.
+    async fn break_ceiling(
+        nether: &Arc<World>,
+        player: &Arc<Player>,
+        connection: &mut TestConnection,
+        flags: BlockFlags,
+    ) -> bool {
+        let ceiling = BlockPos::new(8, 127, 8);
+        nether
+            .set_block_state(
+                &ceiling,
+                Block::BEDROCK.default_state.id,
+                BlockFlags::empty(),
+            )
+            .await;
+        connection.received_packet_ids().await;
+
+        let broken = nether
+            .break_block(&ceiling, Some(player.clone()), flags)
+            .await
+            .is_some();
+        let bedrock_left =
+            nether.get_block_state_id(&ceiling).await == Block::BEDROCK.default_state.id;
+        assert_eq!(broken, !bedrock_left);
+        if bedrock_left {
+            // The client already removed the block, so it is told the block is still there
+            assert!(connection.received::<CBlockUpdate>().await);
+        }
+        broken
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn nether_ceiling_mining_follows_the_config() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let nether = test_world_on(&server, &dir, Dimension::THE_NETHER);
+        load_empty_chunk(&nether, Vector2::new(0, 0));
+        let (player, mut connection) = test_player(&nether, "Steve").await;
+        assert!(!break_ceiling(&nether, &player, &mut connection, BlockFlags::empty()).await);
+        assert!(break_ceiling(&nether, &player, &mut connection, BlockFlags::FORCE_BREAK).await);
+
+        let dir = tempdir().unwrap();
+        let advanced_config = AdvancedConfiguration {
+            world: LevelConfig {
+                allow_nether_ceiling_mining: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let server = test_server_with(&dir, advanced_config).await;
+        let nether = test_world_on(&server, &dir, Dimension::THE_NETHER);
+        load_empty_chunk(&nether, Vector2::new(0, 0));
+        let (player, mut connection) = test_player(&nether, "Steve").await;
+        assert!(break_ceiling(&nether, &player, &mut connection, BlockFlags::empty()).await);
+        assert!(break_ceiling(&nether, &player, &mut connection, BlockFlags::FORCE_BREAK).await);
+    }
+
+    #[test]
+    fn at_least_one_player_has_to_sleep() {
+        assert!(!enough_players_sleeping(0, 3, 0));
+        assert!(enough_players_sleeping(1, 3, 0));
+        assert!(!enough_players_sleeping(0, 0, 0));
+        assert!(enough_players_sleeping(2, 3, 50));
+        assert!(!enough_players_sleeping(1, 3, 50));
+    }
+
+    #[test]
+    fn stone_is_opaque_but_glass_is_not() {
+        assert!(Block::STONE.default_state.is_opaque());
+        assert!(!Block::GLASS.default_state.is_opaque());
+        assert!(!Block::OAK_LEAVES.default_state.is_opaque());
+        assert!(!Block::AIR.default_state.is_opaque());
+    }
+
+    fn water_state(level: i16, falling: bool) -> FluidState {
+        FluidState {
+            height: f32::from(level) / 9.0,
+            level,
+            is_empty: false,
+            blast_resistance: 100.0,
+            block_state_id: 0,
+            is_still: level == 8 && !falling,
+            is_source: level == 8 && !falling,
+            falling,
+        }
+    }
+
+    #[test]
+    fn source_fluid_height() {
+        let height = fluid_surface_y(64, &water_state(8, false), false);
+        assert!((height - (64.0 + 8.0 / 9.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn flowing_fluid_height() {
+        let height = fluid_surface_y(64, &water_state(4, false), false);
+        assert!((height - (64.0 + 4.0 / 9.0)).abs() < 1e-6);
+
+        let height = fluid_surface_y(-10, &water_state(1, false), false);
+        assert!((height - (-10.0 + 1.0 / 9.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn spawn_search_visits_every_column_once() {
+        let offsets: Vec<_> = spawn_search_offsets(10, 123).collect();
+        assert_eq!(offsets.len(), 21 * 21);
+        let unique: HashSet<_> = offsets.iter().copied().collect();
+        assert_eq!(unique.len(), offsets.len());
+        assert!(offsets.iter().all(|(x, z)| x.abs() <= 10 && z.abs() <= 10));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn spawn_search_skips_obstructed_center() {
+        let dir = tempdir().unwrap();
+        let level_info = Arc::new(ArcSwap::from_pointee(LevelData::default(Seed(0))));
+        let world = Arc::new(test_world(&dir, &level_info, Dimension::OVERWORLD));
+        let chunk = load_empty_chunk(&world, Vector2::new(0, 0));
+        // The world spawn column is a magma block, and only the column east of it has a floor
+        let center = BlockPos::new(1, 64, 1);
+        chunk
+            .section
+            .set_block_absolute_y(1, 63, 1, Block::MAGMA_BLOCK.default_state.id);
+        chunk
+            .section
+            .set_block_absolute_y(2, 63, 1, Block::STONE.default_state.id);
+        *chunk.heightmap.lock().unwrap() = chunk.calculate_heightmap();
+
+        for _ in 0..9 {
+            let position = world.find_safe_spawn_position(center, 1).await;
+            assert_eq!(position, Vector3::new(2.5, 64.0, 1.5));
+        }
+        assert_eq!(spawn_search_offsets(0, 0).collect::<Vec<_>>(), [(0, 0)]);
+    }
+
+    #[test]
+    fn spawn_search_misses_columns_when_the_step_divides_the_square() {
+        // 17 by 17 columns, every step of 17 lands in the same column of the square
+        let offsets: Vec<_> = spawn_search_offsets(8, 0).collect();
+        assert_eq!(offsets.len(), 17 * 17);
+        let unique: HashSet<_> = offsets.iter().copied().collect();
+        assert_eq!(unique.len(), 17);
+    }
+
+    #[test]
+    fn spawn_needs_safe_floor_and_room() {
+        let stone = Block::STONE.default_state;
+        let air = Block::AIR.default_state;
+        assert!(is_safe_spawn(stone, air, air));
+        assert!(is_safe_spawn(
+            Block::GRASS_BLOCK.default_state,
+            Block::SHORT_GRASS.default_state,
+            air
+        ));
+
+        // Tree tops, hot floors and cacti
+        assert!(!is_safe_spawn(Block::OAK_LEAVES.default_state, air, air));
+        assert!(!is_safe_spawn(Block::MAGMA_BLOCK.default_state, air, air));
+        assert!(!is_safe_spawn(Block::CACTUS.default_state, air, air));
+        // Gaps under leaves, fluids and fire
+        assert!(!is_safe_spawn(stone, air, Block::OAK_LEAVES.default_state));
+        assert!(!is_safe_spawn(stone, Block::LAVA.default_state, air));
+        assert!(!is_safe_spawn(stone, Block::WATER.default_state, air));
+        assert!(!is_safe_spawn(stone, Block::FIRE.default_state, air));
+    }
+
+    #[test]
+    fn biome_samples_start_at_the_origin() {
+        let offsets: Vec<_> = ring_offsets(2).collect();
+        assert_eq!(offsets.len(), 25);
+        assert_eq!(offsets[0], (0, 0));
+        assert!(offsets[1..9].iter().all(|(x, z)| x.abs().max(z.abs()) == 1));
+        assert_eq!(offsets.iter().collect::<HashSet<_>>().len(), 25);
+    }
+
+    #[test]
+    fn fluid_below_same_fluid_fills_block() {
+        assert!((fluid_surface_y(64, &water_state(8, true), true) - 65.0).abs() < 1e-6);
+        assert!((fluid_surface_y(64, &water_state(3, false), true) - 65.0).abs() < 1e-6);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cancelled_entity_spawn_adds_nothing() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        load_empty_chunk(&world, Vector2::new(0, 0));
+        let (_player, mut connection) = test_player(&world, "Steve").await;
+        connection.received_packet_ids().await;
+        cancel_all::<EntitySpawnEvent>(&server).await;
+
+        let uuid = uuid::Uuid::new_v4();
+        let zombie = from_type(
+            &EntityType::ZOMBIE,
+            Vector3::new(4.5, 64.0, 4.5),
+            &world,
+            uuid,
+        )
+        .await;
+        let id = zombie.get_entity().entity_id;
+        world.spawn_entity(zombie).await;
+
+        assert!(world.get_entity_by_uuid(uuid).is_none());
+        let around = BoundingBox::new(Vector3::new(0.0, 60.0, 0.0), Vector3::new(8.0, 68.0, 8.0));
+        assert!(
+            world
+                .entity_sections
+                .get_in_box(&around)
+                .iter()
+                .all(|entity| entity.get_entity().entity_id != id)
+        );
+        let entity_chunk = world.level.get_entity_chunk(Vector2::new(0, 0)).await;
+        assert!(!entity_chunk.data.lock().await.contains_key(&uuid));
+        assert!(!connection.received::<CSpawnEntity>().await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cancelled_chunk_load_keeps_the_chunk_from_listeners() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let listener = world.level.chunk_listener.clone();
+        let players = listener.add_global_chunk_listener();
+
+        let loaded = Vector2::new(0, 0);
+        let chunk = load_empty_chunk(&world, loaded);
+        listener.process_new_chunk(loaded, &chunk);
+        // Held back until the event was fired
+        assert!(players.try_recv().is_err());
+        assert!(listener.is_chunk_held(&loaded));
+        world.fire_chunk_loads(&server).await;
+        assert_eq!(players.try_recv().unwrap().0, loaded);
+        assert!(!listener.is_chunk_held(&loaded));
+
+        cancel_all::<ChunkLoad>(&server).await;
+        let cancelled = Vector2::new(1, 0);
+        let chunk = load_empty_chunk(&world, cancelled);
+        listener.process_new_chunk(cancelled, &chunk);
+        world.fire_chunk_loads(&server).await;
+        assert!(players.try_recv().is_err());
+        assert!(listener.is_chunk_withheld(&cancelled));
+        // Not even a resend announces it
+        assert!(world.level.resend_chunk(cancelled));
+        assert!(players.try_recv().is_err());
+
+        // Until it unloads
+        listener.process_unloaded_chunk(cancelled);
+        assert!(!listener.is_chunk_held(&cancelled));
+        assert!(!listener.is_chunk_withheld(&cancelled));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn broadcast_now_arrives_before_later_packets() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let (steve, mut steve_connection) = test_player(&world, "Steve").await;
+        let (alex, mut alex_connection) = test_player(&world, "Alex").await;
+        steve_connection.received_packet_ids().await;
+        alex_connection.received_packet_ids().await;
+
+        world
+            .broadcast_packet_now_all(&CChangeDifficulty::new(0, false))
+            .await;
+        for player in [&steve, &alex] {
+            player
+                .client
+                .send_packet_now(&CGameEvent::new(GameEvent::BeginRaining, 0.0))
+                .await;
+        }
+
+        for connection in [&mut steve_connection, &mut alex_connection] {
+            let ids = connection.received_packet_ids().await;
+            let position = |id| ids.iter().position(|received| *received == id).unwrap();
+            assert!(
+                position(CChangeDifficulty::PACKET_ID.latest_id)
+                    < position(CGameEvent::PACKET_ID.latest_id)
+            );
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn autosave_writes_the_scheduled_callbacks() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let trigger_time = world
+            .schedule_named_callback("test:later".to_string(), 40, ScheduleMode::Append)
+            .await
+            .unwrap();
+
+        let autosave = world.level.autosave_ticks as i64;
+        world.level_time.lock().await.world_age = autosave - 1;
+        world.tick_environment().await;
+
+        // level.dat is written in the background
+        let world_path = server.basic_config.get_world_path();
+        let mut saved = None;
+        for _ in 0..100 {
+            if let Ok(level_data) = AnvilLevelInfo.read_world_info(&world_path)
+                && !level_data.scheduled_events.is_empty()
+            {
+                saved = Some(level_data);
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+        let level_data = saved.expect("the autosave didn't write level.dat");
+        assert_eq!(level_data.time, autosave);
+
+        let loaded = ScheduledCallbacks::from_events(&level_data.scheduled_events);
+        assert!(loaded.take_due(trigger_time - 1).is_empty());
+        let due: Vec<_> = loaded
+            .take_due(trigger_time)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(due, ["test:later"]);
+    }
+
+    /// A zombie spawner in the middle of an empty chunk that is ready to spawn.
+    fn ready_zombie_spawner(world: &World, max_nearby_entities: u8) -> MobSpawnerBlockEntity {
+        load_empty_chunk(world, Vector2::new(0, 0));
+        let spawner = MobSpawnerBlockEntity {
+            max_nearby_entities,
+            ..MobSpawnerBlockEntity::new(BlockPos::new(8, 64, 8))
+        };
+        spawner.set_entity_type(&EntityType::ZOMBIE);
+        spawner.delay.store(0, Relaxed);
+        spawner
+    }
+
+    fn zombies_around_spawner(world: &World) -> usize {
+        let around = BoundingBox::from_block(&BlockPos::new(8, 64, 8)).expand_all(8.0);
+        world
+            .get_entities_at_box(&around)
+            .iter()
+            .filter(|entity| entity.get_entity().entity_type == &EntityType::ZOMBIE)
+            .count()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn spawner_needs_a_player_in_range() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let spawner = ready_zombie_spawner(&world, 6);
+        let (player, _connection) = test_player(&world, "Steve").await;
+        player
+            .living_entity
+            .entity
+            .set_pos(Vector3::new(8.5, 64.0, 40.5));
+
+        let simple_world: Arc<dyn SimpleWorld> = world.clone();
+        spawner.tick(&simple_world).await;
+        assert_eq!(zombies_around_spawner(&world), 0);
+        // The delay doesn't count down either
+        assert_eq!(spawner.delay.load(Relaxed), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn spawner_stops_at_max_nearby_entities() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let spawner = ready_zombie_spawner(&world, 2);
+        let (player, _connection) = test_player(&world, "Steve").await;
+        player
+            .living_entity
+            .entity
+            .set_pos(Vector3::new(8.5, 64.0, 12.5));
+
+        // It tries to spawn 4 at once but stops after 2
+        let simple_world: Arc<dyn SimpleWorld> = world.clone();
+        spawner.tick(&simple_world).await;
+        assert_eq!(zombies_around_spawner(&world), 2);
+        assert!(spawner.delay.load(Relaxed) >= spawner.min_spawn_delay);
+
+        spawner.delay.store(0, Relaxed);
+        spawner.tick(&simple_world).await;
+        assert_eq!(zombies_around_spawner(&world), 2);
+    }
+}
@@ -4,10 +4,16 @@ use crate::world::World;
 use arc_swap::ArcSwap;
 use pumpkin_data::biome::Spawner;
 use pumpkin_data::entity::{EntityType, MobCategory, SpawnLocation};
-use pumpkin_data::tag::Block::MINECRAFT_PREVENT_MOB_SPAWNING_INSIDE;
-use pumpkin_data::tag::Fluid::{MINECRAFT_LAVA, MINECRAFT_WATER};
-use pumpkin_data::tag::Taggable;
+use pumpkin_data::tag::Block::{
+    C_GLASS_BLOCKS, MINECRAFT_ANIMALS_SPAWNABLE_ON, MINECRAFT_ARMADILLO_SPAWNABLE_ON,
+    MINECRAFT_CAMELS_SPAWNABLE_ON, MINECRAFT_FIRE, MINECRAFT_FOXES_SPAWNABLE_ON,
+    MINECRAFT_FROGS_SPAWNABLE_ON, MINECRAFT_GOATS_SPAWNABLE_ON, MINECRAFT_LEAVES,
+    MINECRAFT_MOOSHROOMS_SPAWNABLE_ON, MINECRAFT_PARROTS_SPAWNABLE_ON,
+    MINECRAFT_POLAR_BEARS_SPAWNABLE_ON_ALTERNATE, MINECRAFT_PREVENT_MOB_SPAWNING_INSIDE,
+    MINECRAFT_RABBITS_SPAWNABLE_ON, MINECRAFT_SAND, MINECRAFT_WOLVES_SPAWNABLE_ON,
+};
 use pumpkin_data::tag::WorldgenBiome::MINECRAFT_REDUCE_WATER_AMBIENT_SPAWNS;
+use pumpkin_data::tag::{Tag, Taggable};
 use pumpkin_data::{Block, BlockDirection, BlockState};
 use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_util::GameMode;
@@ -30,6 +36,19 @@ use uuid::Uuid;
 
 const MAGIC_NUMBER: i32 = 17 * 17;
 
+/// Entity types which have no natural spawn conditions and are never spawned by the natural spawner.
+pub const NO_SPAWN_CONDITIONS: &[&EntityType] = &[
+    &EntityType::ENDER_DRAGON,
+    &EntityType::WITHER,
+    &EntityType::GIANT,
+    &EntityType::ILLUSIONER,
+];
+
+/// The highest sky light monsters may spawn in, after the darkening of the time of day.
+const MONSTER_MAX_SKY_LIGHT: u8 = 7;
+/// Creatures need a brighter light level than this to spawn.
+const CREATURE_MIN_LIGHT: u8 = 8;
+
 #[derive(Default, Debug)]
 pub struct MobCounts([i32; 8]);
 
@@ -183,8 +202,10 @@ impl fmt::Debug for SpawnState {
 }
 
 impl SpawnState {
+    /// Counts the mobs in the chunks mobs can spawn in. Mobs in other chunks don't take up the
+    /// mob caps, which scale with the number of spawning chunks.
     pub async fn new(
-        chunk_count: i32,
+        spawning_chunks: &HashMap<Vector2<i32>, Arc<ChunkData>>,
         entities: &ArcSwap<Vec<Arc<dyn EntityBase>>>,
         world: &Arc<World>,
     ) -> Self {
@@ -198,6 +219,9 @@ impl SpawnState {
                 // TODO (mob.isPersistenceRequired() || mob.requiresCustomPersistence())
                 continue;
             }
+            if !spawning_chunks.contains_key(&entity.chunk_pos.load()) {
+                continue;
+            }
             let entity_pos = entity.block_pos.load();
             let biome = world.level.get_rough_biome(&entity_pos).await;
             if let Some(cost) = biome.spawn_costs.get(entity_type.resource_name) {
@@ -209,7 +233,7 @@ impl SpawnState {
             counter.add(entity_type.category);
         }
         Self {
-            spawnable_chunk_count: chunk_count,
+            spawnable_chunk_count: spawning_chunks.len() as i32,
             mob_category_counts: counter,
             spawn_potential: potential,
             local_mob_cap_calculator: local_mob_cap,
@@ -339,12 +363,7 @@ pub fn get_random_pos_within(
 
     let x = (chunk_pos.x << 4) + rng.next_bounded_i32(16);
     let z = (chunk_pos.y << 4) + rng.next_bounded_i32(16);
-    let temp_y = chunk
-        .heightmap
-        .lock()
-        .unwrap()
-        .get(ChunkHeightmapType::WorldSurface, x, z, min_y)
-        + 1;
+    let temp_y = chunk.get_height(ChunkHeightmapType::WorldSurface, x, z) + 1;
     let y = rng.next_inbetween_i32(min_y, temp_y);
     BlockPos::new(x, y, z)
 }
@@ -360,29 +379,36 @@ pub async fn spawn_category_for_position(
     // TODO blockState.isRedstoneConductor(chunk, pos) is true then return
     let mut batch_buffer = vec![];
     let mut spawn_cluster_size = 0;
-    let mut new_pos = pos;
     let player_positions: Vec<_> = world.players.load().iter().map(|p| p.position()).collect();
-    for _ in 0..3 {
-        let mut new_x = new_pos.0.x;
-        let mut new_z = new_pos.0.z;
+    'packs: for _ in 0..3 {
+        // Every pack wanders off from the initial position on its own, so spawns cluster around it
+        let mut new_x = pos.0.x;
+        let mut new_z = pos.0.z;
+        let mut pack_spawner: Option<&'static Spawner> = None;
 
+        // Up to 4 tries, until the mob of the pack is picked and decides the group size
         let mut random_group_size = (rng().random::<f32>() * 4.).ceil() as i32;
         let mut inc = 0;
 
-        'outer: while inc < random_group_size {
+        while inc < random_group_size {
+            inc += 1;
             new_x += rng().random_range(0..6) - rng().random_range(0..6);
             new_z += rng().random_range(0..6) - rng().random_range(0..6);
-            new_pos = BlockPos::new(new_x, new_pos.0.y, new_z);
+            let new_pos = BlockPos::new(new_x, pos.0.y, new_z);
             let new_pos_center = new_pos.to_centered_f64();
             let player_distance = get_nearest_player(&new_pos_center, &player_positions);
             if !is_right_distance_to_player_and_spawn_point(&new_pos, player_distance, chunk_pos) {
-                inc += 1;
                 continue;
             }
-            let Some(spawner) = get_random_spawn_mob_at(world, category, &new_pos).await else {
-                break 'outer;
+            let spawner = if let Some(spawner) = pack_spawner {
+                spawner
+            } else {
+                let Some(spawner) = get_random_spawn_mob_at(world, category, &new_pos).await else {
+                    break;
+                };
+                random_group_size = rng().random_range(spawner.min_count..=spawner.max_count);
+                *pack_spawner.insert(spawner)
             };
-            random_group_size = rng().random_range(spawner.min_count..=spawner.max_count);
             let entity_type =
                 &EntityType::from_name(spawner.r#type.strip_prefix("minecraft:").unwrap()).unwrap();
             if !is_valid_spawn_position_for_type(
@@ -394,11 +420,9 @@ pub async fn spawn_category_for_position(
             )
             .await
             {
-                inc += 1;
                 continue;
             }
             if !spawn_state.can_spawn(entity_type, &new_pos, world).await {
-                inc += 1;
                 continue;
             }
             let entity = from_type(entity_type, new_pos_center, world, Uuid::new_v4()).await;
@@ -408,15 +432,13 @@ pub async fn spawn_category_for_position(
             // TODO isValidPositionForMob(level, mob, f)
             // TODO spawnGroupData = mob.finalizeSpawn(level, level.getCurrentDifficultyAt(mob.blockPosition()), EntitySpawnReason.NATURAL, spawnGroupData);
             spawn_cluster_size += 1;
-            //group_size += 1;
             batch_buffer.push(entity);
             spawn_state.after_spawn(entity_type, &new_pos, world).await;
             if spawn_cluster_size >= entity_type.limit_per_chunk {
-                return;
+                break 'packs;
             }
 
             //TODO mob.isMaxGroupSizeReached(p)
-            inc += 1;
         }
     }
 
@@ -457,6 +479,14 @@ pub async fn spawn_category_for_position(
                 }
                 new_entities
             });
+            for (uuid, _, _, entity_ref) in &prepared_data {
+                // Skip the ones that lost against an entity with the same UUID
+                if world.get_entity_by_uuid(*uuid).is_some_and(|loaded| {
+                    loaded.get_entity().entity_id == entity_ref.get_entity().entity_id
+                }) {
+                    world.entity_sections.insert(entity_ref.clone());
+                }
+            }
         };
 
         for (_, _, packet, _) in prepared_data {
@@ -551,48 +581,186 @@ pub async fn is_valid_spawn_position_for_type(
     if !entity_type.summonable {
         return false;
     }
-    if !is_spawn_position_ok(world, block_pos, entity_type).await {
+    if !world.can_entities_spawn_at(*block_pos, entity_type).await {
         return false;
     }
     // TODO: we should use getSpawnBox, but this is only modified for slimes and magma slimes
-    world
-        .is_space_empty(BoundingBox::new_from_pos(
-            f64::from(block_pos.0.x) + 0.5,
-            f64::from(block_pos.0.y),
-            f64::from(block_pos.0.z) + 0.5,
-            &EntityDimensions {
-                width: entity_type.dimension[0],
-                height: entity_type.dimension[1],
-                eye_height: entity_type.eye_height,
-            },
-        ))
-        .await
+    // Wide entities at the edge of the chunk reach into its neighbors, which must not be loaded
+    // just for this check
+    world.is_loaded_space_empty(&BoundingBox::new_from_pos(
+        f64::from(block_pos.0.x) + 0.5,
+        f64::from(block_pos.0.y),
+        f64::from(block_pos.0.z) + 0.5,
+        &EntityDimensions {
+            width: entity_type.dimension[0],
+            height: entity_type.dimension[1],
+            eye_height: entity_type.eye_height,
+        },
+    ))
 }
 
-pub async fn is_spawn_position_ok(
-    world: &Arc<World>,
-    block_pos: &BlockPos,
-    entity_type: &'static EntityType,
-) -> bool {
-    match entity_type.spawn_restriction.location {
-        SpawnLocation::InLava => world.get_fluid(block_pos).await.has_tag(&MINECRAFT_LAVA),
-        SpawnLocation::InWater => {
-            // TODO !level.getBlockState(blockPos).isRedstoneConductor(level, blockPos)
-            world.get_fluid(block_pos).await.has_tag(&MINECRAFT_WATER)
+/// The blocks and light levels around a position, used to decide whether an entity can spawn there.
+pub struct SpawnSurroundings {
+    /// The block the entity would stand on.
+    pub below: &'static BlockState,
+    /// The block the entity's feet would be in.
+    pub at: &'static BlockState,
+    /// The block the entity's head would be in.
+    pub above: &'static BlockState,
+    pub block_light: u8,
+    /// The sky light, as stored by the light engine.
+    pub sky_light: u8,
+    /// How much the sky light is dimmed by the time of day and the weather, see
+    /// [`World::get_ambient_darkness`].
+    pub sky_darkness: u8,
+    /// The highest block light monsters may spawn in, this depends on the dimension.
+    pub monster_block_light_limit: u8,
+    /// The height the entity would spawn at.
+    pub y: i32,
+    pub sea_level: i32,
+}
+
+impl SpawnSurroundings {
+    /// Runs every block and light check for the given entity type.
+    ///
+    /// Fluid requirements of `InWater` and `InLava` entities are checked by the world,
+    /// since they need the fluid state at the position.
+    #[must_use]
+    pub fn allows(&self, entity_type: &EntityType) -> bool {
+        if NO_SPAWN_CONDITIONS.iter().any(|ty| *ty == entity_type) {
+            return false;
         }
-        SpawnLocation::OnGround => {
-            let down = world.get_block_state(&block_pos.down()).await;
-            let up = world.get_block_state(&block_pos.up()).await;
-            let cur = world.get_block_state(block_pos).await;
-            // TODO: blockState.allowsSpawning
-            if down.is_side_solid(BlockDirection::Up) {
-                is_valid_empty_spawn_block(cur) && is_valid_empty_spawn_block(up)
-            } else {
-                false
+        // Striders only need the lava surface the world checks for, no light or floor
+        if entity_type == &EntityType::STRIDER {
+            return !is_block_dangerous(self.at, entity_type);
+        }
+        if !is_light_valid_for_category(
+            entity_type.category,
+            self.block_light,
+            self.sky_light,
+            self.sky_darkness,
+            self.monster_block_light_limit,
+        ) {
+            return false;
+        }
+        if matches!(
+            entity_type.spawn_restriction.location,
+            SpawnLocation::OnGround
+        ) && !(is_valid_spawn_floor(self.below, entity_type)
+            && is_valid_empty_spawn_block(self.at)
+            && is_valid_empty_spawn_block(self.above))
+        {
+            return false;
+        }
+        if entity_type == &EntityType::TURTLE {
+            // Turtles spawn on beaches
+            if !(self.y < self.sea_level + 4
+                && Block::from_state_id(self.below.id).has_tag(&MINECRAFT_SAND))
+            {
+                return false;
             }
+        } else if entity_type.category == &MobCategory::CREATURE
+            && !is_valid_animal_floor(self.below, entity_type)
+        {
+            return false;
         }
-        SpawnLocation::Unrestricted => true,
+        !is_block_dangerous(self.at, entity_type)
+    }
+}
+
+/// Monsters need darkness, where the sky light counts as dimmed by `sky_darkness`. Creatures need
+/// a bright spot, but unlike monsters they only look at the undimmed light, so they also spawn at
+/// night.
+#[must_use]
+pub fn is_light_valid_for_category(
+    category: &MobCategory,
+    block_light: u8,
+    sky_light: u8,
+    sky_darkness: u8,
+    monster_block_light_limit: u8,
+) -> bool {
+    if category == &MobCategory::MONSTER {
+        block_light <= monster_block_light_limit
+            && sky_light.saturating_sub(sky_darkness) <= MONSTER_MAX_SKY_LIGHT
+    } else if category == &MobCategory::CREATURE {
+        block_light.max(sky_light) > CREATURE_MIN_LIGHT
+    } else {
+        true
+    }
+}
+
+/// Whether an entity can stand on top of the given block when spawning.
+#[must_use]
+pub fn is_valid_spawn_floor(state: &BlockState, entity_type: &EntityType) -> bool {
+    if !state.is_side_solid(BlockDirection::Up) {
+        return false;
+    }
+    // TODO: blockState.allowsSpawning
+    let block = Block::from_state_id(state.id);
+    if block == &Block::BEDROCK || block == &Block::BARRIER || block.has_tag(&C_GLASS_BLOCKS) {
+        return false;
+    }
+    if block.has_tag(&MINECRAFT_LEAVES) {
+        return entity_type == &EntityType::OCELOT || entity_type == &EntityType::PARROT;
+    }
+    if block == &Block::ICE {
+        return entity_type == &EntityType::POLAR_BEAR;
+    }
+    if block == &Block::MAGMA_BLOCK {
+        return entity_type.fire_immune;
     }
+    true
+}
+
+/// Whether an animal can spawn on top of the given block. Most animals need grass, the others have
+/// their own `*_spawnable_on` tag.
+#[must_use]
+pub fn is_valid_animal_floor(state: &BlockState, entity_type: &EntityType) -> bool {
+    let block = Block::from_state_id(state.id);
+    // Polar bears also spawn on ice and ocelots in jungle trees
+    if (entity_type == &EntityType::POLAR_BEAR
+        && block.has_tag(&MINECRAFT_POLAR_BEARS_SPAWNABLE_ON_ALTERNATE))
+        || (entity_type == &EntityType::OCELOT && block.has_tag(&MINECRAFT_LEAVES))
+    {
+        return true;
+    }
+    let floor: &'static Tag = if entity_type == &EntityType::MOOSHROOM {
+        &MINECRAFT_MOOSHROOMS_SPAWNABLE_ON
+    } else if entity_type == &EntityType::GOAT {
+        &MINECRAFT_GOATS_SPAWNABLE_ON
+    } else if entity_type == &EntityType::RABBIT {
+        &MINECRAFT_RABBITS_SPAWNABLE_ON
+    } else if entity_type == &EntityType::FOX {
+        &MINECRAFT_FOXES_SPAWNABLE_ON
+    } else if entity_type == &EntityType::WOLF {
+        &MINECRAFT_WOLVES_SPAWNABLE_ON
+    } else if entity_type == &EntityType::PARROT {
+        &MINECRAFT_PARROTS_SPAWNABLE_ON
+    } else if entity_type == &EntityType::FROG {
+        &MINECRAFT_FROGS_SPAWNABLE_ON
+    } else if entity_type == &EntityType::CAMEL {
+        &MINECRAFT_CAMELS_SPAWNABLE_ON
+    } else if entity_type == &EntityType::ARMADILLO {
+        &MINECRAFT_ARMADILLO_SPAWNABLE_ON
+    } else {
+        &MINECRAFT_ANIMALS_SPAWNABLE_ON
+    };
+    block.has_tag(floor)
+}
+
+/// Whether standing in the given block would hurt the entity.
+#[must_use]
+pub fn is_block_dangerous(state: &BlockState, entity_type: &EntityType) -> bool {
+    let block = Block::from_state_id(state.id);
+    if !entity_type.fire_immune
+        && (block == &Block::LAVA || block == &Block::MAGMA_BLOCK || block.has_tag(&MINECRAFT_FIRE))
+    {
+        return true;
+    }
+    block == &Block::CACTUS
+        || block == &Block::SWEET_BERRY_BUSH
+        || block == &Block::WITHER_ROSE
+        || block == &Block::POWDER_SNOW
 }
 
 #[must_use]
@@ -601,6 +769,219 @@ pub fn is_valid_empty_spawn_block(state: &'static BlockState) -> bool {
     if state.is_full_cube() || state.is_liquid() {
         return false;
     }
-    // TODO !entityType.isBlockDangerous(blockState);
     !Block::from_state_id(state.id).has_tag(&MINECRAFT_PREVENT_MOB_SPAWNING_INSIDE)
 }
+
+#[cfg(test)]
+mod test {
+    use super::{SpawnState, SpawnSurroundings, is_block_dangerous};
+    use crate::entity::r#type::from_type;
+    use crate::server::test::test_server;
+    use crate::world::test::{load_empty_chunk, test_world_on};
+    use pumpkin_data::dimension::Dimension;
+    use pumpkin_data::entity::{EntityType, MobCategory};
+    use pumpkin_data::{Block, BlockState};
+    use pumpkin_util::math::vector2::Vector2;
+    use pumpkin_util::math::vector3::Vector3;
+    use std::collections::HashMap;
+    use tempfile::tempdir;
+    use uuid::Uuid;
+
+    fn surroundings(below: &Block, at: &Block, above: &Block) -> SpawnSurroundings {
+        SpawnSurroundings {
+            below: below.default_state,
+            at: at.default_state,
+            above: above.default_state,
+            block_light: 0,
+            sky_light: 0,
+            sky_darkness: 0,
+            monster_block_light_limit: 0,
+            y: 64,
+            sea_level: 63,
+        }
+    }
+
+    fn dark_ground() -> SpawnSurroundings {
+        surroundings(&Block::STONE, &Block::AIR, &Block::AIR)
+    }
+
+    #[test]
+    fn valid_setup() {
+        assert!(dark_ground().allows(&EntityType::ZOMBIE));
+    }
+
+    #[test]
+    fn light_level() {
+        let mut lit = dark_ground();
+        lit.block_light = 1;
+        assert!(!lit.allows(&EntityType::ZOMBIE));
+
+        let mut daylight = dark_ground();
+        daylight.sky_light = 15;
+        assert!(!daylight.allows(&EntityType::ZOMBIE));
+
+        let mut nether = dark_ground();
+        nether.block_light = 15;
+        nether.monster_block_light_limit = 15;
+        assert!(nether.allows(&EntityType::ZOMBIE));
+
+        let mut grass = surroundings(&Block::GRASS_BLOCK, &Block::AIR, &Block::AIR);
+        assert!(!grass.allows(&EntityType::COW));
+        grass.sky_light = 15;
+        assert!(grass.allows(&EntityType::COW));
+    }
+
+    #[test]
+    fn time_of_day() {
+        let mut night = dark_ground();
+        night.sky_light = 15;
+        night.sky_darkness = 11;
+        assert!(night.allows(&EntityType::ZOMBIE));
+
+        // Dusk isn't dark enough yet
+        night.sky_darkness = 7;
+        assert!(!night.allows(&EntityType::ZOMBIE));
+
+        let mut grass = surroundings(&Block::GRASS_BLOCK, &Block::AIR, &Block::AIR);
+        grass.sky_light = 15;
+        grass.sky_darkness = 11;
+        assert!(grass.allows(&EntityType::COW));
+    }
+
+    #[test]
+    fn animal_floor() {
+        let lit = |below: &Block| {
+            let mut surroundings = surroundings(below, &Block::AIR, &Block::AIR);
+            surroundings.sky_light = 15;
+            surroundings
+        };
+        assert!(lit(&Block::GRASS_BLOCK).allows(&EntityType::COW));
+        assert!(!lit(&Block::STONE).allows(&EntityType::COW));
+        assert!(!lit(&Block::SAND).allows(&EntityType::PIG));
+
+        assert!(lit(&Block::MYCELIUM).allows(&EntityType::MOOSHROOM));
+        assert!(!lit(&Block::GRASS_BLOCK).allows(&EntityType::MOOSHROOM));
+        assert!(lit(&Block::SNOW_BLOCK).allows(&EntityType::WOLF));
+    }
+
+    #[test]
+    fn turtles_spawn_on_beaches() {
+        let mut beach = surroundings(&Block::SAND, &Block::AIR, &Block::AIR);
+        beach.sky_light = 15;
+        assert!(beach.allows(&EntityType::TURTLE));
+        assert!(!beach.allows(&EntityType::COW));
+
+        let mut grass = surroundings(&Block::GRASS_BLOCK, &Block::AIR, &Block::AIR);
+        grass.sky_light = 15;
+        assert!(!grass.allows(&EntityType::TURTLE));
+
+        beach.y = beach.sea_level + 4;
+        assert!(!beach.allows(&EntityType::TURTLE));
+    }
+
+    #[test]
+    fn striders_ignore_the_animal_rules() {
+        // Lit by nothing and standing in lava over netherrack, like a lava sea
+        let lava = surroundings(&Block::NETHERRACK, &Block::LAVA, &Block::AIR);
+        assert!(lava.allows(&EntityType::STRIDER));
+        assert!(!lava.allows(&EntityType::PIG));
+    }
+
+    #[test]
+    fn block_below() {
+        let grid: [(&Block, bool); 5] = [
+            (&Block::STONE, true),
+            (&Block::AIR, false),
+            (&Block::BEDROCK, false),
+            (&Block::BARRIER, false),
+            (&Block::GLASS, false),
+        ];
+        for (below, expected) in grid {
+            assert_eq!(
+                surroundings(below, &Block::AIR, &Block::AIR).allows(&EntityType::ZOMBIE),
+                expected,
+                "{}",
+                below.name
+            );
+        }
+
+        let magma = surroundings(&Block::MAGMA_BLOCK, &Block::AIR, &Block::AIR);
+        assert!(!magma.allows(&EntityType::ZOMBIE));
+        assert!(magma.allows(&EntityType::BLAZE));
+    }
+
+    #[test]
+    fn blocks_above() {
+        let grid: [(&Block, &Block, bool); 5] = [
+            (&Block::AIR, &Block::AIR, true),
+            (&Block::SHORT_GRASS, &Block::AIR, true),
+            (&Block::STONE, &Block::AIR, false),
+            (&Block::AIR, &Block::STONE, false),
+            (&Block::RAIL, &Block::AIR, false),
+        ];
+        for (at, above, expected) in grid {
+            assert_eq!(
+                surroundings(&Block::STONE, at, above).allows(&EntityType::ZOMBIE),
+                expected,
+                "{} {}",
+                at.name,
+                above.name
+            );
+        }
+    }
+
+    #[test]
+    fn no_spawn_conditions() {
+        assert!(!dark_ground().allows(&EntityType::WITHER));
+        assert!(!dark_ground().allows(&EntityType::GIANT));
+    }
+
+    #[test]
+    fn dangerous_blocks() {
+        let dangerous: [&BlockState; 3] = [
+            Block::LAVA.default_state,
+            Block::FIRE.default_state,
+            Block::CACTUS.default_state,
+        ];
+        for state in dangerous {
+            assert!(is_block_dangerous(state, &EntityType::ZOMBIE));
+        }
+        assert!(!is_block_dangerous(
+            Block::FIRE.default_state,
+            &EntityType::BLAZE
+        ));
+        assert!(is_block_dangerous(
+            Block::CACTUS.default_state,
+            &EntityType::BLAZE
+        ));
+        assert!(!is_block_dangerous(
+            Block::AIR.default_state,
+            &EntityType::ZOMBIE
+        ));
+
+        let fire = surroundings(&Block::STONE, &Block::FIRE, &Block::AIR);
+        assert!(!fire.allows(&EntityType::ZOMBIE));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn only_mobs_in_spawning_chunks_count() {
+        let dir = tempdir().unwrap();
+        let server = test_server(&dir).await;
+        let world = test_world_on(&server, &dir, Dimension::OVERWORLD);
+        let spawning_chunk = Vector2::new(0, 0);
+        load_empty_chunk(&world, spawning_chunk);
+        load_empty_chunk(&world, Vector2::new(5, 0));
+        for position in [Vector3::new(4.5, 64.0, 4.5), Vector3::new(84.5, 64.0, 4.5)] {
+            let zombie = from_type(&EntityType::ZOMBIE, position, &world, Uuid::new_v4()).await;
+            world.spawn_entity(zombie).await;
+        }
+        let spawning_chunks = HashMap::from([(
+            spawning_chunk,
+            world.get_chunk_if_loaded(spawning_chunk).unwrap(),
+        )]);
+
+        let state = SpawnState::new(&spawning_chunks, &world.entities, &world).await;
+        assert_eq!(state.spawnable_chunk_count, 1);
+        assert_eq!(state.mob_category_counts.0[MobCategory::MONSTER.id], 1);
+    }
+}
@@ -0,0 +1,298 @@
+use std::{
+    collections::HashMap,
+    fs::{File, create_dir_all},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+use pumpkin_util::random::{RandomImpl, get_seed, xoroshiro128::Xoroshiro};
+use thiserror::Error;
+
+const DATA_VERSION: i32 = pumpkin_world::chunk::format::anvil::WORLD_DATA_VERSION;
+
+#[derive(Debug, Error)]
+pub enum RandomSequencesDataError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("NBT error: {0}")]
+    Nbt(String),
+}
+
+/// A handle to one random sequence. Cloning is cheap; all clones share the same state, which is
+/// locked per sequence.
+#[derive(Clone)]
+pub struct RandomHandle(Arc<Mutex<Xoroshiro>>);
+
+impl RandomHandle {
+    fn new(random: Xoroshiro) -> Self {
+        Self(Arc::new(Mutex::new(random)))
+    }
+
+    /// Runs `f` with the sequence's generator, advancing the sequence.
+    pub fn with<T>(&self, f: impl FnOnce(&mut Xoroshiro) -> T) -> T {
+        f(&mut self.0.lock().unwrap())
+    }
+
+    /// Returns a random value in `min..=max`.
+    pub fn next_between_inclusive(&self, min: i32, max: i32) -> i32 {
+        self.with(|random| random.next_bounded_i32(max - min + 1) + min)
+    }
+
+    fn state(&self) -> (u64, u64) {
+        self.with(Xoroshiro::state)
+    }
+
+    fn set(&self, random: Xoroshiro) {
+        *self.0.lock().unwrap() = random;
+    }
+}
+
+/// How new (or reset) sequences are seeded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SequenceSettings {
+    pub salt: i32,
+    pub include_world_seed: bool,
+    pub include_sequence_id: bool,
+}
+
+impl Default for SequenceSettings {
+    fn default() -> Self {
+        Self {
+            salt: 0,
+            include_world_seed: true,
+            include_sequence_id: true,
+        }
+    }
+}
+
+#[derive(Default)]
+struct SequencesInner {
+    settings: SequenceSettings,
+    sequences: HashMap<String, RandomHandle>,
+}
+
+/// Named random sequences, e.g. used by `/random` and persisted in `random_sequences.dat`.
+pub struct RandomSequences {
+    world_seed: u64,
+    inner: Mutex<SequencesInner>,
+    /// Used when no sequence is named. Seeded independently of the world seed so it can't be
+    /// used to learn anything about world generation, and not persisted.
+    default: RandomHandle,
+}
+
+impl RandomSequences {
+    #[must_use]
+    pub fn new(world_seed: u64) -> Self {
+        Self {
+            world_seed,
+            inner: Mutex::new(SequencesInner::default()),
+            default: RandomHandle::new(Xoroshiro::from_seed(get_seed())),
+        }
+    }
+
+    /// Returns the sequence named `id`, creating it from the world seed and `id` if it doesn't
+    /// exist yet. `None` gives the unnamed default sequence.
+    pub fn get(&self, id: Option<&str>) -> RandomHandle {
+        let Some(id) = id else {
+            return self.default.clone();
+        };
+        let mut inner = self.inner.lock().unwrap();
+        let settings = inner.settings;
+        inner
+            .sequences
+            .entry(id.to_string())
+            .or_insert_with(|| RandomHandle::new(self.create(id, settings)))
+            .clone()
+    }
+
+    /// Restarts the sequence `id` using the current default settings.
+    pub fn reset(&self, id: &str) {
+        let settings = self.inner.lock().unwrap().settings;
+        self.reset_with(id, settings);
+    }
+
+    /// Restarts the sequence `id` using `settings`. Existing handles see the new state.
+    pub fn reset_with(&self, id: &str, settings: SequenceSettings) {
+        let random = self.create(id, settings);
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(handle) = inner.sequences.get(id) {
+            handle.set(random);
+            return;
+        }
+        inner
+            .sequences
+            .insert(id.to_string(), RandomHandle::new(random));
+    }
+
+    /// Forgets all sequences and returns how many there were.
+    pub fn clear(&self) -> usize {
+        let mut inner = self.inner.lock().unwrap();
+        let count = inner.sequences.len();
+        inner.sequences.clear();
+        count
+    }
+
+    /// Forgets all sequences, makes new ones use `settings` and returns how many there were.
+    pub fn clear_and_set_defaults(&self, settings: SequenceSettings) -> usize {
+        let mut inner = self.inner.lock().unwrap();
+        inner.settings = settings;
+        let count = inner.sequences.len();
+        inner.sequences.clear();
+        count
+    }
+
+    fn create(&self, id: &str, settings: SequenceSettings) -> Xoroshiro {
+        let world_seed = if settings.include_world_seed {
+            self.world_seed
+        } else {
+            0
+        };
+        let seed = world_seed ^ i64::from(settings.salt) as u64;
+        Xoroshiro::from_sequence_seed(seed, settings.include_sequence_id.then_some(id))
+    }
+
+    /// Writes the sequences to a `random_sequences.dat` file in the vanilla format.
+    pub fn save_nbt(&self, path: &Path) -> Result<(), RandomSequencesDataError> {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+        let file = File::create(path)?;
+        pumpkin_nbt::nbt_compress::write_gzip_compound_tag(self.to_nbt(), file)
+            .map_err(|err| RandomSequencesDataError::Nbt(err.to_string()))
+    }
+
+    /// Reads a `random_sequences.dat` file in the vanilla format. A missing file gives no sequences.
+    pub fn load_nbt(path: &Path, world_seed: u64) -> Result<Self, RandomSequencesDataError> {
+        if !path.exists() {
+            return Ok(Self::new(world_seed));
+        }
+        let file = File::open(path)?;
+        let nbt = pumpkin_nbt::nbt_compress::read_gzip_compound_tag(file)
+            .map_err(|err| RandomSequencesDataError::Nbt(err.to_string()))?;
+        Ok(Self::from_nbt(&nbt, world_seed))
+    }
+
+    fn to_nbt(&self) -> NbtCompound {
+        let inner = self.inner.lock().unwrap();
+
+        let mut sequences = NbtCompound::new();
+        for (id, handle) in &inner.sequences {
+            let (lo, hi) = handle.state();
+            let mut sequence = NbtCompound::new();
+            sequence.put("source", NbtTag::LongArray(vec![lo as i64, hi as i64]));
+            sequences.put_component(id, sequence);
+        }
+
+        let mut data = NbtCompound::new();
+        data.put_int("salt", inner.settings.salt);
+        data.put_bool("include_world_seed", inner.settings.include_world_seed);
+        data.put_bool("include_sequence_id", inner.settings.include_sequence_id);
+        data.put_component("sequences", sequences);
+
+        let mut root = NbtCompound::new();
+        root.put_int("DataVersion", DATA_VERSION);
+        root.put_component("data", data);
+        root
+    }
+
+    fn from_nbt(root: &NbtCompound, world_seed: u64) -> Self {
+        let random_sequences = Self::new(world_seed);
+        let Some(data) = root.get_compound("data") else {
+            return random_sequences;
+        };
+
+        let mut inner = random_sequences.inner.lock().unwrap();
+        let defaults = SequenceSettings::default();
+        inner.settings = SequenceSettings {
+            salt: data.get_int("salt").unwrap_or(defaults.salt),
+            include_world_seed: data
+                .get_bool("include_world_seed")
+                .unwrap_or(defaults.include_world_seed),
+            include_sequence_id: data
+                .get_bool("include_sequence_id")
+                .unwrap_or(defaults.include_sequence_id),
+        };
+
+        if let Some(sequences) = data.get_compound("sequences") {
+            for (id, sequence) in &sequences.child_tags {
+                let Some(&[lo, hi]) = sequence
+                    .extract_compound()
+                    .and_then(|sequence| sequence.get_long_array("source"))
+                else {
+                    continue;
+                };
+                inner.sequences.insert(
+                    id.clone(),
+                    RandomHandle::new(Xoroshiro::from_state(lo as u64, hi as u64)),
+                );
+            }
+        }
+        drop(inner);
+        random_sequences
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_util::random::RandomImpl;
+    use tempfile::tempdir;
+
+    use super::{RandomSequences, SequenceSettings};
+
+    #[test]
+    fn sequences_round_trip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("data").join("random_sequences.dat");
+
+        let sequences = RandomSequences::new(1234);
+        sequences.clear_and_set_defaults(SequenceSettings {
+            salt: 7,
+            include_world_seed: false,
+            include_sequence_id: true,
+        });
+        let handle = sequences.get(Some("minecraft:test"));
+        handle.with(|random| random.next_i64());
+        sequences.save_nbt(&path).unwrap();
+
+        let loaded = RandomSequences::load_nbt(&path, 1234).unwrap();
+        assert_eq!(
+            loaded.inner.lock().unwrap().settings,
+            sequences.inner.lock().unwrap().settings
+        );
+        let loaded_handle = loaded.get(Some("minecraft:test"));
+        for _ in 0..10 {
+            assert_eq!(
+                handle.with(|random| random.next_i32()),
+                loaded_handle.with(|random| random.next_i32())
+            );
+        }
+    }
+
+    #[test]
+    fn sequences_are_deterministic() {
+        let first = RandomSequences::new(42).get(Some("minecraft:a"));
+        let second = RandomSequences::new(42).get(Some("minecraft:a"));
+        let other = RandomSequences::new(42).get(Some("minecraft:b"));
+        assert_eq!(first.state(), second.state());
+        assert_ne!(first.state(), other.state());
+    }
+
+    #[test]
+    fn reset_restarts_existing_handles() {
+        let sequences = RandomSequences::new(42);
+        let handle = sequences.get(Some("minecraft:a"));
+        let start = handle.state();
+        handle.with(|random| random.next_i64());
+        sequences.reset("minecraft:a");
+        assert_eq!(handle.state(), start);
+    }
+
+    #[test]
+    fn missing_file_is_empty() {
+        let dir = tempdir().unwrap();
+        let sequences =
+            RandomSequences::load_nbt(&dir.path().join("random_sequences.dat"), 0).unwrap();
+        assert!(sequences.inner.lock().unwrap().sequences.is_empty());
+    }
+}
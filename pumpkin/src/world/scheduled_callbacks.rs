@@ -0,0 +1,289 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex, RwLock},
+};
+
+use pumpkin_util::resource_location::ResourceLocation;
+use pumpkin_world::world_info::{ScheduledEvent, ScheduledEventCallback};
+use thiserror::Error;
+
+use crate::{plugin::BoxFuture, server::Server};
+
+/// More callbacks than this can't be pending at once, so a misbehaving plugin can't grow the
+/// queue (and `level.dat`) without bound.
+pub const MAX_SCHEDULED_CALLBACKS: usize = 4096;
+
+/// The callback type written to `level.dat`, matching vanilla's scheduled functions.
+const FUNCTION_CALLBACK_TYPE: &str = "minecraft:function";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ScheduleError {
+    #[error("Callbacks can't be scheduled for the current tick")]
+    SameTick,
+    #[error("There are already {MAX_SCHEDULED_CALLBACKS} scheduled callbacks")]
+    QueueFull,
+}
+
+/// What happens to callbacks that are already scheduled under the same ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ScheduleMode {
+    /// Runs the callback once more, keeping the earlier entries.
+    Append,
+    /// Drops the earlier entries first.
+    #[default]
+    Replace,
+}
+
+/// Runs the callbacks scheduled under an ID, e.g. registered by a plugin. Datapack functions
+/// will bind their IDs here once they are loaded.
+pub trait ScheduledCallbackHandler: Send + Sync {
+    fn run<'a>(&'a self, server: &'a Arc<Server>, id: &'a str) -> BoxFuture<'a, ()>;
+}
+
+/// Pending callbacks ordered by the game time they trigger at.
+#[derive(Default)]
+struct TimerQueue {
+    events: BTreeMap<i64, Vec<ResourceLocation>>,
+    len: usize,
+}
+
+impl TimerQueue {
+    fn schedule(
+        &mut self,
+        id: ResourceLocation,
+        trigger_time: i64,
+        mode: ScheduleMode,
+    ) -> Result<(), ScheduleError> {
+        if mode == ScheduleMode::Replace {
+            self.remove(&id);
+        }
+        if self.len >= MAX_SCHEDULED_CALLBACKS {
+            return Err(ScheduleError::QueueFull);
+        }
+        self.events.entry(trigger_time).or_default().push(id);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes every entry for `id` and returns how many there were.
+    fn remove(&mut self, id: &str) -> usize {
+        let mut removed = 0;
+        self.events.retain(|_, ids| {
+            let before = ids.len();
+            ids.retain(|scheduled| scheduled != id);
+            removed += before - ids.len();
+            !ids.is_empty()
+        });
+        self.len -= removed;
+        removed
+    }
+
+    /// Takes the IDs of all callbacks due at `time`, earliest first.
+    fn take_due(&mut self, time: i64) -> Vec<ResourceLocation> {
+        let later = self.events.split_off(&(time + 1));
+        let due: Vec<_> = std::mem::replace(&mut self.events, later)
+            .into_values()
+            .flatten()
+            .collect();
+        self.len -= due.len();
+        due
+    }
+}
+
+/// The callbacks scheduled with `/schedule` or by plugins, shared by all worlds and saved in
+/// `level.dat` like in vanilla. They are run by the overworld using its game time.
+#[derive(Default)]
+pub struct ScheduledCallbacks {
+    queue: Mutex<TimerQueue>,
+    handlers: RwLock<HashMap<ResourceLocation, Arc<dyn ScheduledCallbackHandler>>>,
+}
+
+impl ScheduledCallbacks {
+    /// Restores the callbacks saved in `level.dat`. Entries beyond the cap are dropped.
+    #[must_use]
+    pub fn from_events(events: &[ScheduledEvent]) -> Self {
+        let mut queue = TimerQueue::default();
+        for event in events {
+            if queue
+                .schedule(
+                    event.callback.name.clone(),
+                    event.trigger_time,
+                    ScheduleMode::Append,
+                )
+                .is_err()
+            {
+                break;
+            }
+        }
+        Self {
+            queue: Mutex::new(queue),
+            handlers: RwLock::default(),
+        }
+    }
+
+    /// The pending callbacks in the format saved to `level.dat`.
+    pub fn to_events(&self) -> Vec<ScheduledEvent> {
+        let queue = self.queue.lock().unwrap();
+        queue
+            .events
+            .iter()
+            .flat_map(|(&trigger_time, ids)| {
+                ids.iter().map(move |id| ScheduledEvent {
+                    name: id.clone(),
+                    trigger_time,
+                    callback: ScheduledEventCallback {
+                        callback_type: FUNCTION_CALLBACK_TYPE.to_string(),
+                        name: id.clone(),
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// Schedules `id` to run once the game time reaches `trigger_time`.
+    pub fn schedule(
+        &self,
+        id: ResourceLocation,
+        trigger_time: i64,
+        mode: ScheduleMode,
+    ) -> Result<(), ScheduleError> {
+        self.queue.lock().unwrap().schedule(id, trigger_time, mode)
+    }
+
+    /// Unschedules all callbacks for `id` and returns how many there were.
+    pub fn clear(&self, id: &str) -> usize {
+        self.queue.lock().unwrap().remove(id)
+    }
+
+    /// The number of pending callbacks.
+    pub fn len(&self) -> usize {
+        self.queue.lock().unwrap().len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Binds `handler` to `id`, replacing any handler bound before.
+    pub fn register_handler(
+        &self,
+        id: ResourceLocation,
+        handler: Arc<dyn ScheduledCallbackHandler>,
+    ) {
+        self.handlers.write().unwrap().insert(id, handler);
+    }
+
+    /// Unbinds the handler for `id`. Callbacks still scheduled under it are dropped when due.
+    pub fn unregister_handler(&self, id: &str) -> Option<Arc<dyn ScheduledCallbackHandler>> {
+        self.handlers.write().unwrap().remove(id)
+    }
+
+    /// Takes the callbacks due at `time` together with their handlers. Due callbacks without a
+    /// handler are returned with `None` so the caller can report them.
+    pub fn take_due(
+        &self,
+        time: i64,
+    ) -> Vec<(ResourceLocation, Option<Arc<dyn ScheduledCallbackHandler>>)> {
+        let due = self.queue.lock().unwrap().take_due(time);
+        if due.is_empty() {
+            return Vec::new();
+        }
+        let handlers = self.handlers.read().unwrap();
+        due.into_iter()
+            .map(|id| {
+                let handler = handlers.get(&id).cloned();
+                (id, handler)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MAX_SCHEDULED_CALLBACKS, ScheduleError, ScheduleMode, ScheduledCallbacks};
+
+    fn due_ids(callbacks: &ScheduledCallbacks, time: i64) -> Vec<String> {
+        callbacks
+            .take_due(time)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    #[test]
+    fn replace_and_append() {
+        let callbacks = ScheduledCallbacks::default();
+        callbacks
+            .schedule("test:a".to_string(), 10, ScheduleMode::Replace)
+            .unwrap();
+        callbacks
+            .schedule("test:a".to_string(), 20, ScheduleMode::Replace)
+            .unwrap();
+        assert_eq!(callbacks.len(), 1);
+
+        callbacks
+            .schedule("test:a".to_string(), 5, ScheduleMode::Append)
+            .unwrap();
+        callbacks
+            .schedule("test:b".to_string(), 5, ScheduleMode::Append)
+            .unwrap();
+        assert_eq!(callbacks.len(), 3);
+
+        assert!(due_ids(&callbacks, 4).is_empty());
+        assert_eq!(due_ids(&callbacks, 5), ["test:a", "test:b"]);
+        assert_eq!(due_ids(&callbacks, 25), ["test:a"]);
+        assert!(callbacks.is_empty());
+    }
+
+    #[test]
+    fn clear_removes_all_entries() {
+        let callbacks = ScheduledCallbacks::default();
+        for time in [3, 7, 7] {
+            callbacks
+                .schedule("test:a".to_string(), time, ScheduleMode::Append)
+                .unwrap();
+        }
+        callbacks
+            .schedule("test:b".to_string(), 7, ScheduleMode::Append)
+            .unwrap();
+        assert_eq!(callbacks.clear("test:a"), 3);
+        assert_eq!(callbacks.clear("test:a"), 0);
+        assert_eq!(due_ids(&callbacks, 100), ["test:b"]);
+    }
+
+    #[test]
+    fn queue_is_capped() {
+        let callbacks = ScheduledCallbacks::default();
+        for time in 0..MAX_SCHEDULED_CALLBACKS as i64 {
+            callbacks
+                .schedule("test:a".to_string(), time, ScheduleMode::Append)
+                .unwrap();
+        }
+        assert_eq!(
+            callbacks.schedule("test:b".to_string(), 1, ScheduleMode::Append),
+            Err(ScheduleError::QueueFull)
+        );
+        // Replacing frees the old entries before checking the cap
+        callbacks
+            .schedule("test:a".to_string(), 1, ScheduleMode::Replace)
+            .unwrap();
+        assert_eq!(callbacks.len(), 1);
+    }
+
+    #[test]
+    fn events_round_trip() {
+        let callbacks = ScheduledCallbacks::default();
+        callbacks
+            .schedule("test:a".to_string(), 40, ScheduleMode::Append)
+            .unwrap();
+        callbacks
+            .schedule("test:b".to_string(), 30, ScheduleMode::Append)
+            .unwrap();
+        let events = callbacks.to_events();
+        assert_eq!(events[0].name, "test:b");
+        assert_eq!(events[1].trigger_time, 40);
+
+        let loaded = ScheduledCallbacks::from_events(&events);
+        assert_eq!(loaded.to_events(), events);
+    }
+}
@@ -1,25 +1,109 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    fs::{File, create_dir_all},
+    path::Path,
+};
 
 use pumpkin_data::scoreboard::ScoreboardDisplaySlot;
+use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
 use pumpkin_protocol::{
     NumberFormat,
     codec::var_int::VarInt,
     java::client::play::{CDisplayObjective, CUpdateObjectives, CUpdateScore, RenderType},
 };
 use pumpkin_util::text::TextComponent;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use tracing::warn;
 
+use crate::net::java::JavaClient;
+
 use super::World;
 
+const DATA_VERSION: i32 = pumpkin_world::chunk::format::anvil::WORLD_DATA_VERSION;
+
+#[derive(Debug, Error)]
+pub enum ScoreboardDataError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("NBT error: {0}")]
+    Nbt(String),
+}
+
 #[derive(Default)]
 pub struct Scoreboard {
-    objectives: HashMap<String, ScoreboardObjective<'static>>,
+    objectives: HashMap<String, ScoreboardObjective>,
+    /// Scores keyed by the score holder's name and the objective name
+    scores: HashMap<(String, String), StoredScore>,
+    sidebar: Option<String>,
     //  teams: HashMap<String, Team>,
 }
 
+struct StoredScore {
+    value: i32,
+    locked: bool,
+}
+
+/// The contents of `scoreboard.dat`.
+#[derive(Serialize, Deserialize)]
+struct SavedScoreboard {
+    #[serde(rename = "DataVersion", default)]
+    data_version: i32,
+    data: SavedScoreboardData,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SavedScoreboardData {
+    #[serde(default)]
+    objectives: Vec<SavedObjective>,
+    #[serde(default)]
+    player_scores: Vec<SavedScore>,
+    /// Teams aren't supported yet, so none are written.
+    #[serde(default, skip_deserializing)]
+    teams: Vec<NbtCompound>,
+    /// Objective names keyed by display slot, e.g. `sidebar`.
+    #[serde(default)]
+    display_slots: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SavedObjective {
+    name: String,
+    #[serde(default = "default_criteria")]
+    criteria_name: String,
+    /// A text component, see [`write_text`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    display_name: Option<NbtTag>,
+    #[serde(default = "default_render_type")]
+    render_type: String,
+    #[serde(default)]
+    display_auto_update: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SavedScore {
+    name: String,
+    objective: String,
+    #[serde(default)]
+    score: i32,
+    #[serde(default)]
+    locked: bool,
+}
+
+fn default_criteria() -> String {
+    "dummy".to_string()
+}
+
+fn default_render_type() -> String {
+    "integer".to_string()
+}
+
 impl Scoreboard {
-    pub async fn add_objective(&mut self, world: &World, objective: ScoreboardObjective<'_>) {
-        if self.objectives.contains_key(objective.name) {
+    pub async fn add_objective(&mut self, world: &World, objective: ScoreboardObjective) {
+        if self.objectives.contains_key(&objective.name) {
             // Maybe make this an error?
             warn!(
                 "Tried to create an objective which already exists: {}",
@@ -29,29 +113,41 @@ impl Scoreboard {
         }
         world
             .broadcast_packet_all(&CUpdateObjectives::new(
-                objective.name.to_string(),
+                objective.name.clone(),
                 pumpkin_protocol::java::client::play::Mode::Add,
-                objective.display_name,
+                objective.display_name.clone(),
                 objective.render_type,
-                objective.number_format,
+                objective.number_format.clone(),
             ))
             .await;
         world
             .broadcast_packet_all(&CDisplayObjective::new(
                 ScoreboardDisplaySlot::Sidebar,
-                objective.name.to_string(),
+                objective.name.clone(),
             ))
             .await;
+        self.sidebar = Some(objective.name.clone());
+        self.objectives.insert(objective.name.clone(), objective);
     }
 
-    pub async fn update_score(&self, world: &World, score: ScoreboardScore<'_>) {
-        if self.objectives.contains_key(score.objective_name) {
+    pub async fn update_score(&mut self, world: &World, score: ScoreboardScore<'_>) {
+        if !self.objectives.contains_key(score.objective_name) {
             warn!(
                 "Tried to place a score into an objective which does not exist: {}",
                 &score.objective_name
             );
             return;
         }
+        self.scores
+            .entry((
+                score.entity_name.to_string(),
+                score.objective_name.to_string(),
+            ))
+            .or_insert(StoredScore {
+                value: 0,
+                locked: true,
+            })
+            .value = score.value.0;
         world
             .broadcast_packet_all(&CUpdateScore::new(
                 score.entity_name.to_string(),
@@ -63,6 +159,146 @@ impl Scoreboard {
             .await;
     }
 
+    /// Sends all objectives and scores to a player joining the world.
+    pub async fn init_client(&self, client: &JavaClient) {
+        for objective in self.objectives.values() {
+            client
+                .enqueue_packet(&CUpdateObjectives::new(
+                    objective.name.clone(),
+                    pumpkin_protocol::java::client::play::Mode::Add,
+                    objective.display_name.clone(),
+                    objective.render_type,
+                    objective.number_format.clone(),
+                ))
+                .await;
+        }
+        if let Some(sidebar) = &self.sidebar {
+            client
+                .enqueue_packet(&CDisplayObjective::new(
+                    ScoreboardDisplaySlot::Sidebar,
+                    sidebar.clone(),
+                ))
+                .await;
+        }
+        for ((entity_name, objective_name), score) in &self.scores {
+            client
+                .enqueue_packet(&CUpdateScore::new(
+                    entity_name.clone(),
+                    objective_name.clone(),
+                    VarInt(score.value),
+                    None,
+                    None,
+                ))
+                .await;
+        }
+    }
+
+    /// Writes the objectives and scores to a `scoreboard.dat` file in the vanilla format.
+    /// Number formats are not saved.
+    pub fn save_nbt(&self, path: &Path) -> Result<(), ScoreboardDataError> {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+        let file = File::create(path)?;
+        pumpkin_nbt::nbt_compress::to_gzip_bytes(&self.to_saved(), file)
+            .map_err(|err| ScoreboardDataError::Nbt(err.to_string()))
+    }
+
+    /// Reads a `scoreboard.dat` file in the vanilla format. A missing file gives an empty scoreboard.
+    pub fn load_nbt(path: &Path) -> Result<Self, ScoreboardDataError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let file = File::open(path)?;
+        let saved: SavedScoreboard = pumpkin_nbt::nbt_compress::from_gzip_bytes(file)
+            .map_err(|err| ScoreboardDataError::Nbt(err.to_string()))?;
+        Ok(Self::from_saved(saved))
+    }
+
+    fn to_saved(&self) -> SavedScoreboard {
+        let objectives = self
+            .objectives
+            .values()
+            .map(|objective| SavedObjective {
+                name: objective.name.clone(),
+                criteria_name: objective.criteria.clone(),
+                display_name: Some(write_text(&objective.display_name)),
+                render_type: match objective.render_type {
+                    RenderType::Integer => "integer",
+                    RenderType::Hearts => "hearts",
+                }
+                .to_string(),
+                display_auto_update: false,
+            })
+            .collect();
+
+        let player_scores = self
+            .scores
+            .iter()
+            .map(|((entity_name, objective_name), score)| SavedScore {
+                name: entity_name.clone(),
+                objective: objective_name.clone(),
+                score: score.value,
+                locked: score.locked,
+            })
+            .collect();
+
+        SavedScoreboard {
+            data_version: DATA_VERSION,
+            data: SavedScoreboardData {
+                objectives,
+                player_scores,
+                teams: Vec::new(),
+                display_slots: self
+                    .sidebar
+                    .iter()
+                    .map(|sidebar| ("sidebar".to_string(), sidebar.clone()))
+                    .collect(),
+            },
+        }
+    }
+
+    fn from_saved(saved: SavedScoreboard) -> Self {
+        let mut scoreboard = Self::default();
+        let data = saved.data;
+
+        for objective in data.objectives {
+            let display_name = objective
+                .display_name
+                .as_ref()
+                .map_or_else(|| TextComponent::text(objective.name.clone()), read_text);
+            let render_type = match objective.render_type.as_str() {
+                "hearts" => RenderType::Hearts,
+                _ => RenderType::Integer,
+            };
+            let mut objective_data =
+                ScoreboardObjective::new(&objective.name, display_name, render_type, None);
+            objective_data.criteria = objective.criteria_name;
+            scoreboard.objectives.insert(objective.name, objective_data);
+        }
+
+        for score in data.player_scores {
+            if !scoreboard.objectives.contains_key(&score.objective) {
+                continue;
+            }
+            scoreboard.scores.insert(
+                (score.name, score.objective),
+                StoredScore {
+                    value: score.score,
+                    locked: score.locked,
+                },
+            );
+        }
+
+        scoreboard.sidebar = data
+            .display_slots
+            .get("sidebar")
+            .filter(|name| scoreboard.objectives.contains_key(*name))
+            .cloned();
+
+        scoreboard
+    }
+
     // pub fn add_team(&mut self, name: String) {
     //     if self.teams.contains_key(&name) {
     //         // Maybe make this an error ?
@@ -71,24 +307,89 @@ impl Scoreboard {
     // }
 }
 
-pub struct ScoreboardObjective<'a> {
-    name: &'a str,
+/// Text components are stored as NBT, a plain string when they have no style.
+fn write_text(text: &TextComponent) -> NbtTag {
+    fn to_nbt(value: serde_json::Value) -> NbtTag {
+        match value {
+            serde_json::Value::Bool(value) => NbtTag::Byte(value.into()),
+            serde_json::Value::Number(number) => number.as_i64().map_or_else(
+                || NbtTag::Double(number.as_f64().unwrap_or_default()),
+                |int| i32::try_from(int).map_or(NbtTag::Long(int), NbtTag::Int),
+            ),
+            serde_json::Value::String(string) => NbtTag::String(string),
+            serde_json::Value::Array(values) => {
+                NbtTag::List(values.into_iter().map(to_nbt).collect())
+            }
+            serde_json::Value::Object(map) => {
+                if map.len() == 1
+                    && let Some(serde_json::Value::String(text)) = map.get("text")
+                {
+                    return NbtTag::String(text.clone());
+                }
+                let mut compound = NbtCompound::new();
+                for (key, value) in map {
+                    if !value.is_null() {
+                        compound.put(&key, to_nbt(value));
+                    }
+                }
+                NbtTag::Compound(compound)
+            }
+            serde_json::Value::Null => NbtTag::End,
+        }
+    }
+    serde_json::to_value(text).map_or_else(|_| NbtTag::String(String::new()), to_nbt)
+}
+
+/// Reads a text component stored as NBT, or as a JSON string like worlds before 1.21.5 do.
+fn read_text(tag: &NbtTag) -> TextComponent {
+    fn to_json(tag: &NbtTag) -> serde_json::Value {
+        match tag {
+            // Text components only use bytes for their style flags
+            NbtTag::Byte(value) => serde_json::Value::Bool(*value != 0),
+            NbtTag::Short(value) => (*value).into(),
+            NbtTag::Int(value) => (*value).into(),
+            NbtTag::Long(value) => (*value).into(),
+            NbtTag::Float(value) => f64::from(*value).into(),
+            NbtTag::Double(value) => (*value).into(),
+            NbtTag::String(value) => value.clone().into(),
+            NbtTag::List(values) => values.iter().map(to_json).collect(),
+            NbtTag::Compound(compound) => compound
+                .child_tags
+                .iter()
+                .map(|(key, value)| (key.clone(), to_json(value)))
+                .collect::<serde_json::Map<_, _>>()
+                .into(),
+            _ => serde_json::Value::Null,
+        }
+    }
+    match tag {
+        NbtTag::String(text) => {
+            serde_json::from_str(text).unwrap_or_else(|_| TextComponent::text(text.clone()))
+        }
+        tag => serde_json::from_value(to_json(tag)).unwrap_or_else(|_| TextComponent::text("")),
+    }
+}
+
+pub struct ScoreboardObjective {
+    name: String,
     display_name: TextComponent,
+    criteria: String,
     render_type: RenderType,
     number_format: Option<NumberFormat>,
 }
 
-impl<'a> ScoreboardObjective<'a> {
+impl ScoreboardObjective {
     #[must_use]
-    pub const fn new(
-        name: &'a str,
+    pub fn new(
+        name: &str,
         display_name: TextComponent,
         render_type: RenderType,
         number_format: Option<NumberFormat>,
     ) -> Self {
         Self {
-            name,
+            name: name.to_string(),
             display_name,
+            criteria: "dummy".to_string(),
             render_type,
             number_format,
         }
@@ -121,3 +422,160 @@ impl<'a> ScoreboardScore<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+    use pumpkin_protocol::java::client::play::RenderType;
+    use pumpkin_util::text::TextComponent;
+    use pumpkin_util::text::color::NamedColor;
+    use tempfile::tempdir;
+
+    use super::{Scoreboard, read_text, write_text};
+
+    /// Builds the data a vanilla server writes for `/scoreboard objectives add kills
+    /// playerKillCount "Kills"`, `... modify kills rendertype hearts`,
+    /// `/scoreboard objectives setdisplay sidebar kills` and `/scoreboard players set Steve kills 7`.
+    fn vanilla_scoreboard() -> NbtCompound {
+        let mut objective = NbtCompound::new();
+        objective.put_string("Name", "kills".to_string());
+        objective.put_string("CriteriaName", "playerKillCount".to_string());
+        objective.put(
+            "DisplayName",
+            NbtTag::Compound({
+                let mut name = NbtCompound::new();
+                name.put_string("text", "Kills".to_string());
+                name.put_string("color", "red".to_string());
+                name.put_bool("bold", true);
+                name
+            }),
+        );
+        objective.put_string("RenderType", "hearts".to_string());
+        objective.put_bool("DisplayAutoUpdate", false);
+
+        let mut score = NbtCompound::new();
+        score.put_string("Name", "Steve".to_string());
+        score.put_string("Objective", "kills".to_string());
+        score.put_int("Score", 7);
+        score.put_bool("Locked", false);
+
+        let mut display_slots = NbtCompound::new();
+        display_slots.put_string("sidebar", "kills".to_string());
+
+        let mut data = NbtCompound::new();
+        data.put_list("Objectives", vec![NbtTag::Compound(objective)]);
+        data.put_list("PlayerScores", vec![NbtTag::Compound(score)]);
+        data.put_list("Teams", Vec::new());
+        data.put_component("DisplaySlots", display_slots);
+
+        let mut root = NbtCompound::new();
+        root.put_int("DataVersion", 4671);
+        root.put_component("data", data);
+        root
+    }
+
+    fn assert_vanilla_contents(scoreboard: &Scoreboard) {
+        let objective = &scoreboard.objectives["kills"];
+        assert_eq!(objective.criteria, "playerKillCount");
+        assert_eq!(
+            objective.display_name,
+            TextComponent::text("Kills")
+                .color_named(NamedColor::Red)
+                .bold()
+        );
+        assert_eq!(objective.render_type, RenderType::Hearts);
+
+        let score = &scoreboard.scores[&("Steve".to_string(), "kills".to_string())];
+        assert_eq!(score.value, 7);
+        assert!(!score.locked);
+        assert_eq!(scoreboard.sidebar.as_deref(), Some("kills"));
+    }
+
+    fn write_vanilla_scoreboard(path: &Path) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        pumpkin_nbt::nbt_compress::write_gzip_compound_tag(
+            vanilla_scoreboard(),
+            std::fs::File::create(path).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn load_vanilla_scoreboard() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("data").join("scoreboard.dat");
+        write_vanilla_scoreboard(&path);
+
+        assert_vanilla_contents(&Scoreboard::load_nbt(&path).unwrap());
+    }
+
+    #[test]
+    fn scoreboard_round_trip() {
+        let dir = tempdir().unwrap();
+        let vanilla_path = dir.path().join("vanilla").join("scoreboard.dat");
+        write_vanilla_scoreboard(&vanilla_path);
+        let path = dir.path().join("data").join("scoreboard.dat");
+        Scoreboard::load_nbt(&vanilla_path)
+            .unwrap()
+            .save_nbt(&path)
+            .unwrap();
+
+        assert_vanilla_contents(&Scoreboard::load_nbt(&path).unwrap());
+
+        // The file is laid out like the vanilla one
+        let saved =
+            pumpkin_nbt::nbt_compress::read_gzip_compound_tag(std::fs::File::open(&path).unwrap())
+                .unwrap();
+        let vanilla = vanilla_scoreboard();
+        let data = saved.get_compound("data").unwrap();
+        let vanilla_data = vanilla.get_compound("data").unwrap();
+        assert!(saved.get_int("DataVersion").is_some());
+        for key in ["PlayerScores", "DisplaySlots"] {
+            assert_eq!(data.get(key), vanilla_data.get(key), "{key}");
+        }
+        let objectives = data.get_list("Objectives").unwrap();
+        let vanilla_objectives = vanilla_data.get_list("Objectives").unwrap();
+        let (Some(objective), Some(vanilla_objective)) = (
+            objectives[0].extract_compound(),
+            vanilla_objectives[0].extract_compound(),
+        ) else {
+            panic!("objectives are compounds");
+        };
+        for (key, value) in &vanilla_objective.child_tags {
+            if key == "DisplayName" {
+                assert_eq!(read_text(objective.get(key).unwrap()), read_text(value));
+            } else {
+                assert_eq!(objective.get(key), Some(value), "{key}");
+            }
+        }
+    }
+
+    #[test]
+    fn display_names_are_nbt() {
+        assert_eq!(
+            write_text(&TextComponent::text("Kills")),
+            NbtTag::String("Kills".to_string())
+        );
+        let styled = TextComponent::text("Kills").italic();
+        assert!(matches!(write_text(&styled), NbtTag::Compound(_)));
+        assert_eq!(read_text(&write_text(&styled)), styled);
+
+        // Worlds from before 1.21.5 store them as JSON
+        assert_eq!(
+            read_text(&NbtTag::String(
+                r#"{"text":"Kills","italic":true}"#.to_string()
+            )),
+            styled
+        );
+    }
+
+    #[test]
+    fn missing_file_is_empty() {
+        let dir = tempdir().unwrap();
+        let scoreboard = Scoreboard::load_nbt(&dir.path().join("scoreboard.dat")).unwrap();
+        assert!(scoreboard.objectives.is_empty());
+        assert!(scoreboard.scores.is_empty());
+    }
+}
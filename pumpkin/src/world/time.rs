@@ -1,3 +1,5 @@
+use std::f64::consts::PI;
+
 use pumpkin_protocol::{bedrock::client::set_time::CSetTime, java::client::play::CUpdateTime};
 
 use super::World;
@@ -66,6 +68,15 @@ impl LevelTime {
         self.time_of_day / 24000
     }
 
+    /// The position of the sun as a fraction of a full rotation, where 0 is noon and 0.5 is
+    /// midnight.
+    #[must_use]
+    pub fn sky_angle(&self) -> f32 {
+        let fraction = (self.time_of_day as f64 / 24000.0 - 0.25).rem_euclid(1.0);
+        let smoothed = 0.5 - (fraction * PI).cos() / 2.0;
+        ((fraction * 2.0 + smoothed) / 3.0) as f32
+    }
+
     #[must_use]
     pub const fn is_night(&self) -> bool {
         (self.time_of_day % 24000) >= 12000 && (self.time_of_day % 24000) <= 23999
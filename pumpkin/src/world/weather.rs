@@ -1,4 +1,8 @@
+use std::sync::Arc;
+use std::sync::atomic::Ordering::Relaxed;
+
 use super::World;
+use crate::plugin::world::weather_change::WeatherChangeEvent;
 use pumpkin_protocol::java::client::play::{CGameEvent, GameEvent};
 use rand::RngExt;
 
@@ -13,6 +17,8 @@ const THUNDER_DURATION_MIN: i32 = 3_600;
 const THUNDER_DURATION_MAX: i32 = 15_600;
 
 const WEATHER_TRANSITION_SPEED: f32 = 0.01;
+/// Rain only puts out burning entities once it is heavier than a drizzle.
+const RAIN_EXTINGUISH_LEVEL: f32 = 0.2;
 
 pub struct Weather {
     pub clear_weather_time: i32,
@@ -52,14 +58,14 @@ impl Weather {
         }
     }
 
-    pub async fn set_weather_parameters(
+    /// Applies new weather timers and state. Returns whether rain started or stopped.
+    const fn set_weather_parameters(
         &mut self,
-        world: &World,
         clear_time: i32,
         rain_time: i32,
         raining: bool,
         thundering: bool,
-    ) {
+    ) -> bool {
         let was_raining = self.raining;
 
         self.clear_weather_time = clear_time;
@@ -68,24 +74,10 @@ impl Weather {
         self.raining = raining;
         self.thundering = thundering;
 
-        if was_raining != raining {
-            if was_raining {
-                world
-                    .broadcast_packet_all(&CGameEvent::new(GameEvent::EndRaining, 0.0))
-                    .await;
-            } else {
-                world
-                    .broadcast_packet_all(&CGameEvent::new(GameEvent::BeginRaining, 0.0))
-                    .await;
-            }
-        }
+        was_raining != raining
     }
 
     pub async fn tick_weather(&mut self, world: &World) {
-        if !self.weather_cycle_enabled {
-            self.advance_weather_cycle();
-        }
-
         // Update visual transitions
         self.old_rain_level = self.rain_level;
         self.old_thunder_level = self.thunder_level;
@@ -122,8 +114,10 @@ impl Weather {
         }
     }
 
-    fn advance_weather_cycle(&mut self) {
-        // Removed async since there are no await calls
+    /// Advances the weather timers. Returns the rain and thunder state they ask for if it
+    /// differs from the current one, which stays until plugins had their say.
+    fn advance_weather_cycle(&mut self) -> Option<(bool, bool)> {
+        let current = (self.raining, self.thundering);
         if self.clear_weather_time > 0 {
             self.clear_weather_time -= 1;
             self.thunder_time = i32::from(!self.thundering);
@@ -156,10 +150,94 @@ impl Weather {
                 self.rain_time = rand::rng().random_range(RAIN_DELAY_MIN..=RAIN_DELAY_MAX);
             }
         }
+        let next = (self.raining, self.thundering);
+        (self.raining, self.thundering) = current;
+        (next != current).then_some(next)
     }
+}
+
+impl World {
+    /// Fires a `WeatherChangeEvent` if rain or thunder would start or stop.
+    ///
+    /// Returns the weather plugins settled on, or `None` if the change was cancelled. The
+    /// weather lock must not be held, since handlers may read the weather of this world.
+    async fn fire_weather_change(
+        self: &Arc<Self>,
+        raining: bool,
+        thundering: bool,
+    ) -> Option<(bool, bool)> {
+        {
+            let weather = self.weather.lock().await;
+            if raining == weather.raining && thundering == weather.thundering {
+                return Some((raining, thundering));
+            }
+        }
+        let Some(server) = self.server.upgrade() else {
+            return Some((raining, thundering));
+        };
+        let event = server
+            .plugin_manager
+            .fire(WeatherChangeEvent::new(self.clone(), raining, thundering))
+            .await;
+        (!event.cancelled).then_some((event.raining, event.thundering))
+    }
+
+    pub async fn set_weather_parameters(
+        self: &Arc<Self>,
+        clear_time: i32,
+        rain_time: i32,
+        raining: bool,
+        thundering: bool,
+    ) {
+        let Some((raining, thundering)) = self.fire_weather_change(raining, thundering).await
+        else {
+            return;
+        };
+        let rain_changed = self
+            .weather
+            .lock()
+            .await
+            .set_weather_parameters(clear_time, rain_time, raining, thundering);
+
+        if rain_changed {
+            if raining {
+                self.broadcast_packet_all(&CGameEvent::new(GameEvent::BeginRaining, 0.0))
+                    .await;
+            } else {
+                self.broadcast_packet_all(&CGameEvent::new(GameEvent::EndRaining, 0.0))
+                    .await;
+            }
+        }
+    }
+
+    pub async fn reset_weather_cycle(self: &Arc<Self>) {
+        self.set_weather_parameters(0, 0, false, false).await;
+    }
+
+    /// Runs the weather cycle and the rain and thunder transitions for one tick.
+    pub async fn tick_weather(self: &Arc<Self>) {
+        let change = {
+            let mut weather = self.weather.lock().await;
+            if weather.weather_cycle_enabled {
+                None
+            } else {
+                weather.advance_weather_cycle()
+            }
+        };
+        if let Some((raining, thundering)) = change
+            && let Some((raining, thundering)) = self.fire_weather_change(raining, thundering).await
+        {
+            let mut weather = self.weather.lock().await;
+            weather.raining = raining;
+            weather.thundering = thundering;
+        }
 
-    pub async fn reset_weather_cycle(&mut self, world: &World) {
-        self.set_weather_parameters(world, 0, 0, false, false).await;
+        let mut weather = self.weather.lock().await;
+        weather.tick_weather(self).await;
+        self.raining_hard.store(
+            weather.raining && weather.rain_level > RAIN_EXTINGUISH_LEVEL,
+            Relaxed,
+        );
     }
 }
 